@@ -1291,6 +1291,8 @@ fn counter_app() {
                                                     PointerEventKind::Cancel => {
                                                         pointer_down_state.set(false)
                                                     }
+                                                    PointerEventKind::Enter
+                                                    | PointerEventKind::Exit => {}
                                                 }
                                             }
                                         })
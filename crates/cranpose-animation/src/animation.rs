@@ -36,6 +36,14 @@ pub trait SpringScalar: Lerp + Clone {
     /// Convert the value to `f32` for physics calculations.
     fn to_f32(&self) -> f32;
 
+    /// Convert the value to `f64` for spring integration.
+    fn to_f64(&self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Convert back from the `f64` used for spring integration.
+    fn from_f64(value: f64) -> Self;
+
     /// Compute the current progress between the start and target values.
     fn spring_progress(start: &Self, target: &Self, current: &Self) -> f32 {
         let start_val = start.to_f32();
@@ -60,12 +68,24 @@ impl SpringScalar for f32 {
     fn to_f32(&self) -> f32 {
         *self
     }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
 }
 
 impl SpringScalar for f64 {
     fn to_f32(&self) -> f32 {
         *self as f32
     }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
 }
 
 /// Easing functions for animations matching Jetpack Compose.
@@ -289,13 +309,15 @@ struct AnimatableInner<T: SpringScalar + 'static> {
     state: MutableState<T>,
     runtime: RuntimeHandle,
     current: T,
-    /// Velocity for spring animations (currently unused, reserved for future spring physics)
-    #[allow(dead_code)]
-    velocity: f32,
+    /// Rate of change of `current` in units per second.
+    velocity: f64,
     start: T,
     target: T,
     animation_type: AnimationType,
     start_time_nanos: Option<u64>,
+    /// Time of the last frame that moved the value, kept across retargets so
+    /// the next animation integrates from there.
+    last_frame_nanos: Option<u64>,
     registration: Option<FrameCallbackRegistration>,
 }
 
@@ -311,6 +333,7 @@ impl<T: SpringScalar + 'static> Animatable<T> {
             target: initial,
             animation_type: AnimationType::default(),
             start_time_nanos: None,
+            last_frame_nanos: None,
             registration: None,
         };
         Self {
@@ -319,6 +342,10 @@ impl<T: SpringScalar + 'static> Animatable<T> {
     }
 
     /// Animate to the target value using the specified animation.
+    ///
+    /// Interrupting a running animation retargets it from the current value,
+    /// and a spring continues with the current velocity so the motion does
+    /// not jump. Tweens always start from rest.
     pub fn animateTo(&mut self, target: T, animation: AnimationType) {
         let should_schedule = {
             let mut inner = self.inner.borrow_mut();
@@ -341,6 +368,18 @@ impl<T: SpringScalar + 'static> Animatable<T> {
         }
     }
 
+    /// Like [`animateTo`](Self::animateTo), starting from `initial_velocity`
+    /// units per second, e.g. the velocity of the gesture that ends here.
+    pub fn animateToWithVelocity(
+        &mut self,
+        target: T,
+        animation: AnimationType,
+        initial_velocity: f32,
+    ) {
+        self.inner.borrow_mut().velocity = initial_velocity as f64;
+        self.animateTo(target, animation);
+    }
+
     /// Return the current animation target.
     pub fn target(&self) -> T {
         self.inner.borrow().target.clone()
@@ -351,6 +390,11 @@ impl<T: SpringScalar + 'static> Animatable<T> {
         self.inner.borrow().animation_type
     }
 
+    /// Current velocity in units per second; zero when at rest.
+    pub fn velocity(&self) -> f32 {
+        self.inner.borrow().velocity as f32
+    }
+
     /// Get the current state.
     pub fn state(&self) -> State<T> {
         self.inner.borrow().state.as_state()
@@ -365,7 +409,9 @@ impl<T: SpringScalar + 'static> Animatable<T> {
         inner.current = target.clone();
         inner.start = target.clone();
         inner.target = target.clone();
+        inner.velocity = 0.0;
         inner.start_time_nanos = None;
+        inner.last_frame_nanos = None;
         inner.state.set_value(target);
     }
 
@@ -391,6 +437,11 @@ impl<T: SpringScalar + 'static> Animatable<T> {
         {
             let mut inner = this.borrow_mut();
             inner.registration = None;
+            let frame_seconds = inner
+                .last_frame_nanos
+                .replace(frame_time_nanos)
+                .map(|last| frame_time_nanos.saturating_sub(last) as f64 / 1_000_000_000.0)
+                .unwrap_or(0.0);
 
             match inner.animation_type {
                 AnimationType::Tween(spec) => {
@@ -409,85 +460,54 @@ impl<T: SpringScalar + 'static> Animatable<T> {
                         let progress = spec.easing.transform(linear_progress);
 
                         let new_value = inner.start.lerp(&inner.target, progress);
+                        if frame_seconds > 0.0 {
+                            inner.velocity =
+                                (new_value.to_f64() - inner.current.to_f64()) / frame_seconds;
+                        }
                         inner.current = new_value.clone();
                         inner.state.set_value(new_value);
 
                         if linear_progress >= 1.0 {
-                            inner.current = inner.target.clone();
-                            inner.start = inner.target.clone();
-                            inner.start_time_nanos = None;
-                            inner.state.set_value(inner.target.clone());
+                            inner.finish();
                         } else {
                             schedule_next = true;
                         }
                     }
                 }
                 AnimationType::Spring(spec) => {
-                    // Implement spring physics using damped harmonic oscillator
-                    let start_time = inner.start_time_nanos.get_or_insert(frame_time_nanos);
-                    let elapsed_nanos = frame_time_nanos.saturating_sub(*start_time);
-                    let dt = elapsed_nanos as f32 / 1_000_000_000.0; // Convert to seconds
+                    // Damped harmonic oscillator in value space, so the
+                    // velocity stays meaningful when the target changes.
+                    let stiffness = spec.stiffness as f64;
+                    let damping = 2.0 * spec.damping_ratio as f64 * stiffness.sqrt();
+                    let target = inner.target.to_f64();
+                    let mut position = inner.current.to_f64();
+                    let mut velocity = inner.velocity;
+
+                    // Semi-implicit Euler integration for stability
+                    let timestep: f64 = 0.016; // ~60fps timestep for stability
+                    let mut remaining = frame_seconds;
+                    while remaining > 0.0 {
+                        let step = timestep.min(remaining);
+                        let force = -stiffness * (position - target) - damping * velocity;
+                        velocity += force * step;
+                        position += velocity * step;
+                        remaining -= step;
+                    }
 
-                    // SpringScalar ensures we have scalar values that support the
-                    // physics calculations below (currently f32 and f64).
-                    if dt == 0.0 {
-                        schedule_next = true;
+                    inner.velocity = velocity;
+                    inner.current = T::from_f64(position);
+                    inner.state.set_value(inner.current.clone());
+
+                    let at_rest = velocity.abs() < spec.velocity_threshold as f64;
+                    let near_target = <T as SpringScalar>::is_near_target(
+                        &inner.current,
+                        &inner.target,
+                        spec.position_threshold,
+                    );
+                    if at_rest && near_target {
+                        inner.finish();
                     } else {
-                        // Spring physics calculations
-                        // Using semi-implicit Euler integration for stability
-                        let stiffness = spec.stiffness;
-                        let damping = 2.0 * spec.damping_ratio * stiffness.sqrt();
-
-                        // Simulate spring from last frame to current frame
-                        let mut prev_time = 0.0f32;
-                        let timestep: f32 = 0.016; // ~60fps timestep for stability
-
-                        while prev_time < dt {
-                            let step = timestep.min(dt - prev_time);
-
-                            // Spring force: F = -k * displacement - damping * velocity
-                            // For interpolation between start and target:
-                            // We treat position as progress from 0 to 1
-                            let current_progress = <T as SpringScalar>::spring_progress(
-                                &inner.start,
-                                &inner.target,
-                                &inner.current,
-                            );
-
-                            let displacement = current_progress - 1.0; // Target is at 1.0
-                            let spring_force = -stiffness * displacement - damping * inner.velocity;
-
-                            // Update velocity and position
-                            inner.velocity += spring_force * step;
-                            let new_progress = current_progress + inner.velocity * step;
-
-                            // Update current value
-                            inner.current = inner
-                                .start
-                                .lerp(&inner.target, new_progress.clamp(0.0, 2.0));
-
-                            prev_time += step;
-                        }
-
-                        inner.state.set_value(inner.current.clone());
-
-                        // Check if we've settled (velocity and displacement both small)
-                        let at_rest = inner.velocity.abs() < spec.velocity_threshold;
-                        let near_target = <T as SpringScalar>::is_near_target(
-                            &inner.current,
-                            &inner.target,
-                            spec.position_threshold,
-                        );
-
-                        if at_rest && near_target {
-                            inner.current = inner.target.clone();
-                            inner.start = inner.target.clone();
-                            inner.start_time_nanos = None;
-                            inner.velocity = 0.0;
-                            inner.state.set_value(inner.target.clone());
-                        } else {
-                            schedule_next = true;
-                        }
+                        schedule_next = true;
                     }
                 }
             }
@@ -499,6 +519,17 @@ impl<T: SpringScalar + 'static> Animatable<T> {
     }
 }
 
+impl<T: SpringScalar + 'static> AnimatableInner<T> {
+    fn finish(&mut self) {
+        self.current = self.target.clone();
+        self.start = self.target.clone();
+        self.velocity = 0.0;
+        self.start_time_nanos = None;
+        self.last_frame_nanos = None;
+        self.state.set_value(self.target.clone());
+    }
+}
+
 #[allow(non_snake_case)]
 pub fn animateFloatAsState(target: f32, label: &str) -> State<f32> {
     animateFloatAsStateWithSpec(target, AnimationType::default(), label)
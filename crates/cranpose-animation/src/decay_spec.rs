@@ -307,6 +307,24 @@ impl SplineBasedDecaySpec {
     pub fn with_calculator(calculator: FlingCalculator) -> Self {
         Self { calculator }
     }
+
+    /// Position where a fling from `start` with `velocity` (px/sec) comes to
+    /// rest, e.g. to pick a snap target before animating.
+    pub fn fling_target(&self, start: f32, velocity: f32) -> f32 {
+        let distance = self.calculator.fling_distance(velocity);
+        if !distance.is_finite() || velocity == 0.0 {
+            return start;
+        }
+        start + distance * velocity.signum()
+    }
+
+    /// Duration in nanoseconds of a fling with `velocity` (px/sec).
+    pub fn fling_duration(&self, velocity: f32) -> u64 {
+        if velocity == 0.0 {
+            return 0;
+        }
+        self.calculator.fling_duration(velocity).max(0) as u64 * 1_000_000
+    }
 }
 
 impl FloatDecayAnimationSpec for SplineBasedDecaySpec {
@@ -337,13 +355,11 @@ impl FloatDecayAnimationSpec for SplineBasedDecaySpec {
     }
 
     fn get_duration_nanos(&self, _initial_value: f32, initial_velocity: f32) -> i64 {
-        let duration_ms = self.calculator.fling_duration(initial_velocity);
-        duration_ms * 1_000_000
+        self.fling_duration(initial_velocity) as i64
     }
 
     fn get_target_value(&self, initial_value: f32, initial_velocity: f32) -> f32 {
-        let distance = self.calculator.fling_distance(initial_velocity);
-        initial_value + distance * initial_velocity.signum()
+        self.fling_target(initial_value, initial_velocity)
     }
 }
 
@@ -415,6 +431,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fling_target_matches_decay_integral() {
+        let spec = SplineBasedDecaySpec::new(2.0);
+        let start = 100.0;
+        let velocity = -4000.0;
+
+        let duration = spec.fling_duration(velocity);
+        let step = 1_000_000;
+        let mut travelled = 0.0;
+        let mut time = 0;
+        while time < duration {
+            let v = spec.get_velocity_from_nanos(time as i64, start, velocity);
+            travelled += v * step as f32 / 1_000_000_000.0;
+            time += step;
+        }
+
+        let target = spec.fling_target(start, velocity);
+        assert!(target < start);
+        assert!(
+            (start + travelled - target).abs() < (target - start).abs() * 0.01,
+            "integrated landing {} should match predicted target {}",
+            start + travelled,
+            target
+        );
+    }
+
+    #[test]
+    fn test_tiny_fling_stays_put() {
+        let spec = SplineBasedDecaySpec::new(2.0);
+
+        assert_eq!(spec.fling_target(50.0, 0.0), 50.0);
+        assert_eq!(spec.fling_duration(0.0), 0);
+
+        let target = spec.fling_target(50.0, 0.01);
+        assert!(target >= 50.0 && target - 50.0 < 0.01);
+    }
+
     #[test]
     fn test_negative_velocity() {
         let calc = FlingCalculator::with_density(2.0);
@@ -152,3 +152,70 @@ fn spring_spec_stiff_has_high_stiffness() {
     assert_eq!(spec.stiffness, 3000.0);
     assert!(spec.stiffness > SpringSpec::default().stiffness);
 }
+
+const FRAME_NANOS: u64 = 16_000_000;
+
+#[test]
+fn retargeting_a_spring_keeps_its_velocity() {
+    let composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let mut animatable = Animatable::new(0.0f32, runtime.clone());
+    // Soft enough that a single frame cannot reverse the motion.
+    let spring = AnimationType::Spring(SpringSpec {
+        stiffness: 200.0,
+        ..SpringSpec::default()
+    });
+
+    animatable.animateTo(100.0, spring);
+    let mut time = 0;
+    for _ in 0..3 {
+        runtime.drain_frame_callbacks(time);
+        time += FRAME_NANOS;
+    }
+    let value = animatable.state().get();
+    let velocity = animatable.velocity();
+    assert!(value > 0.0 && value < 100.0);
+    assert!(velocity > 0.0);
+
+    // Send it back to where it started: the value and velocity carry over
+    // instead of restarting from rest.
+    animatable.animateTo(0.0, spring);
+    assert_eq!(animatable.state().get(), value);
+    assert_eq!(animatable.velocity(), velocity);
+
+    runtime.drain_frame_callbacks(time);
+    time += FRAME_NANOS;
+    assert!(
+        animatable.state().get() > value,
+        "momentum carries the value past the retarget point"
+    );
+
+    for _ in 0..120 {
+        runtime.drain_frame_callbacks(time);
+        time += FRAME_NANOS;
+    }
+    assert_eq!(animatable.state().get(), 0.0);
+    assert_eq!(animatable.velocity(), 0.0);
+    assert!(!runtime.has_frame_callbacks());
+}
+
+#[test]
+fn snap_to_stops_an_animation_in_flight() {
+    let composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let mut animatable = Animatable::new(0.0f32, runtime.clone());
+
+    animatable.animateTo(100.0, AnimationType::Spring(SpringSpec::default()));
+    runtime.drain_frame_callbacks(0);
+    runtime.drain_frame_callbacks(FRAME_NANOS);
+    assert!(animatable.velocity() > 0.0);
+
+    animatable.snapTo(40.0);
+    assert_eq!(animatable.state().get(), 40.0);
+    assert_eq!(animatable.velocity(), 0.0);
+    assert!(!runtime.has_frame_callbacks());
+
+    runtime.drain_frame_callbacks(2 * FRAME_NANOS);
+    assert_eq!(animatable.state().get(), 40.0);
+    assert_eq!(animatable.target(), 40.0);
+}
@@ -0,0 +1,189 @@
+//! Plain-data export of the semantics tree for platform accessibility bridges.
+//!
+//! [`AccessibilityNode::to_json`] writes a snapshot out for bridges that run
+//! outside the process, without pulling in a serialisation framework.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use cranpose_core::NodeId;
+use cranpose_render_common::json::{write_numbers, write_optional, write_string};
+use cranpose_ui::{
+    LayoutBox, LayoutOverlay, LayoutTree, Rect, SemanticsAction, SemanticsNode, SemanticsRole,
+};
+
+/// An action an accessibility service can ask a node to perform.
+///
+/// Perform it through the shell with the node's id; the handlers stay in the
+/// [`SemanticsTree`](cranpose_ui::SemanticsTree).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessibilityAction {
+    Click,
+    ScrollBy,
+    ScrollToIndex,
+    Focus,
+}
+
+impl AccessibilityAction {
+    fn name(self) -> &'static str {
+        match self {
+            AccessibilityAction::Click => "click",
+            AccessibilityAction::ScrollBy => "scroll_by",
+            AccessibilityAction::ScrollToIndex => "scroll_to_index",
+            AccessibilityAction::Focus => "focus",
+        }
+    }
+}
+
+/// One node of an accessibility snapshot.
+///
+/// Holds only owned values, so a snapshot can be sent to another thread or
+/// serialized for a platform accessibility API.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessibilityNode {
+    pub node_id: NodeId,
+    pub role: SemanticsRole,
+    /// Bounds in window coordinates.
+    pub bounds: Rect,
+    pub description: Option<String>,
+    pub state_description: Option<String>,
+    pub actions: Vec<AccessibilityAction>,
+    pub is_enabled: bool,
+    pub is_focusable: bool,
+    /// Children in reading order.
+    pub children: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityNode {
+    /// Serialises the node and its descendants as a JSON object:
+    ///
+    /// ```json
+    /// {"node":4,"role":"button","text":null,"bounds":[0,0,100,40],
+    ///  "description":"Save","state":null,"actions":["click","focus"],
+    ///  "enabled":true,"focusable":true,"children":[]}
+    /// ```
+    ///
+    /// Bounds are `[x, y, width, height]`. `text` carries the value of a
+    /// `text` role and is `null` for every other role.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        write_node(&mut out, self);
+        out
+    }
+}
+
+/// Combines `semantics` with the bounds from `layout` and its popup
+/// `overlay`. Nodes entirely outside `viewport`, and their descendants, are
+/// left out; `None` if the root itself is.
+pub(crate) fn build_accessibility_tree(
+    semantics: &SemanticsNode,
+    layout: &LayoutTree,
+    overlay: &LayoutOverlay,
+    viewport: Rect,
+) -> Option<AccessibilityNode> {
+    let mut bounds = HashMap::new();
+    collect_bounds(layout.root(), &mut bounds);
+    for layer in overlay.layers() {
+        collect_bounds(layer, &mut bounds);
+    }
+    build_node(semantics, &bounds, viewport)
+}
+
+fn collect_bounds(layout: &LayoutBox, bounds: &mut HashMap<NodeId, Rect>) {
+    bounds.insert(layout.node_id, layout.rect);
+    for child in &layout.children {
+        collect_bounds(child, bounds);
+    }
+}
+
+fn build_node(
+    node: &SemanticsNode,
+    bounds: &HashMap<NodeId, Rect>,
+    viewport: Rect,
+) -> Option<AccessibilityNode> {
+    let rect = bounds.get(&node.node_id).copied()?;
+    if !intersects(rect, viewport) {
+        return None;
+    }
+    Some(AccessibilityNode {
+        node_id: node.node_id,
+        role: node.role.clone(),
+        bounds: rect,
+        description: node.description.clone(),
+        state_description: node.state_description.clone(),
+        actions: node
+            .actions
+            .iter()
+            .map(|action| match action {
+                SemanticsAction::Click { .. } => AccessibilityAction::Click,
+                SemanticsAction::ScrollBy { .. } => AccessibilityAction::ScrollBy,
+                SemanticsAction::ScrollToIndex { .. } => AccessibilityAction::ScrollToIndex,
+                SemanticsAction::RequestFocus { .. } => AccessibilityAction::Focus,
+            })
+            .collect(),
+        is_enabled: node.is_enabled,
+        is_focusable: node.is_focusable,
+        children: node
+            .children
+            .iter()
+            .filter_map(|child| build_node(child, bounds, viewport))
+            .collect(),
+    })
+}
+
+fn intersects(a: Rect, b: Rect) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+fn write_node(out: &mut String, node: &AccessibilityNode) {
+    let _ = write!(out, "{{\"node\":{},\"role\":", node.node_id);
+    write_string(out, role_name(&node.role));
+    out.push_str(",\"text\":");
+    match &node.role {
+        SemanticsRole::Text { value } => write_string(out, value),
+        _ => out.push_str("null"),
+    }
+    out.push_str(",\"bounds\":");
+    let rect = node.bounds;
+    write_numbers(out, &[rect.x, rect.y, rect.width, rect.height]);
+    out.push_str(",\"description\":");
+    write_optional(out, node.description.as_deref(), write_string);
+    out.push_str(",\"state\":");
+    write_optional(out, node.state_description.as_deref(), write_string);
+    out.push_str(",\"actions\":[");
+    for (index, action) in node.actions.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_string(out, action.name());
+    }
+    let _ = write!(
+        out,
+        "],\"enabled\":{},\"focusable\":{},\"children\":[",
+        node.is_enabled, node.is_focusable
+    );
+    for (index, child) in node.children.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_node(out, child);
+    }
+    out.push_str("]}");
+}
+
+fn role_name(role: &SemanticsRole) -> &'static str {
+    match role {
+        SemanticsRole::Layout => "layout",
+        SemanticsRole::Subcompose => "subcompose",
+        SemanticsRole::Text { .. } => "text",
+        SemanticsRole::Spacer => "spacer",
+        SemanticsRole::Button => "button",
+        SemanticsRole::Checkbox => "checkbox",
+        SemanticsRole::Switch => "switch",
+        SemanticsRole::Slider => "slider",
+        SemanticsRole::Image => "image",
+        SemanticsRole::Header => "header",
+        SemanticsRole::Group => "group",
+        SemanticsRole::Unknown => "unknown",
+    }
+}
@@ -1,8 +1,11 @@
 #![allow(clippy::type_complexity)]
 
+mod accessibility;
 mod fps_monitor;
 mod hit_path_tracker;
 
+pub use accessibility::{AccessibilityAction, AccessibilityNode};
+
 // Re-export FPS monitoring API
 pub use fps_monitor::{
     current_fps, fps_display, fps_display_detailed, fps_stats, record_recomposition, FpsStats,
@@ -14,25 +17,73 @@ use web_time::Instant;
 
 use cranpose_core::{
     enter_event_handler, exit_event_handler, location_key, run_in_mutable_snapshot, Applier,
-    Composition, Key, MemoryApplier, NodeError, NodeId,
+    Composition, CompositionLocalProvider, Key, MemoryApplier, MutableState, NodeError, NodeId,
+};
+use cranpose_foundation::{
+    PointerButtons, PointerEvent, PointerEventKind, ScrollAxisRange, DRAG_THRESHOLD,
 };
-use cranpose_foundation::{PointerButton, PointerButtons, PointerEvent, PointerEventKind};
+
 use cranpose_render_common::{HitTestTarget, RenderScene, Renderer};
 use cranpose_runtime_std::StdRuntime;
 use cranpose_ui::{
-    has_pending_focus_invalidations, has_pending_pointer_repasses, log_layout_tree,
+    composable, has_pending_focus_invalidations, has_pending_pointer_repasses, log_layout_tree,
     log_render_scene, log_screen_summary, peek_focus_invalidation, peek_layout_invalidation,
     peek_pointer_invalidation, peek_render_invalidation, process_focus_invalidations,
     process_pointer_repasses, request_render_invalidation, take_draw_repass_nodes,
     take_focus_invalidation, take_layout_invalidation, take_pointer_invalidation,
-    take_render_invalidation, HeadlessRenderer, LayoutNode, LayoutTree, SemanticsTree,
-    SubcomposeLayoutNode,
+    take_render_invalidation, BackDispatcher, HeadlessRenderer, LayoutMeasurements, LayoutNode,
+    LayoutOverlay, LayoutTree, Lifecycle, LocalBackDispatcher, LocalLifecycle,
+    LocalSoftwareKeyboardController, LocalWindowInsets, SemanticsAction, SemanticsTree,
+    SoftwareKeyboardController, SubcomposeLayoutNode, WindowInsets,
 };
 use cranpose_ui_graphics::{Point, Size};
 use hit_path_tracker::{HitPathTracker, PointerId};
+use std::cell::RefCell;
 use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Provides the window insets to `content`. Its own scope recomposes when
+/// they change; the root content closure has none.
+#[allow(non_snake_case)]
+#[composable]
+fn ProvideWindowInsets<F>(insets: MutableState<WindowInsets>, content: F)
+where
+    F: FnMut() + 'static,
+{
+    CompositionLocalProvider([LocalWindowInsets().provides(insets.value())], content);
+}
+
+/// Keyboard controller provided to content. Forwards to the platform
+/// controller once one is installed and does nothing before that, e.g. when
+/// running headless.
+#[derive(Default)]
+struct ShellKeyboardController {
+    platform: RefCell<Option<Rc<dyn SoftwareKeyboardController>>>,
+}
+
+/// There is one controller per shell, so compare by identity.
+impl PartialEq for ShellKeyboardController {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
 
-// Re-export key event types for use by compose-app
+impl SoftwareKeyboardController for ShellKeyboardController {
+    fn show(&self) {
+        if let Some(platform) = self.platform.borrow().as_ref() {
+            platform.show();
+        }
+    }
+
+    fn hide(&self) {
+        if let Some(platform) = self.platform.borrow().as_ref() {
+            platform.hide();
+        }
+    }
+}
+
+// Re-export key event and pointer button types for use by compose-app
+pub use cranpose_foundation::PointerButton;
 pub use cranpose_ui::{KeyCode, KeyEvent, KeyEventType, Modifiers};
 
 pub struct AppShell<R>
@@ -44,9 +95,21 @@ where
     renderer: R,
     cursor: (f32, f32),
     viewport: (f32, f32),
+    /// Insets reported by the platform, provided to content as
+    /// [`LocalWindowInsets`].
+    window_insets: MutableState<WindowInsets>,
+    keyboard: Rc<ShellKeyboardController>,
+    /// Back handlers registered by content, see [`AppShell::dispatch_back`].
+    back_dispatcher: BackDispatcher,
+    /// Foreground state reported by the platform, see
+    /// [`AppShell::set_lifecycle_started`].
+    lifecycle: Lifecycle,
     buffer_size: (u32, u32),
     start_time: Instant,
     layout_tree: Option<LayoutTree>,
+    /// Popup layers, laid out apart from `layout_tree` and drawn and
+    /// hit-tested above it.
+    layout_overlay: LayoutOverlay,
     semantics_tree: Option<SemanticsTree>,
     layout_dirty: bool,
     scene_dirty: bool,
@@ -60,6 +123,9 @@ where
     /// - On Move/Up/Cancel: resolve fresh HitTargets from current scene
     /// - Handler closures are preserved (same Rc), so internal state survives
     hit_path_tracker: HitPathTracker,
+    /// Nodes under the pointer after the last hover move, used to send
+    /// `Enter`/`Exit` events as the pointer crosses their bounds.
+    hovered: Vec<NodeId>,
     /// Persistent clipboard for desktop (Linux X11 requires clipboard to stay alive)
     #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
     clipboard: Option<arboard::Clipboard>,
@@ -92,7 +158,25 @@ where
 
         let runtime = StdRuntime::new();
         let mut composition = Composition::with_runtime(MemoryApplier::new(), runtime.runtime());
-        let build = content;
+        let window_insets =
+            MutableState::with_runtime(WindowInsets::default(), runtime.runtime_handle());
+        let keyboard = Rc::new(ShellKeyboardController::default());
+        let mut content = Some(content);
+        let back_dispatcher = BackDispatcher::new();
+        // A shell is created for a visible window.
+        let lifecycle = Lifecycle::new();
+        lifecycle.set_started(true);
+        // Platform objects that never change for the shell's lifetime.
+        composition.provide_root_locals([
+            LocalSoftwareKeyboardController().provides(Some(keyboard.clone() as _)),
+            LocalBackDispatcher().provides(Some(back_dispatcher.clone())),
+            LocalLifecycle().provides(Some(lifecycle.clone())),
+        ]);
+        let build = move || {
+            if let Some(content) = content.take() {
+                ProvideWindowInsets(window_insets, content);
+            }
+        };
         if let Err(err) = composition.render(root_key, build) {
             log::error!("initial render failed: {err}");
         }
@@ -103,15 +187,21 @@ where
             renderer,
             cursor: (0.0, 0.0),
             viewport: (800.0, 600.0),
+            window_insets,
+            keyboard,
+            back_dispatcher,
+            lifecycle,
             buffer_size: (800, 600),
             start_time: Instant::now(),
             layout_tree: None,
+            layout_overlay: LayoutOverlay::default(),
             semantics_tree: None,
             layout_dirty: true,
             scene_dirty: true,
             is_dirty: true,
             buttons_pressed: PointerButtons::NONE,
             hit_path_tracker: HitPathTracker::new(),
+            hovered: Vec::new(),
             #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
             clipboard: arboard::Clipboard::new().ok(),
             dev_options: DevOptions::default(),
@@ -140,6 +230,30 @@ where
         self.process_frame();
     }
 
+    /// Update the insets covered by system bars and the keyboard. Content
+    /// reading [`LocalWindowInsets`] recomposes on the next
+    /// [`update`](Self::update).
+    pub fn set_window_insets(&mut self, insets: WindowInsets) {
+        if self.window_insets.get_non_reactive() == insets {
+            return;
+        }
+        self.window_insets.set(insets);
+        self.mark_dirty();
+    }
+
+    pub fn window_insets(&self) -> WindowInsets {
+        self.window_insets.get_non_reactive()
+    }
+
+    /// Install the platform's on-screen keyboard. Content reaches it through
+    /// [`LocalSoftwareKeyboardController`], and focused text fields show it.
+    pub fn set_software_keyboard_controller(
+        &mut self,
+        controller: Rc<dyn SoftwareKeyboardController>,
+    ) {
+        *self.keyboard.platform.borrow_mut() = Some(controller);
+    }
+
     pub fn set_buffer_size(&mut self, width: u32, height: u32) {
         self.buffer_size = (width, height);
     }
@@ -234,6 +348,49 @@ where
             .as_nanos() as u64;
         self.runtime.drain_frame_callbacks(frame_time);
         self.runtime.runtime_handle().drain_ui();
+        self.recompose();
+        self.process_frame();
+        // Clear dirty flag after update (frame has been processed)
+        self.is_dirty = false;
+    }
+
+    /// Composes and measures the content for a `width`x`height` viewport
+    /// ahead of the first visible frame, e.g. while the platform surface is
+    /// still being set up.
+    ///
+    /// The first [`update`](Self::update) then reuses the measured tree and
+    /// only builds the scene. A viewport that changes in between is
+    /// remeasured like any resize.
+    pub fn prewarm(&mut self, width: f32, height: f32) {
+        self.viewport = (width, height);
+        let viewport_size = Size { width, height };
+        let handle = self.composition.runtime_handle();
+        let prewarmed = self.composition.prewarm(|applier, root| {
+            applier.set_runtime_handle(handle);
+            let measurements = cranpose_ui::measure_layout(applier, root, viewport_size);
+            applier.clear_runtime_handle();
+            measurements
+        });
+        match prewarmed {
+            Ok(Some(measurements)) => {
+                // The layout requests made while composing are answered by
+                // this measure.
+                take_layout_invalidation();
+                self.store_layout(measurements);
+                self.layout_dirty = false;
+            }
+            Ok(None) => self.layout_dirty = true,
+            Err(err) => {
+                log::error!("prewarm failed: {err}");
+                self.layout_dirty = true;
+            }
+        }
+        self.run_dispatch_queues();
+        self.mark_dirty();
+    }
+
+    /// Recomposes invalidated scopes, marking layout dirty when they changed.
+    fn recompose(&mut self) {
         if self.composition.should_render() {
             match self.composition.process_invalid_scopes() {
                 Ok(changed) => {
@@ -267,9 +424,6 @@ where
                 }
             }
         }
-        self.process_frame();
-        // Clear dirty flag after update (frame has been processed)
-        self.is_dirty = false;
     }
 
     pub fn set_cursor(&mut self, x: f32, y: f32) -> bool {
@@ -288,12 +442,7 @@ where
                         PointerEvent::new(PointerEventKind::Move, Point { x, y }, Point { x, y })
                             .with_buttons(self.buttons_pressed);
 
-                    for hit in targets {
-                        hit.dispatch(event.clone());
-                        if event.is_consumed() {
-                            break;
-                        }
-                    }
+                    self.dispatch_along_hit_path(targets, event);
                     self.mark_dirty();
                     return true;
                 }
@@ -308,12 +457,7 @@ where
                     let event =
                         PointerEvent::new(PointerEventKind::Move, Point { x, y }, Point { x, y })
                             .with_buttons(self.buttons_pressed);
-                    for hit in hits {
-                        hit.dispatch(event.clone());
-                        if event.is_consumed() {
-                            break;
-                        }
-                    }
+                    self.dispatch_along_hit_path(hits, event);
                     self.mark_dirty();
                     return true;
                 }
@@ -327,6 +471,7 @@ where
 
         // No gesture in progress: regular hover move using hit-test.
         let hits = self.renderer.scene().hit_test(x, y);
+        let hover_changed = self.update_hover(&hits, x, y);
         if !hits.is_empty() {
             let event = PointerEvent::new(PointerEventKind::Move, Point { x, y }, Point { x, y })
                 .with_buttons(self.buttons_pressed); // usually NONE here
@@ -339,20 +484,68 @@ where
             self.mark_dirty();
             true
         } else {
-            false
+            hover_changed
         }
     }
 
+    /// Sends `Exit` to the nodes the pointer left and `Enter` to the nodes it
+    /// just reached. Returns whether the hovered set changed.
+    fn update_hover(
+        &mut self,
+        hits: &[<<R as Renderer>::Scene as RenderScene>::HitTarget],
+        x: f32,
+        y: f32,
+    ) -> bool {
+        let position = Point { x, y };
+        let hovered: Vec<NodeId> = hits.iter().map(|hit| hit.node_id()).collect();
+        let scene = self.renderer.scene();
+        let mut changed = false;
+        for &left in self.hovered.iter().filter(|id| !hovered.contains(id)) {
+            if let Some(target) = scene.find_target(left) {
+                target.dispatch(
+                    PointerEvent::new(PointerEventKind::Exit, position, position)
+                        .with_buttons(self.buttons_pressed),
+                );
+            }
+            changed = true;
+        }
+        for hit in hits
+            .iter()
+            .filter(|hit| !self.hovered.contains(&hit.node_id()))
+        {
+            hit.dispatch(
+                PointerEvent::new(PointerEventKind::Enter, position, position)
+                    .with_buttons(self.buttons_pressed),
+            );
+            changed = true;
+        }
+        self.hovered = hovered;
+        if changed {
+            self.mark_dirty();
+        }
+        changed
+    }
+
     pub fn pointer_pressed(&mut self) -> bool {
+        self.pointer_button_pressed(PointerButton::Primary)
+    }
+
+    /// Press `button` at the cursor, e.g. the secondary button for a context
+    /// menu. [`pointer_pressed`](Self::pointer_pressed) presses the primary one.
+    pub fn pointer_button_pressed(&mut self, button: PointerButton) -> bool {
         enter_event_handler();
-        let result = self.pointer_pressed_inner();
+        let result = self.pointer_pressed_inner(button);
         exit_event_handler();
         result
     }
 
-    fn pointer_pressed_inner(&mut self) -> bool {
+    fn pointer_pressed_inner(&mut self, button: PointerButton) -> bool {
         // Track button state
-        self.buttons_pressed.insert(PointerButton::Primary);
+        self.buttons_pressed.insert(button);
+
+        // A press outside an open popup asks it to close; the press itself still
+        // reaches whatever lies under the pointer.
+        self.dismiss_popups_outside(self.cursor.0, self.cursor.1);
 
         // Hit-test against the current (last rendered) scene.
         // Even if the app is dirty, this scene is what the user actually saw and clicked.
@@ -380,15 +573,11 @@ where
                     y: self.cursor.1,
                 },
             )
-            .with_buttons(self.buttons_pressed);
+            .with_buttons(self.buttons_pressed)
+            .with_button(button);
 
             // Dispatch to fresh hits (geometry is already current for Down event)
-            for hit in hits {
-                hit.dispatch(event.clone());
-                if event.is_consumed() {
-                    break;
-                }
-            }
+            self.dispatch_along_hit_path(hits, event);
             self.mark_dirty();
             true
         } else {
@@ -396,26 +585,104 @@ where
         }
     }
 
+    /// Dispatches `event` to `targets` top to bottom until a handler consumes
+    /// it. A handler that locks the gesture keeps the hit path to its own
+    /// node; the other targets get a Cancel so they drop their half-started
+    /// gestures.
+    fn dispatch_along_hit_path(
+        &mut self,
+        targets: Vec<<<R as Renderer>::Scene as RenderScene>::HitTarget>,
+        event: PointerEvent,
+    ) {
+        let Some(owner) = targets.iter().position(|hit| {
+            hit.dispatch(event.clone());
+            event.is_gesture_locked() || event.is_consumed()
+        }) else {
+            return;
+        };
+        if !event.is_gesture_locked() || targets.len() == 1 {
+            return;
+        }
+
+        self.hit_path_tracker
+            .add_hit_path(PointerId::PRIMARY, vec![targets[owner].node_id()]);
+        let cancel = PointerEvent::new(
+            PointerEventKind::Cancel,
+            event.global_position,
+            event.global_position,
+        )
+        .with_buttons(event.buttons);
+        for (index, hit) in targets.iter().enumerate() {
+            if index != owner {
+                hit.dispatch(cancel.clone());
+            }
+        }
+    }
+
+    /// Requests dismissal of the popup layers stacked above the top-most popup
+    /// containing `(x, y)`, or of every popup when the point hits none of them.
+    fn dismiss_popups_outside(&mut self, x: f32, y: f32) {
+        let mut outside = Vec::new();
+        for layer in self.layout_overlay.layers().iter().rev() {
+            if layer.rect.contains(x, y) {
+                break;
+            }
+            outside.push(layer.node_id);
+        }
+        if outside.is_empty() {
+            return;
+        }
+
+        let handlers: Vec<_> = {
+            let mut applier = self.composition.applier_mut();
+            outside
+                .into_iter()
+                .filter_map(|popup| {
+                    applier
+                        .with_node::<LayoutNode, _>(popup, |node| {
+                            node.popup_layer()
+                                .map(|layer| Rc::clone(&layer.on_dismiss_request))
+                        })
+                        .ok()
+                        .flatten()
+                })
+                .collect()
+        };
+        for handler in handlers {
+            (handler.borrow_mut())();
+        }
+        self.mark_dirty();
+    }
+
     pub fn pointer_released(&mut self) -> bool {
+        self.pointer_button_released(PointerButton::Primary)
+    }
+
+    /// Release `button`, which [`pointer_button_pressed`](Self::pointer_button_pressed)
+    /// pressed.
+    pub fn pointer_button_released(&mut self, button: PointerButton) -> bool {
         enter_event_handler();
-        let result = self.pointer_released_inner();
+        let result = self.pointer_released_inner(button);
         exit_event_handler();
         result
     }
 
-    fn pointer_released_inner(&mut self) -> bool {
+    fn pointer_released_inner(&mut self, button: PointerButton) -> bool {
         // UP events report buttons as "currently pressed" (after release),
-        // matching typical platform semantics where primary is already gone.
-        self.buttons_pressed.remove(PointerButton::Primary);
+        // matching typical platform semantics where the released one is already gone.
+        self.buttons_pressed.remove(button);
         let corrected_buttons = self.buttons_pressed;
 
         // Resolve FRESH targets from cached NodeIds
         let targets = self.resolve_hit_path(PointerId::PRIMARY);
 
-        // Always remove the path, even if targets is empty (node may have been removed)
-        self.hit_path_tracker.remove_path(PointerId::PRIMARY);
+        // Remove the path once no button holds the pointer, even if targets is
+        // empty (node may have been removed)
+        if corrected_buttons == PointerButtons::NONE {
+            self.hit_path_tracker.remove_path(PointerId::PRIMARY);
+        }
 
-        if !targets.is_empty() {
+        let handled = if !targets.is_empty() {
             let event = PointerEvent::new(
                 PointerEventKind::Up,
                 Point {
@@ -427,7 +694,8 @@ where
                     y: self.cursor.1,
                 },
             )
-            .with_buttons(corrected_buttons);
+            .with_buttons(corrected_buttons)
+            .with_button(button);
 
             for hit in targets {
                 hit.dispatch(event.clone());
@@ -439,7 +707,14 @@ where
             true
         } else {
             false
-        }
+        };
+
+        // The gesture no longer captures the pointer: a drag released outside
+        // the pressed node exits it now rather than on the next move.
+        let (x, y) = self.cursor;
+        let hits = self.renderer.scene().hit_test(x, y);
+        self.update_hover(&hits, x, y);
+        handled
     }
 
     /// Cancels any active gesture, dispatching Cancel events to cached targets.
@@ -557,6 +832,38 @@ where
         handled
     }
 
+    /// Routes the platform back button or gesture to the most recently
+    /// registered enabled [`BackHandler`](cranpose_ui::BackHandler).
+    ///
+    /// Returns false when no handler is enabled, so the platform can apply
+    /// its default, e.g. closing the app.
+    pub fn dispatch_back(&mut self) -> bool {
+        enter_event_handler();
+        let consumed = run_in_mutable_snapshot(|| self.back_dispatcher.dispatch()).unwrap_or(false);
+        exit_event_handler();
+        if consumed {
+            self.mark_dirty();
+        }
+        consumed
+    }
+
+    /// Report whether the app is in the foreground. Content observes it
+    /// through [`LifecycleEffect`](cranpose_ui::LifecycleEffect); repeating
+    /// the current state does nothing.
+    pub fn set_lifecycle_started(&mut self, started: bool) {
+        if self.lifecycle.is_started() == started {
+            return;
+        }
+        enter_event_handler();
+        let _ = run_in_mutable_snapshot(|| self.lifecycle.set_started(started));
+        exit_event_handler();
+        self.mark_dirty();
+    }
+
+    pub fn is_lifecycle_started(&self) -> bool {
+        self.lifecycle.is_started()
+    }
+
     /// Handles paste event from platform clipboard.
     /// Returns `true` if the paste was consumed by a focused text field.
     /// O(1) operation using stored handler.
@@ -708,11 +1015,148 @@ where
         self.layout_tree.as_ref()
     }
 
+    /// Popup layers drawn above the layout tree (for robot/testing)
+    pub fn layout_overlay(&self) -> &LayoutOverlay {
+        &self.layout_overlay
+    }
+
+    /// Formats the current layout tree with per-node geometry (for debugging/testing)
+    pub fn dump_layout_tree_with_geometry(&self) -> Option<String> {
+        self.layout_tree
+            .as_ref()
+            .map(LayoutTree::dump_layout_tree_with_geometry)
+    }
+
     /// Get the current semantics tree (for robot/testing)
     pub fn semantics_tree(&self) -> Option<&SemanticsTree> {
         self.semantics_tree.as_ref()
     }
 
+    /// Snapshot of the semantics tree with window-space bounds, for bridging
+    /// to platform accessibility APIs. Nodes outside the viewport are left out.
+    pub fn accessibility_tree(&self) -> Option<AccessibilityNode> {
+        let semantics = self.semantics_tree.as_ref()?;
+        let layout = self.layout_tree.as_ref()?;
+        let viewport = cranpose_ui::Rect {
+            x: 0.0,
+            y: 0.0,
+            width: self.viewport.0,
+            height: self.viewport.1,
+        };
+        accessibility::build_accessibility_tree(
+            semantics.root(),
+            layout,
+            &self.layout_overlay,
+            viewport,
+        )
+    }
+
+    /// Clicks `node` with a press and release at its center, as a user
+    /// would. Returns false if the node exposes no click action.
+    pub fn click_node(&mut self, node: NodeId) -> bool {
+        let Some(center) = self.node_center(node, |action| {
+            matches!(action, SemanticsAction::Click { .. })
+        }) else {
+            return false;
+        };
+        self.set_cursor(center.x, center.y);
+        self.pointer_pressed();
+        self.pointer_released();
+        true
+    }
+
+    /// Scrolls `node` by `delta` pixels along its scroll axis with a drag, as
+    /// a user would: press at its center, move against the axis and cancel,
+    /// so no fling follows. Returns the change of its scroll position, or
+    /// `None` if the node is not scrollable.
+    pub fn scroll_node_by(&mut self, node: NodeId, delta: f32) -> Option<f32> {
+        let before = self.scroll_axis_range(node)?;
+        let center = self.node_center(node, |action| {
+            matches!(action, SemanticsAction::ScrollBy { .. })
+        })?;
+        if delta != 0.0 {
+            // Movement inside the touch slop never scrolls, so first move to
+            // its edge and then drag the whole distance.
+            let slop = -delta.signum() * DRAG_THRESHOLD;
+            let along_axis = |offset: f32| {
+                if before.is_vertical {
+                    (center.x, center.y + offset)
+                } else {
+                    (center.x + offset, center.y)
+                }
+            };
+            self.set_cursor(center.x, center.y);
+            self.pointer_pressed();
+            for offset in [slop, slop - delta] {
+                let (x, y) = along_axis(offset);
+                self.set_cursor(x, y);
+            }
+            self.cancel_gesture();
+            self.update();
+        }
+        let after = self.scroll_axis_range(node)?;
+        Some(after.value - before.value)
+    }
+
+    /// Moves focus to `node` through its focus request action. Returns false
+    /// if the node is not an enabled focusable node.
+    pub fn focus_node(&mut self, node: NodeId) -> bool {
+        let Some(handler) = self.semantics_tree.as_ref().and_then(|tree| {
+            tree.node(node)?
+                .actions
+                .iter()
+                .find_map(|action| match action {
+                    SemanticsAction::RequestFocus { handler } => Some(handler.clone()),
+                    _ => None,
+                })
+        }) else {
+            return false;
+        };
+        enter_event_handler();
+        let focused = {
+            let mut applier = self.composition.applier_mut();
+            run_in_mutable_snapshot(|| {
+                cranpose_ui::request_focus(handler.node_id(), |id, state| {
+                    applier
+                        .with_node::<LayoutNode, _>(id, |node| node.set_focus_state(state))
+                        .unwrap_or(false)
+                })
+            })
+            .unwrap_or(false)
+        };
+        exit_event_handler();
+        if focused {
+            self.mark_dirty();
+        }
+        focused
+    }
+
+    fn scroll_axis_range(&self, node: NodeId) -> Option<ScrollAxisRange> {
+        self.semantics_tree.as_ref()?.node(node)?.scroll_axis_range
+    }
+
+    /// Center of `node` in window coordinates, if it exposes an action
+    /// matching `action`.
+    fn node_center(
+        &self,
+        node: NodeId,
+        action: impl Fn(&SemanticsAction) -> bool,
+    ) -> Option<Point> {
+        let semantics = self.semantics_tree.as_ref()?.node(node)?;
+        if !semantics.actions.iter().any(action) {
+            return None;
+        }
+        let rect = self
+            .layout_tree
+            .as_ref()?
+            .node_rect(node)
+            .or_else(|| self.layout_overlay.node_rect(node))?;
+        Some(Point {
+            x: rect.x + rect.width / 2.0,
+            y: rect.y + rect.height / 2.0,
+        })
+    }
+
     fn process_frame(&mut self) {
         // Record frame for FPS tracking
         fps_monitor::record_frame();
@@ -865,28 +1309,37 @@ where
             self.layout_dirty = false;
 
             // Ensure slots exist and borrow mutably (handled inside measure_layout via MemoryApplier)
-            match cranpose_ui::measure_layout(&mut applier, root, viewport_size) {
-                Ok(measurements) => {
-                    self.semantics_tree = Some(measurements.semantics_tree().clone());
-                    self.layout_tree = Some(measurements.into_layout_tree());
-                    self.scene_dirty = true;
-                }
-                Err(err) => {
-                    log::error!("failed to compute layout: {err}");
-                    self.layout_tree = None;
-                    self.semantics_tree = None;
-                    self.scene_dirty = true;
-                }
-            }
+            let measurements = cranpose_ui::measure_layout(&mut applier, root, viewport_size);
             applier.clear_runtime_handle();
+            drop(applier);
+            self.store_layout(measurements);
         } else {
             self.layout_tree = None;
+            self.layout_overlay = LayoutOverlay::default();
             self.semantics_tree = None;
             self.scene_dirty = true;
             self.layout_dirty = false;
         }
     }
 
+    fn store_layout(&mut self, measurements: Result<LayoutMeasurements, NodeError>) {
+        match measurements {
+            Ok(measurements) => {
+                self.semantics_tree = Some(measurements.semantics_tree().clone());
+                let (layout_tree, overlay) = measurements.into_layout_tree_and_overlay();
+                self.layout_tree = Some(layout_tree);
+                self.layout_overlay = overlay;
+            }
+            Err(err) => {
+                log::error!("failed to compute layout: {err}");
+                self.layout_tree = None;
+                self.layout_overlay = LayoutOverlay::default();
+                self.semantics_tree = None;
+            }
+        }
+        self.scene_dirty = true;
+    }
+
     fn run_dispatch_queues(&mut self) {
         // Process pointer input repasses
         // Similar to Jetpack Compose's pointer input invalidation processing,
@@ -947,7 +1400,14 @@ where
 
         let dirty_set: HashSet<NodeId> = dirty_nodes.into_iter().collect();
         let mut applier = self.composition.applier_mut();
-        refresh_layout_box_data(&mut applier, layout_tree.root_mut(), &dirty_set);
+        if let Some(root) = refreshed_layout_box(&mut applier, layout_tree.root(), &dirty_set) {
+            *layout_tree.root_mut() = root;
+        }
+        for layer in self.layout_overlay.layers_mut() {
+            if let Some(refreshed) = refreshed_layout_box(&mut applier, layer, &dirty_set) {
+                *layer = refreshed;
+            }
+        }
     }
 
     fn run_render_phase(&mut self) {
@@ -969,7 +1429,10 @@ where
             height: self.viewport.1,
         };
         if let Some(layout_tree) = self.layout_tree.as_ref() {
-            if let Err(err) = self.renderer.rebuild_scene(layout_tree, viewport_size) {
+            if let Err(err) =
+                self.renderer
+                    .rebuild_scene(layout_tree, &self.layout_overlay, viewport_size)
+            {
                 log::error!("renderer rebuild failed: {err:?}");
             }
         } else {
@@ -988,39 +1451,50 @@ where
     }
 }
 
-fn refresh_layout_box_data(
+/// Returns a copy of `layout` with the node data of `dirty_nodes` in its
+/// subtree refreshed, or `None` when the subtree has no dirty node. Children
+/// are shared with the placement cache, so only the path to a dirty node is
+/// copied.
+fn refreshed_layout_box(
     applier: &mut MemoryApplier,
-    layout: &mut cranpose_ui::layout::LayoutBox,
+    layout: &cranpose_ui::layout::LayoutBox,
     dirty_nodes: &HashSet<NodeId>,
-) {
-    if dirty_nodes.contains(&layout.node_id) {
-        if let Ok((modifier, resolved_modifiers, slices)) =
-            applier.with_node::<LayoutNode, _>(layout.node_id, |node| {
-                node.clear_needs_redraw();
-                (
-                    node.modifier.clone(),
-                    node.resolved_modifiers(),
-                    node.modifier_slices_snapshot(),
-                )
-            })
-        {
-            layout.node_data.modifier = modifier;
-            layout.node_data.resolved_modifiers = resolved_modifiers;
-            layout.node_data.modifier_slices = slices;
-        } else if let Ok((modifier, resolved_modifiers)) = applier
-            .with_node::<SubcomposeLayoutNode, _>(layout.node_id, |node| {
-                node.clear_needs_redraw();
-                (node.modifier(), node.resolved_modifiers())
-            })
-        {
-            layout.node_data.modifier = modifier.clone();
-            layout.node_data.resolved_modifiers = resolved_modifiers;
-            layout.node_data.modifier_slices = cranpose_ui::collect_slices_from_modifier(&modifier);
+) -> Option<cranpose_ui::layout::LayoutBox> {
+    let mut refreshed: Option<cranpose_ui::layout::LayoutBox> = None;
+    for (index, child) in layout.children.iter().enumerate() {
+        if let Some(child) = refreshed_layout_box(applier, child, dirty_nodes) {
+            refreshed.get_or_insert_with(|| layout.clone()).children[index] = Rc::new(child);
         }
     }
+    if dirty_nodes.contains(&layout.node_id) {
+        refresh_node_data(applier, refreshed.get_or_insert_with(|| layout.clone()));
+    }
+    refreshed
+}
 
-    for child in &mut layout.children {
-        refresh_layout_box_data(applier, child, dirty_nodes);
+fn refresh_node_data(applier: &mut MemoryApplier, layout: &mut cranpose_ui::layout::LayoutBox) {
+    if let Ok((modifier, resolved_modifiers, slices)) =
+        applier.with_node::<LayoutNode, _>(layout.node_id, |node| {
+            node.clear_needs_redraw();
+            (
+                node.modifier.clone(),
+                node.resolved_modifiers(),
+                node.modifier_slices_snapshot(),
+            )
+        })
+    {
+        layout.node_data.modifier = modifier;
+        layout.node_data.resolved_modifiers = resolved_modifiers;
+        layout.node_data.modifier_slices = slices;
+    } else if let Ok((modifier, resolved_modifiers)) =
+        applier.with_node::<SubcomposeLayoutNode, _>(layout.node_id, |node| {
+            node.clear_needs_redraw();
+            (node.modifier(), node.resolved_modifiers())
+        })
+    {
+        layout.node_data.modifier = modifier.clone();
+        layout.node_data.resolved_modifiers = resolved_modifiers;
+        layout.node_data.modifier_slices = cranpose_ui::collect_slices_from_modifier(&modifier);
     }
 }
 
@@ -2,10 +2,16 @@ use super::*;
 use cranpose_core::{
     __launched_effect_async_impl as launched_effect_async_impl, location_key, useState,
 };
+use cranpose_foundation::lazy::LazyListScope;
+use cranpose_foundation::{DOUBLE_CLICK_TIMEOUT_MILLIS, LONG_CLICK_TIMEOUT_MILLIS};
 use cranpose_macros::composable;
 use cranpose_ui::{
-    Box, BoxSpec, Brush, Color, Column, ColumnSpec, HeadlessRenderer, Modifier, Rect, RenderOp,
-    Row, RowSpec, Size, Text,
+    rememberNavController, rememberSaveable, rememberSaveableWith, BackHandler, Box, BoxSpec,
+    BringIntoViewRequester, Brush, Button, Color, Column, ColumnSpec, Dialog, EdgeInsets,
+    HeadlessRenderer, Layout, LazyColumn, LazyColumnSpec, LazyListState, LazyListStateSaver,
+    LifecycleEffect, LocalSoftwareKeyboardController, Modifier, NavController, NavHost, Point,
+    Popup, Rect, RenderOp, Row, RowSpec, ScrollState, ScrollStateSaver, SemanticsRole, Size,
+    SoftwareKeyboardController, Text, Tooltip, WindowInsets, TOOLTIP_DWELL_MILLIS,
 };
 use cranpose_ui_graphics::DrawPrimitive;
 use std::cell::{Cell, RefCell};
@@ -59,6 +65,7 @@ impl Renderer for TestRenderer {
     fn rebuild_scene(
         &mut self,
         _layout_tree: &LayoutTree,
+        _overlay: &LayoutOverlay,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         Ok(())
@@ -86,6 +93,7 @@ impl Renderer for RecordingRenderer {
     fn rebuild_scene(
         &mut self,
         layout_tree: &LayoutTree,
+        _overlay: &LayoutOverlay,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         let renderer = HeadlessRenderer::new();
@@ -94,6 +102,103 @@ impl Renderer for RecordingRenderer {
     }
 }
 
+#[derive(Clone)]
+struct BoxHitTarget {
+    node_id: cranpose_core::NodeId,
+    rect: Rect,
+    pointer_inputs: Vec<Rc<dyn Fn(PointerEvent)>>,
+}
+
+impl HitTestTarget for BoxHitTarget {
+    fn dispatch(&self, event: PointerEvent) {
+        run_in_mutable_snapshot(|| {
+            for handler in &self.pointer_inputs {
+                if event.is_consumed() {
+                    break;
+                }
+                handler(event.clone());
+            }
+        })
+        .expect("pointer snapshot");
+    }
+
+    fn node_id(&self) -> cranpose_core::NodeId {
+        self.node_id
+    }
+}
+
+/// Hit-tests and dispatches to layout boxes in paint order, like the real backends do.
+#[derive(Default)]
+struct BoxHitScene {
+    boxes: Vec<BoxHitTarget>,
+}
+
+impl RenderScene for BoxHitScene {
+    type HitTarget = BoxHitTarget;
+
+    fn clear(&mut self) {
+        self.boxes.clear();
+    }
+
+    fn hit_test(&self, x: f32, y: f32) -> Vec<Self::HitTarget> {
+        self.boxes
+            .iter()
+            .rev()
+            .filter(|target| target.rect.contains(x, y))
+            .cloned()
+            .collect()
+    }
+
+    fn find_target(&self, node_id: cranpose_core::NodeId) -> Option<Self::HitTarget> {
+        self.boxes
+            .iter()
+            .find(|target| target.node_id == node_id)
+            .cloned()
+    }
+}
+
+#[derive(Default)]
+struct BoxHitRenderer {
+    scene: BoxHitScene,
+}
+
+impl Renderer for BoxHitRenderer {
+    type Scene = BoxHitScene;
+    type Error = ();
+
+    fn scene(&self) -> &Self::Scene {
+        &self.scene
+    }
+
+    fn scene_mut(&mut self) -> &mut Self::Scene {
+        &mut self.scene
+    }
+
+    fn rebuild_scene(
+        &mut self,
+        layout_tree: &LayoutTree,
+        overlay: &LayoutOverlay,
+        _viewport: Size,
+    ) -> Result<(), Self::Error> {
+        fn collect(layout: &cranpose_ui::LayoutBox, boxes: &mut Vec<BoxHitTarget>) {
+            boxes.push(BoxHitTarget {
+                node_id: layout.node_id,
+                rect: layout.rect,
+                pointer_inputs: layout.node_data.modifier_slices.pointer_inputs().to_vec(),
+            });
+            for child in &layout.children {
+                collect(child, boxes);
+            }
+        }
+        self.scene.clear();
+        collect(layout_tree.root(), &mut self.scene.boxes);
+        for layer in overlay.layers() {
+            collect(layer, &mut self.scene.boxes);
+        }
+        Ok(())
+    }
+}
+
 #[composable]
 fn tabbed_progress_content() {
     let progress = useState(|| 0.6f32);
@@ -322,3 +427,1079 @@ fn find_rect_width(scene: &cranpose_ui::RecordedRenderScene, color: Color) -> Op
     }
     None
 }
+
+#[test]
+fn popup_is_drawn_above_and_outside_its_anchor() {
+    let root_key = location_key(file!(), line!(), column!());
+    let dismissals = Rc::new(Cell::new(0));
+    let popup_content = Rc::new(Cell::new(None));
+    let mut shell = AppShell::new(BoxHitRenderer::default(), root_key, {
+        let dismissals = Rc::clone(&dismissals);
+        let popup_content = Rc::clone(&popup_content);
+        move || {
+            let dismissals = Rc::clone(&dismissals);
+            let popup_content = Rc::clone(&popup_content);
+            Box(
+                Modifier::empty().size_points(100.0, 40.0),
+                BoxSpec::default(),
+                move || {
+                    let dismissals = Rc::clone(&dismissals);
+                    let popup_content = Rc::clone(&popup_content);
+                    Popup(
+                        Point { x: 10.0, y: 60.0 },
+                        move || dismissals.set(dismissals.get() + 1),
+                        move || {
+                            popup_content.set(Some(Box(
+                                Modifier::empty().size_points(80.0, 30.0),
+                                BoxSpec::default(),
+                                || {},
+                            )));
+                        },
+                    );
+                },
+            );
+        }
+    });
+    shell.update();
+
+    let tree = shell.layout_tree().expect("layout tree");
+    // The anchor box is the root; the popup takes no space inside it.
+    let anchor = tree.root();
+    assert_eq!((anchor.rect.width, anchor.rect.height), (100.0, 40.0));
+    let overlay = shell.layout_overlay();
+    assert_eq!(overlay.layers().len(), 1);
+    let popup = &overlay.layers()[0];
+    // Popups are laid out in the overlay, not as children of the main tree.
+    assert!(anchor
+        .children
+        .iter()
+        .all(|child| child.node_id != popup.node_id));
+    assert_eq!(
+        (
+            popup.rect.x,
+            popup.rect.y,
+            popup.rect.width,
+            popup.rect.height
+        ),
+        (10.0, 60.0, 80.0, 30.0)
+    );
+
+    let content = popup_content.get().expect("popup content composed");
+    assert_eq!(shell.scene().hit_test_nodes(20.0, 70.0)[0], content);
+
+    shell.set_cursor(20.0, 70.0);
+    shell.pointer_pressed();
+    shell.pointer_released();
+    assert_eq!(dismissals.get(), 0);
+
+    shell.set_cursor(50.0, 20.0);
+    shell.pointer_pressed();
+    shell.pointer_released();
+    assert_eq!(dismissals.get(), 1);
+}
+
+#[test]
+fn dialog_scrim_dismisses_without_reaching_the_tree_below() {
+    let root_key = location_key(file!(), line!(), column!());
+    let clicks = Rc::new(Cell::new(0));
+    let dismissals = Rc::new(Cell::new(0));
+    let mut shell = AppShell::new(BoxHitRenderer::default(), root_key, {
+        let clicks = Rc::clone(&clicks);
+        let dismissals = Rc::clone(&dismissals);
+        move || {
+            let open = useState(|| true);
+            let clicks = Rc::clone(&clicks);
+            let dismissals = Rc::clone(&dismissals);
+            Column(
+                Modifier::empty().fill_max_size(),
+                ColumnSpec::default(),
+                move || {
+                    let clicks = Rc::clone(&clicks);
+                    Box(
+                        Modifier::empty()
+                            .size_points(100.0, 40.0)
+                            .clickable(move |_| clicks.set(clicks.get() + 1)),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    if open.value() {
+                        let dismissals = Rc::clone(&dismissals);
+                        Dialog(
+                            move || {
+                                dismissals.set(dismissals.get() + 1);
+                                open.set(false);
+                            },
+                            || {
+                                Box(
+                                    Modifier::empty().size_points(200.0, 100.0),
+                                    BoxSpec::default(),
+                                    || {},
+                                );
+                            },
+                        );
+                    }
+                },
+            );
+        }
+    });
+    shell.update();
+    let tap = |shell: &mut AppShell<BoxHitRenderer>, x: f32, y: f32| {
+        shell.set_cursor(x, y);
+        shell.pointer_pressed();
+        shell.pointer_released();
+        shell.update();
+    };
+
+    // The content is centered in the 800x600 viewport; tapping it keeps the dialog open.
+    tap(&mut shell, 400.0, 300.0);
+    assert_eq!((dismissals.get(), clicks.get()), (0, 0));
+
+    // Tapping the scrim over the button dismisses the dialog instead of clicking.
+    tap(&mut shell, 50.0, 20.0);
+    assert_eq!((dismissals.get(), clicks.get()), (1, 0));
+
+    tap(&mut shell, 50.0, 20.0);
+    assert_eq!((dismissals.get(), clicks.get()), (1, 1));
+}
+
+#[test]
+fn tooltip_appears_after_hover_dwell_and_hides_on_exit() {
+    const SECOND: u64 = 1_000_000_000;
+    let dwell = TOOLTIP_DWELL_MILLIS * 1_000_000;
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(BoxHitRenderer::default(), root_key, || {
+        Column(
+            Modifier::empty().fill_max_size(),
+            ColumnSpec::default(),
+            || {
+                Tooltip("Save", || {
+                    Box(
+                        Modifier::empty().size_points(100.0, 40.0),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                });
+            },
+        );
+    });
+    shell.update();
+    let popup_count = |shell: &AppShell<BoxHitRenderer>| shell.layout_overlay().layers().len();
+
+    shell.set_cursor(50.0, 20.0);
+    shell.runtime.drain_frame_callbacks(SECOND);
+    shell.update();
+    assert_eq!(popup_count(&shell), 0);
+
+    shell.runtime.drain_frame_callbacks(SECOND + dwell);
+    shell.update();
+    assert_eq!(popup_count(&shell), 1);
+
+    shell.set_cursor(300.0, 300.0);
+    shell.update();
+    assert_eq!(popup_count(&shell), 0);
+
+    // Leaving before the delay elapses cancels the pending tooltip.
+    shell.set_cursor(50.0, 20.0);
+    shell.runtime.drain_frame_callbacks(3 * SECOND);
+    shell.set_cursor(300.0, 300.0);
+    shell.runtime.drain_frame_callbacks(10 * SECOND);
+    shell.update();
+    assert_eq!(popup_count(&shell), 0);
+}
+
+/// A 100x40 box at the top left of the window with `modifier`.
+fn click_target(modifier: Modifier) -> AppShell<BoxHitRenderer> {
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(BoxHitRenderer::default(), root_key, move || {
+        let modifier = modifier.clone();
+        Column(
+            Modifier::empty().fill_max_size(),
+            ColumnSpec::default(),
+            move || {
+                Box(
+                    Modifier::empty()
+                        .size_points(100.0, 40.0)
+                        .then(modifier.clone()),
+                    BoxSpec::default(),
+                    || {},
+                );
+            },
+        );
+    });
+    shell.update();
+    shell.set_cursor(50.0, 20.0);
+    shell
+}
+
+#[test]
+fn right_click_reaches_on_secondary_click_but_not_clickable() {
+    let clicks: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+    let (primary, secondary) = (Rc::clone(&clicks), Rc::clone(&clicks));
+    let mut shell = click_target(
+        Modifier::empty()
+            .clickable(move |_| primary.borrow_mut().push("primary"))
+            .on_secondary_click(move |_| secondary.borrow_mut().push("secondary")),
+    );
+
+    shell.pointer_button_pressed(PointerButton::Secondary);
+    shell.pointer_button_released(PointerButton::Secondary);
+    assert_eq!(*clicks.borrow(), ["secondary"]);
+
+    shell.pointer_pressed();
+    shell.pointer_released();
+    assert_eq!(*clicks.borrow(), ["secondary", "primary"]);
+}
+
+#[test]
+fn chorded_presses_report_every_held_button() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let recorded = Rc::clone(&events);
+    let mut shell = click_target(Modifier::empty().pointer_input((), move |scope| {
+        let events = Rc::clone(&recorded);
+        async move {
+            scope
+                .await_pointer_event_scope(|scope| async move {
+                    loop {
+                        let event = scope.await_pointer_event().await;
+                        if matches!(event.kind, PointerEventKind::Down | PointerEventKind::Up) {
+                            events.borrow_mut().push((
+                                event.kind,
+                                event.changed_button(),
+                                event.buttons.is_primary_pressed(),
+                                event.buttons.is_secondary_pressed(),
+                            ));
+                        }
+                    }
+                })
+                .await;
+        }
+    }));
+
+    shell.pointer_button_pressed(PointerButton::Secondary);
+    shell.pointer_pressed();
+    shell.pointer_button_released(PointerButton::Secondary);
+    shell.pointer_released();
+
+    use PointerButton::{Primary, Secondary};
+    use PointerEventKind::{Down, Up};
+    assert_eq!(
+        *events.borrow(),
+        [
+            (Down, Secondary, false, true),
+            (Down, Primary, true, true),
+            (Up, Secondary, true, false),
+            (Up, Primary, false, false),
+        ]
+    );
+}
+
+#[test]
+fn secondary_press_during_a_click_does_not_cancel_it() {
+    let clicks: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+    let (primary, secondary) = (Rc::clone(&clicks), Rc::clone(&clicks));
+    let mut shell = click_target(
+        Modifier::empty()
+            .clickable(move |_| primary.borrow_mut().push("primary"))
+            .on_secondary_click(move |_| secondary.borrow_mut().push("secondary")),
+    );
+
+    shell.pointer_pressed();
+    shell.pointer_button_pressed(PointerButton::Secondary);
+    shell.pointer_button_released(PointerButton::Secondary);
+    assert_eq!(*clicks.borrow(), ["secondary"]);
+    shell.pointer_released();
+    assert_eq!(*clicks.borrow(), ["secondary", "primary"]);
+}
+
+#[test]
+fn combined_clickable_tells_a_double_click_from_two_single_clicks() {
+    const SECOND: u64 = 1_000_000_000;
+    let timeout = DOUBLE_CLICK_TIMEOUT_MILLIS * 1_000_000;
+    let clicks: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+    let (single, double) = (Rc::clone(&clicks), Rc::clone(&clicks));
+    let mut shell = click_target(Modifier::empty().combined_clickable(
+        move |_| single.borrow_mut().push("click"),
+        None::<fn(Point)>,
+        Some(move |_| double.borrow_mut().push("double")),
+    ));
+    let click = |shell: &mut AppShell<BoxHitRenderer>| {
+        shell.pointer_pressed();
+        shell.pointer_released();
+    };
+
+    click(&mut shell);
+    shell.runtime.drain_frame_callbacks(SECOND);
+    click(&mut shell);
+    assert_eq!(*clicks.borrow(), ["double"]);
+
+    // A click waits for a second one before it counts as a single click.
+    click(&mut shell);
+    shell.runtime.drain_frame_callbacks(2 * SECOND);
+    assert_eq!(*clicks.borrow(), ["double"]);
+    shell.runtime.drain_frame_callbacks(2 * SECOND + timeout);
+    assert_eq!(*clicks.borrow(), ["double", "click"]);
+
+    // The next click after the timeout starts over.
+    click(&mut shell);
+    shell.runtime.drain_frame_callbacks(3 * SECOND);
+    shell.runtime.drain_frame_callbacks(3 * SECOND + timeout);
+    assert_eq!(*clicks.borrow(), ["double", "click", "click"]);
+}
+
+#[test]
+fn combined_clickable_long_click_replaces_the_click() {
+    const SECOND: u64 = 1_000_000_000;
+    let timeout = LONG_CLICK_TIMEOUT_MILLIS * 1_000_000;
+    let clicks: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+    let (single, long) = (Rc::clone(&clicks), Rc::clone(&clicks));
+    let mut shell = click_target(Modifier::empty().combined_clickable(
+        move |_| single.borrow_mut().push("click"),
+        Some(move |_| long.borrow_mut().push("long")),
+        None::<fn(Point)>,
+    ));
+
+    shell.pointer_pressed();
+    shell.runtime.drain_frame_callbacks(SECOND);
+    shell.runtime.drain_frame_callbacks(SECOND + timeout);
+    assert_eq!(*clicks.borrow(), ["long"]);
+    shell.pointer_released();
+    assert_eq!(*clicks.borrow(), ["long"]);
+
+    // Released before the timeout: a plain click.
+    shell.pointer_pressed();
+    shell.runtime.drain_frame_callbacks(2 * SECOND);
+    shell.pointer_released();
+    shell.runtime.drain_frame_callbacks(2 * SECOND + timeout);
+    assert_eq!(*clicks.borrow(), ["long", "click"]);
+}
+
+#[test]
+fn drag_keeps_reaching_the_pressed_node_outside_its_bounds() {
+    let root_key = location_key(file!(), line!(), column!());
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut shell = AppShell::new(BoxHitRenderer::default(), root_key, {
+        let events = Rc::clone(&events);
+        move || {
+            let events = Rc::clone(&events);
+            Column(
+                Modifier::empty().fill_max_size(),
+                ColumnSpec::default(),
+                move || {
+                    let events = Rc::clone(&events);
+                    Box(
+                        Modifier::empty().size_points(100.0, 40.0).pointer_input(
+                            (),
+                            move |scope| {
+                                let events = Rc::clone(&events);
+                                async move {
+                                    scope
+                                        .await_pointer_event_scope(|scope| async move {
+                                            loop {
+                                                let event = scope.await_pointer_event().await;
+                                                events
+                                                    .borrow_mut()
+                                                    .push((event.kind, event.position));
+                                            }
+                                        })
+                                        .await;
+                                }
+                            },
+                        ),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                },
+            );
+        }
+    });
+    shell.update();
+
+    shell.set_cursor(50.0, 20.0);
+    events.borrow_mut().clear();
+
+    // Moves far outside the 100x40 box keep reaching it while pressed.
+    shell.pointer_pressed();
+    shell.set_cursor(300.0, 300.0);
+    shell.update();
+    shell.set_cursor(400.0, 310.0);
+    shell.pointer_released();
+    shell.update();
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            (PointerEventKind::Down, Point { x: 50.0, y: 20.0 }),
+            (PointerEventKind::Move, Point { x: 300.0, y: 300.0 }),
+            (PointerEventKind::Move, Point { x: 400.0, y: 310.0 }),
+            (PointerEventKind::Up, Point { x: 400.0, y: 310.0 }),
+            (PointerEventKind::Exit, Point { x: 400.0, y: 310.0 }),
+        ]
+    );
+
+    // Release ends the capture: later moves outside no longer reach it.
+    shell.set_cursor(410.0, 320.0);
+    assert_eq!(events.borrow().len(), 5);
+}
+
+#[test]
+fn accessibility_snapshot_serialises_to_json() {
+    let label = AccessibilityNode {
+        node_id: 5,
+        role: SemanticsRole::Text {
+            value: "Say \"hi\"".into(),
+        },
+        bounds: Rect {
+            x: 8.0,
+            y: 10.0,
+            width: 40.5,
+            height: 20.0,
+        },
+        description: None,
+        state_description: None,
+        actions: Vec::new(),
+        is_enabled: true,
+        is_focusable: false,
+        children: Vec::new(),
+    };
+    let button = AccessibilityNode {
+        node_id: 4,
+        role: SemanticsRole::Button,
+        bounds: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 40.0,
+        },
+        description: Some("Save".into()),
+        state_description: Some("off".into()),
+        actions: vec![AccessibilityAction::Click, AccessibilityAction::Focus],
+        is_enabled: false,
+        is_focusable: true,
+        children: vec![label],
+    };
+
+    assert_eq!(
+        button.to_json(),
+        concat!(
+            r#"{"node":4,"role":"button","text":null,"bounds":[0,0,100,40],"#,
+            r#""description":"Save","state":"off","actions":["click","focus"],"#,
+            r#""enabled":false,"focusable":true,"children":["#,
+            r#"{"node":5,"role":"text","text":"Say \"hi\"","bounds":[8,10,40.5,20],"#,
+            r#""description":null,"state":null,"actions":[],"#,
+            r#""enabled":true,"focusable":false,"children":[]}]}"#,
+        )
+    );
+}
+
+#[test]
+fn accessibility_tree_exports_visible_buttons_with_bounds() {
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, || {
+        Column(Modifier::empty(), ColumnSpec::default(), || {
+            Button(
+                Modifier::empty()
+                    .size_points(100.0, 40.0)
+                    .semantics(|config| {
+                        config.is_button = true;
+                        config.content_description = Some("Save".into());
+                    }),
+                || {},
+                || {
+                    Text("Save", Modifier::empty());
+                },
+            );
+            Box(
+                Modifier::empty().size_points(100.0, 200.0),
+                BoxSpec::default(),
+                || {},
+            );
+            Button(
+                Modifier::empty().size_points(100.0, 40.0),
+                || {},
+                || {
+                    Text("Below the fold", Modifier::empty());
+                },
+            );
+        });
+    });
+    shell.set_viewport(200.0, 100.0);
+    shell.update();
+
+    let root = shell.accessibility_tree().expect("accessibility tree");
+    let buttons: Vec<&AccessibilityNode> = root
+        .children
+        .iter()
+        .filter(|node| node.role == SemanticsRole::Button)
+        .collect();
+    assert_eq!(buttons.len(), 1, "offscreen button is excluded");
+    let button = buttons[0];
+    assert_eq!(button.description.as_deref(), Some("Save"));
+    assert_eq!(
+        button.bounds,
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 40.0
+        }
+    );
+    assert_eq!(
+        button.actions,
+        vec![AccessibilityAction::Click, AccessibilityAction::Focus]
+    );
+}
+
+fn find_described(node: &AccessibilityNode, description: &str) -> Option<Rect> {
+    if node.description.as_deref() == Some(description) {
+        return Some(node.bounds);
+    }
+    node.children
+        .iter()
+        .find_map(|child| find_described(child, description))
+}
+
+#[test]
+fn nested_scrollables_keep_a_drag_with_the_one_that_claimed_it() {
+    let root_key = location_key(file!(), line!(), column!());
+    let states: Rc<RefCell<Option<(ScrollState, ScrollState)>>> = Rc::default();
+    let mut shell = AppShell::new(BoxHitRenderer::default(), root_key, {
+        let states = states.clone();
+        move || {
+            let outer = cranpose_core::remember(|| ScrollState::new(0.0)).with(Clone::clone);
+            let inner = cranpose_core::remember(|| ScrollState::new(0.0)).with(Clone::clone);
+            *states.borrow_mut() = Some((outer.clone(), inner.clone()));
+            Row(
+                Modifier::empty()
+                    .size_points(100.0, 100.0)
+                    .horizontal_scroll(outer, false),
+                RowSpec::default(),
+                move || {
+                    let inner = inner.clone();
+                    Column(
+                        Modifier::empty()
+                            .size_points(100.0, 100.0)
+                            .vertical_scroll(inner, false),
+                        ColumnSpec::default(),
+                        || {
+                            Box(
+                                Modifier::empty().size_points(100.0, 400.0),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                        },
+                    );
+                    Box(
+                        Modifier::empty().size_points(300.0, 100.0),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                },
+            );
+        }
+    });
+    shell.update();
+    let (outer, inner) = states.borrow().clone().expect("scroll states");
+    let drag = |shell: &mut AppShell<BoxHitRenderer>, path: &[(f32, f32)]| {
+        shell.set_cursor(50.0, 50.0);
+        shell.pointer_pressed();
+        for &(x, y) in path {
+            shell.set_cursor(x, y);
+        }
+        shell.pointer_released();
+        shell.update();
+    };
+
+    // A clear vertical drag stays with the list, even when it then veers sideways.
+    drag(&mut shell, &[(52.0, 38.0), (52.0, 20.0), (10.0, 10.0)]);
+    assert!(inner.value() > 0.0);
+    assert_eq!(outer.value(), 0.0);
+
+    // Claimed sideways first, the drag stays with the outer scroller.
+    let scrolled = inner.value();
+    drag(&mut shell, &[(38.0, 50.0), (38.0, 10.0), (30.0, 5.0)]);
+    assert!(outer.value() > 0.0);
+    assert_eq!(inner.value(), scrolled);
+
+    // The lock ends with the gesture: the list takes the next vertical drag.
+    let offset = outer.value();
+    drag(&mut shell, &[(50.0, 38.0), (50.0, 20.0)]);
+    assert!(inner.value() > scrolled);
+    assert_eq!(outer.value(), offset);
+}
+
+/// Fills the constraints and counts how often it is measured.
+#[derive(Clone)]
+struct CountingPolicy(Rc<Cell<usize>>);
+
+impl PartialEq for CountingPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl cranpose_ui_layout::MeasurePolicy for CountingPolicy {
+    fn measure(
+        &self,
+        _measurables: &[std::boxed::Box<dyn cranpose_ui::Measurable>],
+        constraints: cranpose_ui_layout::Constraints,
+    ) -> cranpose_ui_layout::MeasureResult {
+        self.0.set(self.0.get() + 1);
+        let size = Size {
+            width: constraints.max_width,
+            height: constraints.max_height,
+        };
+        cranpose_ui_layout::MeasureResult::new(size, Vec::new())
+    }
+}
+
+#[test]
+fn first_frame_after_prewarm_reuses_the_measured_tree() {
+    let root_key = location_key(file!(), line!(), column!());
+    let measures = Rc::new(Cell::new(0));
+    let mut shell = AppShell::new(RecordingRenderer::default(), root_key, {
+        let measures = measures.clone();
+        move || {
+            Layout(Modifier::empty(), CountingPolicy(measures.clone()), || {});
+        }
+    });
+    let root_size = |shell: &AppShell<RecordingRenderer>| {
+        let root = shell.layout_tree().expect("layout tree").root().rect;
+        (root.width, root.height)
+    };
+
+    shell.prewarm(400.0, 300.0);
+    assert_eq!(root_size(&shell), (400.0, 300.0));
+    let prewarmed = measures.get();
+    shell.renderer().last_scene = None;
+
+    shell.update();
+    assert_eq!(measures.get(), prewarmed);
+    assert!(shell.renderer().last_scene.is_some(), "first frame renders");
+
+    // The window came up at another size: measured again for it.
+    shell.set_viewport(500.0, 300.0);
+    assert!(measures.get() > prewarmed);
+    assert_eq!(root_size(&shell), (500.0, 300.0));
+}
+
+#[test]
+fn bring_into_view_scrolls_the_element_into_the_viewport() {
+    let root_key = location_key(file!(), line!(), column!());
+    let scroll: Rc<RefCell<Option<ScrollState>>> = Rc::default();
+    let (below, tall) = (BringIntoViewRequester::new(), BringIntoViewRequester::new());
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let scroll = scroll.clone();
+        let (below, tall) = (below.clone(), tall.clone());
+        move || {
+            let state = cranpose_core::remember(|| ScrollState::new(0.0)).with(Clone::clone);
+            *scroll.borrow_mut() = Some(state.clone());
+            let (below, tall) = (below.clone(), tall.clone());
+            Column(
+                Modifier::empty()
+                    .height(100.0)
+                    .vertical_scroll(state, false),
+                ColumnSpec::default(),
+                move || {
+                    for _ in 0..4 {
+                        Box(
+                            Modifier::empty().size_points(50.0, 50.0),
+                            BoxSpec::default(),
+                            || {},
+                        );
+                    }
+                    Box(
+                        Modifier::empty()
+                            .size_points(50.0, 50.0)
+                            .bring_into_view_requester(&below),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    Box(
+                        Modifier::empty()
+                            .size_points(50.0, 150.0)
+                            .bring_into_view_requester(&tall),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                },
+            );
+        }
+    });
+    shell.set_viewport(200.0, 400.0);
+    shell.update();
+    let state = scroll.borrow().clone().expect("scroll state");
+
+    // The element spans 200..250, so its bottom edge aligns with the viewport's.
+    assert!(below.bring_into_view());
+    assert_eq!(state.value(), 150.0);
+    shell.update();
+    assert!(!below.bring_into_view(), "already visible");
+
+    // Taller than the viewport: its top edge aligns with the viewport's.
+    assert!(tall.bring_into_view());
+    assert_eq!(state.value(), 250.0);
+}
+
+#[test]
+fn safe_content_padding_keeps_content_clear_of_window_insets() {
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, || {
+        Box(
+            Modifier::empty().fill_max_size().safe_content_padding(),
+            BoxSpec::default(),
+            || {
+                // Already padded by the parent, so this adds nothing.
+                Box(
+                    Modifier::empty()
+                        .fill_max_size()
+                        .window_insets_padding(EdgeInsets::from_components(0.0, 24.0, 0.0, 0.0)),
+                    BoxSpec::default(),
+                    || {
+                        Box(
+                            Modifier::empty().fill_max_size().semantics(|config| {
+                                config.content_description = Some("content".into());
+                            }),
+                            BoxSpec::default(),
+                            || {},
+                        );
+                    },
+                );
+            },
+        );
+    });
+    shell.set_viewport(200.0, 400.0);
+    shell.set_window_insets(WindowInsets {
+        status_bars: EdgeInsets::from_components(0.0, 24.0, 0.0, 0.0),
+        navigation_bars: EdgeInsets::from_components(10.0, 0.0, 0.0, 48.0),
+        ime: EdgeInsets::default(),
+    });
+    shell.update();
+
+    let content = |shell: &AppShell<TestRenderer>| {
+        let root = shell.accessibility_tree().expect("accessibility tree");
+        find_described(&root, "content").expect("content node")
+    };
+    assert_eq!(
+        content(&shell),
+        Rect {
+            x: 10.0,
+            y: 24.0,
+            width: 190.0,
+            height: 328.0
+        }
+    );
+
+    // Opening the keyboard recomposes the reader and shrinks the content.
+    shell.set_window_insets(WindowInsets {
+        ime: EdgeInsets::from_components(0.0, 0.0, 0.0, 200.0),
+        ..shell.window_insets()
+    });
+    shell.update();
+    assert_eq!(
+        content(&shell),
+        Rect {
+            x: 10.0,
+            y: 24.0,
+            width: 190.0,
+            height: 176.0
+        }
+    );
+}
+
+#[derive(Default)]
+struct RecordingKeyboard {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+impl SoftwareKeyboardController for RecordingKeyboard {
+    fn show(&self) {
+        self.calls.borrow_mut().push("show");
+    }
+
+    fn hide(&self) {
+        self.calls.borrow_mut().push("hide");
+    }
+}
+
+#[test]
+fn software_keyboard_controller_forwards_to_the_platform() {
+    let root_key = location_key(file!(), line!(), column!());
+    let read: Rc<RefCell<Option<Rc<dyn SoftwareKeyboardController>>>> = Rc::default();
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let read = read.clone();
+        move || {
+            *read.borrow_mut() = LocalSoftwareKeyboardController().current();
+        }
+    });
+    let controller = read.borrow().clone().expect("shell provides a controller");
+
+    // Headless: nothing installed yet, so requests are dropped.
+    controller.show();
+
+    let keyboard = Rc::new(RecordingKeyboard::default());
+    shell.set_software_keyboard_controller(keyboard.clone());
+    controller.show();
+    controller.hide();
+    assert_eq!(*keyboard.calls.borrow(), ["show", "hide"]);
+}
+
+#[test]
+fn back_is_routed_to_the_most_recent_enabled_handler() {
+    let root_key = location_key(file!(), line!(), column!());
+    let calls: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+    let inner_enabled: Rc<RefCell<Option<cranpose_core::MutableState<bool>>>> = Rc::default();
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let calls = calls.clone();
+        let inner_enabled = inner_enabled.clone();
+        move || {
+            let enabled = useState(|| true);
+            *inner_enabled.borrow_mut() = Some(enabled);
+            let outer = calls.clone();
+            BackHandler(true, move || outer.borrow_mut().push("outer"));
+            let inner = calls.clone();
+            BackHandler(enabled.value(), move || inner.borrow_mut().push("inner"));
+        }
+    });
+
+    assert!(shell.dispatch_back());
+    assert_eq!(*calls.borrow(), ["inner"]);
+
+    inner_enabled.borrow().expect("state").set(false);
+    shell.update();
+    assert!(shell.dispatch_back());
+    assert_eq!(*calls.borrow(), ["inner", "outer"]);
+}
+
+#[test]
+fn back_without_enabled_handlers_is_not_consumed() {
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, || {
+        BackHandler(false, || panic!("disabled handler must not run"));
+    });
+    assert!(!shell.dispatch_back());
+}
+
+#[test]
+fn going_back_restores_the_saved_state_of_the_previous_route() {
+    let root_key = location_key(file!(), line!(), column!());
+    let controller: Rc<RefCell<Option<NavController>>> = Rc::default();
+    let count: Rc<RefCell<Option<cranpose_core::MutableState<i32>>>> = Rc::default();
+    let composed: Rc<RefCell<Vec<String>>> = Rc::default();
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let controller = controller.clone();
+        let count = count.clone();
+        let composed = composed.clone();
+        move || {
+            let nav = rememberNavController();
+            *controller.borrow_mut() = Some(nav.clone());
+            let count = count.clone();
+            let composed = composed.clone();
+            NavHost(nav, "a", move |route| {
+                composed.borrow_mut().push(route.to_owned());
+                if route == "a" {
+                    *count.borrow_mut() = Some(rememberSaveable("count", || 0));
+                }
+            });
+        }
+    });
+    let nav = controller.borrow().clone().expect("controller");
+    let state = count.borrow().expect("state");
+    state.set(5);
+
+    nav.navigate("b");
+    shell.update();
+    nav.navigate("b");
+    shell.update();
+    assert_eq!(nav.back_stack(), ["a", "b"]);
+    assert_eq!(composed.borrow().last().map(String::as_str), Some("b"));
+
+    assert!(shell.dispatch_back());
+    shell.update();
+    assert_eq!(nav.current_route().as_deref(), Some("a"));
+    assert_eq!(count.borrow().expect("state").value(), 5);
+
+    // Nothing left to go back to.
+    assert!(!nav.pop_back_stack());
+    assert!(!shell.dispatch_back());
+}
+
+#[test]
+fn leaving_a_route_removes_its_content() {
+    let root_key = location_key(file!(), line!(), column!());
+    let controller: Rc<RefCell<Option<NavController>>> = Rc::default();
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let controller = controller.clone();
+        move || {
+            let nav = rememberNavController();
+            *controller.borrow_mut() = Some(nav.clone());
+            NavHost(nav, "a", |route| {
+                let description = route.to_owned();
+                Box(
+                    Modifier::empty()
+                        .size_points(50.0, 50.0)
+                        .semantics(move |config| {
+                            config.content_description = Some(description.clone());
+                        }),
+                    BoxSpec::default(),
+                    || {},
+                );
+            });
+        }
+    });
+    shell.set_viewport(200.0, 400.0);
+    shell.update();
+    let shown = |shell: &AppShell<TestRenderer>, route: &str| {
+        let root = shell.accessibility_tree().expect("accessibility tree");
+        find_described(&root, route).is_some()
+    };
+    assert!(shown(&shell, "a"));
+    let nav = controller.borrow().clone().expect("controller");
+
+    nav.navigate("b");
+    shell.update();
+    assert!(!shown(&shell, "a"));
+    assert!(shown(&shell, "b"));
+
+    assert!(shell.dispatch_back());
+    shell.update();
+    assert!(shown(&shell, "a"));
+    assert!(!shown(&shell, "b"));
+}
+#[test]
+fn going_back_restores_scroll_positions_of_the_previous_route() {
+    let root_key = location_key(file!(), line!(), column!());
+    let controller: Rc<RefCell<Option<NavController>>> = Rc::default();
+    let scroll: Rc<RefCell<Option<ScrollState>>> = Rc::default();
+    let list: Rc<RefCell<Option<LazyListState>>> = Rc::default();
+    let item_count = Rc::new(Cell::new(100));
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let controller = controller.clone();
+        let scroll = scroll.clone();
+        let list = list.clone();
+        let item_count = item_count.clone();
+        move || {
+            let nav = rememberNavController();
+            *controller.borrow_mut() = Some(nav.clone());
+            let (scroll, list, item_count) = (scroll.clone(), list.clone(), item_count.clone());
+            NavHost(nav, "lists", move |route| {
+                if route != "lists" {
+                    return;
+                }
+                let scroll_state =
+                    rememberSaveableWith("scroll", ScrollStateSaver, || ScrollState::new(0.0));
+                *scroll.borrow_mut() = Some(scroll_state.clone());
+                let list_state =
+                    rememberSaveableWith("list", LazyListStateSaver, || LazyListState::new(0, 0.0));
+                *list.borrow_mut() = Some(list_state);
+                let count = item_count.get();
+                Row(Modifier::empty(), RowSpec::default(), move || {
+                    Column(
+                        Modifier::empty()
+                            .size_points(50.0, 100.0)
+                            .vertical_scroll(scroll_state.clone(), false),
+                        ColumnSpec::default(),
+                        || {
+                            Box(
+                                Modifier::empty().size_points(50.0, 500.0),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                        },
+                    );
+                    LazyColumn(
+                        Modifier::empty().size_points(50.0, 100.0),
+                        list_state,
+                        LazyColumnSpec::default(),
+                        |scope| {
+                            scope.items(
+                                count,
+                                None::<fn(usize) -> u64>,
+                                None::<fn(usize) -> u64>,
+                                |_| {
+                                    Box(
+                                        Modifier::empty().size_points(50.0, 20.0),
+                                        BoxSpec::default(),
+                                        || {},
+                                    );
+                                },
+                            );
+                        },
+                    );
+                });
+            });
+        }
+    });
+    shell.set_viewport(200.0, 400.0);
+    shell.update();
+    let nav = controller.borrow().clone().expect("controller");
+    let first_scroll = scroll.borrow().clone().expect("scroll state");
+    first_scroll.scroll_to(120.0);
+    list.borrow().expect("list state").scroll_to_item(30, 5.0);
+    shell.update();
+
+    nav.navigate("details");
+    shell.update();
+    assert!(shell.dispatch_back());
+    shell.update();
+    let restored_scroll = scroll.borrow().clone().expect("scroll state");
+    assert_ne!(restored_scroll.id(), first_scroll.id(), "composed again");
+    assert_eq!(restored_scroll.value(), 120.0);
+    let restored_list = list.borrow().expect("list state");
+    assert_eq!(restored_list.first_visible_item_index(), 30);
+    assert_eq!(restored_list.first_visible_item_scroll_offset(), 5.0);
+
+    // The list shrank while its screen was away: the position is clamped.
+    nav.navigate("details");
+    shell.update();
+    item_count.set(10);
+    assert!(shell.dispatch_back());
+    shell.update();
+    // The list clamps its position while measuring; that write is committed
+    // on the following frame.
+    shell.update();
+    let restored_list = list.borrow().expect("list state");
+    assert!(restored_list.first_visible_item_index() < 10);
+    let info = restored_list.layout_info();
+    assert_eq!(
+        info.visible_items_info.last().map(|item| item.index),
+        Some(9),
+        "the end of the list is visible"
+    );
+}
+#[test]
+fn lifecycle_effect_follows_start_and_stop_in_order() {
+    let root_key = location_key(file!(), line!(), column!());
+    let calls: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+    let shown: Rc<RefCell<Option<cranpose_core::MutableState<bool>>>> = Rc::default();
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, {
+        let calls = calls.clone();
+        let shown = shown.clone();
+        move || {
+            let visible = useState(|| true);
+            *shown.borrow_mut() = Some(visible);
+            let calls = calls.clone();
+            cranpose_ui::widgets::When(visible.value(), move || {
+                let (start, stop) = (calls.clone(), calls.clone());
+                LifecycleEffect(
+                    move || start.borrow_mut().push("start"),
+                    move || stop.borrow_mut().push("stop"),
+                );
+            });
+        }
+    });
+    // The shell starts in the foreground.
+    assert_eq!(*calls.borrow(), ["start"]);
+
+    // A configuration change stops and restarts the app.
+    shell.set_lifecycle_started(false);
+    shell.set_lifecycle_started(false);
+    shell.set_lifecycle_started(true);
+    assert_eq!(*calls.borrow(), ["start", "stop", "start"]);
+
+    // Leaving the composition while started pairs the last start.
+    shown.borrow().expect("state").set(false);
+    shell.update();
+    assert_eq!(*calls.borrow(), ["start", "stop", "start", "stop"]);
+
+    shell.set_lifecycle_started(false);
+    assert_eq!(calls.borrow().len(), 4);
+}
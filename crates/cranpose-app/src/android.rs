@@ -7,6 +7,8 @@ use crate::launcher::AppSettings;
 use cranpose_app_shell::{default_root_key, AppShell};
 use cranpose_platform_android::AndroidPlatform;
 use cranpose_render_wgpu::WgpuRenderer;
+use cranpose_ui::{EdgeInsets, SoftwareKeyboardController, WindowInsets};
+use std::rc::Rc;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
@@ -15,7 +17,6 @@ use std::sync::{
 /// GPU resources for the surface (recreated when window is destroyed/created).
 struct GpuResources {
     surface: wgpu::Surface<'static>,
-    device: Arc<wgpu::Device>,
     config: wgpu::SurfaceConfiguration,
 }
 
@@ -39,10 +40,54 @@ fn get_display_density(app: &android_activity::AndroidApp) -> f32 {
     density_dpi.map(|dpi| dpi as f32 / 160.0).unwrap_or(2.0) // Fallback to xhdpi (2.0) if density unavailable
 }
 
+/// Window insets in dp from the activity's content rect.
+///
+/// The content rect is the part of the window not covered by system bars, so
+/// its top edge is reported as the status bar and the other edges as the
+/// navigation bar. NativeActivity does not tell the keyboard apart, so `ime`
+/// stays zero.
+fn get_window_insets(app: &android_activity::AndroidApp, density: f32) -> WindowInsets {
+    let Some(native_window) = app.native_window() else {
+        return WindowInsets::default();
+    };
+    let rect = app.content_rect();
+    let to_dp = |px: i32| px.max(0) as f32 / density;
+    WindowInsets {
+        status_bars: EdgeInsets::from_components(0.0, to_dp(rect.top), 0.0, 0.0),
+        navigation_bars: EdgeInsets::from_components(
+            to_dp(rect.left),
+            0.0,
+            to_dp(native_window.width() - rect.right),
+            to_dp(native_window.height() - rect.bottom),
+        ),
+        ime: EdgeInsets::default(),
+    }
+}
+
+/// Shows and hides the soft keyboard through the activity's input method
+/// manager.
+struct AndroidKeyboardController(android_activity::AndroidApp);
+
+impl SoftwareKeyboardController for AndroidKeyboardController {
+    fn show(&self) {
+        self.0.show_soft_input(true);
+    }
+
+    fn hide(&self) {
+        self.0.hide_soft_input(true);
+    }
+}
+
 /// Renders a single frame. Returns true if out of memory (should exit).
 fn render_once(resources: &mut GpuResources, shell: &mut AppShell<WgpuRenderer>) -> bool {
     shell.update();
 
+    if shell.renderer().surface_needs_reconfigure() {
+        shell
+            .renderer()
+            .configure_surface(&resources.surface, &mut resources.config);
+    }
+
     match resources.surface.get_current_texture() {
         Ok(frame) => {
             let view = frame
@@ -62,9 +107,9 @@ fn render_once(resources: &mut GpuResources, shell: &mut AppShell<WgpuRenderer>)
             let (width, height) = shell.buffer_size();
             resources.config.width = width;
             resources.config.height = height;
-            resources
-                .surface
-                .configure(&resources.device, &resources.config);
+            shell
+                .renderer()
+                .configure_surface(&resources.surface, &mut resources.config);
             false
         }
         Err(wgpu::SurfaceError::OutOfMemory) => {
@@ -89,7 +134,10 @@ pub fn run(
     settings: AppSettings,
     content: impl FnMut() + 'static,
 ) {
-    use android_activity::{input::MotionAction, InputStatus, MainEvent, PollEvent};
+    use android_activity::{
+        input::{KeyAction, Keycode, MotionAction},
+        InputStatus, MainEvent, PollEvent,
+    };
 
     // Install panic hook for better crash logging in Logcat
     std::panic::set_hook(Box::new(|panic_info| {
@@ -251,20 +299,6 @@ pub fn run(
                                 .find(|f| f.is_srgb())
                                 .unwrap_or(surface_caps.formats[0]);
 
-                            // Configure surface
-                            let surface_config = wgpu::SurfaceConfiguration {
-                                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                                format: surface_format,
-                                width,
-                                height,
-                                present_mode: wgpu::PresentMode::Fifo,
-                                alpha_mode: surface_caps.alpha_modes[0],
-                                view_formats: vec![],
-                                desired_maximum_frame_latency: 2,
-                            };
-
-                            surface.configure(&device, &surface_config);
-
                             // Get display density and update platform
                             let density = get_display_density(&app);
                             android_platform.set_scale_factor(density as f64);
@@ -284,10 +318,15 @@ pub fn run(
 
                                 // Create app shell with content closure
                                 let content_clone = content.clone();
-                                let shell =
+                                let mut shell =
                                     AppShell::new(renderer, default_root_key(), move || {
                                         content_clone.borrow_mut()()
                                     });
+                                shell.set_software_keyboard_controller(Rc::new(
+                                    AndroidKeyboardController(app.clone()),
+                                ));
+                                // Compose and measure while the surface is configured below.
+                                shell.prewarm(width as f32 / density, height as f32 / density);
 
                                 app_shell = Some(shell);
 
@@ -314,6 +353,19 @@ pub fn run(
                                 }
                             }
 
+                            // Configure surface
+                            let renderer = app_shell
+                                .as_mut()
+                                .expect("app shell initialized above")
+                                .renderer();
+                            let mut surface_config = renderer.surface_configuration(
+                                &surface_caps,
+                                surface_format,
+                                width,
+                                height,
+                            );
+                            renderer.configure_surface(&surface, &mut surface_config);
+
                             // Set buffer_size and viewport
                             if let Some(shell) = &mut app_shell {
                                 shell.set_buffer_size(width, height);
@@ -321,6 +373,7 @@ pub fn run(
                                 let width_dp = width as f32 / density;
                                 let height_dp = height as f32 / density;
                                 shell.set_viewport(width_dp, height_dp);
+                                shell.set_window_insets(get_window_insets(&app, density));
                                 log::info!(
                                     "Set viewport to {:.1}x{:.1} dp ({}x{} px at {:.2}x density)",
                                     width_dp,
@@ -334,7 +387,6 @@ pub fn run(
                             // Store GPU resources
                             gpu_resources = Some(GpuResources {
                                 surface,
-                                device,
                                 config: surface_config,
                             });
 
@@ -366,9 +418,10 @@ pub fn run(
                                 if width > 0 && height > 0 {
                                     resources.config.width = width;
                                     resources.config.height = height;
-                                    resources
-                                        .surface
-                                        .configure(&resources.device, &resources.config);
+                                    shell.renderer().configure_surface(
+                                        &resources.surface,
+                                        &mut resources.config,
+                                    );
 
                                     // Set buffer_size to physical pixels
                                     shell.set_buffer_size(width, height);
@@ -377,6 +430,7 @@ pub fn run(
                                     let width_dp = width as f32 / density;
                                     let height_dp = height as f32 / density;
                                     shell.set_viewport(width_dp, height_dp);
+                                    shell.set_window_insets(get_window_insets(&app, density));
 
                                     // Update renderer scale
                                     shell.renderer().set_root_scale(density);
@@ -385,6 +439,12 @@ pub fn run(
                             }
                         }
                     }
+                    MainEvent::ContentRectChanged { .. } => {
+                        if let Some(shell) = &mut app_shell {
+                            let density = get_display_density(&app);
+                            shell.set_window_insets(get_window_insets(&app, density));
+                        }
+                    }
                     MainEvent::RedrawNeeded { .. } => {
                         if let Some(shell) = &mut app_shell {
                             shell.mark_dirty();
@@ -398,9 +458,15 @@ pub fn run(
                     }
                     MainEvent::Start => {
                         log::info!("App started");
+                        if let Some(shell) = &mut app_shell {
+                            shell.set_lifecycle_started(true);
+                        }
                     }
                     MainEvent::Stop => {
                         log::info!("App stopped");
+                        if let Some(shell) = &mut app_shell {
+                            shell.set_lifecycle_started(false);
+                        }
                     }
                     MainEvent::SaveState { .. } => {
                         log::info!("Save state requested (hook for future serialization)");
@@ -460,6 +526,16 @@ pub fn run(
                                         }
                                         true
                                     }
+                                    android_activity::input::InputEvent::KeyEvent(key_event)
+                                        if key_event.key_code() == Keycode::Back =>
+                                    {
+                                        // Unhandled presses fall through so the
+                                        // system can close the activity.
+                                        key_event.action() == KeyAction::Down
+                                            && app_shell
+                                                .as_mut()
+                                                .is_some_and(|shell| shell.dispatch_back())
+                                    }
                                     _ => false,
                                 };
 
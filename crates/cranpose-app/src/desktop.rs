@@ -3,7 +3,7 @@
 //! This module provides the desktop event loop implementation using winit.
 
 use crate::launcher::AppSettings;
-use cranpose_app_shell::{default_root_key, AppShell};
+use cranpose_app_shell::{default_root_key, AppShell, PointerButton};
 use cranpose_platform_desktop_winit::DesktopWinitPlatform;
 use cranpose_render_wgpu::WgpuRenderer;
 use std::sync::Arc;
@@ -18,6 +18,8 @@ use cranpose_ui::{LayoutBox, SemanticsAction, SemanticsNode, SemanticsRole};
 
 #[cfg(feature = "robot")]
 use std::sync::mpsc;
+#[cfg(feature = "robot")]
+use std::time::Duration;
 
 /// Serializable semantic element combining semantics + geometry
 ///
@@ -31,6 +33,8 @@ pub struct SemanticElement {
     pub role: String,
     /// Text content if available
     pub text: Option<String>,
+    /// State description if available (e.g., "Checked")
+    pub state_description: Option<String>,
     /// Geometric bounds in logical pixels
     pub bounds: SemanticRect,
     /// Whether this element has click actions
@@ -217,42 +221,83 @@ impl Robot {
     /// robot.drag(400.0, 200.0, 100.0, 200.0)?;
     /// ```
     pub fn drag(&self, from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> Result<(), String> {
-        // Touch down at start position
-        self.tx
-            .send(RobotCommand::TouchDown {
-                x: from_x,
-                y: from_y,
-            })
-            .map_err(|e| format!("Failed to send touch down: {}", e))?;
-        match self.rx.recv() {
-            Ok(RobotResponse::Ok) => {}
-            Ok(RobotResponse::Error(e)) => return Err(e),
-            Ok(_) => return Err("Unexpected response".to_string()),
-            Err(e) => return Err(format!("Failed to receive response: {}", e)),
-        }
+        self.touch_gesture((from_x, from_y), (to_x, to_y), 10, Duration::ZERO)
+    }
+
+    /// Perform a swipe gesture from one point to another over `duration`
+    ///
+    /// Intermediate moves are paced in real time (one per ~16ms frame), so the
+    /// velocity tracker sees realistic timestamps and a fast swipe on a
+    /// scrollable container ends in a fling.
+    ///
+    /// # Example
+    /// ```text
+    /// // Fast upward swipe to fling a list
+    /// robot.swipe(400.0, 500.0, 400.0, 100.0, Duration::from_millis(100))?;
+    /// ```
+    pub fn swipe(
+        &self,
+        from_x: f32,
+        from_y: f32,
+        to_x: f32,
+        to_y: f32,
+        duration: Duration,
+    ) -> Result<(), String> {
+        let steps = (duration.as_millis() / 16).max(2) as u32;
+        self.touch_gesture((from_x, from_y), (to_x, to_y), steps, duration / steps)
+    }
+
+    /// Drag an element by `(dx, dy)`, starting from the center of its bounds
+    ///
+    /// # Example
+    /// ```text
+    /// let semantics = robot.get_semantics()?;
+    /// let handle = Robot::find_by_text(&semantics, "Handle").ok_or("not found")?;
+    /// robot.drag_by(handle, 0.0, -120.0)?;
+    /// ```
+    pub fn drag_by(&self, element: &SemanticElement, dx: f32, dy: f32) -> Result<(), String> {
+        let center_x = element.bounds.x + element.bounds.width / 2.0;
+        let center_y = element.bounds.y + element.bounds.height / 2.0;
+        self.swipe(
+            center_x,
+            center_y,
+            center_x + dx,
+            center_y + dy,
+            Duration::from_millis(300),
+        )
+    }
+
+    /// Touch down at `from`, move to `to` in `steps` interpolated moves
+    /// separated by `step_delay`, then touch up.
+    fn touch_gesture(
+        &self,
+        from: (f32, f32),
+        to: (f32, f32),
+        steps: u32,
+        step_delay: Duration,
+    ) -> Result<(), String> {
+        self.send_touch(RobotCommand::TouchDown {
+            x: from.0,
+            y: from.1,
+        })?;
 
-        // Move in steps to simulate smooth drag
-        let steps = 10;
         for i in 1..=steps {
-            let t = i as f32 / steps as f32;
-            let x = from_x + (to_x - from_x) * t;
-            let y = from_y + (to_y - from_y) * t;
-
-            self.tx
-                .send(RobotCommand::TouchMove { x, y })
-                .map_err(|e| format!("Failed to send touch move: {}", e))?;
-            match self.rx.recv() {
-                Ok(RobotResponse::Ok) => {}
-                Ok(RobotResponse::Error(e)) => return Err(e),
-                Ok(_) => return Err("Unexpected response".to_string()),
-                Err(e) => return Err(format!("Failed to receive response: {}", e)),
+            if !step_delay.is_zero() {
+                std::thread::sleep(step_delay);
             }
+            let t = i as f32 / steps as f32;
+            let x = from.0 + (to.0 - from.0) * t;
+            let y = from.1 + (to.1 - from.1) * t;
+            self.send_touch(RobotCommand::TouchMove { x, y })?;
         }
 
-        // Touch up at end position
+        self.send_touch(RobotCommand::TouchUp { x: to.0, y: to.1 })
+    }
+
+    fn send_touch(&self, command: RobotCommand) -> Result<(), String> {
         self.tx
-            .send(RobotCommand::TouchUp { x: to_x, y: to_y })
-            .map_err(|e| format!("Failed to send touch up: {}", e))?;
+            .send(command)
+            .map_err(|e| format!("Failed to send touch command: {}", e))?;
         match self.rx.recv() {
             Ok(RobotResponse::Ok) => Ok(()),
             Ok(RobotResponse::Error(e)) => Err(e),
@@ -680,19 +725,6 @@ impl ApplicationHandler for App {
             .find(|f| f.is_srgb())
             .unwrap_or(surface_caps.formats[0]);
 
-        let surface_config = wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            format: surface_format,
-            width: size.width,
-            height: size.height,
-            present_mode: wgpu::PresentMode::Fifo,
-            alpha_mode: surface_caps.alpha_modes[0],
-            view_formats: vec![],
-            desired_maximum_frame_latency: 2,
-        };
-
-        surface.configure(&device, &surface_config);
-
         // Create renderer with fonts from settings
         let mut renderer = if let Some(fonts) = self.settings.fonts.take() {
             WgpuRenderer::new_with_fonts(fonts)
@@ -700,6 +732,11 @@ impl ApplicationHandler for App {
             WgpuRenderer::new()
         };
         renderer.init_gpu(Arc::new(device), Arc::new(queue), surface_format);
+
+        let mut surface_config =
+            renderer.surface_configuration(&surface_caps, surface_format, size.width, size.height);
+        renderer.configure_surface(&surface, &mut surface_config);
+
         let initial_scale = window.scale_factor();
         renderer.set_root_scale(initial_scale as f32);
         cranpose_ui::set_density(initial_scale as f32);
@@ -714,11 +751,12 @@ impl ApplicationHandler for App {
         let mut platform = DesktopWinitPlatform::default();
         platform.set_scale_factor(initial_scale);
 
-        // Set buffer_size to physical pixels and viewport to logical dp
+        // Set buffer_size to physical pixels, then compose and measure at the
+        // logical dp viewport so the first redraw only builds the scene
         app.set_buffer_size(size.width, size.height);
         let logical_width = size.width as f32 / initial_scale as f32;
         let logical_height = size.height as f32 / initial_scale as f32;
-        app.set_viewport(logical_width, logical_height);
+        app.prewarm(logical_width, logical_height);
 
         self.window = Some(window);
         self.surface = Some(surface);
@@ -757,20 +795,17 @@ impl ApplicationHandler for App {
                 }
                 event_loop.exit();
             }
-            WindowEvent::SurfaceResized(new_size) => {
-                if new_size.width > 0 && new_size.height > 0 {
-                    surface_config.width = new_size.width;
-                    surface_config.height = new_size.height;
-                    let device = app.renderer().device();
-                    surface.configure(device, surface_config);
+            WindowEvent::SurfaceResized(new_size) if new_size.width > 0 && new_size.height > 0 => {
+                surface_config.width = new_size.width;
+                surface_config.height = new_size.height;
+                app.renderer().configure_surface(surface, surface_config);
 
-                    let scale_factor = window.scale_factor();
-                    let logical_width = new_size.width as f32 / scale_factor as f32;
-                    let logical_height = new_size.height as f32 / scale_factor as f32;
+                let scale_factor = window.scale_factor();
+                let logical_width = new_size.width as f32 / scale_factor as f32;
+                let logical_height = new_size.height as f32 / scale_factor as f32;
 
-                    app.set_buffer_size(new_size.width, new_size.height);
-                    app.set_viewport(logical_width, logical_height);
-                }
+                app.set_buffer_size(new_size.width, new_size.height);
+                app.set_viewport(logical_width, logical_height);
             }
             WindowEvent::ScaleFactorChanged {
                 scale_factor,
@@ -786,8 +821,7 @@ impl ApplicationHandler for App {
                 if new_size.width > 0 && new_size.height > 0 {
                     surface_config.width = new_size.width;
                     surface_config.height = new_size.height;
-                    let device = app.renderer().device();
-                    surface.configure(device, surface_config);
+                    app.renderer().configure_surface(surface, surface_config);
 
                     let logical_width = new_size.width as f32 / scale_factor as f32;
                     let logical_height = new_size.height as f32 / scale_factor as f32;
@@ -798,14 +832,12 @@ impl ApplicationHandler for App {
             }
             WindowEvent::PointerMoved {
                 position, primary, ..
-            } => {
-                if primary {
-                    let logical = platform.pointer_position(position);
-                    app.set_cursor(logical.x, logical.y);
-                    // Record mouse move
-                    if let Some(recorder) = &mut self.recorder {
-                        recorder.record_mouse_move(logical.x, logical.y);
-                    }
+            } if primary => {
+                let logical = platform.pointer_position(position);
+                app.set_cursor(logical.x, logical.y);
+                // Record mouse move
+                if let Some(recorder) = &mut self.recorder {
+                    recorder.record_mouse_move(logical.x, logical.y);
                 }
             }
             WindowEvent::ModifiersChanged(modifiers) => {
@@ -818,33 +850,46 @@ impl ApplicationHandler for App {
                 primary,
                 button: ButtonSource::Mouse(MouseButton::Left),
                 ..
-            } => {
-                if primary {
-                    let logical = platform.pointer_position(position);
-                    app.set_cursor(logical.x, logical.y);
-                    match state {
-                        ElementState::Pressed => {
-                            app.pointer_pressed();
-                            // Record mouse down
-                            if let Some(recorder) = &mut self.recorder {
-                                recorder.record_mouse_down();
-                            }
+            } if primary => {
+                let logical = platform.pointer_position(position);
+                app.set_cursor(logical.x, logical.y);
+                match state {
+                    ElementState::Pressed => {
+                        app.pointer_pressed();
+                        // Record mouse down
+                        if let Some(recorder) = &mut self.recorder {
+                            recorder.record_mouse_down();
                         }
-                        ElementState::Released => {
-                            app.pointer_released();
-                            // Sync selection to PRIMARY (Linux X11 middle-click paste)
-                            app.sync_selection_to_primary();
-                            // Record mouse up
-                            if let Some(recorder) = &mut self.recorder {
-                                recorder.record_mouse_up();
-                            }
+                    }
+                    ElementState::Released => {
+                        app.pointer_released();
+                        // Sync selection to PRIMARY (Linux X11 middle-click paste)
+                        app.sync_selection_to_primary();
+                        // Record mouse up
+                        if let Some(recorder) = &mut self.recorder {
+                            recorder.record_mouse_up();
                         }
                     }
                 }
             }
-            // Middle-click paste from Linux primary selection
+            // Secondary click, e.g. for context menus
+            WindowEvent::PointerButton {
+                state,
+                position,
+                primary,
+                button: ButtonSource::Mouse(MouseButton::Right),
+                ..
+            } if primary => {
+                let logical = platform.pointer_position(position);
+                app.set_cursor(logical.x, logical.y);
+                match state {
+                    ElementState::Pressed => app.pointer_button_pressed(PointerButton::Secondary),
+                    ElementState::Released => app.pointer_button_released(PointerButton::Secondary),
+                };
+            }
+            // Middle-click also pastes from Linux primary selection
             WindowEvent::PointerButton {
-                state: ElementState::Pressed,
+                state,
                 position,
                 primary,
                 button: ButtonSource::Mouse(MouseButton::Middle),
@@ -853,9 +898,18 @@ impl ApplicationHandler for App {
                 if primary {
                     let logical = platform.pointer_position(position);
                     app.set_cursor(logical.x, logical.y);
+                    match state {
+                        ElementState::Pressed => app.pointer_button_pressed(PointerButton::Middle),
+                        ElementState::Released => {
+                            app.pointer_button_released(PointerButton::Middle)
+                        }
+                    };
                 }
                 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
-                if let Some(text) = app.get_primary_selection() {
+                if let Some(text) = (state == ElementState::Pressed)
+                    .then(|| app.get_primary_selection())
+                    .flatten()
+                {
                     if app.on_paste(&text) {
                         window.request_redraw();
                     }
@@ -966,6 +1020,13 @@ impl ApplicationHandler for App {
                 app.cancel_gesture();
                 // Clear any active IME composition
                 let _ = app.on_ime_preedit("", None);
+                // Desktop has no background state; an unfocused window counts as stopped
+                app.set_lifecycle_started(false);
+                window.request_redraw();
+            }
+            WindowEvent::Focused(true) => {
+                app.set_lifecycle_started(true);
+                window.request_redraw();
             }
             WindowEvent::Ime(ime_event) => {
                 use winit::event::Ime;
@@ -1003,15 +1064,17 @@ impl ApplicationHandler for App {
                     }
                 }
             }
-            WindowEvent::PointerLeft { primary, .. } => {
-                if primary {
-                    // Pointer left the window - cancel any in-progress gestures
-                    app.cancel_gesture();
-                }
+            WindowEvent::PointerLeft { primary, .. } if primary => {
+                // Pointer left the window - cancel any in-progress gestures
+                app.cancel_gesture();
             }
             WindowEvent::RedrawRequested => {
                 app.update();
 
+                if app.renderer().surface_needs_reconfigure() {
+                    app.renderer().configure_surface(surface, surface_config);
+                }
+
                 let output = match surface.get_current_texture() {
                     Ok(output) => output,
                     Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
@@ -1020,8 +1083,7 @@ impl ApplicationHandler for App {
                         if size.width > 0 && size.height > 0 {
                             surface_config.width = size.width;
                             surface_config.height = size.height;
-                            let device = app.renderer().device();
-                            surface.configure(device, surface_config);
+                            app.renderer().configure_surface(surface, surface_config);
                         }
                         return;
                     }
@@ -1391,6 +1453,12 @@ fn combine_trees(sem_node: &SemanticsNode, layout_box: &LayoutBox) -> SemanticEl
         SemanticsRole::Layout => "Layout",
         SemanticsRole::Subcompose => "Subcompose",
         SemanticsRole::Spacer => "Spacer",
+        SemanticsRole::Checkbox => "Checkbox",
+        SemanticsRole::Switch => "Switch",
+        SemanticsRole::Slider => "Slider",
+        SemanticsRole::Image => "Image",
+        SemanticsRole::Header => "Header",
+        SemanticsRole::Group => "Group",
         SemanticsRole::Unknown => "Unknown",
     }
     .to_string();
@@ -1426,6 +1494,7 @@ fn combine_trees(sem_node: &SemanticsNode, layout_box: &LayoutBox) -> SemanticEl
     SemanticElement {
         role,
         text,
+        state_description: sem_node.state_description.clone(),
         bounds,
         clickable,
         children,
@@ -100,19 +100,6 @@ pub async fn run(
         .find(|f| f.is_srgb())
         .unwrap_or(surface_caps.formats[0]);
 
-    let surface_config = wgpu::SurfaceConfiguration {
-        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-        format: surface_format,
-        width: (width as f64 * scale_factor) as u32,
-        height: (height as f64 * scale_factor) as u32,
-        present_mode: wgpu::PresentMode::Fifo,
-        alpha_mode: surface_caps.alpha_modes[0],
-        view_formats: vec![],
-        desired_maximum_frame_latency: 2,
-    };
-
-    surface.configure(&device, &surface_config);
-
     // Create renderer with fonts from settings
     let mut renderer = if let Some(fonts) = settings.fonts {
         WgpuRenderer::new_with_fonts(fonts)
@@ -120,6 +107,15 @@ pub async fn run(
         WgpuRenderer::new()
     };
     renderer.init_gpu(Arc::new(device), Arc::new(queue), surface_format);
+
+    let mut surface_config = renderer.surface_configuration(
+        &surface_caps,
+        surface_format,
+        (width as f64 * scale_factor) as u32,
+        (height as f64 * scale_factor) as u32,
+    );
+    renderer.configure_surface(&surface, &mut surface_config);
+
     renderer.set_root_scale(scale_factor as f32);
     cranpose_ui::set_density(scale_factor as f32);
 
@@ -474,7 +470,13 @@ pub async fn run(
     *render_loop.borrow_mut() = Some(Closure::wrap(Box::new(move || {
         app.borrow_mut().update();
 
-        let config = surface_config.borrow();
+        let mut config = surface_config.borrow_mut();
+        {
+            let mut app_mut = app.borrow_mut();
+            if app_mut.renderer().surface_needs_reconfigure() {
+                app_mut.renderer().configure_surface(&surface, &mut config);
+            }
+        }
         match surface.get_current_texture() {
             Ok(output) => {
                 let view = output
@@ -495,9 +497,9 @@ pub async fn run(
             }
             Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
                 // Reconfigure surface
-                let mut app_mut = app.borrow_mut();
-                let device = app_mut.renderer().device();
-                surface.configure(device, &*config);
+                app.borrow_mut()
+                    .renderer()
+                    .configure_surface(&surface, &mut config);
             }
             Err(wgpu::SurfaceError::OutOfMemory) => {
                 log::error!("Out of memory");
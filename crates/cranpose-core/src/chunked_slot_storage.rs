@@ -29,7 +29,7 @@
 //! - **Cons**: Higher memory overhead (fixed chunk sizes), more complex indexing
 
 use crate::{
-    slot_storage::{GroupId, SlotStorage, StartGroup, ValueSlotId},
+    slot_storage::{compact_slots, CompactSlot, GroupId, SlotStorage, StartGroup, ValueSlotId},
     AnchorId, Key, NodeId, Owned, ScopeId,
 };
 use std::any::Any;
@@ -59,10 +59,12 @@ pub struct ChunkedSlotStorage {
     next_anchor_id: Cell<usize>,
     /// Tracks whether the most recent begin_group reused a gap.
     last_start_was_gap: bool,
+    /// Group stack depth below the enclosing-group frames pushed by each
+    /// active `begin_recranpose_at_scope`.
+    recompose_bases: Vec<usize>,
 }
 
 struct GroupFrame {
-    #[allow(dead_code)] // Tracked for debugging/future inspection tools
     key: Key,
     start: usize,
     end: usize,
@@ -137,12 +139,23 @@ impl Default for ChunkedSlot {
     }
 }
 
+impl Drop for ChunkedSlotStorage {
+    /// Releases slots last-to-first so disposing a whole composition runs
+    /// effect cleanups in reverse order.
+    fn drop(&mut self) {
+        while let Some(mut chunk) = self.chunks.pop() {
+            while let Some(slot) = chunk.pop() {
+                drop(slot);
+            }
+        }
+    }
+}
+
 impl ChunkedSlotStorage {
     pub fn new() -> Self {
-        Self {
-            next_anchor_id: Cell::new(1), // Start at 1 (0 is INVALID)
-            ..Default::default()
-        }
+        let storage = Self::default();
+        storage.next_anchor_id.set(1); // Start at 1 (0 is INVALID)
+        storage
     }
 
     /// Get total number of slots across all chunks.
@@ -483,12 +496,10 @@ impl ChunkedSlotStorage {
         }
     }
 
-    /// Skip over the current group (internal implementation).
+    /// Skip the rest of the current group (internal implementation).
     fn do_skip_current_group(&mut self) {
-        if let Some(slot) = self.get_slot(self.cursor) {
-            if let ChunkedSlot::Group { len, .. } = slot {
-                self.cursor += 1 + len;
-            }
+        if let Some(frame) = self.group_stack.last() {
+            self.cursor = frame.end;
         }
     }
 
@@ -502,36 +513,27 @@ impl ChunkedSlotStorage {
                 if self.cursor >= total {
                     return false;
                 }
-                let mut marked = false;
-                while self.cursor < total {
-                    if let Some(slot) = self.get_slot_mut(self.cursor) {
-                        let anchor = slot.anchor_id();
-                        let (group_key, group_scope, group_len) = match slot {
-                            ChunkedSlot::Group {
-                                key, scope, len, ..
-                            } => (Some(*key), *scope, *len),
-                            _ => (None, None, 0),
-                        };
-                        *slot = ChunkedSlot::Gap {
-                            anchor,
-                            group_key,
-                            group_scope,
-                            group_len,
-                        };
-                        marked = true;
-                    }
-                    self.cursor += 1;
-                }
+                let marked = self.mark_gaps_until(total);
                 // Mark anchors dirty so flush() rebuilds the anchor map
                 self.anchors_dirty = true;
                 return marked;
             }
         };
 
-        let mut marked = false;
-        while self.cursor < frame_end {
+        let marked = self.mark_gaps_until(frame_end);
+        if let Some(frame) = self.group_stack.last_mut() {
+            frame.end = self.cursor;
+        }
+        marked
+    }
+
+    /// Converts every slot from the cursor up to `end` into a gap, advancing
+    /// the cursor. Released slots are dropped last-to-first so cleanups run in
+    /// reverse registration order.
+    fn mark_gaps_until(&mut self, end: usize) -> bool {
+        let mut released = Vec::new();
+        while self.cursor < end {
             if let Some(slot) = self.get_slot_mut(self.cursor) {
-                // Convert to gap
                 let anchor = slot.anchor_id();
                 let (group_key, group_scope, group_len) = match slot {
                     ChunkedSlot::Group {
@@ -539,21 +541,52 @@ impl ChunkedSlotStorage {
                     } => (Some(*key), *scope, *len),
                     _ => (None, None, 0),
                 };
-                *slot = ChunkedSlot::Gap {
-                    anchor,
-                    group_key,
-                    group_scope,
-                    group_len,
-                };
-                marked = true;
+                released.push(std::mem::replace(
+                    slot,
+                    ChunkedSlot::Gap {
+                        anchor,
+                        group_key,
+                        group_scope,
+                        group_len,
+                    },
+                ));
             }
             self.cursor += 1;
         }
+        let marked = !released.is_empty();
+        released.into_iter().rev().for_each(drop);
+        marked
+    }
 
-        if let Some(frame) = self.group_stack.last_mut() {
-            frame.end = self.cursor;
+    /// Frames for the groups containing the slot at `index`, outermost first.
+    fn enclosing_group_frames(&self, index: usize) -> Vec<GroupFrame> {
+        let mut frames = Vec::new();
+        let mut position = 0;
+        while position < index {
+            match self.get_slot(position) {
+                Some(ChunkedSlot::Group { key, len, .. }) if position + len >= index => {
+                    frames.push(GroupFrame {
+                        key: *key,
+                        start: position,
+                        end: position + len + 1,
+                        force_children_recompose: false,
+                    });
+                    position += 1;
+                }
+                Some(ChunkedSlot::Group { len, .. }) => position += len + 1,
+                _ => position += 1,
+            }
+        }
+        frames
+    }
+
+    /// Grows the group at `start` to reach at least `end`.
+    fn commit_group_len(&mut self, group_key: Key, start: usize, end: usize) {
+        if let Some(ChunkedSlot::Group { key, len, .. }) = self.get_slot_mut(start) {
+            if *key == group_key {
+                *len = (*len).max(end.saturating_sub(start + 1));
+            }
         }
-        marked
     }
 }
 
@@ -601,21 +634,71 @@ impl SlotStorage for ChunkedSlotStorage {
         nodes
     }
 
-    fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group> {
+    fn current_group_anchor(&self) -> AnchorId {
+        self.group_stack
+            .last()
+            .and_then(|frame| self.get_slot(frame.start))
+            .map(ChunkedSlot::anchor_id)
+            .unwrap_or(AnchorId::INVALID)
+    }
+
+    fn rewind_current_group(&mut self) {
+        if let Some(frame) = self.group_stack.last() {
+            self.cursor = frame.start + 1;
+        }
+    }
+
+    fn group_index_of_scope(&self, scope: ScopeId) -> Option<usize> {
         // Linear scan to find group with this scope
-        for global_idx in 0..self.total_slots() {
-            if let Some(ChunkedSlot::Group { scope: Some(s), .. }) = self.get_slot(global_idx) {
-                if *s == scope {
-                    self.cursor = global_idx;
-                    return Some(GroupId::new(global_idx));
-                }
-            }
+        (0..self.total_slots()).find(|&global_idx| {
+            matches!(
+                self.get_slot(global_idx),
+                Some(ChunkedSlot::Group { scope: Some(s), .. }) if *s == scope
+            )
+        })
+    }
+
+    fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group> {
+        let index = self.group_index_of_scope(scope)?;
+        let Some(ChunkedSlot::Group { key, len, .. }) = self.get_slot(index) else {
+            return None;
+        };
+        let frame = GroupFrame {
+            key: *key,
+            start: index,
+            end: index + len + 1,
+            force_children_recompose: false,
+        };
+        // The groups around this one get frames too, so their lengths cover
+        // whatever the recomposition adds.
+        self.recompose_bases.push(self.group_stack.len());
+        let enclosing = self.enclosing_group_frames(index);
+        self.group_stack.extend(enclosing);
+        self.group_stack.push(frame);
+        // Content resumes after the group's scope slot.
+        self.cursor = index + 1;
+        if matches!(self.get_slot(self.cursor), Some(ChunkedSlot::Value { .. })) {
+            self.cursor += 1;
         }
-        None
+        Some(GroupId::new(index))
     }
 
     fn end_recompose(&mut self) {
-        // No-op for chunked storage
+        let base = self.recompose_bases.pop().unwrap_or(self.group_stack.len());
+        let mut content_end = self.cursor;
+        if self.group_stack.len() > base {
+            if let Some(frame) = self.group_stack.pop() {
+                content_end = content_end.max(frame.end);
+                self.commit_group_len(frame.key, frame.start, content_end);
+                self.cursor = content_end;
+            }
+        }
+        while self.group_stack.len() > base {
+            if let Some(frame) = self.group_stack.pop() {
+                content_end = content_end.max(frame.end);
+                self.commit_group_len(frame.key, frame.start, content_end);
+            }
+        }
     }
 
     fn alloc_value_slot<T: 'static>(&mut self, init: impl FnOnce() -> T) -> Self::ValueSlot {
@@ -699,11 +782,52 @@ impl SlotStorage for ChunkedSlotStorage {
     fn reset(&mut self) {
         self.cursor = 0;
         self.group_stack.clear();
+        self.recompose_bases.clear();
     }
 
     fn flush(&mut self) {
         self.rebuild_anchors();
     }
+
+    fn compact(&mut self) -> usize {
+        if !self.group_stack.is_empty() {
+            return 0;
+        }
+        let before = self.total_slots();
+        let flat: Vec<ChunkedSlot> = std::mem::take(&mut self.chunks)
+            .into_iter()
+            .flatten()
+            .collect();
+        let mut kept = compact_slots(
+            flat,
+            |slot| match slot {
+                ChunkedSlot::Gap { .. } => CompactSlot::Drop,
+                ChunkedSlot::Group { len, .. } => CompactSlot::Group { extent: len + 1 },
+                _ => CompactSlot::Keep,
+            },
+            |slot, extent| {
+                if let ChunkedSlot::Group {
+                    len,
+                    has_gap_children,
+                    ..
+                } = slot
+                {
+                    *len = extent - 1;
+                    *has_gap_children = false;
+                }
+            },
+        );
+        while !kept.is_empty() {
+            let rest = kept.split_off(kept.len().min(CHUNK_SIZE));
+            self.chunks.push(kept);
+            kept = rest;
+        }
+        self.cursor = 0;
+        self.last_start_was_gap = false;
+        self.anchors_dirty = true;
+        self.rebuild_anchors();
+        before - self.total_slots()
+    }
 }
 
 impl ChunkedSlotStorage {
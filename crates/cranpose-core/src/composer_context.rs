@@ -55,3 +55,23 @@ pub fn try_with_composer<R>(f: impl FnOnce(&Composer) -> R) -> Option<R> {
         Some(f(&composer))
     })
 }
+
+/// Runs `f` with no active composer, e.g. for callbacks that are not part of
+/// composing content even though a composer is running.
+pub fn without_composer<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(Vec<Rc<ComposerCore>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let saved = std::mem::take(&mut self.0);
+            COMPOSER_STACK.with(|stack| {
+                let mut stack = stack.borrow_mut();
+                let entered = std::mem::replace(&mut *stack, saved);
+                stack.extend(entered);
+            });
+        }
+    }
+
+    let _restore = Restore(COMPOSER_STACK.with(|stack| std::mem::take(&mut *stack.borrow_mut())));
+    f()
+}
@@ -6,6 +6,21 @@ use std::pin::Pin;
 use std::rc::Rc;
 use std::task::{Context, Poll, Waker};
 
+/// Order in which frame callbacks run within one frame.
+///
+/// Callbacks run from `Layout` to `Draw`; callbacks of equal priority run in
+/// registration order. An animation that drives layout should use `Layout`
+/// so draw-only animations reading the same frame see its new value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FramePriority {
+    /// Updates state that affects measurement or placement.
+    Layout,
+    #[default]
+    Normal,
+    /// Updates state that only affects drawing.
+    Draw,
+}
+
 #[derive(Clone)]
 pub struct FrameClock {
     runtime: RuntimeHandle,
@@ -23,10 +38,20 @@ impl FrameClock {
     pub fn with_frame_nanos(
         &self,
         callback: impl FnOnce(u64) + 'static,
+    ) -> FrameCallbackRegistration {
+        self.with_frame_nanos_with_priority(FramePriority::Normal, callback)
+    }
+
+    /// Like [`with_frame_nanos`](Self::with_frame_nanos), running the
+    /// callback among the next frame's callbacks according to `priority`.
+    pub fn with_frame_nanos_with_priority(
+        &self,
+        priority: FramePriority,
+        callback: impl FnOnce(u64) + 'static,
     ) -> FrameCallbackRegistration {
         let mut callback_opt = Some(callback);
         let runtime = self.runtime.clone();
-        match runtime.register_frame_callback(move |time| {
+        match runtime.register_frame_callback_with_priority(priority, move |time| {
             if let Some(callback) = callback_opt.take() {
                 callback(time);
             }
@@ -46,6 +71,12 @@ impl FrameClock {
         })
     }
 
+    /// Time of the frame being processed, or of the last one; `None` before
+    /// the first frame.
+    pub fn frame_time_nanos(&self) -> Option<u64> {
+        self.runtime.frame_time_nanos()
+    }
+
     pub fn next_frame(&self) -> NextFrame {
         NextFrame::new(self.clone())
     }
@@ -6,7 +6,7 @@
 
 use crate::{
     slot_storage::{GroupId, SlotStorage, StartGroup, ValueSlotId},
-    Key, NodeId, Owned, ScopeId, SlotTable,
+    AnchorId, Key, NodeId, Owned, ScopeId, SlotTable,
 };
 
 /// Hierarchical slot storage implementation.
@@ -51,6 +51,18 @@ impl SlotStorage for HierarchicalSlotStorage {
         SlotStorage::nodes_in_current_group(&self.root)
     }
 
+    fn current_group_anchor(&self) -> AnchorId {
+        SlotStorage::current_group_anchor(&self.root)
+    }
+
+    fn rewind_current_group(&mut self) {
+        SlotStorage::rewind_current_group(&mut self.root);
+    }
+
+    fn group_index_of_scope(&self, scope: ScopeId) -> Option<usize> {
+        SlotStorage::group_index_of_scope(&self.root, scope)
+    }
+
     fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group> {
         SlotStorage::begin_recranpose_at_scope(&mut self.root, scope)
     }
@@ -106,6 +118,10 @@ impl SlotStorage for HierarchicalSlotStorage {
     fn flush(&mut self) {
         SlotStorage::flush(&mut self.root);
     }
+
+    fn compact(&mut self) -> usize {
+        SlotStorage::compact(&mut self.root)
+    }
 }
 
 impl HierarchicalSlotStorage {
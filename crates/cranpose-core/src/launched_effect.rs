@@ -1,4 +1,7 @@
-use crate::{hash_key, with_current_composer, Key, RuntimeHandle, TaskHandle};
+use crate::{
+    hash_key, location_key, with_current_composer, Key, MutableState, RuntimeHandle, State,
+    TaskHandle,
+};
 use std::cell::{Cell, RefCell};
 use std::future::Future;
 use std::hash::Hash;
@@ -7,6 +10,18 @@ use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+/// Controls when a `LaunchedEffect!` body first runs after its keys change.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EffectDispatch {
+    /// Queue the body on the UI task queue; it runs after all side effects of
+    /// the current composition have been applied.
+    #[default]
+    Deferred,
+    /// Run the body synchronously while the composition's side effects are
+    /// applied, so state it sets is visible to later effects in the same pass.
+    Immediate,
+}
+
 #[derive(Default)]
 struct LaunchedEffectState {
     key: Option<Key>,
@@ -38,11 +53,8 @@ impl LaunchedEffectState {
         self.key = Some(key);
     }
 
-    fn launch(
-        &mut self,
-        runtime: RuntimeHandle,
-        effect: impl FnOnce(LaunchedEffectScope) + 'static,
-    ) {
+    /// Cancels the previous run and returns the scope for the next one.
+    fn begin(&mut self, runtime: RuntimeHandle) -> LaunchedEffectScope {
         self.cancel_current();
         let active = Arc::new(AtomicBool::new(true));
         let continuations = Rc::new(RefCell::new(Vec::new()));
@@ -51,12 +63,11 @@ impl LaunchedEffectState {
             active: Arc::clone(&active),
             continuations: Rc::clone(&continuations),
         });
-        let scope = LaunchedEffectScope {
-            active: Arc::clone(&active),
-            runtime: runtime.clone(),
+        LaunchedEffectScope {
+            active,
+            runtime,
             continuations,
-        };
-        runtime.enqueue_ui_task(Box::new(move || effect(scope)));
+        }
     }
 
     fn cancel_current(&mut self) {
@@ -292,7 +303,7 @@ impl CancelToken {
     }
 }
 
-pub fn __launched_effect_impl<K, F>(group_key: Key, keys: K, effect: F)
+pub fn __launched_effect_impl<K, F>(group_key: Key, keys: K, dispatch: EffectDispatch, effect: F)
 where
     K: Hash,
     F: FnOnce(LaunchedEffectScope) + 'static,
@@ -310,7 +321,13 @@ where
                 let mut effect_opt = Some(effect);
                 composer.register_side_effect(move || {
                     if let Some(effect) = effect_opt.take() {
-                        state_for_effect.update(|state| state.launch(runtime.clone(), effect));
+                        let scope = state_for_effect.update(|state| state.begin(runtime.clone()));
+                        match dispatch {
+                            EffectDispatch::Deferred => {
+                                runtime.enqueue_ui_task(Box::new(move || effect(scope)));
+                            }
+                            EffectDispatch::Immediate => effect(scope),
+                        }
                     }
                 });
             }
@@ -324,6 +341,15 @@ macro_rules! LaunchedEffect {
         $crate::__launched_effect_impl(
             $crate::location_key(file!(), line!(), column!()),
             $keys,
+            $crate::EffectDispatch::Deferred,
+            $effect,
+        )
+    };
+    ($keys:expr, $dispatch:expr, $effect:expr) => {
+        $crate::__launched_effect_impl(
+            $crate::location_key(file!(), line!(), column!()),
+            $keys,
+            $dispatch,
             $effect,
         )
     };
@@ -365,3 +391,166 @@ macro_rules! LaunchedEffectAsync {
         )
     };
 }
+
+/// Remembered state behind `rememberCoroutineScope!`.
+struct CoroutineScopeState {
+    cancel: LaunchedEffectCancellation,
+    scope: LaunchedEffectScope,
+}
+
+impl CoroutineScopeState {
+    fn new(runtime: RuntimeHandle) -> Self {
+        let active = Arc::new(AtomicBool::new(true));
+        let continuations = Rc::new(RefCell::new(Vec::new()));
+        Self {
+            cancel: LaunchedEffectCancellation {
+                runtime: runtime.clone(),
+                active: Arc::clone(&active),
+                continuations: Rc::clone(&continuations),
+            },
+            scope: LaunchedEffectScope {
+                active,
+                runtime,
+                continuations,
+            },
+        }
+    }
+}
+
+impl Drop for CoroutineScopeState {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+pub fn __remember_coroutine_scope_impl(group_key: Key) -> LaunchedEffectScope {
+    with_current_composer(|composer| {
+        composer.with_group(group_key, |composer| {
+            let runtime = composer.runtime_handle();
+            let state = composer.remember(|| CoroutineScopeState::new(runtime));
+            state.with(|state| state.scope.clone())
+        })
+    })
+}
+
+/// Returns a [`LaunchedEffectScope`] that lives as long as the calling
+/// composable stays in composition.
+///
+/// Unlike `LaunchedEffect!`, the scope is meant for work started from event
+/// handlers, e.g. a click that kicks off a fetch via
+/// [`LaunchedEffectScope::launch_background`]. When the composable leaves
+/// composition the scope is cancelled: queued tasks and pending background
+/// results are dropped, and anything launched afterwards is a no-op.
+#[macro_export]
+macro_rules! rememberCoroutineScope {
+    () => {
+        $crate::__remember_coroutine_scope_impl($crate::location_key(file!(), line!(), column!()))
+    };
+}
+
+/// Progress of a future started with `rememberAsync!`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AsyncState<T, E> {
+    /// The future is still running (or was restarted by a key change).
+    Loading,
+    /// The future completed with `Ok`.
+    Success(T),
+    /// The future completed with `Err`.
+    Error(E),
+}
+
+impl<T, E> AsyncState<T, E> {
+    pub fn is_loading(&self) -> bool {
+        matches!(self, AsyncState::Loading)
+    }
+
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            AsyncState::Success(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            AsyncState::Error(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for AsyncState<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => AsyncState::Success(value),
+            Err(error) => AsyncState::Error(error),
+        }
+    }
+}
+
+pub fn __remember_async_impl<K, T, E, F>(
+    group_key: Key,
+    keys: K,
+    mk_future: F,
+) -> State<AsyncState<T, E>>
+where
+    K: Hash,
+    T: Clone + 'static,
+    E: Clone + 'static,
+    F: FnOnce(LaunchedEffectScope) -> Pin<Box<dyn Future<Output = Result<T, E>>>> + 'static,
+{
+    with_current_composer(|composer| {
+        composer.with_group(group_key, |composer| {
+            let key_hash = hash_key(&keys);
+            // Keyed so that a key change hands readers a fresh `Loading` state in
+            // the same composition, rather than the previous key's result.
+            let runtime = composer.runtime_handle();
+            let state = composer
+                .remember_keyed(&key_hash, || {
+                    MutableState::with_runtime(AsyncState::Loading, runtime)
+                })
+                .with(|state| *state);
+            let target = state;
+            // The effect cancels the prior task on key change, so a stale
+            // future never gets to publish into the new state.
+            __launched_effect_async_impl(
+                location_key(file!(), line!(), column!()),
+                key_hash,
+                move |scope| {
+                    let future = mk_future(scope.clone());
+                    Box::pin(async move {
+                        let result = future.await;
+                        if scope.is_active() {
+                            target.set_value(result.into());
+                        }
+                    })
+                },
+            );
+            state.as_state()
+        })
+    })
+}
+
+/// Runs a future keyed on `keys` and exposes its outcome as an
+/// [`AsyncState`] that readers observe like any other state.
+///
+/// The state starts as [`AsyncState::Loading`] and flips to `Success` or
+/// `Error` once the future resolves. Changing `keys` cancels the running
+/// future and returns the state to `Loading` until the new one completes.
+///
+/// ```ignore
+/// let user = rememberAsync!(user_id, move |scope| {
+///     Box::pin(async move { fetch_user(scope, user_id).await })
+/// });
+/// if let Some(user) = user.value().value() { ... }
+/// ```
+#[macro_export]
+macro_rules! rememberAsync {
+    ($keys:expr, $future:expr) => {
+        $crate::__remember_async_impl(
+            $crate::location_key(file!(), line!(), column!()),
+            $keys,
+            $future,
+        )
+    };
+}
@@ -8,6 +8,7 @@ mod launched_effect;
 pub mod owned;
 pub mod platform;
 pub mod runtime;
+mod saveable;
 pub mod snapshot_double_index_heap;
 pub mod snapshot_id_set;
 pub mod snapshot_pinning;
@@ -15,19 +16,24 @@ pub mod snapshot_state_observer;
 pub mod snapshot_v2;
 mod snapshot_weak_set;
 mod state;
+pub mod state_history;
 pub mod subcompose;
 
-pub use frame_clock::{FrameCallbackRegistration, FrameClock};
+pub use frame_clock::{FrameCallbackRegistration, FrameClock, FramePriority};
 pub use launched_effect::{
-    CancelToken, LaunchedEffectScope, __launched_effect_async_impl, __launched_effect_impl,
+    __launched_effect_async_impl, __launched_effect_impl, __remember_async_impl,
+    __remember_coroutine_scope_impl, AsyncState, CancelToken, EffectDispatch, LaunchedEffectScope,
 };
 pub use owned::Owned;
 pub use platform::{Clock, RuntimeScheduler};
 pub use runtime::{
     current_runtime_handle, schedule_frame, schedule_node_update, DefaultScheduler, Runtime,
-    RuntimeHandle, StateId, TaskHandle,
+    RuntimeHandle, RuntimeMetrics, StateId, TaskHandle,
 };
+pub use saveable::{Saveable, StateSnapshot, StateSnapshotParseError};
 pub use snapshot_state_observer::SnapshotStateObserver;
+pub use snapshot_v2::without_reads;
+pub use state_history::StateMutation;
 
 /// Runs the provided closure inside a mutable snapshot and applies the result.
 ///
@@ -110,7 +116,8 @@ pub use runtime::{TestRuntime, TestScheduler};
 
 use crate::collections::map::HashMap;
 use crate::collections::map::HashSet;
-use crate::runtime::{runtime_handle_for, RuntimeId};
+use crate::runtime::{expect_runtime_handle, RuntimeId};
+use crate::saveable::{SaveableEntry, SaveableRegistration, SaveableRegistry};
 use crate::state::{NeverEqual, SnapshotMutableState, UpdateScope};
 use std::any::Any;
 use std::cell::{Cell, Ref, RefCell, RefMut};
@@ -173,8 +180,24 @@ pub(crate) struct RecomposeScopeInner {
     force_reuse: Cell<bool>,
     force_recompose: Cell<bool>,
     parent_hint: Cell<Option<NodeId>>,
+    /// The scope that was running when this one's group started.
+    parent_scope: RefCell<Weak<RecomposeScopeInner>>,
+    /// Nodes the group attached to `parent_hint` in its last run, in order;
+    /// `None` when the group ran without a parent node to attach to.
+    top_level_nodes: RefCell<Option<Vec<NodeId>>>,
+    /// `(parent, node)` pairs a scope inside this one emitted while
+    /// recomposing on its own and left for this scope to place.
+    unplaced_nodes: RefCell<Vec<(NodeId, NodeId)>>,
+    /// Set once the scope body has run, so later runs count as recompositions.
+    composed: Cell<bool>,
+    /// Set when the current run skipped the scope body.
+    skipped: Cell<bool>,
+    /// Whether the last finished run skipped the scope body.
+    last_skipped: Cell<bool>,
     recompose: RefCell<Option<RecomposeCallback>>,
     local_stack: RefCell<Vec<LocalContext>>,
+    /// Derived states whose recomputation invalidated this scope.
+    derivation_cause: RefCell<Vec<DerivationSite>>,
 }
 
 impl RecomposeScopeInner {
@@ -189,12 +212,25 @@ impl RecomposeScopeInner {
             force_reuse: Cell::new(false),
             force_recompose: Cell::new(false),
             parent_hint: Cell::new(None),
+            parent_scope: RefCell::new(Weak::new()),
+            top_level_nodes: RefCell::new(None),
+            unplaced_nodes: RefCell::new(Vec::new()),
+            composed: Cell::new(false),
+            skipped: Cell::new(false),
+            last_skipped: Cell::new(false),
             recompose: RefCell::new(None),
             local_stack: RefCell::new(Vec::new()),
+            derivation_cause: RefCell::new(Vec::new()),
         }
     }
 }
 
+impl Drop for RecomposeScopeInner {
+    fn drop(&mut self) {
+        self.runtime.forget_scope_recompositions(self.id);
+    }
+}
+
 type RecomposeCallback = Box<dyn FnMut(&Composer) + 'static>;
 
 #[derive(Clone)]
@@ -229,8 +265,21 @@ impl RecomposeScope {
         self.inner.active.get()
     }
 
+    /// Whether the last run of this scope skipped its body because its
+    /// parameters were unchanged. Composables that cannot skip, such as
+    /// those taking `impl Trait` parameters, always report `false`.
+    pub fn last_skipped(&self) -> bool {
+        self.inner.last_skipped.get()
+    }
+
     fn invalidate(&self) {
         self.inner.invalid.set(true);
+        DERIVATION_CHAIN.with(|chain| {
+            let chain = chain.borrow();
+            if !chain.is_empty() {
+                *self.inner.derivation_cause.borrow_mut() = chain.clone();
+            }
+        });
         if !self.inner.active.get() {
             return;
         }
@@ -241,8 +290,20 @@ impl RecomposeScope {
         }
     }
 
+    /// Queues the scope again after the pass that took it from the queue
+    /// stopped before recomposing it.
+    fn requeue(&self) {
+        if self.inner.enqueued.get() {
+            self.inner.runtime.mark_scope_recomposed(self.inner.id);
+            self.inner
+                .runtime
+                .register_invalid_scope(self.inner.id, self.downgrade());
+        }
+    }
+
     fn mark_recomposed(&self) {
         self.inner.invalid.set(false);
+        self.inner.derivation_cause.borrow_mut().clear();
         self.inner.force_reuse.set(false);
         self.inner.force_recompose.set(false);
         if self.inner.enqueued.replace(false) {
@@ -258,6 +319,20 @@ impl RecomposeScope {
         }
     }
 
+    /// Records a finished run of the scope body. The first composition and
+    /// skipped runs are not recompositions.
+    fn finish_run(&self) {
+        let skipped = self.inner.skipped.replace(false);
+        self.inner.last_skipped.set(skipped);
+        if self.inner.composed.replace(true) && !skipped {
+            self.inner.runtime.record_scope_recomposition(self.inner.id);
+        }
+    }
+
+    fn take_derivation_cause(&self) -> Vec<DerivationSite> {
+        std::mem::take(&mut *self.inner.derivation_cause.borrow_mut())
+    }
+
     fn downgrade(&self) -> Weak<RecomposeScopeInner> {
         Rc::downgrade(&self.inner)
     }
@@ -266,12 +341,37 @@ impl RecomposeScope {
         *self.inner.recompose.borrow_mut() = Some(callback);
     }
 
-    fn run_recompose(&self, composer: &Composer) {
-        let mut callback_cell = self.inner.recompose.borrow_mut();
-        if let Some(mut callback) = callback_cell.take() {
-            drop(callback_cell);
+    /// Runs the recompose callback, returning whether the scope had one.
+    ///
+    /// A callback that panics is put back, so the scope can recompose again
+    /// once whatever made it panic has changed.
+    fn run_recompose(&self, composer: &Composer) -> bool {
+        struct Restore<'a> {
+            scope: &'a RecomposeScopeInner,
+            callback: Option<RecomposeCallback>,
+        }
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                if std::thread::panicking() {
+                    let mut installed = self.scope.recompose.borrow_mut();
+                    if installed.is_none() {
+                        *installed = self.callback.take();
+                    }
+                }
+            }
+        }
+
+        let Some(callback) = self.inner.recompose.borrow_mut().take() else {
+            return false;
+        };
+        let mut running = Restore {
+            scope: &self.inner,
+            callback: Some(callback),
+        };
+        if let Some(callback) = running.callback.as_mut() {
             callback(composer);
         }
+        true
     }
 
     fn snapshot_locals(&self, stack: &[LocalContext]) {
@@ -290,6 +390,34 @@ impl RecomposeScope {
         self.inner.parent_hint.get()
     }
 
+    fn set_parent_scope(&self, parent: Option<&RecomposeScope>) {
+        *self.inner.parent_scope.borrow_mut() = parent.map_or_else(Weak::new, Self::downgrade);
+    }
+
+    /// The nearest enclosing scope that can recompose on its own.
+    fn restartable_parent(&self) -> Option<RecomposeScope> {
+        let mut current = self.inner.parent_scope.borrow().upgrade();
+        while let Some(inner) = current {
+            if inner.recompose.borrow().is_some() {
+                return Some(RecomposeScope { inner });
+            }
+            current = inner.parent_scope.borrow().upgrade();
+        }
+        None
+    }
+
+    fn replace_top_level_nodes(&self, nodes: Option<Vec<NodeId>>) -> Option<Vec<NodeId>> {
+        self.inner.top_level_nodes.replace(nodes)
+    }
+
+    fn take_unplaced_nodes(&self) -> Vec<(NodeId, NodeId)> {
+        std::mem::take(&mut *self.inner.unplaced_nodes.borrow_mut())
+    }
+
+    fn add_unplaced_nodes(&self, nodes: impl IntoIterator<Item = (NodeId, NodeId)>) {
+        self.inner.unplaced_nodes.borrow_mut().extend(nodes);
+    }
+
     pub fn deactivate(&self) {
         if !self.inner.active.replace(false) {
             return;
@@ -349,10 +477,29 @@ pub struct RecomposeOptions {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NodeError {
-    Missing { id: NodeId },
-    TypeMismatch { id: NodeId, expected: &'static str },
-    MissingContext { id: NodeId, reason: &'static str },
-    AlreadyExists { id: NodeId },
+    Missing {
+        id: NodeId,
+    },
+    TypeMismatch {
+        id: NodeId,
+        expected: &'static str,
+    },
+    MissingContext {
+        id: NodeId,
+        reason: &'static str,
+    },
+    AlreadyExists {
+        id: NodeId,
+    },
+    /// Composable code panicked; `scope_id` is the innermost scope running.
+    ///
+    /// Only reported when panics unwind. With `panic = "abort"`, or on
+    /// targets that always abort such as `wasm32-unknown-unknown`, the panic
+    /// ends the process instead.
+    Composition {
+        scope_id: Option<ScopeId>,
+        message: String,
+    },
 }
 
 impl std::fmt::Display for NodeError {
@@ -368,6 +515,14 @@ impl std::fmt::Display for NodeError {
             NodeError::AlreadyExists { id } => {
                 write!(f, "node {id} already exists")
             }
+            NodeError::Composition {
+                scope_id: Some(scope),
+                message,
+            } => write!(f, "composition panicked in scope {scope}: {message}"),
+            NodeError::Composition {
+                scope_id: None,
+                message,
+            } => write!(f, "composition panicked: {message}"),
         }
     }
 }
@@ -409,6 +564,24 @@ pub fn remember<T: 'static>(init: impl FnOnce() -> T) -> Owned<T> {
     with_current_composer(|composer| composer.remember(init))
 }
 
+/// Like [`remember`], but re-runs `init` whenever `keys` hash differently from
+/// the previous composition, discarding the old value.
+///
+/// Mirrors Jetpack Compose's `remember(key1, key2) { ... }`.
+pub fn remember_keyed<K: Hash, T: 'static>(keys: K, init: impl FnOnce() -> T) -> Owned<T> {
+    with_current_composer(|composer| composer.remember_keyed(&keys, init))
+}
+
+/// Like [`useState`], but the value is registered under its group anchor so
+/// [`Composition::export_state`] can save it and [`Composition::import_state`]
+/// can restore it into a rebuilt composition.
+#[allow(non_snake_case)]
+pub fn rememberSaveable<T: Saveable + Clone + 'static>(
+    init: impl FnOnce() -> T,
+) -> MutableState<T> {
+    with_current_composer(|composer| composer.remember_saveable(init))
+}
+
 /// Returns a [`MutableState`] that always holds the latest value.
 ///
 /// The state **reference** is stable across recompositions; only the **value** updates.
@@ -465,6 +638,124 @@ pub fn withFrameMillis(callback: impl FnOnce(u64) + 'static) -> FrameCallbackReg
     })
 }
 
+/// Returns a state that follows `source` once it has stayed unchanged for
+/// `delay_millis`, timed on the frame clock.
+///
+/// Every change to `source` restarts the wait, so a burst of changes yields a
+/// single update carrying the last value. Typical use is debouncing text input
+/// before starting a search.
+#[allow(non_snake_case)]
+pub fn rememberDebouncedState<T: Clone + 'static>(source: State<T>, delay_millis: u64) -> State<T> {
+    let debouncer = remember(|| RefCell::new(None::<Debouncer<T>>));
+    debouncer.with(|slot| {
+        let mut slot = slot.borrow_mut();
+        let current = slot.as_ref().filter(|debouncer| {
+            debouncer.source == source && debouncer.delay_millis == delay_millis
+        });
+        match current {
+            Some(debouncer) => debouncer.output.as_state(),
+            None => {
+                let clock =
+                    with_current_composer(|composer| composer.runtime_handle().frame_clock());
+                let debouncer = Debouncer::new(clock, source, delay_millis);
+                let output = debouncer.output.as_state();
+                *slot = Some(debouncer);
+                output
+            }
+        }
+    })
+}
+
+/// State behind [`rememberDebouncedState`]; dropping it cancels the pending wait.
+struct Debouncer<T: Clone + 'static> {
+    source: State<T>,
+    delay_millis: u64,
+    output: MutableState<T>,
+    _wait: Rc<RefCell<DebounceWait<T>>>,
+    _subscription: Subscription,
+}
+
+/// The latest change of the source, waiting to be published.
+struct DebounceWait<T> {
+    value: Option<T>,
+    /// Frame time when the source changed; `None` if it changed before the
+    /// first frame, in which case the wait starts at the next one.
+    changed_at_nanos: Option<u64>,
+    registration: Option<FrameCallbackRegistration>,
+}
+
+impl<T: Clone + 'static> Debouncer<T> {
+    fn new(clock: FrameClock, source: State<T>, delay_millis: u64) -> Self {
+        let output = MutableState::with_runtime(source.peek(), clock.runtime_handle());
+        let wait = Rc::new(RefCell::new(DebounceWait {
+            value: None,
+            changed_at_nanos: None,
+            registration: None,
+        }));
+        let subscription = {
+            let wait = Rc::clone(&wait);
+            source.observe(move |value| {
+                let mut pending = wait.borrow_mut();
+                pending.value = Some(value.clone());
+                // Observers are delivered while the host processes a frame,
+                // so the frame time is when the change happened.
+                pending.changed_at_nanos = clock.frame_time_nanos();
+                if pending.registration.is_none() {
+                    pending.registration = Some(schedule_debounce(
+                        &clock,
+                        Rc::downgrade(&wait),
+                        delay_millis,
+                        output,
+                    ));
+                }
+            })
+        };
+        Self {
+            source,
+            delay_millis,
+            output,
+            _wait: wait,
+            _subscription: subscription,
+        }
+    }
+}
+
+/// Publishes the pending value on the first frame at least `delay_millis`
+/// after the latest change, re-arming itself when it runs before then.
+/// Changes in the meantime only move the deadline.
+fn schedule_debounce<T: Clone + 'static>(
+    clock: &FrameClock,
+    wait: Weak<RefCell<DebounceWait<T>>>,
+    delay_millis: u64,
+    output: MutableState<T>,
+) -> FrameCallbackRegistration {
+    let next_clock = clock.clone();
+    clock.with_frame_nanos(move |frame_time_nanos| {
+        let Some(wait) = wait.upgrade() else {
+            return;
+        };
+        let value = {
+            let mut pending = wait.borrow_mut();
+            let changed_at = *pending.changed_at_nanos.get_or_insert(frame_time_nanos);
+            if frame_time_nanos.saturating_sub(changed_at) < delay_millis.saturating_mul(1_000_000)
+            {
+                pending.registration = Some(schedule_debounce(
+                    &next_clock,
+                    Rc::downgrade(&wait),
+                    delay_millis,
+                    output,
+                ));
+                return;
+            }
+            pending.registration = None;
+            pending.value.take()
+        };
+        if let Some(value) = value {
+            output.set(value);
+        }
+    })
+}
+
 #[allow(non_snake_case)]
 pub fn mutableStateOf<T: Clone + 'static>(initial: T) -> MutableState<T> {
     // Get runtime handle from current composer if available, otherwise from global registry.
@@ -536,7 +827,30 @@ pub fn use_state<T: Clone + 'static>(init: impl FnOnce() -> T) -> MutableState<T
 }
 
 #[allow(non_snake_case)]
+#[track_caller]
 pub fn derivedStateOf<T: 'static + Clone>(compute: impl Fn() -> T + 'static) -> State<T> {
+    derived_state_with_policy(compute, None)
+}
+
+/// Like [`derivedStateOf`], but readers are only invalidated when the derived
+/// value changes according to `PartialEq`.
+///
+/// Use this when the computation frequently produces a result equal to the
+/// previous one, so readers don't recompose for no visible change.
+#[allow(non_snake_case)]
+#[track_caller]
+pub fn derivedStateOfStructural<T: 'static + Clone + PartialEq>(
+    compute: impl Fn() -> T + 'static,
+) -> State<T> {
+    derived_state_with_policy(compute, Some(|a: &T, b: &T| a == b))
+}
+
+#[track_caller]
+fn derived_state_with_policy<T: 'static + Clone>(
+    compute: impl Fn() -> T + 'static,
+    equivalent: Option<fn(&T, &T) -> bool>,
+) -> State<T> {
+    let location = std::panic::Location::caller();
     with_current_composer(|composer| {
         let key = location_key(file!(), line!(), column!());
         composer.with_group(key, |composer| {
@@ -546,19 +860,101 @@ pub fn derivedStateOf<T: 'static + Clone>(compute: impl Fn() -> T + 'static) ->
                 .unwrap_or(true);
             let runtime = composer.runtime_handle();
             let compute_rc: Rc<dyn Fn() -> T> = Rc::new(compute); // FUTURE(no_std): replace Rc with arena-managed callbacks.
-            let derived =
-                composer.remember(|| DerivedState::new(runtime.clone(), compute_rc.clone()));
+            let derived = composer.remember(|| {
+                DerivedState::new(runtime.clone(), compute_rc.clone(), equivalent, location)
+            });
             derived.update(|derived| {
                 derived.set_compute(compute_rc.clone());
                 if should_recompute {
-                    derived.recompute();
+                    derived.recompute(Vec::new());
                 }
             });
+            install_derived_recompose(composer, derived.clone());
             derived.with(|derived| derived.state.as_state())
         })
     })
 }
 
+/// Recomputes the derived value when one of its inputs invalidates the
+/// enclosing group, without requiring the caller's scope to recompose.
+fn install_derived_recompose<T: 'static + Clone>(
+    composer: &Composer,
+    derived: Owned<DerivedState<T>>,
+) {
+    composer.set_recranpose_callback(move |composer| {
+        let cause = composer
+            .current_recranpose_scope()
+            .map(|scope| scope.take_derivation_cause())
+            .unwrap_or_default();
+        derived.with(|derived| derived.recompute(cause));
+        // The group's slots are not walked again; step over them so they are
+        // kept rather than released as content the run no longer emits.
+        composer.with_slots_mut(|slots| slots.skip_current_group());
+        install_derived_recompose(composer, derived.clone());
+    });
+}
+
+/// A `derivedStateOf` call site on a chain of derived recomputations.
+#[derive(Clone, Copy)]
+struct DerivationSite {
+    id: usize,
+    location: &'static std::panic::Location<'static>,
+}
+
+thread_local! {
+    /// Derived states whose recomputations led to the one currently running,
+    /// outermost first. Scopes invalidated while it is non-empty remember it.
+    static DERIVATION_CHAIN: RefCell<Vec<DerivationSite>> = const { RefCell::new(Vec::new()) };
+}
+
+static NEXT_DERIVATION_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Puts back the enclosing derivation chain when dropped, so a panicking
+/// recomputation does not leave its chain behind.
+#[must_use = "the chain is restored when the guard is dropped"]
+struct DerivationChainGuard {
+    outer: Vec<DerivationSite>,
+}
+
+impl DerivationChainGuard {
+    fn enter(chain: Vec<DerivationSite>) -> Self {
+        let outer = DERIVATION_CHAIN.with(|current| current.replace(chain));
+        Self { outer }
+    }
+}
+
+impl Drop for DerivationChainGuard {
+    fn drop(&mut self) {
+        let outer = std::mem::take(&mut self.outer);
+        DERIVATION_CHAIN.with(|current| *current.borrow_mut() = outer);
+    }
+}
+
+/// Diagnostic for a derived state whose recomputation, through the derived
+/// states it invalidated, ended up invalidating itself again.
+///
+/// The recomputation that would close the loop is skipped, so the derived
+/// value stays at its last result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivedStateCycle {
+    /// `derivedStateOf` call sites forming the cycle, starting with the one
+    /// that was re-entered.
+    pub locations: Vec<&'static std::panic::Location<'static>>,
+}
+
+impl fmt::Display for DerivedStateCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "derived state cycle: ")?;
+        for location in &self.locations {
+            write!(f, "{location} -> ")?;
+        }
+        match self.locations.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
 pub struct ProvidedValue {
     key: LocalKey,
     #[allow(clippy::type_complexity)] // Closure returns trait object for flexible local values
@@ -566,11 +962,9 @@ pub struct ProvidedValue {
 }
 
 impl ProvidedValue {
-    fn into_entry(self, composer: &Composer) -> (LocalKey, Rc<dyn Any>) {
+    fn entry(&self, composer: &Composer) -> (LocalKey, Rc<dyn Any>) {
         // FUTURE(no_std): avoid Rc allocation per entry.
-        let ProvidedValue { key, apply } = self;
-        let entry = apply(composer);
-        (key, entry)
+        (self.key, (self.apply)(composer))
     }
 }
 
@@ -658,6 +1052,13 @@ impl<T: Clone + 'static> CompositionLocal<T> {
         with_current_composer(|composer| composer.read_composition_local(self))
     }
 
+    /// Like [`current`](Self::current), but falls back to the default value
+    /// when called outside of composition.
+    pub fn current_or_default(&self) -> T {
+        with_current_composer_opt(|composer| composer.read_composition_local(self))
+            .unwrap_or_else(|| self.default_value())
+    }
+
     pub fn default_value(&self) -> T {
         (self.default)()
     }
@@ -731,6 +1132,20 @@ pub fn staticCompositionLocalOf<T: Clone + 'static>(
     }
 }
 
+/// Slot payload for [`remember_keyed`]: the remembered value and the keys hash
+/// it was created for.
+struct KeyedRemembered<T> {
+    key: Key,
+    value: Owned<T>,
+}
+
+/// Slot payload for [`rememberSaveable`]: the state plus its registry entry,
+/// which is removed when the slot is dropped.
+struct SaveableSlot<T: Clone + 'static> {
+    state: MutableState<T>,
+    _registration: SaveableRegistration,
+}
+
 #[derive(Default)]
 struct DisposableEffectState {
     key: Option<Key>,
@@ -755,7 +1170,8 @@ impl DisposableEffectState {
 
     fn run_cleanup(&mut self) {
         if let Some(cleanup) = self.cleanup.take() {
-            cleanup();
+            // Cleanups run while slots are released; they do not compose.
+            composer_context::without_composer(cleanup);
         }
     }
 }
@@ -852,6 +1268,45 @@ pub fn pop_parent() {
     with_current_composer(|composer| composer.pop_parent());
 }
 
+/// Indices into `values` forming one longest strictly increasing subsequence.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+    // `tails[k]` is the index of the smallest tail of an increasing run of length k + 1.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = vec![usize::MAX; values.len()];
+    for (index, &value) in values.iter().enumerate() {
+        let rank = tails.partition_point(|&tail| values[tail] < value);
+        if rank > 0 {
+            predecessors[index] = tails[rank - 1];
+        }
+        if rank == tails.len() {
+            tails.push(index);
+        } else {
+            tails[rank] = index;
+        }
+    }
+    let mut run = Vec::with_capacity(tails.len());
+    let mut cursor = tails.last().copied().unwrap_or(usize::MAX);
+    while cursor != usize::MAX {
+        run.push(cursor);
+        cursor = predecessors[cursor];
+    }
+    run.reverse();
+    run
+}
+
+/// Whether `COMPOSE_DEBUG` tracing is enabled; the environment is read once per process.
+fn compose_debug_enabled() -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        static ENV_DEBUG: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+        *ENV_DEBUG.get_or_init(|| std::env::var_os("COMPOSE_DEBUG").is_some())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        false
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Public SlotStorage trait and newtypes
 // ═══════════════════════════════════════════════════════════════════════════
@@ -874,7 +1329,16 @@ pub use slot_table::SlotTable;
 
 pub trait Node: Any {
     fn mount(&mut self) {}
+    /// Called after the node is created and, for reused nodes, whenever
+    /// [`Node::needs_update`] reports changed inputs.
     fn update(&mut self) {}
+    /// Whether the node's inputs changed since its last `update()`.
+    /// Nodes that override `update()` should keep a dirty flag, set it only
+    /// when a modifier or parameter actually changes and clear it in
+    /// `update()`; reused nodes are not updated while this returns false.
+    fn needs_update(&self) -> bool {
+        false
+    }
     fn unmount(&mut self) {}
     fn insert_child(&mut self, _child: NodeId) {}
     fn remove_child(&mut self, _child: NodeId) {}
@@ -883,6 +1347,11 @@ pub trait Node: Any {
     fn children(&self) -> Vec<NodeId> {
         Vec::new()
     }
+    /// Whether `child` is one of this node's children. Nodes that store
+    /// their children should answer without building [`children`](Self::children).
+    fn has_child(&self, child: NodeId) -> bool {
+        self.children().contains(&child)
+    }
     /// Called after the node is created to record its own ID.
     /// Useful for nodes that need to store their ID for later operations.
     fn set_node_id(&mut self, _id: NodeId) {}
@@ -1472,15 +1941,21 @@ impl<'a, A: Applier + 'static> DerefMut for ApplierGuard<'a, A> {
 
 pub struct SlotsHost {
     inner: RefCell<SlotBackend>,
+    saveables: Rc<SaveableRegistry>,
 }
 
 impl SlotsHost {
     pub fn new(storage: SlotBackend) -> Self {
         Self {
             inner: RefCell::new(storage),
+            saveables: Rc::default(),
         }
     }
 
+    pub(crate) fn saveables(&self) -> &Rc<SaveableRegistry> {
+        &self.saveables
+    }
+
     pub fn borrow(&self) -> Ref<'_, SlotBackend> {
         self.inner.borrow()
     }
@@ -1510,7 +1985,9 @@ pub(crate) struct ComposerCore {
     pending_scope_options: RefCell<Option<RecomposeOptions>>,
     phase: Cell<Phase>,
     last_node_reused: Cell<Option<bool>>,
-    recranpose_parent_hint: Cell<Option<NodeId>>,
+    /// Nodes reused since the commands were last taken; they get an
+    /// `update()` command only if their inputs changed.
+    reused_nodes: RefCell<Vec<NodeId>>,
     _not_send: PhantomData<*const ()>,
 }
 
@@ -1554,7 +2031,7 @@ impl ComposerCore {
             pending_scope_options: RefCell::new(None),
             phase: Cell::new(Phase::Compose),
             last_node_reused: Cell::new(None),
-            recranpose_parent_hint: Cell::new(None),
+            reused_nodes: RefCell::new(Vec::new()),
             _not_send: PhantomData,
         }
     }
@@ -1689,6 +2166,15 @@ impl Composer {
         applier.insert_with_id(node_id, node)
     }
 
+    /// Removes a virtual node and the children it owns from the Applier.
+    ///
+    /// This is used by SubcomposeLayoutNode to dispose precomposed slots that
+    /// were cancelled or exceeded the precompose budget.
+    pub fn remove_virtual_node(&self, node_id: NodeId) -> Result<(), NodeError> {
+        let mut applier = self.borrow_applier();
+        applier.remove(node_id)
+    }
+
     /// Checks if a node has no parent (is a root node).
     /// Used by SubcomposeMeasureScope to filter subcompose results.
     pub fn node_has_no_parent(&self, node_id: NodeId) -> bool {
@@ -1769,6 +2255,7 @@ impl Composer {
 
         {
             let mut stack = self.scope_stack();
+            scope_ref.set_parent_scope(stack.last());
             stack.push(scope_ref.clone());
         }
 
@@ -1788,7 +2275,10 @@ impl Composer {
             scope_ref.set_parent_hint(parent_hint);
         }
 
+        let children_mark = self.children_mark();
         let result = self.observe_scope(&scope_ref, || f(self));
+        self.record_top_level_nodes(&scope_ref, children_mark);
+        self.remove_unplaced_nodes(&scope_ref);
 
         let trimmed = self.with_slots_mut(|slots| slots.finalize_current_group());
         if trimmed {
@@ -1799,6 +2289,7 @@ impl Composer {
             let mut stack = self.scope_stack();
             stack.pop();
         }
+        scope_ref.finish_run();
         scope_ref.mark_recomposed();
         self.with_slots_mut(|slots| slots.end_group());
         result
@@ -1819,10 +2310,263 @@ impl Composer {
         self.with_group(hashed, f)
     }
 
+    /// Runs `f` in a group keyed by `key`, containing any panic raised while
+    /// composing it.
+    ///
+    /// On panic the partially composed group is rolled back: its slots become
+    /// gaps, the nodes it recorded are removed from the tree and the commands
+    /// and side effects it queued are dropped, leaving the composer ready to
+    /// continue with the group's siblings. The panic payload is returned.
+    ///
+    /// Containing a panic relies on unwinding. Built with `panic = "abort"`,
+    /// or for targets that always abort such as `wasm32-unknown-unknown`, a
+    /// panic in `f` ends the process before this returns.
+    pub fn with_group_catching<R>(
+        &self,
+        key: Key,
+        f: impl FnOnce(&Composer) -> R,
+    ) -> Result<R, Box<dyn Any + Send>> {
+        self.group_catching(key, f).map_err(|panic| panic.payload)
+    }
+
+    /// [`with_group_catching`](Self::with_group_catching), also reporting the
+    /// innermost scope that was composing when the panic was raised.
+    fn group_catching<R>(
+        &self,
+        key: Key,
+        f: impl FnOnce(&Composer) -> R,
+    ) -> Result<R, CompositionPanic> {
+        let checkpoint = self.checkpoint();
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.with_group(key, f))).map_err(
+            |payload| CompositionPanic {
+                scope_id: self.discard_failed_group(checkpoint, false),
+                payload,
+            },
+        )
+    }
+
+    /// Recomposes `scope`, rolling its group back as
+    /// [`with_group_catching`](Self::with_group_catching) does if it panics.
+    fn recranpose_group_catching(&self, scope: &RecomposeScope) -> Result<(), CompositionPanic> {
+        let checkpoint = self.checkpoint();
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.recranpose_group(scope)
+        }))
+        .map_err(|payload| CompositionPanic {
+            scope_id: self.discard_failed_group(checkpoint, true),
+            payload,
+        })
+    }
+
+    /// Where the next node attached to the current parent will go: the
+    /// depth of the parent stack and the parent's child count so far.
+    fn children_mark(&self) -> Option<(usize, usize)> {
+        let stack = self.parent_stack();
+        stack
+            .last()
+            .map(|frame| (stack.len(), frame.new_children.len()))
+    }
+
+    /// Records the nodes attached to the current parent since `mark` as the
+    /// scope's top-level nodes, reusing the scope's buffer.
+    fn record_top_level_nodes(&self, scope: &RecomposeScope, mark: Option<(usize, usize)>) {
+        let stack = self.parent_stack();
+        let nodes = mark.and_then(|(depth, start)| {
+            if stack.len() != depth {
+                return None;
+            }
+            stack.last()?.new_children.get(start..)
+        });
+        let mut recorded = scope.inner.top_level_nodes.borrow_mut();
+        match nodes {
+            Some(nodes) => {
+                let recorded = recorded.get_or_insert_with(Vec::new);
+                recorded.clear();
+                recorded.extend_from_slice(nodes);
+            }
+            None => *recorded = None,
+        }
+    }
+
+    fn checkpoint(&self) -> GroupCheckpoint {
+        let (parent_depth, parent_children) = {
+            let stack = self.parent_stack();
+            (
+                stack.len(),
+                stack.last().map_or(0, |frame| frame.new_children.len()),
+            )
+        };
+        let (subcompose_nodes, subcompose_scopes) = self
+            .subcompose_stack()
+            .last()
+            .map_or((0, 0), |frame| (frame.nodes.len(), frame.scopes.len()));
+        GroupCheckpoint {
+            snapshot: snapshot_v2::current_snapshot(),
+            scope_depth: self.scope_stack().len(),
+            parent_depth,
+            parent_children,
+            subcompose_nodes,
+            subcompose_scopes,
+            local_depth: self.local_stack().len(),
+            commands: self.commands_mut().len(),
+            side_effects: self.side_effects_mut().len(),
+        }
+    }
+
+    /// Unwinds the composer to `checkpoint` after the group started right after
+    /// it panicked. Groups the panic left open are closed, the failed group's
+    /// body is turned into gaps so none of its scopes can be recomposed again,
+    /// and the nodes it recorded are queued for removal. `recomposing` tells
+    /// that the failed group was entered by recomposing its scope.
+    ///
+    /// Returns the innermost scope that was open when the panic was raised.
+    fn discard_failed_group(
+        &self,
+        checkpoint: GroupCheckpoint,
+        recomposing: bool,
+    ) -> Option<ScopeId> {
+        snapshot_v2::set_current_snapshot(checkpoint.snapshot);
+        let open_scopes: Vec<RecomposeScope> =
+            self.scope_stack().drain(checkpoint.scope_depth..).collect();
+        let nodes = if open_scopes.is_empty() {
+            Vec::new()
+        } else {
+            self.with_slots_mut(|slots| {
+                for _ in 1..open_scopes.len() {
+                    slots.finalize_current_group();
+                    slots.end_group();
+                }
+                let nodes = slots.nodes_in_current_group();
+                slots.rewind_current_group();
+                // Keep the group's own scope slot, leaving it shaped like a group
+                // whose body emitted nothing.
+                slots.remember(|| RecomposeScope::new(self.runtime_handle()));
+                slots.finalize_current_group();
+                if recomposing {
+                    slots.end_recompose();
+                } else {
+                    slots.end_group();
+                }
+                nodes
+            })
+        };
+        for scope in &open_scopes {
+            scope.deactivate();
+        }
+        let failed_scope = open_scopes.last().map(RecomposeScope::id);
+
+        {
+            let mut stack = self.parent_stack();
+            stack.truncate(checkpoint.parent_depth);
+            if let Some(frame) = stack.last_mut() {
+                frame.new_children.truncate(checkpoint.parent_children);
+            }
+        }
+        if let Some(frame) = self.subcompose_stack().last_mut() {
+            frame.nodes.truncate(checkpoint.subcompose_nodes);
+            frame.scopes.truncate(checkpoint.subcompose_scopes);
+        }
+        self.local_stack().truncate(checkpoint.local_depth);
+        self.side_effects_mut().truncate(checkpoint.side_effects);
+        self.pending_scope_options().take();
+
+        let mut commands = self.commands_mut();
+        commands.truncate(checkpoint.commands);
+        if nodes.is_empty() {
+            return failed_scope;
+        }
+        let discarded: HashSet<NodeId> = nodes.iter().copied().collect();
+        let runtime = self.runtime_handle();
+        commands.push(Box::new(move |applier: &mut dyn Applier| {
+            // Nodes created by the failed group were never inserted into their
+            // parents, so each one is removed explicitly; only links to parents
+            // outside the group need detaching.
+            for &id in &nodes {
+                let parent = match applier.get_mut(id) {
+                    Ok(node) => node.parent(),
+                    Err(_) => continue,
+                };
+                if let Some(parent_id) = parent.filter(|parent| !discarded.contains(parent)) {
+                    if let Ok(parent_node) = applier.get_mut(parent_id) {
+                        parent_node.remove_child(id);
+                    }
+                    bubble_layout_dirty(applier, parent_id);
+                    bubble_measure_dirty(applier, parent_id);
+                }
+                if let Ok(node) = applier.get_mut(id) {
+                    node.on_removed_from_parent();
+                    node.unmount();
+                }
+                if applier.remove(id).is_ok() {
+                    runtime.record_node_removed();
+                }
+            }
+            Ok(())
+        }));
+        failed_scope
+    }
+
     pub fn remember<T: 'static>(&self, init: impl FnOnce() -> T) -> Owned<T> {
         self.with_slots_mut(|slots| slots.remember(init))
     }
 
+    pub fn remember_keyed<K: Hash, T: 'static>(
+        &self,
+        keys: &K,
+        init: impl FnOnce() -> T,
+    ) -> Owned<T> {
+        let key = hash_key(keys);
+        let mut init = Some(init);
+        let entry = self.remember(|| KeyedRemembered {
+            key,
+            value: Owned::new((init.take().expect("init consumed"))()),
+        });
+        if let Some(init) = init {
+            if entry.with(|entry| entry.key != key) {
+                let value = Owned::new(init());
+                entry.update(|entry| {
+                    entry.key = key;
+                    entry.value = value;
+                });
+            }
+        }
+        entry.with(|entry| entry.value.clone())
+    }
+
+    pub fn remember_saveable<T: Saveable + Clone + 'static>(
+        &self,
+        init: impl FnOnce() -> T,
+    ) -> MutableState<T> {
+        self.with_key(&"rememberSaveable", |composer| {
+            let registry = Rc::clone(composer.active_slots_host().saveables());
+            let anchor = composer.with_slots(|slots| slots.current_group_anchor());
+            let runtime = composer.runtime_handle();
+            composer
+                .remember(|| {
+                    let type_name = std::any::type_name::<T>();
+                    let initial = registry
+                        .take_pending(anchor, type_name)
+                        .and_then(|saved| T::restore(&saved))
+                        .unwrap_or_else(init);
+                    let state = MutableState::with_runtime(initial, runtime);
+                    let entry = SaveableEntry::new(
+                        type_name,
+                        move || state.get_non_reactive().save(),
+                        move |saved| {
+                            if let Some(value) = T::restore(saved) {
+                                state.set(value);
+                            }
+                        },
+                    );
+                    SaveableSlot {
+                        state,
+                        _registration: registry.register(anchor, entry),
+                    }
+                })
+                .with(|slot| slot.state)
+        })
+    }
+
     pub fn use_value_slot<T: 'static>(&self, init: impl FnOnce() -> T) -> usize {
         self.with_slots_mut(|slots| slots.alloc_value_slot(init).index())
     }
@@ -1877,7 +2621,8 @@ impl Composer {
                 return typed.value();
             }
         }
-        local.default_value()
+        self.runtime_handle()
+            .composition_local_default(local.key, || local.default_value())
     }
 
     pub fn read_static_composition_local<T: Clone + 'static>(
@@ -1894,7 +2639,8 @@ impl Composer {
                 return typed.value();
             }
         }
-        local.default_value()
+        self.runtime_handle()
+            .composition_local_default(local.key, || local.default_value())
     }
 
     pub fn current_recranpose_scope(&self) -> Option<RecomposeScope> {
@@ -1949,10 +2695,14 @@ impl Composer {
             let mut slots = slot_host.borrow_mut();
             slots.reset();
         }
+        // The subcomposed content is composition, even though it runs inside
+        // the measure pass.
+        let composing = self.runtime_handle().suspend_layout_phase();
         let result = self.with_slot_override(slot_host.clone(), |composer| {
             // Use with_group to create/reuse a group for this slot_id within the slot table.
             composer.with_group(slot_id.raw(), |composer| content(composer))
         });
+        drop(composing);
         {
             let mut slots = slot_host.borrow_mut();
             slots.finalize_current_group();
@@ -2100,6 +2850,10 @@ impl Composer {
     }
 
     pub fn skip_current_group(&self) {
+        if let Some(scope) = self.current_recranpose_scope() {
+            scope.inner.skipped.set(true);
+        }
+        self.core.runtime.record_skipped_group();
         let nodes = self.with_slots(|slots| slots.nodes_in_current_group());
         self.with_slots_mut(|slots| slots.skip_current_group());
         // Get the current parent from the stack (if any)
@@ -2108,18 +2862,28 @@ impl Composer {
             stack.last().map(|frame| frame.id)
         };
 
-        // Only attach nodes whose parent matches the current parent in the stack.
-        // This ensures we only attach direct children of the current parent,
-        // not nested nodes that belong to other nodes within the skipped group.
+        // Only attach the group's top-level nodes to the current parent. Nested
+        // nodes stay with the node that owns them inside the skipped group; they
+        // are recognised either by their parent link or, for nodes that don't
+        // track parents, by being a child of another node in the group.
         let mut applier = self.borrow_applier();
-        for id in nodes {
-            if let Ok(node) = applier.get_mut(id) {
-                let node_parent = node.parent();
-                if node_parent.is_none() || node_parent == current_parent {
-                    drop(applier);
-                    self.attach_to_parent(id);
-                    applier = self.borrow_applier();
-                }
+        for &id in &nodes {
+            let Ok(node) = applier.get_mut(id) else {
+                continue;
+            };
+            let top_level = match node.parent() {
+                None => !nodes.iter().any(|&owner| {
+                    owner != id
+                        && applier
+                            .get_mut(owner)
+                            .is_ok_and(|owner| owner.has_child(id))
+                }),
+                parent => parent == current_parent,
+            };
+            if top_level {
+                drop(applier);
+                self.attach_to_parent(id);
+                applier = self.borrow_applier();
             }
         }
     }
@@ -2128,6 +2892,14 @@ impl Composer {
         self.core.runtime.clone()
     }
 
+    /// The applier this composer emits nodes into.
+    ///
+    /// Nodes that compose content of their own outside of composition, like
+    /// `SubcomposeLayoutNode` precomposing slots, keep a weak reference to it.
+    pub fn applier_host(&self) -> Rc<dyn ApplierHost> {
+        Rc::clone(&self.core.applier)
+    }
+
     pub fn set_recranpose_callback<F>(&self, callback: F)
     where
         F: FnMut(&Composer) + 'static,
@@ -2159,11 +2931,7 @@ impl Composer {
         if provided.is_empty() {
             return f(self);
         }
-        let mut context = LocalContext::default();
-        for value in provided {
-            let (key, entry) = value.into_entry(self);
-            context.values.insert(key, entry);
-        }
+        let context = self.local_context(&provided);
         {
             let mut stack = self.local_stack();
             stack.push(context);
@@ -2176,6 +2944,27 @@ impl Composer {
         result
     }
 
+    fn local_context(&self, provided: &[ProvidedValue]) -> LocalContext {
+        let mut context = LocalContext::default();
+        for value in provided {
+            let (key, entry) = value.entry(self);
+            context.values.insert(key, entry);
+        }
+        context
+    }
+
+    /// Pushes `provided` as the base of the local stack, under everything the
+    /// content provides. The entries live in a group of their own, so they
+    /// stay put when the content after them changes.
+    fn provide_root_locals(&self, provided: &[ProvidedValue]) {
+        let context = self.with_group(hash_key(&"root_locals"), |composer| {
+            composer.local_context(provided)
+        });
+        if !context.values.is_empty() {
+            self.local_stack().push(context);
+        }
+    }
+
     fn recranpose_group(&self, scope: &RecomposeScope) {
         // CRITICAL FIX: Check if scope is still invalid before recomposing.
         // When parent and child scopes are both invalidated, the child may be
@@ -2188,23 +2977,18 @@ impl Composer {
         }
         let started = self.with_slots_mut(|slots| slots.begin_recranpose_at_scope(scope.id()));
         if started.is_some() {
-            let previous_hint = self
-                .core
-                .recranpose_parent_hint
-                .replace(scope.parent_hint());
-            struct HintGuard {
-                core: Rc<ComposerCore>,
-                previous: Option<NodeId>,
-            }
-            impl Drop for HintGuard {
-                fn drop(&mut self) {
-                    self.core.recranpose_parent_hint.set(self.previous);
-                }
+            // The group's nodes belong to the node it was first composed
+            // under; collect them in a frame of their own to see whether the
+            // group still attaches the same ones.
+            let parent = scope.parent_hint();
+            if let Some(id) = parent {
+                self.parent_stack().push(ParentFrame {
+                    id,
+                    remembered: Owned::new(ParentChildren::default()),
+                    previous: Vec::new(),
+                    new_children: Vec::new(),
+                });
             }
-            let _hint_guard = HintGuard {
-                core: self.clone_core(),
-                previous: previous_hint,
-            };
             {
                 let mut stack = self.scope_stack();
                 stack.push(scope.clone());
@@ -2217,9 +3001,7 @@ impl Composer {
                 let mut locals = self.local_stack();
                 *locals = scope.local_stack();
             }
-            self.observe_scope(scope, || {
-                scope.run_recompose(self);
-            });
+            let ran = self.observe_scope(scope, || scope.run_recompose(self));
             {
                 let mut locals = self.local_stack();
                 *locals = saved_locals;
@@ -2228,13 +3010,128 @@ impl Composer {
                 let mut stack = self.scope_stack();
                 stack.pop();
             }
-            self.with_slots_mut(SlotStorage::end_recompose);
+            if let Some(parent) = parent {
+                let nodes = self
+                    .parent_stack()
+                    .pop()
+                    .map(|frame| frame.new_children)
+                    .unwrap_or_default();
+                self.reattach_recomposed_nodes(scope, parent, nodes);
+            }
+            self.remove_unplaced_nodes(scope);
+            // Content the run no longer emits is released here, as when the
+            // group is composed through `with_group`.
+            if ran {
+                self.with_slots_mut(|slots| slots.finalize_current_group());
+            }
+            self.with_slots_mut(SlotStorage::end_recompose);
+            scope.finish_run();
             scope.mark_recomposed();
         } else {
             scope.mark_recomposed();
         }
     }
 
+    /// Removes the nodes scopes inside `scope` left for it to place that
+    /// its run did not attach to their parent.
+    fn remove_unplaced_nodes(&self, scope: &RecomposeScope) {
+        let unplaced = scope.take_unplaced_nodes();
+        if unplaced.is_empty() {
+            return;
+        }
+        let runtime = self.runtime_handle();
+        self.commands_mut()
+            .push(Box::new(move |applier: &mut dyn Applier| {
+                for &(parent, id) in &unplaced {
+                    let placed = applier
+                        .get_mut(parent)
+                        .map(|node| node.children().contains(&id))
+                        .unwrap_or(false);
+                    let owned = match applier.get_mut(id) {
+                        Ok(node) => {
+                            matches!(node.parent(), Some(p) if p == parent)
+                                || node.parent().is_none()
+                        }
+                        Err(_) => false,
+                    };
+                    if placed || !owned {
+                        continue;
+                    }
+                    if let Ok(node) = applier.get_mut(id) {
+                        node.unmount();
+                    }
+                    if applier.remove(id).is_ok() {
+                        runtime.record_node_removed();
+                    }
+                }
+                Ok(())
+            }));
+    }
+
+    /// Places the nodes a group attached while recomposing on its own.
+    ///
+    /// Only the parent node's composition knows where the group's nodes sit
+    /// among their siblings, so when the group added, dropped or reordered
+    /// nodes the nearest enclosing restartable scope recomposes and diffs the
+    /// parent's children. Without one, new nodes are appended to the parent.
+    fn reattach_recomposed_nodes(
+        &self,
+        scope: &RecomposeScope,
+        parent: NodeId,
+        nodes: Vec<NodeId>,
+    ) {
+        if scope.inner.top_level_nodes.borrow().as_ref() == Some(&nodes) {
+            return;
+        }
+        let previous = scope.replace_top_level_nodes(Some(nodes.clone()));
+        if let (Some(previous), Some(mut owner)) = (&previous, scope.restartable_parent()) {
+            // An owner that attached exactly these nodes would only change
+            // the same way, so go straight past it.
+            while owner.inner.top_level_nodes.borrow().as_ref() == Some(previous) {
+                match owner.restartable_parent() {
+                    Some(next) => owner = next,
+                    None => break,
+                }
+            }
+            // Nodes the owner's run doesn't place again are removed then.
+            let mut unplaced = scope.take_unplaced_nodes();
+            unplaced.extend(
+                nodes
+                    .iter()
+                    .filter(|id| !previous.contains(id))
+                    .map(|&id| (parent, id)),
+            );
+            owner.add_unplaced_nodes(unplaced);
+            owner.invalidate();
+            return;
+        }
+        let previous = previous.unwrap_or_default();
+        for id in nodes {
+            if previous.contains(&id) {
+                continue;
+            }
+            self.commands_mut()
+                .push(Box::new(move |applier: &mut dyn Applier| {
+                    let attached = applier
+                        .get_mut(parent)
+                        .map(|node| node.children().contains(&id))
+                        .unwrap_or(true);
+                    if attached {
+                        return Ok(());
+                    }
+                    if let Ok(parent_node) = applier.get_mut(parent) {
+                        parent_node.insert_child(id);
+                    }
+                    if let Ok(child_node) = applier.get_mut(id) {
+                        child_node.on_attached_to_parent(parent);
+                    }
+                    bubble_layout_dirty(applier, parent);
+                    bubble_measure_dirty(applier, parent);
+                    Ok(())
+                }));
+        }
+    }
+
     pub fn use_state<T: Clone + 'static>(&self, init: impl FnOnce() -> T) -> MutableState<T> {
         let runtime = self.runtime_handle();
         let state = self.with_slots_mut(|slots| {
@@ -2267,38 +3164,22 @@ impl Composer {
                 // accidentally inherit children from a different parent.
                 let reuse_allowed = true;
 
-                #[cfg(not(target_arch = "wasm32"))]
-                if std::env::var("COMPOSE_DEBUG").is_ok() {
+                if compose_debug_enabled() {
                     eprintln!("emit_node: candidate #{id} reuse_allowed={reuse_allowed}");
                 }
 
                 if reuse_allowed {
                     self.core.last_node_reused.set(Some(true));
-                    #[cfg(not(target_arch = "wasm32"))]
-                    if std::env::var("COMPOSE_DEBUG").is_ok() {
+                    if compose_debug_enabled() {
                         eprintln!(
                             "emit_node: reusing node #{id} as {}",
                             std::any::type_name::<N>()
                         );
                     }
                     self.with_slots_mut(|slots| slots.advance_after_node_read());
-
-                    self.commands_mut()
-                        .push(Box::new(move |applier: &mut dyn Applier| {
-                            let node = match applier.get_mut(id) {
-                                Ok(node) => node,
-                                Err(NodeError::Missing { .. }) => return Ok(()),
-                                Err(err) => return Err(err),
-                            };
-                            let typed = node.as_any_mut().downcast_mut::<N>().ok_or(
-                                NodeError::TypeMismatch {
-                                    id,
-                                    expected: std::any::type_name::<N>(),
-                                },
-                            )?;
-                            typed.update();
-                            Ok(())
-                        }));
+                    // Inputs are set after emission, so whether an update is
+                    // needed is decided when the commands are taken.
+                    self.core.reused_nodes.borrow_mut().push(id);
                     self.attach_to_parent(id);
                     return id;
                 }
@@ -2308,20 +3189,24 @@ impl Composer {
         // If there was a mismatched node in this slot, schedule its removal before creating a new one.
         if let Some(old_id) = existing_id {
             if !type_matches {
-                #[cfg(not(target_arch = "wasm32"))]
-                if std::env::var("COMPOSE_DEBUG").is_ok() {
+                if compose_debug_enabled() {
                     eprintln!(
                         "emit_node: replacing node #{old_id} with new {}",
                         std::any::type_name::<N>()
                     );
                 }
+                let runtime = self.runtime_handle();
                 self.commands_mut()
                     .push(Box::new(move |applier: &mut dyn Applier| {
                         if let Ok(node) = applier.get_mut(old_id) {
                             node.unmount();
                         }
                         match applier.remove(old_id) {
-                            Ok(()) | Err(NodeError::Missing { .. }) => Ok(()),
+                            Ok(()) => {
+                                runtime.record_node_removed();
+                                Ok(())
+                            }
+                            Err(NodeError::Missing { .. }) => Ok(()),
                             Err(err) => Err(err),
                         }
                     }));
@@ -2334,9 +3219,9 @@ impl Composer {
             let mut applier = self.borrow_applier();
             applier.create(Box::new(init()))
         };
+        self.core.runtime.record_node_created();
         self.core.last_node_reused.set(Some(false));
-        #[cfg(not(target_arch = "wasm32"))]
-        if std::env::var("COMPOSE_DEBUG").is_ok() {
+        if compose_debug_enabled() {
             eprintln!(
                 "emit_node: creating node #{} as {}",
                 id,
@@ -2355,6 +3240,7 @@ impl Composer {
                 };
                 node.set_node_id(id);
                 node.mount();
+                node.update();
                 Ok(())
             }));
         self.attach_to_parent(id);
@@ -2434,36 +3320,6 @@ impl Composer {
             return;
         }
 
-        // During recomposition, preserve the original parent when possible.
-        if let Some(parent_hint) = self.core.recranpose_parent_hint.get() {
-            let parent_status = {
-                let mut applier = self.borrow_applier();
-                applier
-                    .get_mut(id)
-                    .map(|node| node.parent())
-                    .unwrap_or(None)
-            };
-            match parent_status {
-                Some(existing) if existing == parent_hint => {}
-                None => {
-                    self.commands_mut()
-                        .push(Box::new(move |applier: &mut dyn Applier| {
-                            if let Ok(parent_node) = applier.get_mut(parent_hint) {
-                                parent_node.insert_child(id);
-                            }
-                            if let Ok(child_node) = applier.get_mut(id) {
-                                child_node.on_attached_to_parent(parent_hint);
-                            }
-                            bubble_layout_dirty(applier, parent_hint);
-                            bubble_measure_dirty(applier, parent_hint);
-                            Ok(())
-                        }));
-                }
-                Some(_) => {}
-            }
-            return;
-        }
-
         // Neither parent nor subcompose - check if this node already has a parent.
         // During recomposition, reused nodes already have their correct parent from
         // initial composition. We should NOT set them as root, as that would corrupt
@@ -2535,8 +3391,7 @@ impl Composer {
                 new_children,
             } = frame;
 
-            #[cfg(not(target_arch = "wasm32"))]
-            if std::env::var("COMPOSE_DEBUG").is_ok() {
+            if compose_debug_enabled() {
                 eprintln!("pop_parent: node #{}", id);
                 eprintln!("  previous children: {:?}", previous);
                 eprintln!("  new children: {:?}", new_children);
@@ -2552,6 +3407,7 @@ impl Composer {
                     let child = current[index];
                     if !desired.contains(&child) {
                         current.remove(index);
+                        let runtime = self.runtime_handle();
                         self.commands_mut()
                             .push(Box::new(move |applier: &mut dyn Applier| {
                                 // Remove child from parent and clear parent link atomically
@@ -2582,20 +3438,45 @@ impl Composer {
                                     true
                                 };
 
-                                if should_remove {
-                                    let _ = applier.remove(child);
+                                if should_remove && applier.remove(child).is_ok() {
+                                    runtime.record_node_removed();
                                 }
                                 Ok(())
                             }));
                     }
                 }
 
+                // Children that already appear in target order stay put; only the rest
+                // move, each to just after its predecessor in `target`. Keeping the
+                // longest such run in place yields the fewest moves.
+                let kept_positions: Vec<usize> = target
+                    .iter()
+                    .filter_map(|child| current.iter().position(|c| c == child))
+                    .collect();
+                let stable: HashSet<NodeId> = longest_increasing_subsequence(&kept_positions)
+                    .into_iter()
+                    .map(|index| current[kept_positions[index]])
+                    .collect();
+
                 for (target_index, &child) in target.iter().enumerate() {
+                    let placement = if target_index == 0 {
+                        0
+                    } else {
+                        let previous_child = target[target_index - 1];
+                        current
+                            .iter()
+                            .position(|&c| c == previous_child)
+                            .map_or(0, |index| index + 1)
+                    };
                     if let Some(current_index) = current.iter().position(|&c| c == child) {
-                        if current_index != target_index {
+                        if !stable.contains(&child) && current_index != placement {
                             let from_index = current_index;
                             current.remove(from_index);
-                            let to_index = target_index.min(current.len());
+                            let to_index = if from_index < placement {
+                                placement - 1
+                            } else {
+                                placement
+                            };
                             current.insert(to_index, child);
                             self.commands_mut()
                                 .push(Box::new(move |applier: &mut dyn Applier| {
@@ -2614,7 +3495,7 @@ impl Composer {
                                 }));
                         }
                     } else {
-                        let insert_index = target_index.min(current.len());
+                        let insert_index = placement;
                         let appended_index = current.len();
                         current.insert(insert_index, child);
                         self.commands_mut()
@@ -2660,6 +3541,35 @@ impl Composer {
                         }
                     }
                 }
+                debug_assert_eq!(current, target, "child moves must reproduce target order");
+
+                #[cfg(debug_assertions)]
+                self.commands_mut()
+                    .push(Box::new(move |applier: &mut dyn Applier| {
+                        // Nodes that track their children should now hold them in
+                        // target order. A node may also hold children this pass
+                        // does not place, and children moving in from another
+                        // parent are only attached by the repair below, so
+                        // only the order of the children in both is compared.
+                        if let Ok(parent_node) = applier.get_mut(id) {
+                            let actual = parent_node.children();
+                            let placed: Vec<NodeId> = actual
+                                .iter()
+                                .copied()
+                                .filter(|child| target.contains(child))
+                                .collect();
+                            let expected: Vec<NodeId> = target
+                                .iter()
+                                .copied()
+                                .filter(|child| placed.contains(child))
+                                .collect();
+                            debug_assert!(
+                                placed == expected,
+                                "node #{id} children {actual:?} diverged from {target:?}"
+                            );
+                        }
+                        Ok(())
+                    }));
             }
 
             let expected_children = new_children.clone();
@@ -2697,19 +3607,17 @@ impl Composer {
                             repaired = true;
                         }
                     }
-                    let is_dirty = if needs_dirty_check {
-                        if let Ok(node) = applier.get_mut(id) {
-                            node.needs_layout()
-                        } else {
-                            false
-                        }
+                    let (needs_measure, needs_layout) = if needs_dirty_check {
+                        applier.get_mut(id).map_or((false, false), |node| {
+                            (node.needs_measure(), node.needs_layout())
+                        })
                     } else {
-                        false
+                        (false, false)
                     };
-                    if repaired {
+                    if repaired || needs_measure {
                         bubble_layout_dirty(applier, id);
                         bubble_measure_dirty(applier, id);
-                    } else if is_dirty {
+                    } else if needs_layout {
                         bubble_layout_dirty(applier, id);
                     }
                     Ok(())
@@ -2720,9 +3628,40 @@ impl Composer {
     }
 
     pub fn take_commands(&self) -> Vec<Command> {
+        self.queue_reused_node_updates();
         std::mem::take(&mut *self.commands_mut())
     }
 
+    /// Queue `update()` for the reused nodes whose inputs changed.
+    fn queue_reused_node_updates(&self) {
+        let reused = std::mem::take(&mut *self.core.reused_nodes.borrow_mut());
+        if reused.is_empty() {
+            return;
+        }
+        let dirty: Vec<NodeId> = {
+            let mut applier = self.borrow_applier();
+            reused
+                .into_iter()
+                .filter(|&id| applier.get_mut(id).is_ok_and(|node| node.needs_update()))
+                .collect()
+        };
+        let mut commands = self.commands_mut();
+        for id in dirty {
+            let runtime = self.runtime_handle();
+            commands.push(Box::new(move |applier: &mut dyn Applier| {
+                match applier.get_mut(id) {
+                    Ok(node) => {
+                        node.update();
+                        runtime.record_node_updated();
+                    }
+                    Err(NodeError::Missing { .. }) => {}
+                    Err(err) => return Err(err),
+                }
+                Ok(())
+            }));
+        }
+    }
+
     /// Applies any pending applier commands and runtime updates.
     ///
     /// This is useful during measure-time subcomposition to ensure newly created
@@ -2772,6 +3711,42 @@ struct ParentFrame {
     new_children: Vec<NodeId>,
 }
 
+/// Composer state captured before a group guarded by
+/// [`Composer::with_group_catching`] starts.
+struct GroupCheckpoint {
+    snapshot: Option<snapshot_v2::AnySnapshot>,
+    scope_depth: usize,
+    parent_depth: usize,
+    parent_children: usize,
+    subcompose_nodes: usize,
+    subcompose_scopes: usize,
+    local_depth: usize,
+    commands: usize,
+    side_effects: usize,
+}
+
+/// A panic caught while composing, with the innermost scope that was running.
+struct CompositionPanic {
+    scope_id: Option<ScopeId>,
+    payload: Box<dyn Any + Send>,
+}
+
+impl CompositionPanic {
+    fn into_error(self) -> NodeError {
+        let message = if let Some(message) = self.payload.downcast_ref::<&str>() {
+            (*message).to_string()
+        } else if let Some(message) = self.payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "non-string panic payload".to_string()
+        };
+        NodeError::Composition {
+            scope_id: self.scope_id,
+            message,
+        }
+    }
+}
+
 #[derive(Default)]
 struct SubcomposeFrame {
     nodes: Vec<NodeId>,
@@ -2783,9 +3758,46 @@ struct LocalContext {
     values: HashMap<LocalKey, Rc<dyn Any>>,
 }
 
+/// Entry in a state's watcher list: either a recompose scope that read the
+/// state during composition or an explicit [`State::observe`] callback.
+enum StateWatcher<T: Clone + 'static> {
+    Scope(Weak<RecomposeScopeInner>),
+    Callback(Weak<StateCallback<T>>),
+}
+
+impl<T: Clone + 'static> StateWatcher<T> {
+    fn is_alive(&self) -> bool {
+        match self {
+            StateWatcher::Scope(scope) => scope.strong_count() > 0,
+            StateWatcher::Callback(callback) => callback.strong_count() > 0,
+        }
+    }
+}
+
+struct StateCallback<T: Clone + 'static> {
+    callback: Box<dyn Fn(&T)>,
+    /// Coalesces multiple invalidations into a single queued notification.
+    pending: Cell<bool>,
+}
+
+/// Handle returned by [`State::observe`]. Dropping it unsubscribes the callback.
+///
+/// The state only holds a weak reference to the callback, so a subscription
+/// never keeps itself alive through the state it observes.
+#[must_use = "dropping a Subscription immediately unsubscribes the callback"]
+pub struct Subscription {
+    _callback: Rc<dyn Any>,
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription").finish_non_exhaustive()
+    }
+}
+
 pub(crate) struct MutableStateInner<T: Clone + 'static> {
     state: Arc<SnapshotMutableState<T>>,
-    watchers: RefCell<Vec<Weak<RecomposeScopeInner>>>, // FUTURE(no_std): move to stack-allocated subscription list.
+    watchers: RefCell<Vec<StateWatcher<T>>>, // FUTURE(no_std): move to stack-allocated subscription list.
     runtime: RuntimeHandle,
 }
 
@@ -2818,19 +3830,50 @@ impl<T: Clone + 'static> MutableStateInner<T> {
     }
 
     fn invalidate_watchers(&self) {
-        let watchers: Vec<RecomposeScope> = {
+        let mut scopes = Vec::new();
+        let mut callbacks = Vec::new();
+        {
             let mut watchers = self.watchers.borrow_mut();
-            watchers.retain(|w| w.strong_count() > 0);
-            watchers
-                .iter()
-                .filter_map(|w| w.upgrade())
-                .map(|inner| RecomposeScope { inner })
-                .collect()
-        };
+            watchers.retain(StateWatcher::is_alive);
+            for watcher in watchers.iter() {
+                match watcher {
+                    StateWatcher::Scope(scope) => {
+                        if let Some(inner) = scope.upgrade() {
+                            scopes.push(RecomposeScope { inner });
+                        }
+                    }
+                    StateWatcher::Callback(callback) => {
+                        if let Some(callback) = callback.upgrade() {
+                            callbacks.push(callback);
+                        }
+                    }
+                }
+            }
+        }
+
+        for scope in scopes {
+            scope.invalidate();
+        }
+        for callback in callbacks {
+            self.notify_callback(callback);
+        }
+    }
 
-        for watcher in watchers {
-            watcher.invalidate();
+    /// Queues `callback` on the UI task queue so it runs outside the state arena
+    /// borrow and may freely read or write other states.
+    fn notify_callback(&self, callback: Rc<StateCallback<T>>) {
+        if callback.pending.replace(true) {
+            return;
         }
+        let state = self.state.clone();
+        let callback = Rc::downgrade(&callback);
+        self.runtime.enqueue_ui_task(Box::new(move || {
+            if let Some(callback) = callback.upgrade() {
+                callback.pending.set(false);
+                let value = state.get();
+                (callback.callback)(&value);
+            }
+        }));
     }
 }
 
@@ -2870,7 +3913,7 @@ impl<T: Clone + 'static> Eq for MutableState<T> {}
 
 impl<T: Clone + 'static> State<T> {
     fn runtime_handle(&self) -> RuntimeHandle {
-        runtime_handle_for(self.runtime_id).expect("runtime handle missing")
+        expect_runtime_handle(self.runtime_id)
     }
 
     fn with_inner<R>(&self, f: impl FnOnce(&MutableStateInner<T>) -> R) -> R {
@@ -2881,23 +3924,50 @@ impl<T: Clone + 'static> State<T> {
     }
 
     fn subscribe_current_scope(&self) {
+        if snapshot_v2::reads_suppressed() {
+            return;
+        }
         if let Some(Some(scope)) =
             with_current_composer_opt(|composer| composer.current_recranpose_scope())
         {
             self.with_inner(|inner| {
                 let mut watchers = inner.watchers.borrow_mut();
-                watchers.retain(|w| w.strong_count() > 0);
+                watchers.retain(StateWatcher::is_alive);
                 let id = scope.id();
-                let already_registered = watchers
-                    .iter()
-                    .any(|w| w.upgrade().map(|inner| inner.id == id).unwrap_or(false));
+                let already_registered = watchers.iter().any(|w| match w {
+                    StateWatcher::Scope(w) => {
+                        w.upgrade().map(|inner| inner.id == id).unwrap_or(false)
+                    }
+                    StateWatcher::Callback(_) => false,
+                });
                 if !already_registered {
-                    watchers.push(scope.downgrade());
+                    watchers.push(StateWatcher::Scope(scope.downgrade()));
                 }
             });
         }
     }
 
+    /// Invokes `callback` with the new value whenever this state changes, without
+    /// requiring a composition.
+    ///
+    /// Notifications are delivered through the runtime's UI task queue, so several
+    /// changes before the queue drains are coalesced into one call. Drop the
+    /// returned [`Subscription`] to stop observing.
+    pub fn observe(&self, callback: impl Fn(&T) + 'static) -> Subscription {
+        let callback = Rc::new(StateCallback {
+            callback: Box::new(callback),
+            pending: Cell::new(false),
+        });
+        self.with_inner(|inner| {
+            let mut watchers = inner.watchers.borrow_mut();
+            watchers.retain(StateWatcher::is_alive);
+            watchers.push(StateWatcher::Callback(Rc::downgrade(&callback)));
+        });
+        Subscription {
+            _callback: callback,
+        }
+    }
+
     pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
         self.subscribe_current_scope();
         self.with_inner(|inner| inner.with_value(f))
@@ -2911,6 +3981,15 @@ impl<T: Clone + 'static> State<T> {
     pub fn get(&self) -> T {
         self.value()
     }
+
+    /// Returns the current value without subscribing the current scope.
+    ///
+    /// Behaves the same inside and outside composition: later writes never
+    /// recompose the reader. Intended for one-shot reads in event handlers and
+    /// effects.
+    pub fn peek(&self) -> T {
+        snapshot_v2::without_reads(|| self.with_inner(|inner| inner.with_value(T::clone)))
+    }
 }
 
 impl<T: Clone + 'static> MutableState<T> {
@@ -2924,7 +4003,7 @@ impl<T: Clone + 'static> MutableState<T> {
     }
 
     fn runtime_handle(&self) -> RuntimeHandle {
-        runtime_handle_for(self.runtime_id).expect("runtime handle missing")
+        expect_runtime_handle(self.runtime_id)
     }
 
     fn with_inner<R>(&self, f: impl FnOnce(&MutableStateInner<T>) -> R) -> R {
@@ -2934,6 +4013,11 @@ impl<T: Clone + 'static> MutableState<T> {
         })
     }
 
+    /// Identifies this state in the runtime's state history.
+    pub fn id(&self) -> StateId {
+        self.id
+    }
+
     pub fn as_state(&self) -> State<T> {
         State {
             id: self.id,
@@ -2946,31 +4030,107 @@ impl<T: Clone + 'static> MutableState<T> {
         self.as_state().with(f)
     }
 
+    /// See [`State::observe`].
+    pub fn observe(&self, callback: impl Fn(&T) + 'static) -> Subscription {
+        self.as_state().observe(callback)
+    }
+
+    /// Mutates the value in place and invalidates its readers.
+    ///
+    /// During measure or layout the write is deferred until after the frame;
+    /// see [`replace`](Self::replace).
     pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
         let runtime = self.runtime_handle();
         runtime.assert_ui_thread();
-        runtime.with_state_arena(|arena| {
+        if runtime.in_layout_phase() {
+            let mut value = runtime
+                .pending_write::<T>(self.id)
+                .unwrap_or_else(|| self.get_non_reactive());
+            let result = f(&mut value);
+            self.write_after_layout(&runtime, value);
+            return result;
+        }
+        let history = runtime.state_history_capture(self.id);
+        let keep_values = history == Some(true);
+        let mut recorded = None;
+        let result = runtime.with_state_arena(|arena| {
             let inner = arena.get_typed::<T>(self.id);
             let mut value = inner.state.get();
+            let before = keep_values.then(|| value.clone());
             let tracker = UpdateScope::new(inner.state.id());
             let result = f(&mut value);
             let wrote_elsewhere = tracker.finish();
             if !wrote_elsewhere {
+                recorded = Some(before.map(|before| (before, value.clone())));
                 inner.state.set(value);
             }
             inner.invalidate_watchers();
             result
-        })
+        });
+        // A write made through the state inside `f` was already recorded.
+        if let (Some(_), Some(values)) = (history, recorded) {
+            self.record_write(&runtime, values);
+        }
+        result
     }
 
+    /// Replaces the value and invalidates its readers.
+    ///
+    /// During measure or layout the write is held back until the frame is
+    /// over, so the rest of the pass keeps reading the value it started with
+    /// and no reader is invalidated mid-measure. Later writes in the same
+    /// pass, including [`update`](Self::update), build on the held value.
     pub fn replace(&self, value: T) {
         let runtime = self.runtime_handle();
         runtime.assert_ui_thread();
+        if runtime.in_layout_phase() {
+            self.write_after_layout(&runtime, value);
+            return;
+        }
+        let history = runtime.state_history_capture(self.id);
+        let recorded = history
+            .filter(|keep_values| *keep_values)
+            .map(|_| (self.get_non_reactive(), value.clone()));
         runtime.with_state_arena(|arena| {
             let inner = arena.get_typed::<T>(self.id);
             inner.state.set(value);
             inner.invalidate_watchers();
         });
+        if history.is_some() {
+            self.record_write(&runtime, recorded);
+        }
+    }
+
+    /// Records only the type of this state's writes in the runtime's state
+    /// history, not the values, e.g. for large collections. Replay leaves
+    /// such states untouched.
+    pub fn set_history_summary_only(&self) {
+        self.runtime_handle()
+            .set_state_history_summary_only(self.id);
+    }
+
+    fn record_write(&self, runtime: &RuntimeHandle, values: Option<(T, T)>) {
+        let state = *self;
+        let write = values.map(|(before, after)| state_history::RecordedWrite {
+            before,
+            after,
+            restore: Rc::new(move |value: &dyn Any| {
+                if let Some(value) = value.downcast_ref::<T>() {
+                    state.replace(value.clone());
+                }
+            }),
+        });
+        runtime.record_state_write(self.id, write);
+    }
+
+    fn write_after_layout(&self, runtime: &RuntimeHandle, value: T) {
+        let state = *self;
+        let commit_runtime = runtime.clone();
+        runtime.defer_write(self.id, value, move || {
+            if let Some(value) = commit_runtime.take_pending_write::<T>(state.id) {
+                state.replace(value);
+            }
+        });
     }
 
     pub fn set_value(&self, value: T) {
@@ -2989,6 +4149,13 @@ impl<T: Clone + 'static> MutableState<T> {
         self.value()
     }
 
+    /// Returns the current value without subscribing the current scope.
+    ///
+    /// See [`State::peek`].
+    pub fn peek(&self) -> T {
+        self.as_state().peek()
+    }
+
     /// Gets the current value WITHOUT subscribing to recomposition.
     ///
     /// Use this in layout/measure/draw phases to read state without causing
@@ -3008,7 +4175,11 @@ impl<T: Clone + 'static> MutableState<T> {
 
     #[cfg(test)]
     pub(crate) fn watcher_count(&self) -> usize {
-        self.with_inner(|inner| inner.watchers.borrow().len())
+        self.with_inner(|inner| {
+            let mut watchers = inner.watchers.borrow_mut();
+            watchers.retain(StateWatcher::is_alive);
+            watchers.len()
+        })
     }
 }
 
@@ -3104,6 +4275,40 @@ impl<T: Clone + 'static> SnapshotStateList<T> {
         self.state.update(|values| values.remove(index))
     }
 
+    /// Swaps the elements at `a` and `b` in a single update.
+    ///
+    /// Panics if either index is out of bounds, leaving the list untouched.
+    pub fn swap(&self, a: usize, b: usize) {
+        self.check_index(a);
+        self.check_index(b);
+        if a != b {
+            self.state.update(|values| values.swap(a, b));
+        }
+    }
+
+    /// Moves the element at `from` so that it ends up at index `to`, shifting
+    /// the elements in between, in a single update.
+    ///
+    /// Panics if either index is out of bounds, leaving the list untouched.
+    pub fn move_item(&self, from: usize, to: usize) {
+        self.check_index(from);
+        self.check_index(to);
+        if from < to {
+            self.state.update(|values| values[from..=to].rotate_left(1));
+        } else if from > to {
+            self.state
+                .update(|values| values[to..=from].rotate_right(1));
+        }
+    }
+
+    fn check_index(&self, index: usize) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "SnapshotStateList index {index} out of bounds for length {len}"
+        );
+    }
+
     pub fn pop(&self) -> Option<T> {
         self.state.update(|values| values.pop())
     }
@@ -3205,6 +4410,44 @@ where
         self.state.update(|map| map.remove(key))
     }
 
+    /// Replaces the entry for `key` with the value `remapping` computes from
+    /// the current one, in a single update. Returning `None` removes the key.
+    ///
+    /// Returns the new value.
+    pub fn compute<F>(&self, key: K, remapping: F) -> Option<V>
+    where
+        F: FnOnce(Option<&V>) -> Option<V>,
+    {
+        self.state.update(|map| match remapping(map.get(&key)) {
+            Some(value) => {
+                map.insert(key, value.clone());
+                Some(value)
+            }
+            None => {
+                map.remove(&key);
+                None
+            }
+        })
+    }
+
+    /// Inserts `value` for `key`, or combines it with the existing value via
+    /// `combine(old, new)`, in a single update.
+    ///
+    /// Returns the stored value.
+    pub fn merge<F>(&self, key: K, value: V, combine: F) -> V
+    where
+        F: FnOnce(&V, V) -> V,
+    {
+        self.state.update(|map| {
+            let merged = match map.get(&key) {
+                Some(old) => combine(old, value),
+                None => value,
+            };
+            map.insert(key, merged.clone());
+            merged
+        })
+    }
+
     pub fn clear(&self) {
         self.state.replace(HashMap::default());
     }
@@ -3233,15 +4476,29 @@ where
 struct DerivedState<T: Clone + 'static> {
     compute: Rc<dyn Fn() -> T>, // FUTURE(no_std): store compute closures in arena-managed cell.
     state: MutableState<T>,
+    site: DerivationSite,
+    /// When set, a recomputed value equivalent to the current one is dropped
+    /// instead of invalidating readers.
+    equivalent: Option<fn(&T, &T) -> bool>,
 }
 
 impl<T: Clone + 'static> DerivedState<T> {
-    fn new(runtime: RuntimeHandle, compute: Rc<dyn Fn() -> T>) -> Self {
+    fn new(
+        runtime: RuntimeHandle,
+        compute: Rc<dyn Fn() -> T>,
+        equivalent: Option<fn(&T, &T) -> bool>,
+        location: &'static std::panic::Location<'static>,
+    ) -> Self {
         // FUTURE(no_std): accept arena-managed compute handle.
         let initial = compute();
         Self {
             compute,
             state: MutableState::with_runtime(initial, runtime),
+            site: DerivationSite {
+                id: NEXT_DERIVATION_ID.fetch_add(1, Ordering::Relaxed),
+                location,
+            },
+            equivalent,
         }
     }
 
@@ -3250,9 +4507,29 @@ impl<T: Clone + 'static> DerivedState<T> {
         self.compute = compute;
     }
 
-    fn recompute(&self) {
+    /// Recomputes the value. `cause` is the chain of derived states whose
+    /// writes invalidated this one; finding this state on it means a cycle.
+    fn recompute(&self, mut cause: Vec<DerivationSite>) {
+        if let Some(start) = cause.iter().position(|site| site.id == self.site.id) {
+            let cycle = DerivedStateCycle {
+                locations: cause[start..].iter().map(|site| site.location).collect(),
+            };
+            log::error!("{cycle}");
+            self.state
+                .runtime_handle()
+                .record_derived_state_cycle(cycle);
+            return;
+        }
+        cause.push(self.site);
+        let _chain = DerivationChainGuard::enter(cause);
         let value = (self.compute)();
-        self.state.set_value(value);
+        let changed = match self.equivalent {
+            Some(equivalent) => !equivalent(&self.state.get_non_reactive(), &value),
+            None => true,
+        };
+        if changed {
+            self.state.set_value(value);
+        }
     }
 }
 
@@ -3290,6 +4567,54 @@ impl<T> ParamState<T> {
     }
 }
 
+/// Marks a type as stable for skipping: a composable parameter of this type
+/// counts as unchanged while it is the same instance as the previous value,
+/// and `PartialEq` is never consulted, even when the type implements it.
+///
+/// Mirrors Compose's `@Stable`/`@Immutable`. Implement it for types that are
+/// expensive to compare and never mutated in place, typically wrappers around
+/// an `Rc`. A value mutated through shared ownership keeps its identity, so
+/// composables receiving it are skipped and keep showing stale content;
+/// create a new instance instead of mutating one.
+pub trait ComposeStable: Clone {
+    /// Returns whether `other` is the same instance as `self`.
+    fn is_same_instance(&self, other: &Self) -> bool;
+}
+
+/// Borrowed parameter checked against its [`ParamState`] by the
+/// `#[composable]` macro. Stable types take the identity check through
+/// [`StableParamCheck`]; other types fall back to [`EqParamCheck`].
+#[doc(hidden)]
+pub struct ParamCheck<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait StableParamCheck<T> {
+    fn param_changed(&self, state: &mut ParamState<T>) -> bool;
+}
+
+impl<T: ComposeStable> StableParamCheck<T> for ParamCheck<'_, T> {
+    fn param_changed(&self, state: &mut ParamState<T>) -> bool {
+        match &state.value {
+            Some(old) if old.is_same_instance(self.0) => false,
+            _ => {
+                state.value = Some(self.0.clone());
+                true
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait EqParamCheck<T> {
+    fn param_changed(&self, state: &mut ParamState<T>) -> bool;
+}
+
+impl<T: PartialEq + Clone> EqParamCheck<T> for &ParamCheck<'_, T> {
+    fn param_changed(&self, state: &mut ParamState<T>) -> bool {
+        state.update(self.0)
+    }
+}
+
 /// ParamSlot holds function/closure parameters by ownership (no PartialEq/Clone required).
 /// Used by the #[composable] macro to store Fn-like parameters in the slot table.
 pub struct ParamSlot<T> {
@@ -3341,14 +4666,32 @@ impl CallbackHolder {
     }
 
     /// Produce a forwarder closure that keeps the holder alive and forwards calls to it.
+    ///
+    /// Called during composition, the callback runs in a group of its own, so
+    /// state it reads recomposes just that group with the latest callback
+    /// instead of the composable that called it.
     pub fn clone_rc(&self) -> impl FnMut() + 'static {
         let rc = self.rc.clone();
         move || {
-            (rc.borrow_mut())();
+            let composed = composer_context::try_with_composer(|composer| {
+                composer.with_group(location_key(file!(), line!(), column!()), |composer| {
+                    run_restartable(composer, Rc::clone(&rc));
+                });
+            });
+            if composed.is_none() {
+                (rc.borrow_mut())();
+            }
         }
     }
 }
 
+fn run_restartable(composer: &Composer, callback: Rc<RefCell<Box<dyn FnMut()>>>) {
+    (callback.borrow_mut())();
+    composer.set_recranpose_callback(move |composer| {
+        run_restartable(composer, Rc::clone(&callback));
+    });
+}
+
 impl Default for CallbackHolder {
     fn default() -> Self {
         Self {
@@ -3389,6 +4732,10 @@ pub struct Composition<A: Applier + 'static> {
     runtime: Runtime,
     observer: SnapshotStateObserver,
     root: Option<NodeId>,
+    /// Bumped by `set_content` so each content gets its own root group.
+    content_generation: u64,
+    /// Locals provided to all content, see [`Composition::provide_root_locals`].
+    root_locals: Vec<ProvidedValue>,
 }
 
 impl<A: Applier + 'static> Composition<A> {
@@ -3401,9 +4748,32 @@ impl<A: Applier + 'static> Composition<A> {
     }
 
     pub fn with_backend(applier: A, runtime: Runtime, backend_kind: SlotBackendKind) -> Self {
+        Self::with_applier_host(
+            Rc::new(ConcreteApplierHost::new(applier)),
+            runtime,
+            backend_kind,
+        )
+    }
+
+    /// Creates a composition that emits into the applier of another one,
+    /// e.g. an overlay rendered into the main UI's node tree.
+    ///
+    /// Both compositions allocate node ids from the one applier, so ids never
+    /// collide and nodes of one can be attached under the other. Each
+    /// composition keeps its own root and slot table; `runtime` must not be
+    /// shared with the other composition, since a runtime's invalidated
+    /// scopes are recomposed against a single slot table.
+    pub fn with_shared_applier(applier: Rc<ConcreteApplierHost<A>>, runtime: Runtime) -> Self {
+        Self::with_applier_host(applier, runtime, SlotBackendKind::default())
+    }
+
+    fn with_applier_host(
+        applier: Rc<ConcreteApplierHost<A>>,
+        runtime: Runtime,
+        backend_kind: SlotBackendKind,
+    ) -> Self {
         let storage = make_backend(backend_kind);
         let slots = Rc::new(SlotsHost::new(storage));
-        let applier = Rc::new(ConcreteApplierHost::new(applier));
         let observer_handle = runtime.handle();
         let observer = SnapshotStateObserver::new(move |callback| {
             observer_handle.enqueue_ui_task(callback);
@@ -3415,6 +4785,8 @@ impl<A: Applier + 'static> Composition<A> {
             runtime,
             observer,
             root: None,
+            content_generation: 0,
+            root_locals: Vec::new(),
         }
     }
 
@@ -3426,6 +4798,13 @@ impl<A: Applier + 'static> Composition<A> {
         self.applier.clone()
     }
 
+    /// Composes `content` under `key` and applies the resulting changes.
+    ///
+    /// A panic in `content` rolls its group back and is returned as
+    /// [`NodeError::Composition`]; the next render composes the content
+    /// afresh. Catching the panic relies on unwinding: built with
+    /// `panic = "abort"`, or for targets that always abort, it ends the
+    /// process instead.
     pub fn render(&mut self, key: Key, mut content: impl FnMut()) -> Result<(), NodeError> {
         self.slots.borrow_mut().reset();
         let runtime_handle = self.runtime_handle();
@@ -3438,12 +4817,14 @@ impl<A: Applier + 'static> Composition<A> {
             self.root,
         );
         self.observer.begin_frame();
-        let (root, mut commands, side_effects) = composer.install(|composer| {
-            composer.with_group(key, |_| content());
+        let root_locals = &self.root_locals;
+        let (root, mut commands, side_effects, failure) = composer.install(|composer| {
+            composer.provide_root_locals(root_locals);
+            let failure = composer.group_catching(key, |_| content()).err();
             let root = composer.root();
             let commands = composer.take_commands();
             let side_effects = composer.take_side_effects();
-            (root, commands, side_effects)
+            (root, commands, side_effects, failure)
         });
 
         {
@@ -3455,6 +4836,11 @@ impl<A: Applier + 'static> Composition<A> {
                 update(&mut *applier)?;
             }
         }
+        if let Some(failure) = failure {
+            // The content group was rolled back to empty; the next render
+            // composes it afresh.
+            return Err(failure.into_error());
+        }
 
         runtime_handle.drain_ui();
         for effect in side_effects {
@@ -3468,6 +4854,9 @@ impl<A: Applier + 'static> Composition<A> {
             slots.flush();
         }
         let _ = self.process_invalid_scopes()?;
+        // Imported values whose anchors were not composed belong to content
+        // that no longer exists.
+        self.slots.saveables().clear_pending();
         if !self.runtime.has_updates()
             && !runtime_handle.has_invalid_scopes()
             && !runtime_handle.has_frame_callbacks()
@@ -3475,9 +4864,50 @@ impl<A: Applier + 'static> Composition<A> {
         {
             self.runtime.set_needs_frame(false);
         }
+        runtime_handle.run_idle_callbacks();
+        Ok(())
+    }
+
+    /// Replaces the root content: the previous content leaves the
+    /// composition, disposing its effects and removing its nodes, and
+    /// `content` is composed in its place on the same runtime and slots.
+    ///
+    /// Nothing carries over from the previous content, including composition
+    /// locals it provided at its root; each content must set up the providers
+    /// it needs.
+    pub fn set_content(&mut self, content: impl FnMut()) -> Result<(), NodeError> {
+        self.content_generation += 1;
+        let key = hash_key(&("set_content", self.content_generation));
+        // The new content emits its own root instead of nesting under the old one.
+        let previous_root = self.root.take();
+        self.render(key, content)?;
+        // Root nodes have no parent to remove them when their group goes away.
+        if let Some(previous) = previous_root.filter(|&id| Some(id) != self.root) {
+            let mut applier = self.applier.borrow_dyn();
+            if let Ok(node) = applier.get_mut(previous) {
+                node.unmount();
+            }
+            match applier.remove(previous) {
+                Ok(()) => self.runtime.handle().record_node_removed(),
+                Err(NodeError::Missing { .. }) => {}
+                Err(err) => return Err(err),
+            }
+        }
         Ok(())
     }
 
+    /// Provides `values` to all content rendered from now on, as if the
+    /// content were wrapped in a [`CompositionLocalProvider`].
+    ///
+    /// Lets an embedder hand platform values such as density or insets to
+    /// the app without changing its content function. Providers inside the
+    /// content override these. Replaces the values of an earlier call and
+    /// takes effect on the next [`render`](Self::render) or
+    /// [`set_content`](Self::set_content).
+    pub fn provide_root_locals(&mut self, values: impl IntoIterator<Item = ProvidedValue>) {
+        self.root_locals = values.into_iter().collect();
+    }
+
     /// Returns true if composition needs to process invalid scopes (recompose).
     ///
     /// This checks both:
@@ -3498,10 +4928,58 @@ impl<A: Applier + 'static> Composition<A> {
         ApplierGuard::new(self.applier.borrow_typed())
     }
 
+    /// The applier this composition emits into, for
+    /// [`with_shared_applier`](Self::with_shared_applier).
+    pub fn shared_applier(&self) -> Rc<ConcreteApplierHost<A>> {
+        Rc::clone(&self.applier)
+    }
+
     pub fn root(&self) -> Option<NodeId> {
         self.root
     }
 
+    /// Captures every [`rememberSaveable`] value in this composition, keyed by
+    /// the anchor of the group that remembered it.
+    pub fn export_state(&self) -> StateSnapshot {
+        self.slots.saveables().export()
+    }
+
+    /// Restores values from `snapshot`.
+    ///
+    /// Live values with a matching anchor and type are written immediately;
+    /// the rest are handed to `rememberSaveable` calls that compose those
+    /// anchors during the next [`render`](Self::render). Entries whose anchors
+    /// are not composed by then are dropped.
+    pub fn import_state(&mut self, snapshot: StateSnapshot) {
+        self.slots.saveables().import(snapshot);
+    }
+
+    /// Runs `read` in a read-only snapshot so every state read inside it sees
+    /// the same point in time.
+    ///
+    /// Changes applied while `read` runs, e.g. by an event handler that goes
+    /// through [`run_in_mutable_snapshot`], stay invisible until it returns.
+    /// Calls nest: an inner call reads the outer call's view. Writing state
+    /// inside `read` panics.
+    pub fn with_consistent_snapshot<R>(&self, read: impl FnOnce() -> R) -> R {
+        let snapshot = match snapshot_v2::current_snapshot() {
+            Some(current) => current.take_nested_snapshot(None),
+            None => snapshot_v2::AnySnapshot::Readonly(
+                snapshot_v2::GlobalSnapshot::get_or_create().take_nested_snapshot(None),
+            ),
+        };
+        let result = snapshot.enter(read);
+        snapshot.dispose();
+        result
+    }
+
+    /// Drops gap slots accumulated by conditional content and shrinks the slot
+    /// storage, returning how many slots were released. Intended for idle time
+    /// between frames; subcomposition slot tables are left untouched.
+    pub fn compact_slots(&mut self) -> usize {
+        self.slots.borrow_mut().compact()
+    }
+
     pub fn debug_dump_slot_table_groups(&self) -> Vec<(usize, Key, Option<ScopeId>, usize)> {
         self.slots.borrow().debug_dump_groups()
     }
@@ -3510,8 +4988,26 @@ impl<A: Applier + 'static> Composition<A> {
         self.slots.borrow().debug_dump_all_slots()
     }
 
+    /// Starts or stops counting recompositions per scope. Starting a new
+    /// session discards the counts of the previous one.
+    pub fn set_debug_scope_recompose_counting(&self, enabled: bool) {
+        self.runtime_handle().set_scope_recompose_counting(enabled);
+    }
+
+    /// Returns how often each live scope recomposed since counting started,
+    /// most recomposed first. Scopes that never recomposed are omitted.
+    pub fn debug_scope_recompose_counts(&self) -> Vec<(ScopeId, usize)> {
+        self.runtime_handle().scope_recompose_counts()
+    }
+
+    /// Returns the derived state cycles detected since the last call.
+    pub fn take_derived_state_cycles(&self) -> Vec<DerivedStateCycle> {
+        self.runtime_handle().take_derived_state_cycles()
+    }
+
     pub fn process_invalid_scopes(&mut self) -> Result<bool, NodeError> {
         let runtime_handle = self.runtime_handle();
+        runtime_handle.flush_deferred_writes();
         let mut did_recompose = false;
         let mut loop_count = 0;
         loop {
@@ -3536,9 +5032,19 @@ impl<A: Applier + 'static> Composition<A> {
             if scopes.is_empty() {
                 continue;
             }
+            // Recompose top-down so a parent runs before its children: the
+            // parent may recompose them itself, or change what they read.
+            // Scopes whose group is gone keep their order at the end.
+            {
+                let slots = self.slots.borrow();
+                scopes.sort_by_cached_key(|scope| {
+                    slots.group_index_of_scope(scope.id()).unwrap_or(usize::MAX)
+                });
+            }
             did_recompose = true;
+            runtime_handle.record_recompositions(scopes.len() as u64);
             let runtime_clone = runtime_handle.clone();
-            let (mut commands, side_effects) = {
+            let (mut commands, side_effects, failure) = {
                 let composer = Composer::new(
                     self.slots_host(),
                     self.applier_host(),
@@ -3548,12 +5054,18 @@ impl<A: Applier + 'static> Composition<A> {
                 );
                 self.observer.begin_frame();
                 composer.install(|composer| {
-                    for scope in scopes.iter() {
-                        composer.recranpose_group(scope);
+                    let mut failure = None;
+                    for (index, scope) in scopes.iter().enumerate() {
+                        if let Err(panic) = composer.recranpose_group_catching(scope) {
+                            // Scopes after the failed one wait for the next pass.
+                            scopes[index + 1..].iter().for_each(RecomposeScope::requeue);
+                            failure = Some(panic);
+                            break;
+                        }
                     }
                     let commands = composer.take_commands();
                     let side_effects = composer.take_side_effects();
-                    (commands, side_effects)
+                    (commands, side_effects, failure)
                 })
             };
             {
@@ -3568,6 +5080,9 @@ impl<A: Applier + 'static> Composition<A> {
             for effect in side_effects {
                 effect();
             }
+            if let Some(failure) = failure {
+                return Err(failure.into_error());
+            }
             runtime_handle.drain_ui();
         }
         if !self.runtime.has_updates()
@@ -3577,9 +5092,28 @@ impl<A: Applier + 'static> Composition<A> {
         {
             self.runtime.set_needs_frame(false);
         }
+        runtime_handle.run_idle_callbacks();
         Ok(did_recompose)
     }
 
+    /// Composes pending content and hands the root to `measure` ahead of the
+    /// first visible frame, e.g. while the platform surface is still being
+    /// set up.
+    ///
+    /// Returns `None` when nothing has been composed yet.
+    pub fn prewarm<R>(
+        &mut self,
+        measure: impl FnOnce(&mut A, NodeId) -> R,
+    ) -> Result<Option<R>, NodeError> {
+        self.process_invalid_scopes()?;
+        self.flush_pending_node_updates()?;
+        let Some(root) = self.root else {
+            return Ok(None);
+        };
+        let mut applier = self.applier_mut();
+        Ok(Some(measure(&mut applier, root)))
+    }
+
     pub fn flush_pending_node_updates(&mut self) -> Result<(), NodeError> {
         let updates = self.runtime_handle().take_updates();
         let mut applier = self.applier.borrow_dyn();
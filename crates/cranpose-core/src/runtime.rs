@@ -7,15 +7,18 @@ use std::collections::{HashMap as StdHashMap, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
 use std::rc::{Rc, Weak};
-use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{mpsc, Arc};
 use std::task::{Context, Poll, Waker};
 use std::thread::ThreadId;
 use std::thread_local;
 
-use crate::frame_clock::FrameClock;
+use crate::frame_clock::{FrameClock, FramePriority};
 use crate::platform::RuntimeScheduler;
-use crate::{Applier, Command, FrameCallbackId, NodeError, RecomposeScopeInner, ScopeId};
+use crate::state_history::{RecordedWrite, StateHistory, StateMutation};
+use crate::{
+    Applier, Command, DerivedStateCycle, FrameCallbackId, NodeError, RecomposeScopeInner, ScopeId,
+};
 
 enum UiMessage {
     Task(Box<dyn FnOnce() + Send + 'static>),
@@ -134,6 +137,26 @@ pub(crate) fn runtime_handle_for(id: RuntimeId) -> Option<RuntimeHandle> {
     RUNTIME_HANDLES.with(|registry| registry.borrow().get(&id).cloned())
 }
 
+/// Looks up the handle for `id`, panicking with a thread-affinity diagnostic when the
+/// runtime is not registered on the current thread.
+pub(crate) fn expect_runtime_handle(id: RuntimeId) -> RuntimeHandle {
+    runtime_handle_for(id).unwrap_or_else(|| {
+        panic!(
+            "runtime {id:?} is not available on thread {}: state must be used on the UI thread \
+             that created its runtime, and only while that runtime is alive",
+            describe_current_thread()
+        )
+    })
+}
+
+fn describe_current_thread() -> String {
+    let thread = std::thread::current();
+    match thread.name() {
+        Some(name) => format!("'{name}' ({:?})", thread.id()),
+        None => format!("{:?}", thread.id()),
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct StateId(pub(crate) u32);
 
@@ -222,14 +245,90 @@ impl UiDispatcher {
     }
 }
 
+/// Counters describing the work a runtime has done since it was created or
+/// last reset. Returned by [`RuntimeHandle::metrics`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeMetrics {
+    /// Scopes recomposed by `process_invalid_scopes`.
+    pub recompositions: u64,
+    /// Calls into the measure phase.
+    pub layout_passes: u64,
+    /// Nodes created by `emit_node`.
+    pub nodes_created: u64,
+    /// Nodes the composer removed from the applier. A removed subtree counts once.
+    pub nodes_removed: u64,
+    /// Reused nodes updated because [`Node::needs_update`](crate::Node::needs_update)
+    /// reported changed inputs.
+    pub nodes_updated: u64,
+    /// Frames driven through `drain_frame_callbacks`.
+    pub frames: u64,
+    /// Composable groups skipped because their parameters were unchanged.
+    pub skipped_groups: u64,
+}
+
+#[derive(Default)]
+struct MetricCounters {
+    recompositions: AtomicU64,
+    layout_passes: AtomicU64,
+    nodes_created: AtomicU64,
+    nodes_removed: AtomicU64,
+    nodes_updated: AtomicU64,
+    frames: AtomicU64,
+    skipped_groups: AtomicU64,
+}
+
+impl MetricCounters {
+    fn add(counter: &AtomicU64, count: u64) {
+        counter.fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> RuntimeMetrics {
+        RuntimeMetrics {
+            recompositions: self.recompositions.load(Ordering::Relaxed),
+            layout_passes: self.layout_passes.load(Ordering::Relaxed),
+            nodes_created: self.nodes_created.load(Ordering::Relaxed),
+            nodes_removed: self.nodes_removed.load(Ordering::Relaxed),
+            nodes_updated: self.nodes_updated.load(Ordering::Relaxed),
+            frames: self.frames.load(Ordering::Relaxed),
+            skipped_groups: self.skipped_groups.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reset(&self) {
+        for counter in [
+            &self.recompositions,
+            &self.layout_passes,
+            &self.nodes_created,
+            &self.nodes_removed,
+            &self.nodes_updated,
+            &self.frames,
+            &self.skipped_groups,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
 struct RuntimeInner {
     scheduler: Arc<dyn RuntimeScheduler>,
     needs_frame: RefCell<bool>,
+    /// Whether the host was asked for a frame that has not started yet, so a
+    /// burst of invalidations wakes it only once.
+    frame_scheduled: Cell<bool>,
     node_updates: RefCell<Vec<Command>>, // FUTURE(no_std): replace Vec with ring buffer.
     invalid_scopes: RefCell<HashSet<ScopeId>>, // FUTURE(no_std): replace HashSet with sparse bitset.
     scope_queue: RefCell<Vec<(ScopeId, Weak<RecomposeScopeInner>)>>, // FUTURE(no_std): use smallvec-backed queue.
     frame_callbacks: RefCell<VecDeque<FrameCallbackEntry>>, // FUTURE(no_std): migrate to ring buffer.
     next_frame_callback_id: Cell<u64>,
+    idle_callbacks: RefCell<VecDeque<Box<dyn FnOnce() + 'static>>>, // FUTURE(no_std): migrate to ring buffer.
+    /// Commits of state written during measure or layout, in write order.
+    deferred_writes: RefCell<Vec<Box<dyn FnOnce() + 'static>>>, // FUTURE(no_std): replace Vec with ring buffer.
+    /// Latest value written to each state during measure or layout.
+    pending_writes: RefCell<HashMap<StateId, Box<dyn Any>>>,
+    /// Whether a node is being measured or laid out right now.
+    layout_phase: Cell<bool>,
+    /// Time passed to the latest `drain_frame_callbacks`.
+    frame_time_nanos: Cell<Option<u64>>,
     ui_dispatcher: Arc<UiDispatcherInner>,
     ui_rx: RefCell<mpsc::Receiver<UiMessage>>,
     local_tasks: RefCell<VecDeque<Box<dyn FnOnce() + 'static>>>,
@@ -240,6 +339,16 @@ struct RuntimeInner {
     next_task_id: Cell<u64>,
     task_waker: RefCell<Option<Waker>>,
     state_arena: StateArena,
+    /// Memoized composition local defaults, keyed by local key.
+    local_defaults: RefCell<HashMap<usize, Rc<dyn Any>>>,
+    metrics: MetricCounters,
+    /// Recompositions per scope; `None` while counting is disabled.
+    scope_recompositions: RefCell<Option<HashMap<ScopeId, usize>>>,
+    derived_state_cycles: RefCell<Vec<DerivedStateCycle>>,
+    state_history: RefCell<StateHistory>,
+    /// Mirrors `state_history.is_capturing()` so that state writes can skip
+    /// the history when nothing is recording.
+    state_history_capturing: Cell<bool>,
     runtime_id: RuntimeId,
 }
 
@@ -249,17 +358,30 @@ struct TaskEntry {
 }
 
 impl RuntimeInner {
+    /// Changes the state history and refreshes the capture flag.
+    fn update_state_history(&self, f: impl FnOnce(&mut StateHistory)) {
+        let mut history = self.state_history.borrow_mut();
+        f(&mut history);
+        self.state_history_capturing.set(history.is_capturing());
+    }
+
     fn new(scheduler: Arc<dyn RuntimeScheduler>) -> Self {
         let (tx, rx) = mpsc::channel();
         let dispatcher = Arc::new(UiDispatcherInner::new(scheduler.clone(), tx));
         Self {
             scheduler,
             needs_frame: RefCell::new(false),
+            frame_scheduled: Cell::new(false),
             node_updates: RefCell::new(Vec::new()),
             invalid_scopes: RefCell::new(HashSet::default()),
             scope_queue: RefCell::new(Vec::new()),
             frame_callbacks: RefCell::new(VecDeque::new()),
             next_frame_callback_id: Cell::new(1),
+            idle_callbacks: RefCell::new(VecDeque::new()),
+            deferred_writes: RefCell::new(Vec::new()),
+            pending_writes: RefCell::new(HashMap::default()),
+            layout_phase: Cell::new(false),
+            frame_time_nanos: Cell::new(None),
             ui_dispatcher: dispatcher,
             ui_rx: RefCell::new(rx),
             local_tasks: RefCell::new(VecDeque::new()),
@@ -270,6 +392,12 @@ impl RuntimeInner {
             next_task_id: Cell::new(1),
             task_waker: RefCell::new(None),
             state_arena: StateArena::default(),
+            local_defaults: RefCell::new(HashMap::default()),
+            metrics: MetricCounters::default(),
+            scope_recompositions: RefCell::new(None),
+            derived_state_cycles: RefCell::new(Vec::new()),
+            state_history: RefCell::new(StateHistory::default()),
+            state_history_capturing: Cell::new(false),
             runtime_id: RuntimeId::next(),
         }
     }
@@ -282,7 +410,9 @@ impl RuntimeInner {
 
     fn schedule(&self) {
         *self.needs_frame.borrow_mut() = true;
-        self.scheduler.schedule_frame();
+        if !self.frame_scheduled.replace(true) {
+            self.scheduler.schedule_frame();
+        }
     }
 
     fn enqueue_update(&self, command: Command) {
@@ -297,7 +427,24 @@ impl RuntimeInner {
     }
 
     fn has_updates(&self) -> bool {
-        !self.node_updates.borrow().is_empty() || self.has_invalid_scopes()
+        !self.node_updates.borrow().is_empty()
+            || self.has_invalid_scopes()
+            || !self.deferred_writes.borrow().is_empty()
+    }
+
+    fn defer_write(&self, id: StateId, value: Box<dyn Any>, commit: Box<dyn FnOnce() + 'static>) {
+        let first = self.pending_writes.borrow_mut().insert(id, value).is_none();
+        if first {
+            self.deferred_writes.borrow_mut().push(commit);
+            self.schedule();
+        }
+    }
+
+    fn flush_deferred_writes(&self) {
+        let pending = std::mem::take(&mut *self.deferred_writes.borrow_mut());
+        for commit in pending {
+            commit();
+        }
     }
 
     fn register_invalid_scope(&self, id: ScopeId, scope: Weak<RecomposeScopeInner>) {
@@ -335,6 +482,31 @@ impl RuntimeInner {
         !self.frame_callbacks.borrow().is_empty()
     }
 
+    fn is_idle(&self) -> bool {
+        !*self.needs_frame.borrow()
+            && !self.ui_dispatcher.has_pending()
+            && !self.has_updates()
+            && !self.has_frame_callbacks()
+            && !self.has_pending_ui()
+    }
+
+    fn on_idle(&self, callback: Box<dyn FnOnce() + 'static>) {
+        self.idle_callbacks.borrow_mut().push_back(callback);
+        // Wake the host so the next settled frame gets a chance to run it.
+        self.scheduler.schedule_frame();
+    }
+
+    /// Runs queued idle callbacks one at a time for as long as the runtime
+    /// stays idle, so work scheduled by a callback postpones the rest.
+    fn run_idle_callbacks(&self) {
+        while self.is_idle() {
+            let Some(callback) = self.idle_callbacks.borrow_mut().pop_front() else {
+                break;
+            };
+            let _ = crate::run_in_mutable_snapshot(callback);
+        }
+    }
+
     /// Queues a closure that is already bound to the UI thread's local queue.
     ///
     /// The closure may capture `Rc`/`RefCell` values because it never leaves the
@@ -387,6 +559,8 @@ impl RuntimeInner {
     }
 
     fn drain_ui(&self) {
+        // The frame is running: work scheduled from here on needs another one.
+        self.frame_scheduled.set(false);
         loop {
             let mut executed = false;
 
@@ -488,13 +662,18 @@ impl RuntimeInner {
         self.ui_conts.borrow_mut().remove(&id);
     }
 
-    fn register_frame_callback(&self, callback: Box<dyn FnOnce(u64) + 'static>) -> FrameCallbackId {
+    fn register_frame_callback(
+        &self,
+        priority: FramePriority,
+        callback: Box<dyn FnOnce(u64) + 'static>,
+    ) -> FrameCallbackId {
         let id = self.next_frame_callback_id.get();
         self.next_frame_callback_id.set(id + 1);
         self.frame_callbacks
             .borrow_mut()
             .push_back(FrameCallbackEntry {
                 id,
+                priority,
                 callback: Some(callback),
             });
         self.schedule();
@@ -530,14 +709,20 @@ impl RuntimeInner {
     }
 
     fn drain_frame_callbacks(&self, frame_time_nanos: u64) {
+        MetricCounters::add(&self.metrics.frames, 1);
+        self.frame_time_nanos.set(Some(frame_time_nanos));
+        self.frame_scheduled.set(false);
+        self.flush_deferred_writes();
         let mut callbacks = self.frame_callbacks.borrow_mut();
-        let mut pending: Vec<Box<dyn FnOnce(u64) + 'static>> = Vec::with_capacity(callbacks.len());
-        while let Some(mut entry) = callbacks.pop_front() {
-            if let Some(callback) = entry.callback.take() {
-                pending.push(callback);
-            }
-        }
+        let mut entries: Vec<FrameCallbackEntry> = callbacks.drain(..).collect();
         drop(callbacks);
+        // Stable, so equal priorities keep registration order. Callbacks
+        // registered while these run are queued for the next frame.
+        entries.sort_by_key(|entry| entry.priority);
+        let pending: Vec<Box<dyn FnOnce(u64) + 'static>> = entries
+            .into_iter()
+            .filter_map(|mut entry| entry.callback.take())
+            .collect();
 
         // Wrap ALL frame callbacks in a single mutable snapshot so state changes
         // are properly applied to the global snapshot and visible to subsequent reads.
@@ -558,6 +743,7 @@ impl RuntimeInner {
         {
             *self.needs_frame.borrow_mut() = false;
         }
+        self.run_idle_callbacks();
     }
 }
 
@@ -655,6 +841,33 @@ pub struct TaskHandle {
     runtime: RuntimeHandle,
 }
 
+/// Restores the runtime's previous layout phase when dropped.
+#[must_use = "the layout phase ends when the guard is dropped"]
+pub(crate) struct LayoutPhaseGuard {
+    runtime: RuntimeHandle,
+    previous: bool,
+}
+
+impl Drop for LayoutPhaseGuard {
+    fn drop(&mut self) {
+        if let Some(inner) = self.runtime.inner.upgrade() {
+            inner.layout_phase.set(self.previous);
+        }
+    }
+}
+
+/// Ends a state history replay when dropped, even if a restore panicked.
+struct StateHistoryReplayGuard<'a> {
+    inner: &'a RuntimeInner,
+}
+
+impl Drop for StateHistoryReplayGuard<'_> {
+    fn drop(&mut self) {
+        self.inner
+            .update_state_history(|history| history.set_replaying(false));
+    }
+}
+
 impl RuntimeHandle {
     pub fn id(&self) -> RuntimeId {
         self.id
@@ -671,6 +884,29 @@ impl RuntimeHandle {
             .unwrap_or_else(|| panic!("runtime dropped"))
     }
 
+    /// Returns the default value for the composition local `key`, running `init`
+    /// only the first time it is requested on this runtime.
+    pub(crate) fn composition_local_default<T: Clone + 'static>(
+        &self,
+        key: usize,
+        init: impl FnOnce() -> T,
+    ) -> T {
+        let Some(inner) = self.inner.upgrade() else {
+            return init();
+        };
+        let cached = inner.local_defaults.borrow().get(&key).cloned();
+        if let Some(value) = cached.and_then(|value| value.downcast::<T>().ok()) {
+            return (*value).clone();
+        }
+        // The borrow is released while `init` runs so defaults may read other locals.
+        let value = init();
+        inner
+            .local_defaults
+            .borrow_mut()
+            .insert(key, Rc::new(value.clone()));
+        value
+    }
+
     #[allow(dead_code)]
     pub(crate) fn alloc_value<T: 'static>(&self, value: T) -> StateId {
         self.with_state_arena(|arena| arena.alloc_raw(value))
@@ -763,10 +999,18 @@ impl RuntimeHandle {
     pub fn register_frame_callback(
         &self,
         callback: impl FnOnce(u64) + 'static,
+    ) -> Option<FrameCallbackId> {
+        self.register_frame_callback_with_priority(FramePriority::Normal, callback)
+    }
+
+    pub fn register_frame_callback_with_priority(
+        &self,
+        priority: FramePriority,
+        callback: impl FnOnce(u64) + 'static,
     ) -> Option<FrameCallbackId> {
         self.inner
             .upgrade()
-            .map(|inner| inner.register_frame_callback(Box::new(callback)))
+            .map(|inner| inner.register_frame_callback(priority, Box::new(callback)))
     }
 
     pub fn cancel_frame_callback(&self, id: FrameCallbackId) {
@@ -785,12 +1029,116 @@ impl RuntimeHandle {
         FrameClock::new(self.clone())
     }
 
+    /// Time of the frame being processed, or of the last one; `None` before
+    /// the first frame.
+    pub fn frame_time_nanos(&self) -> Option<u64> {
+        self.inner
+            .upgrade()
+            .and_then(|inner| inner.frame_time_nanos.get())
+    }
+
+    /// Queues `callback` to run once the runtime is idle: no frame is
+    /// requested and there are no invalid scopes, node updates, UI tasks, or
+    /// frame callbacks.
+    ///
+    /// Idle callbacks never run synchronously from this call. They are
+    /// checked whenever frame callbacks drain and whenever composition
+    /// settles; any new work postpones the callbacks still queued.
+    pub fn on_idle(&self, callback: impl FnOnce() + 'static) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.on_idle(Box::new(callback));
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.inner
+            .upgrade()
+            .map(|inner| inner.is_idle())
+            .unwrap_or(true)
+    }
+
+    pub(crate) fn run_idle_callbacks(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.run_idle_callbacks();
+        }
+    }
+
     pub fn set_needs_frame(&self, value: bool) {
         if let Some(inner) = self.inner.upgrade() {
             *inner.needs_frame.borrow_mut() = value;
         }
     }
 
+    /// Holds `value` as the pending write of state `id` until the next frame
+    /// starts or invalid scopes are processed, whichever comes first. `commit`
+    /// runs once per state at that point and takes the latest pending value.
+    pub(crate) fn defer_write<T: 'static>(
+        &self,
+        id: StateId,
+        value: T,
+        commit: impl FnOnce() + 'static,
+    ) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.defer_write(id, Box::new(value), Box::new(commit));
+        }
+    }
+
+    /// Latest value written to state `id` during the current layout pass.
+    pub(crate) fn pending_write<T: Clone + 'static>(&self, id: StateId) -> Option<T> {
+        let inner = self.inner.upgrade()?;
+        let pending = inner.pending_writes.borrow();
+        pending
+            .get(&id)
+            .and_then(|value| value.downcast_ref::<T>())
+            .cloned()
+    }
+
+    pub(crate) fn take_pending_write<T: 'static>(&self, id: StateId) -> Option<T> {
+        let inner = self.inner.upgrade()?;
+        let value = inner.pending_writes.borrow_mut().remove(&id)?;
+        value.downcast::<T>().ok().map(|value| *value)
+    }
+
+    pub(crate) fn flush_deferred_writes(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.flush_deferred_writes();
+        }
+    }
+
+    /// Runs `measure` with the runtime marked as measuring or laying out.
+    ///
+    /// State written inside it keeps its current value for the rest of the
+    /// pass; the write is committed, and its readers invalidated, after the
+    /// frame.
+    pub fn with_layout_phase<R>(&self, measure: impl FnOnce() -> R) -> R {
+        let _layout_phase = self.swap_layout_phase(true);
+        measure()
+    }
+
+    /// Leaves the layout phase until the guard drops, for composition that
+    /// runs inside a measure pass such as subcomposition.
+    pub(crate) fn suspend_layout_phase(&self) -> LayoutPhaseGuard {
+        self.swap_layout_phase(false)
+    }
+
+    fn swap_layout_phase(&self, active: bool) -> LayoutPhaseGuard {
+        let previous = self
+            .inner
+            .upgrade()
+            .is_some_and(|inner| inner.layout_phase.replace(active));
+        LayoutPhaseGuard {
+            runtime: self.clone(),
+            previous,
+        }
+    }
+
+    /// Returns true while a node is being measured or laid out.
+    pub fn in_layout_phase(&self) -> bool {
+        self.inner
+            .upgrade()
+            .is_some_and(|inner| inner.layout_phase.get())
+    }
+
     pub(crate) fn take_updates(&self) -> Vec<Command> {
         // FUTURE(no_std): return iterator over static buffer.
         self.inner
@@ -840,17 +1188,191 @@ impl RuntimeHandle {
             .unwrap_or(false)
     }
 
+    /// Returns `true` when called on the thread that owns this runtime.
+    pub fn is_ui_thread(&self) -> bool {
+        std::thread::current().id() == self.ui_thread_id
+    }
+
     pub fn assert_ui_thread(&self) {
-        debug_assert_eq!(
-            std::thread::current().id(),
+        debug_assert!(
+            self.is_ui_thread(),
+            "state of runtime {:?} mutated off its UI thread: expected {:?}, found {}",
+            self.id,
             self.ui_thread_id,
-            "state mutated off the runtime's UI thread"
+            describe_current_thread()
         );
     }
 
     pub fn dispatcher(&self) -> UiDispatcher {
         self.dispatcher.clone()
     }
+
+    /// Returns the runtime's work counters. A dropped runtime reports zeros.
+    pub fn metrics(&self) -> RuntimeMetrics {
+        self.inner
+            .upgrade()
+            .map(|inner| inner.metrics.snapshot())
+            .unwrap_or_default()
+    }
+
+    /// Sets every counter reported by [`metrics`](Self::metrics) back to zero.
+    pub fn reset_metrics(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.metrics.reset();
+        }
+    }
+
+    /// Counts one measure pass; called by the layout engine.
+    pub fn record_layout_pass(&self) {
+        self.record(|metrics| &metrics.layout_passes, 1);
+    }
+
+    pub(crate) fn record_recompositions(&self, count: u64) {
+        self.record(|metrics| &metrics.recompositions, count);
+    }
+
+    pub(crate) fn record_node_created(&self) {
+        self.record(|metrics| &metrics.nodes_created, 1);
+    }
+
+    pub(crate) fn record_node_removed(&self) {
+        self.record(|metrics| &metrics.nodes_removed, 1);
+    }
+
+    pub(crate) fn record_node_updated(&self) {
+        self.record(|metrics| &metrics.nodes_updated, 1);
+    }
+
+    pub(crate) fn record_skipped_group(&self) {
+        self.record(|metrics| &metrics.skipped_groups, 1);
+    }
+
+    pub(crate) fn set_scope_recompose_counting(&self, enabled: bool) {
+        if let Some(inner) = self.inner.upgrade() {
+            *inner.scope_recompositions.borrow_mut() = enabled.then(HashMap::default);
+        }
+    }
+
+    /// Starts recording every `MutableState` write on this runtime, replacing
+    /// any earlier history. Meant for debugging; recording clones each written
+    /// value, see [`MutableState::set_history_summary_only`](crate::MutableState::set_history_summary_only).
+    pub fn start_state_history(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.update_state_history(StateHistory::start);
+        }
+    }
+
+    /// Stops recording. The recorded history can still be inspected and replayed.
+    pub fn stop_state_history(&self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.update_state_history(StateHistory::stop);
+        }
+    }
+
+    /// The writes recorded since [`start_state_history`](Self::start_state_history), oldest first.
+    pub fn state_history(&self) -> Vec<StateMutation> {
+        self.inner
+            .upgrade()
+            .map(|inner| inner.state_history.borrow().mutations())
+            .unwrap_or_default()
+    }
+
+    /// Puts every recorded state back to its value right after `step`.
+    ///
+    /// States recorded as a summary only are left as they are. The restoring
+    /// writes are not recorded, so replaying back and forth keeps the history
+    /// intact. Returns `false` if `step` was not recorded.
+    pub fn replay_state_history(&self, step: usize) -> bool {
+        let Some(inner) = self.inner.upgrade() else {
+            return false;
+        };
+        let Some(restores) = inner.state_history.borrow().restores_for(step) else {
+            return false;
+        };
+        inner.update_state_history(|history| history.set_replaying(true));
+        let _replaying = StateHistoryReplayGuard { inner: &inner };
+        for (restore, value) in restores {
+            restore(value.as_ref());
+        }
+        true
+    }
+
+    /// `Some(keep_values)` while writes to `state` should be recorded.
+    pub(crate) fn state_history_capture(&self, state: StateId) -> Option<bool> {
+        let inner = self.inner.upgrade()?;
+        if !inner.state_history_capturing.get() {
+            return None;
+        }
+        let keep_values = inner.state_history.borrow().keeps_values(state);
+        Some(keep_values)
+    }
+
+    pub(crate) fn record_state_write<T: Clone + 'static>(
+        &self,
+        state: StateId,
+        write: Option<RecordedWrite<T>>,
+    ) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.state_history.borrow_mut().record(state, write);
+        }
+    }
+
+    pub(crate) fn set_state_history_summary_only(&self, state: StateId) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.state_history.borrow_mut().set_summary_only(state);
+        }
+    }
+
+    pub(crate) fn record_scope_recomposition(&self, id: ScopeId) {
+        if let Some(inner) = self.inner.upgrade() {
+            if let Some(counts) = inner.scope_recompositions.borrow_mut().as_mut() {
+                *counts.entry(id).or_default() += 1;
+            }
+        }
+    }
+
+    /// Drops the count of a disposed scope.
+    pub(crate) fn forget_scope_recompositions(&self, id: ScopeId) {
+        if let Some(inner) = self.inner.upgrade() {
+            if let Some(counts) = inner.scope_recompositions.borrow_mut().as_mut() {
+                counts.remove(&id);
+            }
+        }
+    }
+
+    pub(crate) fn scope_recompose_counts(&self) -> Vec<(ScopeId, usize)> {
+        let Some(inner) = self.inner.upgrade() else {
+            return Vec::new();
+        };
+        let mut counts: Vec<_> = inner
+            .scope_recompositions
+            .borrow()
+            .iter()
+            .flatten()
+            .map(|(&id, &count)| (id, count))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+    }
+
+    pub(crate) fn record_derived_state_cycle(&self, cycle: DerivedStateCycle) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.derived_state_cycles.borrow_mut().push(cycle);
+        }
+    }
+
+    pub(crate) fn take_derived_state_cycles(&self) -> Vec<DerivedStateCycle> {
+        self.inner
+            .upgrade()
+            .map(|inner| std::mem::take(&mut *inner.derived_state_cycles.borrow_mut()))
+            .unwrap_or_default()
+    }
+
+    fn record(&self, counter: impl FnOnce(&MetricCounters) -> &AtomicU64, count: u64) {
+        if let Some(inner) = self.inner.upgrade() {
+            MetricCounters::add(counter(&inner.metrics), count);
+        }
+    }
 }
 
 impl TaskHandle {
@@ -861,6 +1383,7 @@ impl TaskHandle {
 
 pub(crate) struct FrameCallbackEntry {
     id: FrameCallbackId,
+    priority: FramePriority,
     callback: Option<Box<dyn FnOnce(u64) + 'static>>,
 }
 
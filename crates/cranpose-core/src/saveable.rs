@@ -0,0 +1,289 @@
+//! Saveable state: values that can be written out and restored across
+//! compositions.
+//!
+//! Every [`rememberSaveable`](crate::rememberSaveable) call registers itself
+//! with the composition's [`SaveableRegistry`] under the anchor of its group.
+//! Anchors are allocated in composition order, so a fresh composition running
+//! the same content hands out the same anchors again. That is what lets
+//! [`Composition::export_state`](crate::Composition::export_state) and
+//! [`Composition::import_state`](crate::Composition::import_state) carry state
+//! across a rebuild (for example a dev hot-reload).
+
+use crate::AnchorId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::{Rc, Weak};
+use std::str::FromStr;
+
+/// A value that can be converted to and from a string for saved state.
+pub trait Saveable: Sized {
+    fn save(&self) -> String;
+    fn restore(saved: &str) -> Option<Self>;
+}
+
+macro_rules! impl_saveable_via_parse {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Saveable for $ty {
+                fn save(&self) -> String {
+                    self.to_string()
+                }
+
+                fn restore(saved: &str) -> Option<Self> {
+                    saved.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_saveable_via_parse!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String,
+);
+
+/// One saved value: the anchor it belongs to, the Rust type it was saved from
+/// and its string form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SavedValue {
+    anchor: AnchorId,
+    type_name: String,
+    value: String,
+}
+
+/// Serializable snapshot of every saveable value in a composition.
+///
+/// The text form (via `Display` / `FromStr`) is one `anchor<TAB>type<TAB>value`
+/// line per entry, with tabs, newlines and backslashes in values escaped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateSnapshot {
+    values: Vec<SavedValue>,
+}
+
+impl StateSnapshot {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl fmt::Display for StateSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for saved in &self.values {
+            writeln!(
+                f,
+                "{}\t{}\t{}",
+                saved.anchor.0,
+                saved.type_name,
+                escape(&saved.value)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a [`StateSnapshot`] string is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateSnapshotParseError {
+    /// 1-based line number of the offending entry.
+    pub line: usize,
+}
+
+impl fmt::Display for StateSnapshotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed state snapshot entry on line {}", self.line)
+    }
+}
+
+impl std::error::Error for StateSnapshotParseError {}
+
+impl FromStr for StateSnapshot {
+    type Err = StateSnapshotParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = Vec::new();
+        for (index, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let error = StateSnapshotParseError { line: index + 1 };
+            let mut fields = line.splitn(3, '\t');
+            let anchor = fields
+                .next()
+                .and_then(|anchor| anchor.parse::<usize>().ok())
+                .filter(|&anchor| anchor != AnchorId::INVALID.0)
+                .ok_or_else(|| error.clone())?;
+            let type_name = fields.next().ok_or_else(|| error.clone())?;
+            let value = fields.next().and_then(unescape).ok_or(error)?;
+            values.push(SavedValue {
+                anchor: AnchorId::new(anchor),
+                type_name: type_name.to_string(),
+                value,
+            });
+        }
+        values.sort_by_key(|saved| saved.anchor.0);
+        Ok(Self { values })
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+fn unescape(value: &str) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next()? {
+            '\\' => out.push('\\'),
+            't' => out.push('\t'),
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+/// A live saveable value: how to read it out and how to write a saved string
+/// back into it.
+pub(crate) struct SaveableEntry {
+    type_name: &'static str,
+    save: Box<dyn Fn() -> String>,
+    restore: Box<dyn Fn(&str)>,
+}
+
+impl SaveableEntry {
+    pub(crate) fn new(
+        type_name: &'static str,
+        save: impl Fn() -> String + 'static,
+        restore: impl Fn(&str) + 'static,
+    ) -> Self {
+        Self {
+            type_name,
+            save: Box::new(save),
+            restore: Box::new(restore),
+        }
+    }
+}
+
+/// Per-slot-table registry of live saveable values plus values imported for
+/// anchors that have not been composed yet.
+#[derive(Default)]
+pub(crate) struct SaveableRegistry {
+    entries: RefCell<HashMap<AnchorId, Rc<SaveableEntry>>>,
+    pending: RefCell<HashMap<AnchorId, SavedValue>>,
+}
+
+impl SaveableRegistry {
+    /// Registers `entry` under `anchor`. The returned registration removes it
+    /// again when dropped together with the remembered slot.
+    pub(crate) fn register(
+        self: &Rc<Self>,
+        anchor: AnchorId,
+        entry: SaveableEntry,
+    ) -> SaveableRegistration {
+        let entry = Rc::new(entry);
+        self.entries.borrow_mut().insert(anchor, Rc::clone(&entry));
+        SaveableRegistration {
+            registry: Rc::downgrade(self),
+            anchor,
+            entry,
+        }
+    }
+
+    /// Takes the imported string for `anchor` if it was saved from `type_name`.
+    pub(crate) fn take_pending(&self, anchor: AnchorId, type_name: &str) -> Option<String> {
+        let mut pending = self.pending.borrow_mut();
+        match pending.get(&anchor) {
+            Some(saved) if saved.type_name == type_name => {
+                pending.remove(&anchor).map(|saved| saved.value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Drops imported values that no composed anchor claimed.
+    pub(crate) fn clear_pending(&self) {
+        self.pending.borrow_mut().clear();
+    }
+
+    pub(crate) fn export(&self) -> StateSnapshot {
+        let entries: Vec<(AnchorId, Rc<SaveableEntry>)> = self
+            .entries
+            .borrow()
+            .iter()
+            .map(|(anchor, entry)| (*anchor, Rc::clone(entry)))
+            .collect();
+        let mut values: Vec<SavedValue> = entries
+            .into_iter()
+            .map(|(anchor, entry)| SavedValue {
+                anchor,
+                type_name: entry.type_name.to_string(),
+                value: (entry.save)(),
+            })
+            .collect();
+        values.sort_by_key(|saved| saved.anchor.0);
+        StateSnapshot { values }
+    }
+
+    /// Restores live entries whose anchor and type match, and keeps the rest
+    /// pending for the next composition pass.
+    pub(crate) fn import(&self, snapshot: StateSnapshot) {
+        let mut pending = self.pending.borrow_mut();
+        pending.clear();
+        for saved in snapshot.values {
+            let entry = self.entries.borrow().get(&saved.anchor).cloned();
+            match entry {
+                Some(entry) if entry.type_name == saved.type_name => {
+                    (entry.restore)(&saved.value);
+                }
+                Some(_) => {}
+                None => {
+                    pending.insert(saved.anchor, saved);
+                }
+            }
+        }
+    }
+}
+
+/// Keeps a saveable value registered for as long as its slot is alive.
+pub(crate) struct SaveableRegistration {
+    registry: Weak<SaveableRegistry>,
+    anchor: AnchorId,
+    entry: Rc<SaveableEntry>,
+}
+
+impl Drop for SaveableRegistration {
+    fn drop(&mut self) {
+        let Some(registry) = self.registry.upgrade() else {
+            return;
+        };
+        let Ok(mut entries) = registry.entries.try_borrow_mut() else {
+            return;
+        };
+        if entries
+            .get(&self.anchor)
+            .is_some_and(|entry| Rc::ptr_eq(entry, &self.entry))
+        {
+            entries.remove(&self.anchor);
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     hierarchical_slot_storage::HierarchicalSlotStorage,
     slot_storage::{GroupId, SlotStorage, StartGroup, ValueSlotId},
     split_slot_storage::SplitSlotStorage,
-    Key, NodeId, Owned, ScopeId, SlotTable,
+    AnchorId, Key, NodeId, Owned, ScopeId, SlotTable,
 };
 
 /// Factory function to create a backend of the specified kind.
@@ -110,6 +110,33 @@ impl SlotStorage for SlotBackend {
         }
     }
 
+    fn current_group_anchor(&self) -> AnchorId {
+        match self {
+            Self::Baseline(s) => SlotStorage::current_group_anchor(s),
+            Self::Chunked(s) => s.current_group_anchor(),
+            Self::Hierarchical(s) => s.current_group_anchor(),
+            Self::Split(s) => s.current_group_anchor(),
+        }
+    }
+
+    fn rewind_current_group(&mut self) {
+        match self {
+            Self::Baseline(s) => s.rewind_current_group(),
+            Self::Chunked(s) => s.rewind_current_group(),
+            Self::Hierarchical(s) => s.rewind_current_group(),
+            Self::Split(s) => s.rewind_current_group(),
+        }
+    }
+
+    fn group_index_of_scope(&self, scope: ScopeId) -> Option<usize> {
+        match self {
+            Self::Baseline(s) => s.group_index_of_scope(scope),
+            Self::Chunked(s) => s.group_index_of_scope(scope),
+            Self::Hierarchical(s) => s.group_index_of_scope(scope),
+            Self::Split(s) => s.group_index_of_scope(scope),
+        }
+    }
+
     fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group> {
         match self {
             Self::Baseline(s) => s.begin_recranpose_at_scope(scope),
@@ -235,6 +262,15 @@ impl SlotStorage for SlotBackend {
             Self::Split(s) => s.flush(),
         }
     }
+
+    fn compact(&mut self) -> usize {
+        match self {
+            Self::Baseline(s) => s.compact(),
+            Self::Chunked(s) => s.compact(),
+            Self::Hierarchical(s) => s.compact(),
+            Self::Split(s) => s.compact(),
+        }
+    }
 }
 
 // Additional debug methods not in the SlotStorage trait
@@ -6,7 +6,7 @@
 //! chunked storage, hierarchical, split layout/payload, etc.) to be used
 //! interchangeably.
 
-use crate::{Key, NodeId, Owned, ScopeId};
+use crate::{AnchorId, Key, NodeId, Owned, ScopeId};
 
 /// Opaque handle to a group in the slot storage.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -73,8 +73,22 @@ pub trait SlotStorage {
     /// can reattach them to the parent when skipping).
     fn nodes_in_current_group(&self) -> Vec<NodeId>;
 
+    /// Anchor of the innermost open group, or `AnchorId::INVALID` at the root.
+    /// Anchors are handed out in composition order, so a fresh storage that
+    /// composes the same structure reproduces the same anchors.
+    fn current_group_anchor(&self) -> AnchorId;
+
+    /// Move the cursor back to the start of the current group's body, so the
+    /// next `finalize_current_group` turns everything recorded in it into gaps.
+    fn rewind_current_group(&mut self);
+
     // ── recomposition ───────────────────────────────────────────────────────
 
+    /// Position of the group that owns `scope` in composition order, or
+    /// `None` if that scope is gone. A parent group always comes before its
+    /// children.
+    fn group_index_of_scope(&self, scope: ScopeId) -> Option<usize>;
+
     /// Start recomposing the group that owns `scope`. Returns the group we
     /// started, or `None` if that scope is gone.
     fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group>;
@@ -124,4 +138,54 @@ pub trait SlotStorage {
 
     /// Flush any deferred anchor rebuilds.
     fn flush(&mut self);
+
+    /// Drop gap slots and release spare capacity, returning how many slots were
+    /// removed. Only valid between passes; does nothing while a group is open.
+    fn compact(&mut self) -> usize;
+}
+
+/// How [`compact_slots`] treats a slot.
+pub(crate) enum CompactSlot {
+    /// Keep the slot as-is.
+    Keep,
+    /// Keep a group slot covering `extent` physical slots, itself included.
+    Group { extent: usize },
+    /// Remove the slot.
+    Drop,
+}
+
+/// Removes dropped slots from a flat slot sequence, rewriting the extent of every
+/// surviving group via `set_group_extent` so it only covers kept slots.
+pub(crate) fn compact_slots<S>(
+    slots: Vec<S>,
+    classify: impl Fn(&S) -> CompactSlot,
+    mut set_group_extent: impl FnMut(&mut S, usize),
+) -> Vec<S> {
+    let mut kept: Vec<S> = Vec::with_capacity(slots.len());
+    // (position in `kept`, old exclusive end) for each group still open.
+    let mut open: Vec<(usize, usize)> = Vec::new();
+    for (index, slot) in slots.into_iter().enumerate() {
+        while let Some(&(start, end)) = open.last() {
+            if index < end {
+                break;
+            }
+            let extent = kept.len() - start;
+            set_group_extent(&mut kept[start], extent);
+            open.pop();
+        }
+        match classify(&slot) {
+            CompactSlot::Drop => {}
+            CompactSlot::Keep => kept.push(slot),
+            CompactSlot::Group { extent } => {
+                open.push((kept.len(), index + extent.max(1)));
+                kept.push(slot);
+            }
+        }
+    }
+    while let Some((start, _)) = open.pop() {
+        let extent = kept.len() - start;
+        set_group_extent(&mut kept[start], extent);
+    }
+    kept.shrink_to_fit();
+    kept
 }
@@ -10,7 +10,7 @@
 #![allow(clippy::collapsible_match)]
 
 use crate::{
-    slot_storage::{GroupId, SlotStorage, StartGroup, ValueSlotId},
+    slot_storage::{compact_slots, CompactSlot, GroupId, SlotStorage, StartGroup, ValueSlotId},
     AnchorId, Key, NodeId, Owned, ScopeId,
 };
 use std::any::Any;
@@ -29,6 +29,9 @@ pub struct SlotTable {
     next_anchor_id: Cell<usize>,
     /// Tracks whether the most recent start() reused a gap slot.
     last_start_was_gap: bool,
+    /// Group stack depth below the enclosing-group frames pushed by each
+    /// active `start_recompose`.
+    recompose_bases: Vec<usize>,
 }
 
 enum Slot {
@@ -126,6 +129,16 @@ impl Default for Slot {
     }
 }
 
+impl Drop for SlotTable {
+    /// Releases slots last-to-first, like `mark_range_as_gaps`, so disposing a
+    /// whole composition also runs effect cleanups in reverse order.
+    fn drop(&mut self) {
+        while let Some(slot) = self.slots.pop() {
+            drop(slot);
+        }
+    }
+}
+
 impl SlotTable {
     const INITIAL_CAP: usize = 32;
     const LOCAL_GAP_SCAN: usize = 256; // tune
@@ -139,6 +152,7 @@ impl SlotTable {
             anchors_dirty: false,
             next_anchor_id: Cell::new(1), // Start at 1 (0 is INVALID)
             last_start_was_gap: false,
+            recompose_bases: Vec::new(),
         }
     }
 
@@ -303,6 +317,8 @@ impl SlotTable {
     /// Mark a range of slots as gaps instead of truncating.
     /// This preserves sibling components while allowing structure changes.
     /// When encountering a Group, recursively marks the entire group structure as gaps.
+    /// Remembered values in the range are dropped last-to-first, so effect
+    /// cleanups run in reverse registration order.
     pub fn mark_range_as_gaps(
         &mut self,
         start: usize,
@@ -312,6 +328,7 @@ impl SlotTable {
         let mut i = start;
         let end = end.min(self.slots.len());
         let mut marked_any = false;
+        let mut released = Vec::new();
 
         while i < end {
             if i >= self.slots.len() {
@@ -341,12 +358,15 @@ impl SlotTable {
 
             // Mark this slot as a gap, preserving Group metadata if it was a Group
             // This allows Groups to be properly matched and reused during tab switching
-            self.slots[i] = Slot::Gap {
-                anchor,
-                group_key,
-                group_scope,
-                group_len,
-            };
+            released.push(std::mem::replace(
+                &mut self.slots[i],
+                Slot::Gap {
+                    anchor,
+                    group_key,
+                    group_scope,
+                    group_len,
+                },
+            ));
             marked_any = true;
 
             // If it was a group, recursively mark its children as gaps too
@@ -374,12 +394,15 @@ impl SlotTable {
                         } else {
                             // For Nodes and other slots, mark as regular gaps
                             let child_anchor = self.slots[j].anchor_id();
-                            self.slots[j] = Slot::Gap {
-                                anchor: child_anchor,
-                                group_key: None,
-                                group_scope: None,
-                                group_len: 0,
-                            };
+                            released.push(std::mem::replace(
+                                &mut self.slots[j],
+                                Slot::Gap {
+                                    anchor: child_anchor,
+                                    group_key: None,
+                                    group_scope: None,
+                                    group_len: 0,
+                                },
+                            ));
                             marked_any = true;
                         }
                     }
@@ -404,6 +427,7 @@ impl SlotTable {
                 }
             }
         }
+        released.into_iter().rev().for_each(drop);
         marked_any
     }
 
@@ -566,6 +590,17 @@ impl SlotTable {
             .map(|frame| frame.force_children_recompose)
             .unwrap_or(false);
 
+        // Slots at or past the end of the enclosing group belong to the groups
+        // after it; a group started there is new, even when a sibling of the
+        // enclosing group has the same key.
+        let past_parent_end = self
+            .group_stack
+            .last()
+            .is_some_and(|frame| !frame.force_children_recompose && cursor >= frame.end);
+        if past_parent_end && matches!(self.slots.get(cursor), Some(Slot::Group { .. })) {
+            return self.insert_new_group_at_cursor(key);
+        }
+
         // === FAST PATH =======================================================
         if let Some(Slot::Group {
             key: existing_key,
@@ -1158,6 +1193,11 @@ impl SlotTable {
                 index
             );
             if let Slot::Group { key, len, .. } = *slot {
+                // The groups around this one get frames too, so slots the
+                // recomposition inserts or removes keep their lengths right.
+                self.recompose_bases.push(self.group_stack.len());
+                let enclosing = self.enclosing_group_frames(index);
+                self.group_stack.extend(enclosing);
                 let frame = GroupFrame {
                     key,
                     start: index,
@@ -1177,8 +1217,49 @@ impl SlotTable {
 
     fn end_recompose(&mut self) {
         if let Some(frame) = self.group_stack.pop() {
+            // Like `end`, the group grows to where its content ended; gaps
+            // moved in after that are left to the enclosing group.
+            let content_end = self.cursor.max(frame.start + 1);
+            self.commit_group_len(frame.key, frame.start, content_end);
             self.cursor = frame.end;
         }
+        let base = self.recompose_bases.pop().unwrap_or(self.group_stack.len());
+        while self.group_stack.len() > base {
+            if let Some(frame) = self.group_stack.pop() {
+                self.commit_group_len(frame.key, frame.start, frame.end);
+            }
+        }
+    }
+
+    /// Frames for the groups containing the slot at `index`, outermost first.
+    fn enclosing_group_frames(&self, index: usize) -> Vec<GroupFrame> {
+        let mut frames = Vec::new();
+        let mut position = 0;
+        while position < index {
+            match self.slots[position] {
+                Slot::Group { key, len, .. } if position + len > index => {
+                    frames.push(GroupFrame {
+                        key,
+                        start: position,
+                        end: position + len,
+                        force_children_recompose: false,
+                    });
+                    position += 1;
+                }
+                Slot::Group { len, .. } => position += len.max(1),
+                _ => position += 1,
+            }
+        }
+        frames
+    }
+
+    /// Grows the group at `start` to reach at least `end`.
+    fn commit_group_len(&mut self, group_key: Key, start: usize, end: usize) {
+        if let Some(Slot::Group { key, len, .. }) = self.slots.get_mut(start) {
+            if *key == group_key {
+                *len = (*len).max(end - start);
+            }
+        }
     }
 
     pub fn skip_current(&mut self) {
@@ -1435,6 +1516,7 @@ impl SlotTable {
     pub fn reset(&mut self) {
         self.cursor = 0;
         self.group_stack.clear();
+        self.recompose_bases.clear();
     }
 
     /// Step the cursor back by one position.
@@ -1487,6 +1569,50 @@ impl SlotTable {
         }
         marked
     }
+
+    /// Remove gap slots left behind by conditional content and release spare capacity.
+    ///
+    /// Group lengths are rewritten to cover only surviving slots and anchors are
+    /// rebuilt, so anchors of live slots keep resolving. Dropping a gap forfeits its
+    /// preserved group metadata; content that returns later is inserted afresh.
+    /// Must run between composition passes: returns 0 without touching anything
+    /// while a group is open.
+    pub fn compact(&mut self) -> usize {
+        if !self.group_stack.is_empty() {
+            return 0;
+        }
+        let before = self.slots.len();
+        self.slots = compact_slots(
+            std::mem::take(&mut self.slots),
+            |slot| match slot {
+                Slot::Gap { .. } => CompactSlot::Drop,
+                Slot::Group { len, .. } => CompactSlot::Group { extent: *len },
+                _ => CompactSlot::Keep,
+            },
+            |slot, extent| {
+                if let Slot::Group {
+                    len,
+                    has_gap_children,
+                    ..
+                } = slot
+                {
+                    *len = extent;
+                    *has_gap_children = false;
+                }
+            },
+        );
+        self.cursor = 0;
+        self.last_start_was_gap = false;
+        self.anchors_dirty = false;
+        self.rebuild_all_anchor_positions();
+        self.anchors.shrink_to_fit();
+        before - self.slots.len()
+    }
+
+    /// Number of physical slots currently allocated, gaps included.
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1534,6 +1660,24 @@ impl SlotStorage for SlotTable {
         SlotTable::node_ids_in_current_group(self)
     }
 
+    fn current_group_anchor(&self) -> AnchorId {
+        self.group_stack
+            .last()
+            .and_then(|frame| self.slots.get(frame.start))
+            .map(Slot::anchor_id)
+            .unwrap_or(AnchorId::INVALID)
+    }
+
+    fn rewind_current_group(&mut self) {
+        if let Some(frame) = self.group_stack.last() {
+            self.cursor = frame.start + 1;
+        }
+    }
+
+    fn group_index_of_scope(&self, scope: ScopeId) -> Option<usize> {
+        SlotTable::find_group_index_by_scope(self, scope)
+    }
+
     fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group> {
         SlotTable::start_recranpose_at_scope(self, scope).map(GroupId)
     }
@@ -1590,4 +1734,8 @@ impl SlotStorage for SlotTable {
     fn flush(&mut self) {
         SlotTable::flush_anchors_if_dirty(self);
     }
+
+    fn compact(&mut self) -> usize {
+        SlotTable::compact(self)
+    }
 }
@@ -635,4 +635,49 @@ mod tests {
         assert_eq!(triggered.get(), 0);
         observer.stop();
     }
+
+    #[test]
+    fn without_reads_suppresses_observation_until_outermost_block_exits() {
+        let _guard = reset_runtime();
+
+        let suppressed = SnapshotMutableState::new_in_arc(0, Arc::new(NeverEqual));
+        let resumed = SnapshotMutableState::new_in_arc(0, Arc::new(NeverEqual));
+        let triggered = Rc::new(Cell::new(0));
+        let observer_trigger = triggered.clone();
+
+        let observer = SnapshotStateObserver::new(|callback| callback());
+        observer.start();
+
+        let scope = TestScope("scope");
+        observer.observe_reads(
+            scope.clone(),
+            move |_| {
+                observer_trigger.set(observer_trigger.get() + 1);
+            },
+            || {
+                crate::without_reads(|| {
+                    crate::without_reads(|| {
+                        let _ = suppressed.get();
+                    });
+                    let _ = suppressed.get();
+                });
+                let _ = resumed.get();
+            },
+        );
+
+        let snapshot = take_mutable_snapshot(None, None);
+        snapshot.enter(|| {
+            suppressed.set(1);
+        });
+        snapshot.apply().check();
+        assert_eq!(triggered.get(), 0);
+
+        let snapshot = take_mutable_snapshot(None, None);
+        snapshot.enter(|| {
+            resumed.set(1);
+        });
+        snapshot.apply().check();
+        assert_eq!(triggered.get(), 1);
+        observer.stop();
+    }
 }
@@ -343,6 +343,46 @@ pub(crate) fn set_current_snapshot(snapshot: Option<AnySnapshot>) {
     });
 }
 
+thread_local! {
+    // Depth of nested `without_reads` blocks on this thread.
+    static READ_SUPPRESSION_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Restores the read suppression depth when a `without_reads` block exits,
+/// including when it unwinds.
+struct ReadSuppressionGuard;
+
+impl ReadSuppressionGuard {
+    fn enter() -> Self {
+        READ_SUPPRESSION_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        Self
+    }
+}
+
+impl Drop for ReadSuppressionGuard {
+    fn drop(&mut self) {
+        let _ = READ_SUPPRESSION_DEPTH.try_with(|depth| depth.set(depth.get().saturating_sub(1)));
+    }
+}
+
+/// Runs `block` without recording state reads.
+///
+/// Reads performed inside the block are not reported to snapshot read observers
+/// (and therefore to any `SnapshotStateObserver`) and do not subscribe the
+/// current recompose scope. Blocks may be nested; tracking resumes once the
+/// outermost block returns.
+pub fn without_reads<R>(block: impl FnOnce() -> R) -> R {
+    let _guard = ReadSuppressionGuard::enter();
+    block()
+}
+
+/// Returns true while a [`without_reads`] block is executing on this thread.
+pub(crate) fn reads_suppressed() -> bool {
+    READ_SUPPRESSION_DEPTH
+        .try_with(|depth| depth.get() > 0)
+        .unwrap_or(false)
+}
+
 /// Convenience helper that mirrors the legacy `take_mutable_snapshot` API.
 ///
 /// Returns a mutable snapshot rooted at the global snapshot with the provided
@@ -672,6 +712,9 @@ impl SnapshotState {
     }
 
     pub(crate) fn record_read(&self, state: &dyn StateObject) {
+        if reads_suppressed() {
+            return;
+        }
         if let Some(ref observer) = self.read_observer {
             observer(state);
         }
@@ -29,12 +29,12 @@
 //!   debug assertions), indirection cost for payload access
 
 use crate::{
-    slot_storage::{GroupId, SlotStorage, StartGroup, ValueSlotId},
+    slot_storage::{compact_slots, CompactSlot, GroupId, SlotStorage, StartGroup, ValueSlotId},
     AnchorId, Key, NodeId, Owned, ScopeId,
 };
 use std::any::Any;
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Split slot storage implementation.
 ///
@@ -58,10 +58,12 @@ pub struct SplitSlotStorage {
     next_anchor_id: Cell<usize>,
     /// Tracks whether last begin_group restored from gap.
     last_start_was_gap: bool,
+    /// Group stack depth below the enclosing-group frames pushed by each
+    /// active `begin_recranpose_at_scope`.
+    recompose_bases: Vec<usize>,
 }
 
 struct GroupFrame {
-    #[allow(dead_code)] // Tracked for debugging/future inspection tools
     key: Key,
     start: usize,
     end: usize,
@@ -118,10 +120,9 @@ impl Default for LayoutSlot {
 
 impl SplitSlotStorage {
     pub fn new() -> Self {
-        Self {
-            next_anchor_id: Cell::new(1), // Start at 1 (0 is INVALID)
-            ..Default::default()
-        }
+        let storage = Self::default();
+        storage.next_anchor_id.set(1); // Start at 1 (0 is INVALID)
+        storage
     }
 
     fn alloc_anchor(&self) -> AnchorId {
@@ -166,6 +167,30 @@ impl SplitSlotStorage {
     fn start_group(&mut self, key: Key) -> (usize, bool) {
         self.ensure_capacity();
 
+        // Reuse a group with a matching key in place
+        if let Some(LayoutSlot::Group {
+            key: existing_key,
+            len,
+            has_gap_children,
+            ..
+        }) = self.layout.get_mut(self.cursor)
+        {
+            if *existing_key == key {
+                let len = *len;
+                let had_gap_children = std::mem::replace(has_gap_children, false);
+                let start = self.cursor;
+                self.cursor += 1;
+                self.group_stack.push(GroupFrame {
+                    key,
+                    start,
+                    end: start + len + 1,
+                    force_children_recompose: had_gap_children,
+                });
+                self.last_start_was_gap = false;
+                return (start, false);
+            }
+        }
+
         // Check for gap group restoration
         if let Some(LayoutSlot::Gap {
             group_key: Some(gap_key),
@@ -304,6 +329,37 @@ impl SplitSlotStorage {
         }
         marked
     }
+
+    /// Frames for the groups containing the slot at `index`, outermost first.
+    fn enclosing_group_frames(&self, index: usize) -> Vec<GroupFrame> {
+        let mut frames = Vec::new();
+        let mut position = 0;
+        while position < index {
+            match self.layout.get(position) {
+                Some(LayoutSlot::Group { key, len, .. }) if position + len >= index => {
+                    frames.push(GroupFrame {
+                        key: *key,
+                        start: position,
+                        end: position + len + 1,
+                        force_children_recompose: false,
+                    });
+                    position += 1;
+                }
+                Some(LayoutSlot::Group { len, .. }) => position += len + 1,
+                _ => position += 1,
+            }
+        }
+        frames
+    }
+
+    /// Grows the group at `start` to reach at least `end`.
+    fn commit_group_len(&mut self, group_key: Key, start: usize, end: usize) {
+        if let Some(LayoutSlot::Group { key, len, .. }) = self.layout.get_mut(start) {
+            if *key == group_key {
+                *len = (*len).max(end.saturating_sub(start + 1));
+            }
+        }
+    }
 }
 
 impl SlotStorage for SplitSlotStorage {
@@ -332,8 +388,8 @@ impl SlotStorage for SplitSlotStorage {
     }
 
     fn skip_current_group(&mut self) {
-        if let Some(LayoutSlot::Group { len, .. }) = self.layout.get(self.cursor) {
-            self.cursor += 1 + len;
+        if let Some(frame) = self.group_stack.last() {
+            self.cursor = frame.end;
         }
     }
 
@@ -349,20 +405,70 @@ impl SlotStorage for SplitSlotStorage {
         nodes
     }
 
+    fn current_group_anchor(&self) -> AnchorId {
+        self.group_stack
+            .last()
+            .and_then(|frame| self.layout.get(frame.start))
+            .map(LayoutSlot::anchor_id)
+            .unwrap_or(AnchorId::INVALID)
+    }
+
+    fn rewind_current_group(&mut self) {
+        if let Some(frame) = self.group_stack.last() {
+            self.cursor = frame.start + 1;
+        }
+    }
+
+    fn group_index_of_scope(&self, scope: ScopeId) -> Option<usize> {
+        self.layout.iter().position(
+            |slot| matches!(slot, LayoutSlot::Group { scope: Some(s), .. } if *s == scope),
+        )
+    }
+
     fn begin_recranpose_at_scope(&mut self, scope: ScopeId) -> Option<Self::Group> {
-        for (idx, slot) in self.layout.iter().enumerate() {
-            if let LayoutSlot::Group { scope: Some(s), .. } = slot {
-                if *s == scope {
-                    self.cursor = idx;
-                    return Some(GroupId::new(idx));
-                }
-            }
+        let index = self.group_index_of_scope(scope)?;
+        let Some(LayoutSlot::Group { key, len, .. }) = self.layout.get(index) else {
+            return None;
+        };
+        let frame = GroupFrame {
+            key: *key,
+            start: index,
+            end: index + len + 1,
+            force_children_recompose: false,
+        };
+        // The groups around this one get frames too, so their lengths cover
+        // whatever the recomposition adds.
+        self.recompose_bases.push(self.group_stack.len());
+        let enclosing = self.enclosing_group_frames(index);
+        self.group_stack.extend(enclosing);
+        self.group_stack.push(frame);
+        // Content resumes after the group's scope slot.
+        self.cursor = index + 1;
+        if matches!(
+            self.layout.get(self.cursor),
+            Some(LayoutSlot::ValueRef { .. })
+        ) {
+            self.cursor += 1;
         }
-        None
+        Some(GroupId::new(index))
     }
 
     fn end_recompose(&mut self) {
-        // No-op
+        let base = self.recompose_bases.pop().unwrap_or(self.group_stack.len());
+        let mut content_end = self.cursor;
+        if self.group_stack.len() > base {
+            if let Some(frame) = self.group_stack.pop() {
+                content_end = content_end.max(frame.end);
+                self.commit_group_len(frame.key, frame.start, content_end);
+                self.cursor = content_end;
+            }
+        }
+        while self.group_stack.len() > base {
+            if let Some(frame) = self.group_stack.pop() {
+                content_end = content_end.max(frame.end);
+                self.commit_group_len(frame.key, frame.start, content_end);
+            }
+        }
     }
 
     fn alloc_value_slot<T: 'static>(&mut self, init: impl FnOnce() -> T) -> Self::ValueSlot {
@@ -499,6 +605,7 @@ impl SlotStorage for SplitSlotStorage {
     fn reset(&mut self) {
         self.cursor = 0;
         self.group_stack.clear();
+        self.recompose_bases.clear();
     }
 
     fn flush(&mut self) {
@@ -522,9 +629,76 @@ impl SlotStorage for SplitSlotStorage {
             self.anchors_dirty = false;
         }
     }
+
+    fn compact(&mut self) -> usize {
+        if !self.group_stack.is_empty() {
+            return 0;
+        }
+        let before = self.layout.len();
+        self.layout = compact_slots(
+            std::mem::take(&mut self.layout),
+            |slot| match slot {
+                LayoutSlot::Gap { .. } => CompactSlot::Drop,
+                LayoutSlot::Group { len, .. } => CompactSlot::Group { extent: len + 1 },
+                _ => CompactSlot::Keep,
+            },
+            |slot, extent| {
+                if let LayoutSlot::Group {
+                    len,
+                    has_gap_children,
+                    ..
+                } = slot
+                {
+                    *len = extent - 1;
+                    *has_gap_children = false;
+                }
+            },
+        );
+        // Payloads only referenced from dropped gaps are no longer reachable.
+        let live: HashSet<usize> = self
+            .layout
+            .iter()
+            .filter_map(|slot| match slot {
+                LayoutSlot::ValueRef { anchor } => Some(anchor.0),
+                _ => None,
+            })
+            .collect();
+        let dead: Vec<usize> = self
+            .payload
+            .keys()
+            .copied()
+            .filter(|anchor| !live.contains(anchor))
+            .collect();
+        self.release_payloads(dead);
+        self.payload.shrink_to_fit();
+        self.cursor = 0;
+        self.last_start_was_gap = false;
+        self.anchors_dirty = true;
+        self.flush();
+        before - self.layout.len()
+    }
+}
+
+impl Drop for SplitSlotStorage {
+    /// Releases payloads last-to-first so disposing a whole composition runs
+    /// effect cleanups in reverse order.
+    fn drop(&mut self) {
+        let anchors: Vec<usize> = self.payload.keys().copied().collect();
+        self.release_payloads(anchors);
+    }
 }
 
 impl SplitSlotStorage {
+    /// Drops the payloads stored under `anchors`, newest anchor first. Anchors
+    /// are allocated in composition order, so this mirrors the last-to-first
+    /// release of the other backends.
+    fn release_payloads(&mut self, mut anchors: Vec<usize>) {
+        anchors.sort_unstable_by(|a, b| b.cmp(a));
+        for anchor in anchors {
+            drop(self.payload.remove(&anchor));
+        }
+    }
+
     /// Debug method to dump all groups.
     pub fn debug_dump_groups(&self) -> Vec<(usize, Key, Option<ScopeId>, usize)> {
         self.layout
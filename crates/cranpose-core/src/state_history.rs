@@ -0,0 +1,148 @@
+//! Opt-in recording of `MutableState` writes for time-travel debugging.
+//!
+//! While recording, every [`MutableState::replace`](crate::MutableState::replace)
+//! and [`MutableState::update`](crate::MutableState::update) logs the value
+//! before and after the write. [`RuntimeHandle::replay_state_history`]
+//! then puts every recorded state back to how it was right after a given step.
+//!
+//! [`RuntimeHandle::replay_state_history`]: crate::RuntimeHandle::replay_state_history
+
+use crate::collections::map::HashSet;
+use crate::StateId;
+use std::any::Any;
+use std::rc::Rc;
+
+/// One recorded state write.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateMutation {
+    /// Position in the history, starting at 0.
+    pub step: usize,
+    pub state: StateId,
+    /// Type of the state's value, e.g. `i32`.
+    pub type_name: &'static str,
+    /// `false` for states recorded as a summary only; replay leaves them as they are.
+    pub restorable: bool,
+}
+
+/// Writes a recorded value back into its state.
+pub(crate) type RestoreFn = Rc<dyn Fn(&dyn Any)>;
+
+/// Values captured for a restorable write of a `T`.
+pub(crate) struct RecordedWrite<T> {
+    pub(crate) before: T,
+    pub(crate) after: T,
+    pub(crate) restore: RestoreFn,
+}
+
+/// Type-erased [`RecordedWrite`].
+struct RecordedValues {
+    before: Rc<dyn Any>,
+    after: Rc<dyn Any>,
+    restore: RestoreFn,
+}
+
+struct HistoryEntry {
+    mutation: StateMutation,
+    values: Option<RecordedValues>,
+}
+
+/// A restore action collected by [`StateHistory::restores_for`], run once the
+/// history is no longer borrowed.
+pub(crate) type PendingRestore = (RestoreFn, Rc<dyn Any>);
+
+#[derive(Default)]
+pub(crate) struct StateHistory {
+    recording: bool,
+    entries: Vec<HistoryEntry>,
+    /// States whose values are too large or too sensitive to keep.
+    summary_only: HashSet<StateId>,
+    replaying: bool,
+}
+
+impl StateHistory {
+    /// Starts a fresh history, dropping any earlier one.
+    pub(crate) fn start(&mut self) {
+        self.recording = true;
+        self.entries.clear();
+    }
+
+    /// Stops recording; the history stays available for replay.
+    pub(crate) fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    pub(crate) fn set_summary_only(&mut self, state: StateId) {
+        self.summary_only.insert(state);
+    }
+
+    /// Whether writes should be recorded; writes made by replay itself are not.
+    pub(crate) fn is_capturing(&self) -> bool {
+        self.recording && !self.replaying
+    }
+
+    pub(crate) fn keeps_values(&self, state: StateId) -> bool {
+        !self.summary_only.contains(&state)
+    }
+
+    pub(crate) fn record<T: Clone + 'static>(
+        &mut self,
+        state: StateId,
+        write: Option<RecordedWrite<T>>,
+    ) {
+        let values = write.map(|write| RecordedValues {
+            before: Rc::new(write.before),
+            after: Rc::new(write.after),
+            restore: write.restore,
+        });
+        self.entries.push(HistoryEntry {
+            mutation: StateMutation {
+                step: self.entries.len(),
+                state,
+                type_name: std::any::type_name::<T>(),
+                restorable: values.is_some(),
+            },
+            values,
+        });
+    }
+
+    pub(crate) fn mutations(&self) -> Vec<StateMutation> {
+        self.entries
+            .iter()
+            .map(|entry| entry.mutation.clone())
+            .collect()
+    }
+
+    /// The writes that put every restorable state back to its value right
+    /// after `step`: the last write at or before `step`, or the value before
+    /// the first write after it. `None` if `step` was never recorded.
+    pub(crate) fn restores_for(&self, step: usize) -> Option<Vec<PendingRestore>> {
+        if step >= self.entries.len() {
+            return None;
+        }
+        let mut seen = HashSet::default();
+        let mut restores = Vec::new();
+        for entry in self.entries[..=step].iter().rev() {
+            if let Some(values) = &entry.values {
+                if seen.insert(entry.mutation.state) {
+                    restores.push((values.restore.clone(), values.after.clone()));
+                }
+            }
+        }
+        for entry in &self.entries[step + 1..] {
+            if let Some(values) = &entry.values {
+                if seen.insert(entry.mutation.state) {
+                    restores.push((values.restore.clone(), values.before.clone()));
+                }
+            }
+        }
+        Some(restores)
+    }
+
+    pub(crate) fn set_replaying(&mut self, replaying: bool) {
+        self.replaying = replaying;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/state_history_tests.rs"]
+mod tests;
@@ -307,6 +307,10 @@ pub struct SubcomposeState {
     /// Maps slot to its content type for efficient lookup during reuse.
     slot_content_types: HashMap<SlotId, u64>,
     precomposed_nodes: HashMap<SlotId, Vec<NodeId>>, // FUTURE(no_std): use arena-backed precomposition lists.
+    /// Precomposed slots, least recently precomposed first.
+    precomposed_order: VecDeque<SlotId>,
+    /// Maximum number of unplaced precomposed slots to keep.
+    max_precomposed: usize,
     policy: Box<dyn SlotReusePolicy>,
     pub(crate) current_index: usize,
     pub(crate) reusable_count: usize,
@@ -355,6 +359,10 @@ const DEFAULT_MAX_REUSABLE_PER_TYPE: usize = 5;
 /// Matches RecyclerView's default cache size.
 const DEFAULT_MAX_REUSABLE_UNTYPED: usize = 10;
 
+/// Default maximum of precomposed slots kept while nothing places them.
+/// Beyond it the least recently precomposed slots are disposed.
+const DEFAULT_MAX_PRECOMPOSED: usize = 8;
+
 impl SubcomposeState {
     /// Creates a new [`SubcomposeState`] using the supplied reuse policy.
     pub fn new(policy: Box<dyn SlotReusePolicy>) -> Self {
@@ -365,6 +373,8 @@ impl SubcomposeState {
             reusable_nodes_untyped: VecDeque::new(),
             slot_content_types: HashMap::default(),
             precomposed_nodes: HashMap::default(), // FUTURE(no_std): initialize arena-backed precomposition map.
+            precomposed_order: VecDeque::new(),
+            max_precomposed: DEFAULT_MAX_PRECOMPOSED,
             policy,
             current_index: 0,
             reusable_count: 0,
@@ -491,12 +501,26 @@ impl SubcomposeState {
     }
 
     /// Stores a precomposed node for the provided slot. Precomposed nodes stay
-    /// detached from the tree until they are activated by `register_active`.
+    /// detached from the tree until they are activated by `register_active`,
+    /// removed by [`remove_precomposed`](Self::remove_precomposed) or trimmed
+    /// by [`trim_precomposed`](Self::trim_precomposed).
+    ///
+    /// A slot that was active before this pass but has not been activated by
+    /// it yet now counts as precomposed only, so finishing the pass does not
+    /// also move its nodes to the reusable pool.
     pub fn register_precomposed(&mut self, slot_id: SlotId, node_id: NodeId) {
-        self.precomposed_nodes
-            .entry(slot_id)
-            .or_default()
-            .push(node_id);
+        if let Some(position) = self.active_order.iter().position(|slot| *slot == slot_id) {
+            if position >= self.current_index {
+                self.active_order.remove(position);
+            }
+        }
+        self.precomposed_order.retain(|slot| *slot != slot_id);
+        self.precomposed_order.push_back(slot_id);
+        let nodes = self.precomposed_nodes.entry(slot_id).or_default();
+        if nodes.contains(&node_id) {
+            return;
+        }
+        nodes.push(node_id);
         self.precomposed_count += 1;
     }
 
@@ -513,6 +537,15 @@ impl SubcomposeState {
         // reusable pools. During multi-pass measurement, nodes registered via register_active
         // are in the mapping but NOT in reusable pools (pools only get populated in finish_pass).
         // Without this fix, new virtual nodes are created each measure pass, losing children.
+        if let Some(node_id) = self
+            .precomposed_nodes
+            .get(&slot_id)
+            .and_then(|nodes| nodes.first().copied())
+        {
+            // Precomposed nodes already hold this slot's content; `register_active`
+            // moves them out of the precomposed set.
+            return Some(node_id);
+        }
         if let Some(nodes) = self.mapping.get_nodes(&slot_id) {
             let first_node = nodes.first().copied();
             if let Some(node_id) = first_node {
@@ -675,8 +708,11 @@ impl SubcomposeState {
             reusable_slots.insert(*slot);
         }
 
-        let mut keep_slots = active.clone();
+        // Precomposed slots wait for a pass that places them, so their
+        // compositions survive the passes in between.
+        let mut keep_slots = active;
         keep_slots.extend(reusable_slots);
+        keep_slots.extend(self.precomposed_nodes.keys().copied());
         self.mapping.retain_slots(&keep_slots);
 
         // Keep slot compositions for both active AND reusable slots.
@@ -692,18 +728,6 @@ impl SubcomposeState {
         // Notify policy to prune its internal slot data
         self.policy.prune_slots(&keep_slots);
 
-        // Track count before pruning to compute removed count
-        let before_count = self.precomposed_count;
-        let mut removed_from_precomposed = 0usize;
-        self.precomposed_nodes.retain(|slot, nodes| {
-            if active.contains(slot) {
-                true
-            } else {
-                removed_from_precomposed += nodes.len();
-                false
-            }
-        });
-
         // Prune typed pools - retain only nodes that still have valid slots in mapping
         for pool in self.reusable_by_type.values_mut() {
             pool.retain(|(_, node)| self.mapping.get_slot(node).is_some());
@@ -716,7 +740,6 @@ impl SubcomposeState {
             .retain(|(_, node)| self.mapping.get_slot(node).is_some());
 
         self.update_reusable_count();
-        self.precomposed_count = before_count.saturating_sub(removed_from_precomposed);
     }
 
     /// Returns a snapshot of currently reusable nodes.
@@ -738,6 +761,12 @@ impl SubcomposeState {
         self.active_order.len()
     }
 
+    /// Returns whether `slot_id` was activated by the current pass, or by the
+    /// last one once it has finished.
+    pub fn is_activated(&self, slot_id: SlotId) -> bool {
+        self.active_order[..self.current_index.min(self.active_order.len())].contains(&slot_id)
+    }
+
     /// Returns the number of reusable slots in the pool.
     ///
     /// These are slots that were previously active but are now available for reuse
@@ -763,11 +792,39 @@ impl SubcomposeState {
         &self.precomposed_nodes
     }
 
+    /// Forgets the precomposed nodes of `slot_id` and returns them for
+    /// disposal. Slots that were activated meanwhile have none left.
+    pub fn remove_precomposed(&mut self, slot_id: SlotId) -> Vec<NodeId> {
+        self.precomposed_order.retain(|slot| *slot != slot_id);
+        let disposed = self.precomposed_nodes.remove(&slot_id).unwrap_or_default();
+        for node in &disposed {
+            self.mapping.remove_by_node(node);
+        }
+        self.precomposed_count = self.precomposed_count.saturating_sub(disposed.len());
+        disposed
+    }
+
+    /// Disposes the least recently precomposed slots beyond the precompose
+    /// budget and returns their nodes.
+    pub fn trim_precomposed(&mut self) -> Vec<NodeId> {
+        let precomposed = &self.precomposed_nodes;
+        self.precomposed_order
+            .retain(|slot| precomposed.contains_key(slot));
+        let mut disposed = Vec::new();
+        while self.precomposed_order.len() > self.max_precomposed {
+            if let Some(slot) = self.precomposed_order.front().copied() {
+                disposed.extend(self.remove_precomposed(slot));
+            }
+        }
+        disposed
+    }
+
     /// Removes any precomposed nodes whose slots were not activated during the
     /// current pass and returns their identifiers for disposal.
     pub fn drain_inactive_precomposed(&mut self) -> Vec<NodeId> {
         // FUTURE(no_std): drain into smallvec buffer.
-        let active: HashSet<SlotId> = self.active_order.iter().copied().collect();
+        let activated = &self.active_order[..self.current_index.min(self.active_order.len())];
+        let active: HashSet<SlotId> = activated.iter().copied().collect();
         let mut disposed = Vec::new();
         let mut empty_slots = Vec::new();
         for (slot, nodes) in self.precomposed_nodes.iter_mut() {
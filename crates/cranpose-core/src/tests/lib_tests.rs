@@ -32,6 +32,42 @@ impl Node for MountTrackingNode {
     }
 }
 
+struct UpdateTrackingNode {
+    label: &'static str,
+    dirty: bool,
+    updates: Rc<Cell<usize>>,
+}
+
+impl UpdateTrackingNode {
+    fn set_label(&mut self, label: &'static str) {
+        if self.label != label {
+            self.label = label;
+            self.dirty = true;
+        }
+    }
+}
+
+impl Node for UpdateTrackingNode {
+    fn update(&mut self) {
+        self.dirty = false;
+        self.updates.set(self.updates.get() + 1);
+    }
+
+    fn needs_update(&self) -> bool {
+        self.dirty
+    }
+}
+
+struct UpdateCountingNode {
+    updates: Rc<Cell<usize>>,
+}
+
+impl Node for UpdateCountingNode {
+    fn update(&mut self) {
+        self.updates.set(self.updates.get() + 1);
+    }
+}
+
 fn runtime_handle() -> (RuntimeHandle, Runtime) {
     let runtime = Runtime::new(Arc::new(TestScheduler));
     (runtime.handle(), runtime)
@@ -342,6 +378,55 @@ fn snapshot_state_list_commits_snapshot_mutations() {
     assert_eq!(list.to_vec(), vec![5, 10, 15]);
 }
 
+#[composable]
+fn list_order_reader(values: MutableState<Vec<i32>>, seen: Rc<RefCell<Vec<Vec<i32>>>>) {
+    seen.borrow_mut().push(values.value());
+}
+
+#[test]
+fn snapshot_state_list_swap_and_move_recompose_once() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let list = SnapshotStateList::with_runtime([1, 2, 3, 4], runtime.clone());
+    let seen: Rc<RefCell<Vec<Vec<i32>>>> = Rc::new(RefCell::new(Vec::new()));
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, {
+            let list = list.clone();
+            let seen = Rc::clone(&seen);
+            move || list_order_reader(list.as_mutable_state(), Rc::clone(&seen))
+        })
+        .expect("render");
+
+    list.swap(0, 3);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    list.move_item(0, 2);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    list.move_item(3, 0);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(
+        *seen.borrow(),
+        vec![
+            vec![1, 2, 3, 4],
+            vec![4, 2, 3, 1],
+            vec![2, 3, 4, 1],
+            vec![1, 2, 3, 4],
+        ]
+    );
+
+    // No-op reorders and out-of-bounds indices leave the list and its readers alone.
+    list.swap(1, 1);
+    list.move_item(2, 2);
+    let out_of_bounds = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.swap(0, 4)));
+    assert!(out_of_bounds.is_err());
+    let out_of_bounds =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.move_item(4, 0)));
+    assert!(out_of_bounds.is_err());
+    assert!(!runtime.has_invalid_scopes());
+    assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+    assert_eq!(seen.borrow().len(), 4);
+}
+
 #[test]
 fn snapshot_state_map_basic_operations() {
     let (runtime_handle, _runtime) = runtime_handle();
@@ -374,6 +459,29 @@ fn snapshot_state_map_basic_operations() {
     assert!(map.is_empty());
 }
 
+#[test]
+fn snapshot_state_map_compute_and_merge_update_in_place() {
+    let (runtime_handle, _runtime) = runtime_handle();
+    let counts: SnapshotStateMap<&'static str, u32> =
+        SnapshotStateMap::with_runtime([], runtime_handle.clone());
+
+    for key in ["a", "b", "a", "a"] {
+        counts.merge(key, 1, |old, new| old + new);
+    }
+    assert_eq!(counts.get(&"a"), Some(3));
+    assert_eq!(counts.get(&"b"), Some(1));
+
+    let doubled = counts.compute("a", |old| old.map(|count| count * 2));
+    assert_eq!(doubled, Some(6));
+    assert_eq!(counts.compute("c", |old| old.copied()), None);
+    assert!(!counts.contains_key(&"c"));
+
+    // Returning `None` removes the key.
+    assert_eq!(counts.compute("b", |_| None), None);
+    assert!(!counts.contains_key(&"b"));
+    assert_eq!(counts.len(), 1);
+}
+
 #[test]
 fn snapshot_state_map_commits_snapshot_mutations() {
     let (runtime_handle, _runtime) = runtime_handle();
@@ -474,6 +582,132 @@ fn launched_effect_runs_and_cancels() {
     }
 }
 
+#[test]
+fn remembered_coroutine_scope_cancels_tasks_when_leaving_composition() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let show = MutableState::with_runtime(true, runtime.clone());
+    let fetched = MutableState::with_runtime(0i32, runtime.clone());
+    type ClickHandler = Rc<dyn Fn()>;
+    let on_click: Rc<RefCell<Option<ClickHandler>>> = Rc::new(RefCell::new(None));
+    let scopes: Rc<RefCell<Vec<LaunchedEffectScope>>> = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = std::sync::mpsc::channel::<i32>();
+    let receiver = Rc::new(RefCell::new(Some(rx)));
+
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        let on_click = Rc::clone(&on_click);
+        let scopes = Rc::clone(&scopes);
+        let receiver = Rc::clone(&receiver);
+        composition
+            .render(0, move || {
+                if show.value() {
+                    let scope = rememberCoroutineScope!();
+                    scopes.borrow_mut().push(scope.clone());
+                    let receiver = Rc::clone(&receiver);
+                    let handler: ClickHandler = Rc::new(move || {
+                        if let Some(rx) = receiver.borrow_mut().take() {
+                            scope.launch_background(
+                                move |_| rx.recv().expect("value available"),
+                                move |value| fetched.set_value(value),
+                            );
+                        }
+                    });
+                    *on_click.borrow_mut() = Some(handler);
+                }
+            })
+            .expect("render succeeds");
+    };
+
+    render(&mut composition);
+    render(&mut composition);
+    assert!(
+        scopes.borrow()[0].is_active(),
+        "the scope survives recomposition"
+    );
+
+    let click = on_click.borrow().clone().expect("click handler");
+    click();
+    show.set_value(false);
+    render(&mut composition);
+    assert!(!scopes.borrow()[0].is_active());
+
+    // The fetch finishes after the composable left; its result is dropped.
+    tx.send(27).expect("send succeeds");
+    std::thread::sleep(Duration::from_millis(20));
+    composition
+        .process_invalid_scopes()
+        .expect("process succeeds");
+    runtime.drain_ui();
+    assert_eq!(fetched.value(), 0);
+
+    // Launching after disposal is a no-op.
+    let ran = Rc::new(Cell::new(false));
+    scopes.borrow()[0].launch({
+        let ran = Rc::clone(&ran);
+        move |_| ran.set(true)
+    });
+    runtime.drain_ui();
+    assert!(!ran.get());
+}
+
+#[test]
+fn immediate_launched_effect_is_visible_within_same_pass_and_cancels_on_key_change() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let key_state = MutableState::with_runtime(0i32, runtime.clone());
+    let immediate_value = Rc::new(Cell::new(-1));
+    let deferred_value = Rc::new(Cell::new(-1));
+    let observed: Rc<RefCell<Vec<(i32, i32)>>> = Rc::new(RefCell::new(Vec::new()));
+    let scopes: Rc<RefCell<Vec<LaunchedEffectScope>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        let immediate_value = Rc::clone(&immediate_value);
+        let deferred_value = Rc::clone(&deferred_value);
+        let observed = Rc::clone(&observed);
+        let scopes = Rc::clone(&scopes);
+        composition
+            .render(0, move || {
+                let key = key_state.value();
+                {
+                    let immediate_value = Rc::clone(&immediate_value);
+                    let scopes = Rc::clone(&scopes);
+                    LaunchedEffect!(key, EffectDispatch::Immediate, move |scope| {
+                        immediate_value.set(key);
+                        scopes.borrow_mut().push(scope);
+                    });
+                }
+                {
+                    let deferred_value = Rc::clone(&deferred_value);
+                    LaunchedEffect!(key, move |_| deferred_value.set(key));
+                }
+                let immediate_value = Rc::clone(&immediate_value);
+                let deferred_value = Rc::clone(&deferred_value);
+                let observed = Rc::clone(&observed);
+                cranpose_core::SideEffect(move || {
+                    observed
+                        .borrow_mut()
+                        .push((immediate_value.get(), deferred_value.get()));
+                });
+            })
+            .expect("render succeeds");
+    };
+
+    render(&mut composition);
+    assert_eq!(observed.borrow().as_slice(), [(0, -1)]);
+    assert_eq!(deferred_value.get(), 0);
+
+    key_state.set_value(1);
+    render(&mut composition);
+    assert_eq!(observed.borrow().as_slice(), [(0, -1), (1, 0)]);
+    let scopes = scopes.borrow();
+    assert_eq!(scopes.len(), 2);
+    assert!(
+        !scopes[0].is_active(),
+        "previous immediate run is cancelled"
+    );
+    assert!(scopes[1].is_active());
+}
+
 #[test]
 fn launched_effect_runs_side_effect_body() {
     let mut composition = Composition::new(MemoryApplier::new());
@@ -1234,6 +1468,161 @@ fn disposable_effect_host() -> NodeId {
     cranpose_test_node(TestTextNode::default)
 }
 
+#[test]
+fn state_observe_fires_outside_composition_until_dropped() {
+    let runtime = Runtime::new(Arc::new(TestScheduler));
+    let handle = runtime.handle();
+    let state = MutableState::with_runtime(0i32, handle.clone());
+    let seen: Rc<RefCell<Vec<i32>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let subscription = {
+        let seen = seen.clone();
+        state.observe(move |value| seen.borrow_mut().push(*value))
+    };
+    assert_eq!(state.watcher_count(), 1);
+
+    state.set(1);
+    handle.drain_ui();
+    assert_eq!(seen.borrow().as_slice(), [1]);
+
+    state.set(2);
+    state.set(3);
+    handle.drain_ui();
+    assert_eq!(seen.borrow().as_slice(), [1, 3]);
+
+    drop(subscription);
+    assert_eq!(state.watcher_count(), 0);
+    state.set(4);
+    handle.drain_ui();
+    assert_eq!(seen.borrow().as_slice(), [1, 3]);
+}
+
+#[test]
+fn debounced_state_updates_once_after_source_settles() {
+    const MS: u64 = 1_000_000;
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let source = MutableState::with_runtime(String::new(), runtime.clone());
+    let debounced: Rc<Cell<Option<State<String>>>> = Rc::new(Cell::new(None));
+
+    let mut render = {
+        let debounced = debounced.clone();
+        move || debounced.set(Some(rememberDebouncedState(source.as_state(), 100)))
+    };
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    let debounced = debounced.get().expect("debounced state");
+
+    // Sample the debounced value after every frame.
+    let mut samples = Vec::new();
+    let mut frame = |time: u64| {
+        runtime.drain_frame_callbacks(time * MS);
+        samples.push((time, debounced.peek()));
+    };
+
+    // Each keystroke lands before the previous one settled, restarting the
+    // wait. Like a host, observers run right after the frame's callbacks.
+    for (text, time) in [("a", 0), ("ab", 60), ("abc", 120)] {
+        frame(time);
+        source.set(text.to_string());
+        runtime.drain_ui();
+        frame(time + 30);
+    }
+    frame(200);
+    frame(220);
+    assert!(!runtime.has_frame_callbacks(), "the wait is over");
+    frame(400);
+
+    let changes: Vec<_> = samples
+        .windows(2)
+        .filter(|pair| pair[0].1 != pair[1].1)
+        .map(|pair| pair[1].clone())
+        .collect();
+    // Timed from the last change, not from the first frame after it.
+    assert_eq!(changes, [(220, "abc".to_string())]);
+}
+
+#[derive(Default)]
+struct CountingScheduler {
+    frames: AtomicUsize,
+}
+
+impl RuntimeScheduler for CountingScheduler {
+    fn schedule_frame(&self) {
+        self.frames.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+thread_local! {
+    static BATCHED_READS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[composable]
+fn batched_reader(state: MutableState<i32>, follow_up: MutableState<i32>) {
+    BATCHED_READS.with(|reads| reads.set(reads.get() + 1));
+    if state.value() == 1 {
+        // Runs after the pass, while the frame is already being processed.
+        cranpose_core::SideEffect(move || follow_up.set(1));
+    }
+}
+
+#[test]
+fn invalidations_from_one_handler_schedule_a_single_frame() {
+    let scheduler = Arc::new(CountingScheduler::default());
+    let mut composition =
+        Composition::with_runtime(MemoryApplier::new(), Runtime::new(scheduler.clone()));
+    let runtime = composition.runtime_handle();
+    let states: Vec<_> = (0..5)
+        .map(|_| MutableState::with_runtime(0i32, runtime.clone()))
+        .collect();
+    let follow_up = MutableState::with_runtime(0i32, runtime.clone());
+    let _subscription = follow_up.observe(|_| {});
+
+    let mut render = {
+        let states = states.clone();
+        move || {
+            for state in &states {
+                batched_reader(*state, follow_up);
+            }
+        }
+    };
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    // The host starts a frame; anything scheduled before it is consumed.
+    runtime.drain_frame_callbacks(0);
+    let frames = || scheduler.frames.load(Ordering::SeqCst);
+    let before = frames();
+
+    run_in_mutable_snapshot(|| {
+        for state in &states {
+            state.set(1);
+        }
+    })
+    .expect("handler snapshot");
+    assert_eq!(
+        frames() - before,
+        1,
+        "five writes should wake the host once"
+    );
+
+    BATCHED_READS.with(|reads| reads.set(0));
+    assert!(composition
+        .process_invalid_scopes()
+        .expect("recompose changed readers"));
+    assert_eq!(
+        BATCHED_READS.with(Cell::get),
+        5,
+        "each reader recomposes once in a single pass"
+    );
+    assert_eq!(
+        frames() - before,
+        2,
+        "a write made while the frame runs must schedule the next frame"
+    );
+}
+
 #[test]
 fn frame_callbacks_fire_in_registration_order() {
     let runtime = Runtime::new(Arc::new(TestScheduler));
@@ -1262,6 +1651,55 @@ fn frame_callbacks_fire_in_registration_order() {
     assert!(!runtime.needs_frame());
 }
 
+#[test]
+fn frame_callbacks_run_in_priority_order() {
+    let runtime = Runtime::new(Arc::new(TestScheduler));
+    let handle = runtime.handle();
+    let clock = runtime.frame_clock();
+    let events: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+    let nested = Rc::new(RefCell::new(None));
+    let mut guards = Vec::new();
+    for (priority, label) in [
+        (FramePriority::Draw, "draw"),
+        (FramePriority::Normal, "normal"),
+        (FramePriority::Layout, "layout"),
+        (FramePriority::Draw, "draw again"),
+        (FramePriority::Layout, "layout again"),
+    ] {
+        let events = events.clone();
+        let clock = clock.clone();
+        let nested = nested.clone();
+        guards.push(
+            clock
+                .clone()
+                .with_frame_nanos_with_priority(priority, move |_| {
+                    events.borrow_mut().push(label);
+                    if label == "draw" {
+                        // Registered mid-drain: waits for the next frame even though
+                        // it outranks callbacks still queued in this one.
+                        let events = events.clone();
+                        *nested.borrow_mut() =
+                            Some(clock.with_frame_nanos_with_priority(
+                                FramePriority::Layout,
+                                move |_| events.borrow_mut().push("nested layout"),
+                            ));
+                    }
+                }),
+        );
+    }
+
+    handle.drain_frame_callbacks(1);
+    assert_eq!(
+        events.borrow().as_slice(),
+        ["layout", "layout again", "normal", "draw", "draw again"]
+    );
+
+    handle.drain_frame_callbacks(2);
+    assert_eq!(events.borrow().last(), Some(&"nested layout"));
+    drop(guards);
+    assert!(!runtime.needs_frame());
+}
+
 #[test]
 fn next_frame_future_resolves_after_callback() {
     let runtime = Runtime::new(Arc::new(TestScheduler));
@@ -1381,6 +1819,94 @@ fn launched_effect_async_restarts_on_key_change() {
     }
 }
 
+#[composable]
+fn async_reader(
+    request: MutableState<i32>,
+    seen: Rc<RefCell<Vec<AsyncState<i32, String>>>>,
+    started: Rc<RefCell<Vec<i32>>>,
+) {
+    let id = request.value();
+    let result = cranpose_core::rememberAsync!(id, move |scope| {
+        started.borrow_mut().push(id);
+        Box::pin(async move {
+            scope.runtime().frame_clock().next_frame().await;
+            if id < 0 {
+                Err(format!("bad id {id}"))
+            } else {
+                Ok(id * 10)
+            }
+        })
+    });
+    seen.borrow_mut().push(result.value());
+}
+
+#[test]
+fn remember_async_exposes_loading_then_result_and_restarts_on_key_change() {
+    let mut composition =
+        Composition::with_runtime(MemoryApplier::new(), Runtime::new(Arc::new(TestScheduler)));
+    let runtime_handle = composition.runtime_handle();
+    let request = MutableState::with_runtime(1i32, runtime_handle.clone());
+    let seen: Rc<RefCell<Vec<AsyncState<i32, String>>>> = Rc::new(RefCell::new(Vec::new()));
+    let started: Rc<RefCell<Vec<i32>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let mut render = {
+        let seen = seen.clone();
+        let started = started.clone();
+        move || async_reader(request, seen.clone(), started.clone())
+    };
+
+    let root_key = location_key(file!(), line!(), column!());
+    composition
+        .render(root_key, &mut render)
+        .expect("initial render");
+    assert_eq!(seen.borrow().as_slice(), &[AsyncState::Loading]);
+
+    runtime_handle.drain_ui();
+    runtime_handle.drain_frame_callbacks(1);
+    runtime_handle.drain_ui();
+    composition
+        .process_invalid_scopes()
+        .expect("recompose after load");
+    assert_eq!(
+        seen.borrow().last(),
+        Some(&AsyncState::Success(10)),
+        "readers observe the loaded value"
+    );
+
+    // A key change drops back to Loading; switching again before the first
+    // future resolves cancels it, so only the latest result is published.
+    seen.borrow_mut().clear();
+    request.set_value(2);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose with key 2");
+    runtime_handle.drain_ui();
+    request.set_value(-3);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose with key -3");
+    assert_eq!(
+        seen.borrow().as_slice(),
+        &[AsyncState::Loading, AsyncState::Loading]
+    );
+
+    runtime_handle.drain_ui();
+    runtime_handle.drain_frame_callbacks(2);
+    runtime_handle.drain_ui();
+    composition
+        .process_invalid_scopes()
+        .expect("recompose after error");
+    assert_eq!(started.borrow().as_slice(), &[1, 2, -3]);
+    assert_eq!(
+        seen.borrow().as_slice(),
+        &[
+            AsyncState::Loading,
+            AsyncState::Loading,
+            AsyncState::Error("bad id -3".to_string()),
+        ]
+    );
+}
+
 #[test]
 fn draining_callbacks_clears_needs_frame() {
     let runtime = Runtime::new(Arc::new(TestScheduler));
@@ -1552,28 +2078,290 @@ fn recranpose_does_not_use_stale_indices_when_prior_scope_changes_length() {
 }
 
 #[test]
-fn recranpose_handles_removed_scopes_gracefully() {
+fn invalid_scopes_recompose_parents_before_children() {
     thread_local! {
-        static REMOVED_SCOPE_LOG: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        static RECOMPOSE_ORDER: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
     }
 
-    fn render_optional_scope(
-        composer: &Composer,
-        state_a: &MutableState<i32>,
-        toggle_group: &MutableState<bool>,
-    ) {
-        if toggle_group.value() {
-            let state_clone = *state_a;
-            composer.with_group(21, |composer| {
-                let state_capture = state_clone;
-                composer.set_recranpose_callback({
-                    move |composer| {
-                        let _ = state_capture.value();
-                        composer.register_side_effect(|| {
-                            REMOVED_SCOPE_LOG.with(|log| log.borrow_mut().push("scope"));
-                        });
-                    }
-                });
+    #[composable]
+    fn ordered_child(child_state: MutableState<i32>) {
+        RECOMPOSE_ORDER.with(|order| order.borrow_mut().push("child"));
+        let _ = child_state.value();
+    }
+
+    #[composable]
+    fn ordered_parent(parent_state: MutableState<i32>, child_state: MutableState<i32>) {
+        RECOMPOSE_ORDER.with(|order| order.borrow_mut().push("parent"));
+        let _ = parent_state.value();
+        ordered_child(child_state);
+    }
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let parent_state = MutableState::with_runtime(0i32, runtime.clone());
+    let child_state = MutableState::with_runtime(0i32, runtime.clone());
+
+    let mut render = move || ordered_parent(parent_state, child_state);
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    RECOMPOSE_ORDER.with(|order| order.borrow_mut().clear());
+
+    // Invalidate the child first so invalidation order disagrees with tree order.
+    child_state.set_value(1);
+    parent_state.set_value(1);
+    composition
+        .process_invalid_scopes()
+        .expect("recomposition succeeds");
+
+    RECOMPOSE_ORDER.with(|order| {
+        assert_eq!(order.borrow().as_slice(), ["parent", "child"]);
+    });
+}
+
+#[composable]
+fn optional_disposable_effect(show: MutableState<bool>, disposed: Rc<Cell<usize>>) {
+    if show.value() {
+        DisposableEffect!((), move |scope| {
+            scope.on_dispose(move || disposed.set(disposed.get() + 1))
+        });
+    }
+}
+
+#[test]
+fn recomposed_scope_releases_content_it_stops_emitting() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let show = MutableState::with_runtime(true, runtime);
+    let disposed = Rc::new(Cell::new(0));
+
+    let mut render = {
+        let disposed = Rc::clone(&disposed);
+        move || optional_disposable_effect(show, Rc::clone(&disposed))
+    };
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+
+    show.set(false);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose without the effect");
+    assert_eq!(
+        disposed.get(),
+        1,
+        "the effect is disposed by the recomposition, not by a later full render"
+    );
+}
+
+#[test]
+fn set_content_disposes_previous_content_and_composes_new_one() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let log: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let screen = |name: &'static str, log: &Rc<RefCell<Vec<&'static str>>>| {
+        let log = Rc::clone(log);
+        move || {
+            let log = Rc::clone(&log);
+            DisposableEffect!((), move |scope| {
+                log.borrow_mut().push(name);
+                let log = Rc::clone(&log);
+                scope.on_dispose(move || log.borrow_mut().push("disposed"))
+            });
+            let id = cranpose_test_node(TestTextNode::default);
+            with_node_mut(id, |node: &mut TestTextNode| node.text = name.to_string())
+                .expect("update text node");
+        }
+    };
+
+    composition
+        .set_content(screen("A", &log))
+        .expect("compose A");
+    let root_a = composition.root().expect("A emits a root");
+    assert_eq!(*log.borrow(), ["A"]);
+
+    composition
+        .set_content(screen("B", &log))
+        .expect("compose B");
+    assert_eq!(*log.borrow(), ["A", "disposed", "B"]);
+
+    let root_b = composition.root().expect("B emits a root");
+    assert_ne!(root_a, root_b);
+    let mut applier = composition.applier_mut();
+    assert!(applier.get_mut(root_a).is_err(), "A's node is removed");
+    let text = applier
+        .with_node(root_b, |node: &mut TestTextNode| node.text.clone())
+        .expect("B's node exists");
+    assert_eq!(text, "B");
+}
+
+thread_local! {
+    static TEARDOWN_LOG: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn logged_effect(
+    name: &'static str,
+) -> impl FnOnce(DisposableEffectScope) -> DisposableEffectResult {
+    move |scope| scope.on_dispose(move || TEARDOWN_LOG.with(|log| log.borrow_mut().push(name)))
+}
+
+#[composable]
+fn teardown_child() {
+    DisposableEffect!((), logged_effect("child first"));
+    DisposableEffect!((), logged_effect("child second"));
+}
+
+#[composable]
+fn teardown_subtree() {
+    DisposableEffect!((), logged_effect("connection"));
+    DisposableEffect!((), logged_effect("session"));
+    teardown_child();
+    DisposableEffect!((), logged_effect("subscription"));
+}
+
+#[composable]
+fn teardown_host(visible: MutableState<bool>) {
+    if visible.value() {
+        teardown_subtree();
+    }
+}
+
+#[test]
+fn disposable_effect_cleanups_run_in_reverse_registration_order() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let visible = MutableState::with_runtime(true, runtime);
+    TEARDOWN_LOG.with(|log| log.borrow_mut().clear());
+
+    let mut render = move || teardown_host(visible);
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    assert!(TEARDOWN_LOG.with(|log| log.borrow().is_empty()));
+
+    let reversed = [
+        "subscription",
+        "child second",
+        "child first",
+        "session",
+        "connection",
+    ];
+    visible.set(false);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose without the subtree");
+    assert_eq!(TEARDOWN_LOG.with(|log| log.take()), reversed);
+
+    // Disposing the whole composition tears down in the same order.
+    visible.set(true);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose with the subtree");
+    assert!(TEARDOWN_LOG.with(|log| log.borrow().is_empty()));
+    drop(composition);
+    assert_eq!(TEARDOWN_LOG.with(|log| log.take()), reversed);
+}
+
+#[test]
+fn reused_nodes_are_updated_only_when_inputs_change() {
+    thread_local! {
+        static UPDATES: Rc<Cell<usize>> = Rc::new(Cell::new(0));
+    }
+
+    #[composable]
+    fn labeled_node(label: MutableState<&'static str>, tick: MutableState<i32>) {
+        let _ = tick.value();
+        let text = label.value();
+        let id = cranpose_test_node(|| UpdateTrackingNode {
+            label: text,
+            dirty: false,
+            updates: UPDATES.with(Rc::clone),
+        });
+        with_node_mut(id, |node: &mut UpdateTrackingNode| node.set_label(text))
+            .expect("update node");
+    }
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let label = MutableState::with_runtime("a", runtime.clone());
+    let tick = MutableState::with_runtime(0i32, runtime.clone());
+    let updates = UPDATES.with(Rc::clone);
+
+    let mut render = move || labeled_node(label, tick);
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    assert_eq!(updates.get(), 1, "the first emission always updates");
+
+    tick.set_value(1);
+    assert!(composition
+        .process_invalid_scopes()
+        .expect("recomposition succeeds"));
+    assert_eq!(updates.get(), 1, "unchanged inputs queue no update");
+
+    label.set_value("b");
+    composition
+        .process_invalid_scopes()
+        .expect("recomposition succeeds");
+    assert_eq!(updates.get(), 2);
+}
+
+#[test]
+fn reused_nodes_without_a_dirty_flag_are_not_updated() {
+    thread_local! {
+        static UPDATES: Rc<Cell<usize>> = Rc::new(Cell::new(0));
+    }
+
+    #[composable]
+    fn counting_node(tick: MutableState<i32>) {
+        let _ = tick.value();
+        cranpose_test_node(|| UpdateCountingNode {
+            updates: UPDATES.with(Rc::clone),
+        });
+    }
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let tick = MutableState::with_runtime(0i32, runtime.clone());
+    let updates = UPDATES.with(Rc::clone);
+
+    let mut render = move || counting_node(tick);
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    assert_eq!(updates.get(), 1);
+
+    tick.set_value(1);
+    assert!(composition
+        .process_invalid_scopes()
+        .expect("recomposition succeeds"));
+    assert_eq!(updates.get(), 1, "reuse skips update() by default");
+    assert_eq!(runtime.metrics().nodes_updated, 0);
+}
+
+#[test]
+fn recranpose_handles_removed_scopes_gracefully() {
+    thread_local! {
+        static REMOVED_SCOPE_LOG: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn render_optional_scope(
+        composer: &Composer,
+        state_a: &MutableState<i32>,
+        toggle_group: &MutableState<bool>,
+    ) {
+        if toggle_group.value() {
+            let state_clone = *state_a;
+            composer.with_group(21, |composer| {
+                let state_capture = state_clone;
+                composer.set_recranpose_callback({
+                    move |composer| {
+                        let _ = state_capture.value();
+                        composer.register_side_effect(|| {
+                            REMOVED_SCOPE_LOG.with(|log| log.borrow_mut().push("scope"));
+                        });
+                    }
+                });
                 let _ = state_capture.value();
                 composer.register_side_effect(|| {
                     REMOVED_SCOPE_LOG.with(|log| log.borrow_mut().push("scope"));
@@ -1720,6 +2508,60 @@ fn state_invalidation_skips_parent_scope() {
     assert!(!composition.should_render());
 }
 
+thread_local! {
+    static DERIVED_INPUT: RefCell<Option<cranpose_core::MutableState<i32>>> =
+        const { RefCell::new(None) };
+    static DERIVED_READER_RECOMPOSITIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[composable]
+fn derived_parity_reader(is_even: cranpose_core::State<bool>) -> NodeId {
+    DERIVED_READER_RECOMPOSITIONS.with(|calls| calls.set(calls.get() + 1));
+    counted_text(is_even.value() as i32)
+}
+
+#[composable]
+fn derived_parity_host() -> NodeId {
+    let input = cranpose_core::useState(|| 0);
+    DERIVED_INPUT.with(|slot| *slot.borrow_mut() = Some(input));
+    let is_even = cranpose_core::derivedStateOfStructural(move || input.value() % 2 == 0);
+    derived_parity_reader(is_even)
+}
+
+#[test]
+fn structural_derived_state_skips_readers_when_result_is_equal() {
+    DERIVED_READER_RECOMPOSITIONS.with(|calls| calls.set(0));
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let root_key = location_key(file!(), line!(), column!());
+    composition
+        .render(root_key, || {
+            derived_parity_host();
+        })
+        .expect("initial render succeeds");
+    DERIVED_READER_RECOMPOSITIONS.with(|calls| assert_eq!(calls.get(), 1));
+
+    let input = DERIVED_INPUT
+        .with(|slot| *slot.borrow())
+        .expect("captured input");
+
+    // 0 -> 2 keeps the derived value `true`; the reader must not recompose.
+    input.set(2);
+    while composition
+        .process_invalid_scopes()
+        .expect("process invalid scopes succeeds")
+    {}
+    DERIVED_READER_RECOMPOSITIONS.with(|calls| assert_eq!(calls.get(), 1));
+
+    // 2 -> 3 flips the derived value; the reader recomposes.
+    input.set(3);
+    while composition
+        .process_invalid_scopes()
+        .expect("process invalid scopes succeeds")
+    {}
+    DERIVED_READER_RECOMPOSITIONS.with(|calls| assert_eq!(calls.get(), 2));
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Operation {
     Insert(NodeId),
@@ -1757,6 +2599,10 @@ impl Node for RecordingNode {
         }
         self.operations.push(Operation::Move { from, to });
     }
+
+    fn children(&self) -> Vec<NodeId> {
+        self.children.clone()
+    }
 }
 
 #[derive(Default)]
@@ -1882,6 +2728,147 @@ fn reorder_keyed_children_emits_moves() {
     }
 }
 
+#[test]
+fn rotation_moves_only_the_displaced_child() {
+    let mut slots = SlotBackend::default();
+    let mut applier = MemoryApplier::new();
+    let runtime = Runtime::new(Arc::new(TestScheduler));
+    let parent_id = applier.create(Box::new(RecordingNode::default()));
+    let children: Vec<NodeId> = (0..4)
+        .map(|_| {
+            applier.create(Box::new(TrackingChild {
+                parent: Some(parent_id),
+                ..Default::default()
+            }))
+        })
+        .collect();
+    applier
+        .with_node(parent_id, |node: &mut RecordingNode| {
+            node.children = children.clone();
+        })
+        .expect("seed parent state");
+
+    let mut rotated = children.clone();
+    rotated.rotate_left(1);
+    let operations = apply_child_diff(
+        &mut slots,
+        &mut applier,
+        &runtime,
+        parent_id,
+        children,
+        rotated.clone(),
+    );
+
+    assert_eq!(operations, vec![Operation::Move { from: 0, to: 3 }]);
+    let final_children = applier
+        .with_node(parent_id, |node: &mut RecordingNode| node.children.clone())
+        .expect("read rotated children");
+    assert_eq!(final_children, rotated);
+}
+
+#[test]
+fn reorder_leaves_children_the_composer_does_not_place() {
+    let mut slots = SlotBackend::default();
+    let mut applier = MemoryApplier::new();
+    let runtime = Runtime::new(Arc::new(TestScheduler));
+    let parent_id = applier.create(Box::new(RecordingNode::default()));
+    let children: Vec<NodeId> = (0..3)
+        .map(|_| {
+            applier.create(Box::new(TrackingChild {
+                parent: Some(parent_id),
+                ..Default::default()
+            }))
+        })
+        .collect();
+    // Attached by something other than this composer, e.g. a subcomposition.
+    let foreign = applier.create(Box::new(TrackingChild {
+        parent: Some(parent_id),
+        ..Default::default()
+    }));
+    applier
+        .with_node(parent_id, |node: &mut RecordingNode| {
+            node.children = children.iter().copied().chain([foreign]).collect();
+        })
+        .expect("seed parent state");
+
+    let mut rotated = children.clone();
+    rotated.rotate_left(1);
+    let operations = apply_child_diff(
+        &mut slots,
+        &mut applier,
+        &runtime,
+        parent_id,
+        children,
+        rotated.clone(),
+    );
+
+    assert_eq!(operations, vec![Operation::Move { from: 0, to: 2 }]);
+    let final_children = applier
+        .with_node(parent_id, |node: &mut RecordingNode| node.children.clone())
+        .expect("read rotated children");
+    let expected: Vec<NodeId> = rotated.into_iter().chain([foreign]).collect();
+    assert_eq!(final_children, expected);
+}
+
+#[test]
+fn successive_permutations_keep_applier_order_in_sync() {
+    let mut slots = SlotBackend::default();
+    let mut applier = MemoryApplier::new();
+    let runtime = Runtime::new(Arc::new(TestScheduler));
+    let parent_id = applier.create(Box::new(RecordingNode::default()));
+    let mut order: Vec<NodeId> = (0..9)
+        .map(|_| {
+            applier.create(Box::new(TrackingChild {
+                parent: Some(parent_id),
+                ..Default::default()
+            }))
+        })
+        .collect();
+    applier
+        .with_node(parent_id, |node: &mut RecordingNode| {
+            node.children = order.clone();
+        })
+        .expect("seed parent state");
+
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    for round in 0..25 {
+        let mut target = order.clone();
+        for index in (1..target.len()).rev() {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            target.swap(index, (seed >> 33) as usize % (index + 1));
+        }
+        applier
+            .with_node(parent_id, |node: &mut RecordingNode| {
+                node.operations.clear()
+            })
+            .expect("clear operations");
+
+        let operations = apply_child_diff(
+            &mut slots,
+            &mut applier,
+            &runtime,
+            parent_id,
+            order.clone(),
+            target.clone(),
+        );
+
+        assert!(
+            operations
+                .iter()
+                .all(|op| matches!(op, Operation::Move { .. })),
+            "round {round}: reorder must only move children: {operations:?}"
+        );
+        assert!(operations.len() < target.len(), "round {round}");
+        let actual = applier
+            .with_node(parent_id, |node: &mut RecordingNode| node.children.clone())
+            .expect("read children");
+        assert_eq!(actual, target, "round {round}");
+        order = target;
+    }
+}
+
 #[test]
 fn insert_and_remove_emit_expected_ops() {
     let mut slots = SlotBackend::default();
@@ -2046,17 +3033,55 @@ fn composition_local_default_value_used_outside_provider() {
 }
 
 #[test]
-fn composition_local_simple_subscription_test() {
-    // Simplified test to verify basic subscription behavior
+fn root_locals_reach_content_that_does_not_provide_them() {
     thread_local! {
-        static READER_RECOMPOSITIONS: Cell<usize> = const { Cell::new(0) };
-        static LAST_VALUE: Cell<i32> = const { Cell::new(-1) };
+        static READ_VALUES: RefCell<Vec<i32>> = const { RefCell::new(Vec::new()) };
     }
 
-    let local_value = compositionLocalOf(|| 0);
+    let local_counter = compositionLocalOf(|| 0);
     let mut composition = Composition::new(MemoryApplier::new());
     let runtime = composition.runtime_handle();
-    let trigger = MutableState::with_runtime(10, runtime.clone());
+    let trigger = MutableState::with_runtime(0, runtime.clone());
+
+    #[composable]
+    fn reader(local_counter: CompositionLocal<i32>, trigger: MutableState<i32>) {
+        let _ = trigger.value();
+        let value = local_counter.current();
+        READ_VALUES.with(|values| values.borrow_mut().push(value));
+    }
+
+    composition.provide_root_locals([local_counter.provides(3)]);
+    composition
+        .render(1, || {
+            reader(local_counter.clone(), trigger);
+            // Providers in the content take precedence over root ones.
+            CompositionLocalProvider([local_counter.provides(9)], || {
+                reader(local_counter.clone(), trigger);
+            });
+        })
+        .expect("initial composition");
+    assert_eq!(READ_VALUES.with(|values| values.take()), [3, 9]);
+
+    // Recomposed scopes still see the root value.
+    trigger.set_value(1);
+    let _ = composition
+        .process_invalid_scopes()
+        .expect("recompose readers");
+    assert_eq!(READ_VALUES.with(|values| values.take()), [3, 9]);
+}
+
+#[test]
+fn composition_local_simple_subscription_test() {
+    // Simplified test to verify basic subscription behavior
+    thread_local! {
+        static READER_RECOMPOSITIONS: Cell<usize> = const { Cell::new(0) };
+        static LAST_VALUE: Cell<i32> = const { Cell::new(-1) };
+    }
+
+    let local_value = compositionLocalOf(|| 0);
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let trigger = MutableState::with_runtime(10, runtime.clone());
 
     #[composable]
     fn reader(local_value: CompositionLocal<i32>) {
@@ -2285,6 +3310,155 @@ fn static_composition_local_default_value_used_outside_provider() {
     assert_eq!(READ_VALUE.with(|slot| slot.get()), 7);
 }
 
+#[test]
+fn composition_local_defaults_are_constructed_once_per_composition() {
+    let dynamic_constructions = Rc::new(Cell::new(0));
+    let static_constructions = Rc::new(Cell::new(0));
+    let dynamic_local = {
+        let constructions = Rc::clone(&dynamic_constructions);
+        compositionLocalOf(move || {
+            constructions.set(constructions.get() + 1);
+            7
+        })
+    };
+    let static_local = {
+        let constructions = Rc::clone(&static_constructions);
+        staticCompositionLocalOf(move || {
+            constructions.set(constructions.get() + 1);
+            9
+        })
+    };
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    for pass in 0..3 {
+        let dynamic_local = dynamic_local.clone();
+        let static_local = static_local.clone();
+        composition
+            .render(pass, move || {
+                for _ in 0..10 {
+                    assert_eq!(dynamic_local.current(), 7);
+                    assert_eq!(static_local.current(), 9);
+                }
+            })
+            .expect("render succeeds");
+    }
+
+    assert_eq!(dynamic_constructions.get(), 1);
+    assert_eq!(static_constructions.get(), 1);
+}
+
+#[test]
+fn remember_keyed_reinitializes_only_when_keys_change() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let config = MutableState::with_runtime("a", runtime.clone());
+    let inits = Rc::new(Cell::new(0));
+    let seen: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        let inits = Rc::clone(&inits);
+        let seen = Rc::clone(&seen);
+        composition
+            .render(0, move || {
+                let name = config.value();
+                let inits = Rc::clone(&inits);
+                let parser = cranpose_core::remember_keyed(name, move || {
+                    inits.set(inits.get() + 1);
+                    format!("parser-{name}")
+                });
+                seen.borrow_mut().push(parser.with(|parser| parser.clone()));
+            })
+            .expect("render succeeds");
+    };
+
+    render(&mut composition);
+    render(&mut composition);
+    assert_eq!(inits.get(), 1, "stable keys reuse the remembered value");
+
+    config.set_value("b");
+    render(&mut composition);
+    assert_eq!(inits.get(), 2, "changed keys re-run init");
+
+    render(&mut composition);
+    assert_eq!(inits.get(), 2);
+    assert_eq!(
+        seen.borrow().as_slice(),
+        ["parser-a", "parser-a", "parser-b", "parser-b"]
+    );
+}
+
+type SaveableStates = (
+    MutableState<i32>,
+    MutableState<String>,
+    Option<MutableState<u32>>,
+);
+
+fn render_saveable_content(
+    composition: &mut Composition<MemoryApplier>,
+    show_extra: bool,
+) -> SaveableStates {
+    let captured: Rc<RefCell<Option<SaveableStates>>> = Rc::new(RefCell::new(None));
+    let sink = Rc::clone(&captured);
+    composition
+        .render(0, move || {
+            let counter = cranpose_core::rememberSaveable(|| 0i32);
+            let name = cranpose_core::rememberSaveable(String::new);
+            let extra = show_extra.then(|| cranpose_core::rememberSaveable(|| 0u32));
+            *sink.borrow_mut() = Some((counter, name, extra));
+        })
+        .expect("render succeeds");
+    let states = captured.borrow_mut().take().expect("content composed");
+    states
+}
+
+#[test]
+fn exported_state_survives_rebuilding_the_composition() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let (counter, name, extra) = render_saveable_content(&mut composition, true);
+    counter.set(7);
+    name.set("tab\there\nand newline".to_string());
+    extra.expect("extra composed").set(3);
+
+    let text = composition.export_state().to_string();
+    let snapshot: StateSnapshot = text.parse().expect("snapshot parses");
+    assert_eq!(snapshot.len(), 3);
+
+    let mut rebuilt = Composition::new(MemoryApplier::new());
+    rebuilt.import_state(snapshot.clone());
+    let (counter, name, extra) = render_saveable_content(&mut rebuilt, true);
+    assert_eq!(counter.get(), 7);
+    assert_eq!(name.get(), "tab\there\nand newline");
+    assert_eq!(extra.expect("extra composed").get(), 3);
+
+    // Importing into a live composition writes straight into its states.
+    counter.set(0);
+    rebuilt.import_state(snapshot);
+    assert_eq!(counter.get(), 7);
+}
+
+#[test]
+fn imported_state_for_missing_anchors_is_dropped() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let (counter, _, extra) = render_saveable_content(&mut composition, true);
+    counter.set(5);
+    extra.expect("extra composed").set(9);
+    let snapshot = composition.export_state();
+
+    let mut rebuilt = Composition::new(MemoryApplier::new());
+    rebuilt.import_state(snapshot);
+    let (counter, _, extra) = render_saveable_content(&mut rebuilt, false);
+    assert_eq!(counter.get(), 5);
+    assert!(extra.is_none());
+    assert_eq!(rebuilt.export_state().len(), 2);
+
+    let (_, _, extra) = render_saveable_content(&mut rebuilt, true);
+    assert_eq!(
+        extra.expect("extra composed").get(),
+        0,
+        "values for anchors that were not composed are not replayed later"
+    );
+}
+
 #[test]
 fn cranpose_with_reuse_skips_then_recomposes() {
     thread_local! {
@@ -2571,6 +3745,83 @@ fn stats_watchers_survive_conditional_toggle() {
     );
 }
 
+#[composable]
+fn untracked_read_content(tracked: MutableState<i32>, untracked: MutableState<i32>) {
+    let _ = tracked.value();
+    cranpose_core::without_reads(|| {
+        let _ = untracked.value();
+        cranpose_core::without_reads(|| {
+            let _ = untracked.value();
+        });
+        let _ = untracked.value();
+    });
+}
+
+#[test]
+fn without_reads_does_not_register_watchers() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let tracked = MutableState::with_runtime(0i32, runtime.clone());
+    let untracked = MutableState::with_runtime(0i32, runtime.clone());
+
+    let mut render = { move || untracked_read_content(tracked, untracked) };
+
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+
+    assert!(
+        tracked.watcher_count() > 0,
+        "reads outside without_reads should subscribe"
+    );
+    assert_eq!(
+        untracked.watcher_count(),
+        0,
+        "reads inside (nested) without_reads must not subscribe"
+    );
+}
+
+thread_local! {
+    static PEEK_CONTENT_RUNS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[composable]
+fn peeking_content(state: MutableState<i32>) {
+    PEEK_CONTENT_RUNS.with(|runs| runs.set(runs.get() + 1));
+    let _ = state.peek();
+}
+
+#[test]
+fn peek_reads_without_subscribing() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let state = MutableState::with_runtime(1i32, runtime);
+    PEEK_CONTENT_RUNS.with(|runs| runs.set(0));
+
+    let mut render = { move || peeking_content(state) };
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    assert_eq!(PEEK_CONTENT_RUNS.with(Cell::get), 1);
+    assert_eq!(
+        state.watcher_count(),
+        0,
+        "peek inside a scope must not subscribe"
+    );
+
+    assert_eq!(state.peek(), 1);
+    state.set(2);
+    assert_eq!(state.peek(), 2);
+    assert!(!composition
+        .process_invalid_scopes()
+        .expect("process invalid scopes"));
+    assert_eq!(
+        PEEK_CONTENT_RUNS.with(Cell::get),
+        1,
+        "writes to a peeked state must not recompose the reader"
+    );
+}
+
 // ============================================================================
 // Slot Table Unit Tests - Gap Architecture
 // ============================================================================
@@ -2592,6 +3843,31 @@ fn slot_table_marks_values_as_gaps() {
     assert_eq!(slots.read_value::<i32>(2), &3);
 }
 
+#[test]
+fn slot_table_compact_keeps_anchors_resolving() {
+    let mut slots = SlotTable::new();
+    slots.start(1);
+    let (_, dropped) = slots.remember_with_anchor(|| 1i32);
+    slots.end();
+    slots.start(2);
+    let (_, kept) = slots.remember_with_anchor(|| 2i32);
+    slots.end();
+    SlotStorage::flush(&mut slots);
+
+    // Re-render only the second group; the first becomes gaps.
+    slots.reset();
+    slots.mark_range_as_gaps(0, 2, None);
+    let before = slots.slot_count();
+
+    assert!(slots.compact() > 0);
+    assert!(slots.slot_count() < before);
+    assert!(slots.read_value_by_anchor::<Owned<i32>>(dropped).is_none());
+    let value = slots
+        .read_value_by_anchor::<Owned<i32>>(kept)
+        .expect("live anchor resolves after compaction");
+    assert_eq!(value.with(|v| *v), 2);
+}
+
 #[test]
 fn slot_table_reuses_gap_slots_for_values() {
     let mut slots = SlotTable::new();
@@ -4375,3 +5651,744 @@ fn multiple_frame_callbacks_state_visibility() {
         "Sequential frame callback state changes should accumulate correctly"
     );
 }
+
+#[test]
+fn mutating_state_off_ui_thread_names_runtime_and_thread() {
+    let (handle, _runtime) = runtime_handle();
+    assert!(handle.is_ui_thread());
+    let state = MutableState::with_runtime(0i32, handle.clone());
+
+    let result = std::thread::Builder::new()
+        .name("worker".into())
+        .spawn(move || state.set(1))
+        .expect("spawn worker")
+        .join();
+
+    let payload = result.expect_err("off-thread mutation should panic");
+    let message = payload
+        .downcast_ref::<String>()
+        .expect("panic message should be formatted");
+    assert!(
+        message.contains(&format!("{:?}", handle.id())),
+        "missing runtime id: {message}"
+    );
+    assert!(message.contains("'worker'"), "missing thread: {message}");
+    assert_eq!(state.get(), 0);
+}
+
+thread_local! {
+    static COMPACT_READER_RUNS: Cell<usize> = const { Cell::new(0) };
+    static COMPACT_READER_INITS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[composable]
+fn compact_reader(counter: MutableState<i32>) {
+    COMPACT_READER_RUNS.with(|runs| runs.set(runs.get() + 1));
+    let _ = remember(|| COMPACT_READER_INITS.with(|inits| inits.set(inits.get() + 1)));
+    let _ = counter.value();
+}
+
+#[composable]
+fn compact_extras(count: usize) {
+    for index in 0..count {
+        with_key(&index, || {
+            let _ = remember(|| index);
+        });
+    }
+}
+
+#[test]
+fn compact_slots_releases_churned_content_and_keeps_live_state() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let counter = MutableState::with_runtime(0i32, composition.runtime_handle());
+    let extras = Rc::new(Cell::new(64usize));
+    let key = location_key(file!(), line!(), column!());
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        let extras = Rc::clone(&extras);
+        composition
+            .render(key, move || {
+                compact_reader(counter);
+                compact_extras(extras.get());
+            })
+            .expect("render");
+    };
+
+    render(&mut composition);
+    extras.set(0);
+    render(&mut composition);
+
+    let before = composition.debug_dump_all_slots().len();
+    assert!(composition.compact_slots() > 0);
+    assert!(composition.debug_dump_all_slots().len() < before);
+
+    let runs = COMPACT_READER_RUNS.with(Cell::get);
+    counter.set(1);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose after compaction");
+    assert_eq!(COMPACT_READER_RUNS.with(Cell::get), runs + 1);
+
+    extras.set(8);
+    render(&mut composition);
+    assert_eq!(COMPACT_READER_INITS.with(Cell::get), 1);
+}
+
+#[derive(Default)]
+struct ChildListNode {
+    children: Vec<NodeId>,
+    operations: Vec<Operation>,
+    parent: Option<NodeId>,
+}
+
+impl Node for ChildListNode {
+    fn insert_child(&mut self, child: NodeId) {
+        self.children.push(child);
+        self.operations.push(Operation::Insert(child));
+    }
+
+    fn remove_child(&mut self, child: NodeId) {
+        self.children.retain(|&c| c != child);
+        self.operations.push(Operation::Remove(child));
+    }
+
+    fn children(&self) -> Vec<NodeId> {
+        self.children.clone()
+    }
+
+    fn on_attached_to_parent(&mut self, parent: NodeId) {
+        self.parent = Some(parent);
+    }
+
+    fn on_removed_from_parent(&mut self) {
+        self.parent = None;
+    }
+
+    fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+}
+
+thread_local! {
+    static SKIPPED_COLUMN_RUNS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[composable]
+fn skipped_column(rows: usize) {
+    SKIPPED_COLUMN_RUNS.with(|runs| runs.set(runs.get() + 1));
+    let column = with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+    push_parent(column);
+    for row in 0..rows {
+        with_key(&row, || {
+            let cell = with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+            push_parent(cell);
+            with_current_composer(|composer| composer.emit_node(TestDummyNode::default));
+            pop_parent();
+        });
+    }
+    pop_parent();
+}
+
+#[test]
+fn skipped_group_keeps_nested_subtree_attached() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        composition
+            .render(key, || {
+                let root =
+                    with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+                push_parent(root);
+                skipped_column(2);
+                pop_parent();
+            })
+            .expect("render");
+    };
+
+    render(&mut composition);
+    let root = composition.root().expect("root node");
+    let snapshot = |composition: &mut Composition<MemoryApplier>| {
+        let mut applier = composition.applier_mut();
+        let mut tree = Vec::new();
+        let mut pending = vec![root];
+        while let Some(id) = pending.pop() {
+            if let Ok(children) = applier.with_node(id, |node: &mut ChildListNode| {
+                node.operations.clear();
+                node.children.clone()
+            }) {
+                pending.extend(children.iter().copied());
+                tree.push((id, children));
+            }
+        }
+        tree
+    };
+    let before = snapshot(&mut composition);
+    let node_count = composition.applier_mut().len();
+
+    render(&mut composition);
+    assert_eq!(SKIPPED_COLUMN_RUNS.with(Cell::get), 1, "column should skip");
+    assert_eq!(composition.applier_mut().len(), node_count);
+    let mut applier = composition.applier_mut();
+    for (id, children) in &before {
+        let (now, operations) = applier
+            .with_node(*id, |node: &mut ChildListNode| {
+                (node.children.clone(), node.operations.clone())
+            })
+            .expect("container survives skip");
+        assert_eq!(&now, children, "children of #{id} changed");
+        assert!(
+            operations.is_empty(),
+            "unexpected ops on #{id}: {operations:?}"
+        );
+    }
+}
+
+#[test]
+fn caught_group_panic_rolls_back_its_subtree() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let state = MutableState::with_runtime(0, composition.runtime_handle());
+    let failed_runs = Rc::new(Cell::new(0));
+    let key = location_key(file!(), line!(), column!());
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        let failed_runs = Rc::clone(&failed_runs);
+        composition
+            .render(key, move || {
+                let root =
+                    with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+                push_parent(root);
+                with_key(&"before", || {
+                    with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+                });
+                with_key(&"boundary", || {
+                    let guarded = location_key(file!(), line!(), column!());
+                    // Like `ErrorBoundary`, later passes only revisit the emptied group.
+                    if failed_runs.get() > 0 {
+                        with_current_composer(|composer| composer.with_group(guarded, |_| {}));
+                        return;
+                    }
+                    let result = with_current_composer(|composer| {
+                        composer.with_group_catching(guarded, |_| {
+                            let partial =
+                                with_current_composer(|c| c.emit_node(ChildListNode::default));
+                            push_parent(partial);
+                            with_current_composer(|c| c.emit_node(TestDummyNode::default));
+                            failed_runs.set(failed_runs.get() + 1);
+                            if state.value() >= 0 {
+                                panic!("composable failed");
+                            }
+                            pop_parent();
+                        })
+                    });
+                    assert!(result.is_err());
+                });
+                with_key(&"after", || {
+                    with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+                });
+                pop_parent();
+            })
+            .expect("render");
+    };
+
+    render(&mut composition);
+    let root = composition.root().expect("root node");
+    let children = composition
+        .applier_mut()
+        .with_node(root, |node: &mut ChildListNode| node.children.clone())
+        .expect("root node");
+    assert_eq!(children.len(), 2, "only the siblings stay attached");
+    assert_eq!(composition.applier_mut().len(), 3, "failed nodes removed");
+
+    state.set(1);
+    let _ = composition.process_invalid_scopes().expect("recomposition");
+    assert_eq!(failed_runs.get(), 1, "failed group is never recomposed");
+
+    render(&mut composition);
+    let after = composition
+        .applier_mut()
+        .with_node(root, |node: &mut ChildListNode| node.children.clone())
+        .expect("root node");
+    assert_eq!(after, children);
+    assert_eq!(composition.applier_mut().len(), 3);
+}
+
+#[composable]
+fn fragile_widget(explode: MutableState<bool>) {
+    with_current_composer(|composer| composer.emit_node(TestDummyNode::default));
+    if explode.value() {
+        // Panics while the slot table is borrowed to initialize the slot.
+        cranpose_core::remember(|| -> u8 { panic!("widget failed") });
+    }
+}
+
+#[test]
+fn composition_panics_are_returned_as_errors() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let explode = MutableState::with_runtime(false, composition.runtime_handle());
+    let key = location_key(file!(), line!(), column!());
+    let mut render = move || fragile_widget(explode);
+    let is_widget_failure = |error: &NodeError| {
+        matches!(
+            error,
+            NodeError::Composition { scope_id: Some(_), message } if message == "widget failed"
+        )
+    };
+
+    composition
+        .render(key, &mut render)
+        .expect("initial render");
+    assert_eq!(composition.applier_mut().len(), 1);
+
+    explode.set(true);
+    let error = composition
+        .process_invalid_scopes()
+        .expect_err("recomposition panics");
+    assert!(is_widget_failure(&error), "{error:?}");
+    assert_eq!(composition.applier_mut().len(), 0, "failed node removed");
+
+    explode.set(false);
+    composition
+        .render(key, &mut render)
+        .expect("render recovers");
+    assert_eq!(composition.applier_mut().len(), 1);
+
+    explode.set(true);
+    let error = composition
+        .render(key, &mut render)
+        .expect_err("render panics");
+    assert!(is_widget_failure(&error), "{error:?}");
+    assert_eq!(composition.applier_mut().len(), 0);
+
+    explode.set(false);
+    composition
+        .render(key, &mut render)
+        .expect("render recovers");
+    assert_eq!(composition.applier_mut().len(), 1);
+}
+
+#[composable]
+fn metrics_host(show_child: MutableState<bool>) {
+    let root = with_current_composer(|composer| composer.emit_node(ChildListNode::default));
+    push_parent(root);
+    if show_child.value() {
+        with_current_composer(|composer| composer.emit_node(TestDummyNode::default));
+    }
+    pop_parent();
+}
+
+#[test]
+fn runtime_metrics_count_recompositions_nodes_and_frames() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let show_child = MutableState::with_runtime(true, runtime.clone());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || metrics_host(show_child))
+        .expect("render");
+    let initial = runtime.metrics();
+    assert_eq!(initial.nodes_created, 2);
+    assert_eq!((initial.recompositions, initial.nodes_removed), (0, 0));
+
+    show_child.set(false);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    let after_hide = runtime.metrics();
+    assert_eq!(after_hide.recompositions, 1);
+    assert_eq!(after_hide.nodes_removed, 1);
+    assert_eq!(after_hide.nodes_created, 2);
+
+    runtime.drain_frame_callbacks(1);
+    runtime.drain_frame_callbacks(2);
+    assert_eq!(runtime.metrics().frames, 2);
+
+    runtime.reset_metrics();
+    assert_eq!(runtime.metrics(), RuntimeMetrics::default());
+    show_child.set(true);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    let after_show = runtime.metrics();
+    assert_eq!(
+        (after_show.recompositions, after_show.nodes_created),
+        (1, 1)
+    );
+}
+
+#[composable]
+fn skip_probe_child(label: i32, scope: Rc<RefCell<Option<RecomposeScope>>>) {
+    *scope.borrow_mut() = with_current_composer(|composer| composer.current_recranpose_scope());
+    let _ = label;
+}
+
+#[composable]
+fn unskippable_probe_child(label: impl AsRef<str>, scope: Rc<RefCell<Option<RecomposeScope>>>) {
+    *scope.borrow_mut() = with_current_composer(|composer| composer.current_recranpose_scope());
+    let _ = label.as_ref();
+}
+
+#[composable]
+fn skip_probe_parent(
+    tick: MutableState<i32>,
+    skippable: Rc<RefCell<Option<RecomposeScope>>>,
+    unskippable: Rc<RefCell<Option<RecomposeScope>>>,
+) {
+    let _ = tick.value();
+    skip_probe_child(7, skippable);
+    unskippable_probe_child("fixed", unskippable);
+}
+
+#[test]
+fn unchanged_params_report_skipped_groups() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let tick = MutableState::with_runtime(0, runtime.clone());
+    let skippable = Rc::new(RefCell::new(None));
+    let unskippable = Rc::new(RefCell::new(None));
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, {
+            let skippable = Rc::clone(&skippable);
+            let unskippable = Rc::clone(&unskippable);
+            move || skip_probe_parent(tick, Rc::clone(&skippable), Rc::clone(&unskippable))
+        })
+        .expect("render");
+    let child = skippable.borrow().clone().expect("child scope");
+    assert!(!child.last_skipped(), "first composition runs the body");
+    assert_eq!(runtime.metrics().skipped_groups, 0);
+
+    runtime.reset_metrics();
+    tick.set(1);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert!(child.last_skipped());
+    assert_eq!(runtime.metrics().skipped_groups, 1);
+    let fallback = unskippable.borrow().clone().expect("impl Trait scope");
+    assert!(!fallback.last_skipped());
+}
+
+#[composable]
+fn counted_reader(state: MutableState<i32>, scope_id: Rc<Cell<Option<ScopeId>>>) {
+    scope_id.set(with_current_composer(|composer| {
+        composer.current_recranpose_scope().map(|scope| scope.id())
+    }));
+    let _ = state.value();
+}
+
+#[test]
+fn scope_recompose_counts_track_only_invalidated_scopes() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let hot = MutableState::with_runtime(0, runtime.clone());
+    let cold = MutableState::with_runtime(0, runtime.clone());
+    let hot_scope = Rc::new(Cell::new(None));
+    let cold_scope = Rc::new(Cell::new(None));
+    composition.set_debug_scope_recompose_counting(true);
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, {
+            let hot_scope = Rc::clone(&hot_scope);
+            let cold_scope = Rc::clone(&cold_scope);
+            move || {
+                counted_reader(hot, Rc::clone(&hot_scope));
+                counted_reader(cold, Rc::clone(&cold_scope));
+            }
+        })
+        .expect("render");
+    assert!(composition.debug_scope_recompose_counts().is_empty());
+
+    for value in 1..=3 {
+        hot.set(value);
+        assert!(composition.process_invalid_scopes().expect("recompose"));
+    }
+    let hot_id = hot_scope.get().expect("hot scope");
+    assert_ne!(Some(hot_id), cold_scope.get());
+    assert_eq!(
+        composition.debug_scope_recompose_counts(),
+        vec![(hot_id, 3)]
+    );
+
+    // A new session starts from zero.
+    composition.set_debug_scope_recompose_counting(true);
+    assert!(composition.debug_scope_recompose_counts().is_empty());
+    composition.set_debug_scope_recompose_counting(false);
+    hot.set(4);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert!(composition.debug_scope_recompose_counts().is_empty());
+}
+
+thread_local! {
+    static STABLE_CONFIG_COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Large configuration that is only ever replaced, never mutated.
+#[derive(Clone)]
+struct StableConfig {
+    rows: Rc<Vec<u32>>,
+}
+
+impl PartialEq for StableConfig {
+    fn eq(&self, other: &Self) -> bool {
+        STABLE_CONFIG_COMPARISONS.with(|count| count.set(count.get() + 1));
+        self.rows == other.rows
+    }
+}
+
+impl ComposeStable for StableConfig {
+    fn is_same_instance(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.rows, &other.rows)
+    }
+}
+
+#[composable]
+fn stable_config_child(config: StableConfig, runs: Rc<Cell<usize>>) {
+    let _ = config.rows.len();
+    runs.set(runs.get() + 1);
+}
+
+#[composable]
+fn stable_config_parent(
+    tick: MutableState<u32>,
+    config: Rc<RefCell<StableConfig>>,
+    runs: Rc<Cell<usize>>,
+) {
+    let _ = tick.value();
+    let current = config.borrow().clone();
+    stable_config_child(current, runs);
+}
+
+#[test]
+fn stable_params_skip_by_identity_without_comparing() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let tick = MutableState::with_runtime(0, runtime.clone());
+    let config = Rc::new(RefCell::new(StableConfig {
+        rows: Rc::new((0..10_000).collect()),
+    }));
+    let runs = Rc::new(Cell::new(0));
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, {
+            let config = Rc::clone(&config);
+            let runs = Rc::clone(&runs);
+            move || stable_config_parent(tick, Rc::clone(&config), Rc::clone(&runs))
+        })
+        .expect("render");
+    assert_eq!(runs.get(), 1);
+
+    tick.set(1);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(runs.get(), 1, "same instance skips the child");
+
+    // An equal but distinct instance counts as a change.
+    let rows = config.borrow().rows.as_ref().clone();
+    config.borrow_mut().rows = Rc::new(rows);
+    tick.set(2);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(runs.get(), 2);
+    assert_eq!(STABLE_CONFIG_COMPARISONS.with(Cell::get), 0);
+}
+
+#[test]
+fn idle_callbacks_wait_until_pending_work_drains() {
+    let runtime = Runtime::new(Arc::new(TestScheduler));
+    let handle = runtime.handle();
+    let log: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+
+    {
+        let log = Rc::clone(&log);
+        handle.enqueue_ui_task(Box::new(move || log.borrow_mut().push("task")));
+    }
+    {
+        let log = Rc::clone(&log);
+        let handle_for_idle = handle.clone();
+        handle.on_idle(move || {
+            log.borrow_mut().push("first idle");
+            let log = Rc::clone(&log);
+            handle_for_idle.enqueue_ui_task(Box::new(move || log.borrow_mut().push("late task")));
+        });
+    }
+    {
+        let log = Rc::clone(&log);
+        handle.on_idle(move || log.borrow_mut().push("second idle"));
+    }
+    {
+        let log = Rc::clone(&log);
+        handle
+            .register_frame_callback(move |_| log.borrow_mut().push("frame"))
+            .expect("frame callback");
+    }
+
+    assert!(!handle.is_idle());
+    assert!(log.borrow().is_empty());
+
+    // The UI task is still queued, so the frame alone does not reach idle.
+    handle.drain_frame_callbacks(0);
+    assert_eq!(log.borrow().as_slice(), ["frame"]);
+
+    handle.drain_ui();
+    assert_eq!(log.borrow().as_slice(), ["frame", "task"]);
+
+    // Work queued by the first idle callback postpones the second one.
+    handle.drain_frame_callbacks(16);
+    assert_eq!(log.borrow().as_slice(), ["frame", "task", "first idle"]);
+
+    handle.drain_ui();
+    handle.drain_frame_callbacks(32);
+    assert_eq!(
+        log.borrow().as_slice(),
+        ["frame", "task", "first idle", "late task", "second idle"]
+    );
+    assert!(handle.is_idle());
+}
+
+#[composable]
+fn derived_cycle(seed: MutableState<i32>, computes: Rc<Cell<usize>>) {
+    let b_slot: Rc<RefCell<Option<State<i32>>>> =
+        remember(|| Rc::new(RefCell::new(None))).with(Rc::clone);
+    let a = {
+        let b_slot = Rc::clone(&b_slot);
+        derivedStateOf(move || {
+            computes.set(computes.get() + 1);
+            seed.value() + b_slot.borrow().as_ref().map_or(0, |b| b.value())
+        })
+    };
+    let b = derivedStateOf(move || a.value() + 1);
+    *b_slot.borrow_mut() = Some(b);
+}
+
+#[composable]
+fn derived_chain(seed: MutableState<i32>, out: Rc<Cell<i32>>) {
+    let a = derivedStateOf(move || seed.value() + 1);
+    let b = derivedStateOf(move || a.value() * 2);
+    let c = derivedStateOf(move || b.value() + 3);
+    out.set(c.value());
+}
+
+#[test]
+fn derived_state_cycle_is_detected_and_broken() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let seed = MutableState::with_runtime(0, runtime.clone());
+    let computes = Rc::new(Cell::new(0));
+    let chain_out = Rc::new(Cell::new(0));
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, {
+            let computes = Rc::clone(&computes);
+            let chain_out = Rc::clone(&chain_out);
+            move || {
+                derived_cycle(seed, Rc::clone(&computes));
+                derived_chain(seed, Rc::clone(&chain_out));
+            }
+        })
+        .expect("render");
+    assert_eq!(computes.get(), 1);
+    assert_eq!(chain_out.get(), 5);
+
+    seed.set(1);
+    composition.process_invalid_scopes().expect("recompose");
+
+    // The cycle is cut when `a` would recompute a second time.
+    assert_eq!(computes.get(), 2);
+    let cycles = composition.take_derived_state_cycles();
+    assert_eq!(cycles.len(), 1);
+    let [a_site, b_site] = cycles[0].locations[..] else {
+        panic!("expected a two-node cycle, got {}", cycles[0]);
+    };
+    assert_eq!(a_site.file(), file!());
+    assert_eq!(b_site.file(), file!());
+    assert!(a_site.line() < b_site.line());
+
+    // A deep chain without a cycle still propagates.
+    assert_eq!(chain_out.get(), 7);
+    assert!(composition.take_derived_state_cycles().is_empty());
+}
+
+#[test]
+fn derivation_chain_is_restored_when_a_recomputation_panics() {
+    let site = DerivationSite {
+        id: usize::MAX,
+        location: std::panic::Location::caller(),
+    };
+    let result = std::panic::catch_unwind(|| {
+        let _chain = DerivationChainGuard::enter(vec![site]);
+        panic!("compute failed");
+    });
+
+    assert!(result.is_err());
+    assert!(DERIVATION_CHAIN.with(|chain| chain.borrow().is_empty()));
+}
+
+#[test]
+fn consistent_snapshot_hides_changes_applied_during_the_read() {
+    let composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let width = MutableState::with_runtime(10i32, runtime.clone());
+    let height = MutableState::with_runtime(20i32, runtime);
+
+    let (outer, inner) = composition.with_consistent_snapshot(|| {
+        let before = (width.value(), height.value());
+        // Another step (e.g. an input handler) updates both values mid-pass.
+        run_in_mutable_snapshot(|| {
+            width.set_value(11);
+            height.set_value(21);
+        })
+        .expect("apply succeeds");
+        let nested = composition.with_consistent_snapshot(|| (width.value(), height.value()));
+        assert_eq!((width.value(), height.value()), before);
+        (before, nested)
+    });
+
+    assert_eq!(outer, (10, 20));
+    assert_eq!(inner, (10, 20));
+    assert_eq!((width.value(), height.value()), (11, 21));
+}
+
+#[composable]
+fn labelled_text(label: &'static str, count: cranpose_core::State<i32>) -> NodeId {
+    let id = cranpose_test_node(TestTextNode::default);
+    with_node_mut(id, |node: &mut TestTextNode| {
+        node.text = format!("{label} {}", count.value());
+    })
+    .expect("update text node");
+    id
+}
+
+#[test]
+fn compositions_sharing_an_applier_keep_their_subtrees_apart() {
+    let mut main = Composition::new(MemoryApplier::new());
+    let mut overlay = Composition::with_shared_applier(
+        main.shared_applier(),
+        Runtime::new(Arc::new(TestScheduler)),
+    );
+    let main_count = MutableState::with_runtime(0, main.runtime_handle());
+    let overlay_count = MutableState::with_runtime(0, overlay.runtime_handle());
+
+    main.render(location_key(file!(), line!(), column!()), move || {
+        labelled_text("main", main_count.as_state());
+    })
+    .expect("main render");
+    overlay
+        .render(location_key(file!(), line!(), column!()), move || {
+            labelled_text("overlay", overlay_count.as_state());
+        })
+        .expect("overlay render");
+
+    let main_root = main.root().expect("main root");
+    let overlay_root = overlay.root().expect("overlay root");
+    assert_ne!(main_root, overlay_root);
+
+    let text = |composition: &mut Composition<MemoryApplier>, id: NodeId| {
+        composition
+            .applier_mut()
+            .with_node(id, |node: &mut TestTextNode| node.text.clone())
+            .expect("text node")
+    };
+    assert_eq!(main.applier_mut().len(), 2);
+    assert_eq!(text(&mut overlay, main_root), "main 0");
+    assert_eq!(text(&mut main, overlay_root), "overlay 0");
+
+    main_count.set(1);
+    assert!(main.process_invalid_scopes().expect("main recompose"));
+    assert!(!overlay.process_invalid_scopes().expect("overlay recompose"));
+    overlay_count.set(2);
+    assert!(overlay.process_invalid_scopes().expect("overlay recompose"));
+
+    assert_eq!(main.applier_mut().len(), 2);
+    assert_eq!(text(&mut main, main_root), "main 1");
+    assert_eq!(text(&mut main, overlay_root), "overlay 2");
+}
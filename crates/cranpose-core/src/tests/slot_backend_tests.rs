@@ -1,6 +1,8 @@
 //! Tests for slot storage backends.
 
 use crate::{SlotBackendKind, SlotStorage};
+use std::cell::RefCell;
+use std::rc::Rc;
 
 /// Smoke test to verify all backends can perform basic operations.
 #[test]
@@ -1223,3 +1225,242 @@ fn test_chunked_gap_scan_upper_bound_fallback() {
     storage.end_group();
     storage.flush();
 }
+
+/// Compaction drops gaps left by removed content while live groups keep their
+/// values and stay reachable by scope. Split is excluded because it inserts a
+/// fresh layout group on every pass instead of reusing the live one.
+#[test]
+fn test_backends_compact_drops_gaps_and_keeps_live_groups() {
+    for kind in [
+        SlotBackendKind::Baseline,
+        SlotBackendKind::Chunked,
+        SlotBackendKind::Hierarchical,
+    ] {
+        use crate::slot_backend::SlotBackend;
+        let mut storage = SlotBackend::new(kind);
+
+        // pass 1: two sibling groups
+        storage.reset();
+        let kept = storage.begin_group(6000);
+        storage.set_group_scope(kept.group, 77);
+        let _ = storage.alloc_value_slot(|| "kept");
+        storage.end_group();
+        let _ = storage.begin_group(6001);
+        let _ = storage.alloc_value_slot(|| 1u64);
+        storage.end_group();
+        storage.flush();
+
+        // pass 2: drop the second group, leaving gaps behind
+        storage.reset();
+        let _ = storage.begin_group(6000);
+        let _ = storage.alloc_value_slot(|| "kept");
+        storage.end_group();
+        storage.finalize_current_group();
+        storage.flush();
+
+        let removed = storage.compact();
+        assert!(removed > 0, "{:?}: compaction should release gaps", kind);
+        assert_eq!(
+            storage.compact(),
+            0,
+            "{:?}: second compact is a no-op",
+            kind
+        );
+
+        // pass 3: the surviving group and its value are still in place
+        storage.reset();
+        let res = storage.begin_group(6000);
+        assert!(!res.restored_from_gap, "{:?}", kind);
+        let value = storage.alloc_value_slot(|| "fresh");
+        assert_eq!(*storage.read_value::<&str>(value), "kept", "{:?}", kind);
+        storage.end_group();
+        storage.flush();
+
+        storage.reset();
+        assert!(
+            storage.begin_recranpose_at_scope(77).is_some(),
+            "{:?}: scope must survive compaction",
+            kind
+        );
+        storage.end_group();
+        storage.end_recompose();
+        storage.flush();
+    }
+}
+
+#[test]
+fn test_split_compact_releases_orphaned_payloads() {
+    use crate::slot_backend::SlotBackend;
+    let mut storage = SlotBackend::new(SlotBackendKind::Split);
+
+    storage.reset();
+    let _ = storage.begin_group(7000);
+    let _ = storage.alloc_value_slot(|| 5u32);
+    storage.end_group();
+    storage.flush();
+
+    storage.reset();
+    storage.finalize_current_group();
+    storage.flush();
+
+    assert!(storage.compact() > 0);
+    assert!(storage.debug_dump_all_slots().is_empty());
+}
+
+/// Index one past the last slot of each group, keyed by group key. Baseline
+/// and Hierarchical count the group slot in `len`; the others do not.
+fn group_ends(
+    storage: &crate::slot_backend::SlotBackend,
+    kind: SlotBackendKind,
+) -> Vec<(crate::Key, usize)> {
+    let own_slot = match kind {
+        SlotBackendKind::Baseline | SlotBackendKind::Hierarchical => 0,
+        SlotBackendKind::Chunked | SlotBackendKind::Split => 1,
+    };
+    storage
+        .debug_dump_groups()
+        .into_iter()
+        .map(|(start, key, _, len)| (key, start + len + own_slot))
+        .collect()
+}
+
+/// Content a group adds while recomposing on its own is covered by the group
+/// and by every group enclosing it.
+#[test]
+fn test_backends_recomposed_group_growth_extends_enclosing_groups() {
+    for kind in all_backends() {
+        use crate::slot_backend::SlotBackend;
+        let mut storage = SlotBackend::new(kind);
+
+        // outer(8000) -> inner(8001, scope 80) -> [scope slot, value]
+        storage.reset();
+        let _ = storage.begin_group(8000);
+        let inner = storage.begin_group(8001);
+        storage.set_group_scope(inner.group, 80);
+        let _ = storage.alloc_value_slot(|| "scope");
+        let _ = storage.alloc_value_slot(|| 1u32);
+        storage.end_group();
+        storage.end_group();
+        storage.flush();
+        assert_eq!(
+            group_ends(&storage, kind),
+            vec![(8000, 4), (8001, 4)],
+            "{:?}",
+            kind
+        );
+
+        // Recompose the inner group alone; it now also records a node.
+        storage.reset();
+        assert!(
+            storage.begin_recranpose_at_scope(80).is_some(),
+            "{:?}",
+            kind
+        );
+        let value = storage.alloc_value_slot(|| 2u32);
+        assert_eq!(*storage.read_value::<u32>(value), 1, "{:?}", kind);
+        storage.record_node(42);
+        storage.finalize_current_group();
+        storage.end_recompose();
+        storage.flush();
+
+        assert_eq!(
+            group_ends(&storage, kind),
+            vec![(8000, 5), (8001, 5)],
+            "{:?}: both groups must cover the recorded node",
+            kind
+        );
+    }
+}
+
+/// Skipping a group from inside it resumes after the group's content.
+#[test]
+fn test_backends_skip_current_group_resumes_after_content() {
+    for kind in all_backends() {
+        use crate::slot_backend::SlotBackend;
+        let mut storage = SlotBackend::new(kind);
+
+        storage.reset();
+        let _ = storage.begin_group(8100);
+        let _ = storage.alloc_value_slot(|| 1u32);
+        let _ = storage.alloc_value_slot(|| 2u32);
+        storage.end_group();
+        let _ = storage.begin_group(8101);
+        let _ = storage.alloc_value_slot(|| "after");
+        storage.end_group();
+        storage.flush();
+
+        storage.reset();
+        let _ = storage.begin_group(8100);
+        storage.skip_current_group();
+        storage.end_group();
+        let _ = storage.begin_group(8101);
+        let value = storage.alloc_value_slot(|| "fresh");
+        assert_eq!(*storage.read_value::<&str>(value), "after", "{:?}", kind);
+        storage.end_group();
+        storage.flush();
+    }
+}
+
+/// Records its id into a shared log when dropped.
+struct DropRecorder(u32, Rc<RefCell<Vec<u32>>>);
+
+impl Drop for DropRecorder {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+fn record_three_values(
+    storage: &mut crate::slot_backend::SlotBackend,
+    log: &Rc<RefCell<Vec<u32>>>,
+) {
+    for id in 1..=3 {
+        let log = Rc::clone(log);
+        let _ = storage.alloc_value_slot(move || DropRecorder(id, log));
+    }
+}
+
+/// Values removed from a group are released last-to-first.
+#[test]
+fn test_backends_release_removed_values_last_to_first() {
+    for kind in all_backends() {
+        use crate::slot_backend::SlotBackend;
+        let mut storage = SlotBackend::new(kind);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        storage.reset();
+        let _ = storage.begin_group(8200);
+        record_three_values(&mut storage, &log);
+        storage.end_group();
+        storage.flush();
+
+        storage.reset();
+        let _ = storage.begin_group(8200);
+        storage.finalize_current_group();
+        storage.end_group();
+        storage.flush();
+        // Split keeps payloads across gaps until compaction.
+        storage.compact();
+
+        assert_eq!(*log.borrow(), vec![3, 2, 1], "{:?}", kind);
+    }
+}
+
+/// Dropping the storage releases its values last-to-first.
+#[test]
+fn test_backends_release_values_last_to_first_on_drop() {
+    for kind in all_backends() {
+        use crate::slot_backend::SlotBackend;
+        let mut storage = SlotBackend::new(kind);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        storage.reset();
+        let _ = storage.begin_group(8300);
+        record_three_values(&mut storage, &log);
+        storage.end_group();
+        storage.flush();
+        drop(storage);
+
+        assert_eq!(*log.borrow(), vec![3, 2, 1], "{:?}", kind);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{DefaultScheduler, MutableState, Runtime};
+use std::sync::Arc;
+
+#[test]
+fn replay_restores_values_at_an_earlier_step() {
+    let runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let handle = runtime.handle();
+    let count = MutableState::with_runtime(0i32, handle.clone());
+    let label = MutableState::with_runtime(String::from("a"), handle.clone());
+
+    handle.start_state_history();
+    count.set(1); // step 0
+    label.set("b".into()); // step 1
+    count.update(|value| *value += 1); // step 2
+    label.set("c".into()); // step 3
+
+    let history = handle.state_history();
+    assert_eq!(history.len(), 4);
+    assert_eq!(history[2].state, count.id());
+    assert_eq!(history[2].type_name, "i32");
+
+    assert!(handle.replay_state_history(1));
+    assert_eq!(
+        (count.get_non_reactive(), label.get_non_reactive()),
+        (1, "b".into())
+    );
+
+    assert!(handle.replay_state_history(0));
+    assert_eq!(
+        (count.get_non_reactive(), label.get_non_reactive()),
+        (1, "a".into())
+    );
+
+    // Replaying does not add to the history, so later steps stay reachable.
+    assert_eq!(handle.state_history().len(), 4);
+    assert!(handle.replay_state_history(3));
+    assert_eq!(
+        (count.get_non_reactive(), label.get_non_reactive()),
+        (2, "c".into())
+    );
+
+    assert!(!handle.replay_state_history(4));
+}
+
+#[test]
+fn summary_only_states_are_listed_but_not_replayed() {
+    let runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let handle = runtime.handle();
+    let big = MutableState::with_runtime(vec![0u8; 16], handle.clone());
+    big.set_history_summary_only();
+
+    handle.start_state_history();
+    big.set(vec![1u8; 16]);
+    big.set(vec![2u8; 16]);
+
+    let history = handle.state_history();
+    assert_eq!(history.len(), 2);
+    assert!(!history[0].restorable);
+
+    assert!(handle.replay_state_history(0));
+    assert_eq!(big.get_non_reactive(), vec![2u8; 16]);
+}
+
+#[test]
+fn writes_outside_a_recording_are_not_logged() {
+    let runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let handle = runtime.handle();
+    let count = MutableState::with_runtime(0i32, handle.clone());
+
+    count.set(1);
+    handle.start_state_history();
+    count.set(2);
+    handle.stop_state_history();
+    count.set(3);
+
+    assert_eq!(handle.state_history().len(), 1);
+    assert!(handle.replay_state_history(0));
+    assert_eq!(count.get_non_reactive(), 2);
+}
+
+#[test]
+fn writes_after_a_replay_are_recorded_again() {
+    let runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let handle = runtime.handle();
+    let count = MutableState::with_runtime(0i32, handle.clone());
+
+    handle.start_state_history();
+    count.set(1);
+    count.set(2);
+    assert!(handle.replay_state_history(0));
+    count.set(5);
+
+    assert_eq!(handle.state_history().len(), 3);
+    assert!(handle.replay_state_history(2));
+    assert_eq!(count.get_non_reactive(), 5);
+}
@@ -146,6 +146,37 @@ fn draining_inactive_precomposed_returns_nodes() {
     assert!(state.precomposed().is_empty());
 }
 
+#[test]
+fn trimming_precomposed_disposes_the_least_recently_precomposed_slots() {
+    let mut state = SubcomposeState::default();
+    for slot in 0..=DEFAULT_MAX_PRECOMPOSED as u64 {
+        state.register_precomposed(SlotId::new(slot), 100 + slot as NodeId);
+    }
+    // Precomposing slot 0 again makes slot 1 the oldest.
+    state.register_precomposed(SlotId::new(0), 100);
+
+    assert_eq!(state.trim_precomposed(), vec![101]);
+    assert_eq!(state.precomposed().len(), DEFAULT_MAX_PRECOMPOSED);
+    assert!(state.trim_precomposed().is_empty());
+
+    assert_eq!(state.remove_precomposed(SlotId::new(0)), vec![100]);
+    assert!(!state.precomposed().contains_key(&SlotId::new(0)));
+}
+
+#[test]
+fn finish_pass_keeps_unplaced_precomposed_slots() {
+    let mut state = SubcomposeState::default();
+    state.register_precomposed(SlotId::new(7), 77);
+    state.begin_pass();
+    state.register_active(SlotId::new(8), &[88], &[]);
+    assert!(state.trim_precomposed().is_empty());
+    state.finish_pass();
+
+    assert_eq!(state.precomposed().get(&SlotId::new(7)), Some(&vec![77]));
+    assert!(!state.is_activated(SlotId::new(7)));
+    assert!(state.is_activated(SlotId::new(8)));
+}
+
 #[test]
 fn finish_pass_disposes_inactive_slots() {
     let mut state = SubcomposeState::default();
@@ -30,3 +30,15 @@ pub const DRAG_THRESHOLD: f32 = 8.0;
 /// baseline density. This will be replaced by a platform-driven configuration
 /// when ViewConfiguration is plumbed through.
 pub const MAX_FLING_VELOCITY: f32 = 8_000.0;
+
+/// How long a press has to be held before it counts as a long click, in
+/// milliseconds.
+///
+/// Matches Android's ViewConfiguration long press timeout.
+pub const LONG_CLICK_TIMEOUT_MILLIS: u64 = 400;
+
+/// Longest gap between two clicks that still makes them a double click, in
+/// milliseconds.
+///
+/// Matches Android's ViewConfiguration double tap timeout.
+pub const DOUBLE_CLICK_TIMEOUT_MILLIS: u64 = 300;
@@ -288,21 +288,36 @@ pub fn remember_lazy_list_state_with_position(
     initial_first_visible_item_index: usize,
     initial_first_visible_item_scroll_offset: f32,
 ) -> LazyListState {
-    // Create scroll position with reactive fields (matches JC LazyListScrollPosition)
-    let scroll_position = LazyListScrollPosition {
-        index: cranpose_core::useState(|| initial_first_visible_item_index),
-        scroll_offset: cranpose_core::useState(|| initial_first_visible_item_scroll_offset),
-        inner: cranpose_core::useState(|| {
-            Rc::new(RefCell::new(ScrollPositionInner {
+    cranpose_core::remember(|| {
+        LazyListState::new(
+            initial_first_visible_item_index,
+            initial_first_visible_item_scroll_offset,
+        )
+    })
+    .with(|state| *state)
+}
+
+impl LazyListState {
+    /// Creates a state scrolled to the given position, outside of
+    /// composition. Needs an active runtime.
+    ///
+    /// Prefer [`remember_lazy_list_state_with_position`] in composable code.
+    pub fn new(
+        initial_first_visible_item_index: usize,
+        initial_first_visible_item_scroll_offset: f32,
+    ) -> Self {
+        // Create scroll position with reactive fields (matches JC LazyListScrollPosition)
+        let scroll_position = LazyListScrollPosition {
+            index: cranpose_core::mutableStateOf(initial_first_visible_item_index),
+            scroll_offset: cranpose_core::mutableStateOf(initial_first_visible_item_scroll_offset),
+            inner: cranpose_core::mutableStateOf(Rc::new(RefCell::new(ScrollPositionInner {
                 last_known_first_item_key: None,
                 nearest_range_state: NearestRangeState::new(initial_first_visible_item_index),
-            }))
-        }),
-    };
+            }))),
+        };
 
-    // Non-reactive internal state
-    let inner = cranpose_core::useState(|| {
-        Rc::new(RefCell::new(LazyListStateInner {
+        // Non-reactive internal state
+        let inner = cranpose_core::mutableStateOf(Rc::new(RefCell::new(LazyListStateInner {
             scroll_to_be_consumed: 0.0,
             pending_scroll_to_index: None,
             layout_info: LazyListLayoutInfo::default(),
@@ -318,24 +333,22 @@ pub fn remember_lazy_list_state_with_position(
             prefetch_scheduler: PrefetchScheduler::new(),
             prefetch_strategy: PrefetchStrategy::default(),
             last_scroll_direction: 0.0,
-        }))
-    });
+        })));
 
-    // Reactive state
-    let can_scroll_forward_state = cranpose_core::useState(|| false);
-    let can_scroll_backward_state = cranpose_core::useState(|| false);
-    let stats_state = cranpose_core::useState(LazyLayoutStats::default);
+        // Reactive state
+        let can_scroll_forward_state = cranpose_core::mutableStateOf(false);
+        let can_scroll_backward_state = cranpose_core::mutableStateOf(false);
+        let stats_state = cranpose_core::mutableStateOf(LazyLayoutStats::default());
 
-    LazyListState {
-        scroll_position,
-        can_scroll_forward_state,
-        can_scroll_backward_state,
-        stats_state,
-        inner,
+        LazyListState {
+            scroll_position,
+            can_scroll_forward_state,
+            can_scroll_backward_state,
+            stats_state,
+            inner,
+        }
     }
-}
 
-impl LazyListState {
     /// Returns a pointer to the inner state for unique identification.
     /// Used by scroll gesture detection to create unique keys.
     pub fn inner_ptr(&self) -> *const () {
@@ -838,46 +851,9 @@ pub mod test_helpers {
         initial_first_visible_item_index: usize,
         initial_first_visible_item_scroll_offset: f32,
     ) -> LazyListState {
-        // Create scroll position with reactive fields (matches JC LazyListScrollPosition)
-        let scroll_position = LazyListScrollPosition {
-            index: cranpose_core::mutableStateOf(initial_first_visible_item_index),
-            scroll_offset: cranpose_core::mutableStateOf(initial_first_visible_item_scroll_offset),
-            inner: cranpose_core::mutableStateOf(Rc::new(RefCell::new(ScrollPositionInner {
-                last_known_first_item_key: None,
-                nearest_range_state: NearestRangeState::new(initial_first_visible_item_index),
-            }))),
-        };
-
-        // Non-reactive internal state
-        let inner = cranpose_core::mutableStateOf(Rc::new(RefCell::new(LazyListStateInner {
-            scroll_to_be_consumed: 0.0,
-            pending_scroll_to_index: None,
-            layout_info: LazyListLayoutInfo::default(),
-            invalidate_callbacks: Vec::new(),
-            next_callback_id: 1,
-            has_layout_invalidation_callback: false,
-            total_composed: 0,
-            reuse_count: 0,
-            item_size_cache: std::collections::HashMap::new(),
-            item_size_lru: std::collections::VecDeque::new(),
-            average_item_size: super::super::DEFAULT_ITEM_SIZE_ESTIMATE,
-            total_measured_items: 0,
-            prefetch_scheduler: PrefetchScheduler::new(),
-            prefetch_strategy: PrefetchStrategy::default(),
-            last_scroll_direction: 0.0,
-        })));
-
-        // Reactive state
-        let can_scroll_forward_state = cranpose_core::mutableStateOf(false);
-        let can_scroll_backward_state = cranpose_core::mutableStateOf(false);
-        let stats_state = cranpose_core::mutableStateOf(LazyLayoutStats::default());
-
-        LazyListState {
-            scroll_position,
-            can_scroll_forward_state,
-            can_scroll_backward_state,
-            stats_state,
-            inner,
-        }
+        LazyListState::new(
+            initial_first_visible_item_index,
+            initial_first_visible_item_scroll_offset,
+        )
     }
 }
@@ -635,12 +635,105 @@ pub trait FocusNode: ModifierNode {
     }
 }
 
+/// Callback backing a semantics action such as scrolling.
+///
+/// Handlers compare by identity so configurations holding them stay comparable.
+pub struct SemanticsActionHandler<A, R = ()> {
+    callback: Rc<dyn Fn(A) -> R>,
+}
+
+impl<A, R> SemanticsActionHandler<A, R> {
+    pub fn new(callback: impl Fn(A) -> R + 'static) -> Self {
+        Self {
+            callback: Rc::new(callback),
+        }
+    }
+
+    /// Performs the action.
+    pub fn invoke(&self, args: A) -> R {
+        (self.callback)(args)
+    }
+}
+
+impl<A, R> Clone for SemanticsActionHandler<A, R> {
+    fn clone(&self) -> Self {
+        Self {
+            callback: self.callback.clone(),
+        }
+    }
+}
+
+impl<A, R> PartialEq for SemanticsActionHandler<A, R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.callback, &other.callback)
+    }
+}
+
+impl<A, R> Eq for SemanticsActionHandler<A, R> {}
+
+impl<A, R> fmt::Debug for SemanticsActionHandler<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SemanticsActionHandler")
+    }
+}
+
+/// Scroll position of a scrollable container, exposed through semantics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollAxisRange {
+    /// Current scroll offset in pixels.
+    pub value: f32,
+    /// Maximum scroll offset in pixels. Lazy lists report an estimate.
+    pub max_value: f32,
+    pub is_vertical: bool,
+}
+
+/// Explicit accessibility role of a node, mirroring Jetpack Compose's `Role`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    Button,
+    Checkbox,
+    Switch,
+    Slider,
+    Image,
+    Header,
+    /// A container announced as one labeled region, e.g. a card with a
+    /// heading. Needs a content description; unlabeled groups stay plain
+    /// containers.
+    Group,
+}
+
 /// Semantics configuration for accessibility.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct SemanticsConfiguration {
     pub content_description: Option<String>,
     pub is_button: bool,
     pub is_clickable: bool,
+    /// Disabled nodes ignore input and are announced as unavailable.
+    pub is_disabled: bool,
+    /// Whether the node takes part in focus traversal.
+    pub is_focusable: bool,
+    /// Explicit role; takes precedence over roles derived from other flags.
+    pub role: Option<Role>,
+    /// Describes the current state of the node, e.g. "Checked" or "50%".
+    pub state_description: Option<String>,
+    /// Folds the semantics of all descendants into this node so it is exposed
+    /// as a single accessible node (Compose's `mergeDescendants = true`).
+    pub is_merging_semantics_of_descendants: bool,
+    /// Drops the semantics of all descendants, leaving only this node's own
+    /// configuration (Compose's `clearAndSetSemantics`).
+    pub is_clearing_semantics: bool,
+    /// Reading order among siblings: lower indices are read first, and
+    /// siblings without an index count as 0 and keep their layout order.
+    /// Mirrors Compose's `traversalIndex`.
+    pub traversal_index: Option<f32>,
+    /// Scroll position, set by scrollable containers.
+    pub scroll_axis_range: Option<ScrollAxisRange>,
+    /// Scrolls by a delta in pixels along the scroll axis; returns the consumed delta.
+    pub scroll_by: Option<SemanticsActionHandler<f32, f32>>,
+    /// Scrolls a lazy list so the item at the given index becomes the first visible one.
+    pub scroll_to_index: Option<SemanticsActionHandler<usize>>,
+    /// Identifies the node to tests; not announced to accessibility services.
+    pub test_tag: Option<String>,
 }
 
 impl SemanticsConfiguration {
@@ -650,6 +743,31 @@ impl SemanticsConfiguration {
         }
         self.is_button |= other.is_button;
         self.is_clickable |= other.is_clickable;
+        self.is_disabled |= other.is_disabled;
+        self.is_focusable |= other.is_focusable;
+        if other.role.is_some() {
+            self.role = other.role;
+        }
+        if let Some(state) = &other.state_description {
+            self.state_description = Some(state.clone());
+        }
+        self.is_merging_semantics_of_descendants |= other.is_merging_semantics_of_descendants;
+        self.is_clearing_semantics |= other.is_clearing_semantics;
+        if other.traversal_index.is_some() {
+            self.traversal_index = other.traversal_index;
+        }
+        if other.scroll_axis_range.is_some() {
+            self.scroll_axis_range = other.scroll_axis_range;
+        }
+        if let Some(scroll_by) = &other.scroll_by {
+            self.scroll_by = Some(scroll_by.clone());
+        }
+        if let Some(scroll_to_index) = &other.scroll_to_index {
+            self.scroll_to_index = Some(scroll_to_index.clone());
+        }
+        if let Some(tag) = &other.test_tag {
+            self.test_tag = Some(tag.clone());
+        }
     }
 }
 
@@ -1146,8 +1264,9 @@ fn detach_node_tree(node: &mut dyn ModifierNode) {
 ///
 /// The chain tracks ownership of modifier nodes and reuses them across
 /// updates when the incoming element list still contains a node of the
-/// same type. Removed nodes detach automatically so callers do not need
-/// to manually manage their lifetimes.
+/// same type, updating them in place so their state survives. Removed nodes
+/// detach automatically so callers do not need to manually manage their
+/// lifetimes.
 pub struct ModifierNodeChain {
     entries: Vec<ModifierNodeEntry>,
     aggregated_capabilities: NodeCapabilities,
@@ -1155,6 +1274,7 @@ pub struct ModifierNodeChain {
     head_sentinel: Box<SentinelNode>,
     tail_sentinel: Box<SentinelNode>,
     ordered_nodes: Vec<NodeLink>,
+    reconcile: ReconcileScratch,
 }
 
 struct SentinelNode {
@@ -1193,6 +1313,7 @@ impl Default for ModifierNodeChain {
 ///
 /// This avoids O(n²) complexity by pre-building hash maps that allow constant-time
 /// lookups for matching entries by key, hash, or type.
+#[derive(Default)]
 struct EntryIndex {
     /// Map (TypeId, key) → index for keyed entries
     keyed: HashMap<(TypeId, u64), Vec<usize>>,
@@ -1203,45 +1324,43 @@ struct EntryIndex {
 }
 
 impl EntryIndex {
-    fn build(entries: &[ModifierNodeEntry]) -> Self {
-        let mut keyed = HashMap::new();
-        let mut hashed = HashMap::new();
-        let mut typed = HashMap::new();
+    /// Re-indexes `entries`, reusing the maps and index lists of the
+    /// previous build. Buckets left empty by the previous build are dropped
+    /// so keys of values that no longer occur do not accumulate.
+    fn rebuild(&mut self, entries: &[ModifierNodeEntry]) {
+        fn reset<K>(map: &mut HashMap<K, Vec<usize>>) {
+            map.retain(|_, indices| {
+                let keep = !indices.is_empty();
+                indices.clear();
+                keep
+            });
+        }
+        reset(&mut self.keyed);
+        reset(&mut self.hashed);
+        reset(&mut self.typed);
 
         for (i, entry) in entries.iter().enumerate() {
             if let Some(key_value) = entry.key {
                 // Keyed entry
-                keyed
+                self.keyed
                     .entry((entry.element_type, key_value))
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(i);
             } else {
                 // Unkeyed entry - add to both hash and type indices
-                hashed
+                self.hashed
                     .entry((entry.element_type, entry.hash_code))
-                    .or_insert_with(Vec::new)
-                    .push(i);
-                typed
-                    .entry(entry.element_type)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(i);
+                self.typed.entry(entry.element_type).or_default().push(i);
             }
         }
-
-        Self {
-            keyed,
-            hashed,
-            typed,
-        }
     }
 
-    /// Find the best matching entry for reuse.
-    ///
-    /// Matching priority (from highest to lowest):
-    /// 1. Keyed match: same type + same key
-    /// 2. Exact match: same type + no key + same hash + equals_element
-    /// 3. Type match: same type + no key (will require update)
-    fn find_match(
+    /// Find an entry that can be reused without changing its element:
+    /// same type and key for keyed entries, otherwise same type and an
+    /// equal element.
+    fn find_exact(
         &self,
         entries: &[ModifierNodeEntry],
         used: &[bool],
@@ -1251,38 +1370,48 @@ impl EntryIndex {
         element: &DynModifierElement,
     ) -> Option<usize> {
         if let Some(key_value) = key {
-            // Priority 1: Keyed lookup - O(1)
-            if let Some(candidates) = self.keyed.get(&(element_type, key_value)) {
-                for &i in candidates {
-                    if !used[i] {
-                        return Some(i);
-                    }
-                }
-            }
-        } else {
-            // Priority 2: Exact match (hash + equality) - O(1) lookup + O(k) equality checks
-            if let Some(candidates) = self.hashed.get(&(element_type, hash_code)) {
-                for &i in candidates {
-                    if !used[i] && entries[i].element.as_ref().equals_element(element.as_ref()) {
-                        return Some(i);
-                    }
-                }
-            }
-
-            // Priority 3: Type match only - O(1) lookup + O(k) scan
-            if let Some(candidates) = self.typed.get(&element_type) {
-                for &i in candidates {
-                    if !used[i] {
-                        return Some(i);
-                    }
-                }
-            }
+            // Keyed lookup - O(1)
+            return self
+                .keyed
+                .get(&(element_type, key_value))?
+                .iter()
+                .copied()
+                .find(|&i| !used[i]);
         }
+        // Hash lookup - O(1) + O(k) equality checks
+        self.hashed
+            .get(&(element_type, hash_code))?
+            .iter()
+            .copied()
+            .find(|&i| !used[i] && entries[i].element.as_ref().equals_element(element.as_ref()))
+    }
 
-        None
+    /// Find any unused unkeyed entry of the same type; reusing it requires
+    /// an update.
+    fn find_by_type(&self, used: &[bool], element_type: TypeId, key: Option<u64>) -> Option<usize> {
+        if key.is_some() {
+            return None;
+        }
+        self.typed
+            .get(&element_type)?
+            .iter()
+            .copied()
+            .find(|&i| !used[i])
     }
 }
 
+/// Working buffers for [`ModifierNodeChain::update_from_ref_iter`], kept on
+/// the chain so that reconciling does not allocate once they have grown.
+/// Every buffer is empty between updates.
+#[derive(Default)]
+struct ReconcileScratch {
+    elements: Vec<DynModifierElement>,
+    matches: Vec<Option<usize>>,
+    used: Vec<bool>,
+    slots: Vec<Option<ModifierNodeEntry>>,
+    index: EntryIndex,
+}
+
 impl ModifierNodeChain {
     pub fn new() -> Self {
         let mut chain = Self {
@@ -1292,6 +1421,7 @@ impl ModifierNodeChain {
             head_sentinel: Box::new(SentinelNode::new()),
             tail_sentinel: Box::new(SentinelNode::new()),
             ordered_nodes: Vec::new(),
+            reconcile: ReconcileScratch::default(),
         };
         chain.sync_chain_links();
         chain
@@ -1333,7 +1463,13 @@ impl ModifierNodeChain {
     /// Reconcile the chain against the provided iterator of element references.
     ///
     /// This is the preferred method as it avoids requiring a collected slice,
-    /// enabling zero-allocation traversal of modifier trees.
+    /// enabling zero-allocation traversal of modifier trees: the working
+    /// buffers live on the chain and are reused by every update.
+    ///
+    /// Unchanged elements keep their node even when another element changes
+    /// or is inserted before them; reordered elements follow their node when
+    /// they are equal or share a key. Nodes of the same type at the same
+    /// position are updated in place rather than recreated.
     pub fn update_from_ref_iter<'a, I>(
         &mut self,
         elements: I,
@@ -1341,129 +1477,141 @@ impl ModifierNodeChain {
     ) where
         I: Iterator<Item = &'a DynModifierElement>,
     {
-        let mut old_entries = std::mem::take(&mut self.entries);
-        let mut old_used = vec![false; old_entries.len()];
-        let mut new_entries: Vec<ModifierNodeEntry> = Vec::new();
-
-        // Build index for O(1) lookups - O(m) where m = old_entries.len()
-        let index = EntryIndex::build(&old_entries);
-
-        // Track which old entry index maps to which position in new list
-        let mut match_order: Vec<Option<usize>> = vec![None; old_entries.len()];
+        let ReconcileScratch {
+            elements: new_elements,
+            matches,
+            used,
+            slots,
+            index,
+        } = &mut self.reconcile;
+        let old_entries = &self.entries;
+        used.resize(old_entries.len(), false);
+
+        // Old entry reused by each new element, decided in three passes so a
+        // change to one element never moves another element's node:
+        // 1. unchanged elements keep the node at their own position,
+        // 2. unchanged elements that moved (or keyed ones) follow their node,
+        // 3. changed elements reuse a node of the same type, preferring the
+        //    one at their own position.
+        for (pos, element) in elements.enumerate() {
+            let matched = old_entries.get(pos).is_some_and(|entry| {
+                entry.element_type == element.element_type()
+                    && entry.key == element.key()
+                    && entry.element.as_ref().equals_element(element.as_ref())
+            });
+            if matched {
+                used[pos] = true;
+            }
+            matches.push(matched.then_some(pos));
+            new_elements.push(element.clone());
+        }
 
-        // Track element count as we iterate
-        let mut element_count = 0usize;
+        // The index is only needed when some element did not keep its node.
+        if matches.iter().any(Option::is_none) {
+            // Build index for O(1) lookups - O(m) where m = old_entries.len()
+            index.rebuild(old_entries);
+            for (element, matched) in new_elements.iter().zip(matches.iter_mut()) {
+                if matched.is_some() {
+                    continue;
+                }
+                *matched = index.find_exact(
+                    old_entries,
+                    used,
+                    element.element_type(),
+                    element.key(),
+                    element.hash_code(),
+                    element,
+                );
+                if let Some(idx) = *matched {
+                    used[idx] = true;
+                }
+            }
+            for (pos, (element, matched)) in new_elements.iter().zip(matches.iter_mut()).enumerate()
+            {
+                if matched.is_some() {
+                    continue;
+                }
+                let element_type = element.element_type();
+                let key = element.key();
+                let same_position = old_entries.get(pos).is_some_and(|entry| {
+                    !used[pos] && entry.element_type == element_type && entry.key == key
+                });
+                *matched = if same_position {
+                    Some(pos)
+                } else {
+                    index.find_by_type(used, element_type, key)
+                };
+                if let Some(idx) = *matched {
+                    used[idx] = true;
+                }
+            }
+        }
 
-        // Process each new element, reusing old entries where possible - O(n)
-        for (new_pos, element) in elements.enumerate() {
-            element_count = new_pos + 1;
+        slots.extend(self.entries.drain(..).map(Some));
+        for (element, matched_idx) in new_elements.drain(..).zip(matches.drain(..)) {
             let element_type = element.element_type();
             let key = element.key();
             let hash_code = element.hash_code();
             let capabilities = element.capabilities();
 
-            // Find best matching old entry via index - O(1) amortized
-            let matched_idx = index.find_match(
-                &old_entries,
-                &old_used,
-                element_type,
-                key,
-                hash_code,
-                element,
-            );
-
-            if let Some(idx) = matched_idx {
-                // Reuse existing entry
-                old_used[idx] = true;
-                match_order[idx] = Some(new_pos);
-                let entry = &mut old_entries[idx];
-
-                // Check if element actually changed
-                let same_element = entry.element.as_ref().equals_element(element.as_ref());
-
-                // Re-attach node if it was detached during a previous update
-                {
-                    let node_borrow = entry.node.borrow();
-                    if !node_borrow.node_state().is_attached() {
-                        drop(node_borrow);
-                        attach_node_tree(&mut **entry.node.borrow_mut(), context);
-                    }
-                }
-
-                // Optimize updates: only call update_node if element changed OR
-                // if the element type explicitly requests forced updates
-                let needs_update = !same_element || element.requires_update();
-                if needs_update {
-                    element.update_node(&mut **entry.node.borrow_mut());
-                    entry.element = element.clone();
-                    entry.hash_code = hash_code;
-                    request_auto_invalidations(context, capabilities);
-                }
-
-                // Always update metadata
-                entry.key = key;
-                entry.element_type = element_type;
-                entry.capabilities = capabilities;
-                entry
-                    .node
-                    .borrow()
-                    .node_state()
-                    .set_capabilities(capabilities);
-            } else {
+            let Some(mut entry) = matched_idx.and_then(|idx| slots[idx].take()) else {
                 // Create new entry
+                let node = element.create_node();
                 let entry = ModifierNodeEntry::new(
                     element_type,
                     key,
-                    element.clone(),
-                    element.create_node(),
+                    element,
+                    node,
                     hash_code,
                     capabilities,
                 );
                 attach_node_tree(&mut **entry.node.borrow_mut(), context);
-                element.update_node(&mut **entry.node.borrow_mut());
+                entry.element.update_node(&mut **entry.node.borrow_mut());
                 request_auto_invalidations(context, capabilities);
-                new_entries.push(entry);
-            }
-        }
-
-        // Assemble final list in correct order
-        let mut matched_entries: Vec<(usize, ModifierNodeEntry)> = Vec::new();
-        for (entry, (used, order)) in old_entries
-            .into_iter()
-            .zip(old_used.into_iter().zip(match_order))
-        {
-            if used {
-                matched_entries.push((order.unwrap(), entry));
-            } else {
-                detach_node_tree(&mut **entry.node.borrow_mut());
-            }
-        }
-
-        matched_entries.sort_by_key(|(pos, _)| *pos);
+                self.entries.push(entry);
+                continue;
+            };
 
-        // Merge matched entries with newly created entries
-        let mut final_entries: Vec<ModifierNodeEntry> = Vec::with_capacity(element_count);
-        let mut matched_iter = matched_entries.into_iter();
-        let mut new_iter = new_entries.into_iter();
-        let mut next_matched = matched_iter.next();
-        let mut next_new = new_iter.next();
+            // Check if element actually changed
+            let same_element = entry.element.as_ref().equals_element(element.as_ref());
 
-        for pos in 0..element_count {
-            if let Some((matched_pos, _)) = next_matched {
-                if matched_pos == pos {
-                    final_entries.push(next_matched.take().unwrap().1);
-                    next_matched = matched_iter.next();
-                    continue;
+            // Re-attach node if it was detached during a previous update
+            {
+                let node_borrow = entry.node.borrow();
+                if !node_borrow.node_state().is_attached() {
+                    drop(node_borrow);
+                    attach_node_tree(&mut **entry.node.borrow_mut(), context);
                 }
             }
 
-            if let Some(entry) = next_new.take() {
-                final_entries.push(entry);
-                next_new = new_iter.next();
+            // Optimize updates: only call update_node if element changed OR
+            // if the element type explicitly requests forced updates
+            let needs_update = !same_element || element.requires_update();
+            if needs_update {
+                element.update_node(&mut **entry.node.borrow_mut());
+                entry.element = element;
+                entry.hash_code = hash_code;
+                request_auto_invalidations(context, capabilities);
             }
+
+            // Always update metadata
+            entry.key = key;
+            entry.element_type = element_type;
+            entry.capabilities = capabilities;
+            entry
+                .node
+                .borrow()
+                .node_state()
+                .set_capabilities(capabilities);
+            self.entries.push(entry);
+        }
+
+        // Detach nodes that no element reused
+        for entry in slots.drain(..).flatten() {
+            detach_node_tree(&mut **entry.node.borrow_mut());
         }
+        used.clear();
 
-        self.entries = final_entries;
         self.sync_chain_links();
     }
 
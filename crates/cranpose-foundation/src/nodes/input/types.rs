@@ -18,6 +18,10 @@ pub enum PointerEventKind {
     Move,
     Up,
     Cancel,
+    /// The pointer started hovering over the node.
+    Enter,
+    /// The pointer stopped hovering over the node.
+    Exit,
 }
 
 #[repr(u8)]
@@ -56,6 +60,23 @@ impl PointerButtons {
     pub fn contains(&self, button: PointerButton) -> bool {
         (self.0 & (1 << (button as u8))) != 0
     }
+
+    pub fn is_primary_pressed(&self) -> bool {
+        self.contains(PointerButton::Primary)
+    }
+
+    pub fn is_secondary_pressed(&self) -> bool {
+        self.contains(PointerButton::Secondary)
+    }
+
+    pub fn is_middle_pressed(&self) -> bool {
+        self.contains(PointerButton::Middle)
+    }
+
+    /// Whether no button is pressed.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
 }
 
 impl Default for PointerButtons {
@@ -76,10 +97,16 @@ pub struct PointerEvent {
     pub phase: PointerPhase,
     pub position: Point,
     pub global_position: Point,
+    /// Buttons held down after this event.
     pub buttons: PointerButtons,
+    /// The button whose press or release this event reports, if the source
+    /// says. While several buttons are held, it tells which one changed.
+    pub button: Option<PointerButton>,
     /// Tracks whether this event has been consumed by a handler.
     /// Shared via Rc<Cell> so consumption can be tracked across copies.
     consumed: Rc<Cell<bool>>,
+    /// Set by a handler that claims the rest of the gesture for its node.
+    gesture_locked: Rc<Cell<bool>>,
 }
 
 impl PointerEvent {
@@ -89,14 +116,18 @@ impl PointerEvent {
             kind,
             phase: match kind {
                 PointerEventKind::Down => PointerPhase::Start,
-                PointerEventKind::Move => PointerPhase::Move,
+                PointerEventKind::Move | PointerEventKind::Enter | PointerEventKind::Exit => {
+                    PointerPhase::Move
+                }
                 PointerEventKind::Up => PointerPhase::End,
                 PointerEventKind::Cancel => PointerPhase::Cancel,
             },
             position,
             global_position,
             buttons: PointerButtons::NONE,
+            button: None,
             consumed: Rc::new(Cell::new(false)),
+            gesture_locked: Rc::new(Cell::new(false)),
         }
     }
 
@@ -106,6 +137,25 @@ impl PointerEvent {
         self
     }
 
+    /// Set the button whose press or release this event reports
+    pub fn with_button(mut self, button: PointerButton) -> Self {
+        self.button = Some(button);
+        self
+    }
+
+    /// The button this event presses or releases. Without one set by the
+    /// source, it counts as the primary button unless only the secondary one
+    /// is held.
+    pub fn changed_button(&self) -> PointerButton {
+        self.button.unwrap_or(
+            if self.buttons.is_secondary_pressed() && !self.buttons.is_primary_pressed() {
+                PointerButton::Secondary
+            } else {
+                PointerButton::Primary
+            },
+        )
+    }
+
     /// Mark this event as consumed, preventing other handlers from processing it.
     ///
     /// Example: Scroll gestures consume events once dragging starts to prevent
@@ -122,6 +172,21 @@ impl PointerEvent {
         self.consumed.get()
     }
 
+    /// Keep the rest of this gesture with the node handling this event, e.g.
+    /// once a scrollable claims a drag along its axis.
+    ///
+    /// The other nodes on the pointer's hit path, enclosing scrollables
+    /// included, receive a Cancel and no further events until every button
+    /// is released.
+    pub fn lock_gesture(&self) {
+        self.gesture_locked.set(true);
+    }
+
+    /// Check if a handler locked the gesture to its node.
+    pub fn is_gesture_locked(&self) -> bool {
+        self.gesture_locked.get()
+    }
+
     /// Creates a copy of this event with a new local position, sharing the consumption state.
     pub fn copy_with_local_position(&self, position: Point) -> Self {
         Self {
@@ -131,7 +196,9 @@ impl PointerEvent {
             position,
             global_position: self.global_position,
             buttons: self.buttons,
+            button: self.button,
             consumed: self.consumed.clone(),
+            gesture_locked: self.gesture_locked.clone(),
         }
     }
 }
@@ -375,6 +375,70 @@ fn equality_matching_prefers_identical_elements_over_type_matches() {
     assert_eq!(chain.node::<EqualityNode>(1).unwrap().value, 3);
 }
 
+#[test]
+fn changing_one_element_keeps_other_nodes_in_place() {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = TestContext::default();
+    let updates = Rc::new(Cell::new(0));
+    let elements = |values: [i32; 3]| {
+        values
+            .into_iter()
+            .map(|value| {
+                modifier_element(EqualityElement {
+                    value,
+                    updates: updates.clone(),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let node_ptrs = |chain: &ModifierNodeChain| {
+        (0..3)
+            .map(|i| &*chain.node::<EqualityNode>(i).unwrap() as *const EqualityNode)
+            .collect::<Vec<_>>()
+    };
+
+    chain.update_from_slice(&elements([1, 2, 3]), &mut context);
+    let before = node_ptrs(&chain);
+    updates.set(0);
+
+    // The middle element now equals the last one; the last one must not
+    // trade its node for the middle one's.
+    chain.update_from_slice(&elements([1, 3, 3]), &mut context);
+    assert_eq!(node_ptrs(&chain), before);
+    assert_eq!(updates.get(), 1, "only the changed node is updated");
+    assert_eq!(chain.node::<EqualityNode>(1).unwrap().value, 3);
+}
+
+#[test]
+fn reconciling_reuses_scratch_buffers_and_releases_elements() {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = TestContext::default();
+    let updates = Rc::new(Cell::new(0));
+    let elements = |values: [i32; 3]| {
+        values
+            .into_iter()
+            .map(|value| {
+                modifier_element(EqualityElement {
+                    value,
+                    updates: updates.clone(),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    chain.update_from_slice(&elements([1, 2, 3]), &mut context);
+    let capacity = chain.reconcile.elements.capacity();
+
+    let unchanged = elements([1, 2, 3]);
+    chain.update_from_slice(&unchanged, &mut context);
+    assert!(unchanged
+        .iter()
+        .all(|element| Rc::strong_count(element) == 1));
+    assert!(chain.reconcile.elements.is_empty());
+    assert!(chain.reconcile.slots.is_empty());
+    assert_eq!(chain.reconcile.elements.capacity(), capacity);
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct KeyedElement {
     key: u64,
@@ -1237,11 +1301,7 @@ fn semantics_delegates_are_visited_by_forward_matching() {
     });
     assert_eq!(visits, 1, "delegated semantics node should be visited");
 
-    let mut config = SemanticsConfiguration {
-        content_description: None,
-        is_button: false,
-        is_clickable: false,
-    };
+    let mut config = SemanticsConfiguration::default();
     chain.for_each_forward_matching(NodeCapabilities::SEMANTICS, |node_ref| {
         node_ref.with_node(|node| {
             if let Some(semantics_node) = node.as_semantics_node() {
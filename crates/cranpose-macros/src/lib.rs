@@ -174,7 +174,7 @@ pub fn composable(attr: TokenStream, item: TokenStream) -> TokenStream {
             } else {
                 let ident = Ident::new(&format!("__arg{}", index), Span::call_site());
                 let original_pat: Box<Pat> = pat.clone();
-                *pat = Box::new(syn::parse_quote! { #ident });
+                **pat = syn::parse_quote! { #ident };
                 param_info.push(ParamInfo {
                     ident,
                     pat: original_pat,
@@ -281,7 +281,11 @@ pub fn composable(attr: TokenStream, item: TokenStream) -> TokenStream {
                             .use_value_slot(|| cranpose_core::ParamState::<#ty>::default());
                         if __composer.with_slot_value_mut::<cranpose_core::ParamState<#ty>, _>(
                             #slot_ident,
-                            |state| state.update(&#ident),
+                            |state| {
+                                #[allow(unused_imports)]
+                                use cranpose_core::{EqParamCheck as _, StableParamCheck as _};
+                                (&cranpose_core::ParamCheck(&#ident)).param_changed(state)
+                            },
                         )
                         {
                             __changed = true;
@@ -509,7 +513,7 @@ pub fn composable(attr: TokenStream, item: TokenStream) -> TokenStream {
                 })
             })
         });
-        func.block = Box::new(syn::parse2(wrapped).expect("failed to build block"));
+        *func.block = syn::parse2(wrapped).expect("failed to build block");
         TokenStream::from(quote! {
             #recranpose_fn
             #helper_fn
@@ -525,7 +529,7 @@ pub fn composable(attr: TokenStream, item: TokenStream) -> TokenStream {
                 })
             })
         });
-        func.block = Box::new(syn::parse2(wrapped).expect("failed to build block"));
+        *func.block = syn::parse2(wrapped).expect("failed to build block");
         TokenStream::from(quote! { #func })
     }
 }
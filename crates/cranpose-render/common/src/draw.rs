@@ -0,0 +1,46 @@
+//! Draw records that every backend collects its scene into.
+
+use crate::{scale_brush, scale_rect, scale_shape};
+use cranpose_core::NodeId;
+use cranpose_ui_graphics::{Brush, Color, Rect, RoundedCornerShape};
+
+#[derive(Clone)]
+pub struct DrawShape {
+    /// The node that drew the shape; `None` for renderer overlays.
+    pub node_id: Option<NodeId>,
+    pub rect: Rect,
+    pub brush: Brush,
+    pub shape: Option<RoundedCornerShape>,
+    /// Stroke width drawn inside `rect`; `None` fills the shape.
+    pub stroke_width: Option<f32>,
+    pub z_index: usize,
+    pub clip: Option<Rect>,
+}
+
+#[derive(Clone)]
+pub struct TextDraw {
+    /// The node that drew the text; `None` for renderer overlays.
+    pub node_id: Option<NodeId>,
+    pub rect: Rect,
+    pub text: String,
+    pub color: Color,
+    pub scale: f32,
+    pub z_index: usize,
+    pub clip: Option<Rect>,
+}
+
+/// Converts `shapes` and `texts` from logical pixels to device pixels.
+pub fn scale_draws(shapes: &mut [DrawShape], texts: &mut [TextDraw], scale: f32) {
+    for shape in shapes {
+        shape.rect = scale_rect(shape.rect, scale);
+        shape.brush = scale_brush(&shape.brush, scale);
+        shape.shape = shape.shape.map(|rounded| scale_shape(rounded, scale));
+        shape.stroke_width = shape.stroke_width.map(|width| width * scale);
+        shape.clip = shape.clip.map(|clip| scale_rect(clip, scale));
+    }
+    for text in texts {
+        text.rect = scale_rect(text.rect, scale);
+        text.scale *= scale;
+        text.clip = text.clip.map(|clip| scale_rect(clip, scale));
+    }
+}
@@ -0,0 +1,98 @@
+//! Hit testing shared by renderer backends, so they agree on which target
+//! an expanded touch target (`Modifier::minimum_touch_target`) resolves to.
+
+use cranpose_ui_graphics::{Rect, Size};
+
+use crate::HitTestTarget;
+
+/// The geometry of a backend's hit region that [`hit_test_regions`] reads.
+pub trait HitRegionGeometry: HitTestTarget {
+    /// Bounds of the node itself.
+    fn rect(&self) -> Rect;
+
+    /// Paint order; higher values are drawn on top.
+    fn z_index(&self) -> usize;
+
+    /// Clip limiting where the region takes pointer input.
+    fn hit_clip(&self) -> Option<Rect>;
+
+    /// Pointer bounds grown past [`rect`](Self::rect), if any.
+    fn touch_bounds(&self) -> Option<Rect>;
+
+    /// Whether `(x, y)` hits the node itself, clip and shape included.
+    fn contains(&self, x: f32, y: f32) -> bool;
+}
+
+/// Pointer bounds for a node whose touch target must be at least `minimum`,
+/// grown evenly around `rect`. Returns `None` when `rect` is already that big.
+pub fn touch_target_bounds(rect: Rect, minimum: Size) -> Option<Rect> {
+    let extra_width = (minimum.width - rect.width).max(0.0);
+    let extra_height = (minimum.height - rect.height).max(0.0);
+    if extra_width == 0.0 && extra_height == 0.0 {
+        return None;
+    }
+    Some(Rect {
+        x: rect.x - extra_width / 2.0,
+        y: rect.y - extra_height / 2.0,
+        width: rect.width + extra_width,
+        height: rect.height + extra_height,
+    })
+}
+
+/// Distance from `(x, y)` to the nearest point of `rect`; zero inside it.
+fn distance_to_rect(rect: Rect, x: f32, y: f32) -> f32 {
+    let dx = (rect.x - x).max(x - (rect.x + rect.width)).max(0.0);
+    let dy = (rect.y - y).max(y - (rect.y + rect.height)).max(0.0);
+    dx.hypot(dy)
+}
+
+/// Whether `inner` lies entirely within `outer`.
+fn rect_encloses(outer: Rect, inner: Rect) -> bool {
+    inner.x >= outer.x
+        && inner.y >= outer.y
+        && inner.x + inner.width <= outer.x + outer.width
+        && inner.y + inner.height <= outer.y + outer.height
+}
+
+/// Whether `(x, y)` misses `region` itself but lands in its expanded touch
+/// target.
+pub fn touch_target_contains<R: HitRegionGeometry>(region: &R, x: f32, y: f32) -> bool {
+    let Some(bounds) = region.touch_bounds() else {
+        return false;
+    };
+    if let Some(clip) = region.hit_clip() {
+        if !clip.contains(x, y) {
+            return false;
+        }
+    }
+    bounds.contains(x, y) && !region.contains(x, y)
+}
+
+/// The regions under `(x, y)`, topmost first.
+///
+/// Of the targets reached only through their expanded touch bounds, the one
+/// closest to the pointer wins. A target hit directly beats them all unless
+/// it is a container around them.
+pub fn hit_test_regions<R: HitRegionGeometry + Clone>(regions: &[R], x: f32, y: f32) -> Vec<R> {
+    let mut hits: Vec<R> = regions
+        .iter()
+        .filter(|region| region.contains(x, y))
+        .cloned()
+        .collect();
+    let nearest_expanded = regions
+        .iter()
+        .filter(|region| touch_target_contains(*region, x, y))
+        .filter(|region| {
+            hits.iter()
+                .all(|direct| rect_encloses(direct.rect(), region.rect()))
+        })
+        .min_by(|a, b| {
+            distance_to_rect(a.rect(), x, y)
+                .total_cmp(&distance_to_rect(b.rect(), x, y))
+                .then(b.z_index().cmp(&a.z_index()))
+        });
+    hits.extend(nearest_expanded.cloned());
+
+    hits.sort_by_key(|hit| std::cmp::Reverse(hit.z_index()));
+    hits
+}
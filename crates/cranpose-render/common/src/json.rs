@@ -0,0 +1,54 @@
+//! Minimal JSON writers shared by the scene snapshot and other debug dumps,
+//! so renderers and the app shell emit the same encoding without a
+//! serialisation framework.
+
+use std::fmt::Write;
+
+/// Writes `value` with `write`, or `null` when it is absent.
+pub fn write_optional<T>(out: &mut String, value: Option<T>, write: impl FnOnce(&mut String, T)) {
+    match value {
+        Some(value) => write(out, value),
+        None => out.push_str("null"),
+    }
+}
+
+/// Writes `value` as a JSON number; NaN and infinities, which JSON lacks,
+/// come out as `null`.
+pub fn write_number(out: &mut String, value: f32) {
+    if value.is_finite() {
+        let _ = write!(out, "{value}");
+    } else {
+        out.push_str("null");
+    }
+}
+
+/// Writes `values` as a JSON array of numbers.
+pub fn write_numbers(out: &mut String, values: &[f32]) {
+    out.push('[');
+    for (index, &value) in values.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_number(out, value);
+    }
+    out.push(']');
+}
+
+/// Writes `value` as a quoted, escaped JSON string.
+pub fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if u32::from(ch) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(ch));
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
@@ -1,11 +1,80 @@
 //! Common rendering contracts shared between renderer backends.
 
 use cranpose_foundation::nodes::input::PointerEvent;
-use cranpose_ui::LayoutTree;
-use cranpose_ui_graphics::Size;
+use cranpose_ui::{LayoutOverlay, LayoutTree};
+use cranpose_ui_graphics::{CornerRadii, Point, Rect, RoundedCornerShape, Size};
 
 pub use cranpose_ui_graphics::Brush;
 
+mod draw;
+mod hit_test;
+pub mod json;
+mod snapshot;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+pub use draw::{scale_draws, DrawShape, TextDraw};
+pub use hit_test::{
+    hit_test_regions, touch_target_bounds, touch_target_contains, HitRegionGeometry,
+};
+pub use snapshot::{HitSnapshot, SceneSnapshot, ShapeSnapshot, TextSnapshot};
+
+/// Font size, in logical pixels (em size), that every backend measures text at.
+///
+/// Backends must report the same metrics for the same font so that a layout
+/// computed once renders identically on each of them: the width of a line is
+/// the sum of its glyph advances, and each line is [`text_line_height`] tall.
+pub const BASE_FONT_SIZE: f32 = cranpose_ui_graphics::DEFAULT_FONT_SIZE;
+
+/// Line height as a multiple of the font size.
+pub const LINE_HEIGHT_FACTOR: f32 = 1.4;
+
+/// Height of one line of text rendered at `font_size`.
+pub fn text_line_height(font_size: f32) -> f32 {
+    font_size * LINE_HEIGHT_FACTOR
+}
+
+/// Converts a rectangle from logical pixels to device pixels.
+pub fn scale_rect(rect: Rect, scale: f32) -> Rect {
+    Rect {
+        x: rect.x * scale,
+        y: rect.y * scale,
+        width: rect.width * scale,
+        height: rect.height * scale,
+    }
+}
+
+/// Converts the geometry carried by a brush (a radial gradient's center and
+/// radius) from logical pixels to device pixels.
+pub fn scale_brush(brush: &Brush, scale: f32) -> Brush {
+    match brush {
+        Brush::RadialGradient {
+            colors,
+            center,
+            radius,
+        } => Brush::RadialGradient {
+            colors: colors.clone(),
+            center: Point {
+                x: center.x * scale,
+                y: center.y * scale,
+            },
+            radius: radius * scale,
+        },
+        other => other.clone(),
+    }
+}
+
+/// Converts a rounded shape's corner radii from logical pixels to device pixels.
+pub fn scale_shape(shape: RoundedCornerShape, scale: f32) -> RoundedCornerShape {
+    let radii = shape.radii();
+    RoundedCornerShape::with_radii(CornerRadii {
+        top_left: radii.top_left * scale,
+        top_right: radii.top_right * scale,
+        bottom_right: radii.bottom_right * scale,
+        bottom_left: radii.bottom_left * scale,
+    })
+}
+
 /// Trait implemented by hit-test targets stored inside a [`RenderScene`].
 pub trait HitTestTarget {
     /// Dispatches a pointer event to this target's handlers.
@@ -45,6 +114,13 @@ pub trait RenderScene {
     ///
     /// Returns None if the node no longer exists in the scene (e.g., removed during gesture).
     fn find_target(&self, node_id: cranpose_core::NodeId) -> Option<Self::HitTarget>;
+
+    /// Describes what the scene draws and where it takes pointer input, e.g.
+    /// for a remote inspector. Scenes that cannot describe themselves report
+    /// an empty snapshot.
+    fn snapshot(&self) -> SceneSnapshot {
+        SceneSnapshot::default()
+    }
 }
 
 /// Abstraction implemented by concrete renderer backends.
@@ -55,9 +131,16 @@ pub trait Renderer {
     fn scene(&self) -> &Self::Scene;
     fn scene_mut(&mut self) -> &mut Self::Scene;
 
+    /// Rebuild the scene from `layout_tree`, with the popup layers of
+    /// `overlay` drawn on top of it and hit-tested before it.
+    ///
+    /// Layout runs in logical pixels; backends convert drawing to device
+    /// pixels using their root scale here, while hit regions stay logical so
+    /// pointer input keeps matching layout coordinates.
     fn rebuild_scene(
         &mut self,
         layout_tree: &LayoutTree,
+        overlay: &LayoutOverlay,
         viewport: Size,
     ) -> Result<(), Self::Error>;
 
@@ -0,0 +1,274 @@
+//! Structured, serialisable description of a rendered scene.
+//!
+//! Devtools clients and golden tests read what a backend drew from a
+//! [`SceneSnapshot`] instead of its draw lists; [`SceneSnapshot::to_json`]
+//! writes it out without pulling a serialisation framework into renderers.
+
+use std::fmt::Write;
+
+use crate::json::{write_number, write_numbers, write_optional, write_string};
+use crate::{DrawShape, HitRegionGeometry, TextDraw};
+use cranpose_core::NodeId;
+use cranpose_ui::{RecordedRenderScene, RenderOp};
+use cranpose_ui_graphics::{Brush, Color, CornerRadii, DrawPrimitive, Rect};
+
+/// A filled or stroked (rounded) rectangle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeSnapshot {
+    /// The node that drew the shape, when the scene records it.
+    pub node_id: Option<NodeId>,
+    pub rect: Rect,
+    pub brush: Brush,
+    /// Corner radii; `None` for a plain rectangle.
+    pub radii: Option<CornerRadii>,
+    /// Outline width drawn inside `rect`; `None` fills the shape.
+    pub stroke_width: Option<f32>,
+}
+
+/// A run of text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSnapshot {
+    /// The node that drew the text, when the scene records it.
+    pub node_id: Option<NodeId>,
+    pub rect: Rect,
+    pub text: String,
+    /// Text color, when the scene records it.
+    pub color: Option<Color>,
+}
+
+/// An area that takes pointer input for a node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HitSnapshot {
+    pub node_id: NodeId,
+    pub rect: Rect,
+}
+
+/// What a scene draws, in paint order, and where it takes pointer input.
+///
+/// Geometry is reported the way the scene stores it: backends that scale
+/// draws to device pixels keep their hit regions in logical pixels.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SceneSnapshot {
+    pub shapes: Vec<ShapeSnapshot>,
+    pub texts: Vec<TextSnapshot>,
+    pub hits: Vec<HitSnapshot>,
+    /// Entries dropped by [`truncate`](Self::truncate).
+    pub omitted: usize,
+}
+
+impl SceneSnapshot {
+    /// Builds a snapshot from a backend's draw lists and hit regions.
+    pub fn from_draws<H: HitRegionGeometry>(
+        shapes: &[DrawShape],
+        texts: &[TextDraw],
+        hits: &[H],
+    ) -> Self {
+        Self {
+            shapes: shapes
+                .iter()
+                .map(|shape| ShapeSnapshot {
+                    node_id: shape.node_id,
+                    rect: shape.rect,
+                    brush: shape.brush.clone(),
+                    radii: shape
+                        .shape
+                        .map(|rounded| rounded.resolve(shape.rect.width, shape.rect.height)),
+                    stroke_width: shape.stroke_width,
+                })
+                .collect(),
+            texts: texts
+                .iter()
+                .map(|text| TextSnapshot {
+                    node_id: text.node_id,
+                    rect: text.rect,
+                    text: text.text.clone(),
+                    color: Some(text.color),
+                })
+                .collect(),
+            hits: hits
+                .iter()
+                .map(|hit| HitSnapshot {
+                    node_id: hit.node_id(),
+                    rect: hit.rect(),
+                })
+                .collect(),
+            omitted: 0,
+        }
+    }
+
+    /// Keeps at most `max_entries` shapes, texts and hit regions each,
+    /// counting the rest in [`omitted`](Self::omitted), so a large scene
+    /// stays cheap to send.
+    pub fn truncate(&mut self, max_entries: usize) {
+        let total = self.shapes.len() + self.texts.len() + self.hits.len();
+        self.shapes.truncate(max_entries);
+        self.texts.truncate(max_entries);
+        self.hits.truncate(max_entries);
+        self.omitted += total - (self.shapes.len() + self.texts.len() + self.hits.len());
+    }
+
+    /// Serialises the snapshot as a JSON object:
+    ///
+    /// ```json
+    /// {"shapes":[{"node":2,"rect":[0,0,100,20],"brush":{"solid":[1,0,0,1]},
+    ///   "radii":null,"stroke":null}],
+    ///  "texts":[{"node":3,"rect":[0,0,40,20],"text":"Hi","color":[0,0,0,1]}],
+    ///  "hits":[{"node":2,"rect":[0,0,100,20]}],
+    ///  "omitted":0}
+    /// ```
+    ///
+    /// Rectangles are `[x, y, width, height]`, colors `[r, g, b, a]` and radii
+    /// `[top_left, top_right, bottom_right, bottom_left]`. Unknown node ids
+    /// and colors are `null`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"shapes\":[");
+        for (index, shape) in self.shapes.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"node\":");
+            write_optional(&mut out, shape.node_id, write_node);
+            out.push_str(",\"rect\":");
+            write_rect(&mut out, shape.rect);
+            out.push_str(",\"brush\":");
+            write_brush(&mut out, &shape.brush);
+            out.push_str(",\"radii\":");
+            write_optional(&mut out, shape.radii, |out, radii| {
+                write_numbers(
+                    out,
+                    &[
+                        radii.top_left,
+                        radii.top_right,
+                        radii.bottom_right,
+                        radii.bottom_left,
+                    ],
+                )
+            });
+            out.push_str(",\"stroke\":");
+            write_optional(&mut out, shape.stroke_width, write_number);
+            out.push('}');
+        }
+        out.push_str("],\"texts\":[");
+        for (index, text) in self.texts.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"node\":");
+            write_optional(&mut out, text.node_id, write_node);
+            out.push_str(",\"rect\":");
+            write_rect(&mut out, text.rect);
+            out.push_str(",\"text\":");
+            write_string(&mut out, &text.text);
+            out.push_str(",\"color\":");
+            write_optional(&mut out, text.color, write_color);
+            out.push('}');
+        }
+        out.push_str("],\"hits\":[");
+        for (index, hit) in self.hits.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"node\":");
+            write_node(&mut out, hit.node_id);
+            out.push_str(",\"rect\":");
+            write_rect(&mut out, hit.rect);
+            out.push('}');
+        }
+        let _ = write!(out, "],\"omitted\":{}}}", self.omitted);
+        out
+    }
+}
+
+impl From<&RecordedRenderScene> for SceneSnapshot {
+    fn from(scene: &RecordedRenderScene) -> Self {
+        let mut snapshot = SceneSnapshot::default();
+        for op in scene.operations() {
+            match op {
+                RenderOp::Primitive {
+                    node_id, primitive, ..
+                } => {
+                    let (rect, brush, radii, stroke_width) = match primitive {
+                        DrawPrimitive::Rect { rect, brush } => (*rect, brush, None, None),
+                        DrawPrimitive::RoundRect { rect, brush, radii } => {
+                            (*rect, brush, Some(*radii), None)
+                        }
+                        DrawPrimitive::Stroke {
+                            rect,
+                            brush,
+                            radii,
+                            width,
+                        } => (*rect, brush, Some(*radii), Some(*width)),
+                    };
+                    snapshot.shapes.push(ShapeSnapshot {
+                        node_id: Some(*node_id),
+                        rect,
+                        brush: brush.clone(),
+                        radii,
+                        stroke_width,
+                    });
+                }
+                RenderOp::Text {
+                    node_id,
+                    rect,
+                    value,
+                } => snapshot.texts.push(TextSnapshot {
+                    node_id: Some(*node_id),
+                    rect: *rect,
+                    text: value.clone(),
+                    color: None,
+                }),
+            }
+        }
+        snapshot
+    }
+}
+
+fn write_node(out: &mut String, id: NodeId) {
+    let _ = write!(out, "{id}");
+}
+
+fn write_rect(out: &mut String, rect: Rect) {
+    write_numbers(out, &[rect.x, rect.y, rect.width, rect.height]);
+}
+
+fn write_color(out: &mut String, color: Color) {
+    write_numbers(out, &[color.0, color.1, color.2, color.3]);
+}
+
+fn write_colors(out: &mut String, colors: &[Color]) {
+    out.push('[');
+    for (index, &color) in colors.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_color(out, color);
+    }
+    out.push(']');
+}
+
+fn write_brush(out: &mut String, brush: &Brush) {
+    match brush {
+        Brush::Solid(color) => {
+            out.push_str("{\"solid\":");
+            write_color(out, *color);
+        }
+        Brush::LinearGradient(colors) => {
+            out.push_str("{\"linear\":");
+            write_colors(out, colors);
+        }
+        Brush::RadialGradient {
+            colors,
+            center,
+            radius,
+        } => {
+            out.push_str("{\"radial\":{\"colors\":");
+            write_colors(out, colors);
+            out.push_str(",\"center\":");
+            write_numbers(out, &[center.x, center.y]);
+            out.push_str(",\"radius\":");
+            write_number(out, *radius);
+            out.push('}');
+        }
+    }
+    out.push('}');
+}
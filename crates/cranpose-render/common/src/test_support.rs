@@ -0,0 +1,42 @@
+//! Layout fixtures shared by the tests of the renderer backends.
+//!
+//! Enabled by the `test-support` feature.
+
+use cranpose_core::NodeId;
+use cranpose_ui::{
+    collect_slices_from_modifier, LayoutBox, LayoutNodeData, LayoutNodeKind, Modifier,
+    ResolvedModifiers,
+};
+use cranpose_ui_graphics::{Point, Rect};
+
+/// A placed layout box for `modifier` at `rect`, without content offset.
+pub fn layout_box(
+    node_id: NodeId,
+    rect: Rect,
+    modifier: Modifier,
+    children: Vec<LayoutBox>,
+) -> LayoutBox {
+    let slices = collect_slices_from_modifier(&modifier);
+    LayoutBox::new(
+        node_id,
+        rect,
+        Point::default(),
+        LayoutNodeData::new(
+            modifier,
+            ResolvedModifiers::default(),
+            slices,
+            LayoutNodeKind::Layout,
+        ),
+        children,
+    )
+}
+
+/// Shorthand for a [`Rect`] literal.
+pub fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
@@ -6,13 +6,12 @@ use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
 
-use cranpose_ui::{Brush, TextMeasurer, TextMetrics};
-use cranpose_ui_graphics::{Color, Rect};
+use cranpose_render_common::{text_line_height, BASE_FONT_SIZE};
+use cranpose_ui::{measure_wrapped, text_width_bucket, Brush, TextMeasurer, TextMetrics};
+use cranpose_ui_graphics::{Color, CornerRadii, Rect};
 
 use crate::scene::{Scene, TextDraw};
-use crate::style::point_in_resolved_rounded_rect;
 
-const TEXT_SIZE: f32 = 24.0;
 static FONT: Lazy<Font<'static>> = Lazy::new(|| {
     let f = Font::try_from_bytes(include_bytes!(
         "../../../../apps/desktop-demo/assets/Roboto-Light.ttf"
@@ -21,6 +20,7 @@ static FONT: Lazy<Font<'static>> = Lazy::new(|| {
     f
 });
 
+/// Measures text with the bundled Roboto Light face under the shared metrics contract.
 pub struct CachedRusttypeTextMeasurer {
     cache: Mutex<TextMetricsCache>,
 }
@@ -50,8 +50,9 @@ impl Borrow<str> for TextKey {
     }
 }
 
+/// Metrics per text, one entry per width bucket it has been measured at.
 struct TextMetricsCache {
-    map: LruCache<TextKey, TextMetrics>,
+    map: LruCache<TextKey, Vec<(u32, TextMetrics)>>,
 }
 
 impl TextMetricsCache {
@@ -63,24 +64,31 @@ impl TextMetricsCache {
         }
     }
 
-    fn get_or_measure<F>(&mut self, text: &str, measure: F) -> TextMetrics
+    fn get_or_measure<F>(&mut self, text: &str, max_width: f32, measure: F) -> TextMetrics
     where
-        F: FnOnce(&str) -> TextMetrics,
+        F: FnOnce(&str, f32) -> TextMetrics,
     {
-        if let Some(metrics) = self.map.get(text).copied() {
+        let bucket = text_width_bucket(max_width);
+        if let Some(entries) = self.map.get_mut(text) {
+            if let Some((_, metrics)) = entries.iter().find(|(b, _)| *b == bucket) {
+                return *metrics;
+            }
+            let metrics = measure(text, max_width);
+            entries.push((bucket, metrics));
             return metrics;
         }
         let key = TextKey {
             text: Arc::from(text),
         };
-        let metrics = measure(text);
-        self.map.put(key, metrics);
+        let metrics = measure(text, max_width);
+        self.map.put(key, vec![(bucket, metrics)]);
         metrics
     }
 }
 
 impl CachedRusttypeTextMeasurer {
-    pub(crate) fn new(capacity: usize) -> Self {
+    /// Creates a measurer caching metrics for up to `capacity` distinct strings.
+    pub fn new(capacity: usize) -> Self {
         Self {
             cache: Mutex::new(TextMetricsCache::new(capacity)),
         }
@@ -149,11 +157,11 @@ fn clip_bounds_from_clip(clip: Option<Rect>, width: u32, height: u32) -> Option<
 }
 
 impl TextMeasurer for CachedRusttypeTextMeasurer {
-    fn measure(&self, text: &str) -> TextMetrics {
+    fn measure(&self, text: &str, max_width: f32) -> TextMetrics {
         self.cache
             .lock()
             .expect("text metrics cache poisoned")
-            .get_or_measure(text, measure_text_impl)
+            .get_or_measure(text, max_width, measure_text_impl)
     }
 
     fn get_offset_for_position(&self, text: &str, x: f32, _y: f32) -> usize {
@@ -161,63 +169,23 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
             return 0;
         }
 
-        let scale = Scale::uniform(TEXT_SIZE);
         let font = &*FONT;
-        let v_metrics = font.v_metrics(scale);
-        let origin = point(0.0, v_metrics.ascent);
+        let scale = font_scale(font, BASE_FONT_SIZE);
+        let carets = caret_positions(font, scale, text);
 
-        // Find the glyph whose center is closest to x
+        // Pick the character boundary closest to x
         let mut best_offset = 0;
         let mut best_distance = f32::INFINITY;
-        let mut current_byte_offset = 0;
-
-        for c in text.chars() {
-            // Get glyph position for this character
-            let prefix = &text[..current_byte_offset];
-            let mut glyph_x = 0.0f32;
-
-            // Measure prefix width to get glyph start position
-            for glyph in font.layout(prefix, scale, origin) {
-                if let Some(bb) = glyph.pixel_bounding_box() {
-                    glyph_x = bb.max.x as f32;
-                }
-            }
-
-            // Get width of current character to find center
-            let char_str = &text[current_byte_offset..current_byte_offset + c.len_utf8()];
-            let char_width = {
-                let mut w = 0.0f32;
-                for glyph in font.layout(char_str, scale, origin) {
-                    if let Some(bb) = glyph.pixel_bounding_box() {
-                        w = (bb.max.x - bb.min.x) as f32;
-                    }
-                }
-                w.max(TEXT_SIZE * 0.5) // Minimum width for whitespace
-            };
-
-            // Check distance to left edge of character
-            let left_dist = (x - glyph_x).abs();
-            if left_dist < best_distance {
-                best_distance = left_dist;
-                best_offset = current_byte_offset;
+        let boundaries = text
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(std::iter::once(text.len()));
+        for (offset, caret_x) in boundaries.zip(carets) {
+            let distance = (x - caret_x).abs();
+            if distance < best_distance {
+                best_distance = distance;
+                best_offset = offset;
             }
-
-            // Check distance to right edge (= after this character)
-            let right_x = glyph_x + char_width;
-            let right_dist = (x - right_x).abs();
-            if right_dist < best_distance {
-                best_distance = right_dist;
-                best_offset = current_byte_offset + c.len_utf8();
-            }
-
-            current_byte_offset += c.len_utf8();
-        }
-
-        // Also check end of text
-        let total_width = measure_text_impl(text).width;
-        let end_dist = (x - total_width).abs();
-        if end_dist < best_distance {
-            best_offset = text.len();
         }
 
         best_offset
@@ -231,22 +199,27 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
 
         // Measure text up to offset
         let prefix = &text[..clamped_offset];
-        measure_text_impl(prefix).width
+        measure_text_impl(prefix, f32::INFINITY).width
+    }
+
+    fn baseline(&self, line_height: f32) -> f32 {
+        // The baseline scales with the line box, so scale it from the base size.
+        let base_line_height = text_line_height(BASE_FONT_SIZE);
+        line_baseline(&FONT, BASE_FONT_SIZE) * line_height / base_line_height
     }
 
     fn layout(&self, text: &str) -> cranpose_ui::text_layout_result::TextLayoutResult {
         use cranpose_ui::text_layout_result::{LineLayout, TextLayoutResult};
 
-        let scale = Scale::uniform(TEXT_SIZE);
         let font = &*FONT;
-        let v_metrics = font.v_metrics(scale);
-        let line_height = (v_metrics.ascent - v_metrics.descent).ceil();
-        let _origin = point(0.0, v_metrics.ascent);
+        let scale = font_scale(font, BASE_FONT_SIZE);
+        let line_height = text_line_height(BASE_FONT_SIZE);
 
         let mut glyph_x_positions = Vec::new();
         let mut char_to_byte = Vec::new();
         let mut lines = Vec::new();
         let mut current_x = 0.0f32;
+        let mut previous = None;
         let mut line_start = 0;
         let mut y = 0.0f32;
 
@@ -265,10 +238,10 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
                 line_start = byte_offset + 1;
                 y += line_height;
                 current_x = 0.0;
+                previous = None;
             } else {
-                // Get glyph advance
-                let glyph = font.glyph(c).scaled(scale);
-                current_x += glyph.h_metrics().advance_width;
+                current_x += glyph_advance(font, scale, previous, c);
+                previous = Some(c);
             }
         }
 
@@ -284,7 +257,7 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
             height: line_height,
         });
 
-        let metrics = measure_text_impl(text);
+        let metrics = measure_text_impl(text, f32::INFINITY);
         TextLayoutResult::new(
             metrics.width,
             metrics.height,
@@ -297,48 +270,56 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
     }
 }
 
-fn measure_text_impl(text: &str) -> TextMetrics {
-    let scale = Scale::uniform(TEXT_SIZE);
+fn measure_text_impl(text: &str, max_width: f32) -> TextMetrics {
     let font = &*FONT;
-    let v_metrics = font.v_metrics(scale);
-    let line_height = (v_metrics.ascent - v_metrics.descent).ceil();
-
-    // Split by newlines for multiline support
-    let lines: Vec<&str> = text.split('\n').collect();
-    let line_count = lines.len().max(1);
-
-    // Measure max width across all lines
-    let mut max_width: f32 = 0.0;
-    for line in &lines {
-        let origin = point(0.0, v_metrics.ascent);
-        let mut min_x: f32 = f32::INFINITY;
-        let mut line_max_x: f32 = 0.0;
-        let mut glyph_count = 0_u32;
-
-        for glyph in font.layout(line, scale, origin) {
-            glyph_count += 1;
-            if let Some(bb) = glyph.pixel_bounding_box() {
-                min_x = min_x.min(bb.min.x as f32);
-                line_max_x = line_max_x.max(bb.max.x as f32);
-            }
-        }
+    let scale = font_scale(font, BASE_FONT_SIZE);
 
-        let line_width = if glyph_count == 0 {
-            0.0
-        } else if min_x.is_infinite() {
-            line_max_x
-        } else {
-            (line_max_x - min_x).max(0.0)
-        };
-        max_width = max_width.max(line_width);
-    }
+    measure_wrapped(text, max_width, text_line_height(BASE_FONT_SIZE), |line| {
+        caret_positions(font, scale, &text[line])
+            .last()
+            .copied()
+            .unwrap_or(0.0)
+    })
+}
+
+/// Rusttype scale for an em size of `font_size` pixels.
+///
+/// `Scale::uniform` sizes the ascent-to-descent height, whereas the shared metrics
+/// contract (and glyphon) size the em square, so convert between the two.
+fn font_scale(font: &Font<'static>, font_size: f32) -> Scale {
+    let v_metrics = font.v_metrics_unscaled();
+    let units_per_em = f32::from(font.units_per_em().max(1));
+    Scale::uniform(font_size * (v_metrics.ascent - v_metrics.descent) / units_per_em)
+}
 
-    TextMetrics {
-        width: max_width,
-        height: line_count as f32 * line_height,
-        line_height,
-        line_count,
+/// Distance from the top of a line box to the baseline at `font_size`.
+fn line_baseline(font: &Font<'static>, font_size: f32) -> f32 {
+    let v_metrics = font.v_metrics(font_scale(font, font_size));
+    // Centre the glyphs in the line box, as glyphon does.
+    let glyph_height = v_metrics.ascent - v_metrics.descent;
+    (text_line_height(font_size) - glyph_height) / 2.0 + v_metrics.ascent
+}
+
+/// Horizontal advance of `c`, including kerning against the preceding character.
+fn glyph_advance(font: &Font<'static>, scale: Scale, previous: Option<char>, c: char) -> f32 {
+    let kerning = previous.map_or(0.0, |prev| font.pair_kerning(scale, prev, c));
+    kerning + font.glyph(c).scaled(scale).h_metrics().advance_width
+}
+
+/// Caret x positions at every character boundary of a single line.
+///
+/// The last entry is the advance width of the whole line.
+fn caret_positions(font: &Font<'static>, scale: Scale, line: &str) -> Vec<f32> {
+    let mut carets = Vec::with_capacity(line.len() + 1);
+    let mut x = 0.0;
+    let mut previous = None;
+    carets.push(x);
+    for c in line.chars() {
+        x += glyph_advance(font, scale, previous, c);
+        previous = Some(c);
+        carets.push(x);
     }
+    carets
 }
 
 pub fn draw_scene(frame: &mut [u8], width: u32, height: u32, scene: &Scene) {
@@ -347,13 +328,13 @@ pub fn draw_scene(frame: &mut [u8], width: u32, height: u32, scene: &Scene) {
     }
 
     let mut shapes = scene.shapes.clone();
-    shapes.sort_by(|a, b| a.z_index.cmp(&b.z_index));
+    shapes.sort_by_key(|a| a.z_index);
     for shape in shapes {
         draw_shape(frame, width, height, shape);
     }
 
     let mut texts = scene.texts.clone();
-    texts.sort_by(|a, b| a.z_index.cmp(&b.z_index));
+    texts.sort_by_key(|a| a.z_index);
     for text in texts {
         draw_text(frame, width, height, text);
     }
@@ -369,9 +350,11 @@ fn draw_shape(frame: &mut [u8], width: u32, height: u32, draw: crate::scene::Dra
         height: rect_height,
         ..
     } = draw.rect;
-    let resolved_shape = draw
+    let radii = draw
         .shape
-        .map(|shape| shape.resolve(rect_width, rect_height));
+        .map(|shape| shape.resolve(rect_width, rect_height))
+        .unwrap_or_default();
+    let stroke_width = draw.stroke_width.filter(|width| *width > 0.0);
     for py in clip_bounds.min_y..clip_bounds.max_y {
         if py < 0 || py >= height as i32 {
             continue;
@@ -382,13 +365,17 @@ fn draw_shape(frame: &mut [u8], width: u32, height: u32, draw: crate::scene::Dra
             }
             let center_x = px as f32 + 0.5;
             let center_y = py as f32 + 0.5;
-            if let Some(ref radii) = resolved_shape {
-                if !point_in_resolved_rounded_rect(center_x, center_y, draw.rect, radii) {
-                    continue;
-                }
+            let distance = rounded_rect_distance(center_x, center_y, draw.rect, &radii);
+            let mut coverage = edge_coverage(distance);
+            // Strokes keep only the band within `stroke_width` of the edge.
+            if let Some(stroke) = stroke_width {
+                coverage *= 1.0 - edge_coverage(distance + stroke);
+            }
+            if coverage < 0.001 {
+                continue;
             }
             let sample = sample_brush(&draw.brush, draw.rect, center_x, center_y);
-            let alpha = sample[3];
+            let alpha = sample[3] * coverage;
             if alpha <= 0.0 {
                 continue;
             }
@@ -410,6 +397,31 @@ fn draw_shape(frame: &mut [u8], width: u32, height: u32, draw: crate::scene::Dra
     }
 }
 
+/// Signed distance from `(x, y)` to the outline of `rect` with corner `radii`,
+/// negative inside. Mirrors `sdf_rounded_rect` in the wgpu shader.
+fn rounded_rect_distance(x: f32, y: f32, rect: Rect, radii: &CornerRadii) -> f32 {
+    let half_width = rect.width * 0.5;
+    let half_height = rect.height * 0.5;
+    let local_x = x - (rect.x + half_width);
+    let local_y = y - (rect.y + half_height);
+    let radius = match (local_x > 0.0, local_y > 0.0) {
+        (false, false) => radii.top_left,
+        (true, false) => radii.top_right,
+        (true, true) => radii.bottom_right,
+        (false, true) => radii.bottom_left,
+    };
+    let qx = local_x.abs() - half_width + radius;
+    let qy = local_y.abs() - half_height + radius;
+    qx.max(qy).min(0.0) + qx.max(0.0).hypot(qy.max(0.0)) - radius
+}
+
+/// How much of a pixel whose centre lies `distance` from an edge is covered,
+/// using the same one-pixel ramp as the wgpu backend's anti-aliasing.
+fn edge_coverage(distance: f32) -> f32 {
+    let t = (distance + 0.5).clamp(0.0, 1.0);
+    1.0 - t * t * (3.0 - 2.0 * t)
+}
+
 fn draw_text(frame: &mut [u8], width: u32, height: u32, draw: TextDraw) {
     let color = color_to_rgba(draw.color);
     let text_scale = draw.scale.max(0.0);
@@ -422,10 +434,10 @@ fn draw_text(frame: &mut [u8], width: u32, height: u32, draw: TextDraw) {
     }
     let clip_limits =
         clip_bounds.map(|bounds| (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y));
-    let scale = Scale::uniform(TEXT_SIZE * text_scale);
     let font = &*FONT;
-    let v_metrics = font.v_metrics(scale);
-    let offset = point(draw.rect.x, draw.rect.y + v_metrics.ascent);
+    let font_size = BASE_FONT_SIZE * text_scale;
+    let scale = font_scale(font, font_size);
+    let offset = point(draw.rect.x, draw.rect.y + line_baseline(font, font_size));
     for glyph in font.layout(&draw.text, scale, offset) {
         if let Some(bb) = glyph.pixel_bounding_box() {
             if let Some((min_x, min_y, max_x, max_y)) = clip_limits {
@@ -525,3 +537,7 @@ fn lerp_color(a: Color, b: Color, t: f32) -> Color {
         lerp(a.3, b.3),
     )
 }
+
+#[cfg(test)]
+#[path = "tests/draw_tests.rs"]
+mod tests;
@@ -4,10 +4,10 @@ pub mod scene;
 pub mod style;
 
 use cranpose_render_common::{RenderScene, Renderer};
-use cranpose_ui::{set_text_measurer, LayoutTree};
+use cranpose_ui::{set_text_measurer, LayoutOverlay, LayoutTree};
 use cranpose_ui_graphics::Size;
 
-pub use draw::draw_scene;
+pub use draw::{draw_scene, CachedRusttypeTextMeasurer};
 pub use scene::{HitRegion, Scene};
 
 #[derive(Debug)]
@@ -17,6 +17,8 @@ pub enum PixelsRendererError {
 
 pub struct PixelsRenderer {
     scene: Scene,
+    /// Device pixels per logical pixel, applied in `rebuild_scene`
+    root_scale: f32,
 }
 
 impl Default for PixelsRenderer {
@@ -30,9 +32,16 @@ impl PixelsRenderer {
         set_text_measurer(draw::CachedRusttypeTextMeasurer::new(64));
         Self {
             scene: Scene::new(),
+            root_scale: 1.0,
         }
     }
 
+    /// Set the device pixels per logical pixel applied when the scene is
+    /// rebuilt, so logical layouts fill a HiDPI frame.
+    pub fn set_root_scale(&mut self, scale: f32) {
+        self.root_scale = scale;
+    }
+
     pub fn draw(&self, frame: &mut [u8], width: u32, height: u32) {
         draw::draw_scene(frame, width, height, &self.scene);
     }
@@ -53,10 +62,17 @@ impl Renderer for PixelsRenderer {
     fn rebuild_scene(
         &mut self,
         layout_tree: &LayoutTree,
+        overlay: &LayoutOverlay,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         self.scene.clear();
         pipeline::render_layout_tree(layout_tree.root(), &mut self.scene);
+        for layer in overlay.layers() {
+            pipeline::render_layout_tree(layer, &mut self.scene);
+        }
+        if self.root_scale != 1.0 {
+            self.scene.scale_draws(self.root_scale);
+        }
         Ok(())
     }
 }
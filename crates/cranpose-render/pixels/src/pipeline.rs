@@ -1,13 +1,13 @@
 use std::rc::Rc;
 
 use cranpose_render_common::Brush;
-use cranpose_ui::{measure_text, LayoutBox, LayoutNodeKind};
+use cranpose_ui::{measure_styled_text, LayoutBox, LayoutNodeKind};
 use cranpose_ui_graphics::{Color, GraphicsLayer, Rect, RoundedCornerShape, Size};
 
 use crate::scene::{ClickAction, Scene};
 use crate::style::{
     apply_draw_commands, apply_layer_to_brush, apply_layer_to_color, apply_layer_to_rect,
-    combine_layers, scale_corner_radii, DrawPlacement, NodeStyle,
+    combine_layers, layer_about, scale_corner_radii, DrawPlacement, NodeStyle,
 };
 
 pub(crate) fn render_layout_tree(root: &LayoutBox, scene: &mut Scene) {
@@ -63,13 +63,14 @@ fn render_container(
     mut extra_clicks: Vec<ClickAction>,
 ) {
     let style = NodeStyle::from_layout_node(&layout.node_data);
-    let node_layer = combine_layers(parent_layer, style.graphics_layer);
     let rect = layout.rect;
     let size = Size {
         width: rect.width,
         height: rect.height,
     };
     let origin = (rect.x, rect.y);
+    let accumulated_layer = combine_layers(parent_layer, style.graphics_layer, origin);
+    let node_layer = layer_about(accumulated_layer, origin);
     let transformed_rect = apply_layer_to_rect(rect, origin, node_layer);
 
     if transformed_rect.width <= 0.0 || transformed_rect.height <= 0.0 {
@@ -104,6 +105,7 @@ fn render_container(
         size,
         node_layer,
         visual_clip,
+        layout.node_id,
         scene,
     );
 
@@ -114,13 +116,21 @@ fn render_container(
 
     if let Some(color) = style.background {
         let brush = apply_layer_to_brush(Brush::solid(color), node_layer);
-        scene.push_shape(transformed_rect, brush, scaled_shape, visual_clip);
+        scene.push_shape(
+            Some(layout.node_id),
+            transformed_rect,
+            brush,
+            scaled_shape,
+            visual_clip,
+        );
     }
 
     // Render text content if present in modifier slices.
     // Text is now handled via TextModifierNode in the modifier chain.
-    if let Some(value) = layout.node_data.modifier_slices().text_content() {
-        let metrics = measure_text(value);
+    let slices = layout.node_data.modifier_slices();
+    if let Some(value) = slices.text_content() {
+        let text_style = slices.text_style().cloned().unwrap_or_default();
+        let metrics = measure_styled_text(value, &text_style);
         let padding = style.padding;
         let text_rect = Rect {
             x: rect.x + padding.left,
@@ -130,10 +140,14 @@ fn render_container(
         };
         let transformed_text_rect = apply_layer_to_rect(text_rect, origin, node_layer);
         scene.push_text(
+            Some(layout.node_id),
             transformed_text_rect,
             value.to_string(),
-            apply_layer_to_color(Color(1.0, 1.0, 1.0, 1.0), node_layer),
-            node_layer.scale,
+            apply_layer_to_color(
+                text_style.color.unwrap_or(Color(1.0, 1.0, 1.0, 1.0)),
+                node_layer,
+            ),
+            node_layer.scale * text_style.font_scale(),
             visual_clip,
         );
     }
@@ -146,13 +160,21 @@ fn render_container(
         layout.node_id,
         transformed_rect,
         scaled_shape,
+        node_layer.scale,
         extra_clicks,
         style.pointer_inputs.clone(),
         hit_clip,
+        style.minimum_touch_target,
     );
 
     for child_layout in &layout.children {
-        render_layout_node(child_layout, node_layer, scene, visual_clip, hit_clip);
+        render_layout_node(
+            child_layout,
+            accumulated_layer,
+            scene,
+            visual_clip,
+            hit_clip,
+        );
     }
 
     apply_draw_commands(
@@ -163,6 +185,7 @@ fn render_container(
         size,
         node_layer,
         visual_clip,
+        layout.node_id,
         scene,
     );
 }
@@ -221,3 +244,7 @@ fn intersect_rect(a: Rect, b: Rect) -> Option<Rect> {
         })
     }
 }
+
+#[cfg(test)]
+#[path = "tests/pipeline_tests.rs"]
+mod tests;
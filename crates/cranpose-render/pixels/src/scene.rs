@@ -4,27 +4,13 @@ use std::rc::Rc;
 
 use cranpose_core::{run_in_mutable_snapshot, NodeId};
 use cranpose_foundation::{PointerEvent, PointerEventKind};
-use cranpose_render_common::{HitTestTarget, RenderScene};
-use cranpose_ui_graphics::{Brush, Color, Rect, RoundedCornerShape};
+use cranpose_render_common::{
+    hit_test_regions, scale_draws, touch_target_bounds, HitRegionGeometry, HitTestTarget,
+    RenderScene, SceneSnapshot,
+};
+use cranpose_ui_graphics::{Brush, Color, Rect, RoundedCornerShape, Size};
 
-#[derive(Clone)]
-pub struct DrawShape {
-    pub rect: Rect,
-    pub brush: Brush,
-    pub shape: Option<RoundedCornerShape>,
-    pub z_index: usize,
-    pub clip: Option<Rect>,
-}
-
-#[derive(Clone)]
-pub struct TextDraw {
-    pub rect: Rect,
-    pub text: String,
-    pub color: Color,
-    pub scale: f32,
-    pub z_index: usize,
-    pub clip: Option<Rect>,
-}
+pub use cranpose_render_common::{DrawShape, TextDraw};
 
 #[derive(Clone)]
 pub enum ClickAction {
@@ -33,13 +19,10 @@ pub enum ClickAction {
 }
 
 impl ClickAction {
-    fn invoke(&self, rect: Rect, x: f32, y: f32) {
+    fn invoke(&self, local: cranpose_ui_graphics::Point) {
         match self {
             ClickAction::Simple(handler) => (handler.borrow_mut())(),
-            ClickAction::WithPoint(handler) => handler(cranpose_ui_graphics::Point {
-                x: x - rect.x,
-                y: y - rect.y,
-            }),
+            ClickAction::WithPoint(handler) => handler(local),
         }
     }
 }
@@ -49,10 +32,15 @@ pub struct HitRegion {
     pub node_id: NodeId,
     pub rect: Rect,
     pub shape: Option<RoundedCornerShape>,
+    /// Accumulated graphics layer scale; maps screen offsets back to the
+    /// node's own coordinates.
+    pub layer_scale: f32,
     pub click_actions: Vec<ClickAction>,
     pub pointer_inputs: Vec<Rc<dyn Fn(PointerEvent)>>,
     pub z_index: usize,
     pub hit_clip: Option<Rect>,
+    /// Pointer bounds grown past `rect` by `Modifier::minimum_touch_target`.
+    pub touch_bounds: Option<Rect>,
 }
 
 impl HitTestTarget for HitRegion {
@@ -65,10 +53,7 @@ impl HitTestTarget for HitRegion {
         let y = event.global_position.y;
         let kind = event.kind;
 
-        let local = cranpose_ui_graphics::Point {
-            x: x - self.rect.x,
-            y: y - self.rect.y,
-        };
+        let local = self.to_local(x, y);
 
         let local_event = event.copy_with_local_position(local);
 
@@ -91,7 +76,7 @@ impl HitTestTarget for HitRegion {
             // Only perform click actions if NOT consumed
             if kind == PointerEventKind::Down && !local_event.is_consumed() {
                 for action in &self.click_actions {
-                    action.invoke(self.rect, x, y);
+                    action.invoke(local);
                 }
             }
         }) {
@@ -108,6 +93,14 @@ impl HitTestTarget for HitRegion {
 }
 
 impl HitRegion {
+    /// Maps a screen position to the node's untransformed coordinates.
+    pub fn to_local(&self, x: f32, y: f32) -> cranpose_ui_graphics::Point {
+        cranpose_ui_graphics::Point {
+            x: (x - self.rect.x) / self.layer_scale,
+            y: (y - self.rect.y) / self.layer_scale,
+        }
+    }
+
     pub fn contains(&self, x: f32, y: f32) -> bool {
         if let Some(clip) = self.hit_clip {
             if !clip.contains(x, y) {
@@ -122,6 +115,28 @@ impl HitRegion {
     }
 }
 
+impl HitRegionGeometry for HitRegion {
+    fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    fn z_index(&self) -> usize {
+        self.z_index
+    }
+
+    fn hit_clip(&self) -> Option<Rect> {
+        self.hit_clip
+    }
+
+    fn touch_bounds(&self) -> Option<Rect> {
+        self.touch_bounds
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        HitRegion::contains(self, x, y)
+    }
+}
+
 pub struct Scene {
     pub shapes: Vec<DrawShape>,
     pub texts: Vec<TextDraw>,
@@ -142,8 +157,16 @@ impl Scene {
         }
     }
 
+    /// Convert every draw from logical pixels to device pixels.
+    ///
+    /// Hit regions are left in logical pixels, matching pointer coordinates.
+    pub fn scale_draws(&mut self, scale: f32) {
+        scale_draws(&mut self.shapes, &mut self.texts, scale);
+    }
+
     pub fn push_shape(
         &mut self,
+        node_id: Option<NodeId>,
         rect: Rect,
         brush: Brush,
         shape: Option<RoundedCornerShape>,
@@ -152,9 +175,34 @@ impl Scene {
         let z_index = self.next_z;
         self.next_z += 1;
         self.shapes.push(DrawShape {
+            node_id,
             rect,
             brush,
             shape,
+            stroke_width: None,
+            z_index,
+            clip,
+        });
+    }
+
+    /// Pushes the outline of `shape`, `width` pixels wide, inside `rect`.
+    pub fn push_stroke(
+        &mut self,
+        node_id: Option<NodeId>,
+        rect: Rect,
+        brush: Brush,
+        shape: RoundedCornerShape,
+        width: f32,
+        clip: Option<Rect>,
+    ) {
+        let z_index = self.next_z;
+        self.next_z += 1;
+        self.shapes.push(DrawShape {
+            node_id,
+            rect,
+            brush,
+            shape: Some(shape),
+            stroke_width: Some(width),
             z_index,
             clip,
         });
@@ -162,6 +210,7 @@ impl Scene {
 
     pub fn push_text(
         &mut self,
+        node_id: Option<NodeId>,
         rect: Rect,
         text: String,
         color: Color,
@@ -171,6 +220,7 @@ impl Scene {
         let z_index = self.next_z;
         self.next_z += 1;
         self.texts.push(TextDraw {
+            node_id,
             rect,
             text,
             color,
@@ -180,14 +230,17 @@ impl Scene {
         });
     }
 
+    #[allow(clippy::too_many_arguments)] // Hit regions capture geometry, transform and handlers
     pub fn push_hit(
         &mut self,
         node_id: NodeId,
         rect: Rect,
         shape: Option<RoundedCornerShape>,
+        layer_scale: f32,
         click_actions: Vec<ClickAction>,
         pointer_inputs: Vec<Rc<dyn Fn(PointerEvent)>>,
         hit_clip: Option<Rect>,
+        minimum_touch_target: Option<Size>,
     ) {
         if click_actions.is_empty() && pointer_inputs.is_empty() {
             return;
@@ -198,10 +251,12 @@ impl Scene {
             node_id,
             rect,
             shape,
+            layer_scale,
             click_actions,
             pointer_inputs,
             z_index,
             hit_clip,
+            touch_bounds: minimum_touch_target.and_then(|size| touch_target_bounds(rect, size)),
         };
         // Populate both the list and the index for O(1) lookup
         self.node_index.insert(node_id, hit_region.clone());
@@ -227,20 +282,15 @@ impl RenderScene for Scene {
     }
 
     fn hit_test(&self, x: f32, y: f32) -> Vec<Self::HitTarget> {
-        let mut hits: Vec<_> = self
-            .hits
-            .iter()
-            .filter(|hit| hit.contains(x, y))
-            .cloned()
-            .collect();
-
-        // Sort by z-index descending (top to bottom)
-        hits.sort_by(|a, b| b.z_index.cmp(&a.z_index));
-        hits
+        hit_test_regions(&self.hits, x, y)
     }
 
     fn find_target(&self, node_id: NodeId) -> Option<Self::HitTarget> {
         // O(1) lookup using the node index
         self.node_index.get(&node_id).cloned()
     }
+
+    fn snapshot(&self) -> SceneSnapshot {
+        SceneSnapshot::from_draws(&self.shapes, &self.texts, &self.hits)
+    }
 }
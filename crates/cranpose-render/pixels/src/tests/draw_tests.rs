@@ -0,0 +1,143 @@
+use super::*;
+use crate::style::{apply_draw_commands, DrawPlacement};
+use cranpose_ui::DrawCommand;
+use cranpose_ui_graphics::{DrawScope, DrawScopeDefault, GraphicsLayer, Size};
+use std::rc::Rc;
+
+const WIDTH: u32 = 64;
+const HEIGHT: u32 = 64;
+const BACKGROUND: [u8; 4] = [18, 18, 24, 255];
+const RED: [u8; 4] = [255, 0, 0, 255];
+
+/// Renders a draw-behind command over a 40x30 node at (10, 10).
+fn render_behind(draw: impl Fn(&mut DrawScopeDefault) + 'static) -> Vec<u8> {
+    let command = DrawCommand::Behind(Rc::new(move |size| {
+        let mut scope = DrawScopeDefault::new(size);
+        draw(&mut scope);
+        scope.into_primitives()
+    }));
+    let rect = Rect {
+        x: 10.0,
+        y: 10.0,
+        width: 40.0,
+        height: 30.0,
+    };
+    let mut scene = Scene::new();
+    apply_draw_commands(
+        &[command],
+        DrawPlacement::Behind,
+        rect,
+        (0.0, 0.0),
+        Size {
+            width: rect.width,
+            height: rect.height,
+        },
+        GraphicsLayer::default(),
+        None,
+        0,
+        &mut scene,
+    );
+    let mut frame = vec![0; (WIDTH * HEIGHT * 4) as usize];
+    draw_scene(&mut frame, WIDTH, HEIGHT, &scene);
+    frame
+}
+
+fn pixel(frame: &[u8], x: u32, y: u32) -> [u8; 4] {
+    let idx = ((y * WIDTH + x) * 4) as usize;
+    [frame[idx], frame[idx + 1], frame[idx + 2], frame[idx + 3]]
+}
+
+#[test]
+fn stroke_rect_paints_edges_and_leaves_interior() {
+    let frame = render_behind(|scope| scope.stroke_rect(Brush::solid(Color::RED), 4.0));
+
+    // Every edge band is painted...
+    assert_eq!(pixel(&frame, 11, 25), RED);
+    assert_eq!(pixel(&frame, 48, 25), RED);
+    assert_eq!(pixel(&frame, 30, 11), RED);
+    assert_eq!(pixel(&frame, 30, 38), RED);
+    // ...but not the interior or anything outside the node.
+    assert_eq!(pixel(&frame, 15, 25), BACKGROUND);
+    assert_eq!(pixel(&frame, 30, 25), BACKGROUND);
+    assert_eq!(pixel(&frame, 9, 25), BACKGROUND);
+    assert_eq!(pixel(&frame, 51, 25), BACKGROUND);
+}
+
+#[test]
+fn round_rect_stroke_follows_corner_radius() {
+    let frame = render_behind(|scope| {
+        scope.draw_round_rect_stroke(Brush::solid(Color::RED), CornerRadii::uniform(10.0), 2.0)
+    });
+
+    // The rounded corner itself stays clear, the straight edges are painted.
+    assert_eq!(pixel(&frame, 10, 10), BACKGROUND);
+    assert_eq!(pixel(&frame, 10, 25), RED);
+    assert_eq!(pixel(&frame, 30, 10), RED);
+    assert_eq!(pixel(&frame, 30, 25), BACKGROUND);
+}
+
+#[test]
+fn stroke_wider_than_shape_fills_it() {
+    let frame = render_behind(|scope| scope.stroke_rect(Brush::solid(Color::RED), 100.0));
+
+    assert_eq!(pixel(&frame, 30, 25), RED);
+    assert_eq!(pixel(&frame, 49, 39), RED);
+    assert_eq!(pixel(&frame, 50, 25), BACKGROUND);
+}
+
+#[test]
+fn rounded_gradient_fill_antialiases_corners_and_interpolates_colors() {
+    let frame = render_behind(|scope| {
+        scope.draw_round_rect(
+            Brush::linear_gradient(vec![Color::RED, Color::BLUE]),
+            CornerRadii::uniform(10.0),
+        )
+    });
+
+    // Outside the arc the background shows through untouched.
+    assert_eq!(pixel(&frame, 10, 10), BACKGROUND);
+    // A pixel straddling the arc is only partially covered.
+    let edge = pixel(&frame, 11, 14);
+    assert!(
+        edge[0] > BACKGROUND[0] && edge[0] < 200,
+        "corner edge should blend with the background, got {edge:?}"
+    );
+    // The top edge starts red and the middle sits halfway to blue.
+    let top = pixel(&frame, 30, 10);
+    assert!(top[0] > 240 && top[2] < 15, "top edge {top:?}");
+    let center = pixel(&frame, 29, 24);
+    assert!(
+        (115..=145).contains(&center[0]) && (110..=140).contains(&center[2]),
+        "center {center:?}"
+    );
+}
+
+#[test]
+fn subpixel_edges_and_tiny_radii_get_partial_coverage() {
+    let frame = render_behind(|scope| {
+        scope.draw_rect_at(
+            Rect {
+                x: 0.5,
+                y: 0.0,
+                width: 10.0,
+                height: 10.0,
+            },
+            Brush::solid(Color::RED),
+        )
+    });
+    // The node-local rect starts half way into column 10.
+    let half = pixel(&frame, 10, 15);
+    assert!((120..=145).contains(&half[0]), "half covered {half:?}");
+    assert_eq!(pixel(&frame, 11, 15), RED);
+
+    let frame = render_behind(|scope| {
+        scope.draw_round_rect(Brush::solid(Color::RED), CornerRadii::uniform(0.75))
+    });
+    // A sub-pixel radius only softens the very corner pixel.
+    let corner = pixel(&frame, 49, 39);
+    assert!(
+        corner[0] < 255 && corner[0] > BACKGROUND[0],
+        "corner {corner:?}"
+    );
+    assert_eq!(pixel(&frame, 48, 39), RED);
+}
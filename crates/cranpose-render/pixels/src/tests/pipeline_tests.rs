@@ -0,0 +1,270 @@
+use super::*;
+use cranpose_foundation::{PointerEvent, PointerEventKind};
+use cranpose_render_common::test_support::{layout_box, rect};
+use cranpose_render_common::{HitTestTarget, RenderScene};
+use cranpose_ui::{Modifier, Point};
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn scaled(scale: f32) -> GraphicsLayer {
+    GraphicsLayer {
+        scale,
+        ..GraphicsLayer::default()
+    }
+}
+
+fn scene_for(root: &LayoutBox) -> Scene {
+    let mut scene = Scene::new();
+    render_layout_tree(root, &mut scene);
+    scene
+}
+
+#[test]
+fn clicks_outside_a_clipped_parent_miss_the_overflowing_child() {
+    // A 100px wide clickable child overflows its 50px clipping parent.
+    let child = layout_box(
+        2,
+        rect(0.0, 0.0, 100.0, 20.0),
+        Modifier::empty().clickable(|_| {}),
+        vec![],
+    );
+    let root = layout_box(
+        1,
+        rect(0.0, 0.0, 50.0, 20.0),
+        Modifier::empty().clip_to_bounds(),
+        vec![child],
+    );
+    let scene = scene_for(&root);
+
+    assert_eq!(scene.hit_test_nodes(49.0, 10.0), vec![2]);
+    assert!(scene.hit_test_nodes(51.0, 10.0).is_empty());
+}
+
+#[test]
+fn offset_content_is_clipped_only_by_a_clipping_ancestor() {
+    // A badge offset past the top-left corner of its parent.
+    let badge = |id| {
+        layout_box(
+            id,
+            rect(-10.0, -10.0, 20.0, 20.0),
+            Modifier::empty().background(Color(1.0, 0.0, 0.0, 1.0)),
+            vec![],
+        )
+    };
+    let free = layout_box(
+        2,
+        rect(0.0, 0.0, 50.0, 50.0),
+        Modifier::empty(),
+        vec![badge(3)],
+    );
+    let scene = scene_for(&free);
+    assert_eq!(scene.shapes.len(), 1);
+    assert_eq!(scene.shapes[0].clip, None);
+
+    let clipped = layout_box(
+        2,
+        rect(0.0, 0.0, 50.0, 50.0),
+        Modifier::empty().clip_to_bounds(),
+        vec![badge(3)],
+    );
+    let scene = scene_for(&clipped);
+    assert_eq!(scene.shapes.len(), 1);
+    assert_eq!(scene.shapes[0].clip, Some(rect(0.0, 0.0, 50.0, 50.0)));
+}
+
+#[test]
+fn nested_layers_compose_for_hit_testing() {
+    // The outer layer doubles everything about (0, 0); the child then halves
+    // itself about its own origin, landing at (20, 20) with its layout size.
+    let child = layout_box(
+        3,
+        rect(10.0, 10.0, 10.0, 10.0),
+        Modifier::empty()
+            .graphics_layer(scaled(0.5))
+            .clickable(|_| {}),
+        vec![],
+    );
+    let parent = layout_box(
+        2,
+        rect(0.0, 0.0, 40.0, 40.0),
+        Modifier::empty().graphics_layer(scaled(2.0)),
+        vec![child],
+    );
+    let root = layout_box(
+        1,
+        rect(0.0, 0.0, 100.0, 100.0),
+        Modifier::empty(),
+        vec![parent],
+    );
+    let scene = scene_for(&root);
+
+    assert_eq!(scene.hit_test_nodes(21.0, 21.0), vec![3]);
+    assert_eq!(scene.hit_test_nodes(29.0, 29.0), vec![3]);
+    assert!(scene.hit_test_nodes(15.0, 15.0).is_empty());
+    assert!(scene.hit_test_nodes(31.0, 31.0).is_empty());
+}
+
+#[test]
+fn scaled_targets_receive_untransformed_local_positions() {
+    let clicked = Rc::new(Cell::new(None));
+    let child = layout_box(
+        2,
+        rect(10.0, 10.0, 20.0, 20.0),
+        Modifier::empty().graphics_layer(scaled(2.0)).clickable({
+            let clicked = Rc::clone(&clicked);
+            move |point| clicked.set(Some((point.x, point.y)))
+        }),
+        vec![],
+    );
+    let root = layout_box(
+        1,
+        rect(0.0, 0.0, 100.0, 100.0),
+        Modifier::empty(),
+        vec![child],
+    );
+    let scene = scene_for(&root);
+
+    let position = Point { x: 40.0, y: 30.0 };
+    let target = scene.hit_test(position.x, position.y).remove(0);
+    for kind in [PointerEventKind::Down, PointerEventKind::Up] {
+        target.dispatch(PointerEvent::new(kind, position, position));
+    }
+
+    assert_eq!(clicked.get(), Some((15.0, 10.0)));
+}
+
+#[test]
+fn scene_snapshot_serializes_shapes_and_hit_regions_with_their_nodes() {
+    let child = layout_box(
+        2,
+        rect(10.0, 0.0, 40.0, 20.0),
+        Modifier::empty()
+            .background(Color(1.0, 0.0, 0.0, 1.0))
+            .rounded_corners(4.0)
+            .clickable(|_| {}),
+        vec![],
+    );
+    let root = layout_box(
+        1,
+        rect(0.0, 0.0, 100.0, 20.0),
+        Modifier::empty(),
+        vec![child],
+    );
+    let json = scene_for(&root).snapshot().to_json();
+
+    assert!(
+        json.contains(
+            r#"{"node":2,"rect":[10,0,40,20],"brush":{"solid":[1,0,0,1]},"radii":[4,4,4,4],"stroke":null}"#
+        ),
+        "{json}"
+    );
+    assert!(
+        json.contains(r#""hits":[{"node":2,"rect":[10,0,40,20]}]"#),
+        "{json}"
+    );
+    assert!(json.ends_with(r#""omitted":0}"#), "{json}");
+}
+
+#[test]
+fn scene_snapshot_records_the_node_that_drew_text() {
+    let mut scene = Scene::new();
+    scene.push_text(
+        Some(4),
+        rect(0.0, 0.0, 40.0, 20.0),
+        "Hi".to_string(),
+        Color(0.0, 0.0, 0.0, 1.0),
+        1.0,
+        None,
+    );
+
+    assert_eq!(
+        scene.snapshot().to_json(),
+        r#"{"shapes":[],"texts":[{"node":4,"rect":[0,0,40,20],"text":"Hi","color":[0,0,0,1]}],"hits":[],"omitted":0}"#
+    );
+}
+
+#[test]
+fn truncated_snapshot_counts_what_it_dropped() {
+    let children = (2..7)
+        .map(|id| {
+            layout_box(
+                id,
+                rect(0.0, 0.0, 10.0, 10.0),
+                Modifier::empty().background(Color(0.0, 0.0, 1.0, 1.0)),
+                vec![],
+            )
+        })
+        .collect();
+    let root = layout_box(1, rect(0.0, 0.0, 10.0, 10.0), Modifier::empty(), children);
+    let mut snapshot = scene_for(&root).snapshot();
+
+    snapshot.truncate(2);
+    assert_eq!(snapshot.shapes.len(), 2);
+    assert_eq!(snapshot.omitted, 3);
+    assert!(snapshot.to_json().ends_with(r#""omitted":3}"#));
+}
+
+#[test]
+fn recorded_scene_shares_the_snapshot_format() {
+    let root = layout_box(
+        1,
+        rect(0.0, 0.0, 30.0, 10.0),
+        Modifier::empty().background(Color(0.0, 1.0, 0.0, 1.0)),
+        vec![],
+    );
+    let recorded = cranpose_ui::HeadlessRenderer::new().render(&cranpose_ui::LayoutTree::new(root));
+    let json = cranpose_render_common::SceneSnapshot::from(&recorded).to_json();
+
+    assert_eq!(
+        json,
+        r#"{"shapes":[{"node":1,"rect":[0,0,30,10],"brush":{"solid":[0,1,0,1]},"radii":null,"stroke":null}],"texts":[],"hits":[],"omitted":0}"#
+    );
+}
+
+#[test]
+fn minimum_touch_target_expands_hits_and_nearest_target_wins() {
+    let target = Size {
+        width: 48.0,
+        height: 48.0,
+    };
+    let small_button = |id, x| {
+        layout_box(
+            id,
+            rect(x, 100.0, 10.0, 10.0),
+            Modifier::empty()
+                .clickable(|_| {})
+                .minimum_touch_target(target),
+            vec![],
+        )
+    };
+    let root = layout_box(
+        1,
+        rect(0.0, 0.0, 300.0, 300.0),
+        Modifier::empty(),
+        vec![small_button(2, 100.0), small_button(3, 120.0)],
+    );
+    let scene = scene_for(&root);
+
+    // The visual bounds are untouched; the 48px target reaches 19px past them.
+    assert_eq!(scene.hits[0].rect, rect(100.0, 100.0, 10.0, 10.0));
+    assert_eq!(scene.hit_test_nodes(85.0, 105.0), vec![2]);
+    assert_eq!(scene.hit_test_nodes(105.0, 125.0), vec![2]);
+    assert!(scene.hit_test_nodes(80.0, 105.0).is_empty());
+
+    // Both expanded targets cover the gap; the closer button takes the tap.
+    assert_eq!(scene.hit_test_nodes(112.0, 105.0), vec![2]);
+    assert_eq!(scene.hit_test_nodes(117.0, 105.0), vec![3]);
+    // A direct hit on a button still goes to that button alone.
+    assert_eq!(scene.hit_test_nodes(121.0, 105.0), vec![3]);
+
+    // A clickable container around the button does not swallow its target.
+    let card = layout_box(
+        1,
+        rect(0.0, 0.0, 300.0, 300.0),
+        Modifier::empty().clickable(|_| {}),
+        vec![small_button(2, 100.0)],
+    );
+    let scene = scene_for(&card);
+    assert_eq!(scene.hit_test_nodes(90.0, 105.0), vec![2, 1]);
+    assert_eq!(scene.hit_test_nodes(60.0, 105.0), vec![1]);
+}
@@ -7,19 +7,24 @@ mod pipeline;
 mod render;
 mod scene;
 mod shaders;
+mod surface;
 
 pub use scene::{ClickAction, DrawShape, HitRegion, Scene, TextDraw};
 
-use cranpose_render_common::{RenderScene, Renderer};
-use cranpose_ui::{set_text_measurer, LayoutTree, TextMeasurer};
+pub(crate) use cranpose_render_common::BASE_FONT_SIZE;
+use cranpose_render_common::{scale_rect, text_line_height, RenderScene, Renderer};
+use cranpose_ui::{
+    is_unbounded_width, measure_wrapped, set_text_measurer, text_width_bucket, LayoutOverlay,
+    LayoutTree, TextMeasurer,
+};
 use cranpose_ui_graphics::Size;
-use glyphon::{Attrs, Buffer, FontSystem, Metrics, Shaping};
+use glyphon::{fontdb, Attrs, Buffer, FontSystem, Metrics, Shaping};
 use lru::LruCache;
 use render::GpuRenderer;
-use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
+use surface::SurfaceSettings;
 
 #[derive(Debug)]
 pub enum WgpuRendererError {
@@ -86,7 +91,7 @@ impl SharedTextBuffer {
         }
 
         // Set metrics and size for unlimited layout
-        let metrics = Metrics::new(font_size, font_size * 1.4);
+        let metrics = Metrics::new(font_size, text_line_height(font_size));
         self.buffer.set_metrics(font_system, metrics);
         self.buffer
             .set_size(font_system, Some(f32::MAX), Some(f32::MAX));
@@ -115,7 +120,7 @@ impl SharedTextBuffer {
         for run in layout_runs {
             max_width = max_width.max(run.line_w);
         }
-        let total_height = self.buffer.lines.len() as f32 * font_size * 1.4;
+        let total_height = self.buffer.lines.len() as f32 * text_line_height(font_size);
 
         let size = Size {
             width: max_width,
@@ -127,33 +132,104 @@ impl SharedTextBuffer {
     }
 }
 
-/// Shared cache for text buffers used by both measurement and rendering
-pub(crate) type SharedTextCache = Arc<Mutex<HashMap<TextCacheKey, SharedTextBuffer>>>;
+/// Text buffers shared by measurement and rendering, evicted least recently
+/// used first.
+///
+/// Entries are only evicted by [`TextCache::trim`], never on insertion, so
+/// every buffer prepared for a frame stays available until the frame has been
+/// drawn even if the frame shows more texts than the cache holds.
+pub(crate) struct TextCache {
+    entries: LruCache<TextCacheKey, SharedTextBuffer>,
+    capacity: NonZeroUsize,
+}
+
+impl TextCache {
+    fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            capacity,
+        }
+    }
 
-/// Trim text cache if it exceeds MAX_CACHE_ITEMS.
-/// Removes the oldest half of entries when limit is reached.
-fn trim_text_cache(cache: &mut HashMap<TextCacheKey, SharedTextBuffer>) {
-    if cache.len() > MAX_CACHE_ITEMS {
-        let target_size = MAX_CACHE_ITEMS / 2;
-        let to_remove = cache.len() - target_size;
+    /// Returns the buffer for `key`, marking it as most recently used and
+    /// creating an empty one if needed.
+    pub(crate) fn get_or_create(
+        &mut self,
+        key: TextCacheKey,
+        font_system: &mut FontSystem,
+        font_size: f32,
+    ) -> &mut SharedTextBuffer {
+        self.entries.get_or_insert_mut(key, || SharedTextBuffer {
+            buffer: Buffer::new(
+                font_system,
+                Metrics::new(font_size, text_line_height(font_size)),
+            ),
+            text: String::new(),
+            font_size: 0.0,
+            cached_size: None,
+        })
+    }
+
+    /// Returns the buffer for `key` without affecting eviction order.
+    pub(crate) fn peek(&self, key: &TextCacheKey) -> Option<&SharedTextBuffer> {
+        self.entries.peek(key)
+    }
 
-        // Remove oldest entries (arbitrary keys from the front)
-        let keys_to_remove: Vec<TextCacheKey> = cache.keys().take(to_remove).cloned().collect();
+    fn set_capacity(&mut self, capacity: NonZeroUsize) {
+        self.capacity = capacity;
+        self.trim();
+    }
 
-        for key in keys_to_remove {
-            cache.remove(&key);
+    /// Evicts least recently used buffers until the cache fits its capacity.
+    pub(crate) fn trim(&mut self) {
+        let before = self.entries.len();
+        while self.entries.len() > self.capacity.get() {
+            self.entries.pop_lru();
+        }
+        if self.entries.len() < before {
+            log::debug!(
+                "Trimmed text cache from {} to {} entries",
+                before,
+                self.entries.len()
+            );
         }
+    }
+}
 
-        log::debug!(
-            "Trimmed text cache from {} to {} entries",
-            cache.len() + to_remove,
-            cache.len()
-        );
+/// Shared cache for text buffers used by both measurement and rendering
+pub(crate) type SharedTextCache = Arc<Mutex<TextCache>>;
+
+fn new_text_cache(capacity: NonZeroUsize) -> SharedTextCache {
+    Arc::new(Mutex::new(TextCache::new(capacity)))
+}
+
+/// Loads application-provided fonts. The first one becomes the default
+/// sans-serif family so system fonts never win over what the app ships.
+fn load_app_fonts(font_system: &mut FontSystem, fonts: &[&[u8]]) {
+    let mut primary_family = None;
+    for (i, font_data) in fonts.iter().enumerate() {
+        log::info!("Loading font #{}, size: {} bytes", i, font_data.len());
+        let ids = font_system
+            .db_mut()
+            .load_font_source(fontdb::Source::Binary(Arc::new(font_data.to_vec())));
+        if primary_family.is_none() {
+            primary_family = ids
+                .first()
+                .and_then(|id| font_system.db().face(*id))
+                .and_then(|face| face.families.first())
+                .map(|(family, _)| family.clone());
+        }
+    }
+    if let Some(family) = primary_family {
+        font_system.db_mut().set_sans_serif_family(family);
     }
 }
 
-/// Maximum number of cached text buffers before trimming occurs
-const MAX_CACHE_ITEMS: usize = 256;
+/// Default number of text buffers kept by [`WgpuRenderer`]'s text cache.
+pub const DEFAULT_TEXT_CACHE_CAPACITY: NonZeroUsize = match NonZeroUsize::new(256) {
+    Some(capacity) => capacity,
+    None => unreachable!(),
+};
 
 /// WGPU-based renderer for GPU-accelerated 2D rendering.
 ///
@@ -168,8 +244,10 @@ pub struct WgpuRenderer {
     font_system: Arc<Mutex<FontSystem>>,
     /// Shared text buffer cache used by both measurement and rendering
     text_cache: SharedTextCache,
-    /// Root scale factor for text rendering (use for density scaling)
+    /// Device pixels per logical pixel, applied in `rebuild_scene`
     root_scale: f32,
+    /// Present mode and frame latency applied when the surface is configured
+    surface_settings: SurfaceSettings,
 }
 
 impl WgpuRenderer {
@@ -198,11 +276,7 @@ impl WgpuRenderer {
             // font_system.db_mut().load_fonts_dir("/system/fonts");  // DISABLED
         }
 
-        // Load application-provided fonts
-        for (i, font_data) in fonts.iter().enumerate() {
-            log::info!("Loading font #{}, size: {} bytes", i, font_data.len());
-            font_system.db_mut().load_font_data(font_data.to_vec());
-        }
+        load_app_fonts(&mut font_system, fonts);
 
         let face_count = font_system.db().faces().count();
         log::info!("Total font faces loaded: {}", face_count);
@@ -214,7 +288,7 @@ impl WgpuRenderer {
         let font_system = Arc::new(Mutex::new(font_system));
 
         // Create shared text cache for both measurement and rendering
-        let text_cache = Arc::new(Mutex::new(HashMap::new()));
+        let text_cache = new_text_cache(DEFAULT_TEXT_CACHE_CAPACITY);
 
         let text_measurer = WgpuTextMeasurer::new(font_system.clone(), text_cache.clone());
         set_text_measurer(text_measurer.clone());
@@ -225,6 +299,7 @@ impl WgpuRenderer {
             font_system,
             text_cache,
             root_scale: 1.0,
+            surface_settings: SurfaceSettings::new(),
         }
     }
 
@@ -235,7 +310,7 @@ impl WgpuRenderer {
     pub fn new() -> Self {
         let font_system = FontSystem::new();
         let font_system = Arc::new(Mutex::new(font_system));
-        let text_cache = Arc::new(Mutex::new(HashMap::new()));
+        let text_cache = new_text_cache(DEFAULT_TEXT_CACHE_CAPACITY);
 
         let text_measurer = WgpuTextMeasurer::new(font_system.clone(), text_cache.clone());
         set_text_measurer(text_measurer.clone());
@@ -246,6 +321,7 @@ impl WgpuRenderer {
             font_system,
             text_cache,
             root_scale: 1.0,
+            surface_settings: SurfaceSettings::new(),
         }
     }
 
@@ -265,7 +341,82 @@ impl WgpuRenderer {
         ));
     }
 
-    /// Set root scale factor for text rendering (e.g., density scaling on Android)
+    /// Set how many shaped text buffers are cached between frames.
+    ///
+    /// The least recently used buffers are evicted first; shrinking the
+    /// capacity evicts immediately.
+    pub fn set_text_cache_capacity(&mut self, capacity: NonZeroUsize) {
+        self.text_cache.lock().unwrap().set_capacity(capacity);
+    }
+
+    /// Request a present mode for the window surface.
+    ///
+    /// Takes effect on the next [`configure_surface`](Self::configure_surface).
+    /// Modes the adapter does not support fall back to `Fifo` with a warning.
+    pub fn set_present_mode(&mut self, mode: wgpu::PresentMode) {
+        self.surface_settings.set_present_mode(mode);
+    }
+
+    /// The requested present mode.
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.surface_settings.present_mode()
+    }
+
+    /// Set how many frames may be queued ahead of presentation.
+    ///
+    /// Lower values reduce input latency at the cost of throughput. Takes
+    /// effect on the next [`configure_surface`](Self::configure_surface).
+    pub fn set_max_frame_latency(&mut self, frames: u32) {
+        self.surface_settings.set_max_frame_latency(frames);
+    }
+
+    /// The requested maximum frame latency.
+    pub fn max_frame_latency(&self) -> u32 {
+        self.surface_settings.max_frame_latency()
+    }
+
+    /// Build the initial configuration for a surface with the given
+    /// capabilities, using the requested present mode and frame latency.
+    pub fn surface_configuration(
+        &mut self,
+        capabilities: &wgpu::SurfaceCapabilities,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> wgpu::SurfaceConfiguration {
+        self.surface_settings
+            .set_supported_present_modes(&capabilities.present_modes);
+        wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width,
+            height,
+            present_mode: self.surface_settings.resolved_present_mode(),
+            alpha_mode: capabilities.alpha_modes[0],
+            view_formats: vec![],
+            desired_maximum_frame_latency: self.surface_settings.max_frame_latency(),
+        }
+    }
+
+    /// Whether the present mode or frame latency changed since the surface
+    /// was last configured.
+    pub fn surface_needs_reconfigure(&self) -> bool {
+        self.surface_settings.needs_reconfigure()
+    }
+
+    /// Apply the requested present mode and frame latency to `config` and
+    /// configure `surface` with it.
+    pub fn configure_surface(
+        &mut self,
+        surface: &wgpu::Surface<'_>,
+        config: &mut wgpu::SurfaceConfiguration,
+    ) {
+        self.surface_settings.apply(config);
+        surface.configure(self.device(), config);
+    }
+
+    /// Set the device pixels per logical pixel applied when the scene is
+    /// rebuilt (e.g., density scaling on Android or HiDPI displays).
     pub fn set_root_scale(&mut self, scale: f32) {
         self.root_scale = scale;
     }
@@ -279,14 +430,7 @@ impl WgpuRenderer {
     ) -> Result<(), WgpuRendererError> {
         if let Some(gpu_renderer) = &mut self.gpu_renderer {
             gpu_renderer
-                .render(
-                    view,
-                    &self.scene.shapes,
-                    &self.scene.texts,
-                    width,
-                    height,
-                    self.root_scale,
-                )
+                .render(view, &self.scene.shapes, &self.scene.texts, width, height)
                 .map_err(WgpuRendererError::Wgpu)
         } else {
             Err(WgpuRendererError::Wgpu(
@@ -325,11 +469,17 @@ impl Renderer for WgpuRenderer {
     fn rebuild_scene(
         &mut self,
         layout_tree: &LayoutTree,
+        overlay: &LayoutOverlay,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         self.scene.clear();
-        // Build scene in logical dp - scaling happens in GPU vertex upload
         pipeline::render_layout_tree(layout_tree.root(), &mut self.scene);
+        for layer in overlay.layers() {
+            pipeline::render_layout_tree(layer, &mut self.scene);
+        }
+        if self.root_scale != 1.0 {
+            self.scene.scale_draws(self.root_scale);
+        }
         Ok(())
     }
 
@@ -344,7 +494,7 @@ impl Renderer for WgpuRenderer {
         // Measure text width (approximate: ~7px per character at 14px font)
         let char_width = 7.0;
         let text_width = text.len() as f32 * char_width;
-        let text_height = font_size * 1.4;
+        let text_height = text_line_height(font_size);
 
         let x = viewport.width - text_width - padding * 2.0;
         let y = padding;
@@ -356,10 +506,13 @@ impl Renderer for WgpuRenderer {
             width: text_width + padding,
             height: text_height + padding / 2.0,
         };
+        // The scene is already in device pixels, so scale the overlay too
+        let scale = self.root_scale;
         self.scene.push_shape(
-            bg_rect,
+            None,
+            scale_rect(bg_rect, scale),
             Brush::Solid(Color(0.0, 0.0, 0.0, 0.7)),
-            Some(RoundedCornerShape::uniform(4.0)),
+            Some(RoundedCornerShape::uniform(4.0 * scale)),
             None,
         );
 
@@ -371,10 +524,11 @@ impl Renderer for WgpuRenderer {
             height: text_height,
         };
         self.scene.push_text(
-            text_rect,
+            None,
+            scale_rect(text_rect, scale),
             text.to_string(),
-            Color(0.0, 1.0, 0.0, 1.0),  // Green
-            font_size / BASE_FONT_SIZE, // Scale relative to base
+            Color(0.0, 1.0, 0.0, 1.0),          // Green
+            font_size / BASE_FONT_SIZE * scale, // Scale relative to base
             None,
         );
     }
@@ -382,46 +536,34 @@ impl Renderer for WgpuRenderer {
 
 // Text measurer implementation for WGPU
 
+/// Text, font size (hundredths) and wrap-width bucket.
+type MetricsKey = (String, i32, u32);
+
 #[derive(Clone)]
 struct WgpuTextMeasurer {
     font_system: Arc<Mutex<FontSystem>>,
-    /// Size-only cache for ultra-fast lookups
-    size_cache: Arc<Mutex<LruCache<(String, i32), Size>>>,
+    /// Metrics cache keyed by text, font size and wrap-width bucket
+    metrics_cache: Arc<Mutex<LruCache<MetricsKey, cranpose_ui::TextMetrics>>>,
     /// Shared buffer cache used by both measurement and rendering
     text_cache: SharedTextCache,
+    /// Baseline of a line at the base font size, from the font's ascent
+    base_baseline: Arc<OnceLock<f32>>,
 }
 
 impl WgpuTextMeasurer {
     fn new(font_system: Arc<Mutex<FontSystem>>, text_cache: SharedTextCache) -> Self {
         Self {
             font_system,
-            size_cache: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(64).unwrap()))),
+            metrics_cache: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(64).unwrap()))),
             text_cache,
+            base_baseline: Arc::new(OnceLock::new()),
         }
     }
 }
 
-// Base font size in logical units (dp) - shared between measurement and rendering
-pub(crate) const BASE_FONT_SIZE: f32 = 14.0;
-
-impl TextMeasurer for WgpuTextMeasurer {
-    fn measure(&self, text: &str) -> cranpose_ui::TextMetrics {
-        let size_key = (text.to_string(), (BASE_FONT_SIZE * 100.0) as i32);
-
-        // Check size cache first (fastest path)
-        {
-            let mut cache = self.size_cache.lock().unwrap();
-            if let Some(size) = cache.get(&size_key) {
-                // For cached single-line text, use height as line_height
-                return cranpose_ui::TextMetrics {
-                    width: size.width,
-                    height: size.height,
-                    line_height: BASE_FONT_SIZE * 1.4,
-                    line_count: 1, // Cached entries are single-line simplified
-                };
-            }
-        }
-
+impl WgpuTextMeasurer {
+    /// Measures `text` with its buffer, one line per paragraph.
+    fn measure_unbounded(&self, text: &str) -> cranpose_ui::TextMetrics {
         // Get or create text buffer
         let cache_key = TextCacheKey::new(text, BASE_FONT_SIZE);
         let mut font_system = self.font_system.lock().unwrap();
@@ -429,18 +571,7 @@ impl TextMeasurer for WgpuTextMeasurer {
 
         // Get or create buffer and calculate size
         let size = {
-            let buffer = text_cache.entry(cache_key).or_insert_with(|| {
-                let buffer = Buffer::new(
-                    &mut font_system,
-                    Metrics::new(BASE_FONT_SIZE, BASE_FONT_SIZE * 1.4),
-                );
-                SharedTextBuffer {
-                    buffer,
-                    text: String::new(),
-                    font_size: 0.0,
-                    cached_size: None,
-                }
-            });
+            let buffer = text_cache.get_or_create(cache_key, &mut font_system, BASE_FONT_SIZE);
 
             // Ensure buffer has the correct text
             buffer.ensure(&mut font_system, text, BASE_FONT_SIZE, Attrs::new());
@@ -450,17 +581,13 @@ impl TextMeasurer for WgpuTextMeasurer {
         };
 
         // Trim cache if needed (after we're done with buffer reference)
-        trim_text_cache(&mut text_cache);
+        text_cache.trim();
 
         drop(font_system);
         drop(text_cache);
 
-        // Cache the size result
-        let mut size_cache = self.size_cache.lock().unwrap();
-        size_cache.put(size_key, size);
-
         // Calculate line info for multiline support
-        let line_height = BASE_FONT_SIZE * 1.4;
+        let line_height = text_line_height(BASE_FONT_SIZE);
         let line_count = text.split('\n').count().max(1);
 
         cranpose_ui::TextMetrics {
@@ -471,12 +598,94 @@ impl TextMeasurer for WgpuTextMeasurer {
         }
     }
 
+    /// Distance from the top of a line to its baseline at the base font size,
+    /// as glyphon lays text out: the font's ascent below the centred glyphs.
+    fn base_baseline(&self) -> f32 {
+        *self.base_baseline.get_or_init(|| {
+            let line_height = text_line_height(BASE_FONT_SIZE);
+            let mut font_system = self.font_system.lock().unwrap();
+            let mut buffer =
+                Buffer::new(&mut font_system, Metrics::new(BASE_FONT_SIZE, line_height));
+            buffer.set_size(&mut font_system, Some(f32::MAX), Some(f32::MAX));
+            buffer.set_text(&mut font_system, "M", &Attrs::new(), Shaping::Advanced);
+            buffer.shape_until_scroll(&mut font_system, false);
+            buffer
+                .layout_runs()
+                .next()
+                .map(|run| run.line_y - run.line_top)
+                .unwrap_or(line_height * 0.8)
+        })
+    }
+
+    /// Horizontal position within its line of every glyph boundary in `text`,
+    /// indexed by byte offset, taken from the shared shaped buffer.
+    fn byte_x_positions(&self, text: &str) -> Vec<f32> {
+        let mut line_starts = Vec::new();
+        let mut start = 0;
+        for line in text.split('\n') {
+            line_starts.push(start);
+            start += line.len() + 1;
+        }
+
+        let cache_key = TextCacheKey::new(text, BASE_FONT_SIZE);
+        let mut font_system = self.font_system.lock().unwrap();
+        let mut text_cache = self.text_cache.lock().unwrap();
+        let buffer = text_cache.get_or_create(cache_key, &mut font_system, BASE_FONT_SIZE);
+        buffer.ensure(&mut font_system, text, BASE_FONT_SIZE, Attrs::new());
+
+        let mut x_at = vec![0.0; text.len() + 1];
+        for run in buffer.buffer.layout_runs() {
+            let Some(&line_start) = line_starts.get(run.line_i) else {
+                continue;
+            };
+            for glyph in run.glyphs.iter() {
+                x_at[line_start + glyph.start] = glyph.x;
+                x_at[line_start + glyph.end] = glyph.x + glyph.w;
+            }
+        }
+        x_at
+    }
+}
+
+impl TextMeasurer for WgpuTextMeasurer {
+    fn measure(&self, text: &str, max_width: f32) -> cranpose_ui::TextMetrics {
+        let metrics_key = (
+            text.to_string(),
+            (BASE_FONT_SIZE * 100.0) as i32,
+            text_width_bucket(max_width),
+        );
+
+        // Check metrics cache first (fastest path)
+        if let Some(metrics) = self.metrics_cache.lock().unwrap().get(&metrics_key) {
+            return *metrics;
+        }
+
+        let metrics = if is_unbounded_width(max_width) {
+            self.measure_unbounded(text)
+        } else {
+            let unbounded = self.measure(text, f32::INFINITY);
+            if unbounded.width <= max_width {
+                unbounded
+            } else {
+                // Wrap with the advances of the one shaped buffer instead of
+                // re-measuring (and caching) every candidate line.
+                let x_at = self.byte_x_positions(text);
+                measure_wrapped(text, max_width, unbounded.line_height, |line| {
+                    x_at[line.end] - x_at[line.start]
+                })
+            }
+        };
+
+        self.metrics_cache.lock().unwrap().put(metrics_key, metrics);
+        metrics
+    }
+
     fn get_offset_for_position(&self, text: &str, x: f32, y: f32) -> usize {
         if text.is_empty() {
             return 0;
         }
 
-        let line_height = BASE_FONT_SIZE * 1.4;
+        let line_height = text_line_height(BASE_FONT_SIZE);
 
         // Calculate which line was clicked based on Y coordinate
         let line_index = (y / line_height).floor().max(0.0) as usize;
@@ -501,18 +710,7 @@ impl TextMeasurer for WgpuTextMeasurer {
         let mut font_system = self.font_system.lock().unwrap();
         let mut text_cache = self.text_cache.lock().unwrap();
 
-        let buffer = text_cache.entry(cache_key).or_insert_with(|| {
-            let buffer = Buffer::new(
-                &mut font_system,
-                Metrics::new(BASE_FONT_SIZE, BASE_FONT_SIZE * 1.4),
-            );
-            SharedTextBuffer {
-                buffer,
-                text: String::new(),
-                font_size: 0.0,
-                cached_size: None,
-            }
-        });
+        let buffer = text_cache.get_or_create(cache_key, &mut font_system, BASE_FONT_SIZE);
 
         buffer.ensure(&mut font_system, line_text, BASE_FONT_SIZE, Attrs::new());
 
@@ -547,6 +745,11 @@ impl TextMeasurer for WgpuTextMeasurer {
         line_start_byte + best_offset.min(line_text.len())
     }
 
+    fn baseline(&self, line_height: f32) -> f32 {
+        // The baseline scales with the line box, so scale it from the base size.
+        self.base_baseline() * line_height / text_line_height(BASE_FONT_SIZE)
+    }
+
     fn get_cursor_x_for_offset(&self, text: &str, offset: usize) -> f32 {
         let clamped_offset = offset.min(text.len());
         if clamped_offset == 0 {
@@ -555,28 +758,20 @@ impl TextMeasurer for WgpuTextMeasurer {
 
         // Measure text up to offset
         let prefix = &text[..clamped_offset];
-        self.measure(prefix).width
+        self.measure(prefix, f32::INFINITY).width
     }
 
     fn layout(&self, text: &str) -> cranpose_ui::text_layout_result::TextLayoutResult {
         use cranpose_ui::text_layout_result::{LineLayout, TextLayoutResult};
 
-        let line_height = BASE_FONT_SIZE * 1.4;
+        let line_height = text_line_height(BASE_FONT_SIZE);
 
         // Get buffer to extract glyph positions
         let cache_key = TextCacheKey::new(text, BASE_FONT_SIZE);
         let mut font_system = self.font_system.lock().unwrap();
         let mut text_cache = self.text_cache.lock().unwrap();
 
-        let buffer = text_cache.entry(cache_key.clone()).or_insert_with(|| {
-            let buffer = Buffer::new(&mut font_system, Metrics::new(BASE_FONT_SIZE, line_height));
-            SharedTextBuffer {
-                buffer,
-                text: String::new(),
-                font_size: 0.0,
-                cached_size: None,
-            }
-        });
+        let buffer = text_cache.get_or_create(cache_key, &mut font_system, BASE_FONT_SIZE);
         buffer.ensure(&mut font_system, text, BASE_FONT_SIZE, Attrs::new());
 
         // Extract glyph positions from layout runs
@@ -614,7 +809,7 @@ impl TextMeasurer for WgpuTextMeasurer {
         }
 
         // Add end position
-        let total_width = self.measure(text).width;
+        let total_width = self.measure(text, f32::INFINITY).width;
         glyph_x_positions.push(total_width);
         char_to_byte.push(text.len());
 
@@ -648,7 +843,7 @@ impl TextMeasurer for WgpuTextMeasurer {
             });
         }
 
-        let metrics = self.measure(text);
+        let metrics = self.measure(text, f32::INFINITY);
         TextLayoutResult::new(
             metrics.width,
             metrics.height,
@@ -660,3 +855,11 @@ impl TextMeasurer for WgpuTextMeasurer {
         )
     }
 }
+
+#[cfg(test)]
+#[path = "tests/text_measurer_tests.rs"]
+mod tests;
+
+#[cfg(test)]
+#[path = "tests/root_scale_tests.rs"]
+mod root_scale_tests;
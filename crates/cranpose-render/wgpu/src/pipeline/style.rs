@@ -1,5 +1,6 @@
 use std::rc::Rc;
 
+use cranpose_core::NodeId;
 use cranpose_foundation::PointerEvent;
 use cranpose_ui::{Brush, DrawCommand, LayoutNodeData, ModifierNodeSlices};
 use cranpose_ui_graphics::{
@@ -17,6 +18,7 @@ pub(crate) struct NodeStyle {
     pub draw_commands: Vec<DrawCommand>,
     pub graphics_layer: Option<GraphicsLayer>,
     pub clip_to_bounds: bool,
+    pub minimum_touch_target: Option<Size>,
 }
 
 impl NodeStyle {
@@ -36,26 +38,46 @@ impl NodeStyle {
             draw_commands: slices.draw_commands().to_vec(),
             graphics_layer: slices.graphics_layer(), // Extracted from GraphicsLayerNode
             clip_to_bounds: slices.clip_to_bounds(),
+            minimum_touch_target: slices.minimum_touch_target(),
         }
     }
 }
 
+/// Composes a node's own layer, applied about its `origin`, into the
+/// transform accumulated from its ancestors.
+///
+/// Accumulated layers map layout coordinates to the screen as
+/// `p * scale + translation`, so a scaled parent also scales and moves the
+/// positions of its children.
 pub(crate) fn combine_layers(
     current: GraphicsLayer,
     modifier_layer: Option<GraphicsLayer>,
+    origin: (f32, f32),
 ) -> GraphicsLayer {
     if let Some(layer) = modifier_layer {
+        let local_x = origin.0 * (1.0 - layer.scale) + layer.translation_x;
+        let local_y = origin.1 * (1.0 - layer.scale) + layer.translation_y;
         GraphicsLayer {
             alpha: (current.alpha * layer.alpha).clamp(0.0, 1.0),
             scale: current.scale * layer.scale,
-            translation_x: current.translation_x + layer.translation_x,
-            translation_y: current.translation_y + layer.translation_y,
+            translation_x: local_x * current.scale + current.translation_x,
+            translation_y: local_y * current.scale + current.translation_y,
         }
     } else {
         current
     }
 }
 
+/// Re-expresses an accumulated layer relative to `origin`, the form
+/// [`apply_layer_to_rect`] and the draw helpers expect.
+pub(crate) fn layer_about(layer: GraphicsLayer, origin: (f32, f32)) -> GraphicsLayer {
+    GraphicsLayer {
+        translation_x: layer.translation_x + origin.0 * (layer.scale - 1.0),
+        translation_y: layer.translation_y + origin.1 * (layer.scale - 1.0),
+        ..layer
+    }
+}
+
 pub(crate) fn apply_layer_to_rect(rect: Rect, origin: (f32, f32), layer: GraphicsLayer) -> Rect {
     let offset_x = rect.x - origin.0;
     let offset_y = rect.y - origin.1;
@@ -129,6 +151,7 @@ pub(crate) fn apply_draw_commands(
     size: Size,
     layer: GraphicsLayer,
     clip: Option<Rect>,
+    node_id: NodeId,
     scene: &mut Scene,
 ) {
     for command in commands {
@@ -146,7 +169,7 @@ pub(crate) fn apply_draw_commands(
                     let draw_rect = local_rect.translate(rect.x, rect.y);
                     let transformed = apply_layer_to_rect(draw_rect, origin, layer);
                     let brush = apply_layer_to_brush(brush, layer);
-                    scene.push_shape(transformed, brush, None, clip);
+                    scene.push_shape(Some(node_id), transformed, brush, None, clip);
                 }
                 DrawPrimitive::RoundRect {
                     rect: local_rect,
@@ -158,7 +181,27 @@ pub(crate) fn apply_draw_commands(
                     let scaled_radii = scale_corner_radii(radii, layer.scale);
                     let shape = RoundedCornerShape::with_radii(scaled_radii);
                     let brush = apply_layer_to_brush(brush, layer);
-                    scene.push_shape(transformed, brush, Some(shape), clip);
+                    scene.push_shape(Some(node_id), transformed, brush, Some(shape), clip);
+                }
+                DrawPrimitive::Stroke {
+                    rect: local_rect,
+                    brush,
+                    radii,
+                    width,
+                } => {
+                    let draw_rect = local_rect.translate(rect.x, rect.y);
+                    let transformed = apply_layer_to_rect(draw_rect, origin, layer);
+                    let scaled_radii = scale_corner_radii(radii, layer.scale);
+                    let shape = RoundedCornerShape::with_radii(scaled_radii);
+                    let brush = apply_layer_to_brush(brush, layer);
+                    scene.push_stroke(
+                        Some(node_id),
+                        transformed,
+                        brush,
+                        shape,
+                        width * layer.scale,
+                        clip,
+                    );
                 }
             }
         }
@@ -2,12 +2,12 @@
 
 use crate::scene::{DrawShape, TextDraw};
 use crate::shaders;
-use crate::{SharedTextBuffer, SharedTextCache, TextCacheKey, BASE_FONT_SIZE};
+use crate::{SharedTextCache, TextCacheKey, BASE_FONT_SIZE};
 use bytemuck::{Pod, Zeroable};
 use cranpose_ui_graphics::{Brush, Color};
 use glyphon::{
-    Attrs, Cache, Color as GlyphonColor, FontSystem, Metrics, Resolution, SwashCache, TextArea,
-    TextAtlas, TextBounds, TextRenderer, Viewport,
+    Attrs, Cache, Color as GlyphonColor, FontSystem, Resolution, SwashCache, TextArea, TextAtlas,
+    TextBounds, TextRenderer, Viewport,
 };
 use std::sync::{Arc, Mutex};
 
@@ -55,7 +55,7 @@ struct ShapeData {
     brush_type: u32,           // 0=solid, 1=linear_gradient, 2=radial_gradient
     gradient_start: u32,       // Starting index in gradient buffer
     gradient_count: u32,       // Number of gradient stops
-    _padding: u32,
+    stroke_width: f32,         // Inner stroke width in pixels (0 = filled)
 }
 
 #[repr(C)]
@@ -417,7 +417,6 @@ impl GpuRenderer {
         texts: &[TextDraw],
         width: u32,
         height: u32,
-        root_scale: f32,
     ) -> Result<(), String> {
         log::trace!(
             "🎨 Rendering: {} shapes, {} texts (size: {}x{})",
@@ -463,34 +462,29 @@ impl GpuRenderer {
         for shape in &sorted_shapes {
             let rect = shape.rect;
 
-            // Scale to physical pixels
-            let x = rect.x * root_scale;
-            let y = rect.y * root_scale;
-            let w = rect.width * root_scale;
-            let h = rect.height * root_scale;
+            // The scene is already in physical pixels
+            let x = rect.x;
+            let y = rect.y;
+            let w = rect.width;
+            let h = rect.height;
 
-            // Calculate clip rect (scaled to physical pixels) and skip early if fully clipped
+            // Calculate clip rect and skip early if fully clipped
             let clip_rect = if let Some(clip) = shape.clip {
-                let clip_right = (clip.x + clip.width) * root_scale;
-                let clip_bottom = (clip.y + clip.height) * root_scale;
+                let clip_right = clip.x + clip.width;
+                let clip_bottom = clip.y + clip.height;
                 let shape_right = x + w;
                 let shape_bottom = y + h;
 
                 // Skip shapes that are entirely outside the clip rect
-                if shape_right <= clip.x * root_scale
+                if shape_right <= clip.x
                     || x >= clip_right
-                    || shape_bottom <= clip.y * root_scale
+                    || shape_bottom <= clip.y
                     || y >= clip_bottom
                 {
                     continue;
                 }
 
-                [
-                    clip.x * root_scale,
-                    clip.y * root_scale,
-                    clip.width * root_scale,
-                    clip.height * root_scale,
-                ]
+                [clip.x, clip.y, clip.width, clip.height]
             } else {
                 [0.0, 0.0, 0.0, 0.0] // No clipping
             };
@@ -519,25 +513,20 @@ impl GpuRenderer {
                             color: [c.r(), c.g(), c.b(), c.a()],
                         });
                     }
-                    // Store radial gradient parameters (center is relative to rect, scaled to physical)
-                    gradient_params = [
-                        x + center.x * root_scale,
-                        y + center.y * root_scale,
-                        (radius * root_scale).max(f32::EPSILON),
-                        0.0,
-                    ];
+                    // Store radial gradient parameters (center is relative to rect)
+                    gradient_params = [x + center.x, y + center.y, radius.max(f32::EPSILON), 0.0];
                     (2u32, start, colors.len() as u32)
                 }
             };
 
-            // Shape data (radii scaled to physical pixels)
+            // Shape data
             let radii = if let Some(rounded) = shape.shape {
                 let resolved = rounded.resolve(rect.width, rect.height);
                 [
-                    resolved.top_left * root_scale,
-                    resolved.top_right * root_scale,
-                    resolved.bottom_left * root_scale,
-                    resolved.bottom_right * root_scale,
+                    resolved.top_left,
+                    resolved.top_right,
+                    resolved.bottom_left,
+                    resolved.bottom_right,
                 ]
             } else {
                 [0.0, 0.0, 0.0, 0.0]
@@ -551,7 +540,7 @@ impl GpuRenderer {
                 brush_type,
                 gradient_start,
                 gradient_count,
-                _padding: 0,
+                stroke_width: shape.stroke_width.unwrap_or(0.0),
             });
 
             filtered_shapes.push(shape);
@@ -604,11 +593,10 @@ impl GpuRenderer {
                     }
                 };
 
-                // Scale logical dp to physical pixels for GPU rendering
-                let x = rect.x * root_scale;
-                let y = rect.y * root_scale;
-                let w = rect.width * root_scale;
-                let h = rect.height * root_scale;
+                let x = rect.x;
+                let y = rect.y;
+                let w = rect.width;
+                let h = rect.height;
 
                 // Vertices for quad (in physical pixels)
                 vertices.extend_from_slice(&[
@@ -733,23 +721,12 @@ impl GpuRenderer {
                 continue;
             }
 
-            // Scale font size to physical pixels: BASE_FONT_SIZE is in dp, scale by text zoom and DPI
-            let font_size_px = BASE_FONT_SIZE * text_draw.scale * root_scale;
+            // Text scale already includes the root scale applied in rebuild_scene
+            let font_size_px = BASE_FONT_SIZE * text_draw.scale;
             let key = TextCacheKey::new(&text_draw.text, font_size_px);
 
             // Create or update buffer in cache
-            let buffer = text_cache.entry(key).or_insert_with(|| {
-                let buffer = glyphon::Buffer::new(
-                    &mut font_system,
-                    Metrics::new(font_size_px, font_size_px * 1.4),
-                );
-                SharedTextBuffer {
-                    buffer,
-                    text: String::new(),
-                    font_size: 0.0,
-                    cached_size: None,
-                }
-            });
+            let buffer = text_cache.get_or_create(key, &mut font_system, font_size_px);
 
             // Ensure buffer has the correct text
             buffer.ensure(
@@ -765,7 +742,7 @@ impl GpuRenderer {
             .iter()
             .filter(|t| !t.text.is_empty() && t.rect.width > 0.0 && t.rect.height > 0.0)
             .map(|text| {
-                let font_size_px = BASE_FONT_SIZE * text.scale * root_scale;
+                let font_size_px = BASE_FONT_SIZE * text.scale;
                 (text, TextCacheKey::new(&text.text, font_size_px))
             })
             .collect();
@@ -774,7 +751,7 @@ impl GpuRenderer {
         let mut text_areas = Vec::new();
 
         for (_text_draw, key) in text_data.iter() {
-            let cached = text_cache.get(key).expect("Text should be in cache");
+            let cached = text_cache.peek(key).expect("Text should be in cache");
 
             let color = GlyphonColor::rgba(
                 (_text_draw.color.r() * 255.0) as u8,
@@ -783,26 +760,19 @@ impl GpuRenderer {
                 (_text_draw.color.a() * 255.0) as u8,
             );
 
-            // Scale text position and bounds to physical pixels
-            let left_px = _text_draw.rect.x * root_scale;
-            let top_px = _text_draw.rect.y * root_scale;
+            let left_px = _text_draw.rect.x;
+            let top_px = _text_draw.rect.y;
 
             let bounds = TextBounds {
-                left: _text_draw
-                    .clip
-                    .map(|c| (c.x * root_scale) as i32)
-                    .unwrap_or(0),
-                top: _text_draw
-                    .clip
-                    .map(|c| (c.y * root_scale) as i32)
-                    .unwrap_or(0),
+                left: _text_draw.clip.map(|c| c.x as i32).unwrap_or(0),
+                top: _text_draw.clip.map(|c| c.y as i32).unwrap_or(0),
                 right: _text_draw
                     .clip
-                    .map(|c| ((c.x + c.width) * root_scale) as i32)
+                    .map(|c| (c.x + c.width) as i32)
                     .unwrap_or(width as i32),
                 bottom: _text_draw
                     .clip
-                    .map(|c| ((c.y + c.height) * root_scale) as i32)
+                    .map(|c| (c.y + c.height) as i32)
                     .unwrap_or(height as i32),
             };
 
@@ -839,6 +809,8 @@ impl GpuRenderer {
             self.text_atlas.trim();
         }
 
+        drop(text_areas);
+        text_cache.trim();
         drop(font_system);
         drop(text_cache);
 
@@ -52,7 +52,7 @@ struct ShapeData {
     brush_type: u32,            // 0=solid, 1=linear_gradient, 2=radial_gradient
     gradient_start: u32,
     gradient_count: u32,
-    _padding: u32,
+    stroke_width: f32,          // inner stroke width in pixels (0 = filled)
 }
 
 struct GradientStop {
@@ -113,7 +113,12 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     let dist = sdf_rounded_rect(local_pos, half_size, shape.radii);
 
     // Anti-aliasing
-    let alpha = 1.0 - smoothstep(-0.5, 0.5, dist);
+    var alpha = 1.0 - smoothstep(-0.5, 0.5, dist);
+
+    // Strokes keep only the band within stroke_width of the edge
+    if (shape.stroke_width > 0.0) {
+        alpha = alpha * smoothstep(-0.5, 0.5, dist + shape.stroke_width);
+    }
 
     if (alpha < 0.001) {
         discard;
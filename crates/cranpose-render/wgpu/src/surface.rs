@@ -0,0 +1,91 @@
+//! Presentation settings applied whenever the renderer configures a surface.
+
+/// Present mode and frame latency requested for the window surface.
+///
+/// Changes are recorded immediately and picked up the next time the surface
+/// is configured, since only the platform layer owns the surface itself.
+pub(crate) struct SurfaceSettings {
+    present_mode: wgpu::PresentMode,
+    max_frame_latency: u32,
+    /// Present modes reported by the adapter; empty until a surface is known.
+    supported_present_modes: Vec<wgpu::PresentMode>,
+    pending: bool,
+}
+
+impl SurfaceSettings {
+    pub(crate) fn new() -> Self {
+        Self {
+            present_mode: wgpu::PresentMode::Fifo,
+            max_frame_latency: 2,
+            supported_present_modes: Vec::new(),
+            pending: true,
+        }
+    }
+
+    pub(crate) fn present_mode(&self) -> wgpu::PresentMode {
+        self.present_mode
+    }
+
+    pub(crate) fn set_present_mode(&mut self, mode: wgpu::PresentMode) {
+        if self.present_mode != mode {
+            self.present_mode = mode;
+            self.pending = true;
+        }
+    }
+
+    pub(crate) fn max_frame_latency(&self) -> u32 {
+        self.max_frame_latency
+    }
+
+    /// Zero is not a valid latency; it is raised to one frame.
+    pub(crate) fn set_max_frame_latency(&mut self, frames: u32) {
+        let frames = frames.max(1);
+        if self.max_frame_latency != frames {
+            self.max_frame_latency = frames;
+            self.pending = true;
+        }
+    }
+
+    pub(crate) fn set_supported_present_modes(&mut self, modes: &[wgpu::PresentMode]) {
+        if self.supported_present_modes != modes {
+            self.supported_present_modes = modes.to_vec();
+            self.pending = true;
+        }
+    }
+
+    /// Whether settings changed since the surface was last configured.
+    pub(crate) fn needs_reconfigure(&self) -> bool {
+        self.pending
+    }
+
+    /// The requested present mode, or `Fifo` when the adapter does not
+    /// support it. `Fifo` is the one mode every adapter must provide.
+    pub(crate) fn resolved_present_mode(&self) -> wgpu::PresentMode {
+        if self.supported_present_modes.is_empty()
+            || self.supported_present_modes.contains(&self.present_mode)
+        {
+            self.present_mode
+        } else {
+            wgpu::PresentMode::Fifo
+        }
+    }
+
+    /// Write the settings into `config` ahead of `Surface::configure`.
+    pub(crate) fn apply(&mut self, config: &mut wgpu::SurfaceConfiguration) {
+        let present_mode = self.resolved_present_mode();
+        if self.pending && present_mode != self.present_mode {
+            log::warn!(
+                "present mode {:?} is not supported by the adapter, falling back to {:?}",
+                self.present_mode,
+                present_mode
+            );
+        }
+        config.present_mode = present_mode;
+        config.desired_maximum_frame_latency = self.max_frame_latency;
+        self.pending = false;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/surface_tests.rs"]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use cranpose_render_common::test_support::{layout_box, rect};
+use cranpose_render_pixels::PixelsRenderer;
+use cranpose_ui::{Color, Modifier};
+
+const SCALE: f32 = 1.5;
+
+/// A clickable red 100dp box at (10, 10) inside a 200dp root.
+fn red_box_tree() -> LayoutTree {
+    let red_box = layout_box(
+        2,
+        rect(10.0, 10.0, 100.0, 100.0),
+        Modifier::empty().background(Color::RED).clickable(|_| {}),
+        vec![],
+    );
+    LayoutTree::new(layout_box(
+        1,
+        rect(0.0, 0.0, 200.0, 200.0),
+        Modifier::empty(),
+        vec![red_box],
+    ))
+}
+
+fn viewport() -> Size {
+    Size {
+        width: 200.0,
+        height: 200.0,
+    }
+}
+
+#[test]
+fn both_backends_scale_a_100dp_box_to_150px() {
+    let tree = red_box_tree();
+
+    let mut wgpu = WgpuRenderer::new();
+    wgpu.set_root_scale(SCALE);
+    wgpu.rebuild_scene(&tree, &LayoutOverlay::default(), viewport())
+        .unwrap();
+    let shape = wgpu.scene().shapes.first().expect("box background");
+    assert_eq!(
+        (
+            shape.rect.x,
+            shape.rect.y,
+            shape.rect.width,
+            shape.rect.height
+        ),
+        (15.0, 15.0, 150.0, 150.0)
+    );
+
+    let mut pixels = PixelsRenderer::new();
+    pixels.set_root_scale(SCALE);
+    pixels
+        .rebuild_scene(&tree, &LayoutOverlay::default(), viewport())
+        .unwrap();
+    let size = (200.0 * SCALE) as u32;
+    let mut frame = vec![0; (size * size * 4) as usize];
+    pixels.draw(&mut frame, size, size);
+
+    let is_red = |x: u32, y: u32| {
+        let idx = ((y * size + x) * 4) as usize;
+        frame[idx..idx + 4] == [255, 0, 0, 255]
+    };
+    let row = 90;
+    let painted: Vec<u32> = (0..size).filter(|&x| is_red(x, row)).collect();
+    assert_eq!(painted.len(), 150);
+    assert_eq!(painted.first(), Some(&15));
+    assert_eq!(painted.last(), Some(&164));
+}
+
+#[test]
+fn hit_regions_stay_in_logical_pixels() {
+    let tree = red_box_tree();
+
+    let mut wgpu = WgpuRenderer::new();
+    wgpu.set_root_scale(SCALE);
+    wgpu.rebuild_scene(&tree, &LayoutOverlay::default(), viewport())
+        .unwrap();
+    let mut pixels = PixelsRenderer::new();
+    pixels.set_root_scale(SCALE);
+    pixels
+        .rebuild_scene(&tree, &LayoutOverlay::default(), viewport())
+        .unwrap();
+
+    // (12, 12) is inside the box in layout coordinates but would miss it
+    // if hit regions were scaled; (150, 150) is the reverse.
+    assert_eq!(wgpu.scene().hit_test_nodes(12.0, 12.0), vec![2]);
+    assert_eq!(pixels.scene().hit_test_nodes(12.0, 12.0), vec![2]);
+    assert!(wgpu.scene().hit_test_nodes(150.0, 150.0).is_empty());
+    assert!(pixels.scene().hit_test_nodes(150.0, 150.0).is_empty());
+}
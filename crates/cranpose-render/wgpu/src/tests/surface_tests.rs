@@ -0,0 +1,56 @@
+use super::*;
+
+fn config() -> wgpu::SurfaceConfiguration {
+    wgpu::SurfaceConfiguration {
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        width: 800,
+        height: 600,
+        present_mode: wgpu::PresentMode::Fifo,
+        alpha_mode: wgpu::CompositeAlphaMode::Auto,
+        view_formats: vec![],
+        desired_maximum_frame_latency: 2,
+    }
+}
+
+#[test]
+fn chosen_mode_is_stored_and_applied_on_next_configure() {
+    let mut settings = SurfaceSettings::new();
+    settings.set_supported_present_modes(&[wgpu::PresentMode::Fifo, wgpu::PresentMode::Mailbox]);
+    let mut config = config();
+    settings.apply(&mut config);
+    assert!(!settings.needs_reconfigure());
+
+    settings.set_present_mode(wgpu::PresentMode::Mailbox);
+    settings.set_max_frame_latency(1);
+    assert_eq!(settings.present_mode(), wgpu::PresentMode::Mailbox);
+    assert_eq!(settings.max_frame_latency(), 1);
+    assert!(settings.needs_reconfigure());
+    assert_eq!(config.present_mode, wgpu::PresentMode::Fifo);
+
+    settings.apply(&mut config);
+    assert!(!settings.needs_reconfigure());
+    assert_eq!(config.present_mode, wgpu::PresentMode::Mailbox);
+    assert_eq!(config.desired_maximum_frame_latency, 1);
+}
+
+#[test]
+fn unsupported_mode_falls_back_to_fifo() {
+    let mut settings = SurfaceSettings::new();
+    settings.set_supported_present_modes(&[wgpu::PresentMode::Fifo]);
+    settings.set_present_mode(wgpu::PresentMode::Immediate);
+
+    let mut config = config();
+    config.present_mode = wgpu::PresentMode::Immediate;
+    settings.apply(&mut config);
+
+    assert_eq!(config.present_mode, wgpu::PresentMode::Fifo);
+    assert_eq!(settings.present_mode(), wgpu::PresentMode::Immediate);
+}
+
+#[test]
+fn zero_frame_latency_is_raised_to_one() {
+    let mut settings = SurfaceSettings::new();
+    settings.set_max_frame_latency(0);
+    assert_eq!(settings.max_frame_latency(), 1);
+}
@@ -0,0 +1,127 @@
+use super::*;
+use cranpose_render_pixels::CachedRusttypeTextMeasurer;
+
+const ROBOTO_LIGHT: &[u8] =
+    include_bytes!("../../../../../apps/desktop-demo/assets/Roboto-Light.ttf");
+
+/// A measurer that only knows the app-provided font, independent of system fonts.
+fn wgpu_measurer() -> WgpuTextMeasurer {
+    let mut font_system =
+        FontSystem::new_with_locale_and_db("en-US".into(), fontdb::Database::new());
+    load_app_fonts(&mut font_system, &[ROBOTO_LIGHT]);
+    WgpuTextMeasurer::new(
+        Arc::new(Mutex::new(font_system)),
+        new_text_cache(DEFAULT_TEXT_CACHE_CAPACITY),
+    )
+}
+
+#[test]
+fn pixels_and_wgpu_agree_on_text_metrics() {
+    let wgpu = wgpu_measurer();
+    let pixels = CachedRusttypeTextMeasurer::new(8);
+
+    for text in ["Hello, World!", "Counter: 42", "The quick brown fox\njumps"] {
+        let expected = wgpu.measure(text, f32::INFINITY);
+        let actual = pixels.measure(text, f32::INFINITY);
+
+        let tolerance = (expected.width * 0.02).max(1.0);
+        assert!(
+            (expected.width - actual.width).abs() <= tolerance,
+            "{text:?}: wgpu width {} vs pixels width {}",
+            expected.width,
+            actual.width
+        );
+        assert_eq!(expected.line_height, actual.line_height, "{text:?}");
+        assert_eq!(expected.line_count, actual.line_count, "{text:?}");
+        assert_eq!(expected.height, actual.height, "{text:?}");
+    }
+}
+
+#[test]
+fn first_app_font_becomes_default_family() {
+    let mut font_system =
+        FontSystem::new_with_locale_and_db("en-US".into(), fontdb::Database::new());
+    load_app_fonts(&mut font_system, &[ROBOTO_LIGHT]);
+
+    let resolved = font_system.db().query(&fontdb::Query {
+        families: &[fontdb::Family::SansSerif],
+        ..fontdb::Query::default()
+    });
+    let family = resolved
+        .and_then(|id| font_system.db().face(id))
+        .map(|face| face.families[0].0.clone());
+    assert_eq!(family.as_deref(), Some("Roboto"));
+}
+
+#[test]
+fn text_cache_evicts_least_recently_used_buffers() {
+    let mut font_system =
+        FontSystem::new_with_locale_and_db("en-US".into(), fontdb::Database::new());
+    load_app_fonts(&mut font_system, &[ROBOTO_LIGHT]);
+    let mut cache = TextCache::new(NonZeroUsize::new(3).unwrap());
+    let key = |text: &str| TextCacheKey::new(text, BASE_FONT_SIZE);
+    let mut touch = |cache: &mut TextCache, text: &str| {
+        cache
+            .get_or_create(key(text), &mut font_system, BASE_FONT_SIZE)
+            .ensure(&mut font_system, text, BASE_FONT_SIZE, Attrs::new());
+    };
+
+    for text in ["one", "two", "three"] {
+        touch(&mut cache, text);
+    }
+    // "one" becomes the hottest entry, so "two" is the eviction candidate.
+    touch(&mut cache, "one");
+    // A very large string takes a single slot and evicts a single entry.
+    touch(&mut cache, &"large ".repeat(2_000));
+    // Nothing is evicted before the frame is trimmed.
+    assert!(cache.peek(&key("two")).is_some());
+    cache.trim();
+
+    assert!(cache.peek(&key("two")).is_none());
+    assert!(cache.peek(&key("one")).is_some());
+    assert!(cache.peek(&key("three")).is_some());
+    assert!(cache.peek(&key(&"large ".repeat(2_000))).is_some());
+
+    cache.set_capacity(NonZeroUsize::new(1).unwrap());
+    assert!(cache.peek(&key(&"large ".repeat(2_000))).is_some());
+    assert!(cache.peek(&key("one")).is_none());
+    assert!(cache.peek(&key("three")).is_none());
+}
+
+#[test]
+fn wrapping_reuses_the_shaped_buffer_and_splits_the_metrics_cache() {
+    let wgpu = wgpu_measurer();
+    let text = "The quick brown fox jumps over the lazy dog";
+
+    let unbounded = wgpu.measure(text, f32::INFINITY);
+    let max_width = unbounded.width / 2.0;
+    let wrapped = wgpu.measure(text, max_width);
+
+    assert!(wrapped.line_count > 1, "{wrapped:?}");
+    assert!(wrapped.width <= max_width, "{wrapped:?}");
+    assert_eq!(
+        wrapped.height,
+        wrapped.line_count as f32 * wrapped.line_height
+    );
+    // One metrics entry per width bucket, and no buffers for candidate lines.
+    assert_eq!(wgpu.metrics_cache.lock().unwrap().len(), 2);
+    assert_eq!(wgpu.text_cache.lock().unwrap().entries.len(), 1);
+    let cached = wgpu.measure(text, f32::INFINITY);
+    assert_eq!(cached.width, unbounded.width);
+    assert_eq!(cached.line_count, 1);
+}
+
+#[test]
+fn baseline_comes_from_font_metrics() {
+    let wgpu = wgpu_measurer();
+    let pixels = CachedRusttypeTextMeasurer::new(8);
+    let line_height = text_line_height(BASE_FONT_SIZE);
+
+    let baseline = wgpu.baseline(line_height);
+    let expected = pixels.baseline(line_height);
+    assert!(
+        (baseline - expected).abs() <= 0.01,
+        "wgpu baseline {baseline} vs pixels baseline {expected}"
+    );
+    assert_eq!(wgpu.baseline(line_height * 2.0), baseline * 2.0);
+}
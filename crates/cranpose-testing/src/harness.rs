@@ -0,0 +1,213 @@
+//! Headless harness that runs the full frame pipeline.
+//!
+//! [`TestHarness`] drives composition, measures and places the node tree and
+//! builds the semantics tree after every frame, so tests can query nodes,
+//! click them and inspect their layout without a window. Pointer input is
+//! hit-tested against the scene of the software renderer, like the app shell
+//! does.
+//!
+//! # Example
+//!
+//! ```
+//! use cranpose_testing::harness::TestHarness;
+//! use cranpose_ui::widgets::Text;
+//! use cranpose_ui::Modifier;
+//!
+//! let harness = TestHarness::new(400.0, 300.0, || {
+//!     Text("Hello", Modifier::empty());
+//! });
+//!
+//! let node = harness.find("Hello").expect("text node");
+//! assert!(harness.layout_box(node.node_id).is_some());
+//! ```
+
+use crate::testing::ComposeTestRule;
+use cranpose_core::{enter_event_handler, exit_event_handler, NodeId};
+use cranpose_foundation::{PointerEvent, PointerEventKind};
+use cranpose_render_common::{HitTestTarget, RenderScene, Renderer};
+use cranpose_render_pixels::PixelsRenderer;
+use cranpose_ui::{measure_layout, LayoutBox, LayoutTree, SemanticsNode, SemanticsTree};
+use cranpose_ui_graphics::{Point, Rect, Size};
+
+/// Upper bound on recompose/layout passes in [`TestHarness::run_frame`].
+const MAX_FRAME_PASSES: usize = 10;
+
+/// Composition, layout and semantics wired together for headless tests.
+///
+/// Every mutating call ends by running a frame: pending recompositions are
+/// processed and, when a root exists, the tree is measured against the
+/// viewport so [`find`](Self::find) and [`layout_box`](Self::layout_box)
+/// always observe the latest layout.
+pub struct TestHarness {
+    rule: ComposeTestRule,
+    viewport: Size,
+    semantics_tree: Option<SemanticsTree>,
+    layout_tree: Option<LayoutTree>,
+    renderer: PixelsRenderer,
+}
+
+impl TestHarness {
+    /// Install `content` in a viewport of the given size and run the first frame.
+    pub fn new(width: f32, height: f32, content: impl FnMut() + 'static) -> Self {
+        let mut harness = Self {
+            rule: ComposeTestRule::new(),
+            viewport: Size { width, height },
+            semantics_tree: None,
+            layout_tree: None,
+            renderer: PixelsRenderer::new(),
+        };
+        harness
+            .rule
+            .set_content(content)
+            .expect("initial composition succeeds");
+        harness.run_frame();
+        harness
+    }
+
+    /// Resize the viewport and relayout.
+    pub fn set_viewport(&mut self, width: f32, height: f32) {
+        self.viewport = Size { width, height };
+        self.run_frame();
+    }
+
+    /// Process pending recompositions, then measure and place the tree and
+    /// rebuild its semantics.
+    ///
+    /// State written during measure invalidates its readers after the pass,
+    /// so the frame repeats until layout leaves no invalid scopes behind.
+    pub fn run_frame(&mut self) {
+        for _ in 0..MAX_FRAME_PASSES {
+            self.rule.pump_until_idle().expect("recomposition succeeds");
+            self.measure();
+            if !self.rule.runtime_handle().has_invalid_scopes() {
+                break;
+            }
+        }
+    }
+
+    /// Drain frame callbacks at `frame_time_nanos` and run a frame.
+    pub fn advance_frame(&mut self, frame_time_nanos: u64) {
+        self.rule
+            .advance_frame(frame_time_nanos)
+            .expect("frame callbacks succeed");
+        self.run_frame();
+    }
+
+    /// The semantics tree built by the last frame.
+    pub fn semantics_tree(&self) -> Option<&SemanticsTree> {
+        self.semantics_tree.as_ref()
+    }
+
+    /// The layout tree built by the last frame.
+    pub fn layout_tree(&self) -> Option<&LayoutTree> {
+        self.layout_tree.as_ref()
+    }
+
+    /// Find the first semantics node whose description contains `text`.
+    pub fn find(&self, text: &str) -> Option<SemanticsNode> {
+        fn find_in(node: &SemanticsNode, text: &str) -> Option<SemanticsNode> {
+            if node
+                .description
+                .as_ref()
+                .is_some_and(|description| description.contains(text))
+            {
+                return Some(node.clone());
+            }
+            node.children.iter().find_map(|child| find_in(child, text))
+        }
+
+        find_in(self.semantics_tree()?.root(), text)
+    }
+
+    /// The placed layout box of `node` in the last frame.
+    pub fn layout_box(&self, node: NodeId) -> Option<&LayoutBox> {
+        fn find_in(layout: &LayoutBox, node: NodeId) -> Option<&LayoutBox> {
+            if layout.node_id == node {
+                return Some(layout);
+            }
+            layout
+                .children
+                .iter()
+                .find_map(|child| find_in(child, node))
+        }
+
+        find_in(self.layout_tree()?.root(), node)
+    }
+
+    /// Click the center of the first node whose description contains `text`,
+    /// then run a frame.
+    ///
+    /// Returns false when no such node exists.
+    pub fn click(&mut self, text: &str) -> bool {
+        let Some(center) = self
+            .find(text)
+            .and_then(|node| self.layout_box(node.node_id))
+            .map(|layout| center_of(layout.rect))
+        else {
+            return false;
+        };
+        self.click_at(center.x, center.y);
+        true
+    }
+
+    /// Press and release the pointer at a position in the viewport, then run
+    /// a frame.
+    pub fn click_at(&mut self, x: f32, y: f32) {
+        self.dispatch(PointerEventKind::Down, x, y);
+        self.dispatch(PointerEventKind::Up, x, y);
+        self.run_frame();
+    }
+
+    /// Access the underlying test rule for composition-level assertions.
+    pub fn rule(&mut self) -> &mut ComposeTestRule {
+        &mut self.rule
+    }
+
+    fn measure(&mut self) {
+        let Some(root) = self.rule.root_id() else {
+            self.semantics_tree = None;
+            self.layout_tree = None;
+            self.renderer.scene_mut().clear();
+            return;
+        };
+        let handle = self.rule.runtime_handle();
+        let mut applier = self.rule.applier_mut();
+        applier.set_runtime_handle(handle);
+        let result = measure_layout(&mut applier, root, self.viewport);
+        applier.clear_runtime_handle();
+        drop(applier);
+        let measurements = result.expect("layout succeeds");
+        self.semantics_tree = Some(measurements.semantics_tree().clone());
+        let (layout_tree, overlay) = measurements.into_layout_tree_and_overlay();
+        self.renderer
+            .rebuild_scene(&layout_tree, &overlay, self.viewport)
+            .expect("scene rebuild succeeds");
+        self.layout_tree = Some(layout_tree);
+    }
+
+    fn dispatch(&self, kind: PointerEventKind, x: f32, y: f32) {
+        let hits = self.renderer.scene().hit_test(x, y);
+        let position = Point { x, y };
+        let event = PointerEvent::new(kind, position, position);
+        enter_event_handler();
+        // Hits come topmost first; stop once a handler consumes the event.
+        for hit in hits {
+            if event.is_consumed() {
+                break;
+            }
+            hit.dispatch(event.clone());
+        }
+        exit_event_handler();
+    }
+}
+
+fn center_of(rect: Rect) -> Point {
+    Point {
+        x: rect.x + rect.width / 2.0,
+        y: rect.y + rect.height / 2.0,
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/harness_tests.rs"]
+mod tests;
@@ -26,8 +26,18 @@ use cranpose_app_shell::AppShell;
 use cranpose_core::{location_key, Key};
 use cranpose_foundation::PointerEvent;
 use cranpose_render_common::{HitTestTarget, RenderScene, Renderer};
-use cranpose_ui::LayoutTree;
+use cranpose_ui::{LayoutOverlay, LayoutTree, SemanticsAction, SemanticsNode, SemanticsRole};
 use cranpose_ui_graphics::{Point, Rect, Size};
+use std::time::Duration;
+
+/// Number of interpolated moves used by [`RobotTestRule::drag`].
+const DRAG_STEPS: u32 = 10;
+
+/// Interval between synthesized moves in [`RobotTestRule::swipe`] (~60fps).
+const SWIPE_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Duration used by [`ElementFinder::drag_by`].
+const DRAG_BY_DURATION: Duration = Duration::from_millis(300);
 
 /// Main robot testing rule that provides programmatic control over a real app.
 ///
@@ -115,23 +125,44 @@ where
     ///
     /// This simulates a pointer down, move, and up sequence.
     pub fn drag(&mut self, from_x: f32, from_y: f32, to_x: f32, to_y: f32) {
-        // Move to start position
-        self.shell.set_cursor(from_x, from_y);
+        self.perform_gesture(
+            Point {
+                x: from_x,
+                y: from_y,
+            },
+            Point { x: to_x, y: to_y },
+            DRAG_STEPS,
+            Duration::ZERO,
+        );
+    }
 
-        // Press
+    /// Perform a swipe from `from` to `to` spread over `duration`.
+    ///
+    /// Unlike [`drag`](Self::drag), the intermediate moves are paced in real time
+    /// (one per frame interval), so the velocity tracker sees realistic timestamps
+    /// and a fast swipe on a scrollable container ends in a fling.
+    pub fn swipe(&mut self, from: Point, to: Point, duration: Duration) {
+        let steps = (duration.as_millis() / SWIPE_FRAME_INTERVAL.as_millis()).max(2) as u32;
+        self.perform_gesture(from, to, steps, duration / steps);
+    }
+
+    /// Presses at `from`, moves to `to` in `steps` interpolated moves separated
+    /// by `step_delay`, then releases.
+    fn perform_gesture(&mut self, from: Point, to: Point, steps: u32, step_delay: Duration) {
+        self.shell.set_cursor(from.x, from.y);
         self.shell.pointer_pressed();
 
-        // Move in steps to simulate smooth drag
-        let steps = 10;
         for i in 1..=steps {
+            if !step_delay.is_zero() {
+                std::thread::sleep(step_delay);
+            }
             let t = i as f32 / steps as f32;
-            let x = from_x + (to_x - from_x) * t;
-            let y = from_y + (to_y - from_y) * t;
+            let x = from.x + (to.x - from.x) * t;
+            let y = from.y + (to.y - from.y) * t;
             self.shell.set_cursor(x, y);
             self.shell.update();
         }
 
-        // Release
         self.shell.pointer_released();
         self.wait_for_idle();
     }
@@ -176,6 +207,18 @@ where
         }
     }
 
+    /// Find an element tagged with [`Modifier::test_tag`](cranpose_ui::Modifier::test_tag).
+    ///
+    /// Clicks, scrolls and focus requests on the finder go to the tagged node
+    /// itself and only succeed if it exposes the matching semantics action.
+    pub fn find_by_tag(&mut self, tag: &str) -> ElementFinder<'_, R> {
+        self.wait_for_idle();
+        ElementFinder {
+            robot: self,
+            query: FinderQuery::Tag(tag.to_string()),
+        }
+    }
+
     /// Find all clickable elements.
     ///
     /// Returns a finder that matches all elements with clickable semantics.
@@ -195,7 +238,7 @@ where
 
         // Use HeadlessRenderer to extract text from the layout tree
         if let Some(layout_tree) = self.get_layout_tree() {
-            extract_text_from_layout(layout_tree)
+            extract_text_from_layout(layout_tree, self.shell.layout_overlay())
         } else {
             Vec::new()
         }
@@ -208,7 +251,7 @@ where
         self.wait_for_idle();
 
         if let Some(layout_tree) = self.get_layout_tree() {
-            extract_rects_from_layout(layout_tree)
+            extract_rects_from_layout(layout_tree, self.shell.layout_overlay())
         } else {
             Vec::new()
         }
@@ -241,6 +284,7 @@ where
 #[derive(Clone, Debug)]
 enum FinderQuery {
     Text(String),
+    Tag(String),
     Position(f32, f32),
     Clickable,
 }
@@ -270,6 +314,7 @@ where
                 all_text.iter().any(|t| t.contains(text))
             }
             FinderQuery::Position(x, y) => !self.robot.get_scene().hit_test(*x, *y).is_empty(),
+            FinderQuery::Tag(_) => self.semantics_node().is_some(),
             FinderQuery::Clickable => {
                 // Check if there are any clickable elements
                 // This would require semantics traversal
@@ -294,6 +339,13 @@ where
                 // Get bounds from hit test
                 None // Placeholder
             }
+            FinderQuery::Tag(_) => {
+                let node = self.semantics_node()?;
+                self.robot
+                    .get_layout_tree()?
+                    .node_rect(node.node_id)
+                    .or_else(|| self.robot.shell.layout_overlay().node_rect(node.node_id))
+            }
             FinderQuery::Clickable => None,
         }
     }
@@ -318,8 +370,17 @@ where
 
     /// Click on this element at its center.
     ///
-    /// Returns true if the element was found and clicked.
+    /// Returns true if the element was found and clicked. Tagged elements are
+    /// only clicked if they expose a click action.
     pub fn click(&mut self) -> bool {
+        if let FinderQuery::Tag(_) = self.query {
+            let Some(node) = self.semantics_node() else {
+                return false;
+            };
+            let clicked = self.robot.shell.click_node(node.node_id);
+            self.robot.wait_for_idle();
+            return clicked;
+        }
         if let Some(center) = self.center() {
             self.robot.click_at(center.x, center.y);
             true
@@ -328,6 +389,46 @@ where
         }
     }
 
+    /// Drag this element by `delta`, starting from its center.
+    ///
+    /// The drag is paced over a fixed duration like [`RobotTestRule::swipe`].
+    /// Returns true if the element was found and dragged.
+    pub fn drag_by(&mut self, delta: Point) -> bool {
+        if let Some(center) = self.center() {
+            let target = Point {
+                x: center.x + delta.x,
+                y: center.y + delta.y,
+            };
+            self.robot.swipe(center, target, DRAG_BY_DURATION);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Scroll this element by `delta` pixels along its scroll axis by
+    /// dragging it.
+    ///
+    /// Returns the consumed delta, or `None` if the element is not scrollable.
+    pub fn scroll_by(&mut self, delta: f32) -> Option<f32> {
+        let node = self.semantics_node()?;
+        let consumed = self.robot.shell.scroll_node_by(node.node_id, delta);
+        self.robot.wait_for_idle();
+        consumed
+    }
+
+    /// Move focus to this element.
+    ///
+    /// Returns true if the element was found and is focusable.
+    pub fn focus(&mut self) -> bool {
+        let Some(node) = self.semantics_node() else {
+            return false;
+        };
+        let focused = self.robot.shell.focus_node(node.node_id);
+        self.robot.wait_for_idle();
+        focused
+    }
+
     /// Perform a long press on this element.
     ///
     /// This holds the pointer down for a duration before releasing.
@@ -349,6 +450,83 @@ where
         }
     }
 
+    /// Get the semantics node of the found element.
+    ///
+    /// Text queries match the first node whose description contains the text,
+    /// tag queries the first node with the tag, position queries the topmost
+    /// node under the point and clickable queries the first node with a click
+    /// action.
+    pub fn semantics_node(&mut self) -> Option<SemanticsNode> {
+        fn find(
+            node: &SemanticsNode,
+            matches: &dyn Fn(&SemanticsNode) -> bool,
+        ) -> Option<SemanticsNode> {
+            if matches(node) {
+                return Some(node.clone());
+            }
+            node.children.iter().find_map(|child| find(child, matches))
+        }
+
+        self.robot.wait_for_idle();
+        let query = self.query.clone();
+        let hit = match query {
+            FinderQuery::Position(x, y) => self
+                .robot
+                .get_scene()
+                .hit_test(x, y)
+                .first()
+                .map(|target| target.node_id()),
+            _ => None,
+        };
+        let matches = |node: &SemanticsNode| match &query {
+            FinderQuery::Text(text) => node
+                .description
+                .as_ref()
+                .is_some_and(|description| description.contains(text.as_str())),
+            FinderQuery::Tag(tag) => node.test_tag.as_deref() == Some(tag.as_str()),
+            FinderQuery::Position(..) => Some(node.node_id) == hit,
+            FinderQuery::Clickable => node
+                .actions
+                .iter()
+                .any(|action| matches!(action, SemanticsAction::Click { .. })),
+        };
+        let tree = self.robot.shell.semantics_tree()?;
+        find(tree.root(), &matches)
+    }
+
+    /// Get the semantics role of the found element.
+    pub fn role(&mut self) -> Option<SemanticsRole> {
+        self.semantics_node().map(|node| node.role)
+    }
+
+    /// Get the state description of the found element.
+    pub fn state_description(&mut self) -> Option<String> {
+        self.semantics_node()
+            .and_then(|node| node.state_description)
+    }
+
+    /// Assert that the found element has the given role.
+    pub fn assert_role(&mut self, expected: SemanticsRole) {
+        let role = self.role();
+        assert_eq!(
+            role.as_ref(),
+            Some(&expected),
+            "Unexpected role for {:?}",
+            self.query
+        );
+    }
+
+    /// Assert that the found element has the given state description.
+    pub fn assert_state_description(&mut self, expected: &str) {
+        let state = self.state_description();
+        assert_eq!(
+            state.as_deref(),
+            Some(expected),
+            "Unexpected state description for {:?}",
+            self.query
+        );
+    }
+
     /// Assert that this element exists.
     ///
     /// Panics if the element is not found.
@@ -368,8 +546,8 @@ where
     }
 }
 
-/// Extract all text content from a layout tree.
-fn extract_text_from_layout(layout: &LayoutTree) -> Vec<String> {
+/// Extract all text content from a layout tree and its popup overlay.
+fn extract_text_from_layout(layout: &LayoutTree, overlay: &LayoutOverlay) -> Vec<String> {
     fn collect_text(node: &cranpose_ui::LayoutBox, results: &mut Vec<String>) {
         if let Some(text) = node.node_data.modifier_slices().text_content() {
             results.push(text.to_string());
@@ -381,11 +559,17 @@ fn extract_text_from_layout(layout: &LayoutTree) -> Vec<String> {
 
     let mut results = Vec::new();
     collect_text(layout.root(), &mut results);
+    for layer in overlay.layers() {
+        collect_text(layer, &mut results);
+    }
     results
 }
 
-/// Extract all rectangles with optional text from a layout tree.
-fn extract_rects_from_layout(layout: &LayoutTree) -> Vec<(Rect, Option<String>)> {
+/// Extract all rectangles with optional text from a layout tree and its popup overlay.
+fn extract_rects_from_layout(
+    layout: &LayoutTree,
+    overlay: &LayoutOverlay,
+) -> Vec<(Rect, Option<String>)> {
     fn collect_rects(node: &cranpose_ui::LayoutBox, results: &mut Vec<(Rect, Option<String>)>) {
         // Get the text content if present in modifier slices
         let text = node
@@ -412,6 +596,9 @@ fn extract_rects_from_layout(layout: &LayoutTree) -> Vec<(Rect, Option<String>)>
 
     let mut results = Vec::new();
     collect_rects(layout.root(), &mut results);
+    for layer in overlay.layers() {
+        collect_rects(layer, &mut results);
+    }
     results
 }
 
@@ -439,6 +626,7 @@ impl Renderer for TestRenderer {
     fn rebuild_scene(
         &mut self,
         _layout_tree: &LayoutTree,
+        _overlay: &LayoutOverlay,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         Ok(())
@@ -522,4 +710,247 @@ mod tests {
         // Should not panic
         robot.drag(0.0, 0.0, 100.0, 100.0);
     }
+
+    #[test]
+    fn test_robot_swipe_scrolls_lazy_column() {
+        use cranpose_foundation::lazy::{remember_lazy_list_state, LazyListScope};
+        use cranpose_render_pixels::PixelsRenderer;
+        use cranpose_ui::widgets::{LazyColumn, LazyColumnSpec, Text};
+        use cranpose_ui::{
+            last_fling_velocity, reset_last_fling_velocity, LazyListState, Modifier,
+        };
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let list_state: Rc<Cell<Option<LazyListState>>> = Rc::new(Cell::new(None));
+        let list_state_for_content = Rc::clone(&list_state);
+        let mut robot = RobotTestRule::new(400, 600, PixelsRenderer::new(), move || {
+            let state = remember_lazy_list_state();
+            list_state_for_content.set(Some(state));
+            LazyColumn(
+                Modifier::empty().fill_max_size(),
+                state,
+                LazyColumnSpec::default(),
+                |scope| {
+                    scope.items(
+                        100,
+                        None::<fn(usize) -> u64>,
+                        None::<fn(usize) -> u64>,
+                        |i| {
+                            Text(format!("Item {}", i), Modifier::empty().height(50.0));
+                        },
+                    );
+                },
+            );
+        });
+        robot.wait_for_idle();
+        let state = list_state.get().expect("lazy list state captured");
+        assert_eq!(state.first_visible_item_index(), 0);
+
+        reset_last_fling_velocity();
+        robot.swipe(
+            Point { x: 200.0, y: 500.0 },
+            Point { x: 200.0, y: 100.0 },
+            Duration::from_millis(100),
+        );
+
+        assert!(
+            state.first_visible_item_index() > 0,
+            "swipe up should scroll the list, first visible item is still {}",
+            state.first_visible_item_index()
+        );
+        assert!(
+            last_fling_velocity() < 0.0,
+            "upward swipe should produce negative velocity, got {}",
+            last_fling_velocity()
+        );
+    }
+
+    #[test]
+    fn test_robot_asserts_explicit_role_and_state_description() {
+        use cranpose_foundation::Role;
+        use cranpose_ui::widgets::Text;
+        use cranpose_ui::{Column, ColumnSpec, Modifier};
+
+        let mut robot = create_headless_robot_test(400, 300, || {
+            Column(Modifier::empty(), ColumnSpec::default(), || {
+                Text("Title", Modifier::empty());
+                Text(
+                    "Wi-Fi",
+                    Modifier::empty().semantics(|config| {
+                        // The explicit role wins over the derived button role.
+                        config.is_button = true;
+                        config.role = Some(Role::Switch);
+                        config.state_description = Some("On".into());
+                    }),
+                );
+            });
+        });
+
+        let mut switch = robot.find_by_text("Wi-Fi");
+        switch.assert_role(SemanticsRole::Switch);
+        switch.assert_state_description("On");
+
+        let mut title = robot.find_by_text("Title");
+        assert!(matches!(title.role(), Some(SemanticsRole::Text { .. })));
+        assert_eq!(title.state_description(), None);
+    }
+
+    #[test]
+    fn test_robot_drives_tagged_nodes_through_their_semantics_actions() {
+        use cranpose_foundation::FocusState;
+        use cranpose_render_pixels::PixelsRenderer;
+        use cranpose_ui::widgets::Text;
+        use cranpose_ui::{Box, BoxSpec, Column, ColumnSpec, Modifier, ScrollState};
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let scroll: Rc<RefCell<Option<ScrollState>>> = Rc::default();
+        let clicks = Rc::new(Cell::new(0));
+        let focus_changes: Rc<RefCell<Vec<(&str, FocusState)>>> = Rc::default();
+        let mut robot = RobotTestRule::new(400, 400, PixelsRenderer::new(), {
+            let scroll = Rc::clone(&scroll);
+            let clicks = Rc::clone(&clicks);
+            let focus_changes = Rc::clone(&focus_changes);
+            move || {
+                let state = cranpose_core::remember(|| ScrollState::new(0.0)).with(Clone::clone);
+                *scroll.borrow_mut() = Some(state.clone());
+                let clicks = Rc::clone(&clicks);
+                let focus_changes = Rc::clone(&focus_changes);
+                Column(Modifier::empty(), ColumnSpec::default(), move || {
+                    Text("Title", Modifier::empty().test_tag("title"));
+                    let clicks = Rc::clone(&clicks);
+                    Box(
+                        Modifier::empty()
+                            .size_points(80.0, 40.0)
+                            .clickable(move |_| clicks.set(clicks.get() + 1))
+                            .test_tag("button"),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    for tag in ["field", "other"] {
+                        let focus_changes = Rc::clone(&focus_changes);
+                        Box(
+                            Modifier::empty()
+                                .size_points(80.0, 40.0)
+                                .focusable(true)
+                                .on_focus_changed(move |state| {
+                                    focus_changes.borrow_mut().push((tag, state))
+                                })
+                                .test_tag(tag),
+                            BoxSpec::default(),
+                            || {},
+                        );
+                    }
+                    Column(
+                        Modifier::empty()
+                            .height(100.0)
+                            .vertical_scroll(state.clone(), false)
+                            .test_tag("list"),
+                        ColumnSpec::default(),
+                        || {
+                            for _ in 0..6 {
+                                Box(
+                                    Modifier::empty().size_points(50.0, 50.0),
+                                    BoxSpec::default(),
+                                    || {},
+                                );
+                            }
+                        },
+                    );
+                });
+            }
+        });
+        robot.wait_for_idle();
+
+        assert_eq!(robot.find_by_tag("list").scroll_by(120.0), Some(120.0));
+        let state = scroll.borrow().clone().expect("scroll state");
+        assert_eq!(state.value(), 120.0);
+        // The content is 300 tall in a 100 tall viewport.
+        assert_eq!(robot.find_by_tag("list").scroll_by(200.0), Some(80.0));
+
+        assert!(robot.find_by_tag("button").click());
+        assert_eq!(clicks.get(), 1);
+
+        let field = robot
+            .find_by_tag("field")
+            .semantics_node()
+            .expect("tagged field");
+        assert!(robot.find_by_tag("field").focus());
+        assert_eq!(cranpose_ui::active_focus_target(), Some(field.node_id));
+        assert!(robot.find_by_tag("other").focus());
+        assert_eq!(
+            *focus_changes.borrow(),
+            vec![
+                ("field", FocusState::Active),
+                ("other", FocusState::Active),
+                ("field", FocusState::Inactive),
+            ]
+        );
+        cranpose_ui::set_active_focus_target(None);
+
+        // Tagged nodes without interaction modifiers expose no actions.
+        let mut title = robot.find_by_tag("title");
+        assert!(title.exists());
+        assert!(title.semantics_node().expect("title").actions.is_empty());
+        assert!(!title.click());
+        assert_eq!(title.scroll_by(10.0), None);
+        assert!(!title.focus());
+        assert!(!robot.find_by_tag("missing").exists());
+    }
+
+    #[test]
+    fn test_lazy_column_scroll_to_index_semantics_action() {
+        use cranpose_foundation::lazy::{remember_lazy_list_state, LazyListScope};
+        use cranpose_ui::widgets::{LazyColumn, LazyColumnSpec, Text};
+        use cranpose_ui::{LazyListState, Modifier, SemanticsAction, SemanticsNode};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        fn find_scroll_to_index(
+            node: &SemanticsNode,
+        ) -> Option<cranpose_foundation::SemanticsActionHandler<usize>> {
+            node.actions
+                .iter()
+                .find_map(|action| match action {
+                    SemanticsAction::ScrollToIndex { handler } => Some(handler.clone()),
+                    _ => None,
+                })
+                .or_else(|| node.children.iter().find_map(find_scroll_to_index))
+        }
+
+        let list_state: Rc<Cell<Option<LazyListState>>> = Rc::new(Cell::new(None));
+        let list_state_for_content = Rc::clone(&list_state);
+        let mut robot = create_headless_robot_test(400, 600, move || {
+            let state = remember_lazy_list_state();
+            list_state_for_content.set(Some(state));
+            LazyColumn(
+                Modifier::empty().fill_max_size(),
+                state,
+                LazyColumnSpec::default(),
+                |scope| {
+                    scope.items(
+                        100,
+                        None::<fn(usize) -> u64>,
+                        None::<fn(usize) -> u64>,
+                        |i| {
+                            Text(format!("Item {}", i), Modifier::empty().height(50.0));
+                        },
+                    );
+                },
+            );
+        });
+        robot.wait_for_idle();
+
+        let scroll_to_index = robot
+            .shell_mut()
+            .semantics_tree()
+            .and_then(|tree| find_scroll_to_index(tree.root()))
+            .expect("lazy column exposes scroll-to-index action");
+        scroll_to_index.invoke(42);
+        robot.wait_for_idle();
+
+        let state = list_state.get().expect("lazy list state captured");
+        assert_eq!(state.first_visible_item_index(), 42);
+    }
 }
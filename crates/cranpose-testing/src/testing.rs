@@ -65,13 +65,15 @@ impl ComposeTestRule {
                 panic!("pump_until_idle looped too many times!");
             }
 
-            if self.composition.should_render() {
+            let handle = self.composition.runtime_handle();
+            // Pending frame callbacks keep `should_render` set, but only
+            // `advance_frame` runs them; re-rendering would spin forever.
+            if self.composition.should_render() && !handle.has_frame_callbacks() {
                 eprintln!("pump_until_idle: should_render() is true");
                 self.render()?;
                 progressed = true;
             }
 
-            let handle = self.composition.runtime_handle();
             if handle.has_updates() {
                 eprintln!("pump_until_idle: has_updates() is true");
                 self.composition.flush_pending_node_updates()?;
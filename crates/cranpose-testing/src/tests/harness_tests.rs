@@ -0,0 +1,231 @@
+use super::*;
+use cranpose_core::{useState, NodeId};
+use cranpose_foundation::lazy::{remember_lazy_list_state, LazyListScope};
+use cranpose_foundation::text::TextFieldState;
+use cranpose_macros::composable;
+use cranpose_ui::widgets::{Button, LazyColumn, LazyColumnSpec, Text};
+use cranpose_ui::{
+    BasicTextField, Column, ColumnSpec, LazyListState, LocalSoftwareKeyboardController, Modifier,
+    SnapAnimation, SnapFlingBehavior, SoftwareKeyboardController,
+};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[composable]
+fn counter_screen() {
+    let count = useState(|| 0);
+    Column(Modifier::empty(), ColumnSpec::default(), move || {
+        Text(format!("Count: {}", count.value()), Modifier::empty());
+        Button(
+            Modifier::empty(),
+            move || count.update(|value| *value += 1),
+            || {
+                Text("Increment", Modifier::empty());
+            },
+        );
+    });
+}
+
+#[test]
+fn click_recomposes_and_relayouts_before_assertions() {
+    let mut harness = TestHarness::new(400.0, 300.0, counter_screen);
+
+    let label = harness.find("Count: ").expect("counter label");
+    assert_eq!(label.description.as_deref(), Some("Count: 0"));
+    let initial_width = harness
+        .layout_box(label.node_id)
+        .expect("label is laid out")
+        .rect
+        .width;
+
+    for _ in 0..10 {
+        assert!(harness.click("Increment"));
+    }
+
+    let label = harness.find("Count: ").expect("counter label");
+    assert_eq!(label.description.as_deref(), Some("Count: 10"));
+    let width = harness
+        .layout_box(label.node_id)
+        .expect("label is laid out")
+        .rect
+        .width;
+    assert!(
+        width > initial_width,
+        "longer label should be remeasured: {width} <= {initial_width}"
+    );
+}
+
+#[test]
+fn click_on_missing_node_reports_false() {
+    let mut harness = TestHarness::new(400.0, 300.0, counter_screen);
+
+    assert!(!harness.click("Decrement"));
+    assert_eq!(
+        harness.find("Count: ").and_then(|node| node.description),
+        Some("Count: 0".to_string())
+    );
+}
+
+const FRAME_NANOS: u64 = 16_000_000;
+
+fn snapping_list(state_slot: Rc<Cell<Option<LazyListState>>>) -> TestHarness {
+    TestHarness::new(400.0, 300.0, move || {
+        let state = remember_lazy_list_state();
+        state_slot.set(Some(state));
+        LazyColumn(
+            Modifier::empty().fill_max_size(),
+            state,
+            LazyColumnSpec::default().fling_behavior(SnapFlingBehavior::new()),
+            |scope| {
+                scope.items(
+                    100,
+                    None::<fn(usize) -> u64>,
+                    None::<fn(usize) -> u64>,
+                    |i| {
+                        Text(format!("Item {}", i), Modifier::empty().height(50.0));
+                    },
+                );
+            },
+        );
+    })
+}
+
+fn run_until_settled(harness: &mut TestHarness, snap: &SnapAnimation, time: &mut u64) {
+    for _ in 0..200 {
+        if !snap.is_running() {
+            break;
+        }
+        *time += FRAME_NANOS;
+        harness.advance_frame(*time);
+    }
+    assert!(!snap.is_running(), "snap animation should settle");
+    harness.run_frame();
+}
+
+#[test]
+fn snapping_list_settles_on_item_boundaries() {
+    let state_slot = Rc::new(Cell::new(None));
+    let mut harness = snapping_list(Rc::clone(&state_slot));
+    let state = state_slot.get().expect("lazy list state captured");
+    let behavior = SnapFlingBehavior::new();
+    let mut time = 0;
+
+    // Released 20px into item 1 without a fling: back to item 1.
+    state.scroll_to_item(1, 20.0);
+    harness.run_frame();
+    let snap = behavior
+        .perform_fling(state, 0.0)
+        .expect("list has items to snap to");
+    run_until_settled(&mut harness, &snap, &mut time);
+    assert_eq!(state.first_visible_item_index(), 1);
+    assert_eq!(state.first_visible_item_scroll_offset(), 0.0);
+
+    // Past the middle of item 1: on to item 2.
+    state.scroll_to_item(1, 30.0);
+    harness.run_frame();
+    let snap = behavior.perform_fling(state, 0.0).expect("snap starts");
+    run_until_settled(&mut harness, &snap, &mut time);
+    assert_eq!(state.first_visible_item_index(), 2);
+    assert_eq!(state.first_visible_item_scroll_offset(), 0.0);
+
+    // A fast fling skips several items and still lands on a boundary.
+    let snap = behavior.perform_fling(state, 3000.0).expect("snap starts");
+    run_until_settled(&mut harness, &snap, &mut time);
+    assert!(
+        state.first_visible_item_index() > 4,
+        "fast fling should skip items, settled on {}",
+        state.first_visible_item_index()
+    );
+    assert_eq!(state.first_visible_item_scroll_offset(), 0.0);
+}
+
+#[test]
+fn prefetched_items_are_composed_before_they_scroll_into_view() {
+    let state_slot = Rc::new(Cell::new(None));
+    let composed: Rc<RefCell<HashMap<usize, Vec<NodeId>>>> = Rc::default();
+    let mut harness = {
+        let state_slot = Rc::clone(&state_slot);
+        let composed = Rc::clone(&composed);
+        TestHarness::new(400.0, 300.0, move || {
+            let state = remember_lazy_list_state();
+            state_slot.set(Some(state));
+            let composed = Rc::clone(&composed);
+            LazyColumn(
+                Modifier::empty().fill_max_size(),
+                state,
+                LazyColumnSpec::default(),
+                move |scope| {
+                    let composed = Rc::clone(&composed);
+                    scope.items(
+                        100,
+                        None::<fn(usize) -> u64>,
+                        None::<fn(usize) -> u64>,
+                        move |i| {
+                            let node = Text(format!("Item {}", i), Modifier::empty().height(50.0));
+                            composed.borrow_mut().entry(i).or_default().push(node);
+                        },
+                    );
+                },
+            );
+        })
+    };
+    let state = state_slot.get().expect("lazy list state captured");
+
+    state.dispatch_scroll_delta(10.0);
+    harness.run_frame();
+
+    let (prefetched, node) = composed
+        .borrow()
+        .iter()
+        .filter_map(|(index, nodes)| Some((*index, *nodes.last()?)))
+        .filter(|(_, node)| harness.layout_box(*node).is_none())
+        .max_by_key(|(index, _)| *index)
+        .expect("an item below the viewport is prefetched");
+
+    state.scroll_to_item(prefetched, 0.0);
+    harness.run_frame();
+
+    assert!(
+        harness.layout_box(node).is_some(),
+        "item {prefetched} is placed with the nodes it was prefetched with"
+    );
+    assert!(composed.borrow()[&prefetched].iter().all(|id| *id == node));
+}
+
+#[derive(Default)]
+struct RecordingKeyboard {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+impl SoftwareKeyboardController for RecordingKeyboard {
+    fn show(&self) {
+        self.calls.borrow_mut().push("show");
+    }
+
+    fn hide(&self) {
+        self.calls.borrow_mut().push("hide");
+    }
+}
+
+#[test]
+fn focusing_a_text_field_shows_the_keyboard_until_focus_is_lost() {
+    let keyboard = Rc::new(RecordingKeyboard::default());
+    let provided: Rc<dyn SoftwareKeyboardController> = keyboard.clone();
+    let mut harness = TestHarness::new(400.0, 300.0, move || {
+        let state = cranpose_core::remember(|| TextFieldState::new("hello")).with(Clone::clone);
+        cranpose_core::CompositionLocalProvider(
+            [LocalSoftwareKeyboardController().provides(Some(provided.clone()))],
+            move || {
+                BasicTextField(state, Modifier::empty().size_points(200.0, 40.0));
+            },
+        );
+    });
+    assert!(keyboard.calls.borrow().is_empty());
+
+    harness.click_at(20.0, 20.0);
+    assert_eq!(*keyboard.calls.borrow(), ["show"]);
+
+    cranpose_ui::text_field_focus::clear_focus();
+    assert_eq!(*keyboard.calls.borrow(), ["show", "hide"]);
+}
@@ -196,15 +196,37 @@ impl RoundedCornerShape {
         Self { radii }
     }
 
+    /// Resolves the radii for a `width` x `height` box.
+    ///
+    /// Negative radii become zero. When adjacent corners would overlap along an edge,
+    /// all radii are scaled down by the same factor so the shape keeps its proportions.
     pub fn resolve(&self, width: f32, height: f32) -> CornerRadii {
-        let mut resolved = self.radii;
-        let max_width = (width / 2.0).max(0.0);
-        let max_height = (height / 2.0).max(0.0);
-        resolved.top_left = resolved.top_left.clamp(0.0, max_width).min(max_height);
-        resolved.top_right = resolved.top_right.clamp(0.0, max_width).min(max_height);
-        resolved.bottom_right = resolved.bottom_right.clamp(0.0, max_width).min(max_height);
-        resolved.bottom_left = resolved.bottom_left.clamp(0.0, max_width).min(max_height);
-        resolved
+        let radii = CornerRadii {
+            top_left: self.radii.top_left.max(0.0),
+            top_right: self.radii.top_right.max(0.0),
+            bottom_right: self.radii.bottom_right.max(0.0),
+            bottom_left: self.radii.bottom_left.max(0.0),
+        };
+        let width = width.max(0.0);
+        let height = height.max(0.0);
+        let fit = |extent: f32, a: f32, b: f32| {
+            let sum = a + b;
+            if sum > extent {
+                extent / sum
+            } else {
+                1.0
+            }
+        };
+        let scale = fit(width, radii.top_left, radii.top_right)
+            .min(fit(width, radii.bottom_left, radii.bottom_right))
+            .min(fit(height, radii.top_left, radii.bottom_left))
+            .min(fit(height, radii.top_right, radii.bottom_right));
+        CornerRadii {
+            top_left: radii.top_left * scale,
+            top_right: radii.top_right * scale,
+            bottom_right: radii.bottom_right * scale,
+            bottom_left: radii.bottom_left * scale,
+        }
     }
 
     pub fn radii(&self) -> CornerRadii {
@@ -242,6 +264,44 @@ pub enum DrawPrimitive {
         brush: Brush,
         radii: CornerRadii,
     },
+    /// Outline of a (rounded) rectangle, drawn inside `rect`.
+    ///
+    /// Zero `radii` stroke a plain rectangle. A `width` reaching the centre of
+    /// the shape fills it.
+    Stroke {
+        rect: Rect,
+        brush: Brush,
+        radii: CornerRadii,
+        width: f32,
+    },
+}
+
+impl DrawPrimitive {
+    /// The same primitive moved by `dx`, `dy`.
+    pub fn translate(&self, dx: f32, dy: f32) -> Self {
+        match self {
+            DrawPrimitive::Rect { rect, brush } => DrawPrimitive::Rect {
+                rect: rect.translate(dx, dy),
+                brush: brush.clone(),
+            },
+            DrawPrimitive::RoundRect { rect, brush, radii } => DrawPrimitive::RoundRect {
+                rect: rect.translate(dx, dy),
+                brush: brush.clone(),
+                radii: *radii,
+            },
+            DrawPrimitive::Stroke {
+                rect,
+                brush,
+                radii,
+                width,
+            } => DrawPrimitive::Stroke {
+                rect: rect.translate(dx, dy),
+                brush: brush.clone(),
+                radii: *radii,
+                width: *width,
+            },
+        }
+    }
 }
 
 pub trait DrawScope {
@@ -251,6 +311,10 @@ pub trait DrawScope {
     /// Draws a rectangle at the specified position and size.
     fn draw_rect_at(&mut self, rect: Rect, brush: Brush);
     fn draw_round_rect(&mut self, brush: Brush, radii: CornerRadii);
+    /// Strokes the outline of the whole draw area, `width` pixels inward.
+    fn stroke_rect(&mut self, brush: Brush, width: f32);
+    /// Strokes the outline of a rounded rectangle covering the draw area.
+    fn draw_round_rect_stroke(&mut self, brush: Brush, radii: CornerRadii, width: f32);
     fn into_primitives(self) -> Vec<DrawPrimitive>;
 }
 
@@ -295,6 +359,22 @@ impl DrawScope for DrawScopeDefault {
         });
     }
 
+    fn stroke_rect(&mut self, brush: Brush, width: f32) {
+        self.draw_round_rect_stroke(brush, CornerRadii::default(), width);
+    }
+
+    fn draw_round_rect_stroke(&mut self, brush: Brush, radii: CornerRadii, width: f32) {
+        if width <= 0.0 {
+            return;
+        }
+        self.primitives.push(DrawPrimitive::Stroke {
+            rect: Rect::from_size(self.size),
+            brush,
+            radii,
+            width,
+        });
+    }
+
     fn into_primitives(self) -> Vec<DrawPrimitive> {
         self.primitives
     }
@@ -1,5 +1,10 @@
 //! Typography data structures (font styles, weights, text styles)
 
+use crate::color::Color;
+
+/// Font size text is measured and rendered at when no style sets one.
+pub const DEFAULT_FONT_SIZE: f32 = 14.0;
+
 /// Font style (normal, italic, oblique)
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FontStyle {
@@ -28,9 +33,34 @@ impl FontWeight {
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct TextStyle {
     pub font_family: Option<String>,
+    /// Font size in sp; scaled by the density and the user's font scale.
     pub font_size: Option<f32>,
     pub font_weight: Option<FontWeight>,
     pub font_style: Option<FontStyle>,
     pub letter_spacing: Option<f32>,
     pub line_height: Option<f32>,
+    pub color: Option<Color>,
+}
+
+impl TextStyle {
+    /// Returns this style with every attribute set in `other` taking precedence.
+    pub fn merge(&self, other: &TextStyle) -> TextStyle {
+        TextStyle {
+            font_family: other
+                .font_family
+                .clone()
+                .or_else(|| self.font_family.clone()),
+            font_size: other.font_size.or(self.font_size),
+            font_weight: other.font_weight.or(self.font_weight),
+            font_style: other.font_style.or(self.font_style),
+            letter_spacing: other.letter_spacing.or(self.letter_spacing),
+            line_height: other.line_height.or(self.line_height),
+            color: other.color.or(self.color),
+        }
+    }
+
+    /// Font size relative to [`DEFAULT_FONT_SIZE`].
+    pub fn font_scale(&self) -> f32 {
+        self.font_size.map_or(1.0, |size| size / DEFAULT_FONT_SIZE)
+    }
 }
@@ -31,3 +31,39 @@ impl Sp {
 /// Raw pixels
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub struct Px(pub f32);
+
+/// Conversion factors between [`Dp`]/[`Sp`] and layout pixels.
+///
+/// `font_scale` is the user's text size preference and only applies to [`Sp`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Density {
+    pub density: f32,
+    pub font_scale: f32,
+}
+
+impl Density {
+    pub fn new(density: f32) -> Self {
+        Self {
+            density,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn with_font_scale(self, font_scale: f32) -> Self {
+        Self { font_scale, ..self }
+    }
+
+    pub fn dp_to_px(&self, dp: Dp) -> f32 {
+        dp.to_px(self.density)
+    }
+
+    pub fn sp_to_px(&self, sp: Sp) -> f32 {
+        sp.to_px(self.density, self.font_scale)
+    }
+}
+
+impl Default for Density {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
@@ -1,5 +1,7 @@
 //! Alignment utilities for positioning content
 
+use crate::core::AlignmentLine;
+
 /// Alignment across both axes used for positioning content within a box.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Alignment {
@@ -62,6 +64,10 @@ pub enum VerticalAlignment {
     CenterVertically,
     /// Align children to the bottom edge.
     Bottom,
+    /// Line children up by `AlignmentLine`, e.g. the baselines of texts in
+    /// different sizes. Only rows support it; children that do not report
+    /// the line align to the top edge, as does every child elsewhere.
+    AlignBy(AlignmentLine),
 }
 
 impl VerticalAlignment {
@@ -71,6 +77,7 @@ impl VerticalAlignment {
             VerticalAlignment::Top => 0.0,
             VerticalAlignment::CenterVertically => ((available - child) / 2.0).max(0.0),
             VerticalAlignment::Bottom => (available - child).max(0.0),
+            VerticalAlignment::AlignBy(_) => 0.0,
         }
     }
 }
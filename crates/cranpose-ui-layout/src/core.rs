@@ -1,5 +1,6 @@
 //! Core layout traits and types shared by Compose UI widgets.
 
+use crate::axis::Axis;
 use crate::constraints::Constraints;
 use cranpose_core::NodeId;
 use cranpose_ui_graphics::Size;
@@ -26,6 +27,120 @@ impl FlexParentData {
     }
 }
 
+/// A horizontal line a layout reports so that its parent can align children
+/// by it instead of by their edges.
+///
+/// Layouts that do not report a line themselves inherit it from their
+/// children, merged with the line's [`AlignmentLineMerge`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlignmentLine {
+    /// Baseline of the first line of text; the topmost one wins when merged.
+    FirstBaseline,
+    /// Baseline of the last line of text; the bottommost one wins when merged.
+    LastBaseline,
+    /// A line defined by the application, e.g. where the text of an input
+    /// field sits. Create it with [`AlignmentLine::custom`].
+    Custom {
+        name: &'static str,
+        merge: AlignmentLineMerge,
+    },
+}
+
+/// Which position a layout inherits when several children report a line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlignmentLineMerge {
+    /// The topmost position.
+    Min,
+    /// The bottommost position.
+    Max,
+}
+
+impl AlignmentLine {
+    /// A custom line called `name`, merged across children with `merge`.
+    pub const fn custom(name: &'static str, merge: AlignmentLineMerge) -> Self {
+        AlignmentLine::Custom { name, merge }
+    }
+
+    /// Combines two positions of this line reported by different children.
+    pub fn merge(&self, a: f32, b: f32) -> f32 {
+        let merge = match self {
+            AlignmentLine::FirstBaseline => AlignmentLineMerge::Min,
+            AlignmentLine::LastBaseline => AlignmentLineMerge::Max,
+            AlignmentLine::Custom { merge, .. } => *merge,
+        };
+        match merge {
+            AlignmentLineMerge::Min => a.min(b),
+            AlignmentLineMerge::Max => a.max(b),
+        }
+    }
+}
+
+/// Positions of the alignment lines a layout reports, from its top edge.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlignmentLines {
+    lines: Vec<(AlignmentLine, f32)>,
+}
+
+impl AlignmentLines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reports `line` at `position`, replacing an earlier position.
+    pub fn with(mut self, line: AlignmentLine, position: f32) -> Self {
+        self.set(line, position);
+        self
+    }
+
+    /// Position of `line`, or `None` if the layout does not report it.
+    pub fn get(&self, line: AlignmentLine) -> Option<f32> {
+        self.lines
+            .iter()
+            .find(|(reported, _)| *reported == line)
+            .map(|&(_, position)| position)
+    }
+
+    /// Reports `line` at `position`, replacing an earlier position.
+    pub fn set(&mut self, line: AlignmentLine, position: f32) {
+        match self
+            .lines
+            .iter_mut()
+            .find(|(reported, _)| *reported == line)
+        {
+            Some(entry) => entry.1 = position,
+            None => self.lines.push((line, position)),
+        }
+    }
+
+    /// Adds every line of a child placed `dy` lower, merging lines both
+    /// report.
+    pub fn merge_from(&mut self, child: &AlignmentLines, dy: f32) {
+        for &(line, position) in &child.lines {
+            let position = position + dy;
+            let merged = self
+                .get(line)
+                .map_or(position, |current| line.merge(current, position));
+            self.set(line, merged);
+        }
+    }
+
+    /// The same lines seen from a parent that places this layout `dy` lower.
+    pub fn offset(mut self, dy: f32) -> Self {
+        for (_, position) in &mut self.lines {
+            *position += dy;
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (AlignmentLine, f32)> + '_ {
+        self.lines.iter().copied()
+    }
+}
+
 /// Object capable of measuring a layout child and exposing intrinsic sizes.
 pub trait Measurable {
     /// Measures the child with the provided constraints, returning a [`Placeable`].
@@ -71,6 +186,17 @@ pub trait Placeable {
     fn content_offset(&self) -> (f32, f32) {
         (0.0, 0.0)
     }
+
+    /// Returns every alignment line the child reports, from its top edge.
+    fn alignment_lines(&self) -> AlignmentLines {
+        AlignmentLines::default()
+    }
+
+    /// Returns the position of `line` from the child's top edge, or `None`
+    /// if the child does not report it.
+    fn alignment_line(&self, line: AlignmentLine) -> Option<f32> {
+        self.alignment_lines().get(line)
+    }
 }
 
 /// Scope for measurement operations.
@@ -96,16 +222,46 @@ pub trait MeasurePolicy {
     ) -> MeasureResult;
 
     /// Computes the minimum intrinsic width of this policy.
-    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32;
+    ///
+    /// The intrinsic defaults treat children as stacked on top of each other
+    /// and report the largest child. Override them for policies that place
+    /// children side by side.
+    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        measurables
+            .iter()
+            .map(|m| m.min_intrinsic_width(height))
+            .fold(0.0, f32::max)
+    }
 
     /// Computes the maximum intrinsic width of this policy.
-    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32;
+    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        measurables
+            .iter()
+            .map(|m| m.max_intrinsic_width(height))
+            .fold(0.0, f32::max)
+    }
 
     /// Computes the minimum intrinsic height of this policy.
-    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32;
+    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        measurables
+            .iter()
+            .map(|m| m.min_intrinsic_height(width))
+            .fold(0.0, f32::max)
+    }
 
     /// Computes the maximum intrinsic height of this policy.
-    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32;
+    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        measurables
+            .iter()
+            .map(|m| m.max_intrinsic_height(width))
+            .fold(0.0, f32::max)
+    }
+
+    /// The axis along which this policy shares space between weighted
+    /// children, or `None` if it ignores weights.
+    fn weight_axis(&self) -> Option<Axis> {
+        None
+    }
 }
 
 /// Result of a measurement operation.
@@ -113,11 +269,23 @@ pub trait MeasurePolicy {
 pub struct MeasureResult {
     pub size: Size,
     pub placements: Vec<Placement>,
+    /// Lines the layout reports itself; others are inherited from children.
+    pub alignment_lines: AlignmentLines,
 }
 
 impl MeasureResult {
     pub fn new(size: Size, placements: Vec<Placement>) -> Self {
-        Self { size, placements }
+        Self {
+            size,
+            placements,
+            alignment_lines: AlignmentLines::default(),
+        }
+    }
+
+    /// Reports `alignment_lines` for the measured layout.
+    pub fn with_alignment_lines(mut self, alignment_lines: AlignmentLines) -> Self {
+        self.alignment_lines = alignment_lines;
+        self
     }
 }
 
@@ -146,7 +314,7 @@ impl Placement {
 /// Unlike `MeasureResult` which is for `MeasurePolicy` (multiple children),
 /// this type is specifically for layout modifiers which wrap a single piece
 /// of content and need to specify where that wrapped content should be placed.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct LayoutModifierMeasureResult {
     /// The size this modifier will occupy.
     pub size: Size,
@@ -156,6 +324,9 @@ pub struct LayoutModifierMeasureResult {
     /// to offset the child by the padding amount.
     pub placement_offset_x: f32,
     pub placement_offset_y: f32,
+    /// Alignment lines of this modifier's bounds. Left empty, the lines of
+    /// the wrapped content apply, moved by the placement offset.
+    pub alignment_lines: AlignmentLines,
 }
 
 impl LayoutModifierMeasureResult {
@@ -164,6 +335,7 @@ impl LayoutModifierMeasureResult {
             size,
             placement_offset_x,
             placement_offset_y,
+            alignment_lines: AlignmentLines::default(),
         }
     }
 
@@ -173,6 +345,13 @@ impl LayoutModifierMeasureResult {
             size,
             placement_offset_x: 0.0,
             placement_offset_y: 0.0,
+            alignment_lines: AlignmentLines::default(),
         }
     }
+
+    /// Reports `alignment_lines` for this modifier's bounds.
+    pub fn with_alignment_lines(mut self, alignment_lines: AlignmentLines) -> Self {
+        self.alignment_lines = alignment_lines;
+        self
+    }
 }
@@ -106,6 +106,48 @@ fn format_layout_box(output: &mut String, layout_box: &LayoutBox, depth: usize)
     }
 }
 
+/// Returns the layout tree with each node's kind, geometry and modifier summary.
+///
+/// Unlike [`format_layout_tree`], this has no banner lines, so tests can assert
+/// on exact lines.
+pub fn format_layout_tree_with_geometry(layout: &LayoutTree) -> String {
+    let mut output = String::new();
+    format_layout_box_with_geometry(&mut output, layout.root(), 0);
+    output
+}
+
+fn format_layout_box_with_geometry(output: &mut String, layout_box: &LayoutBox, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let rect = &layout_box.rect;
+
+    write!(
+        output,
+        "{}[Node #{}] {:?} pos: ({:.1}, {:.1}), size: ({:.1}x{:.1})",
+        indent,
+        layout_box.node_id,
+        layout_box.node_data.kind,
+        rect.x,
+        rect.y,
+        rect.width,
+        rect.height
+    )
+    .ok();
+    let offset = layout_box.content_offset;
+    if offset.x != 0.0 || offset.y != 0.0 {
+        write!(
+            output,
+            ", content offset: ({:.1}, {:.1})",
+            offset.x, offset.y
+        )
+        .ok();
+    }
+    writeln!(output, ", modifiers: {}", layout_box.node_data.modifier).ok();
+
+    for child in &layout_box.children {
+        format_layout_box_with_geometry(output, child, depth + 1);
+    }
+}
+
 /// Returns a formatted string representation of the render scene
 pub fn format_render_scene(scene: &RecordedRenderScene) -> String {
     let mut output = String::new();
@@ -166,7 +208,7 @@ pub fn log_screen_summary(layout: &LayoutTree, scene: &RecordedRenderScene) {
 }
 
 fn count_nodes(layout_box: &LayoutBox) -> usize {
-    1 + layout_box.children.iter().map(count_nodes).sum::<usize>()
+    1 + layout_box.children().map(count_nodes).sum::<usize>()
 }
 
 /// Logs the contents of a modifier node chain including capabilities.
@@ -0,0 +1,197 @@
+//! Density composition local and the lengths modifier builders accept.
+//!
+//! Layout runs in logical pixels: the platform scale factor is applied by the
+//! renderer's root scale, so [`LocalDensity`] defaults to `1.0` and a [`Dp`]
+//! equals one layout pixel unless a subtree provides another density.
+//!
+//! Modifier nodes keep the [`LayoutLength`] they were built with. Each layout
+//! node records the [`LocalDensity`] it was composed under, and the measure
+//! pass makes that density current while it measures the node, so a [`Dp`] is
+//! only turned into pixels when it is measured.
+
+#![allow(non_snake_case)]
+
+use cranpose_core::{compositionLocalOf, CompositionLocal};
+use cranpose_ui_graphics::{Density, Dp, EdgeInsets};
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+thread_local! {
+    static LOCAL_DENSITY: CompositionLocal<Density> = compositionLocalOf(Density::default);
+    static MEASURE_DENSITY: Cell<Density> = Cell::new(Density::default());
+}
+
+/// Density used to resolve [`Dp`] and [`Sp`](cranpose_ui_graphics::Sp) values
+/// to layout pixels.
+///
+/// Mirrors Jetpack Compose's `LocalDensity`.
+pub fn LocalDensity() -> CompositionLocal<Density> {
+    LOCAL_DENSITY.with(Clone::clone)
+}
+
+/// Density provided to the current composition, or the default outside of it.
+pub(crate) fn current_local_density() -> Density {
+    LocalDensity().current_or_default()
+}
+
+/// Density of the layout node currently being measured.
+///
+/// Outside of a measure pass this is the default density.
+pub(crate) fn measure_density() -> Density {
+    MEASURE_DENSITY.with(Cell::get)
+}
+
+/// Runs `f` with `density` as the measure-pass density.
+///
+/// The previous density is restored afterwards, also when `f` unwinds, so
+/// nested nodes measured under their own density hand it back to the parent.
+pub(crate) fn with_measure_density<R>(density: Density, f: impl FnOnce() -> R) -> R {
+    struct Restore(Density);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            MEASURE_DENSITY.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(MEASURE_DENSITY.with(|current| current.replace(density)));
+    f()
+}
+
+/// A length as given to a modifier builder.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LayoutLength {
+    /// Layout pixels, used as they are.
+    Px(f32),
+    /// Density-independent pixels, scaled by the measure-pass density.
+    Dp(Dp),
+}
+
+impl LayoutLength {
+    /// Converts the length to layout pixels at `density`.
+    pub fn resolve(self, density: Density) -> f32 {
+        match self {
+            LayoutLength::Px(px) => px,
+            LayoutLength::Dp(dp) => density.dp_to_px(dp),
+        }
+    }
+
+    /// Converts the length to layout pixels at the measure-pass density.
+    pub(crate) fn to_px(self) -> f32 {
+        self.resolve(measure_density())
+    }
+
+    /// Clamps negative lengths to zero, keeping the unit.
+    pub(crate) fn non_negative(self) -> Self {
+        match self {
+            LayoutLength::Px(px) => LayoutLength::Px(px.max(0.0)),
+            LayoutLength::Dp(dp) => LayoutLength::Dp(Dp(dp.0.max(0.0))),
+        }
+    }
+}
+
+impl Default for LayoutLength {
+    fn default() -> Self {
+        LayoutLength::Px(0.0)
+    }
+}
+
+impl From<f32> for LayoutLength {
+    fn from(px: f32) -> Self {
+        LayoutLength::Px(px)
+    }
+}
+
+impl Hash for LayoutLength {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            LayoutLength::Px(px) => {
+                state.write_u8(0);
+                state.write_u32(px.to_bits());
+            }
+            LayoutLength::Dp(dp) => {
+                state.write_u8(1);
+                state.write_u32(dp.0.to_bits());
+            }
+        }
+    }
+}
+
+/// Pixels print as plain numbers and [`Dp`] with a `.dp` suffix, as in the
+/// inspector.
+impl fmt::Display for LayoutLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutLength::Px(px) => write!(f, "{px}"),
+            LayoutLength::Dp(dp) => write!(f, "{}.dp", dp.0),
+        }
+    }
+}
+
+/// Per-side [`LayoutLength`]s, resolved to [`EdgeInsets`] when measured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Hash)]
+pub(crate) struct LengthInsets {
+    pub(crate) left: LayoutLength,
+    pub(crate) top: LayoutLength,
+    pub(crate) right: LayoutLength,
+    pub(crate) bottom: LayoutLength,
+}
+
+impl LengthInsets {
+    pub(crate) fn resolve(&self, density: Density) -> EdgeInsets {
+        EdgeInsets::from_components(
+            self.left.resolve(density),
+            self.top.resolve(density),
+            self.right.resolve(density),
+            self.bottom.resolve(density),
+        )
+    }
+
+    pub(crate) fn non_negative(self) -> Self {
+        Self {
+            left: self.left.non_negative(),
+            top: self.top.non_negative(),
+            right: self.right.non_negative(),
+            bottom: self.bottom.non_negative(),
+        }
+    }
+}
+
+impl From<EdgeInsets> for LengthInsets {
+    fn from(insets: EdgeInsets) -> Self {
+        Self {
+            left: insets.left.into(),
+            top: insets.top.into(),
+            right: insets.right.into(),
+            bottom: insets.bottom.into(),
+        }
+    }
+}
+
+/// A length accepted by modifier builders.
+///
+/// Plain numbers are layout pixels; [`Dp`] is scaled by the [`LocalDensity`]
+/// of the node it ends up on, when that node is measured.
+pub trait Length: Copy {
+    fn to_layout_length(self) -> LayoutLength;
+}
+
+impl Length for f32 {
+    fn to_layout_length(self) -> LayoutLength {
+        LayoutLength::Px(self)
+    }
+}
+
+/// Lets unsuffixed float literals such as `padding(16.0)` infer a type.
+impl Length for f64 {
+    fn to_layout_length(self) -> LayoutLength {
+        LayoutLength::Px(self as f32)
+    }
+}
+
+impl Length for Dp {
+    fn to_layout_length(self) -> LayoutLength {
+        LayoutLength::Dp(self)
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Drives decay animation using the runtime's frame callback system.
 
-use cranpose_animation::{FloatDecayAnimationSpec, SplineBasedDecaySpec};
-use cranpose_core::{FrameCallbackRegistration, FrameClock, RuntimeHandle};
+use cranpose_animation::{
+    Animatable, AnimationType, FlingCalculator, FloatDecayAnimationSpec, SplineBasedDecaySpec,
+    SpringSpec,
+};
+use cranpose_core::{current_runtime_handle, FrameCallbackRegistration, FrameClock, RuntimeHandle};
+use cranpose_foundation::lazy::{LazyListLayoutInfo, LazyListState};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
@@ -239,6 +243,210 @@ impl Clone for FlingAnimation {
     }
 }
 
+// ============================================================================
+// Snapping
+// ============================================================================
+
+/// Spring that carries a snapping list onto its item boundary.
+const SNAP_SPRING: SpringSpec = SpringSpec {
+    damping_ratio: 1.0,
+    stiffness: 400.0,
+    velocity_threshold: 1.0,
+    position_threshold: 0.5,
+};
+
+/// Fling behavior that settles a lazy list with an item at its start, like a
+/// pager.
+///
+/// Where a regular fling would land is predicted first; the list then animates
+/// to the next item boundary past that point in the fling direction, so fast
+/// flings skip several items. A drag released without a fling returns to the
+/// nearest boundary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapFlingBehavior {
+    animation: AnimationType,
+}
+
+impl Default for SnapFlingBehavior {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SnapFlingBehavior {
+    pub fn new() -> Self {
+        Self {
+            animation: AnimationType::Spring(SNAP_SPRING),
+        }
+    }
+
+    /// Animation that moves the list onto the chosen boundary.
+    pub fn with_animation(mut self, animation: AnimationType) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Settles `state` on an item boundary after a fling of `velocity` px/sec,
+    /// where positive velocities scroll forward.
+    ///
+    /// Returns `None` when the list has no items or there is no runtime to
+    /// animate on.
+    pub fn perform_fling(&self, state: LazyListState, velocity: f32) -> Option<SnapAnimation> {
+        let runtime = current_runtime_handle()?;
+        let landing = if velocity.abs() < MIN_FLING_VELOCITY {
+            0.0
+        } else {
+            let calculator = FlingCalculator::new(DEFAULT_FLING_FRICTION, crate::current_density());
+            SplineBasedDecaySpec::with_calculator(calculator).fling_target(0.0, velocity)
+        };
+        let (target_index, distance) = snap_target(
+            &state.layout_info(),
+            state.average_item_size(),
+            landing,
+            velocity,
+        )?;
+        Some(SnapAnimation::start(
+            runtime,
+            state,
+            target_index,
+            distance,
+            velocity,
+            self.animation,
+        ))
+    }
+}
+
+/// The item to settle on and the scroll distance to its start.
+fn snap_target(
+    info: &LazyListLayoutInfo,
+    average_item_size: f32,
+    landing: f32,
+    velocity: f32,
+) -> Option<(usize, f32)> {
+    let last_index = info.total_items_count.checked_sub(1)?;
+    let first = info.visible_items_info.first()?;
+    let start = info.viewport_start_offset;
+    let pitch = match info.visible_items_info.get(1) {
+        Some(second) => second.offset - first.offset,
+        None => average_item_size,
+    }
+    .max(1.0);
+
+    // Scroll position in items, e.g. 2.5 halfway through the third item.
+    let position = first.index as f32 + (start - first.offset) / pitch;
+    let landing_position = position + landing / pitch;
+    let index = if velocity >= MIN_FLING_VELOCITY {
+        landing_position.ceil()
+    } else if velocity <= -MIN_FLING_VELOCITY {
+        landing_position.floor()
+    } else {
+        position.round()
+    };
+    let index = (index.max(0.0) as usize).min(last_index);
+
+    let distance = info
+        .visible_items_info
+        .iter()
+        .find(|item| item.index == index)
+        .map(|item| item.offset - start)
+        .unwrap_or((index as f32 - position) * pitch);
+    Some((index, distance))
+}
+
+/// A running [`SnapFlingBehavior`] animation.
+pub struct SnapAnimation {
+    state: Rc<SnapAnimationState>,
+}
+
+struct SnapAnimationState {
+    list: LazyListState,
+    animatable: RefCell<Animatable<f32>>,
+    target_index: usize,
+    /// Scroll distance to the target item's start.
+    distance: f32,
+    /// Part of `distance` already dispatched to the list.
+    applied: Cell<f32>,
+    is_running: Cell<bool>,
+    registration: RefCell<Option<FrameCallbackRegistration>>,
+}
+
+impl SnapAnimation {
+    fn start(
+        runtime: RuntimeHandle,
+        list: LazyListState,
+        target_index: usize,
+        distance: f32,
+        velocity: f32,
+        animation: AnimationType,
+    ) -> Self {
+        let mut animatable = Animatable::new(0.0, runtime.clone());
+        animatable.animateToWithVelocity(distance, animation, velocity);
+        let state = Rc::new(SnapAnimationState {
+            list,
+            animatable: RefCell::new(animatable),
+            target_index,
+            distance,
+            applied: Cell::new(0.0),
+            is_running: Cell::new(true),
+            registration: RefCell::new(None),
+        });
+        schedule_snap_frame(&state, runtime.frame_clock());
+        Self { state }
+    }
+
+    /// Stops the animation where it is.
+    pub fn cancel(&self) {
+        if self.state.is_running.replace(false) {
+            self.state.registration.borrow_mut().take();
+            let mut animatable = self.state.animatable.borrow_mut();
+            let value = animatable.state().get();
+            animatable.snapTo(value);
+        }
+    }
+
+    /// Returns true until the list has settled on its target item.
+    pub fn is_running(&self) -> bool {
+        self.state.is_running.get()
+    }
+}
+
+/// Moves the list along with the animatable for one frame.
+///
+/// The animatable registered its frame callback before this one, so its value
+/// is already up to date when this runs.
+fn schedule_snap_frame(state: &Rc<SnapAnimationState>, frame_clock: FrameClock) {
+    let weak = Rc::downgrade(state);
+    let next_clock = frame_clock.clone();
+    let registration = frame_clock.with_frame_nanos(move |_| {
+        let Some(state) = weak.upgrade() else {
+            return;
+        };
+        if !state.is_running.get() {
+            return;
+        }
+        let (value, velocity) = {
+            let animatable = state.animatable.borrow();
+            (animatable.state().get(), animatable.velocity())
+        };
+        if value == state.distance && velocity == 0.0 {
+            state.is_running.set(false);
+            // Land exactly on the item even if sizes off screen were
+            // estimates. A delta dispatched now would apply on top of it.
+            state.list.scroll_to_item(state.target_index, 0.0);
+            return;
+        }
+        let delta = value - state.applied.get();
+        if delta != 0.0 {
+            // Positive deltas scroll forward; `dispatch_scroll_delta` takes
+            // gesture deltas, hence the sign flip.
+            state.list.dispatch_scroll_delta(-delta);
+            state.applied.set(value);
+        }
+        schedule_snap_frame(&state, next_clock.clone());
+    });
+    *state.registration.borrow_mut() = Some(registration);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
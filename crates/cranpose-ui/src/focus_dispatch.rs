@@ -5,6 +5,7 @@
 //! reprocessing without forcing layout/draw passes.
 
 use cranpose_core::NodeId;
+use cranpose_foundation::FocusState;
 use std::cell::RefCell;
 use std::collections::HashSet;
 
@@ -91,11 +92,43 @@ pub fn has_pending_focus_invalidations() -> bool {
 /// Sets the currently active focus target.
 ///
 /// This mirrors Kotlin's `FocusOwner.activeFocusTargetNode` and allows
-/// the focus system to track which node currently has focus.
+/// the focus system to track which node currently has focus. When the target
+/// changes, both the old and new nodes are invalidated and a render is
+/// requested so focus-dependent drawing (such as focus rings) updates.
 pub fn set_active_focus_target(node_id: Option<NodeId>) {
-    FOCUS_INVALIDATION_MANAGER.with(|manager| {
-        manager.borrow_mut().set_active_focus_target(node_id);
+    let previous = FOCUS_INVALIDATION_MANAGER.with(|manager| {
+        let mut manager = manager.borrow_mut();
+        let previous = manager.active_focus_target();
+        manager.set_active_focus_target(node_id);
+        previous
     });
+    if previous == node_id {
+        return;
+    }
+    for id in previous.into_iter().chain(node_id) {
+        schedule_focus_invalidation(id);
+    }
+    crate::request_render_invalidation();
+}
+
+/// Moves focus to `node_id`, the way a focus request does.
+///
+/// `apply` moves the focus targets of a node to a [`FocusState`] and reports
+/// whether it has one that took it. `node_id` is activated first; only if that
+/// succeeds is the previously focused node deactivated and `node_id` made the
+/// active focus target.
+pub fn request_focus<F>(node_id: NodeId, mut apply: F) -> bool
+where
+    F: FnMut(NodeId, FocusState) -> bool,
+{
+    if !apply(node_id, FocusState::Active) {
+        return false;
+    }
+    if let Some(previous) = active_focus_target().filter(|&previous| previous != node_id) {
+        apply(previous, FocusState::Inactive);
+    }
+    set_active_focus_target(Some(node_id));
+    true
 }
 
 /// Returns the currently active focus target, if any.
@@ -164,6 +197,26 @@ mod tests {
         assert_eq!(active_focus_target(), None);
     }
 
+    #[test]
+    fn moving_focus_invalidates_old_and_new_target() {
+        set_active_focus_target(Some(7));
+        clear_focus_invalidations();
+
+        set_active_focus_target(Some(8));
+        let mut processed = Vec::new();
+        process_focus_invalidations(|node_id| processed.push(node_id));
+        processed.sort();
+        assert_eq!(processed, vec![7, 8]);
+
+        set_active_focus_target(Some(8));
+        assert!(!has_pending_focus_invalidations());
+
+        set_active_focus_target(None);
+        let mut processed = Vec::new();
+        process_focus_invalidations(|node_id| processed.push(node_id));
+        assert_eq!(processed, vec![8]);
+    }
+
     #[test]
     fn duplicate_invalidations_deduplicated() {
         clear_focus_invalidations();
@@ -0,0 +1,151 @@
+//! Interaction sources report press and hover events so visuals can react.
+//!
+//! Mirrors Jetpack Compose's `MutableInteractionSource`: a clickable emits
+//! [`Interaction`]s into an [`InteractionSource`], composables read
+//! [`InteractionSource::is_pressed`] / [`InteractionSource::is_hovered`] to
+//! restyle themselves, and effects such as ripples subscribe to the raw
+//! stream.
+
+use crate::modifier::Point;
+use cranpose_core::{mutableStateOf, MutableState};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// A single press or hover event.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interaction {
+    /// The pointer went down at `position`, in the node's local coordinates.
+    Press { position: Point },
+    /// The press ended in a click.
+    Release { position: Point },
+    /// The press ended without a click, e.g. the pointer was dragged away.
+    Cancel { position: Point },
+    /// The pointer moved over the node.
+    Enter,
+    /// The pointer left the node.
+    Exit,
+}
+
+type InteractionListener = Rc<dyn Fn(&Interaction)>;
+
+struct InteractionSourceInner {
+    pressed: MutableState<bool>,
+    hovered: MutableState<bool>,
+    listeners: RefCell<Vec<(u64, InteractionListener)>>,
+    next_listener_id: Cell<u64>,
+}
+
+/// Stream of [`Interaction`]s emitted by a component such as a clickable.
+///
+/// Cloning shares the same source. Create one per component with
+/// `rememberInteractionSource!()` so it survives recomposition.
+#[derive(Clone)]
+pub struct InteractionSource {
+    inner: Rc<InteractionSourceInner>,
+}
+
+impl InteractionSource {
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(InteractionSourceInner {
+                pressed: mutableStateOf(false),
+                hovered: mutableStateOf(false),
+                listeners: RefCell::new(Vec::new()),
+                next_listener_id: Cell::new(0),
+            }),
+        }
+    }
+
+    /// Whether a press is in progress. Reading this in composition
+    /// recomposes the reader when the press starts or ends.
+    pub fn is_pressed(&self) -> bool {
+        self.inner.pressed.get()
+    }
+
+    /// Whether the pointer is over the component. Reactive like
+    /// [`is_pressed`](Self::is_pressed).
+    pub fn is_hovered(&self) -> bool {
+        self.inner.hovered.get()
+    }
+
+    /// Record `interaction`, update the pressed and hovered states and notify
+    /// subscribers.
+    pub fn emit(&self, interaction: Interaction) {
+        let (state, value) = match interaction {
+            Interaction::Press { .. } => (&self.inner.pressed, true),
+            Interaction::Release { .. } | Interaction::Cancel { .. } => {
+                (&self.inner.pressed, false)
+            }
+            Interaction::Enter => (&self.inner.hovered, true),
+            Interaction::Exit => (&self.inner.hovered, false),
+        };
+        if state.get_non_reactive() != value {
+            state.set(value);
+        }
+
+        // Listeners may subscribe or unsubscribe while being notified.
+        let listeners: Vec<_> = self
+            .inner
+            .listeners
+            .borrow()
+            .iter()
+            .map(|(_, listener)| Rc::clone(listener))
+            .collect();
+        for listener in listeners {
+            listener(&interaction);
+        }
+    }
+
+    /// Call `listener` for every interaction emitted from now on, e.g. to
+    /// start and stop a ripple. Returns an id for [`unsubscribe`](Self::unsubscribe).
+    pub fn subscribe(&self, listener: impl Fn(&Interaction) + 'static) -> u64 {
+        let id = self.inner.next_listener_id.get();
+        self.inner.next_listener_id.set(id + 1);
+        self.inner
+            .listeners
+            .borrow_mut()
+            .push((id, Rc::new(listener)));
+        id
+    }
+
+    pub fn unsubscribe(&self, id: u64) {
+        self.inner
+            .listeners
+            .borrow_mut()
+            .retain(|(listener_id, _)| *listener_id != id);
+    }
+}
+
+impl Default for InteractionSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for InteractionSource {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl std::fmt::Debug for InteractionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InteractionSource")
+            .field("pressed", &self.inner.pressed.get_non_reactive())
+            .field("hovered", &self.inner.hovered.get_non_reactive())
+            .finish()
+    }
+}
+
+/// Remember an [`InteractionSource`] across recompositions.
+#[macro_export]
+macro_rules! rememberInteractionSource {
+    () => {
+        cranpose_core::remember($crate::interaction::InteractionSource::new)
+            .with(|source| source.clone())
+    };
+}
+
+#[cfg(test)]
+#[path = "tests/interaction_tests.rs"]
+mod tests;
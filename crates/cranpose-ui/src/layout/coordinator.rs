@@ -10,7 +10,7 @@
 
 use cranpose_core::NodeId;
 use cranpose_foundation::ModifierNodeContext;
-use cranpose_ui_layout::{Constraints, Measurable, Placeable};
+use cranpose_ui_layout::{AlignmentLine, AlignmentLines, Constraints, Measurable, Placeable};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
@@ -25,6 +25,10 @@ pub trait NodeCoordinator: Measurable {
     /// Returns the accumulated placement offset from this coordinator
     /// down through the wrapped chain (inner-most coordinator).
     fn total_content_offset(&self) -> Point;
+
+    /// Returns the alignment lines reported by the last measure pass, relative
+    /// to this coordinator's bounds.
+    fn alignment_lines(&self) -> AlignmentLines;
 }
 
 /// Coordinator that wraps a single LayoutModifierNode from the reconciled chain.
@@ -43,6 +47,8 @@ pub struct LayoutModifierCoordinator<'a> {
     /// The ACCUMULATED placement offset from this coordinator through the entire chain.
     /// This is local_offset + wrapped.total_content_offset(), stored for O(1) access.
     accumulated_offset: Cell<Point>,
+    /// Alignment lines from the last measure pass.
+    alignment_lines: RefCell<AlignmentLines>,
     /// Shared context for invalidation tracking.
     context: Rc<RefCell<LayoutNodeContext>>,
 }
@@ -60,6 +66,7 @@ impl<'a> LayoutModifierCoordinator<'a> {
             wrapped,
             measured_size: Cell::new(Size::default()),
             accumulated_offset: Cell::new(Point::default()),
+            alignment_lines: RefCell::new(AlignmentLines::default()),
             context,
         }
     }
@@ -70,6 +77,10 @@ impl<'a> NodeCoordinator for LayoutModifierCoordinator<'a> {
         // O(1): just return the pre-computed accumulated offset
         self.accumulated_offset.get()
     }
+
+    fn alignment_lines(&self) -> AlignmentLines {
+        self.alignment_lines.borrow().clone()
+    }
 }
 
 impl<'a> Measurable for LayoutModifierCoordinator<'a> {
@@ -105,12 +116,15 @@ impl<'a> Measurable for LayoutModifierCoordinator<'a> {
                 // Pass through the child's accumulated offset (stored from its measure())
                 let child_accumulated = self.wrapped.total_content_offset();
                 self.accumulated_offset.set(child_accumulated);
+                let alignment_lines = self.wrapped.alignment_lines();
+                *self.alignment_lines.borrow_mut() = alignment_lines.clone();
                 return Box::new(CoordinatorPlaceable {
                     size: Size {
                         width: placeable.width(),
                         height: placeable.height(),
                     },
                     content_offset: child_accumulated,
+                    alignment_lines,
                 });
             }
         };
@@ -135,9 +149,21 @@ impl<'a> Measurable for LayoutModifierCoordinator<'a> {
         };
         self.accumulated_offset.set(accumulated);
 
+        // Lines the node reports itself win; otherwise the wrapped content's
+        // lines move with it.
+        let alignment_lines = if result.alignment_lines.is_empty() {
+            self.wrapped
+                .alignment_lines()
+                .offset(result.placement_offset_y)
+        } else {
+            result.alignment_lines
+        };
+        *self.alignment_lines.borrow_mut() = alignment_lines.clone();
+
         Box::new(CoordinatorPlaceable {
             size: result.size,
             content_offset: accumulated,
+            alignment_lines,
         })
     }
 
@@ -182,8 +208,8 @@ impl<'a> Measurable for LayoutModifierCoordinator<'a> {
 ///
 /// This is analogous to Jetpack Compose's InnerNodeCoordinator.
 pub struct InnerCoordinator<'a> {
-    /// The measure policy to execute.
-    measure_policy: Rc<dyn MeasurePolicy>,
+    /// The measure policy to execute, borrowed from the node's snapshot.
+    measure_policy: &'a dyn MeasurePolicy,
     /// Child measurables.
     measurables: &'a [Box<dyn Measurable>],
     /// Measured size from last measure pass.
@@ -191,12 +217,14 @@ pub struct InnerCoordinator<'a> {
     /// Position relative to parent.
     /// Shared result holder to store the measure result for placement.
     result_holder: Rc<RefCell<Option<MeasureResult>>>,
+    /// Alignment lines the measure policy reported in the last measure pass.
+    alignment_lines: RefCell<AlignmentLines>,
 }
 
 impl<'a> InnerCoordinator<'a> {
     /// Creates a new inner coordinator with the given measure policy and children.
     pub fn new(
-        measure_policy: Rc<dyn MeasurePolicy>,
+        measure_policy: &'a dyn MeasurePolicy,
         measurables: &'a [Box<dyn Measurable>],
         result_holder: Rc<RefCell<Option<MeasureResult>>>,
     ) -> Self {
@@ -205,6 +233,7 @@ impl<'a> InnerCoordinator<'a> {
             measurables,
             measured_size: Cell::new(Size::ZERO),
             result_holder,
+            alignment_lines: RefCell::new(AlignmentLines::default()),
         }
     }
 }
@@ -213,6 +242,10 @@ impl<'a> NodeCoordinator for InnerCoordinator<'a> {
     fn total_content_offset(&self) -> Point {
         Point::default()
     }
+
+    fn alignment_lines(&self) -> AlignmentLines {
+        self.alignment_lines.borrow().clone()
+    }
 }
 
 impl<'a> Measurable for InnerCoordinator<'a> {
@@ -223,6 +256,8 @@ impl<'a> Measurable for InnerCoordinator<'a> {
         // Store measured size
         let size = result.size;
         self.measured_size.set(size);
+        let alignment_lines = result.alignment_lines.clone();
+        *self.alignment_lines.borrow_mut() = alignment_lines.clone();
 
         // Store the result in the shared holder for placement extraction
         *self.result_holder.borrow_mut() = Some(result);
@@ -231,6 +266,7 @@ impl<'a> Measurable for InnerCoordinator<'a> {
         Box::new(CoordinatorPlaceable {
             size,
             content_offset: Point::default(),
+            alignment_lines,
         })
     }
 
@@ -261,6 +297,8 @@ struct CoordinatorPlaceable {
     size: Size,
     /// Accumulated content offset (sum of all offsets from this coordinator down).
     content_offset: Point,
+    /// Alignment lines relative to this placeable's bounds.
+    alignment_lines: AlignmentLines,
 }
 
 impl Placeable for CoordinatorPlaceable {
@@ -283,4 +321,12 @@ impl Placeable for CoordinatorPlaceable {
     fn content_offset(&self) -> (f32, f32) {
         (self.content_offset.x, self.content_offset.y)
     }
+
+    fn alignment_lines(&self) -> AlignmentLines {
+        self.alignment_lines.clone()
+    }
+
+    fn alignment_line(&self, line: AlignmentLine) -> Option<f32> {
+        self.alignment_lines.get(line)
+    }
 }
@@ -28,12 +28,19 @@ use crate::modifier::{
     Modifier, ModifierNodeSlices, Point, Rect as GeometryRect, ResolvedModifiers, Size,
 };
 
+use crate::current_density;
+use crate::density::with_measure_density;
 use crate::subcompose_layout::SubcomposeLayoutNode;
-use crate::widgets::nodes::{IntrinsicKind, LayoutNode, LayoutNodeCacheHandles};
+use crate::widgets::nodes::{IntrinsicKind, LayoutNode, LayoutNodeCacheHandles, PopupPosition};
 use cranpose_foundation::InvalidationKind;
 use cranpose_foundation::ModifierNodeContext;
-use cranpose_foundation::{NodeCapabilities, SemanticsConfiguration};
-use cranpose_ui_layout::{Constraints, MeasurePolicy, MeasureResult};
+use cranpose_foundation::{
+    NodeCapabilities, Role, ScrollAxisRange, SemanticsActionHandler, SemanticsConfiguration,
+};
+use cranpose_ui_graphics::Density;
+use cranpose_ui_layout::{
+    AlignmentLine, AlignmentLines, Axis, Constraints, MeasurePolicy, MeasureResult,
+};
 
 /// Runtime context for modifier nodes during measurement.
 ///
@@ -208,7 +215,21 @@ impl SemanticsCallback {
 /// Semantics action exposed to the input system.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SemanticsAction {
-    Click { handler: SemanticsCallback },
+    Click {
+        handler: SemanticsCallback,
+    },
+    /// Scrolls by a delta in pixels along the node's scroll axis; returns the consumed delta.
+    ScrollBy {
+        handler: SemanticsActionHandler<f32, f32>,
+    },
+    /// Scrolls a lazy list to the given item index.
+    ScrollToIndex {
+        handler: SemanticsActionHandler<usize>,
+    },
+    /// Moves focus to the node; exposed by enabled focusable nodes.
+    RequestFocus {
+        handler: SemanticsCallback,
+    },
 }
 
 /// Semantic role describing how a node should participate in accessibility and hit testing.
@@ -220,11 +241,20 @@ pub enum SemanticsRole {
     /// Subcomposition boundary
     Subcompose,
     /// Text content (derived from TextNode for backward compatibility)
-    Text { value: String },
+    Text {
+        value: String,
+    },
     /// Spacer (non-interactive)
     Spacer,
-    /// Button (derived from is_button semantics flag)
+    /// Button (derived from is_button semantics flag or an explicit role)
     Button,
+    Checkbox,
+    Switch,
+    Slider,
+    Image,
+    Header,
+    /// Labeled container navigable as a unit (from `Role::Group`)
+    Group,
     /// Unknown or unspecified role
     Unknown,
 }
@@ -237,22 +267,30 @@ pub struct SemanticsNode {
     pub actions: Vec<SemanticsAction>,
     pub children: Vec<SemanticsNode>,
     pub description: Option<String>,
-}
-
-impl SemanticsNode {
-    fn new(
-        node_id: NodeId,
-        role: SemanticsRole,
-        actions: Vec<SemanticsAction>,
-        children: Vec<SemanticsNode>,
-        description: Option<String>,
-    ) -> Self {
-        Self {
-            node_id,
-            role,
-            actions,
-            children,
-            description,
+    /// Current state of the node, e.g. "Checked".
+    pub state_description: Option<String>,
+    /// Scroll position for scrollable containers.
+    pub scroll_axis_range: Option<ScrollAxisRange>,
+    /// `false` for disabled nodes, which expose no click action.
+    pub is_enabled: bool,
+    /// Whether the node takes part in focus traversal.
+    pub is_focusable: bool,
+    /// Reading order among siblings; children are sorted by it.
+    pub traversal_index: f32,
+    /// Tag set with [`Modifier::test_tag`](crate::Modifier::test_tag).
+    pub test_tag: Option<String>,
+}
+
+impl From<Role> for SemanticsRole {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Button => SemanticsRole::Button,
+            Role::Checkbox => SemanticsRole::Checkbox,
+            Role::Switch => SemanticsRole::Switch,
+            Role::Slider => SemanticsRole::Slider,
+            Role::Image => SemanticsRole::Image,
+            Role::Header => SemanticsRole::Header,
+            Role::Group => SemanticsRole::Group,
         }
     }
 }
@@ -271,6 +309,45 @@ impl SemanticsTree {
     pub fn root(&self) -> &SemanticsNode {
         &self.root
     }
+
+    /// Focusable, enabled nodes in traversal (depth-first) order, i.e. the
+    /// order Tab moves focus in. Disabled nodes are skipped.
+    pub fn focus_order(&self) -> Vec<NodeId> {
+        fn visit(node: &SemanticsNode, order: &mut Vec<NodeId>) {
+            if node.is_focusable && node.is_enabled {
+                order.push(node.node_id);
+            }
+            for child in &node.children {
+                visit(child, order);
+            }
+        }
+        let mut order = Vec::new();
+        visit(&self.root, &mut order);
+        order
+    }
+
+    /// The node with the given id, if it is part of the tree.
+    pub fn node(&self, node_id: NodeId) -> Option<&SemanticsNode> {
+        self.find(|node| node.node_id == node_id)
+    }
+
+    /// The first node in traversal order tagged `tag`.
+    pub fn find_by_tag(&self, tag: &str) -> Option<&SemanticsNode> {
+        self.find(|node| node.test_tag.as_deref() == Some(tag))
+    }
+
+    fn find(&self, matches: impl Fn(&SemanticsNode) -> bool) -> Option<&SemanticsNode> {
+        fn visit<'a>(
+            node: &'a SemanticsNode,
+            matches: &dyn Fn(&SemanticsNode) -> bool,
+        ) -> Option<&'a SemanticsNode> {
+            if matches(node) {
+                return Some(node);
+            }
+            node.children.iter().find_map(|child| visit(child, matches))
+        }
+        visit(&self.root, &matches)
+    }
 }
 
 /// Caches semantics configurations for layout nodes, similar to Jetpack Compose's SemanticsOwner.
@@ -329,6 +406,102 @@ impl LayoutTree {
     pub fn into_root(self) -> LayoutBox {
         self.root
     }
+
+    /// Formats the tree with each node's rect and modifier summary, one node per line.
+    pub fn dump_layout_tree_with_geometry(&self) -> String {
+        crate::debug::format_layout_tree_with_geometry(self)
+    }
+
+    /// Returns the topmost node whose rect contains `point`.
+    ///
+    /// Later siblings paint over earlier ones and children over their parent,
+    /// so they win where boxes overlap. A box that clips to its bounds hides
+    /// its subtree outside of them.
+    pub fn node_at(&self, point: Point) -> Option<NodeId> {
+        fn topmost(layout: &LayoutBox, point: Point) -> Option<NodeId> {
+            let inside = layout.rect.contains(point.x, point.y);
+            if !inside && layout.node_data.modifier_slices().clip_to_bounds() {
+                return None;
+            }
+            layout
+                .children
+                .iter()
+                .rev()
+                .find_map(|child| topmost(child, point))
+                .or_else(|| inside.then_some(layout.node_id))
+        }
+        topmost(&self.root, point)
+    }
+
+    /// Returns the bounds of `node`, if it is part of this tree.
+    pub fn node_rect(&self, node: NodeId) -> Option<GeometryRect> {
+        find_node_rect(&self.root, node)
+    }
+
+    /// Returns the ids from `node`'s parent up to the root, nearest first.
+    ///
+    /// Empty when `node` is the root or not part of this tree.
+    pub fn ancestors(&self, node: NodeId) -> Vec<NodeId> {
+        fn collect(layout: &LayoutBox, node: NodeId, path: &mut Vec<NodeId>) -> bool {
+            if layout.node_id == node {
+                return true;
+            }
+            if layout
+                .children
+                .iter()
+                .any(|child| collect(child, node, path))
+            {
+                path.push(layout.node_id);
+                return true;
+            }
+            false
+        }
+        let mut path = Vec::new();
+        collect(&self.root, node, &mut path);
+        path
+    }
+}
+
+/// Popup layers laid out apart from the main [`LayoutTree`], from
+/// bottom-most to top-most.
+///
+/// Each layer is positioned in root coordinates. The layers are drawn and
+/// hit-tested above the main tree, outside of any clip or transform of the
+/// node that anchors them.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutOverlay {
+    layers: Vec<LayoutBox>,
+}
+
+impl LayoutOverlay {
+    pub fn layers(&self) -> &[LayoutBox] {
+        &self.layers
+    }
+
+    pub fn layers_mut(&mut self) -> &mut [LayoutBox] {
+        &mut self.layers
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Returns the bounds of `node`, if it is part of a popup layer.
+    pub fn node_rect(&self, node: NodeId) -> Option<GeometryRect> {
+        self.layers
+            .iter()
+            .find_map(|layer| find_node_rect(layer, node))
+    }
+}
+
+fn find_node_rect(layout: &LayoutBox, node: NodeId) -> Option<GeometryRect> {
+    if layout.node_id == node {
+        return Some(layout.rect);
+    }
+    layout
+        .children
+        .iter()
+        .find_map(|child| find_node_rect(child, node))
 }
 
 /// Layout information for a single node.
@@ -339,7 +512,9 @@ pub struct LayoutBox {
     /// Content offset for scroll/inner transforms (applies to children, NOT this node's position)
     pub content_offset: Point,
     pub node_data: LayoutNodeData,
-    pub children: Vec<LayoutBox>,
+    /// Children are shared with the placement cache, so an unchanged subtree
+    /// is reused without copying it.
+    pub children: Vec<Rc<LayoutBox>>,
 }
 
 impl LayoutBox {
@@ -349,6 +524,24 @@ impl LayoutBox {
         content_offset: Point,
         node_data: LayoutNodeData,
         children: Vec<LayoutBox>,
+    ) -> Self {
+        Self::with_shared_children(
+            node_id,
+            rect,
+            content_offset,
+            node_data,
+            children.into_iter().map(Rc::new).collect(),
+        )
+    }
+
+    /// Like [`new`](Self::new), for children that are already shared, e.g.
+    /// with the placement cache.
+    pub fn with_shared_children(
+        node_id: NodeId,
+        rect: GeometryRect,
+        content_offset: Point,
+        node_data: LayoutNodeData,
+        children: Vec<Rc<LayoutBox>>,
     ) -> Self {
         Self {
             node_id,
@@ -358,6 +551,11 @@ impl LayoutBox {
             children,
         }
     }
+
+    /// The child boxes, in placement order.
+    pub fn children(&self) -> impl ExactSizeIterator<Item = &LayoutBox> {
+        self.children.iter().map(|child| &**child)
+    }
 }
 
 /// Snapshot of the data required to render a layout node.
@@ -438,14 +636,21 @@ pub struct LayoutMeasurements {
     root: Rc<MeasuredNode>,
     semantics: SemanticsTree,
     layout_tree: LayoutTree,
+    overlay: LayoutOverlay,
 }
 
 impl LayoutMeasurements {
-    fn new(root: Rc<MeasuredNode>, semantics: SemanticsTree, layout_tree: LayoutTree) -> Self {
+    fn new(
+        root: Rc<MeasuredNode>,
+        semantics: SemanticsTree,
+        layout_tree: LayoutTree,
+        overlay: LayoutOverlay,
+    ) -> Self {
         Self {
             root,
             semantics,
             layout_tree,
+            overlay,
         }
     }
 
@@ -463,6 +668,17 @@ impl LayoutMeasurements {
         self.layout_tree
     }
 
+    /// Popup layers, laid out apart from the [`LayoutTree`].
+    pub fn overlay(&self) -> &LayoutOverlay {
+        &self.overlay
+    }
+
+    /// Consumes the measurements, producing the [`LayoutTree`] and the
+    /// popup layers drawn above it.
+    pub fn into_layout_tree_and_overlay(self) -> (LayoutTree, LayoutOverlay) {
+        (self.layout_tree, self.overlay)
+    }
+
     /// Returns a borrowed [`LayoutTree`] for rendering.
     pub fn layout_tree(&self) -> LayoutTree {
         self.layout_tree.clone()
@@ -501,6 +717,19 @@ pub fn measure_layout(
     root: NodeId,
     max_size: Size,
 ) -> Result<LayoutMeasurements, NodeError> {
+    measure_layout_at_density(applier, root, max_size, current_density())
+}
+
+/// [`measure_layout`] at an explicit density rather than the current one.
+pub(crate) fn measure_layout_at_density(
+    applier: &mut MemoryApplier,
+    root: NodeId,
+    max_size: Size,
+    density: f32,
+) -> Result<LayoutMeasurements, NodeError> {
+    if let Some(runtime) = applier.runtime_handle() {
+        runtime.record_layout_pass();
+    }
     let constraints = Constraints {
         min_width: 0.0,
         max_width: max_size.width,
@@ -518,12 +747,17 @@ pub fn measure_layout(
     // Scroll operations bubble needs_layout to ancestors, but NOT needs_measure.
     // Using needs_layout here would wipe ALL caches on every scroll frame, causing
     // O(N) full remeasurement instead of O(changed nodes).
+    //
+    // A density change invalidates every cached size even when the
+    // constraints are identical, so it counts as needing measure.
     let (needs_remeasure, _needs_semantics, cached_epoch) = match applier
         .with_node::<LayoutNode, _>(root, |node| {
+            let cache = node.cache_handles();
+            let density_changed = cache.update_density(density);
             (
-                node.needs_measure(), // CORRECT: check needs_measure, not needs_layout
+                node.needs_measure() || density_changed, // CORRECT: check needs_measure, not needs_layout
                 node.needs_semantics(),
-                node.cache_handles().epoch(),
+                cache.epoch(),
             )
         }) {
         Ok(tuple) => tuple,
@@ -567,17 +801,21 @@ pub fn measure_layout(
     // on drop - this is safe because the handle always contains valid slots.
 
     let measured = builder.measure_node(root, normalize_constraints(constraints))?;
+    let popup_layers = builder.measure_popup_layers(&measured, constraints)?;
+    // Popups are read out in the semantics tree after the root's own
+    // children, even though they are laid out apart from it.
+    let with_popups = with_popup_layers(&measured, &popup_layers);
 
     // ---- Metadata ----------------------------------------------------------
     let metadata = {
         let mut applier_ref = applier_host.borrow_typed();
-        collect_runtime_metadata(&mut applier_ref, &measured)?
+        collect_runtime_metadata(&mut applier_ref, &with_popups)?
     };
 
     // ---- Semantics snapshot ------------------------------------------------
     let semantics_snapshot = {
         let mut applier_ref = applier_host.borrow_typed();
-        collect_semantics_snapshot(&mut applier_ref, &measured)?
+        collect_semantics_snapshot(&mut applier_ref, &with_popups)?
     };
 
     // Drop builder before guard - slots are already in the shared handle.
@@ -588,11 +826,24 @@ pub fn measure_layout(
     // `ApplierSlotGuard::drop` will restore everything when this function returns.
 
     // Build semantics and layout trees from `measured` + metadata + snapshot
-    let semantics_root = build_semantics_node(&measured, &metadata, &semantics_snapshot);
+    let semantics_root = build_semantics_node(&with_popups, &metadata, &semantics_snapshot);
     let semantics = SemanticsTree::new(semantics_root);
     let layout_tree = build_layout_tree_from_metadata(&measured, &metadata);
+    let overlay = LayoutOverlay {
+        layers: popup_layers
+            .iter()
+            .map(|layer| {
+                Rc::unwrap_or_clone(place_layout_box(&layer.node, layer.origin, &metadata))
+            })
+            .collect(),
+    };
 
-    Ok(LayoutMeasurements::new(measured, semantics, layout_tree))
+    Ok(LayoutMeasurements::new(
+        measured,
+        semantics,
+        layout_tree,
+        overlay,
+    ))
 }
 
 struct LayoutBuilder {
@@ -620,6 +871,57 @@ impl LayoutBuilder {
         LayoutBuilderState::measure_node(Rc::clone(&self.state), node_id, constraints)
     }
 
+    /// Measures every popup layer anchored inside `root` against `viewport`,
+    /// from bottom-most to top-most. Popups opened from inside a popup come
+    /// after the popup that anchors them.
+    fn measure_popup_layers(
+        &mut self,
+        root: &MeasuredNode,
+        viewport: Constraints,
+    ) -> Result<Vec<MeasuredPopupLayer>, NodeError> {
+        let mut pending = Vec::new();
+        collect_popup_anchors(root, Point::default(), &mut pending);
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let applier = Rc::clone(&self.state.borrow().applier);
+        let mut layers = Vec::new();
+        let mut next = 0;
+        while let Some(&(popup_id, anchor)) = pending.get(next) {
+            next += 1;
+            let position = match applier
+                .borrow_typed()
+                .with_node::<LayoutNode, _>(popup_id, |node| {
+                    node.popup_layer().map(|layer| layer.position)
+                }) {
+                Ok(Some(position)) => position,
+                Ok(None) | Err(NodeError::Missing { .. }) => continue,
+                Err(err) => return Err(err),
+            };
+            let (origin, constraints) = match position {
+                PopupPosition::Anchored(offset) => (
+                    Point {
+                        x: anchor.x + offset.x,
+                        y: anchor.y + offset.y,
+                    },
+                    viewport,
+                ),
+                PopupPosition::Viewport => (
+                    Point::default(),
+                    Constraints::tight(viewport.max_width, viewport.max_height),
+                ),
+            };
+            let measured = self.measure_node(popup_id, constraints)?;
+            collect_popup_anchors(&measured, origin, &mut pending);
+            layers.push(MeasuredPopupLayer {
+                node: measured,
+                origin,
+            });
+        }
+        Ok(layers)
+    }
+
     fn set_runtime_handle(&mut self, handle: Option<RuntimeHandle>) {
         self.state.borrow_mut().runtime_handle = handle;
     }
@@ -698,9 +1000,10 @@ impl LayoutBuilderState {
         }
 
         // Try LayoutNode (the primary modern path)
+        let cache_epoch = state_rc.borrow().cache_epoch;
         if let Some(result) = Self::try_with_applier_result(&state_rc, |applier| {
             match applier.with_node::<LayoutNode, _>(node_id, |layout_node| {
-                LayoutNodeSnapshot::from_layout_node(layout_node)
+                LayoutNodeSnapshot::take(layout_node, cache_epoch, constraints)
             }) {
                 Ok(snapshot) => Ok(Some(snapshot)),
                 Err(NodeError::TypeMismatch { .. }) | Err(NodeError::Missing { .. }) => Ok(None),
@@ -708,13 +1011,30 @@ impl LayoutBuilderState {
             }
         }) {
             // Applier was available, process the result
-            if let Some(snapshot) = result? {
-                return Self::measure_layout_node(
-                    Rc::clone(&state_rc),
-                    node_id,
-                    snapshot,
-                    constraints,
-                );
+            match result? {
+                Some(LayoutNodeMeasure::Cached(cached)) => return Ok(cached),
+                Some(LayoutNodeMeasure::Snapshot(snapshot)) => {
+                    // The node's Dp lengths resolve with the density it was
+                    // composed under.
+                    let measure = || {
+                        with_measure_density(snapshot.density, || {
+                            Self::measure_layout_node(
+                                Rc::clone(&state_rc),
+                                node_id,
+                                *snapshot,
+                                constraints,
+                            )
+                        })
+                    };
+                    // State the measure policy writes is committed after the
+                    // frame.
+                    let runtime_handle = state_rc.borrow().runtime_handle.clone();
+                    return match runtime_handle {
+                        Some(runtime_handle) => runtime_handle.with_layout_phase(measure),
+                        None => measure(),
+                    };
+                }
+                None => {}
             }
         }
         // If applier was busy (None) or snapshot was None, fall through to fallback
@@ -894,7 +1214,7 @@ impl LayoutBuilderState {
         state_rc: &Rc<RefCell<Self>>,
         node_id: NodeId,
         measurables: &[Box<dyn Measurable>],
-        measure_policy: &Rc<dyn MeasurePolicy>,
+        measure_policy: &dyn MeasurePolicy,
         constraints: Constraints,
     ) -> ModifierChainMeasurement {
         use cranpose_foundation::NodeCapabilities;
@@ -963,7 +1283,7 @@ impl LayoutBuilderState {
         let policy_result = Rc::new(RefCell::new(None));
         let inner_coordinator: Box<dyn NodeCoordinator + '_> =
             Box::new(coordinator::InnerCoordinator::new(
-                Rc::clone(measure_policy),
+                measure_policy,
                 measurables,
                 Rc::clone(&policy_result),
             ));
@@ -1000,6 +1320,10 @@ impl LayoutBuilderState {
             y: all_placement_offset.y - offset.y,
         };
 
+        // The node's own offset moves it after the parent aligned it, so its
+        // lines are reported from where the parent places it.
+        let alignment_lines = placeable.alignment_lines().offset(-offset.y);
+
         // offset was already extracted from OffsetNode above
 
         let placements = policy_result
@@ -1029,10 +1353,8 @@ impl LayoutBuilderState {
         }
 
         ModifierChainMeasurement {
-            result: MeasureResult {
-                size: final_size,
-                placements,
-            },
+            result: MeasureResult::new(final_size, placements)
+                .with_alignment_lines(alignment_lines),
             content_offset,
             offset,
         }
@@ -1050,63 +1372,49 @@ impl LayoutBuilderState {
         };
         let LayoutNodeSnapshot {
             resolved_modifiers,
+            density: _,
             measure_policy,
             children,
             cache,
-            needs_measure,
         } = snapshot;
-        cache.activate(cache_epoch);
         let layout_props = resolved_modifiers.layout_properties();
 
-        if needs_measure {
-            // Node has needs_measure=true
-        }
-
-        // Only check cache if not marked as needing measure.
-        // When needs_measure=true, we MUST re-run measure() even if constraints match,
-        // because something else changed (e.g., scroll offset, modifier state).
-        if !needs_measure {
-            // Check cache for current constraints
-            if let Some(cached) = cache.get_measurement(constraints) {
-                // Clear dirty flag after successful cache hit
-                Self::with_applier_result(&state_rc, |applier| {
-                    applier.with_node::<LayoutNode, _>(node_id, |node| {
-                        node.clear_needs_measure();
-                        node.clear_needs_layout();
-                    })
-                })
-                .ok();
-                return Ok(cached);
-            }
-        }
-
         let (runtime_handle, applier_host) = {
             let state = state_rc.borrow();
             (state.runtime_handle.clone(), Rc::clone(&state.applier))
         };
-
         let measure_handle = LayoutMeasureHandle::new(Rc::clone(&state_rc));
         let error = Rc::new(RefCell::new(None));
         let mut pools = VecPools::acquire(Rc::clone(&state_rc));
         let (measurables, records) = pools.parts();
+        let mut popups = Vec::new();
 
         for &child_id in children.iter() {
             let measured = Rc::new(RefCell::new(None));
             let position = Rc::new(RefCell::new(None));
             let cache_handles = {
                 let mut applier = applier_host.borrow_typed();
-                match applier
-                    .with_node::<LayoutNode, _>(child_id, |layout_node| layout_node.cache_handles())
-                {
+                match applier.with_node::<LayoutNode, _>(child_id, |layout_node| {
+                    (
+                        layout_node.cache_handles(),
+                        layout_node.popup_layer().is_some(),
+                    )
+                }) {
                     Ok(value) => Some(value),
-                    Err(NodeError::TypeMismatch { .. }) => Some(LayoutNodeCacheHandles::default()),
+                    Err(NodeError::TypeMismatch { .. }) => {
+                        Some((LayoutNodeCacheHandles::default(), false))
+                    }
                     Err(NodeError::Missing { .. }) => None,
                     Err(err) => return Err(err),
                 }
             };
-            let Some(cache_handles) = cache_handles else {
+            let Some((cache_handles, is_popup)) = cache_handles else {
                 continue;
             };
+            if is_popup {
+                popups.push(child_id);
+                continue;
+            }
             cache_handles.activate(cache_epoch);
 
             records.push((
@@ -1130,15 +1438,28 @@ impl LayoutBuilderState {
         }
 
         // Try to measure through the modifier node chain first.
+        // Weighted children share the space left by their siblings along the
+        // main axis, so that axis keeps its real bound instead of the relaxed one.
+        let weighted_axis = measure_policy.weight_axis().filter(|_| {
+            measurables.iter().any(|measurable| {
+                measurable
+                    .flex_parent_data()
+                    .is_some_and(|data| data.has_weight())
+            })
+        });
         let chain_constraints = Constraints {
             min_width: constraints.min_width,
-            max_width: if matches!(layout_props.width(), DimensionConstraint::Unspecified) {
+            max_width: if matches!(layout_props.width(), DimensionConstraint::Unspecified)
+                && weighted_axis != Some(Axis::Horizontal)
+            {
                 f32::INFINITY
             } else {
                 constraints.max_width
             },
             min_height: constraints.min_height,
-            max_height: if matches!(layout_props.height(), DimensionConstraint::Unspecified) {
+            max_height: if matches!(layout_props.height(), DimensionConstraint::Unspecified)
+                && weighted_axis != Some(Axis::Vertical)
+            {
                 f32::INFINITY
             } else {
                 constraints.max_height
@@ -1149,7 +1470,7 @@ impl LayoutBuilderState {
             &state_rc,
             node_id,
             measurables.as_slice(),
-            &measure_policy,
+            &*measure_policy,
             chain_constraints,
         );
 
@@ -1164,7 +1485,7 @@ impl LayoutBuilderState {
                 &state_rc,
                 node_id,
                 measurables.as_slice(),
-                &measure_policy,
+                &*measure_policy,
                 constraints,
             );
         }
@@ -1214,13 +1535,29 @@ impl LayoutBuilderState {
             }
         }
 
-        let measured = Rc::new(MeasuredNode::new(
+        // Lines the node does not report itself come from its children,
+        // merged where several report the same one.
+        let mut alignment_lines = policy_result.alignment_lines;
+        let mut inherited = AlignmentLines::default();
+        for child in &measured_children {
+            inherited.merge_from(&child.node.alignment_lines, child.offset.y);
+        }
+        for (line, position) in inherited.iter() {
+            if alignment_lines.get(line).is_none() {
+                alignment_lines.set(line, position);
+            }
+        }
+
+        let mut measured = MeasuredNode::new(
             node_id,
             Size { width, height },
             offset,
             content_offset,
             measured_children,
-        ));
+        );
+        measured.popups = popups;
+        measured.alignment_lines = alignment_lines;
+        let measured = Rc::new(measured);
 
         cache.store_measurement(constraints, Rc::clone(&measured));
 
@@ -1240,30 +1577,52 @@ impl LayoutBuilderState {
 /// Snapshot of a LayoutNode's data for measuring.
 /// This is a temporary copy used during the measure phase, not a live node.
 ///
-/// Note: We capture `needs_measure` here because it's checked during measure to enable
-/// selective measure optimization at the individual node level. Even if the tree is partially
-/// dirty (some nodes changed), clean nodes can skip measure and use cached results.
+/// Only nodes that actually run their measure policy are snapshotted: clean
+/// nodes are answered from their measurement cache while the applier is
+/// borrowed, so a partially dirty tree does not clone the policy and children
+/// of every clean node on every pass.
 struct LayoutNodeSnapshot {
     resolved_modifiers: ResolvedModifiers,
+    density: Density,
     measure_policy: Rc<dyn MeasurePolicy>,
     children: Vec<NodeId>,
     cache: LayoutNodeCacheHandles,
-    /// Whether this specific node needs to be measured (vs using cached measurement)
-    needs_measure: bool,
 }
 
 impl LayoutNodeSnapshot {
-    fn from_layout_node(node: &LayoutNode) -> Self {
-        Self {
+    /// Returns the cached measurement of a clean node, or a snapshot to
+    /// measure it with.
+    ///
+    /// A node marked as needing measure always gets a snapshot, even if its
+    /// constraints match a cached entry, because something else changed
+    /// (e.g. scroll offset, modifier state or a replaced measure policy).
+    fn take(node: &LayoutNode, cache_epoch: u64, constraints: Constraints) -> LayoutNodeMeasure {
+        let cache = node.cache_handles();
+        cache.activate(cache_epoch);
+        if !node.needs_measure() {
+            if let Some(cached) = cache.get_measurement(constraints) {
+                node.clear_needs_measure();
+                node.clear_needs_layout();
+                return LayoutNodeMeasure::Cached(cached);
+            }
+        }
+
+        LayoutNodeMeasure::Snapshot(Box::new(Self {
             resolved_modifiers: node.resolved_modifiers(),
+            density: node.density(),
             measure_policy: Rc::clone(&node.measure_policy),
             children: node.children.iter().copied().collect(),
-            cache: node.cache_handles(),
-            needs_measure: node.needs_measure(),
-        }
+            cache,
+        }))
     }
 }
 
+/// How [`LayoutBuilderState::measure_node`] resolves a layout node.
+enum LayoutNodeMeasure {
+    Cached(Rc<MeasuredNode>),
+    Snapshot(Box<LayoutNodeSnapshot>),
+}
+
 // Helper types for accessing subsets of LayoutBuilderState
 struct VecPools {
     state: Rc<RefCell<LayoutBuilderState>>,
@@ -1384,6 +1743,10 @@ pub(crate) struct MeasuredNode {
     /// Content offset for scroll/inner transforms (NOT node position)
     content_offset: Point,
     children: Vec<MeasuredChild>,
+    /// Children left out of this node's measurement because they root a popup layer.
+    popups: Vec<NodeId>,
+    /// Alignment lines relative to the node's bounds.
+    alignment_lines: AlignmentLines,
 }
 
 impl MeasuredNode {
@@ -1400,6 +1763,8 @@ impl MeasuredNode {
             offset,
             content_offset,
             children,
+            popups: Vec::new(),
+            alignment_lines: AlignmentLines::default(),
         }
     }
 }
@@ -1667,6 +2032,21 @@ impl Placeable for LayoutChildPlaceable {
     fn node_id(&self) -> NodeId {
         self.node_id
     }
+
+    fn alignment_lines(&self) -> AlignmentLines {
+        self.measured
+            .borrow()
+            .as_ref()
+            .map(|node| node.alignment_lines.clone())
+            .unwrap_or_default()
+    }
+
+    fn alignment_line(&self, line: AlignmentLine) -> Option<f32> {
+        self.measured
+            .borrow()
+            .as_ref()
+            .and_then(|node| node.alignment_lines.get(line))
+    }
 }
 
 fn measure_node_with_host(
@@ -1696,6 +2076,8 @@ struct RuntimeNodeMetadata {
     modifier_slices: ModifierNodeSlices,
     role: SemanticsRole,
     button_handler: Option<Rc<RefCell<dyn FnMut()>>>,
+    /// Cache handles of layout nodes, used to reuse their placed layout boxes.
+    cache: Option<LayoutNodeCacheHandles>,
 }
 
 impl Default for RuntimeNodeMetadata {
@@ -1706,10 +2088,28 @@ impl Default for RuntimeNodeMetadata {
             modifier_slices: ModifierNodeSlices::default(),
             role: SemanticsRole::Unknown,
             button_handler: None,
+            cache: None,
         }
     }
 }
 
+/// Records each popup anchored in `node`'s subtree together with the
+/// absolute top-left corner of the node that anchors it.
+fn collect_popup_anchors(node: &MeasuredNode, origin: Point, out: &mut Vec<(NodeId, Point)>) {
+    let top_left = Point {
+        x: origin.x + node.offset.x,
+        y: origin.y + node.offset.y,
+    };
+    out.extend(node.popups.iter().map(|&popup| (popup, top_left)));
+    for child in &node.children {
+        let child_origin = Point {
+            x: top_left.x + child.offset.x,
+            y: top_left.y + child.offset.y,
+        };
+        collect_popup_anchors(&child.node, child_origin, out);
+    }
+}
+
 fn collect_runtime_metadata(
     applier: &mut MemoryApplier,
     node: &MeasuredNode,
@@ -1813,6 +2213,7 @@ fn runtime_metadata_for(
             modifier_slices: layout.modifier_slices_snapshot(),
             role,
             button_handler: None,
+            cache: Some(layout.cache_handles()),
         }
     }) {
         return Ok(meta);
@@ -1831,6 +2232,7 @@ fn runtime_metadata_for(
             modifier_slices,
             role: SemanticsRole::Subcompose,
             button_handler: None,
+            cache: None,
         });
     }
     Ok(RuntimeNodeMetadata::default())
@@ -1867,6 +2269,11 @@ fn compute_semantics_for_node(
 /// Builds a semantics node from measured tree data and semantics configurations.
 /// Roles and actions are now derived entirely from SemanticsConfiguration, with
 /// metadata consulted only for legacy widget type information.
+///
+/// Nodes that merge descendants absorb the descriptions and actions of their
+/// subtree; nodes that clear semantics drop their subtree entirely. An explicit
+/// role in the configuration wins over a derived one, except that a group
+/// without a description keeps the plain container role.
 fn build_semantics_node(
     node: &MeasuredNode,
     metadata: &HashMap<NodeId, RuntimeNodeMetadata>,
@@ -1878,79 +2285,281 @@ fn build_semantics_node(
     let mut role = info.role.clone();
     let mut actions = Vec::new();
     let mut description = None;
+    let mut state_description = None;
+
+    let config = semantics.get(&node.node_id).cloned().flatten();
 
     // Override with semantics configuration if present
-    if let Some(config) = semantics.get(&node.node_id).cloned().flatten() {
+    if let Some(config) = &config {
         // Role synthesis: prefer semantics flags over widget type
         if config.is_button {
             role = SemanticsRole::Button;
         }
 
         // Action synthesis: create click action if node is clickable
-        if config.is_clickable {
+        if config.is_clickable && !config.is_disabled {
             actions.push(SemanticsAction::Click {
                 handler: SemanticsCallback::new(node.node_id),
             });
         }
 
+        if let Some(handler) = &config.scroll_by {
+            actions.push(SemanticsAction::ScrollBy {
+                handler: handler.clone(),
+            });
+        }
+        if let Some(handler) = &config.scroll_to_index {
+            actions.push(SemanticsAction::ScrollToIndex {
+                handler: handler.clone(),
+            });
+        }
+        if config.is_focusable && !config.is_disabled {
+            actions.push(SemanticsAction::RequestFocus {
+                handler: SemanticsCallback::new(node.node_id),
+            });
+        }
+
         // Description from configuration
-        if let Some(desc) = config.content_description {
-            description = Some(desc);
+        if let Some(desc) = &config.content_description {
+            description = Some(desc.clone());
         }
+        state_description = config.state_description.clone();
     }
 
-    let children = node
-        .children
-        .iter()
-        .map(|child| build_semantics_node(&child.node, metadata, semantics))
-        .collect();
+    let mut children = match &config {
+        Some(config) if config.is_clearing_semantics => Vec::new(),
+        Some(config) if config.is_merging_semantics_of_descendants => {
+            let mut merged = MergedDescendantSemantics::default();
+            merged.descriptions.extend(description.take());
+            merged.collect(node, metadata, semantics);
 
-    SemanticsNode::new(node.node_id, role, actions, children, description)
+            if !merged.descriptions.is_empty() {
+                description = Some(merged.descriptions.join(" "));
+            }
+            if merged.is_button {
+                role = SemanticsRole::Button;
+            }
+            if state_description.is_none() {
+                state_description = merged.state_description;
+            }
+            let has_click = actions
+                .iter()
+                .any(|action| matches!(action, SemanticsAction::Click { .. }));
+            if !has_click {
+                if let Some(source) = merged.click_source {
+                    actions.push(SemanticsAction::Click {
+                        handler: SemanticsCallback::new(source),
+                    });
+                }
+            }
+            merged.children
+        }
+        _ => node
+            .children
+            .iter()
+            .map(|child| build_semantics_node(&child.node, metadata, semantics))
+            .collect(),
+    };
+
+    // Stable sort: siblings with equal indices keep their layout order.
+    children.sort_by(|a, b| a.traversal_index.total_cmp(&b.traversal_index));
+
+    if let Some(explicit) = config.as_ref().and_then(|config| config.role) {
+        // An unlabeled group has nothing to announce and stays transparent.
+        if explicit != Role::Group || description.is_some() {
+            role = explicit.into();
+        }
+    }
+    let is_enabled = !config.as_ref().is_some_and(|config| config.is_disabled);
+    let is_focusable = config.as_ref().is_some_and(|config| config.is_focusable);
+    let traversal_index = config
+        .as_ref()
+        .and_then(|config| config.traversal_index)
+        .unwrap_or(0.0);
+    let test_tag = config.as_ref().and_then(|config| config.test_tag.clone());
+    let scroll_axis_range = config.and_then(|config| config.scroll_axis_range);
+    SemanticsNode {
+        node_id: node.node_id,
+        role,
+        actions,
+        children,
+        description,
+        state_description,
+        scroll_axis_range,
+        is_enabled,
+        is_focusable,
+        traversal_index,
+        test_tag,
+    }
+}
+
+/// Semantics gathered from the subtree of a node that merges its descendants.
+#[derive(Default)]
+struct MergedDescendantSemantics {
+    /// Descriptions in traversal order.
+    descriptions: Vec<String>,
+    is_button: bool,
+    /// First state description found, used when the merging node has none.
+    state_description: Option<String>,
+    /// First clickable descendant, used when the merging node has no click of its own.
+    click_source: Option<NodeId>,
+    /// Descendants that merge their own subtree stay separate semantics nodes.
+    children: Vec<SemanticsNode>,
+}
+
+impl MergedDescendantSemantics {
+    fn collect(
+        &mut self,
+        node: &MeasuredNode,
+        metadata: &HashMap<NodeId, RuntimeNodeMetadata>,
+        semantics: &HashMap<NodeId, Option<SemanticsConfiguration>>,
+    ) {
+        for child in &node.children {
+            let child_id = child.node.node_id;
+            let config = semantics.get(&child_id).cloned().flatten();
+            match config {
+                Some(config) if config.is_merging_semantics_of_descendants => {
+                    self.children
+                        .push(build_semantics_node(&child.node, metadata, semantics));
+                }
+                Some(config) => {
+                    self.descriptions.extend(config.content_description);
+                    self.is_button |= config.is_button;
+                    if self.state_description.is_none() {
+                        self.state_description = config.state_description;
+                    }
+                    if config.is_clickable && !config.is_disabled && self.click_source.is_none() {
+                        self.click_source = Some(child_id);
+                    }
+                    if !config.is_clearing_semantics {
+                        self.collect(&child.node, metadata, semantics);
+                    }
+                }
+                None => self.collect(&child.node, metadata, semantics),
+            }
+        }
+    }
+}
+
+/// A popup layer measured against the viewport, with the root-space
+/// position its node's own offset applies to.
+struct MeasuredPopupLayer {
+    node: Rc<MeasuredNode>,
+    origin: Point,
+}
+
+/// `root` with `layers` as extra children after its own, for the passes
+/// that walk popups as part of the tree.
+fn with_popup_layers(root: &Rc<MeasuredNode>, layers: &[MeasuredPopupLayer]) -> Rc<MeasuredNode> {
+    if layers.is_empty() {
+        return Rc::clone(root);
+    }
+    let mut with_layers = (**root).clone();
+    with_layers
+        .children
+        .extend(layers.iter().map(|layer| MeasuredChild {
+            node: Rc::clone(&layer.node),
+            offset: Point {
+                x: layer.origin.x - root.offset.x,
+                y: layer.origin.y - root.offset.y,
+            },
+        }));
+    Rc::new(with_layers)
 }
 
 fn build_layout_tree_from_metadata(
-    node: &MeasuredNode,
+    node: &Rc<MeasuredNode>,
     metadata: &HashMap<NodeId, RuntimeNodeMetadata>,
 ) -> LayoutTree {
-    fn place(
-        node: &MeasuredNode,
-        origin: Point,
-        metadata: &HashMap<NodeId, RuntimeNodeMetadata>,
-    ) -> LayoutBox {
-        // Include the node's own offset (from OffsetNode) in its position
-        let top_left = Point {
-            x: origin.x + node.offset.x,
-            y: origin.y + node.offset.y,
-        };
-        let rect = GeometryRect {
-            x: top_left.x,
-            y: top_left.y,
-            width: node.size.width,
-            height: node.size.height,
-        };
-        let info = metadata.get(&node.node_id).cloned().unwrap_or_default();
-        let kind = layout_kind_from_metadata(node.node_id, &info);
-        let data = LayoutNodeData::new(
-            info.modifier.clone(),
-            info.resolved_modifiers,
-            info.modifier_slices.clone(),
-            kind,
-        );
-        let children = node
-            .children
-            .iter()
-            .map(|child| {
-                let child_origin = Point {
-                    x: top_left.x + child.offset.x,
-                    y: top_left.y + child.offset.y,
-                };
-                place(&child.node, child_origin, metadata)
-            })
-            .collect();
-        LayoutBox::new(node.node_id, rect, node.content_offset, data, children)
+    let root = place_layout_box(node, Point { x: 0.0, y: 0.0 }, metadata);
+    LayoutTree::new(Rc::unwrap_or_clone(root))
+}
+
+/// Builds the layout box of `node` placed at `origin`, reusing the cached
+/// one when its subtree is unchanged.
+///
+/// Children are placed first, so deciding whether the cached box still
+/// fits only compares this node and the boxes of its direct children.
+fn place_layout_box(
+    node: &Rc<MeasuredNode>,
+    origin: Point,
+    metadata: &HashMap<NodeId, RuntimeNodeMetadata>,
+) -> Rc<LayoutBox> {
+    // Include the node's own offset (from OffsetNode) in its position
+    let top_left = Point {
+        x: origin.x + node.offset.x,
+        y: origin.y + node.offset.y,
+    };
+    let children: Vec<Rc<LayoutBox>> = node
+        .children
+        .iter()
+        .map(|child| {
+            let child_origin = Point {
+                x: top_left.x + child.offset.x,
+                y: top_left.y + child.offset.y,
+            };
+            place_layout_box(&child.node, child_origin, metadata)
+        })
+        .collect();
+
+    let cache = metadata
+        .get(&node.node_id)
+        .and_then(|info| info.cache.as_ref());
+    if let Some(layout) = cache.and_then(|cache| {
+        cache.get_placement(origin, |cached, layout| {
+            placement_reusable(cached, layout, node, &children)
+        })
+    }) {
+        return layout;
+    }
+
+    let rect = GeometryRect {
+        x: top_left.x,
+        y: top_left.y,
+        width: node.size.width,
+        height: node.size.height,
+    };
+    let info = metadata.get(&node.node_id).cloned().unwrap_or_default();
+    let kind = layout_kind_from_metadata(node.node_id, &info);
+    let data = LayoutNodeData::new(
+        info.modifier.clone(),
+        info.resolved_modifiers,
+        info.modifier_slices.clone(),
+        kind,
+    );
+    let layout = Rc::new(LayoutBox::with_shared_children(
+        node.node_id,
+        rect,
+        node.content_offset,
+        data,
+        children,
+    ));
+    if let Some(cache) = cache {
+        cache.store_placement(origin, Rc::clone(node), Rc::clone(&layout));
     }
+    layout
+}
 
-    LayoutTree::new(place(node, Point { x: 0.0, y: 0.0 }, metadata))
+/// Whether `layout`, built from the measurement `cached`, still describes
+/// `current` whose children were just placed as `children`: the node keeps
+/// its geometry and every child reused its own cached box. A child that was
+/// moved, resized, updated or asked to redraw got a new box.
+fn placement_reusable(
+    cached: &MeasuredNode,
+    layout: &LayoutBox,
+    current: &MeasuredNode,
+    children: &[Rc<LayoutBox>],
+) -> bool {
+    cached.node_id == current.node_id
+        && cached.size == current.size
+        && cached.offset == current.offset
+        && cached.content_offset == current.content_offset
+        && layout.children.len() == children.len()
+        && layout
+            .children
+            .iter()
+            .zip(children)
+            .all(|(cached, current)| Rc::ptr_eq(cached, current))
 }
 
 fn layout_kind_from_metadata(_node_id: NodeId, info: &RuntimeNodeMetadata) -> LayoutNodeKind {
@@ -1964,6 +2573,12 @@ fn layout_kind_from_metadata(_node_id: NodeId, info: &RuntimeNodeMetadata) -> La
             LayoutNodeKind::Layout
         }
         SemanticsRole::Spacer => LayoutNodeKind::Spacer,
+        SemanticsRole::Checkbox
+        | SemanticsRole::Switch
+        | SemanticsRole::Slider
+        | SemanticsRole::Image
+        | SemanticsRole::Header
+        | SemanticsRole::Group => LayoutNodeKind::Layout,
         SemanticsRole::Button => {
             let handler = info
                 .button_handler
@@ -2009,7 +2624,7 @@ pub(crate) fn align_horizontal(alignment: HorizontalAlignment, available: f32, c
 #[cfg(test)]
 pub(crate) fn align_vertical(alignment: VerticalAlignment, available: f32, child: f32) -> f32 {
     match alignment {
-        VerticalAlignment::Top => 0.0,
+        VerticalAlignment::Top | VerticalAlignment::AlignBy(_) => 0.0,
         VerticalAlignment::CenterVertically => ((available - child) / 2.0).max(0.0),
         VerticalAlignment::Bottom => (available - child).max(0.0),
     }
@@ -2,7 +2,7 @@ use crate::layout::core::{
     Alignment, Arrangement, HorizontalAlignment, LinearArrangement, Measurable, VerticalAlignment,
 };
 use cranpose_ui_layout::{
-    Axis, Constraints, FlexParentData, MeasurePolicy, MeasureResult, Placement,
+    AlignmentLine, Axis, Constraints, FlexParentData, MeasurePolicy, MeasureResult, Placement,
 };
 use smallvec::SmallVec;
 
@@ -65,7 +65,7 @@ impl MeasurePolicy for BoxMeasurePolicy {
             };
 
             let y = match self.content_alignment.vertical {
-                VerticalAlignment::Top => 0.0,
+                VerticalAlignment::Top | VerticalAlignment::AlignBy(_) => 0.0,
                 VerticalAlignment::CenterVertically => ((height - child_height) / 2.0).max(0.0),
                 VerticalAlignment::Bottom => (height - child_height).max(0.0),
             };
@@ -77,33 +77,7 @@ impl MeasurePolicy for BoxMeasurePolicy {
         MeasureResult::new(crate::modifier::Size { width, height }, placements)
     }
 
-    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
-        measurables
-            .iter()
-            .map(|m| m.min_intrinsic_width(height))
-            .fold(0.0, f32::max)
-    }
-
-    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
-        measurables
-            .iter()
-            .map(|m| m.max_intrinsic_width(height))
-            .fold(0.0, f32::max)
-    }
-
-    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
-        measurables
-            .iter()
-            .map(|m| m.min_intrinsic_height(width))
-            .fold(0.0, f32::max)
-    }
-
-    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
-        measurables
-            .iter()
-            .map(|m| m.max_intrinsic_height(width))
-            .fold(0.0, f32::max)
-    }
+    // Box stacks its children, so the default intrinsics apply.
 }
 
 // Note: RowMeasurePolicy and ColumnMeasurePolicy have been replaced by FlexMeasurePolicy.
@@ -165,13 +139,16 @@ pub enum CrossAxisAlignment {
     Center,
     /// Align to the end of the cross axis (Bottom for Row, End for Column)
     End,
+    /// Line children up by an alignment line; children without it align to
+    /// the start.
+    AlignBy(AlignmentLine),
 }
 
 impl CrossAxisAlignment {
     /// Calculate the offset for positioning a child on the cross axis.
     fn align(&self, available: f32, child: f32) -> f32 {
         match self {
-            CrossAxisAlignment::Start => 0.0,
+            CrossAxisAlignment::Start | CrossAxisAlignment::AlignBy(_) => 0.0,
             CrossAxisAlignment::Center => ((available - child) / 2.0).max(0.0),
             CrossAxisAlignment::End => (available - child).max(0.0),
         }
@@ -194,6 +171,7 @@ impl From<VerticalAlignment> for CrossAxisAlignment {
             VerticalAlignment::Top => CrossAxisAlignment::Start,
             VerticalAlignment::CenterVertically => CrossAxisAlignment::Center,
             VerticalAlignment::Bottom => CrossAxisAlignment::End,
+            VerticalAlignment::AlignBy(line) => CrossAxisAlignment::AlignBy(line),
         }
     }
 }
@@ -418,8 +396,33 @@ impl MeasurePolicy for FlexMeasurePolicy {
             .sum::<f32>()
             + total_spacing;
 
-        // Container size
-        let container_main = total_main.clamp(min_main, max_main);
+        // Container size: weighted children claim all bounded main-axis space,
+        // even when a fill=false child leaves part of its share unused.
+        let container_main = if main_axis_bounded && !weighted_children.is_empty() {
+            max_main
+        } else {
+            total_main.clamp(min_main, max_main)
+        };
+        // Children aligned by a line extend as far above and below it as the
+        // tallest of them does on each side.
+        let alignment_line = match self.cross_axis_alignment {
+            CrossAxisAlignment::AlignBy(line) => Some(line),
+            _ => None,
+        };
+        let child_lines: SmallVec<[Option<f32>; 8]> = placeables
+            .iter()
+            .map(|p| alignment_line.and_then(|line| p.alignment_line(line)))
+            .collect();
+        let mut max_before_line = 0.0_f32;
+        let mut max_after_line = 0.0_f32;
+        for (placeable, line) in placeables.iter().zip(&child_lines) {
+            if let Some(line) = *line {
+                let child_cross = self.get_cross_axis_size(placeable.width(), placeable.height());
+                max_before_line = max_before_line.max(line);
+                max_after_line = max_after_line.max(child_cross - line);
+            }
+        }
+        let max_cross_size = max_cross_size.max(max_before_line + max_after_line);
         let container_cross = max_cross_size.clamp(min_cross, max_cross);
 
         // Arrange children along main axis
@@ -442,11 +445,16 @@ impl MeasurePolicy for FlexMeasurePolicy {
 
         // Place children
         let mut placements: SmallVec<[Placement; 8]> = SmallVec::with_capacity(placeables.len());
-        for (placeable, main_pos) in placeables.into_iter().zip(main_positions.into_iter()) {
+        for ((placeable, main_pos), line) in
+            placeables.into_iter().zip(main_positions).zip(child_lines)
+        {
             let child_cross = self.get_cross_axis_size(placeable.width(), placeable.height());
-            let cross_pos = self
-                .cross_axis_alignment
-                .align(container_cross, child_cross);
+            let cross_pos = match line {
+                Some(line) => max_before_line - line,
+                None => self
+                    .cross_axis_alignment
+                    .align(container_cross, child_cross),
+            };
 
             let (x, y) = match self.axis {
                 Axis::Horizontal => (main_pos, cross_pos),
@@ -576,6 +584,10 @@ impl MeasurePolicy for FlexMeasurePolicy {
             }
         }
     }
+
+    fn weight_axis(&self) -> Option<Axis> {
+        Some(self.axis)
+    }
 }
 
 /// MeasurePolicy for leaf nodes with fixed intrinsic size (like Spacer).
@@ -114,10 +114,6 @@ fn clamp_dimension_respects_infinite_max() {
     assert_eq!(clamped, 50.0);
 }
 
-// Note: Weight distribution tests removed - weights are not yet implemented
-// in the new MeasurePolicy-based system. They were part of the old
-// ColumnNode/RowNode implementation that has been replaced.
-
 #[test]
 fn resolve_dimension_applies_explicit_points() {
     let size = resolve_dimension(
@@ -414,6 +410,35 @@ fn cache_epoch_not_incremented_when_no_dirty_nodes() -> Result<(), NodeError> {
     Ok(())
 }
 
+#[test]
+fn density_change_invalidates_cache_with_identical_constraints() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let node = LayoutNode::new(Modifier::empty(), Rc::new(MaxSizePolicy));
+    let node_id = applier.create(Box::new(node));
+    let size = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+
+    measure_layout(&mut applier, node_id, size)?;
+    applier.with_node::<LayoutNode, _>(node_id, |node| {
+        node.clear_needs_measure();
+        node.clear_needs_layout();
+    })?;
+    let epoch_before =
+        applier.with_node::<LayoutNode, _>(node_id, |node| node.cache_handles().epoch())?;
+
+    super::measure_layout_at_density(&mut applier, node_id, size, 2.0)?;
+
+    let epoch_after =
+        applier.with_node::<LayoutNode, _>(node_id, |node| node.cache_handles().epoch())?;
+    assert_ne!(
+        epoch_before, epoch_after,
+        "a density change must remeasure even with the same constraints"
+    );
+    Ok(())
+}
+
 #[test]
 fn cache_epoch_increments_when_nodes_dirty() -> Result<(), NodeError> {
     let mut applier = MemoryApplier::new();
@@ -572,6 +597,278 @@ fn dirty_child_triggers_parent_remeasure() -> Result<(), NodeError> {
     Ok(())
 }
 
+#[test]
+fn placement_reuses_layout_boxes_of_unchanged_children() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let leaf = |height: f32| {
+        LayoutNode::new(
+            Modifier::empty(),
+            Rc::new(LeafMeasurePolicy::new(Size {
+                width: 10.0,
+                height,
+            })),
+        )
+    };
+    let children = [
+        applier.create(Box::new(leaf(10.0))),
+        applier.create(Box::new(leaf(20.0))),
+        applier.create(Box::new(leaf(30.0))),
+    ];
+    let mut root = LayoutNode::new(Modifier::empty(), Rc::new(VerticalStackPolicy));
+    for child in children {
+        root.children.insert(child);
+    }
+    let root_id = applier.create(Box::new(root));
+    applier.with_node::<LayoutNode, _>(root_id, |node| node.set_node_id(root_id))?;
+    for child in children {
+        applier.with_node::<LayoutNode, _>(child, |node| {
+            node.set_node_id(child);
+            node.set_parent(root_id);
+        })?;
+    }
+    let viewport = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+    let layout_pass = |applier: &mut MemoryApplier| {
+        let tree = measure_layout(applier, root_id, viewport)
+            .expect("layout")
+            .into_layout_tree();
+        tree.root().children.clone()
+    };
+    let tops = |boxes: &[Rc<LayoutBox>]| boxes.iter().map(|b| b.rect.y).collect::<Vec<_>>();
+    // Which children of `current` are the very boxes of `previous`.
+    let reused = |previous: &[Rc<LayoutBox>], current: &[Rc<LayoutBox>]| {
+        previous
+            .iter()
+            .zip(current)
+            .map(|(previous, current)| Rc::ptr_eq(previous, current))
+            .collect::<Vec<_>>()
+    };
+
+    let first = layout_pass(&mut applier);
+    assert_eq!(tops(&first), vec![0.0, 10.0, 30.0]);
+    let second = layout_pass(&mut applier);
+    assert_eq!(tops(&second), vec![0.0, 10.0, 30.0]);
+    assert_eq!(reused(&first, &second), vec![true, true, true]);
+
+    // Growing the middle child re-places it, the root and the child after it.
+    applier.with_node::<LayoutNode, _>(children[1], |node| {
+        node.set_measure_policy(Rc::new(LeafMeasurePolicy::new(Size {
+            width: 10.0,
+            height: 25.0,
+        })));
+    })?;
+    cranpose_core::bubble_measure_dirty(&mut applier, children[1]);
+    let grown = layout_pass(&mut applier);
+    assert_eq!(tops(&grown), vec![0.0, 10.0, 35.0]);
+    assert_eq!(reused(&second, &grown), vec![true, false, false]);
+
+    // Invalidating every measurement reproduces the same geometry, so nothing is rebuilt.
+    super::invalidate_all_layout_caches();
+    applier.with_node::<LayoutNode, _>(root_id, |node| node.mark_needs_measure())?;
+    let remeasured = layout_pass(&mut applier);
+    assert_eq!(tops(&remeasured), vec![0.0, 10.0, 35.0]);
+    assert_eq!(reused(&grown, &remeasured), vec![true, true, true]);
+    Ok(())
+}
+
+/// Leaf whose height is read from shared state at measure time, counting how
+/// often it is measured.
+struct SharedHeightPolicy {
+    height: Rc<std::cell::Cell<f32>>,
+    measures: Rc<std::cell::Cell<usize>>,
+}
+
+impl MeasurePolicy for SharedHeightPolicy {
+    fn measure(
+        &self,
+        _measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        self.measures.set(self.measures.get() + 1);
+        let (width, height) = constraints.constrain(10.0, self.height.get());
+        MeasureResult::new(Size { width, height }, Vec::new())
+    }
+
+    fn min_intrinsic_width(&self, _measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        10.0
+    }
+
+    fn max_intrinsic_width(&self, _measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        10.0
+    }
+
+    fn min_intrinsic_height(&self, _measurables: &[Box<dyn Measurable>], _width: f32) -> f32 {
+        self.height.get()
+    }
+
+    fn max_intrinsic_height(&self, _measurables: &[Box<dyn Measurable>], _width: f32) -> f32 {
+        self.height.get()
+    }
+}
+
+#[test]
+fn clean_nodes_are_measured_from_cache() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let measures = Rc::new(std::cell::Cell::new(0));
+    let heights = [10.0, 20.0, 30.0].map(|height| Rc::new(std::cell::Cell::new(height)));
+    let children = heights.clone().map(|height| {
+        applier.create(Box::new(LayoutNode::new(
+            Modifier::empty(),
+            Rc::new(SharedHeightPolicy {
+                height,
+                measures: Rc::clone(&measures),
+            }),
+        )))
+    });
+    let mut root = LayoutNode::new(Modifier::empty(), Rc::new(VerticalStackPolicy));
+    for child in children {
+        root.children.insert(child);
+    }
+    let root_id = applier.create(Box::new(root));
+    applier.with_node::<LayoutNode, _>(root_id, |node| node.set_node_id(root_id))?;
+    for child in children {
+        applier.with_node::<LayoutNode, _>(child, |node| {
+            node.set_node_id(child);
+            node.set_parent(root_id);
+        })?;
+    }
+    let viewport = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+    let layout_pass = |applier: &mut MemoryApplier| {
+        measures.set(0);
+        let tree = measure_layout(applier, root_id, viewport)
+            .expect("layout")
+            .into_layout_tree();
+        let tops: Vec<f32> = tree.root().children.iter().map(|c| c.rect.y).collect();
+        (measures.get(), tops)
+    };
+
+    assert_eq!(layout_pass(&mut applier), (3, vec![0.0, 10.0, 30.0]));
+    // A clean tree is answered from the measurement caches alone.
+    assert_eq!(layout_pass(&mut applier), (0, vec![0.0, 10.0, 30.0]));
+
+    // A dirty pass measures each leaf once, and the policy still observes the
+    // state it captured.
+    heights[1].set(25.0);
+    applier.with_node::<LayoutNode, _>(children[1], |node| node.mark_needs_measure())?;
+    cranpose_core::bubble_measure_dirty(&mut applier, children[1]);
+    assert_eq!(layout_pass(&mut applier), (3, vec![0.0, 10.0, 35.0]));
+    Ok(())
+}
+
+#[test]
+fn weighted_column_fills_height_below_fixed_header() -> Result<(), NodeError> {
+    use crate::layout::policies::FlexMeasurePolicy;
+    use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
+
+    let mut applier = MemoryApplier::new();
+    let leaf = |modifier: Modifier, height: f32| {
+        LayoutNode::new(
+            modifier,
+            Rc::new(LeafMeasurePolicy::new(Size {
+                width: 10.0,
+                height,
+            })),
+        )
+    };
+    let children = [
+        applier.create(Box::new(leaf(Modifier::empty(), 40.0))),
+        applier.create(Box::new(leaf(Modifier::empty().weight(1.0), 10.0))),
+        applier.create(Box::new(leaf(
+            Modifier::empty().weight_with_fill(1.0, false),
+            10.0,
+        ))),
+    ];
+    let mut root = LayoutNode::new(
+        Modifier::empty(),
+        Rc::new(FlexMeasurePolicy::column(
+            LinearArrangement::Start,
+            HorizontalAlignment::Start,
+        )),
+    );
+    for child in children {
+        root.children.insert(child);
+    }
+    let root_id = applier.create(Box::new(root));
+    applier.with_node::<LayoutNode, _>(root_id, |node| node.set_node_id(root_id))?;
+    for child in children {
+        applier.with_node::<LayoutNode, _>(child, |node| {
+            node.set_node_id(child);
+            node.set_parent(root_id);
+        })?;
+    }
+
+    let tree = measure_layout(
+        &mut applier,
+        root_id,
+        Size {
+            width: 100.0,
+            height: 200.0,
+        },
+    )?
+    .into_layout_tree();
+    let root = tree.root();
+    assert_eq!(root.rect.height, 200.0);
+    let rows: Vec<(f32, f32)> = root
+        .children
+        .iter()
+        .map(|child| (child.rect.y, child.rect.height))
+        .collect();
+    // The header keeps its height, the fill body takes its full share of the
+    // remaining 160 and the non-fill item sizes to content within its share.
+    assert_eq!(rows, vec![(0.0, 40.0), (40.0, 80.0), (120.0, 10.0)]);
+    Ok(())
+}
+
+#[test]
+fn weighted_column_keeps_its_cross_axis_relaxed() -> Result<(), NodeError> {
+    use crate::layout::policies::FlexMeasurePolicy;
+    use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
+
+    let mut applier = MemoryApplier::new();
+    // Fills the width it is offered, so it reveals the cross-axis bound.
+    let child = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty().weight(1.0).fill_max_width(),
+        Rc::new(LeafMeasurePolicy::new(Size {
+            width: 10.0,
+            height: 10.0,
+        })),
+    )));
+    let mut root = LayoutNode::new(
+        Modifier::empty(),
+        Rc::new(FlexMeasurePolicy::column(
+            LinearArrangement::Start,
+            HorizontalAlignment::Start,
+        )),
+    );
+    root.children.insert(child);
+    let root_id = applier.create(Box::new(root));
+    applier.with_node::<LayoutNode, _>(root_id, |node| node.set_node_id(root_id))?;
+    applier.with_node::<LayoutNode, _>(child, |node| {
+        node.set_node_id(child);
+        node.set_parent(root_id);
+    })?;
+
+    let tree = measure_layout(
+        &mut applier,
+        root_id,
+        Size {
+            width: 100.0,
+            height: 200.0,
+        },
+    )?
+    .into_layout_tree();
+    let child = &tree.root().children[0];
+    // Only the main axis is bounded for the weight; the width stays relaxed
+    // as it is for a column without weighted children.
+    assert_eq!((child.rect.width, child.rect.height), (10.0, 200.0));
+    Ok(())
+}
+
 // ============================================================================
 // PARENT TRACKING AND DIRTY BUBBLING TESTS
 // ============================================================================
@@ -952,6 +1249,234 @@ fn semantics_tree_derives_roles_from_configuration() -> Result<(), NodeError> {
     Ok(())
 }
 
+#[test]
+fn semantics_merge_descendants_yields_single_node_with_combined_text() -> Result<(), NodeError> {
+    use crate::layout::{SemanticsAction, SemanticsRole};
+
+    let mut applier = MemoryApplier::new();
+    let label = |text: &'static str| {
+        LayoutNode::new(
+            Modifier::empty().semantics(move |config| {
+                config.content_description = Some(text.into());
+            }),
+            Rc::new(LeafMeasurePolicy::new(Size::new(10.0, 10.0))),
+        )
+    };
+    let icon = applier.create(Box::new(label("Save")));
+    let caption = applier.create(Box::new(label("changes")));
+
+    let mut button = LayoutNode::new(
+        Modifier::empty().merge_descendants_semantics(|config| {
+            config.is_button = true;
+            config.is_clickable = true;
+        }),
+        Rc::new(VerticalStackPolicy),
+    );
+    button.children.insert(icon);
+    button.children.insert(caption);
+    let button_id = applier.create(Box::new(button));
+
+    let measurements = measure_layout(&mut applier, button_id, Size::new(100.0, 100.0))?;
+    let root = measurements.semantics_tree().root();
+
+    assert!(matches!(root.role, SemanticsRole::Button));
+    assert!(root.children.is_empty(), "descendants should be merged");
+    assert_eq!(root.description.as_deref(), Some("Save changes"));
+    assert_eq!(
+        root.actions,
+        vec![SemanticsAction::Click {
+            handler: crate::layout::SemanticsCallback::new(button_id),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn labeled_group_is_one_region_and_unlabeled_group_stays_transparent() -> Result<(), NodeError> {
+    use crate::layout::SemanticsRole;
+    use cranpose_foundation::Role;
+
+    let mut applier = MemoryApplier::new();
+    let label = |text: &'static str| {
+        LayoutNode::new(
+            Modifier::empty().semantics(move |config| {
+                config.content_description = Some(text.into());
+            }),
+            Rc::new(LeafMeasurePolicy::new(Size::new(10.0, 10.0))),
+        )
+    };
+    let group = |label: Option<&'static str>| {
+        LayoutNode::new(
+            Modifier::empty().semantics(move |config| {
+                config.role = Some(Role::Group);
+                config.content_description = label.map(Into::into);
+            }),
+            Rc::new(VerticalStackPolicy),
+        )
+    };
+    let heading = applier.create(Box::new(label("Account")));
+    let body = applier.create(Box::new(label("Signed in")));
+
+    let mut card = group(Some("Account card"));
+    card.children.insert(heading);
+    card.children.insert(body);
+    let card_id = applier.create(Box::new(card));
+
+    let mut column = group(None);
+    column.children.insert(card_id);
+    let column_id = applier.create(Box::new(column));
+
+    let measurements = measure_layout(&mut applier, column_id, Size::new(100.0, 100.0))?;
+    let root = measurements.semantics_tree().root();
+
+    assert_eq!(
+        root.role,
+        SemanticsRole::Layout,
+        "unlabeled groups stay plain"
+    );
+    assert_eq!(root.description, None);
+    let region = &root.children[0];
+    assert_eq!(region.node_id, card_id);
+    assert_eq!(region.role, SemanticsRole::Group);
+    assert_eq!(region.description.as_deref(), Some("Account card"));
+    let contents: Vec<_> = region
+        .children
+        .iter()
+        .map(|child| child.description.as_deref())
+        .collect();
+    assert_eq!(contents, [Some("Account"), Some("Signed in")]);
+
+    Ok(())
+}
+
+#[test]
+fn semantics_clear_and_set_replaces_descendant_semantics() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let child = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty().semantics(|config| {
+            config.content_description = Some("internal".into());
+            config.is_clickable = true;
+        }),
+        Rc::new(LeafMeasurePolicy::new(Size::new(10.0, 10.0))),
+    )));
+
+    let mut parent = LayoutNode::new(
+        Modifier::empty().clear_and_set_semantics(|config| {
+            config.content_description = Some("Replacement".into());
+        }),
+        Rc::new(VerticalStackPolicy),
+    );
+    parent.children.insert(child);
+    let parent_id = applier.create(Box::new(parent));
+
+    let measurements = measure_layout(&mut applier, parent_id, Size::new(100.0, 100.0))?;
+    let root = measurements.semantics_tree().root();
+
+    assert!(root.children.is_empty());
+    assert_eq!(root.description.as_deref(), Some("Replacement"));
+    assert!(root.actions.is_empty(), "descendant actions are cleared");
+
+    Ok(())
+}
+
+#[test]
+fn semantics_children_follow_traversal_index() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let mut label = |text: &'static str, index: Option<f32>| {
+        applier.create(Box::new(LayoutNode::new(
+            Modifier::empty().semantics(move |config| {
+                config.content_description = Some(text.into());
+                config.traversal_index = index;
+            }),
+            Rc::new(LeafMeasurePolicy::new(Size::new(10.0, 10.0))),
+        )))
+    };
+    let children = [
+        label("first", None),
+        label("last", Some(1.0)),
+        label("second", None),
+        label("headline", Some(-1.0)),
+        label("third", Some(0.0)),
+    ];
+
+    let mut parent = LayoutNode::new(Modifier::empty(), Rc::new(VerticalStackPolicy));
+    for child in children {
+        parent.children.insert(child);
+    }
+    let parent_id = applier.create(Box::new(parent));
+
+    let measurements = measure_layout(&mut applier, parent_id, Size::new(100.0, 100.0))?;
+    let order: Vec<_> = measurements
+        .semantics_tree()
+        .root()
+        .children
+        .iter()
+        .map(|child| child.description.as_deref().unwrap_or_default())
+        .collect();
+
+    assert_eq!(order, ["headline", "first", "second", "third", "last"]);
+    Ok(())
+}
+
+#[test]
+fn vertical_scroll_exposes_scroll_semantics_and_scroll_by_action() -> Result<(), NodeError> {
+    use crate::layout::SemanticsAction;
+    use crate::scroll::ScrollState;
+    use cranpose_core::{DefaultScheduler, Runtime};
+    use std::sync::Arc;
+
+    let _runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let state = ScrollState::new(0.0);
+    let mut applier = MemoryApplier::new();
+    let content = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty(),
+        Rc::new(LeafMeasurePolicy::new(Size::new(100.0, 500.0))),
+    )));
+    let mut scroller = LayoutNode::new(
+        Modifier::empty().vertical_scroll(state.clone(), false),
+        Rc::new(VerticalStackPolicy),
+    );
+    scroller.children.insert(content);
+    let scroller_id = applier.create(Box::new(scroller));
+
+    let measurements = measure_layout(&mut applier, scroller_id, Size::new(100.0, 100.0))?;
+    let root = measurements.semantics_tree().root();
+
+    let range = root.scroll_axis_range.expect("scroll range exposed");
+    assert!(range.is_vertical);
+    assert_eq!(range.value, 0.0);
+    assert_eq!(range.max_value, 400.0);
+
+    let scroll_by = root
+        .actions
+        .iter()
+        .find_map(|action| match action {
+            SemanticsAction::ScrollBy { handler } => Some(handler.clone()),
+            _ => None,
+        })
+        .expect("scroll action exposed");
+    assert_eq!(scroll_by.invoke(150.0), 150.0);
+    assert_eq!(state.value_non_reactive(), 150.0);
+
+    // Non-scrollable content does not expose scroll semantics.
+    let child = &root.children[0];
+    assert!(child.scroll_axis_range.is_none());
+    assert!(child.actions.is_empty());
+
+    let measurements = measure_layout(&mut applier, scroller_id, Size::new(100.0, 100.0))?;
+    let root = measurements.semantics_tree().root();
+    let range = root.scroll_axis_range.expect("scroll range exposed");
+    assert_eq!(range.value, 150.0);
+    // Every evaluation hands out the same handler, so configs stay comparable.
+    assert!(root.actions.iter().any(|action| matches!(
+        action,
+        SemanticsAction::ScrollBy { handler } if *handler == scroll_by
+    )));
+
+    Ok(())
+}
+
 #[test]
 fn semantics_configuration_merges_multiple_modifiers() -> Result<(), NodeError> {
     let mut applier = MemoryApplier::new();
@@ -1204,3 +1729,160 @@ fn measure_layout_error_preserves_applier_and_slots() -> Result<(), NodeError> {
 
     Ok(())
 }
+
+#[test]
+fn layout_tree_dump_includes_geometry_and_modifiers() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let leaf = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty().padding(5.0),
+        Rc::new(LeafMeasurePolicy::new(Size::new(20.0, 10.0))),
+    )));
+    let mut inner = LayoutNode::new(Modifier::empty(), Rc::new(VerticalStackPolicy));
+    inner.children.insert(leaf);
+    let inner_id = applier.create(Box::new(inner));
+    let first = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty(),
+        Rc::new(LeafMeasurePolicy::new(Size::new(40.0, 15.0))),
+    )));
+    let mut root = LayoutNode::new(Modifier::empty(), Rc::new(VerticalStackPolicy));
+    root.children.insert(first);
+    root.children.insert(inner_id);
+    let root_id = applier.create(Box::new(root));
+
+    let measurements = measure_layout(&mut applier, root_id, Size::new(200.0, 200.0))?;
+    let dump = measurements
+        .into_layout_tree()
+        .dump_layout_tree_with_geometry();
+    let lines: Vec<&str> = dump.lines().collect();
+
+    assert_eq!(lines.len(), 4, "one line per node:\n{dump}");
+    assert!(lines[0].starts_with(&format!(
+        "[Node #{root_id}] Layout pos: (0.0, 0.0), size: (40.0x35.0)"
+    )));
+    assert!(lines[1].starts_with(&format!(
+        "  [Node #{first}] Layout pos: (0.0, 0.0), size: (40.0x15.0)"
+    )));
+    assert!(lines[2].starts_with(&format!(
+        "  [Node #{inner_id}] Layout pos: (0.0, 15.0), size: (30.0x20.0)"
+    )));
+    assert!(lines[3].starts_with(&format!(
+        "    [Node #{leaf}] Layout pos: (0.0, 15.0), size: (30.0x20.0)"
+    )));
+    assert!(lines[3].contains("content offset: (5.0, 5.0)"), "{dump}");
+    assert!(lines[3].contains("PaddingElement"), "{dump}");
+
+    Ok(())
+}
+
+fn layout_box(
+    node_id: NodeId,
+    rect: GeometryRect,
+    modifier: Modifier,
+    children: Vec<LayoutBox>,
+) -> LayoutBox {
+    let slices = collect_slices_from_modifier(&modifier);
+    LayoutBox::new(
+        node_id,
+        rect,
+        Point::default(),
+        LayoutNodeData::new(
+            modifier,
+            ResolvedModifiers::default(),
+            slices,
+            LayoutNodeKind::Layout,
+        ),
+        children,
+    )
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> GeometryRect {
+    GeometryRect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn node_at_returns_innermost_topmost_box_and_ancestors_walk_up() {
+    // Root 1 holds a card 2 with a nested badge 3, and an overlay 4 added
+    // after the card that covers its right half.
+    let badge = layout_box(3, rect(10.0, 10.0, 20.0, 20.0), Modifier::empty(), vec![]);
+    let card = layout_box(
+        2,
+        rect(0.0, 0.0, 100.0, 50.0),
+        Modifier::empty(),
+        vec![badge],
+    );
+    let overlay = layout_box(4, rect(50.0, 0.0, 50.0, 50.0), Modifier::empty(), vec![]);
+    let tree = LayoutTree::new(layout_box(
+        1,
+        rect(0.0, 0.0, 200.0, 200.0),
+        Modifier::empty(),
+        vec![card, overlay],
+    ));
+
+    assert_eq!(tree.node_at(Point { x: 15.0, y: 15.0 }), Some(3));
+    assert_eq!(tree.node_at(Point { x: 40.0, y: 40.0 }), Some(2));
+    assert_eq!(
+        tree.node_at(Point { x: 60.0, y: 20.0 }),
+        Some(4),
+        "later sibling is on top"
+    );
+    assert_eq!(tree.node_at(Point { x: 150.0, y: 150.0 }), Some(1));
+    assert_eq!(tree.node_at(Point { x: 250.0, y: 10.0 }), None);
+
+    assert_eq!(tree.ancestors(3), vec![2, 1]);
+    assert_eq!(tree.ancestors(4), vec![1]);
+    assert!(tree.ancestors(1).is_empty());
+    assert!(tree.ancestors(99).is_empty());
+}
+
+#[test]
+fn node_at_skips_content_clipped_away_by_an_ancestor() {
+    let overflowing = layout_box(3, rect(40.0, 0.0, 40.0, 20.0), Modifier::empty(), vec![]);
+    let clip = layout_box(
+        2,
+        rect(0.0, 0.0, 50.0, 20.0),
+        Modifier::empty().clip_to_bounds(),
+        vec![overflowing],
+    );
+    let tree = LayoutTree::new(layout_box(
+        1,
+        rect(0.0, 0.0, 100.0, 100.0),
+        Modifier::empty(),
+        vec![clip],
+    ));
+
+    assert_eq!(tree.node_at(Point { x: 45.0, y: 10.0 }), Some(3));
+    assert_eq!(tree.node_at(Point { x: 70.0, y: 10.0 }), Some(1));
+}
+
+#[test]
+fn asymmetric_padding_offsets_and_shrinks_child() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+    let child = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty().fill_max_size(),
+        Rc::new(LeafMeasurePolicy::new(Size::new(0.0, 0.0))),
+    )));
+    let mut root = LayoutNode::new(
+        Modifier::empty()
+            .size_points(100.0, 100.0)
+            .padding_ltrb(4.0, 8.0, 12.0, 16.0),
+        Rc::new(VerticalStackPolicy),
+    );
+    root.children.insert(child);
+    let root_id = applier.create(Box::new(root));
+
+    let tree = measure_layout(&mut applier, root_id, Size::new(200.0, 200.0))?.into_layout_tree();
+    let root_box = tree.root();
+    assert_eq!(root_box.rect.width, 100.0);
+    assert_eq!(root_box.rect.height, 100.0);
+    let child_box = &root_box.children[0];
+    assert_eq!(child_box.node_id, child);
+    assert_eq!((child_box.rect.x, child_box.rect.y), (4.0, 8.0));
+    assert_eq!((child_box.rect.width, child_box.rect.height), (84.0, 76.0));
+
+    Ok(())
+}
@@ -21,6 +21,7 @@ struct MockPlaceable {
     width: f32,
     height: f32,
     node_id: usize,
+    baseline: Option<f32>,
 }
 
 impl Placeable for MockPlaceable {
@@ -34,6 +35,42 @@ impl Placeable for MockPlaceable {
     fn node_id(&self) -> usize {
         self.node_id
     }
+    fn alignment_line(&self, _line: AlignmentLine) -> Option<f32> {
+        self.baseline
+    }
+}
+
+/// Measurable whose placeable reports `baseline` for every alignment line.
+struct BaselineMeasurable {
+    inner: MockMeasurable,
+    baseline: f32,
+}
+
+impl Measurable for BaselineMeasurable {
+    fn measure(&self, _constraints: Constraints) -> Box<dyn Placeable> {
+        Box::new(MockPlaceable {
+            width: self.inner.width,
+            height: self.inner.height,
+            node_id: self.inner.node_id,
+            baseline: Some(self.baseline),
+        })
+    }
+
+    fn min_intrinsic_width(&self, height: f32) -> f32 {
+        self.inner.min_intrinsic_width(height)
+    }
+
+    fn max_intrinsic_width(&self, height: f32) -> f32 {
+        self.inner.max_intrinsic_width(height)
+    }
+
+    fn min_intrinsic_height(&self, width: f32) -> f32 {
+        self.inner.min_intrinsic_height(width)
+    }
+
+    fn max_intrinsic_height(&self, width: f32) -> f32 {
+        self.inner.max_intrinsic_height(width)
+    }
 }
 
 impl Measurable for MockMeasurable {
@@ -42,6 +79,7 @@ impl Measurable for MockMeasurable {
             width: self.width,
             height: self.height,
             node_id: self.node_id,
+            baseline: None,
         })
     }
 
@@ -137,3 +175,38 @@ fn row_measure_policy_sums_widths() {
     assert_eq!(result.placements[0].x, 0.0);
     assert_eq!(result.placements[1].x, 40.0);
 }
+
+#[test]
+fn row_aligned_by_baseline_lines_up_children_that_report_it() {
+    let policy = FlexMeasurePolicy::row(
+        LinearArrangement::Start,
+        VerticalAlignment::AlignBy(AlignmentLine::FirstBaseline),
+    );
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(BaselineMeasurable {
+            inner: MockMeasurable::new(40.0, 30.0, 1),
+            baseline: 24.0,
+        }),
+        Box::new(BaselineMeasurable {
+            inner: MockMeasurable::new(20.0, 20.0, 2),
+            baseline: 8.0,
+        }),
+        Box::new(MockMeasurable::new(10.0, 10.0, 3)),
+    ];
+
+    let result = policy.measure(
+        &measurables,
+        Constraints {
+            min_width: 0.0,
+            max_width: 200.0,
+            min_height: 0.0,
+            max_height: 200.0,
+        },
+    );
+
+    let tops: Vec<f32> = result.placements.iter().map(|p| p.y).collect();
+    // Baselines meet at 24; the child without one stays at the top.
+    assert_eq!(tops, vec![0.0, 16.0, 0.0]);
+    // 24 above the baseline plus 12 below it for the second child.
+    assert_eq!(result.size.height, 36.0);
+}
@@ -6,18 +6,23 @@ pub use cranpose_macros::composable;
 
 mod cursor_animation;
 mod debug;
+mod density;
 mod draw;
 pub mod fling_animation;
 mod focus_dispatch;
+pub mod interaction;
 mod key_event;
 pub mod layout;
+mod lifecycle;
 mod modifier;
 mod modifier_nodes;
 mod pointer_dispatch;
 mod primitives;
 mod render_state;
 mod renderer;
+mod saveable;
 pub mod scroll;
+mod software_keyboard;
 mod subcompose_layout;
 mod text;
 pub mod text_field_focus;
@@ -27,6 +32,7 @@ mod text_field_modifier_node;
 pub mod text_layout_result;
 mod text_modifier_node;
 pub mod widgets;
+mod window_insets;
 mod word_boundaries;
 
 // Export for cursor blink animation - AppShell checks this to continuously redraw
@@ -37,12 +43,14 @@ pub use cursor_animation::{
     stop_cursor_blink, tick_cursor_blink,
 };
 
-pub use cranpose_ui_graphics::Dp;
-pub use cranpose_ui_layout::IntrinsicSize;
+pub use cranpose_ui_graphics::{Density, Dp, FontStyle, FontWeight, Sp, TextStyle};
+pub use cranpose_ui_layout::{AlignmentLine, AlignmentLineMerge, AlignmentLines, IntrinsicSize};
+pub use density::{LayoutLength, Length, LocalDensity};
 pub use draw::{execute_draw_commands, DrawCacheBuilder, DrawCommand};
 pub use focus_dispatch::{
     active_focus_target, clear_focus_invalidations, has_pending_focus_invalidations,
-    process_focus_invalidations, schedule_focus_invalidation, set_active_focus_target,
+    process_focus_invalidations, request_focus, schedule_focus_invalidation,
+    set_active_focus_target,
 };
 // Re-export FocusManager from compose-foundation to avoid duplication
 pub use cranpose_foundation::nodes::input::focus::FocusManager;
@@ -52,30 +60,36 @@ pub use layout::{
         VerticalAlignment,
     },
     measure_layout, tree_needs_layout, LayoutBox, LayoutEngine, LayoutMeasurements, LayoutNodeData,
-    LayoutNodeKind, LayoutTree, SemanticsAction, SemanticsCallback, SemanticsNode, SemanticsRole,
-    SemanticsTree,
+    LayoutNodeKind, LayoutOverlay, LayoutTree, SemanticsAction, SemanticsCallback, SemanticsNode,
+    SemanticsRole, SemanticsTree,
 };
 pub use modifier::{
-    collect_modifier_slices, collect_slices_from_modifier, Brush, Color, CornerRadii, EdgeInsets,
-    GraphicsLayer, Modifier, ModifierNodeSlices, Point, PointerEvent, PointerEventKind,
-    PointerInputScope, Rect, ResolvedBackground, ResolvedModifiers, RoundedCornerShape, Size,
+    collect_modifier_slices, collect_slices_from_modifier, ripple, BringIntoViewRequester, Brush,
+    ClickableOptions, Color, CornerRadii, EdgeInsets, GraphicsLayer, Modifier, ModifierNodeKind,
+    ModifierNodeSlices, Point, PointerEvent, PointerEventKind, PointerInputScope, Rect,
+    ResolvedBackground, ResolvedModifiers, Ripple, RoundedCornerShape, Size,
 };
 pub use modifier_nodes::{
     AlphaElement, AlphaNode, BackgroundElement, BackgroundNode, ClickableElement, ClickableNode,
     CornerShapeElement, CornerShapeNode, FillDirection, FillElement, FillNode, OffsetElement,
-    OffsetNode, PaddingElement, PaddingNode, SizeElement, SizeNode,
+    OffsetNode, PaddingElement, PaddingNode, SecondaryClickElement, SecondaryClickNode,
+    SizeElement, SizeNode,
 };
 pub use pointer_dispatch::{
     clear_pointer_repasses, has_pending_pointer_repasses, process_pointer_repasses,
     schedule_pointer_repass,
 };
 pub use primitives::{
-    BasicTextField, BasicTextFieldOptions, Box, BoxScope, BoxSpec, BoxWithConstraints,
-    BoxWithConstraintsScope, BoxWithConstraintsScopeImpl, Button, Column, ColumnSpec, ForEach,
-    Layout, LayoutNode, Row, RowSpec, Spacer, SubcomposeLayout, Text,
+    rememberNavController, BackDispatcher, BackHandler, BasicTextField, BasicTextFieldOptions, Box,
+    BoxScope, BoxSpec, BoxWithConstraints, BoxWithConstraintsScope, BoxWithConstraintsScopeImpl,
+    Button, ButtonOptions, ButtonWithOptions, Column, ColumnSpec, Dialog, ForEach, Layout,
+    LayoutNode, LocalBackDispatcher, LocalTextStyle, NavController, NavHost, Popup, PopupLayer,
+    PopupPosition, ProvideTextStyle, Row, RowSpec, Spacer, StyledText, SubcomposeLayout, Text,
+    Tooltip, TOOLTIP_DWELL_MILLIS,
 };
 // Lazy list exports - single source from compose-foundation
 pub use cranpose_foundation::lazy::{LazyListItemInfo, LazyListLayoutInfo, LazyListState};
+pub use interaction::{Interaction, InteractionSource};
 pub use key_event::{KeyCode, KeyEvent, KeyEventType, Modifiers};
 pub use render_state::{
     current_density, has_pending_draw_repasses, has_pending_layout_repasses,
@@ -87,27 +101,38 @@ pub use render_state::{
     take_render_invalidation,
 };
 pub use renderer::{HeadlessRenderer, PaintLayer, RecordedRenderScene, RenderOp};
-pub use scroll::{ScrollElement, ScrollNode, ScrollState};
+pub use saveable::{
+    rememberSaveable, rememberSaveableWith, LocalSaveableStateRegistry, SaveableStateRegistry,
+    Saver,
+};
+pub use scroll::{ScrollElement, ScrollNode, ScrollState, ScrollStateSaver};
 // Test utilities for fling velocity verification (only with test-helpers feature)
+pub use fling_animation::{SnapAnimation, SnapFlingBehavior};
+pub use lifecycle::{Lifecycle, LifecycleEffect, LifecycleObserver, LocalLifecycle};
 #[cfg(feature = "test-helpers")]
 pub use modifier::{last_fling_velocity, reset_last_fling_velocity};
+pub use software_keyboard::{LocalSoftwareKeyboardController, SoftwareKeyboardController};
 pub use subcompose_layout::{
     Constraints, MeasureResult, Placement, SubcomposeLayoutNode, SubcomposeLayoutScope,
     SubcomposeMeasureScope, SubcomposeMeasureScopeImpl,
 };
 pub use text::{
-    get_cursor_x_for_offset, get_offset_for_position, layout_text, measure_text, set_text_measurer,
-    TextMeasurer, TextMetrics,
+    get_cursor_x_for_offset, get_offset_for_position, is_unbounded_width, layout_text,
+    measure_longest_word_width, measure_styled_text, measure_text, measure_text_with_max_width,
+    measure_wrapped, set_text_measurer, text_width_bucket, TextMeasurer, TextMetrics,
 };
 pub use text_field_modifier_node::{TextFieldElement, TextFieldModifierNode};
 pub use text_modifier_node::{TextModifierElement, TextModifierNode};
-pub use widgets::lazy_list::{LazyColumn, LazyColumnSpec, LazyRow, LazyRowSpec};
+pub use widgets::lazy_list::{
+    LazyColumn, LazyColumnSpec, LazyListStateSaver, LazyRow, LazyRowSpec,
+};
+pub use window_insets::{LocalWindowInsets, WindowInsets};
 
 // Debug utilities
 pub use debug::{
-    format_layout_tree, format_modifier_chain, format_render_scene, install_modifier_chain_trace,
-    log_layout_tree, log_modifier_chain, log_render_scene, log_screen_summary,
-    ModifierChainTraceGuard,
+    format_layout_tree, format_layout_tree_with_geometry, format_modifier_chain,
+    format_render_scene, install_modifier_chain_trace, log_layout_tree, log_modifier_chain,
+    log_render_scene, log_screen_summary, ModifierChainTraceGuard,
 };
 
 /// Convenience alias used in examples and tests.
@@ -0,0 +1,143 @@
+//! App lifecycle: whether the app is in the foreground.
+//!
+//! The host owns a [`Lifecycle`], provides it through [`LocalLifecycle`] and
+//! moves it between started (visible, in the foreground) and stopped
+//! (backgrounded). Composables react with [`LifecycleEffect`], e.g. to pause
+//! animations or network polling while nobody can see them.
+
+#![allow(non_snake_case)]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::composable;
+use cranpose_core::{remember, staticCompositionLocalOf, DisposableEffect, StaticCompositionLocal};
+
+/// Receives lifecycle transitions from a [`Lifecycle`].
+pub trait LifecycleObserver {
+    /// The app came to the foreground.
+    fn on_start(&self) {}
+    /// The app went to the background.
+    fn on_stop(&self) {}
+}
+
+#[derive(Default)]
+struct LifecycleInner {
+    started: Cell<bool>,
+    observers: RefCell<Vec<Rc<dyn LifecycleObserver>>>,
+}
+
+/// Foreground state of the app, shared by the host and its observers.
+#[derive(Clone, Default)]
+pub struct Lifecycle {
+    inner: Rc<LifecycleInner>,
+}
+
+impl Lifecycle {
+    /// A lifecycle that starts out stopped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.inner.started.get()
+    }
+
+    /// Move to started or stopped, notifying observers in registration
+    /// order. Setting the current state again does nothing.
+    pub fn set_started(&self, started: bool) {
+        if self.inner.started.replace(started) == started {
+            return;
+        }
+        // Observers may add or remove observers while being notified.
+        let observers = self.inner.observers.borrow().clone();
+        for observer in observers {
+            if started {
+                observer.on_start();
+            } else {
+                observer.on_stop();
+            }
+        }
+    }
+
+    pub fn add_observer(&self, observer: Rc<dyn LifecycleObserver>) {
+        self.inner.observers.borrow_mut().push(observer);
+    }
+
+    pub fn remove_observer(&self, observer: &Rc<dyn LifecycleObserver>) {
+        self.inner
+            .observers
+            .borrow_mut()
+            .retain(|registered| !Rc::ptr_eq(registered, observer));
+    }
+}
+
+impl PartialEq for Lifecycle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+thread_local! {
+    static LOCAL_LIFECYCLE: StaticCompositionLocal<Option<Lifecycle>> =
+        staticCompositionLocalOf(|| None);
+}
+
+/// Lifecycle of the host app, or `None` if the host does not report one.
+pub fn LocalLifecycle() -> StaticCompositionLocal<Option<Lifecycle>> {
+    LOCAL_LIFECYCLE.with(Clone::clone)
+}
+
+struct EffectCallbacks {
+    on_start: RefCell<Box<dyn FnMut()>>,
+    on_stop: RefCell<Box<dyn FnMut()>>,
+}
+
+impl LifecycleObserver for EffectCallbacks {
+    fn on_start(&self) {
+        (self.on_start.borrow_mut())();
+    }
+
+    fn on_stop(&self) {
+        (self.on_stop.borrow_mut())();
+    }
+}
+
+/// Calls `on_start` whenever the app comes to the foreground and `on_stop`
+/// whenever it goes to the background.
+///
+/// If the app is already started when the effect enters the composition,
+/// `on_start` runs right away; if it is still started when the effect
+/// leaves, `on_stop` runs then, so the two calls always pair up.
+#[composable]
+pub fn LifecycleEffect<S, T>(on_start: S, on_stop: T)
+where
+    S: FnMut() + 'static,
+    T: FnMut() + 'static,
+{
+    let Some(lifecycle) = LocalLifecycle().current() else {
+        return;
+    };
+    let callbacks = remember(|| {
+        Rc::new(EffectCallbacks {
+            on_start: RefCell::new(Box::new(|| {})),
+            on_stop: RefCell::new(Box::new(|| {})),
+        })
+    })
+    .with(Rc::clone);
+    *callbacks.on_start.borrow_mut() = Box::new(on_start);
+    *callbacks.on_stop.borrow_mut() = Box::new(on_stop);
+    DisposableEffect!((), move |scope| {
+        let observer: Rc<dyn LifecycleObserver> = callbacks;
+        lifecycle.add_observer(Rc::clone(&observer));
+        if lifecycle.is_started() {
+            observer.on_start();
+        }
+        scope.on_dispose(move || {
+            lifecycle.remove_observer(&observer);
+            if lifecycle.is_started() {
+                observer.on_stop();
+            }
+        })
+    });
+}
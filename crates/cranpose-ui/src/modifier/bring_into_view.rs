@@ -0,0 +1,224 @@
+//! Scrolling an element into view.
+//!
+//! Scroll containers announce themselves to their descendants through a
+//! modifier local. A [`BringIntoViewRequester`] attached with
+//! [`Modifier::bring_into_view_requester`] walks up to those containers and
+//! scrolls each until the element's bounds are visible, e.g. when focus moves
+//! to a field that is scrolled out of sight.
+
+use std::cell::Cell;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use cranpose_core::NodeId;
+use cranpose_foundation::{
+    DelegatableNode, ModifierNode, ModifierNodeContext, ModifierNodeElement, NodeCapabilities,
+    NodeState,
+};
+
+use super::{modifier_element, Modifier, ModifierLocalKey};
+use crate::scroll::ScrollState;
+use crate::widgets::nodes::{ancestors_providing_modifier_local, layout_node_rect};
+
+/// A scroll container that descendants can ask to reveal them.
+#[derive(Clone)]
+struct ScrollViewport {
+    state: ScrollState,
+    is_vertical: bool,
+    reverse_scrolling: bool,
+}
+
+thread_local! {
+    static SCROLL_VIEWPORT: ModifierLocalKey<Option<ScrollViewport>> =
+        ModifierLocalKey::new(|| None);
+}
+
+/// Lets descendants of a `state`-driven scroll container scroll it to reveal
+/// themselves.
+pub(super) fn scroll_viewport(
+    state: ScrollState,
+    is_vertical: bool,
+    reverse_scrolling: bool,
+) -> Modifier {
+    let key = SCROLL_VIEWPORT.with(Clone::clone);
+    let viewport = ScrollViewport {
+        state,
+        is_vertical,
+        reverse_scrolling,
+    };
+    Modifier::empty().modifier_local_provider(key, move || Some(viewport.clone()))
+}
+
+/// Scroll the ancestors of `node`, nearest first, until its last laid out
+/// bounds are visible. Returns whether anything scrolled.
+pub(crate) fn bring_into_view(node: NodeId) -> bool {
+    let Some(mut bounds) = layout_node_rect(node) else {
+        return false;
+    };
+    let viewports = SCROLL_VIEWPORT.with(|key| ancestors_providing_modifier_local(node, key));
+    let mut scrolled = false;
+    for (container, viewport) in viewports {
+        let (Some(viewport), Some(visible)) = (viewport, layout_node_rect(container)) else {
+            continue;
+        };
+        let delta = if viewport.is_vertical {
+            reveal_delta(bounds.y, bounds.height, visible.y, visible.height)
+        } else {
+            reveal_delta(bounds.x, bounds.width, visible.x, visible.width)
+        };
+        // Reversed containers grow their scroll value towards the start.
+        let direction = if viewport.reverse_scrolling {
+            -1.0
+        } else {
+            1.0
+        };
+        let consumed = viewport.state.dispatch_raw_delta(delta * direction) * direction;
+        if consumed == 0.0 {
+            continue;
+        }
+        scrolled = true;
+        // Outer containers see the element where the inner scroll moved it.
+        if viewport.is_vertical {
+            bounds.y -= consumed;
+        } else {
+            bounds.x -= consumed;
+        }
+    }
+    scrolled
+}
+
+/// Scroll delta that brings `[start, start + size]` inside
+/// `[view_start, view_start + view_size]`. Elements larger than the viewport
+/// align to its start.
+fn reveal_delta(start: f32, size: f32, view_start: f32, view_size: f32) -> f32 {
+    let (end, view_end) = (start + size, view_start + view_size);
+    if start < view_start || size > view_size {
+        start - view_start
+    } else if end > view_end {
+        end - view_end
+    } else {
+        0.0
+    }
+}
+
+/// A handle for scrolling a component into view from application code.
+///
+/// Mirrors Jetpack Compose's `BringIntoViewRequester`.
+#[derive(Clone, Default)]
+pub struct BringIntoViewRequester {
+    node: Rc<Cell<Option<NodeId>>>,
+}
+
+impl BringIntoViewRequester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scroll the scrollable ancestors of the attached component until it is
+    /// visible. Returns whether anything scrolled; nothing does before the
+    /// component has been laid out.
+    pub fn bring_into_view(&self) -> bool {
+        self.node.get().is_some_and(bring_into_view)
+    }
+}
+
+impl PartialEq for BringIntoViewRequester {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.node, &other.node)
+    }
+}
+
+impl std::fmt::Debug for BringIntoViewRequester {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BringIntoViewRequester")
+            .field("node", &self.node.get())
+            .finish()
+    }
+}
+
+impl Modifier {
+    /// Attaches `requester`, so it can scroll this component into view.
+    pub fn bring_into_view_requester(self, requester: &BringIntoViewRequester) -> Self {
+        let element = BringIntoViewRequesterElement {
+            requester: requester.clone(),
+        };
+        self.then(Modifier::from_parts(vec![modifier_element(element)]))
+    }
+}
+
+/// Node that tells its requester which layout node it is attached to.
+pub struct BringIntoViewRequesterNode {
+    requester: BringIntoViewRequester,
+    node_id: Option<NodeId>,
+    state: NodeState,
+}
+
+impl BringIntoViewRequesterNode {
+    fn attach_requester(&self) {
+        self.requester.node.set(self.node_id);
+    }
+
+    fn detach_requester(&self) {
+        if self.requester.node.get() == self.node_id {
+            self.requester.node.set(None);
+        }
+    }
+}
+
+impl DelegatableNode for BringIntoViewRequesterNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for BringIntoViewRequesterNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        self.node_id = context.node_id();
+        self.attach_requester();
+    }
+
+    fn on_detach(&mut self) {
+        self.detach_requester();
+    }
+}
+
+/// Element that attaches a [`BringIntoViewRequester`] to its node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BringIntoViewRequesterElement {
+    requester: BringIntoViewRequester,
+}
+
+impl Hash for BringIntoViewRequesterElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "bringIntoViewRequester".hash(state);
+        (Rc::as_ptr(&self.requester.node) as usize).hash(state);
+    }
+}
+
+impl ModifierNodeElement for BringIntoViewRequesterElement {
+    type Node = BringIntoViewRequesterNode;
+
+    fn create(&self) -> Self::Node {
+        BringIntoViewRequesterNode {
+            requester: self.requester.clone(),
+            node_id: None,
+            state: NodeState::new(),
+        }
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        if node.requester != self.requester {
+            node.detach_requester();
+            node.requester = self.requester.clone();
+            node.attach_requester();
+        }
+    }
+
+    fn inspector_name(&self) -> &'static str {
+        "bringIntoViewRequester"
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::NONE
+    }
+}
@@ -14,11 +14,14 @@ use super::{
     ModifierInspectorRecord, ModifierLocalAncestorResolver, ModifierLocalToken, Point,
     ResolvedModifierLocal, ResolvedModifiers,
 };
+use crate::density::with_measure_density;
 use crate::modifier_nodes::{
-    AlignmentNode, FillDirection, FillNode, IntrinsicAxis, IntrinsicSizeNode, OffsetNode,
-    PaddingNode, SizeNode, WeightNode,
+    AlignmentNode, AlphaNode, BackgroundNode, ClickableNode, ClipToBoundsNode, CornerShapeNode,
+    DrawCommandNode, FillDirection, FillNode, GraphicsLayerNode, IntrinsicAxis, IntrinsicSizeNode,
+    OffsetNode, PaddingNode, PointerEventHandlerNode, SecondaryClickNode, SizeNode, WeightNode,
 };
-use std::any::type_name_of_val;
+use cranpose_ui_graphics::Density;
+use std::any::{type_name_of_val, Any};
 use std::cell::RefCell;
 use std::rc::Rc;
 #[cfg(not(target_arch = "wasm32"))]
@@ -35,6 +38,96 @@ pub struct ModifierChainInspectorNode {
     pub inspector: Option<ModifierInspectorRecord>,
 }
 
+/// Kind of a top-level node in a reconciled modifier chain.
+///
+/// Returned in chain order by [`ModifierChainHandle::modifier_summary`] so tests
+/// can assert which modifiers were applied without rendering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModifierNodeKind {
+    Padding,
+    Size,
+    Fill,
+    Offset,
+    Weight,
+    Alignment,
+    IntrinsicSize,
+    Background,
+    CornerShape,
+    GraphicsLayer,
+    Alpha,
+    ClipToBounds,
+    Draw,
+    Clickable,
+    PointerInput,
+    Semantics,
+    Scroll,
+    Focus,
+    FocusRequester,
+    ModifierLocal,
+    Text,
+    TextField,
+    /// Any other node, identified by its type name.
+    Other(&'static str),
+}
+
+impl ModifierNodeKind {
+    fn classify(node: &dyn cranpose_foundation::ModifierNode) -> Self {
+        let any: &dyn Any = node.as_any();
+        if any.is::<PaddingNode>() {
+            Self::Padding
+        } else if any.is::<SizeNode>() {
+            Self::Size
+        } else if any.is::<FillNode>() {
+            Self::Fill
+        } else if any.is::<OffsetNode>() {
+            Self::Offset
+        } else if any.is::<WeightNode>() {
+            Self::Weight
+        } else if any.is::<AlignmentNode>() {
+            Self::Alignment
+        } else if any.is::<IntrinsicSizeNode>() {
+            Self::IntrinsicSize
+        } else if any.is::<BackgroundNode>() {
+            Self::Background
+        } else if any.is::<CornerShapeNode>() {
+            Self::CornerShape
+        } else if any.is::<GraphicsLayerNode>() {
+            Self::GraphicsLayer
+        } else if any.is::<AlphaNode>() {
+            Self::Alpha
+        } else if any.is::<ClipToBoundsNode>() {
+            Self::ClipToBounds
+        } else if any.is::<DrawCommandNode>() {
+            Self::Draw
+        } else if any.is::<ClickableNode>() {
+            Self::Clickable
+        } else if any.is::<PointerEventHandlerNode>()
+            || any.is::<SecondaryClickNode>()
+            || any.is::<super::pointer_input::SuspendingPointerInputNode>()
+        {
+            Self::PointerInput
+        } else if any.is::<super::semantics::SemanticsModifierNode>() {
+            Self::Semantics
+        } else if any.is::<crate::scroll::ScrollNode>() {
+            Self::Scroll
+        } else if any.is::<super::focus::FocusTargetNode>() {
+            Self::Focus
+        } else if any.is::<super::focus::FocusRequesterNode>() {
+            Self::FocusRequester
+        } else if any.is::<super::local::ModifierLocalProviderNode>()
+            || any.is::<super::local::ModifierLocalConsumerNode>()
+        {
+            Self::ModifierLocal
+        } else if any.is::<crate::TextModifierNode>() {
+            Self::Text
+        } else if any.is::<crate::TextFieldModifierNode>() {
+            Self::TextField
+        } else {
+            Self::Other(type_name_of_val(node))
+        }
+    }
+}
+
 /// Runtime helper that keeps a [`ModifierNodeChain`] in sync with a [`Modifier`].
 ///
 /// This is the first step toward Jetpack Compose parity: callers can keep a handle
@@ -47,6 +140,8 @@ pub struct ModifierChainHandle {
     chain: ModifierNodeChain,
     context: RefCell<BasicModifierNodeContext>,
     resolved: ResolvedModifiers,
+    /// Density the chain's lengths are resolved with.
+    density: Density,
     capabilities: NodeCapabilities,
     aggregate_child_capabilities: NodeCapabilities,
     modifier_locals: ModifierLocalsHandle,
@@ -60,6 +155,7 @@ impl Default for ModifierChainHandle {
             chain: ModifierNodeChain::new(),
             context: RefCell::new(BasicModifierNodeContext::new()),
             resolved: ResolvedModifiers::default(),
+            density: Density::default(),
             capabilities: NodeCapabilities::default(),
             aggregate_child_capabilities: NodeCapabilities::default(),
             modifier_locals: Rc::new(RefCell::new(ModifierLocalManager::new())),
@@ -190,6 +286,22 @@ impl ModifierChainHandle {
         self.resolved
     }
 
+    /// Density of the node that owns this chain.
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    /// Sets the density the chain's lengths are resolved with and reports
+    /// whether it changed.
+    pub(crate) fn set_density(&mut self, density: Density) -> bool {
+        if self.density == density {
+            return false;
+        }
+        self.density = density;
+        self.resolved = self.compute_resolved();
+        true
+    }
+
     pub fn resolve_modifier_local(
         &self,
         token: ModifierLocalToken,
@@ -205,6 +317,19 @@ impl ModifierChainHandle {
         &self.inspector_snapshot
     }
 
+    /// Returns the kinds of the chain's top-level nodes in application order.
+    ///
+    /// Delegated nodes are omitted so each entry corresponds to one modifier element.
+    pub fn modifier_summary(&self) -> Vec<ModifierNodeKind> {
+        let mut summary = Vec::new();
+        self.chain.for_each_forward(|node_ref| {
+            if node_ref.delegate_depth() == 0 {
+                node_ref.with_node(|node| summary.push(ModifierNodeKind::classify(node)));
+            }
+        });
+        summary
+    }
+
     /// Visits all LayoutModifierNodes in the chain with mutable access.
     pub(crate) fn visit_layout_nodes_mut<F>(&mut self, mut f: F)
     where
@@ -218,6 +343,10 @@ impl ModifierChainHandle {
     }
 
     fn compute_resolved(&self) -> ResolvedModifiers {
+        with_measure_density(self.density, || self.resolve_nodes())
+    }
+
+    fn resolve_nodes(&self) -> ResolvedModifiers {
         let mut resolved = ResolvedModifiers::default();
         let mut layout = LayoutProperties::default();
         let mut padding = EdgeInsets::default();
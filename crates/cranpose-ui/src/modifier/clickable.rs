@@ -1,20 +1,139 @@
-use super::{inspector_metadata, Modifier, Point, SemanticsConfiguration};
-use crate::modifier_nodes::ClickableElement;
+use super::{inspector_metadata, Modifier, Point, SemanticsConfiguration, Size};
+use crate::interaction::InteractionSource;
+use crate::modifier_nodes::{ClickableElement, MinimumTouchTargetElement, SecondaryClickElement};
 use std::rc::Rc;
 
+/// Optional behaviour for [`Modifier::clickable_with_options`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClickableOptions {
+    /// Disabled clickables ignore input and expose a disabled semantics state.
+    /// Disabling one mid-press cancels the press.
+    pub enabled: bool,
+    /// Receives press and hover interactions.
+    pub interaction_source: Option<InteractionSource>,
+}
+
+impl Default for ClickableOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interaction_source: None,
+        }
+    }
+}
+
 impl Modifier {
+    /// Expand the area that receives pointer input to at least `size`,
+    /// centred on the component, without changing its layout or drawing.
+    ///
+    /// Matches Material's minimum touch target; `Size { width: 48.0, height: 48.0 }`
+    /// is the usual value. Where expanded targets overlap, a pointer that
+    /// lands on neither component goes to the nearest one.
+    pub fn minimum_touch_target(self, size: Size) -> Self {
+        let modifier = Self::with_element(MinimumTouchTargetElement::new(size))
+            .with_inspector_metadata(inspector_metadata("minimumTouchTarget", move |info| {
+                info.add_property("width", size.width.to_string());
+                info.add_property("height", size.height.to_string());
+            }));
+        self.then(modifier)
+    }
+
     /// Make the component clickable.
     ///
     /// Example: `Modifier::empty().clickable(|pt| println!("Clicked at {:?}", pt))`
     pub fn clickable(self, handler: impl Fn(Point) + 'static) -> Self {
-        let handler = Rc::new(handler);
-        let modifier = Self::with_element(ClickableElement::with_handler(handler))
-            .with_inspector_metadata(inspector_metadata("clickable", |info| {
+        self.clickable_with_options(ClickableOptions::default(), handler)
+    }
+
+    /// Make the component clickable and report press and hover interactions
+    /// to `interaction_source`, so the component can show a pressed state.
+    ///
+    /// A press that is dragged off before release does not click but still
+    /// emits [`Interaction::Cancel`](crate::Interaction::Cancel).
+    pub fn clickable_with_interaction(
+        self,
+        interaction_source: InteractionSource,
+        handler: impl Fn(Point) + 'static,
+    ) -> Self {
+        self.clickable_with_options(
+            ClickableOptions {
+                interaction_source: Some(interaction_source),
+                ..ClickableOptions::default()
+            },
+            handler,
+        )
+    }
+
+    /// Make the component clickable with the given [`ClickableOptions`].
+    pub fn clickable_with_options(
+        self,
+        options: ClickableOptions,
+        handler: impl Fn(Point) + 'static,
+    ) -> Self {
+        let mut element =
+            ClickableElement::with_handler(Rc::new(handler)).with_enabled(options.enabled);
+        if let Some(source) = options.interaction_source {
+            element = element.with_interaction_source(source);
+        }
+        self.clickable_element(element, options.enabled)
+    }
+
+    /// Make the component clickable, telling clicks apart from long clicks
+    /// and double clicks. Pass `None::<fn(Point)>` for a gesture the
+    /// component does not handle.
+    ///
+    /// A press held for
+    /// [`LONG_CLICK_TIMEOUT_MILLIS`](cranpose_foundation::LONG_CLICK_TIMEOUT_MILLIS)
+    /// calls `on_long_click` instead of `on_click`. With `on_double_click`, a
+    /// click waits for
+    /// [`DOUBLE_CLICK_TIMEOUT_MILLIS`](cranpose_foundation::DOUBLE_CLICK_TIMEOUT_MILLIS)
+    /// in case a second one follows: two clicks within that time call only
+    /// `on_double_click`, clicks further apart each call `on_click`.
+    ///
+    /// Mirrors Jetpack Compose's `Modifier.combinedClickable`.
+    pub fn combined_clickable<L, D>(
+        self,
+        on_click: impl Fn(Point) + 'static,
+        on_long_click: Option<L>,
+        on_double_click: Option<D>,
+    ) -> Self
+    where
+        L: Fn(Point) + 'static,
+        D: Fn(Point) + 'static,
+    {
+        let mut element = ClickableElement::new(on_click);
+        if let Some(on_long_click) = on_long_click {
+            element = element.with_long_click(Rc::new(on_long_click));
+        }
+        if let Some(on_double_click) = on_double_click {
+            element = element.with_double_click(Rc::new(on_double_click));
+        }
+        self.clickable_element(element, true)
+    }
+
+    /// Call `handler` when the component is clicked with the secondary
+    /// (right) mouse button, e.g. to open a context menu. Clickables ignore
+    /// secondary presses, so this does not click them.
+    pub fn on_secondary_click(self, handler: impl Fn(Point) + 'static) -> Self {
+        let modifier = Self::with_element(SecondaryClickElement::new(handler))
+            .with_inspector_metadata(inspector_metadata("onSecondaryClick", |info| {
+                info.add_property("onSecondaryClick", "provided");
+            }));
+        self.then(modifier)
+    }
+
+    fn clickable_element(self, element: ClickableElement, enabled: bool) -> Self {
+        let modifier = Self::with_element(element)
+            .with_inspector_metadata(inspector_metadata("clickable", move |info| {
                 info.add_property("onClick", "provided");
+                if !enabled {
+                    info.add_property("enabled", "false");
+                }
             }))
             .then(
-                Modifier::empty().semantics(|config: &mut SemanticsConfiguration| {
+                Modifier::empty().semantics(move |config: &mut SemanticsConfiguration| {
                     config.is_clickable = true;
+                    config.is_disabled = !enabled;
                 }),
             );
         self.then(modifier)
@@ -8,10 +8,13 @@ use std::cell::Cell;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+use cranpose_core::NodeId;
 use cranpose_foundation::{
-    impl_focus_node, DelegatableNode, FocusNode, FocusState, ModifierNode, ModifierNodeContext,
-    ModifierNodeElement, NodeCapabilities, NodeState,
+    impl_draw_node, impl_focus_node, DelegatableNode, DrawModifierNode, FocusNode, FocusState,
+    InvalidationKind, ModifierNode, ModifierNodeContext, ModifierNodeElement, NodeCapabilities,
+    NodeState,
 };
+use cranpose_ui_graphics::{Brush, Color, CornerRadii, DrawPrimitive, Rect, Size};
 
 /// Focus direction for navigation.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -43,6 +46,8 @@ pub struct FocusTargetNode {
     state: NodeState,
     focus_state: Cell<FocusState>,
     on_focus_changed: Option<Rc<dyn Fn(FocusState)>>,
+    /// Disabled targets cannot take focus.
+    enabled: bool,
 }
 
 impl FocusTargetNode {
@@ -51,6 +56,7 @@ impl FocusTargetNode {
             state: NodeState::new(),
             focus_state: Cell::new(FocusState::Inactive),
             on_focus_changed: None,
+            enabled: true,
         }
     }
 
@@ -62,6 +68,7 @@ impl FocusTargetNode {
             state: NodeState::new(),
             focus_state: Cell::new(FocusState::Inactive),
             on_focus_changed: Some(Rc::new(callback)),
+            enabled: true,
         }
     }
 
@@ -76,17 +83,23 @@ impl FocusTargetNode {
         }
     }
 
-    /// Requests focus for this node.
-    #[allow(dead_code)] // TODO: used in future focus manager integration
+    /// Requests focus for this node. Disabled targets refuse it.
     pub fn request_focus(&self) -> bool {
-        // This will be wired up to the focus manager in the next phase
-        true
+        self.enabled
     }
 
     /// Clears focus from this node.
     pub fn clear_focus(&self) {
         self.set_focus_state(FocusState::Inactive);
     }
+
+    /// Disabling a focused target drops its focus.
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.clear_focus();
+        }
+    }
 }
 
 impl Default for FocusTargetNode {
@@ -131,12 +144,14 @@ impl FocusNode for FocusTargetNode {
 #[derive(Clone)]
 pub struct FocusTargetElement {
     on_focus_changed: Option<Rc<dyn Fn(FocusState)>>,
+    enabled: bool,
 }
 
 impl FocusTargetElement {
     pub fn new() -> Self {
         Self {
             on_focus_changed: None,
+            enabled: true,
         }
     }
 
@@ -146,8 +161,15 @@ impl FocusTargetElement {
     {
         Self {
             on_focus_changed: Some(Rc::new(callback)),
+            enabled: true,
         }
     }
+
+    /// Disabled targets are skipped by focus traversal.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
 }
 
 impl Default for FocusTargetElement {
@@ -160,6 +182,7 @@ impl std::fmt::Debug for FocusTargetElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FocusTargetElement")
             .field("has_callback", &self.on_focus_changed.is_some())
+            .field("enabled", &self.enabled)
             .finish()
     }
 }
@@ -184,18 +207,21 @@ impl ModifierNodeElement for FocusTargetElement {
     type Node = FocusTargetNode;
 
     fn create(&self) -> Self::Node {
-        if let Some(callback) = &self.on_focus_changed {
+        let mut node = if let Some(callback) = &self.on_focus_changed {
             FocusTargetNode::with_callback({
                 let callback = callback.clone();
                 move |state| callback(state)
             })
         } else {
             FocusTargetNode::new()
-        }
+        };
+        node.enabled = self.enabled;
+        node
     }
 
     fn update(&self, node: &mut Self::Node) {
         node.on_focus_changed = self.on_focus_changed.clone();
+        node.set_enabled(self.enabled);
     }
 
     fn inspector_name(&self) -> &'static str {
@@ -288,6 +314,115 @@ impl ModifierNodeElement for FocusRequesterElement {
     }
 }
 
+/// Draws a focus ring around its layout node while that node is the active
+/// focus target.
+///
+/// The ring is evaluated at render time against [`crate::active_focus_target`],
+/// so moving focus only needs a scene rebuild, not recomposition.
+pub struct FocusBorderNode {
+    state: NodeState,
+    owner: Rc<Cell<Option<NodeId>>>,
+    width: f32,
+    color: Color,
+}
+
+impl FocusBorderNode {
+    pub fn new(width: f32, color: Color) -> Self {
+        Self {
+            state: NodeState::new(),
+            owner: Rc::new(Cell::new(None)),
+            width,
+            color,
+        }
+    }
+}
+
+impl DelegatableNode for FocusBorderNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for FocusBorderNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        self.state.set_attached(true);
+        self.owner.set(context.node_id());
+        context.invalidate(InvalidationKind::Draw);
+    }
+
+    fn on_detach(&mut self) {
+        self.state.set_attached(false);
+        self.owner.set(None);
+    }
+
+    impl_draw_node!();
+}
+
+impl DrawModifierNode for FocusBorderNode {
+    fn create_draw_closure(&self) -> Option<Rc<dyn Fn(Size) -> Vec<DrawPrimitive>>> {
+        let owner = self.owner.clone();
+        let width = self.width;
+        let color = self.color;
+        Some(Rc::new(move |size: Size| {
+            let focused = owner.get().is_some() && owner.get() == crate::active_focus_target();
+            if !focused || width <= 0.0 {
+                return Vec::new();
+            }
+            vec![DrawPrimitive::Stroke {
+                rect: Rect::from_size(size),
+                brush: Brush::solid(color),
+                radii: CornerRadii::default(),
+                width,
+            }]
+        }))
+    }
+}
+
+/// Modifier element for focus rings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusBorderElement {
+    width: f32,
+    color: Color,
+}
+
+impl FocusBorderElement {
+    pub fn new(width: f32, color: Color) -> Self {
+        Self { width, color }
+    }
+}
+
+impl Hash for FocusBorderElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "focus_border".hash(state);
+        self.width.to_bits().hash(state);
+        self.color.0.to_bits().hash(state);
+        self.color.1.to_bits().hash(state);
+        self.color.2.to_bits().hash(state);
+        self.color.3.to_bits().hash(state);
+    }
+}
+
+impl ModifierNodeElement for FocusBorderElement {
+    type Node = FocusBorderNode;
+
+    fn create(&self) -> Self::Node {
+        FocusBorderNode::new(self.width, self.color)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.width = self.width;
+        node.color = self.color;
+    }
+
+    fn inspector_name(&self) -> &'static str {
+        "focusBorder"
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
+    }
+}
+
 /// A handle for requesting focus programmatically.
 ///
 /// This mirrors Jetpack Compose's FocusRequester class and provides
@@ -0,0 +1,348 @@
+//! Press indications drawn from an [`InteractionSource`].
+//!
+//! [`Modifier::indication`] listens to the interactions a clickable emits and
+//! draws visual feedback over the node. [`ripple`] is the Material indication:
+//! a circle that grows from the press point and fades out on release.
+
+use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use cranpose_animation::{Animatable, AnimationSpec, AnimationType, Easing};
+use cranpose_foundation::{
+    impl_draw_node, DelegatableNode, DrawModifierNode, InvalidationKind, ModifierNode,
+    ModifierNodeContext, ModifierNodeElement, NodeCapabilities, NodeState,
+};
+use cranpose_ui_graphics::{
+    Brush, Color, CornerRadii, DrawPrimitive, Point, Rect, RoundedCornerShape, Size,
+};
+
+use super::{inspector_metadata, modifier_element, Modifier};
+use crate::interaction::{Interaction, InteractionSource};
+
+/// Time for a ripple to grow to its full radius.
+const RIPPLE_EXPAND_MILLIS: u64 = 225;
+/// Time for a released ripple to fade out.
+const RIPPLE_FADE_MILLIS: u64 = 150;
+
+/// Configuration of the ripple indication. Create one with [`ripple`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ripple {
+    color: Color,
+    radius: Option<f32>,
+    shape: Option<RoundedCornerShape>,
+}
+
+/// A Material ripple: a translucent circle expanding from the press point,
+/// clipped to the node.
+pub fn ripple() -> Ripple {
+    Ripple {
+        color: Color(1.0, 1.0, 1.0, 0.24),
+        radius: None,
+        shape: None,
+    }
+}
+
+impl Ripple {
+    /// Colour of the ripple; its alpha is the alpha of a held press.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Fixed final radius. By default the ripple grows until it covers the
+    /// node from the press point.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Corner shape of the node, so the ripple keeps its rounded corners.
+    pub fn with_shape(mut self, shape: RoundedCornerShape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+}
+
+impl Modifier {
+    /// Draw `indication` over this component in response to the
+    /// interactions emitted into `interaction_source`.
+    ///
+    /// Example: `Modifier::empty().clickable_with_interaction(source.clone(), on_click).indication(source, ripple())`
+    pub fn indication(self, interaction_source: InteractionSource, indication: Ripple) -> Self {
+        let element = IndicationElement::new(interaction_source, indication);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)])
+            .with_inspector_metadata(inspector_metadata("indication", |info| {
+                info.add_property("indication", "ripple");
+            }));
+        self.then(modifier)
+    }
+}
+
+/// One press. Every press gets its own ripple so quick repeated presses
+/// overlap instead of restarting a single animation.
+struct RippleInstance {
+    origin: Point,
+    /// Grows from 0 to 1 while the ripple expands.
+    progress: Animatable<f32>,
+    /// Falls from 1 to 0 once the press ends.
+    alpha: Animatable<f32>,
+    released: bool,
+}
+
+impl RippleInstance {
+    fn is_finished(&self) -> bool {
+        self.released && self.alpha.state().value() <= 0.0
+    }
+
+    fn is_animating(&self) -> bool {
+        self.progress.state().value() < 1.0 || (self.released && !self.is_finished())
+    }
+}
+
+#[derive(Default)]
+struct RippleState {
+    ripples: RefCell<Vec<RippleInstance>>,
+    /// Runtime that drives the ripple animations, known once attached.
+    runtime: RefCell<Option<cranpose_core::RuntimeHandle>>,
+}
+
+impl RippleState {
+    fn on_interaction(&self, interaction: &Interaction) {
+        match *interaction {
+            Interaction::Press { position } => {
+                let Some(runtime) = self.runtime.borrow().clone() else {
+                    return;
+                };
+                let mut progress = Animatable::new(0.0, runtime.clone());
+                progress.animateTo(
+                    1.0,
+                    AnimationType::Tween(AnimationSpec::tween(
+                        RIPPLE_EXPAND_MILLIS,
+                        Easing::FastOutSlowInEasing,
+                    )),
+                );
+                self.ripples.borrow_mut().push(RippleInstance {
+                    origin: position,
+                    progress,
+                    alpha: Animatable::new(1.0, runtime),
+                    released: false,
+                });
+            }
+            Interaction::Release { .. } | Interaction::Cancel { .. } => {
+                for ripple in self.ripples.borrow_mut().iter_mut() {
+                    if !ripple.released {
+                        ripple.released = true;
+                        ripple.alpha.animateTo(
+                            0.0,
+                            AnimationType::Tween(AnimationSpec::linear(RIPPLE_FADE_MILLIS)),
+                        );
+                    }
+                }
+            }
+            Interaction::Enter | Interaction::Exit => return,
+        }
+        crate::request_render_invalidation();
+    }
+}
+
+/// Node that draws ripples for the presses of an [`InteractionSource`].
+pub struct IndicationNode {
+    state: NodeState,
+    source: InteractionSource,
+    subscription: Option<u64>,
+    ripple: Rc<Cell<Ripple>>,
+    ripples: Rc<RippleState>,
+}
+
+impl IndicationNode {
+    pub fn new(source: InteractionSource, ripple: Ripple) -> Self {
+        Self {
+            state: NodeState::new(),
+            source,
+            subscription: None,
+            ripple: Rc::new(Cell::new(ripple)),
+            ripples: Rc::new(RippleState::default()),
+        }
+    }
+
+    fn subscribe(&mut self) {
+        let ripples = self.ripples.clone();
+        self.subscription = Some(
+            self.source
+                .subscribe(move |interaction| ripples.on_interaction(interaction)),
+        );
+    }
+
+    fn unsubscribe(&mut self) {
+        if let Some(id) = self.subscription.take() {
+            self.source.unsubscribe(id);
+        }
+    }
+}
+
+impl DelegatableNode for IndicationNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for IndicationNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        self.state.set_attached(true);
+        *self.ripples.runtime.borrow_mut() = cranpose_core::current_runtime_handle();
+        self.subscribe();
+        context.invalidate(InvalidationKind::Draw);
+    }
+
+    fn on_detach(&mut self) {
+        self.state.set_attached(false);
+        self.unsubscribe();
+        self.ripples.ripples.borrow_mut().clear();
+    }
+
+    impl_draw_node!();
+}
+
+impl DrawModifierNode for IndicationNode {
+    fn create_draw_closure(&self) -> Option<Rc<dyn Fn(Size) -> Vec<DrawPrimitive>>> {
+        let ripple = self.ripple.clone();
+        let ripples = self.ripples.clone();
+        Some(Rc::new(move |size: Size| {
+            let ripple = ripple.get();
+            let mut instances = ripples.ripples.borrow_mut();
+            instances.retain(|instance| !instance.is_finished());
+            // Animation frames update the ripple values but do not rebuild
+            // the scene, so keep asking for one until every ripple settles.
+            if instances.iter().any(RippleInstance::is_animating) {
+                crate::request_render_invalidation();
+            }
+            let shape_radii = ripple
+                .shape
+                .map(|shape| shape.resolve(size.width, size.height))
+                .unwrap_or_default();
+            instances
+                .iter()
+                .filter_map(|instance| {
+                    let full_radius = ripple
+                        .radius
+                        .unwrap_or_else(|| farthest_corner_distance(instance.origin, size));
+                    let radius = full_radius * instance.progress.state().value();
+                    let alpha = ripple.color.3 * instance.alpha.state().value();
+                    let color = Color(ripple.color.0, ripple.color.1, ripple.color.2, alpha);
+                    clipped_circle(instance.origin, radius, size, shape_radii, color)
+                })
+                .collect()
+        }))
+    }
+}
+
+fn farthest_corner_distance(origin: Point, size: Size) -> f32 {
+    let dx = origin.x.max(size.width - origin.x);
+    let dy = origin.y.max(size.height - origin.y);
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// The circle at `center` intersected with the node's bounds.
+///
+/// Renderers only clip to rectangles, so the intersection is approximated by
+/// a rounded rectangle: corners still inside the node keep the circle's
+/// curvature, and corners cut off on both sides take the node's own radii.
+fn clipped_circle(
+    center: Point,
+    radius: f32,
+    size: Size,
+    shape_radii: CornerRadii,
+    color: Color,
+) -> Option<DrawPrimitive> {
+    if radius <= 0.0 || color.3 <= 0.0 {
+        return None;
+    }
+    let (left, top) = (center.x - radius, center.y - radius);
+    let (right, bottom) = (center.x + radius, center.y + radius);
+    let clip_left = left < 0.0;
+    let clip_top = top < 0.0;
+    let clip_right = right > size.width;
+    let clip_bottom = bottom > size.height;
+
+    let rect = Rect {
+        x: left.max(0.0),
+        y: top.max(0.0),
+        width: right.min(size.width) - left.max(0.0),
+        height: bottom.min(size.height) - top.max(0.0),
+    };
+    if rect.width <= 0.0 || rect.height <= 0.0 {
+        return None;
+    }
+    let max_radius = (rect.width / 2.0).min(rect.height / 2.0);
+    let corner = |horizontal: bool, vertical: bool, shape_radius: f32| {
+        let corner_radius = if horizontal && vertical {
+            shape_radius
+        } else {
+            radius
+        };
+        corner_radius.min(max_radius)
+    };
+    let radii = CornerRadii {
+        top_left: corner(clip_left, clip_top, shape_radii.top_left),
+        top_right: corner(clip_right, clip_top, shape_radii.top_right),
+        bottom_right: corner(clip_right, clip_bottom, shape_radii.bottom_right),
+        bottom_left: corner(clip_left, clip_bottom, shape_radii.bottom_left),
+    };
+    Some(DrawPrimitive::RoundRect {
+        rect,
+        brush: Brush::solid(color),
+        radii,
+    })
+}
+
+/// Modifier element for [`Modifier::indication`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicationElement {
+    source: InteractionSource,
+    ripple: Ripple,
+}
+
+impl IndicationElement {
+    pub fn new(source: InteractionSource, ripple: Ripple) -> Self {
+        Self { source, ripple }
+    }
+}
+
+impl Hash for IndicationElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "indication".hash(state);
+    }
+}
+
+impl ModifierNodeElement for IndicationElement {
+    type Node = IndicationNode;
+
+    fn create(&self) -> Self::Node {
+        IndicationNode::new(self.source.clone(), self.ripple)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.ripple.set(self.ripple);
+        if node.source != self.source {
+            let attached = node.subscription.is_some();
+            node.unsubscribe();
+            node.source = self.source.clone();
+            if attached {
+                node.subscribe();
+            }
+        }
+    }
+
+    fn inspector_name(&self) -> &'static str {
+        "indication"
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/indication_tests.rs"]
+mod tests;
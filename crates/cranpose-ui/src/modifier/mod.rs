@@ -12,12 +12,14 @@ use std::rc::Rc;
 
 mod alignment;
 mod background;
+mod bring_into_view;
 mod chain;
 mod clickable;
 mod draw_cache;
 mod fill;
 mod focus;
 mod graphics_layer;
+mod indication;
 mod local;
 mod offset;
 mod padding;
@@ -27,10 +29,17 @@ mod semantics;
 mod size;
 mod slices;
 mod weight;
+mod window_insets;
 
 pub use crate::draw::{DrawCacheBuilder, DrawCommand};
+
+pub(crate) use bring_into_view::bring_into_view;
+pub use bring_into_view::BringIntoViewRequester;
 #[allow(unused_imports)]
-pub use chain::{ModifierChainHandle, ModifierChainInspectorNode, ModifierLocalsHandle};
+pub use chain::{
+    ModifierChainHandle, ModifierChainInspectorNode, ModifierLocalsHandle, ModifierNodeKind,
+};
+pub use clickable::ClickableOptions;
 use cranpose_foundation::ModifierNodeElement;
 pub use cranpose_foundation::{
     modifier_element, AnyModifierElement, DynModifierElement, FocusState, PointerEvent,
@@ -42,6 +51,7 @@ pub use cranpose_ui_graphics::{
 use cranpose_ui_layout::{Alignment, HorizontalAlignment, IntrinsicSize, VerticalAlignment};
 #[allow(unused_imports)]
 pub use focus::{FocusDirection, FocusRequester};
+pub use indication::{ripple, Ripple};
 pub(crate) use local::{
     ModifierLocalAncestorResolver, ModifierLocalSource, ModifierLocalToken, ResolvedModifierLocal,
 };
@@ -56,7 +66,8 @@ pub use slices::{collect_modifier_slices, collect_slices_from_modifier, Modifier
 pub use scroll::{last_fling_velocity, reset_last_fling_velocity};
 
 use crate::modifier_nodes::ClipToBoundsElement;
-use focus::{FocusRequesterElement, FocusTargetElement};
+pub(crate) use focus::FocusTargetNode;
+use focus::{FocusBorderElement, FocusRequesterElement, FocusTargetElement};
 use local::{ModifierLocalConsumerElement, ModifierLocalProviderElement};
 use semantics::SemanticsElement;
 
@@ -90,16 +101,6 @@ impl InspectorInfo {
         self.add_property(name, describe_dimension(constraint));
     }
 
-    pub fn add_offset_components(
-        &mut self,
-        x_name: &'static str,
-        y_name: &'static str,
-        offset: Point,
-    ) {
-        self.add_property(x_name, offset.x.to_string());
-        self.add_property(y_name, offset.y.to_string());
-    }
-
     pub fn add_alignment<A>(&mut self, name: &'static str, alignment: A)
     where
         A: fmt::Debug,
@@ -324,6 +325,14 @@ impl Modifier {
         let description = preview.content_description.clone();
         let is_button = preview.is_button;
         let is_clickable = preview.is_clickable;
+        let is_disabled = preview.is_disabled;
+        let is_focusable = preview.is_focusable;
+        let role = preview.role;
+        let state_description = preview.state_description.clone();
+        let merge_descendants = preview.is_merging_semantics_of_descendants;
+        let clear_descendants = preview.is_clearing_semantics;
+        let traversal_index = preview.traversal_index;
+        let test_tag = preview.test_tag.clone();
         let metadata = inspector_metadata("semantics", move |info| {
             if let Some(desc) = &description {
                 info.add_property("contentDescription", desc.clone());
@@ -334,6 +343,30 @@ impl Modifier {
             if is_clickable {
                 info.add_property("isClickable", "true");
             }
+            if is_disabled {
+                info.add_property("disabled", "true");
+            }
+            if is_focusable {
+                info.add_property("focusable", "true");
+            }
+            if let Some(role) = role {
+                info.add_property("role", format!("{role:?}"));
+            }
+            if let Some(state) = &state_description {
+                info.add_property("stateDescription", state.clone());
+            }
+            if merge_descendants {
+                info.add_property("mergeDescendants", "true");
+            }
+            if clear_descendants {
+                info.add_property("clearAndSetSemantics", "true");
+            }
+            if let Some(index) = traversal_index {
+                info.add_property("traversalIndex", index.to_string());
+            }
+            if let Some(tag) = &test_tag {
+                info.add_property("testTag", tag.clone());
+            }
         });
         let element = SemanticsElement::new(recorder);
         let modifier =
@@ -341,6 +374,45 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Adds semantics and merges the semantics of all descendants into this node.
+    ///
+    /// Composite components such as buttons use this to expose one accessible
+    /// node whose description combines the text of their children. Descendants
+    /// that merge their own descendants stay separate nodes.
+    ///
+    /// Mirrors Jetpack Compose's `Modifier.semantics(mergeDescendants = true)`.
+    pub fn merge_descendants_semantics<F>(self, recorder: F) -> Self
+    where
+        F: Fn(&mut SemanticsConfiguration) + 'static,
+    {
+        self.semantics(move |config| {
+            recorder(config);
+            config.is_merging_semantics_of_descendants = true;
+        })
+    }
+
+    /// Replaces the semantics of this node and drops all descendant semantics.
+    ///
+    /// Mirrors Jetpack Compose's `Modifier.clearAndSetSemantics`.
+    pub fn clear_and_set_semantics<F>(self, recorder: F) -> Self
+    where
+        F: Fn(&mut SemanticsConfiguration) + 'static,
+    {
+        self.semantics(move |config| {
+            recorder(config);
+            config.is_clearing_semantics = true;
+        })
+    }
+
+    /// Tags this node so tests can find it, e.g. with a robot's `find_by_tag`.
+    ///
+    /// Only identifies the node: the actions a test can perform on it come
+    /// from its other modifiers. Mirrors Jetpack Compose's `Modifier.testTag`.
+    pub fn test_tag(self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        self.semantics(move |config| config.test_tag = Some(tag.clone()))
+    }
+
     /// Makes this component focusable.
     ///
     /// This adds a focus target node that can receive focus and participate
@@ -352,6 +424,17 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Makes this component focusable while `enabled` is true.
+    ///
+    /// Disabled components keep their focus target but drop any focus they
+    /// hold and are skipped by [`SemanticsTree::focus_order`](crate::SemanticsTree::focus_order).
+    pub fn focusable(self, enabled: bool) -> Self {
+        let element = FocusTargetElement::new().with_enabled(enabled);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)]);
+        self.then(modifier)
+            .semantics(move |config| config.is_focusable = enabled)
+    }
+
     /// Makes this component focusable with a callback for focus changes.
     ///
     /// The callback is invoked whenever the focus state changes, allowing
@@ -375,6 +458,16 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Draws a `width`-pixel focus ring inside this component's bounds while it
+    /// is the active focus target.
+    ///
+    /// Moving focus elsewhere removes the ring on the next frame.
+    pub fn focus_border(self, width: f32, color: Color) -> Self {
+        let element = FocusBorderElement::new(width, color);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)]);
+        self.then(modifier)
+    }
+
     /// Enables debug logging for this modifier chain.
     ///
     /// When enabled, logs the entire modifier chain structure including:
@@ -2,40 +2,49 @@
 //!
 //! Reference: /media/huge/composerepo/compose/foundation/foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Offset.kt
 
-use super::{inspector_metadata, Modifier, Point};
+use super::{inspector_metadata, Modifier};
+use crate::density::Length;
 use crate::modifier_nodes::OffsetElement;
 
 impl Modifier {
     /// Offset the content by (x, y). The offsets can be positive or negative.
     ///
+    /// The offset only moves placement: the measured size is unchanged, so the
+    /// parent sizes itself as if there were no offset. Content moved outside the
+    /// parent still draws and receives input unless an ancestor clips it with
+    /// [`Modifier::clip_to_bounds`].
+    ///
     /// This modifier is RTL-aware: positive x offsets move content right in LTR
     /// and left in RTL layouts.
     ///
     /// Matches Kotlin: `Modifier.offset(x: Dp, y: Dp)`
     ///
     /// Example: `Modifier::empty().offset(10.0, 20.0)`
-    pub fn offset(self, x: f32, y: f32) -> Self {
-        let modifier = Self::with_element(OffsetElement::new(x, y, true)).with_inspector_metadata(
-            inspector_metadata("offset", move |info| {
-                info.add_offset_components("offsetX", "offsetY", Point { x, y });
-            }),
-        );
+    pub fn offset(self, x: impl Length, y: impl Length) -> Self {
+        let (x, y) = (x.to_layout_length(), y.to_layout_length());
+        let modifier = Self::with_element(OffsetElement::from_lengths(x, y, true))
+            .with_inspector_metadata(inspector_metadata("offset", move |info| {
+                info.add_property("offsetX", x.to_string());
+                info.add_property("offsetY", y.to_string());
+            }));
         self.then(modifier)
     }
 
     /// Offset the content by (x, y) without considering layout direction.
     ///
-    /// Positive x always moves content to the right regardless of RTL.
+    /// Positive x always moves content to the right regardless of RTL. Sizing and
+    /// clipping behave as for [`Modifier::offset`].
     ///
     /// Matches Kotlin: `Modifier.absoluteOffset(x: Dp, y: Dp)`
     ///
     /// Example: `Modifier::empty().absolute_offset(10.0, 20.0)`
-    pub fn absolute_offset(self, x: f32, y: f32) -> Self {
-        let modifier = Self::with_element(OffsetElement::new(x, y, false)).with_inspector_metadata(
-            inspector_metadata("absoluteOffset", move |info| {
-                info.add_offset_components("absoluteOffsetX", "absoluteOffsetY", Point { x, y });
-            }),
-        );
+    pub fn absolute_offset(self, x: impl Length, y: impl Length) -> Self {
+        let (x, y) = (x.to_layout_length(), y.to_layout_length());
+        let modifier = Self::with_element(OffsetElement::from_lengths(x, y, false))
+            .with_inspector_metadata(inspector_metadata("absoluteOffset", move |info| {
+                info.add_property("absoluteOffsetX", x.to_string());
+                info.add_property("absoluteOffsetY", y.to_string());
+            }));
         self.then(modifier)
     }
 }
@@ -1,59 +1,118 @@
 use super::{inspector_metadata, EdgeInsets, InspectorMetadata, Modifier};
+use crate::density::{LayoutLength, Length, LengthInsets};
 use crate::modifier_nodes::PaddingElement;
 
 impl Modifier {
     /// Add uniform padding to all sides.
     ///
-    /// Example: `Modifier::empty().padding(16.0)`
-    pub fn padding(self, p: f32) -> Self {
-        let padding = EdgeInsets::uniform(p);
-        let modifier = Self::with_element(PaddingElement::new(padding))
-            .with_inspector_metadata(padding_metadata(padding));
-        self.then(modifier)
+    /// Accepts layout pixels or [`Dp`](crate::Dp), which is resolved with the
+    /// [`LocalDensity`](crate::LocalDensity) of the node when it is measured.
+    ///
+    /// Example: `Modifier::empty().padding(16.0)` or `Modifier::empty().padding(Dp(16.0))`
+    pub fn padding(self, p: impl Length) -> Self {
+        let p = p.to_layout_length();
+        self.padding_lengths(p, p, p, p)
     }
 
     /// Add horizontal padding (left and right).
     ///
     /// Example: `Modifier::empty().padding_horizontal(16.0)`
-    pub fn padding_horizontal(self, horizontal: f32) -> Self {
-        let padding = EdgeInsets::horizontal(horizontal);
-        let modifier = Self::with_element(PaddingElement::new(padding))
-            .with_inspector_metadata(padding_metadata(padding));
-        self.then(modifier)
+    pub fn padding_horizontal(self, horizontal: impl Length) -> Self {
+        let horizontal = horizontal.to_layout_length();
+        self.padding_lengths(
+            horizontal,
+            LayoutLength::default(),
+            horizontal,
+            LayoutLength::default(),
+        )
     }
 
     /// Add vertical padding (top and bottom).
     ///
     /// Example: `Modifier::empty().padding_vertical(8.0)`
-    pub fn padding_vertical(self, vertical: f32) -> Self {
-        let padding = EdgeInsets::vertical(vertical);
-        let modifier = Self::with_element(PaddingElement::new(padding))
-            .with_inspector_metadata(padding_metadata(padding));
-        self.then(modifier)
+    pub fn padding_vertical(self, vertical: impl Length) -> Self {
+        let vertical = vertical.to_layout_length();
+        self.padding_lengths(
+            LayoutLength::default(),
+            vertical,
+            LayoutLength::default(),
+            vertical,
+        )
     }
 
     /// Add symmetric padding (horizontal and vertical).
     ///
     /// Example: `Modifier::empty().padding_symmetric(16.0, 8.0)`
-    pub fn padding_symmetric(self, horizontal: f32, vertical: f32) -> Self {
-        let padding = EdgeInsets::symmetric(horizontal, vertical);
-        let modifier = Self::with_element(PaddingElement::new(padding))
-            .with_inspector_metadata(padding_metadata(padding));
-        self.then(modifier)
+    pub fn padding_symmetric(self, horizontal: impl Length, vertical: impl Length) -> Self {
+        let (horizontal, vertical) = (horizontal.to_layout_length(), vertical.to_layout_length());
+        self.padding_lengths(horizontal, vertical, horizontal, vertical)
+    }
+
+    /// Add padding described by `insets`, which may differ per side.
+    ///
+    /// Negative components are clamped to zero; use `offset` to shift content instead.
+    ///
+    /// Example: `Modifier::empty().padding_insets(EdgeInsets::from_components(4.0, 8.0, 12.0, 16.0))`
+    pub fn padding_insets(self, insets: EdgeInsets) -> Self {
+        self.padding_length_insets(insets.into())
     }
 
     /// Add padding to each side individually.
     ///
     /// Example: `Modifier::empty().padding_each(8.0, 4.0, 8.0, 4.0)`
-    pub fn padding_each(self, left: f32, top: f32, right: f32, bottom: f32) -> Self {
-        let padding = EdgeInsets::from_components(left, top, right, bottom);
-        let modifier = Self::with_element(PaddingElement::new(padding))
+    pub fn padding_each(
+        self,
+        left: impl Length,
+        top: impl Length,
+        right: impl Length,
+        bottom: impl Length,
+    ) -> Self {
+        self.padding_lengths(
+            left.to_layout_length(),
+            top.to_layout_length(),
+            right.to_layout_length(),
+            bottom.to_layout_length(),
+        )
+    }
+
+    /// Add padding in left, top, right, bottom order; same as
+    /// [`padding_each`](Self::padding_each).
+    ///
+    /// Example: `Modifier::empty().padding_ltrb(4.0, 8.0, 12.0, 16.0)`
+    pub fn padding_ltrb(
+        self,
+        left: impl Length,
+        top: impl Length,
+        right: impl Length,
+        bottom: impl Length,
+    ) -> Self {
+        self.padding_each(left, top, right, bottom)
+    }
+
+    fn padding_lengths(
+        self,
+        left: LayoutLength,
+        top: LayoutLength,
+        right: LayoutLength,
+        bottom: LayoutLength,
+    ) -> Self {
+        self.padding_length_insets(LengthInsets {
+            left,
+            top,
+            right,
+            bottom,
+        })
+    }
+
+    fn padding_length_insets(self, insets: LengthInsets) -> Self {
+        let padding = insets.non_negative();
+        let modifier = Self::with_element(PaddingElement::from_lengths(padding))
             .with_inspector_metadata(padding_metadata(padding));
         self.then(modifier)
     }
 }
 
-fn padding_metadata(padding: EdgeInsets) -> InspectorMetadata {
+fn padding_metadata(padding: LengthInsets) -> InspectorMetadata {
     inspector_metadata("padding", |info| {
         info.add_property("paddingLeft", padding.left.to_string());
         info.add_property("paddingTop", padding.top.to_string());
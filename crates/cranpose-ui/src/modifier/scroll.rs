@@ -12,17 +12,21 @@
 //! 2. **Move**: Check if total movement exceeds `DRAG_THRESHOLD` (8px)
 //!    - If threshold crossed: start consuming events, apply scroll delta
 //!    - This prevents child click handlers from firing during scrolls
+//!    - The drag also locks the gesture, so enclosing scrollables stop
+//!      receiving it and cannot take it over along their own axis
 //! 3. **Up/Cancel**: Clean up state, consume if was dragging
 
+use super::bring_into_view::scroll_viewport;
 use super::{inspector_metadata, Modifier, Point, PointerEventKind};
 use crate::current_density;
 use crate::fling_animation::FlingAnimation;
 use crate::fling_animation::MIN_FLING_VELOCITY;
+use crate::fling_animation::{SnapAnimation, SnapFlingBehavior};
 use crate::scroll::{ScrollElement, ScrollState};
 use cranpose_core::current_runtime_handle;
 use cranpose_foundation::{
-    velocity_tracker::ASSUME_STOPPED_MS, PointerButton, PointerButtons, VelocityTracker1D,
-    DRAG_THRESHOLD, MAX_FLING_VELOCITY,
+    velocity_tracker::ASSUME_STOPPED_MS, PointerButton, PointerEvent, ScrollAxisRange,
+    SemanticsActionHandler, VelocityTracker1D, DRAG_THRESHOLD, MAX_FLING_VELOCITY,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -111,6 +115,9 @@ struct ScrollGestureState {
 
     /// Current fling animation (if any).
     fling_animation: Option<FlingAnimation>,
+
+    /// Current snap animation (if any), for targets that settle on items.
+    snap_animation: Option<SnapAnimation>,
 }
 
 impl Default for ScrollGestureState {
@@ -123,6 +130,7 @@ impl Default for ScrollGestureState {
             gesture_start_time: None,
             last_velocity_sample_ms: None,
             fling_animation: None,
+            snap_animation: None,
         }
     }
 }
@@ -176,6 +184,12 @@ trait ScrollTarget: Clone {
 
     /// Get the current scroll offset.
     fn current_offset(&self) -> f32;
+
+    /// Settle the target with `behavior` after a gesture ending at `velocity`
+    /// (positive scrolls forward). Targets without items do not snap.
+    fn snap(&self, _behavior: &SnapFlingBehavior, _velocity: f32) -> Option<SnapAnimation> {
+        None
+    }
 }
 
 impl ScrollTarget for ScrollState {
@@ -220,6 +234,10 @@ impl ScrollTarget for LazyListState {
         // LazyListState doesn't have a simple offset - use first visible item offset
         self.first_visible_item_scroll_offset()
     }
+
+    fn snap(&self, behavior: &SnapFlingBehavior, velocity: f32) -> Option<SnapAnimation> {
+        behavior.perform_fling(*self, velocity)
+    }
 }
 
 /// Generic scroll gesture detector that works with any ScrollTarget.
@@ -239,6 +257,9 @@ struct ScrollGestureDetector<S: ScrollTarget> {
 
     /// Whether to reverse the scroll direction (flip delta).
     reverse_scrolling: bool,
+
+    /// Snaps the target onto an item boundary instead of a free fling.
+    fling_behavior: Option<SnapFlingBehavior>,
 }
 
 impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
@@ -248,12 +269,14 @@ impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
         scroll_target: S,
         is_vertical: bool,
         reverse_scrolling: bool,
+        fling_behavior: Option<SnapFlingBehavior>,
     ) -> Self {
         Self {
             gesture_state,
             scroll_target,
             is_vertical,
             reverse_scrolling,
+            fling_behavior,
         }
     }
 
@@ -268,10 +291,13 @@ impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
     fn on_down(&self, position: Point) -> bool {
         let mut gs = self.gesture_state.borrow_mut();
 
-        // Cancel any running fling animation
+        // Cancel any running fling or snap animation
         if let Some(fling) = gs.fling_animation.take() {
             fling.cancel();
         }
+        if let Some(snap) = gs.snap_animation.take() {
+            snap.cancel();
+        }
 
         gs.drag_down_position = Some(position);
         gs.last_position = Some(position);
@@ -298,15 +324,17 @@ impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
     /// 1. Safety check: if no primary button is pressed but we think we're
     ///    tracking, we missed an Up event - reset state.
     /// 2. Calculate total movement from down position.
-    /// 3. If total movement exceeds `DRAG_THRESHOLD` (8px), start dragging.
+    /// 3. If total movement exceeds `DRAG_THRESHOLD` (8px), start dragging
+    ///    and lock the gesture to this node.
     /// 4. While dragging, apply scroll delta and consume events.
     ///
     /// Returns `true` if event should be consumed (we're actively dragging).
-    fn on_move(&self, position: Point, buttons: PointerButtons) -> bool {
+    fn on_move(&self, event: &PointerEvent) -> bool {
+        let position = event.position;
         let mut gs = self.gesture_state.borrow_mut();
 
         // Safety: detect missed Up events (hit test delivered to wrong target)
-        if !buttons.contains(PointerButton::Primary) && gs.drag_down_position.is_some() {
+        if !event.buttons.contains(PointerButton::Primary) && gs.drag_down_position.is_some() {
             gs.drag_down_position = None;
             gs.last_position = None;
             gs.is_dragging = false;
@@ -331,6 +359,7 @@ impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
         // Threshold check: start dragging only after moving 8px from down position.
         if !gs.is_dragging && total_delta.abs() > DRAG_THRESHOLD {
             gs.is_dragging = true;
+            event.lock_gesture();
         }
 
         gs.last_position = Some(position);
@@ -418,6 +447,21 @@ impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
             set_last_fling_velocity(velocity);
         }
 
+        // Snapping targets always settle on an item, even after a slow drag
+        if let (true, Some(behavior)) = (was_dragging, self.fling_behavior) {
+            if let Some(old_fling) = existing_fling {
+                old_fling.cancel();
+            }
+            let adjusted_velocity = if self.reverse_scrolling {
+                -velocity
+            } else {
+                velocity
+            };
+            let snap = self.scroll_target.snap(&behavior, -adjusted_velocity);
+            self.gesture_state.borrow_mut().snap_animation = snap;
+            return was_dragging;
+        }
+
         // Start fling animation if velocity is significant
         if start_fling {
             if let Some(old_fling) = existing_fling {
@@ -543,6 +587,7 @@ fn scroll_impl(state: ScrollState, is_vertical: bool, reverse_scrolling: bool) -
             scroll_state.clone(),
             is_vertical,
             false, // ScrollState handles reversing in layout, not input
+            None,
         );
 
         async move {
@@ -555,11 +600,10 @@ fn scroll_impl(state: ScrollState, is_vertical: bool, reverse_scrolling: bool) -
                         // Delegate to detector's lifecycle methods
                         let should_consume = match event.kind {
                             PointerEventKind::Down => detector.on_down(event.position),
-                            PointerEventKind::Move => {
-                                detector.on_move(event.position, event.buttons)
-                            }
+                            PointerEventKind::Move => detector.on_move(&event),
                             PointerEventKind::Up => detector.on_up(),
                             PointerEventKind::Cancel => detector.on_cancel(),
+                            PointerEventKind::Enter | PointerEventKind::Exit => false,
                         };
 
                         if should_consume {
@@ -586,8 +630,29 @@ fn scroll_impl(state: ScrollState, is_vertical: bool, reverse_scrolling: bool) -
             },
         ));
 
-    // Combine: pointer input THEN layout modifier
-    pointer_input.then(layout_modifier)
+    // Expose scroll position and a scroll action to tests and accessibility
+    // services. The handler is shared by every evaluation so that configs
+    // built from an unchanged state compare equal.
+    let semantics_state = state.clone();
+    let scroll_by = {
+        let target = state.clone();
+        SemanticsActionHandler::new(move |delta| target.dispatch_raw_delta(delta))
+    };
+    let semantics = Modifier::empty().semantics(move |config| {
+        config.scroll_axis_range = Some(ScrollAxisRange {
+            value: semantics_state.value_non_reactive(),
+            max_value: semantics_state.max_value(),
+            is_vertical,
+        });
+        config.scroll_by = Some(scroll_by.clone());
+    });
+
+    // Combine: pointer input THEN semantics THEN layout modifier, and let
+    // descendants scroll themselves into view
+    pointer_input
+        .then(semantics)
+        .then(layout_modifier)
+        .then(scroll_viewport(state, is_vertical, reverse_scrolling))
 }
 
 // ============================================================================
@@ -608,17 +673,38 @@ impl Modifier {
     /// Unlike regular vertical_scroll, no layout offset is applied here
     /// since LazyListState manages item positioning internally.
     pub fn lazy_vertical_scroll(self, state: LazyListState, reverse_scrolling: bool) -> Self {
-        self.then(lazy_scroll_impl(state, true, reverse_scrolling))
+        self.then(lazy_scroll_impl(state, true, reverse_scrolling, None))
     }
 
     /// Creates a horizontally scrollable modifier for lazy lists.
     pub fn lazy_horizontal_scroll(self, state: LazyListState, reverse_scrolling: bool) -> Self {
-        self.then(lazy_scroll_impl(state, false, reverse_scrolling))
+        self.then(lazy_scroll_impl(state, false, reverse_scrolling, None))
+    }
+
+    /// Lazy list scrolling that settles with `fling_behavior` when given.
+    pub(crate) fn lazy_scroll(
+        self,
+        state: LazyListState,
+        is_vertical: bool,
+        reverse_scrolling: bool,
+        fling_behavior: Option<SnapFlingBehavior>,
+    ) -> Self {
+        self.then(lazy_scroll_impl(
+            state,
+            is_vertical,
+            reverse_scrolling,
+            fling_behavior,
+        ))
     }
 }
 
 /// Internal implementation for lazy scroll modifiers.
-fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling: bool) -> Modifier {
+fn lazy_scroll_impl(
+    state: LazyListState,
+    is_vertical: bool,
+    reverse_scrolling: bool,
+    fling_behavior: Option<SnapFlingBehavior>,
+) -> Modifier {
     let gesture_state = Rc::new(RefCell::new(ScrollGestureState::default()));
     let list_state = state;
 
@@ -628,15 +714,21 @@ fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling:
 
     // Use a unique key per LazyListState
     let state_id = std::ptr::addr_of!(*state.inner_ptr()) as usize;
-    let key = (state_id, is_vertical, reverse_scrolling);
+    let key = (
+        state_id,
+        is_vertical,
+        reverse_scrolling,
+        fling_behavior.is_some(),
+    );
 
-    Modifier::empty().pointer_input(key, move |scope| {
+    let pointer_input = Modifier::empty().pointer_input(key, move |scope| {
         // Use the same generic detector with LazyListState
         let detector = ScrollGestureDetector::new(
             gesture_state.clone(),
             list_state,
             is_vertical,
             reverse_scrolling,
+            fling_behavior,
         );
 
         async move {
@@ -648,11 +740,10 @@ fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling:
                         // Delegate to detector's lifecycle methods
                         let should_consume = match event.kind {
                             PointerEventKind::Down => detector.on_down(event.position),
-                            PointerEventKind::Move => {
-                                detector.on_move(event.position, event.buttons)
-                            }
+                            PointerEventKind::Move => detector.on_move(&event),
                             PointerEventKind::Up => detector.on_up(),
                             PointerEventKind::Cancel => detector.on_cancel(),
+                            PointerEventKind::Enter | PointerEventKind::Exit => false,
                         };
 
                         if should_consume {
@@ -662,5 +753,34 @@ fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling:
                 })
                 .await;
         }
+    });
+
+    pointer_input.then(lazy_scroll_semantics(state, is_vertical))
+}
+
+/// Exposes a lazy list's scroll position and scroll actions through semantics.
+///
+/// Lazy lists only know the first visible item, so the absolute offset and its
+/// maximum are estimated from the running average item size.
+fn lazy_scroll_semantics(state: LazyListState, is_vertical: bool) -> Modifier {
+    // Positive deltas scroll forward, matching `ScrollState::dispatch_raw_delta`;
+    // `dispatch_scroll_delta` takes gesture deltas, hence the sign flip.
+    let scroll_by =
+        SemanticsActionHandler::new(move |delta: f32| -state.dispatch_scroll_delta(-delta));
+    let scroll_to_index =
+        SemanticsActionHandler::new(move |index| state.scroll_to_item(index, 0.0));
+    Modifier::empty().semantics(move |config| {
+        let average_size = state.average_item_size();
+        let info = state.layout_info();
+        let value = state.first_visible_item_index() as f32 * average_size
+            + state.first_visible_item_scroll_offset();
+        let estimated_max = info.total_items_count as f32 * average_size - info.viewport_size;
+        config.scroll_axis_range = Some(ScrollAxisRange {
+            value,
+            max_value: estimated_max.max(value),
+            is_vertical,
+        });
+        config.scroll_by = Some(scroll_by.clone());
+        config.scroll_to_index = Some(scroll_to_index.clone());
     })
 }
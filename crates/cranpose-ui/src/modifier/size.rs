@@ -3,6 +3,7 @@
 //! Reference: /media/huge/composerepo/compose/foundation/foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
 
 use super::{inspector_metadata, DimensionConstraint, Modifier, Size};
+use crate::density::Length;
 use crate::modifier_nodes::{IntrinsicSizeElement, SizeElement};
 use cranpose_ui_layout::IntrinsicSize;
 
@@ -31,8 +32,14 @@ impl Modifier {
     /// Convenience method for `size(Size { width, height })`.
     ///
     /// Example: `Modifier::empty().size_points(100.0, 200.0)`
-    pub fn size_points(self, width: f32, height: f32) -> Self {
-        self.size(Size { width, height })
+    pub fn size_points(self, width: impl Length, height: impl Length) -> Self {
+        let (width, height) = (width.to_layout_length(), height.to_layout_length());
+        let modifier = Self::with_element(SizeElement::from_lengths(Some(width), Some(height)))
+            .with_inspector_metadata(inspector_metadata("size", move |info| {
+                info.add_property("width", width.to_string());
+                info.add_property("height", height.to_string());
+            }));
+        self.then(modifier)
     }
 
     /// Declare the preferred width of the content to be exactly [width]dp.
@@ -43,10 +50,11 @@ impl Modifier {
     /// Matches Kotlin: `Modifier.width(width: Dp)`
     ///
     /// Example: `Modifier::empty().width(100.0).height(200.0)`
-    pub fn width(self, width: f32) -> Self {
-        let modifier = Self::with_element(SizeElement::new(Some(width), None))
+    pub fn width(self, width: impl Length) -> Self {
+        let width = width.to_layout_length();
+        let modifier = Self::with_element(SizeElement::from_lengths(Some(width), None))
             .with_inspector_metadata(inspector_metadata("width", move |info| {
-                info.add_dimension("width", DimensionConstraint::Points(width));
+                info.add_property("width", width.to_string());
             }));
         self.then(modifier)
     }
@@ -59,10 +67,11 @@ impl Modifier {
     /// Matches Kotlin: `Modifier.height(height: Dp)`
     ///
     /// Example: `Modifier::empty().width(100.0).height(200.0)`
-    pub fn height(self, height: f32) -> Self {
-        let modifier = Self::with_element(SizeElement::new(None, Some(height)))
+    pub fn height(self, height: impl Length) -> Self {
+        let height = height.to_layout_length();
+        let modifier = Self::with_element(SizeElement::from_lengths(None, Some(height)))
             .with_inspector_metadata(inspector_metadata("height", move |info| {
-                info.add_dimension("height", DimensionConstraint::Points(height));
+                info.add_property("height", height.to_string());
             }));
         self.then(modifier)
     }
@@ -2,20 +2,20 @@ use std::fmt;
 use std::rc::Rc;
 
 use cranpose_foundation::{ModifierNodeChain, NodeCapabilities, PointerEvent};
-use cranpose_ui_graphics::GraphicsLayer;
+use cranpose_ui_graphics::{GraphicsLayer, TextStyle};
 
-use crate::draw::DrawCommand;
+use crate::draw::{DrawCommand, DrawCommandFn};
 use crate::modifier::Modifier;
 use crate::modifier_nodes::{
     BackgroundNode, ClipToBoundsNode, CornerShapeNode, DrawCommandNode, GraphicsLayerNode,
-    PaddingNode,
+    MinimumTouchTargetNode, PaddingNode,
 };
 use crate::text_field_modifier_node::TextFieldModifierNode;
 use crate::text_modifier_node::TextModifierNode;
 use cranpose_ui_graphics::EdgeInsets;
 use std::cell::RefCell;
 
-use super::{ModifierChainHandle, Point};
+use super::{ModifierChainHandle, Point, Size};
 
 /// Snapshot of modifier node slices that impact draw and pointer subsystems.
 #[derive(Default)]
@@ -24,7 +24,9 @@ pub struct ModifierNodeSlices {
     pointer_inputs: Vec<Rc<dyn Fn(PointerEvent)>>,
     click_handlers: Vec<Rc<dyn Fn(Point)>>,
     clip_to_bounds: bool,
+    minimum_touch_target: Option<Size>,
     text_content: Option<String>,
+    text_style: Option<TextStyle>,
     graphics_layer: Option<GraphicsLayer>,
     chain_guard: Option<Rc<ChainGuard>>,
 }
@@ -40,7 +42,9 @@ impl Clone for ModifierNodeSlices {
             pointer_inputs: self.pointer_inputs.clone(),
             click_handlers: self.click_handlers.clone(),
             clip_to_bounds: self.clip_to_bounds,
+            minimum_touch_target: self.minimum_touch_target,
             text_content: self.text_content.clone(),
+            text_style: self.text_style.clone(),
             graphics_layer: self.graphics_layer,
             chain_guard: self.chain_guard.clone(),
         }
@@ -64,10 +68,20 @@ impl ModifierNodeSlices {
         self.clip_to_bounds
     }
 
+    /// Smallest size the node's pointer hit region is expanded to, if any.
+    pub fn minimum_touch_target(&self) -> Option<Size> {
+        self.minimum_touch_target
+    }
+
     pub fn text_content(&self) -> Option<&str> {
         self.text_content.as_deref()
     }
 
+    /// Style of the text in [`text_content`](Self::text_content), if it came from `Text`.
+    pub fn text_style(&self) -> Option<&TextStyle> {
+        self.text_style.as_ref()
+    }
+
     pub fn graphics_layer(&self) -> Option<GraphicsLayer> {
         self.graphics_layer
     }
@@ -85,7 +99,9 @@ impl fmt::Debug for ModifierNodeSlices {
             .field("pointer_inputs", &self.pointer_inputs.len())
             .field("click_handlers", &self.click_handlers.len())
             .field("clip_to_bounds", &self.clip_to_bounds)
+            .field("minimum_touch_target", &self.minimum_touch_target)
             .field("text_content", &self.text_content)
+            .field("text_style", &self.text_style)
             .field("graphics_layer", &self.graphics_layer)
             .finish()
     }
@@ -96,7 +112,19 @@ pub fn collect_modifier_slices(chain: &ModifierNodeChain) -> ModifierNodeSlices
     let mut slices = ModifierNodeSlices::default();
 
     chain.for_each_node_with_capability(NodeCapabilities::POINTER_INPUT, |_ref, node| {
-        let _any = node.as_any();
+        let any = node.as_any();
+
+        if let Some(target) = any.downcast_ref::<MinimumTouchTargetNode>() {
+            let size = target.size();
+            let current = slices.minimum_touch_target.unwrap_or(Size {
+                width: 0.0,
+                height: 0.0,
+            });
+            slices.minimum_touch_target = Some(Size {
+                width: current.width.max(size.width),
+                height: current.height.max(size.height),
+            });
+        }
 
         // ClickableNode is now handled as a standard PointerInputNode
         // to support drag cancellation and proper click semantics (Up vs Down)
@@ -113,64 +141,95 @@ pub fn collect_modifier_slices(chain: &ModifierNodeChain) -> ModifierNodeSlices
     // Track background and shape to combine them in draw commands
     let background_color = RefCell::new(None);
     let corner_shape = RefCell::new(None);
+    // Padding declared before a draw modifier wraps it, so the draw modifier
+    // paints inside that padding; padding declared after it does not.
+    let mut outer_padding = EdgeInsets::default();
+    let mut background_inset = EdgeInsets::default();
+
+    chain.for_each_node_with_capability(
+        NodeCapabilities::DRAW | NodeCapabilities::LAYOUT,
+        |node_ref, node| {
+            let any = node.as_any();
+
+            if let Some(padding_node) = any.downcast_ref::<PaddingNode>() {
+                outer_padding += padding_node.padding();
+                return;
+            }
+            if !node_ref.kind_set().contains(NodeCapabilities::DRAW) {
+                return;
+            }
 
-    chain.for_each_node_with_capability(NodeCapabilities::DRAW, |_ref, node| {
-        let any = node.as_any();
-
-        // Collect background color from BackgroundNode
-        if let Some(bg_node) = any.downcast_ref::<BackgroundNode>() {
-            *background_color.borrow_mut() = Some(bg_node.color());
-            // Note: BackgroundNode can have an optional shape, but we primarily track
-            // shape via CornerShapeNode for flexibility
-            if bg_node.shape().is_some() {
-                *corner_shape.borrow_mut() = bg_node.shape();
+            // Collect background color from BackgroundNode
+            if let Some(bg_node) = any.downcast_ref::<BackgroundNode>() {
+                *background_color.borrow_mut() = Some(bg_node.color());
+                background_inset = outer_padding;
+                // Note: BackgroundNode can have an optional shape, but we primarily track
+                // shape via CornerShapeNode for flexibility
+                if bg_node.shape().is_some() {
+                    *corner_shape.borrow_mut() = bg_node.shape();
+                }
             }
-        }
 
-        // Collect corner shape from CornerShapeNode
-        if let Some(shape_node) = any.downcast_ref::<CornerShapeNode>() {
-            *corner_shape.borrow_mut() = Some(shape_node.shape());
-        }
+            // Collect corner shape from CornerShapeNode
+            if let Some(shape_node) = any.downcast_ref::<CornerShapeNode>() {
+                *corner_shape.borrow_mut() = Some(shape_node.shape());
+            }
 
-        // Collect draw commands from DrawCommandNode
-        if let Some(commands) = any.downcast_ref::<DrawCommandNode>() {
-            slices
-                .draw_commands
-                .extend(commands.commands().iter().cloned());
-        }
+            // Collect draw commands from DrawCommandNode
+            if let Some(commands) = any.downcast_ref::<DrawCommandNode>() {
+                slices.draw_commands.extend(
+                    commands
+                        .commands()
+                        .iter()
+                        .map(|command| inset_draw_command(command, outer_padding)),
+                );
+            }
 
-        // Use create_draw_closure() for nodes with dynamic content (cursor blink, selection)
-        // This defers evaluation to render time, enabling live updates.
-        // Fallback to draw() for nodes with static content.
-        if let Some(draw_node) = node.as_draw_node() {
-            if let Some(closure) = draw_node.create_draw_closure() {
-                // Deferred closure - evaluates at render time
-                slices.draw_commands.push(DrawCommand::Overlay(closure));
-            } else {
-                // Static draw - evaluate now
-                use cranpose_ui_graphics::{DrawScope as _, DrawScopeDefault};
-                let mut scope = DrawScopeDefault::new(crate::modifier::Size {
-                    width: 0.0,
-                    height: 0.0,
-                });
-                draw_node.draw(&mut scope);
-                let primitives = scope.into_primitives();
-                if !primitives.is_empty() {
-                    let draw_cmd = Rc::new(move |_size: crate::modifier::Size| primitives.clone());
-                    slices.draw_commands.push(DrawCommand::Overlay(draw_cmd));
+            // Use create_draw_closure() for nodes with dynamic content (cursor blink, selection)
+            // This defers evaluation to render time, enabling live updates.
+            // Fallback to draw() for nodes with static content.
+            if let Some(draw_node) = node.as_draw_node() {
+                // Text fields offset their cursor and selection by the whole
+                // chain's padding themselves (see content_offset below).
+                let inset = if any.is::<TextFieldModifierNode>() {
+                    EdgeInsets::default()
+                } else {
+                    outer_padding
+                };
+                if let Some(closure) = draw_node.create_draw_closure() {
+                    // Deferred closure - evaluates at render time
+                    slices
+                        .draw_commands
+                        .push(inset_draw_command(&DrawCommand::Overlay(closure), inset));
+                } else {
+                    // Static draw - evaluate now
+                    use cranpose_ui_graphics::{DrawScope as _, DrawScopeDefault};
+                    let mut scope = DrawScopeDefault::new(crate::modifier::Size {
+                        width: 0.0,
+                        height: 0.0,
+                    });
+                    draw_node.draw(&mut scope);
+                    let primitives = scope.into_primitives();
+                    if !primitives.is_empty() {
+                        let draw_cmd =
+                            Rc::new(move |_size: crate::modifier::Size| primitives.clone());
+                        slices
+                            .draw_commands
+                            .push(inset_draw_command(&DrawCommand::Overlay(draw_cmd), inset));
+                    }
                 }
             }
-        }
 
-        // Collect graphics layer from GraphicsLayerNode
-        if let Some(layer_node) = any.downcast_ref::<GraphicsLayerNode>() {
-            slices.graphics_layer = Some(layer_node.layer());
-        }
+            // Collect graphics layer from GraphicsLayerNode
+            if let Some(layer_node) = any.downcast_ref::<GraphicsLayerNode>() {
+                slices.graphics_layer = Some(layer_node.layer());
+            }
 
-        if any.is::<ClipToBoundsNode>() {
-            slices.clip_to_bounds = true;
-        }
-    });
+            if any.is::<ClipToBoundsNode>() {
+                slices.clip_to_bounds = true;
+            }
+        },
+    );
 
     // Collect padding from modifier chain for cursor positioning
     let mut padding = EdgeInsets::default();
@@ -191,11 +250,13 @@ pub fn collect_modifier_slices(chain: &ModifierNodeChain) -> ModifierNodeSlices
         if let Some(text_node) = any.downcast_ref::<TextModifierNode>() {
             // Rightmost text modifier wins
             slices.text_content = Some(text_node.text().to_string());
+            slices.text_style = Some(text_node.style().clone());
         }
         // Also check for TextFieldModifierNode (editable text fields)
         if let Some(text_field_node) = any.downcast_ref::<TextFieldModifierNode>() {
             let text = text_field_node.text();
             slices.text_content = Some(text.clone());
+            slices.text_style = None;
 
             // Update content offsets for cursor positioning in collect_draw_primitives()
             text_field_node.set_content_offset(padding.left);
@@ -210,7 +271,7 @@ pub fn collect_modifier_slices(chain: &ModifierNodeChain) -> ModifierNodeSlices
     if let Some(color) = background_color.into_inner() {
         let shape = corner_shape.into_inner();
 
-        let draw_cmd = Rc::new(move |size: crate::modifier::Size| {
+        let draw_cmd: DrawCommandFn = Rc::new(move |size: crate::modifier::Size| {
             use crate::modifier::{Brush, Rect};
             use cranpose_ui_graphics::DrawPrimitive;
 
@@ -230,14 +291,39 @@ pub fn collect_modifier_slices(chain: &ModifierNodeChain) -> ModifierNodeSlices
             }
         });
 
-        slices
-            .draw_commands
-            .insert(0, DrawCommand::Behind(draw_cmd));
+        slices.draw_commands.insert(
+            0,
+            inset_draw_command(&DrawCommand::Behind(draw_cmd), background_inset),
+        );
     }
 
     slices
 }
 
+/// Shrinks the area `command` draws into by `inset` on each edge.
+fn inset_draw_command(command: &DrawCommand, inset: EdgeInsets) -> DrawCommand {
+    let inset_fn = |func: &DrawCommandFn| -> DrawCommandFn {
+        if inset.is_zero() {
+            return func.clone();
+        }
+        let func = func.clone();
+        Rc::new(move |size: crate::modifier::Size| {
+            let inner = crate::modifier::Size {
+                width: (size.width - inset.horizontal_sum()).max(0.0),
+                height: (size.height - inset.vertical_sum()).max(0.0),
+            };
+            func(inner)
+                .iter()
+                .map(|primitive| primitive.translate(inset.left, inset.top))
+                .collect()
+        })
+    };
+    match command {
+        DrawCommand::Behind(func) => DrawCommand::Behind(inset_fn(func)),
+        DrawCommand::Overlay(func) => DrawCommand::Overlay(inset_fn(func)),
+    }
+}
+
 /// Collects modifier node slices by instantiating a temporary node chain from a [`Modifier`].
 pub fn collect_slices_from_modifier(modifier: &Modifier) -> ModifierNodeSlices {
     let mut handle = ModifierChainHandle::new();
@@ -0,0 +1,156 @@
+use super::*;
+use crate::layout::{LayoutEngine, LayoutTree};
+use crate::renderer::{HeadlessRenderer, PaintLayer};
+use crate::widgets::{Box, BoxSpec};
+use crate::Composition;
+use cranpose_core::{location_key, MemoryApplier};
+use cranpose_foundation::{PointerEvent, PointerEventKind};
+
+const FRAME_NANOS: u64 = 16_000_000;
+
+fn layout(composition: &mut Composition<MemoryApplier>) -> LayoutTree {
+    let root = composition.root().expect("root");
+    let handle = composition.runtime_handle();
+    let mut applier = composition.applier_mut();
+    applier.set_runtime_handle(handle);
+    let tree = applier
+        .compute_layout(
+            root,
+            Size {
+                width: 200.0,
+                height: 200.0,
+            },
+        )
+        .expect("layout");
+    applier.clear_runtime_handle();
+    tree
+}
+
+fn ripple_circles(tree: &LayoutTree) -> Vec<(Rect, f32)> {
+    HeadlessRenderer::new()
+        .render(tree)
+        .primitives_for(PaintLayer::Overlay)
+        .filter_map(|primitive| match primitive {
+            DrawPrimitive::RoundRect {
+                rect,
+                brush: Brush::Solid(color),
+                ..
+            } => Some((*rect, color.3)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn press(tree: &LayoutTree, kind: PointerEventKind, x: f32, y: f32) {
+    let slices = tree.root().node_data.modifier_slices();
+    let handler = slices.pointer_inputs()[0].clone();
+    handler(PointerEvent::new(kind, Point { x, y }, Point { x, y }));
+}
+
+fn compose_ripple_box() -> Composition<MemoryApplier> {
+    let mut composition = Composition::new(MemoryApplier::new());
+    composition
+        .render(location_key(file!(), line!(), column!()), || {
+            let source = crate::rememberInteractionSource!();
+            Box(
+                Modifier::empty()
+                    .size(Size {
+                        width: 100.0,
+                        height: 100.0,
+                    })
+                    .clickable_with_interaction(source.clone(), |_| {})
+                    .indication(source, ripple()),
+                BoxSpec::default(),
+                || {},
+            );
+        })
+        .expect("render");
+    composition
+}
+
+#[test]
+fn pressing_draws_an_expanding_circle_that_fades_on_release() {
+    let mut composition = compose_ripple_box();
+    let tree = layout(&mut composition);
+    let runtime = composition.runtime_handle();
+    assert!(ripple_circles(&tree).is_empty());
+
+    press(&tree, PointerEventKind::Down, 20.0, 30.0);
+    runtime.drain_frame_callbacks(0);
+    runtime.drain_frame_callbacks(3 * FRAME_NANOS);
+
+    let circles = ripple_circles(&tree);
+    assert_eq!(circles.len(), 1);
+    let (small, alpha) = circles[0];
+    assert!(small.width > 0.0 && small.width < 100.0);
+    assert!((alpha - 0.24).abs() < 1e-4);
+
+    runtime.drain_frame_callbacks(8 * FRAME_NANOS);
+    let (larger, _) = ripple_circles(&tree)[0];
+    assert!(larger.width > small.width);
+    assert!(larger.x >= 0.0 && larger.y >= 0.0);
+    assert!(larger.x + larger.width <= 100.0 && larger.y + larger.height <= 100.0);
+
+    press(&tree, PointerEventKind::Up, 20.0, 30.0);
+    runtime.drain_frame_callbacks(9 * FRAME_NANOS);
+    runtime.drain_frame_callbacks(12 * FRAME_NANOS);
+    let (_, fading) = ripple_circles(&tree)[0];
+    assert!(fading < 0.24);
+
+    runtime.drain_frame_callbacks(30 * FRAME_NANOS);
+    assert!(ripple_circles(&tree).is_empty());
+}
+
+#[test]
+fn rapid_presses_draw_overlapping_ripples() {
+    let mut composition = compose_ripple_box();
+    let tree = layout(&mut composition);
+    let runtime = composition.runtime_handle();
+
+    press(&tree, PointerEventKind::Down, 20.0, 20.0);
+    runtime.drain_frame_callbacks(0);
+    runtime.drain_frame_callbacks(FRAME_NANOS);
+    press(&tree, PointerEventKind::Up, 20.0, 20.0);
+    press(&tree, PointerEventKind::Down, 80.0, 80.0);
+    runtime.drain_frame_callbacks(2 * FRAME_NANOS);
+    runtime.drain_frame_callbacks(3 * FRAME_NANOS);
+
+    let circles = ripple_circles(&tree);
+    assert_eq!(circles.len(), 2);
+    assert!(circles[0].1 < circles[1].1);
+}
+
+#[test]
+fn circle_is_clipped_to_bounds_and_takes_the_node_corners() {
+    let size = Size {
+        width: 100.0,
+        height: 50.0,
+    };
+    let color = Color(1.0, 1.0, 1.0, 0.5);
+    let radii = CornerRadii::uniform(8.0);
+
+    let inside = clipped_circle(Point { x: 50.0, y: 25.0 }, 10.0, size, radii, color);
+    assert_eq!(
+        inside,
+        Some(DrawPrimitive::RoundRect {
+            rect: Rect {
+                x: 40.0,
+                y: 15.0,
+                width: 20.0,
+                height: 20.0,
+            },
+            brush: Brush::solid(color),
+            radii: CornerRadii::uniform(10.0),
+        })
+    );
+
+    let covering = clipped_circle(Point { x: 50.0, y: 25.0 }, 200.0, size, radii, color);
+    assert_eq!(
+        covering,
+        Some(DrawPrimitive::RoundRect {
+            rect: Rect::from_size(size),
+            brush: Brush::solid(color),
+            radii,
+        })
+    );
+}
@@ -27,6 +27,18 @@ fn padding_nodes_resolve_padding_values() {
     );
 }
 
+#[test]
+fn padding_insets_clamps_negative_components() {
+    let modifier =
+        Modifier::empty().padding_insets(EdgeInsets::from_components(-3.0, 2.0, 5.0, -1.0));
+    let mut handle = ModifierChainHandle::new();
+    let _ = handle.update(&modifier);
+    assert_eq!(
+        handle.resolved_modifiers().padding(),
+        EdgeInsets::from_components(0.0, 2.0, 5.0, 0.0)
+    );
+}
+
 #[test]
 fn fill_max_size_sets_fraction_constraints() {
     let modifier = Modifier::empty().fill_max_size_fraction(0.75);
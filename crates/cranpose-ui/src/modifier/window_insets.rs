@@ -0,0 +1,195 @@
+//! Padding that keeps content clear of system UI.
+//!
+//! Insets applied by an ancestor are consumed: a nested
+//! [`Modifier::window_insets_padding`] only pads by what the ancestors have not
+//! applied yet, so stacking padded containers does not double the gap.
+
+use std::hash::{Hash, Hasher};
+
+use cranpose_core::NodeId;
+use cranpose_foundation::{
+    Constraints, DelegatableNode, LayoutModifierNode, Measurable, MeasurementProxy, ModifierNode,
+    ModifierNodeContext, ModifierNodeElement, NodeCapabilities, NodeState,
+};
+
+use super::{inspector_metadata, modifier_element, EdgeInsets, Modifier, ModifierLocalKey};
+use crate::modifier_nodes::PaddingNode;
+use crate::widgets::nodes::ancestor_modifier_local_values;
+use crate::window_insets::{exclude, union, LocalWindowInsets};
+
+thread_local! {
+    /// Insets consumed with [`Modifier::consume_window_insets`].
+    static CONSUMED_WINDOW_INSETS: ModifierLocalKey<EdgeInsets> =
+        ModifierLocalKey::new(EdgeInsets::default);
+    /// Insets already applied by [`Modifier::window_insets_padding`].
+    static PADDED_WINDOW_INSETS: ModifierLocalKey<EdgeInsets> =
+        ModifierLocalKey::new(EdgeInsets::default);
+}
+
+impl Modifier {
+    /// Pad by the parts of `insets` that no ancestor has padded or consumed,
+    /// then mark `insets` as consumed for descendants.
+    ///
+    /// Example: `Modifier::empty().window_insets_padding(LocalWindowInsets().current().system_bars())`
+    pub fn window_insets_padding(self, insets: EdgeInsets) -> Self {
+        let element = WindowInsetsPaddingElement::new(insets);
+        let key = PADDED_WINDOW_INSETS.with(Clone::clone);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)])
+            .with_inspector_metadata(inspector_metadata("windowInsetsPadding", move |info| {
+                info.add_property("insets", format!("{insets:?}"));
+            }));
+        self.then(modifier)
+            .modifier_local_provider(key, move || insets)
+    }
+
+    /// Pad by the current [`LocalWindowInsets`] system bars and keyboard, so
+    /// content stays fully visible.
+    ///
+    /// Reads the composition local while the modifier is built, so the
+    /// calling composable recomposes when the insets change.
+    pub fn safe_content_padding(self) -> Self {
+        let insets = LocalWindowInsets().current_or_default().safe_content();
+        self.window_insets_padding(insets)
+    }
+
+    /// Mark `insets` as handled for descendants without adding padding, e.g.
+    /// when this component already sizes itself to avoid them.
+    ///
+    /// Only applies to descendant layouts, not to padding earlier in the same
+    /// modifier chain.
+    pub fn consume_window_insets(self, insets: EdgeInsets) -> Self {
+        let key = CONSUMED_WINDOW_INSETS.with(Clone::clone);
+        self.modifier_local_provider(key, move || insets)
+    }
+}
+
+/// Insets padded or consumed by the ancestors of `node`.
+fn consumed_by_ancestors(node: NodeId) -> EdgeInsets {
+    [&CONSUMED_WINDOW_INSETS, &PADDED_WINDOW_INSETS]
+        .into_iter()
+        .flat_map(|key| key.with(|key| ancestor_modifier_local_values(node, key)))
+        .fold(EdgeInsets::default(), union)
+}
+
+/// Node that pads its content by the window insets its ancestors left over.
+#[derive(Debug)]
+pub struct WindowInsetsPaddingNode {
+    insets: EdgeInsets,
+    node_id: Option<NodeId>,
+    state: NodeState,
+}
+
+impl WindowInsetsPaddingNode {
+    pub fn new(insets: EdgeInsets) -> Self {
+        Self {
+            insets,
+            node_id: None,
+            state: NodeState::new(),
+        }
+    }
+
+    /// Padding for the current position in the tree. Resolved on every
+    /// measure because ancestors may be attached after this node.
+    fn padding(&self) -> PaddingNode {
+        let consumed = self.node_id.map(consumed_by_ancestors).unwrap_or_default();
+        PaddingNode::new(exclude(self.insets, consumed))
+    }
+}
+
+impl DelegatableNode for WindowInsetsPaddingNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for WindowInsetsPaddingNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        self.node_id = context.node_id();
+        context.invalidate(cranpose_foundation::InvalidationKind::Layout);
+    }
+
+    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
+        Some(self)
+    }
+
+    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
+        Some(self)
+    }
+}
+
+impl LayoutModifierNode for WindowInsetsPaddingNode {
+    fn measure(
+        &self,
+        context: &mut dyn ModifierNodeContext,
+        measurable: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        self.padding().measure(context, measurable, constraints)
+    }
+
+    fn min_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        self.padding().min_intrinsic_width(measurable, height)
+    }
+
+    fn max_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        self.padding().max_intrinsic_width(measurable, height)
+    }
+
+    fn min_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        self.padding().min_intrinsic_height(measurable, width)
+    }
+
+    fn max_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        self.padding().max_intrinsic_height(measurable, width)
+    }
+
+    fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
+        self.padding().create_measurement_proxy()
+    }
+}
+
+/// Element that creates and updates window insets padding nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowInsetsPaddingElement {
+    insets: EdgeInsets,
+}
+
+impl WindowInsetsPaddingElement {
+    pub fn new(insets: EdgeInsets) -> Self {
+        Self { insets }
+    }
+}
+
+impl Hash for WindowInsetsPaddingElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "windowInsetsPadding".hash(state);
+        for value in [
+            self.insets.left,
+            self.insets.top,
+            self.insets.right,
+            self.insets.bottom,
+        ] {
+            state.write_u32(value.to_bits());
+        }
+    }
+}
+
+impl ModifierNodeElement for WindowInsetsPaddingElement {
+    type Node = WindowInsetsPaddingNode;
+
+    fn create(&self) -> Self::Node {
+        WindowInsetsPaddingNode::new(self.insets)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.insets = self.insets;
+    }
+
+    fn inspector_name(&self) -> &'static str {
+        "windowInsetsPadding"
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::LAYOUT
+    }
+}
@@ -70,23 +70,15 @@ use cranpose_ui_layout::{Alignment, HorizontalAlignment, IntrinsicSize, Vertical
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+use crate::density::{measure_density, LayoutLength, LengthInsets};
 use crate::draw::DrawCommand;
+use crate::interaction::{Interaction, InteractionSource};
 use crate::modifier::{Color, EdgeInsets, GraphicsLayer, LayoutWeight, Point, RoundedCornerShape};
 
 fn hash_f32_value<H: Hasher>(state: &mut H, value: f32) {
     state.write_u32(value.to_bits());
 }
 
-fn hash_option_f32<H: Hasher>(state: &mut H, value: Option<f32>) {
-    match value {
-        Some(v) => {
-            state.write_u8(1);
-            hash_f32_value(state, v);
-        }
-        None => state.write_u8(0),
-    }
-}
-
 fn hash_graphics_layer<H: Hasher>(state: &mut H, layer: GraphicsLayer) {
     hash_f32_value(state, layer.alpha);
     hash_f32_value(state, layer.scale);
@@ -108,6 +100,11 @@ fn hash_vertical_alignment<H: Hasher>(state: &mut H, alignment: VerticalAlignmen
         VerticalAlignment::Top => 0,
         VerticalAlignment::CenterVertically => 1,
         VerticalAlignment::Bottom => 2,
+        VerticalAlignment::AlignBy(line) => {
+            state.write_u8(3);
+            line.hash(state);
+            return;
+        }
     };
     state.write_u8(tag);
 }
@@ -124,20 +121,25 @@ fn hash_alignment<H: Hasher>(state: &mut H, alignment: Alignment) {
 /// Node that adds padding around its content.
 #[derive(Debug)]
 pub struct PaddingNode {
-    padding: EdgeInsets,
+    padding: LengthInsets,
     state: NodeState,
 }
 
 impl PaddingNode {
     pub fn new(padding: EdgeInsets) -> Self {
+        Self::from_lengths(padding.into())
+    }
+
+    pub(crate) fn from_lengths(padding: LengthInsets) -> Self {
         Self {
             padding,
             state: NodeState::new(),
         }
     }
 
+    /// Padding in layout pixels at the measure-pass density.
     pub fn padding(&self) -> EdgeInsets {
-        self.padding
+        self.padding.resolve(measure_density())
     }
 }
 
@@ -168,9 +170,10 @@ impl LayoutModifierNode for PaddingNode {
         measurable: &dyn Measurable,
         constraints: Constraints,
     ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        let padding = self.padding();
         // Convert padding to floating point values
-        let horizontal_padding = self.padding.horizontal_sum();
-        let vertical_padding = self.padding.vertical_sum();
+        let horizontal_padding = padding.horizontal_sum();
+        let vertical_padding = padding.vertical_sum();
 
         // Subtract padding from available space
         let inner_constraints = Constraints {
@@ -193,37 +196,41 @@ impl LayoutModifierNode for PaddingNode {
         // Return size with padding added, and placement offset to position child inside padding
         cranpose_ui_layout::LayoutModifierMeasureResult::new(
             Size { width, height },
-            self.padding.left, // Place child offset by left padding
-            self.padding.top,  // Place child offset by top padding
+            padding.left, // Place child offset by left padding
+            padding.top,  // Place child offset by top padding
         )
     }
 
     fn min_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
-        let vertical_padding = self.padding.vertical_sum();
+        let padding = self.padding();
+        let vertical_padding = padding.vertical_sum();
         let inner_height = (height - vertical_padding).max(0.0);
         let inner_width = measurable.min_intrinsic_width(inner_height);
-        inner_width + self.padding.horizontal_sum()
+        inner_width + padding.horizontal_sum()
     }
 
     fn max_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
-        let vertical_padding = self.padding.vertical_sum();
+        let padding = self.padding();
+        let vertical_padding = padding.vertical_sum();
         let inner_height = (height - vertical_padding).max(0.0);
         let inner_width = measurable.max_intrinsic_width(inner_height);
-        inner_width + self.padding.horizontal_sum()
+        inner_width + padding.horizontal_sum()
     }
 
     fn min_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
-        let horizontal_padding = self.padding.horizontal_sum();
+        let padding = self.padding();
+        let horizontal_padding = padding.horizontal_sum();
         let inner_width = (width - horizontal_padding).max(0.0);
         let inner_height = measurable.min_intrinsic_height(inner_width);
-        inner_height + self.padding.vertical_sum()
+        inner_height + padding.vertical_sum()
     }
 
     fn max_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
-        let horizontal_padding = self.padding.horizontal_sum();
+        let padding = self.padding();
+        let horizontal_padding = padding.horizontal_sum();
         let inner_width = (width - horizontal_padding).max(0.0);
         let inner_height = measurable.max_intrinsic_height(inner_width);
-        inner_height + self.padding.vertical_sum()
+        inner_height + padding.vertical_sum()
     }
 
     fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
@@ -239,7 +246,13 @@ impl LayoutModifierNode for PaddingNode {
 /// directly implements measurement logic using the snapshotted padding state.
 /// This avoids temporary allocations and matches Jetpack Compose's pattern more closely.
 struct PaddingMeasurementProxy {
-    padding: EdgeInsets,
+    padding: LengthInsets,
+}
+
+impl PaddingMeasurementProxy {
+    fn padding(&self) -> EdgeInsets {
+        self.padding.resolve(measure_density())
+    }
 }
 
 impl MeasurementProxy for PaddingMeasurementProxy {
@@ -249,9 +262,10 @@ impl MeasurementProxy for PaddingMeasurementProxy {
         wrapped: &dyn Measurable,
         constraints: Constraints,
     ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        let padding = self.padding();
         // Directly implement padding measurement logic (no node reconstruction)
-        let horizontal_padding = self.padding.horizontal_sum();
-        let vertical_padding = self.padding.vertical_sum();
+        let horizontal_padding = padding.horizontal_sum();
+        let vertical_padding = padding.vertical_sum();
 
         // Subtract padding from available space
         let inner_constraints = Constraints {
@@ -274,58 +288,63 @@ impl MeasurementProxy for PaddingMeasurementProxy {
         // Return size with padding added, and placement offset to position child inside padding
         cranpose_ui_layout::LayoutModifierMeasureResult::new(
             Size { width, height },
-            self.padding.left, // Place child offset by left padding
-            self.padding.top,  // Place child offset by top padding
+            padding.left, // Place child offset by left padding
+            padding.top,  // Place child offset by top padding
         )
     }
 
     fn min_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
-        let vertical_padding = self.padding.vertical_sum();
+        let padding = self.padding();
+        let vertical_padding = padding.vertical_sum();
         let inner_height = (height - vertical_padding).max(0.0);
         let inner_width = wrapped.min_intrinsic_width(inner_height);
-        inner_width + self.padding.horizontal_sum()
+        inner_width + padding.horizontal_sum()
     }
 
     fn max_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
-        let vertical_padding = self.padding.vertical_sum();
+        let padding = self.padding();
+        let vertical_padding = padding.vertical_sum();
         let inner_height = (height - vertical_padding).max(0.0);
         let inner_width = wrapped.max_intrinsic_width(inner_height);
-        inner_width + self.padding.horizontal_sum()
+        inner_width + padding.horizontal_sum()
     }
 
     fn min_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
-        let horizontal_padding = self.padding.horizontal_sum();
+        let padding = self.padding();
+        let horizontal_padding = padding.horizontal_sum();
         let inner_width = (width - horizontal_padding).max(0.0);
         let inner_height = wrapped.min_intrinsic_height(inner_width);
-        inner_height + self.padding.vertical_sum()
+        inner_height + padding.vertical_sum()
     }
 
     fn max_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
-        let horizontal_padding = self.padding.horizontal_sum();
+        let padding = self.padding();
+        let horizontal_padding = padding.horizontal_sum();
         let inner_width = (width - horizontal_padding).max(0.0);
         let inner_height = wrapped.max_intrinsic_height(inner_width);
-        inner_height + self.padding.vertical_sum()
+        inner_height + padding.vertical_sum()
     }
 }
 
 /// Element that creates and updates padding nodes.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PaddingElement {
-    padding: EdgeInsets,
+    padding: LengthInsets,
 }
 
 impl PaddingElement {
     pub fn new(padding: EdgeInsets) -> Self {
+        Self::from_lengths(padding.into())
+    }
+
+    pub(crate) fn from_lengths(padding: LengthInsets) -> Self {
         Self { padding }
     }
 }
 
 impl Hash for PaddingElement {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        hash_f32_value(state, self.padding.left);
-        hash_f32_value(state, self.padding.top);
-        hash_f32_value(state, self.padding.right);
-        hash_f32_value(state, self.padding.bottom);
+        self.padding.hash(state);
     }
 }
 
@@ -333,7 +352,7 @@ impl ModifierNodeElement for PaddingElement {
     type Node = PaddingNode;
 
     fn create(&self) -> Self::Node {
-        PaddingNode::new(self.padding)
+        PaddingNode::from_lengths(self.padding)
     }
 
     fn update(&self, node: &mut Self::Node) {
@@ -615,10 +634,10 @@ impl ModifierNodeElement for GraphicsLayerElement {
 /// Matches Kotlin: `SizeNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
 #[derive(Debug)]
 pub struct SizeNode {
-    min_width: Option<f32>,
-    max_width: Option<f32>,
-    min_height: Option<f32>,
-    max_height: Option<f32>,
+    min_width: Option<LayoutLength>,
+    max_width: Option<LayoutLength>,
+    min_height: Option<LayoutLength>,
+    max_height: Option<LayoutLength>,
     enforce_incoming: bool,
     state: NodeState,
 }
@@ -632,10 +651,10 @@ impl SizeNode {
         enforce_incoming: bool,
     ) -> Self {
         Self {
-            min_width,
-            max_width,
-            min_height,
-            max_height,
+            min_width: min_width.map(LayoutLength::Px),
+            max_width: max_width.map(LayoutLength::Px),
+            min_height: min_height.map(LayoutLength::Px),
+            max_height: max_height.map(LayoutLength::Px),
             enforce_incoming,
             state: NodeState::new(),
         }
@@ -643,11 +662,17 @@ impl SizeNode {
 
     /// Helper to build target constraints from element parameters
     fn target_constraints(&self) -> Constraints {
-        let max_width = self.max_width.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
-        let max_height = self.max_height.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
+        let max_width = self
+            .max_width()
+            .map(|v| v.max(0.0))
+            .unwrap_or(f32::INFINITY);
+        let max_height = self
+            .max_height()
+            .map(|v| v.max(0.0))
+            .unwrap_or(f32::INFINITY);
 
         let min_width = self
-            .min_width
+            .min_width()
             .map(|v| {
                 let clamped = v.clamp(0.0, max_width);
                 if clamped == f32::INFINITY {
@@ -659,7 +684,7 @@ impl SizeNode {
             .unwrap_or(0.0);
 
         let min_height = self
-            .min_height
+            .min_height()
             .map(|v| {
                 let clamped = v.clamp(0.0, max_height);
                 if clamped == f32::INFINITY {
@@ -679,19 +704,19 @@ impl SizeNode {
     }
 
     pub fn min_width(&self) -> Option<f32> {
-        self.min_width
+        self.min_width.map(LayoutLength::to_px)
     }
 
     pub fn max_width(&self) -> Option<f32> {
-        self.max_width
+        self.max_width.map(LayoutLength::to_px)
     }
 
     pub fn min_height(&self) -> Option<f32> {
-        self.min_height
+        self.min_height.map(LayoutLength::to_px)
     }
 
     pub fn max_height(&self) -> Option<f32> {
-        self.max_height
+        self.max_height.map(LayoutLength::to_px)
     }
 
     pub fn enforce_incoming(&self) -> bool {
@@ -895,22 +920,44 @@ impl LayoutModifierNode for SizeNode {
 /// Phase 2: Instead of reconstructing nodes via `SizeNode::new()`, this proxy
 /// directly implements measurement logic using the snapshotted size configuration.
 struct SizeMeasurementProxy {
-    min_width: Option<f32>,
-    max_width: Option<f32>,
-    min_height: Option<f32>,
-    max_height: Option<f32>,
+    min_width: Option<LayoutLength>,
+    max_width: Option<LayoutLength>,
+    min_height: Option<LayoutLength>,
+    max_height: Option<LayoutLength>,
     enforce_incoming: bool,
 }
 
 impl SizeMeasurementProxy {
+    fn min_width(&self) -> Option<f32> {
+        self.min_width.map(LayoutLength::to_px)
+    }
+
+    fn max_width(&self) -> Option<f32> {
+        self.max_width.map(LayoutLength::to_px)
+    }
+
+    fn min_height(&self) -> Option<f32> {
+        self.min_height.map(LayoutLength::to_px)
+    }
+
+    fn max_height(&self) -> Option<f32> {
+        self.max_height.map(LayoutLength::to_px)
+    }
+
     /// Compute target constraints from the size parameters.
     /// Matches SizeNode::target_constraints() logic.
     fn target_constraints(&self) -> Constraints {
-        let max_width = self.max_width.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
-        let max_height = self.max_height.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
+        let max_width = self
+            .max_width()
+            .map(|v| v.max(0.0))
+            .unwrap_or(f32::INFINITY);
+        let max_height = self
+            .max_height()
+            .map(|v| v.max(0.0))
+            .unwrap_or(f32::INFINITY);
 
         let min_width = self
-            .min_width
+            .min_width()
             .map(|v| {
                 let clamped = v.clamp(0.0, max_width);
                 if clamped == f32::INFINITY {
@@ -922,7 +969,7 @@ impl SizeMeasurementProxy {
             .unwrap_or(0.0);
 
         let min_height = self
-            .min_height
+            .min_height()
             .map(|v| {
                 let clamped = v.clamp(0.0, max_height);
                 if clamped == f32::INFINITY {
@@ -1108,15 +1155,19 @@ impl MeasurementProxy for SizeMeasurementProxy {
 /// Matches Kotlin: `SizeElement` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
 #[derive(Debug, Clone, PartialEq)]
 pub struct SizeElement {
-    min_width: Option<f32>,
-    max_width: Option<f32>,
-    min_height: Option<f32>,
-    max_height: Option<f32>,
+    min_width: Option<LayoutLength>,
+    max_width: Option<LayoutLength>,
+    min_height: Option<LayoutLength>,
+    max_height: Option<LayoutLength>,
     enforce_incoming: bool,
 }
 
 impl SizeElement {
     pub fn new(width: Option<f32>, height: Option<f32>) -> Self {
+        Self::from_lengths(width.map(LayoutLength::Px), height.map(LayoutLength::Px))
+    }
+
+    pub(crate) fn from_lengths(width: Option<LayoutLength>, height: Option<LayoutLength>) -> Self {
         Self {
             min_width: width,
             max_width: width,
@@ -1134,10 +1185,10 @@ impl SizeElement {
         enforce_incoming: bool,
     ) -> Self {
         Self {
-            min_width,
-            max_width,
-            min_height,
-            max_height,
+            min_width: min_width.map(LayoutLength::Px),
+            max_width: max_width.map(LayoutLength::Px),
+            min_height: min_height.map(LayoutLength::Px),
+            max_height: max_height.map(LayoutLength::Px),
             enforce_incoming,
         }
     }
@@ -1145,10 +1196,10 @@ impl SizeElement {
 
 impl Hash for SizeElement {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        hash_option_f32(state, self.min_width);
-        hash_option_f32(state, self.max_width);
-        hash_option_f32(state, self.min_height);
-        hash_option_f32(state, self.max_height);
+        self.min_width.hash(state);
+        self.max_width.hash(state);
+        self.min_height.hash(state);
+        self.max_height.hash(state);
         self.enforce_incoming.hash(state);
     }
 }
@@ -1157,13 +1208,14 @@ impl ModifierNodeElement for SizeElement {
     type Node = SizeNode;
 
     fn create(&self) -> Self::Node {
-        SizeNode::new(
-            self.min_width,
-            self.max_width,
-            self.min_height,
-            self.max_height,
-            self.enforce_incoming,
-        )
+        SizeNode {
+            min_width: self.min_width,
+            max_width: self.max_width,
+            min_height: self.min_height,
+            max_height: self.max_height,
+            enforce_incoming: self.enforce_incoming,
+            state: NodeState::new(),
+        }
     }
 
     fn update(&self, node: &mut Self::Node) {
@@ -1192,7 +1244,10 @@ impl ModifierNodeElement for SizeElement {
 
 /// Node that handles click/tap interactions.
 // Drag threshold is now shared via cranpose_foundation::DRAG_THRESHOLD
-use cranpose_foundation::DRAG_THRESHOLD;
+use cranpose_core::{FrameCallbackRegistration, FrameClock};
+use cranpose_foundation::{
+    PointerButton, DOUBLE_CLICK_TIMEOUT_MILLIS, DRAG_THRESHOLD, LONG_CLICK_TIMEOUT_MILLIS,
+};
 
 use std::cell::RefCell;
 
@@ -1200,11 +1255,129 @@ use std::cell::RefCell;
 // Node reuse is ensured by ClickableElement implementing key() to return a stable key
 // The handler closure is cached to ensure the same closure (and press_position state) is returned
 
-pub struct ClickableNode {
+/// Where an in-progress press went down.
+#[derive(Clone, Copy)]
+struct PressPosition {
+    /// Window coordinates, used for drag detection
+    global: Point,
+    /// Node-local coordinates, reported to the interaction source
+    local: Point,
+}
+
+impl PressPosition {
+    fn from_event(event: &PointerEvent) -> Self {
+        Self {
+            global: event.global_position,
+            local: event.position,
+        }
+    }
+
+    /// Whether `event` stayed close enough to the press to count as a click.
+    fn is_within_drag_threshold(&self, event: &PointerEvent) -> bool {
+        let dx = event.global_position.x - self.global.x;
+        let dy = event.global_position.y - self.global.y;
+        (dx * dx + dy * dy).sqrt() <= DRAG_THRESHOLD
+    }
+}
+
+/// Callbacks of a [`ClickableNode`], by gesture.
+#[derive(Clone)]
+struct ClickHandlers {
     on_click: Rc<dyn Fn(Point)>,
+    on_long_click: Option<Rc<dyn Fn(Point)>>,
+    on_double_click: Option<Rc<dyn Fn(Point)>>,
+}
+
+/// Per-node gesture state, shared with the handler closure.
+#[derive(Default)]
+struct ClickGesture {
+    press: Option<PressPosition>,
+    /// Fires the long click while the press is held
+    long_click_timeout: Option<FrameTimeout>,
+    /// The press in progress already fired its long click, so releasing it
+    /// does not click
+    long_clicked: bool,
+    /// Reports a click as a single one unless a second click follows in time
+    pending_click: Option<FrameTimeout>,
+}
+
+impl ClickGesture {
+    /// End the press in progress, returning it.
+    fn end_press(&mut self) -> Option<PressPosition> {
+        self.long_click_timeout = None;
+        self.press.take()
+    }
+}
+
+/// Runs an action once a delay has passed on the frame clock, counted from
+/// the next frame. Dropping it cancels the action.
+struct FrameTimeout {
+    /// Registration of the next frame callback, shared with the callbacks
+    /// that schedule the following one
+    registration: Rc<RefCell<Option<FrameCallbackRegistration>>>,
+}
+
+impl FrameTimeout {
+    /// Returns `None`, without running `action`, when there is no runtime to
+    /// time the delay with.
+    fn start(delay_millis: u64, action: impl FnOnce() + 'static) -> Option<Self> {
+        let clock = cranpose_core::current_runtime_handle()?.frame_clock();
+        let registration = Rc::new(RefCell::new(None));
+        Self::schedule(
+            clock,
+            Rc::clone(&registration),
+            None,
+            delay_millis * 1_000_000,
+            Box::new(action),
+        );
+        Some(Self { registration })
+    }
+
+    /// Waits frame by frame until `delay_nanos` have passed since the first
+    /// frame.
+    fn schedule(
+        clock: FrameClock,
+        registration: Rc<RefCell<Option<FrameCallbackRegistration>>>,
+        start_nanos: Option<u64>,
+        delay_nanos: u64,
+        action: Box<dyn FnOnce()>,
+    ) {
+        let next_clock = clock.clone();
+        let next_registration = Rc::clone(&registration);
+        let pending = clock.with_frame_nanos(move |frame_time_nanos| {
+            let start = start_nanos.unwrap_or(frame_time_nanos);
+            if frame_time_nanos.saturating_sub(start) >= delay_nanos {
+                next_registration.borrow_mut().take();
+                action();
+            } else {
+                Self::schedule(
+                    next_clock,
+                    next_registration,
+                    Some(start),
+                    delay_nanos,
+                    action,
+                );
+            }
+        });
+        *registration.borrow_mut() = Some(pending);
+    }
+}
+
+impl Drop for FrameTimeout {
+    fn drop(&mut self) {
+        self.registration.borrow_mut().take();
+    }
+}
+
+pub struct ClickableNode {
+    handlers: ClickHandlers,
     state: NodeState,
-    /// Shared press position for drag detection (per-node state, accessible by handler closure)
-    press_position: Rc<RefCell<Option<Point>>>,
+    /// Press, long click and double click state (per-node, accessible by handler closure)
+    gesture: Rc<RefCell<ClickGesture>>,
+    /// Receives press and hover interactions for pressed-state visuals
+    interaction_source: Option<InteractionSource>,
+    /// Disabled nodes ignore all pointer input
+    enabled: bool,
     /// Cached handler closure - created once, returned on every pointer_input_handler() call
     cached_handler: Rc<dyn Fn(PointerEvent)>,
 }
@@ -1221,76 +1394,188 @@ impl ClickableNode {
     }
 
     pub fn with_handler(on_click: Rc<dyn Fn(Point)>) -> Self {
-        let press_position = Rc::new(RefCell::new(None));
-        let cached_handler = Self::create_handler(on_click.clone(), press_position.clone());
-        Self {
+        Self::with_interaction_source(on_click, None)
+    }
+
+    pub fn with_interaction_source(
+        on_click: Rc<dyn Fn(Point)>,
+        interaction_source: Option<InteractionSource>,
+    ) -> Self {
+        let handlers = ClickHandlers {
             on_click,
+            on_long_click: None,
+            on_double_click: None,
+        };
+        let gesture = Rc::new(RefCell::new(ClickGesture::default()));
+        let cached_handler = Self::create_handler(
+            handlers.clone(),
+            gesture.clone(),
+            interaction_source.clone(),
+            true,
+        );
+        Self {
+            handlers,
             state: NodeState::new(),
-            press_position,
+            gesture,
+            interaction_source,
+            enabled: true,
             cached_handler,
         }
     }
 
     fn create_handler(
-        handler: Rc<dyn Fn(Point)>,
-        press_position: Rc<RefCell<Option<Point>>>,
+        handlers: ClickHandlers,
+        gesture: Rc<RefCell<ClickGesture>>,
+        interaction_source: Option<InteractionSource>,
+        enabled: bool,
     ) -> Rc<dyn Fn(PointerEvent)> {
+        let emit = Rc::new(move |interaction: Interaction| {
+            if let Some(source) = &interaction_source {
+                source.emit(interaction);
+            }
+        });
         Rc::new(move |event: PointerEvent| {
+            if !enabled {
+                return;
+            }
+
+            let local = event.position;
+
             // Check if event was consumed by scroll or other gesture handlers
             if event.is_consumed() {
                 // Clear press state if event was consumed
-                *press_position.borrow_mut() = None;
+                if gesture.borrow_mut().end_press().is_some() {
+                    emit(Interaction::Cancel { position: local });
+                }
                 return;
             }
 
             match event.kind {
+                // Only the primary button clicks; other buttons pressed or
+                // released meanwhile leave the press alone
+                PointerEventKind::Down | PointerEventKind::Up
+                    if event.changed_button() != PointerButton::Primary => {}
                 PointerEventKind::Down => {
                     // Store global press position for drag detection on Up
-                    *press_position.borrow_mut() = Some(Point {
-                        x: event.global_position.x,
-                        y: event.global_position.y,
-                    });
+                    let mut state = gesture.borrow_mut();
+                    state.press = Some(PressPosition::from_event(&event));
+                    state.long_clicked = false;
+                    state.long_click_timeout =
+                        handlers.on_long_click.clone().and_then(|on_long_click| {
+                            let gesture = Rc::clone(&gesture);
+                            FrameTimeout::start(LONG_CLICK_TIMEOUT_MILLIS, move || {
+                                {
+                                    let mut state = gesture.borrow_mut();
+                                    state.long_click_timeout = None;
+                                    state.long_clicked = true;
+                                }
+                                on_long_click(local);
+                            })
+                        });
+                    drop(state);
+                    emit(Interaction::Press { position: local });
                 }
                 PointerEventKind::Move => {
-                    // Move events are tracked via press_position for drag detection
+                    // A press dragged too far can no longer become a long click
+                    let mut state = gesture.borrow_mut();
+                    if state
+                        .press
+                        .is_some_and(|press| !press.is_within_drag_threshold(&event))
+                    {
+                        state.long_click_timeout = None;
+                    }
                 }
                 PointerEventKind::Up => {
                     // Check if this is a click (Up near Down) or a drag (Up far from Down)
-                    let press_pos_value = *press_position.borrow();
-
-                    let should_click = if let Some(press_pos) = press_pos_value {
-                        let dx = event.global_position.x - press_pos.x;
-                        let dy = event.global_position.y - press_pos.y;
-                        let distance = (dx * dx + dy * dy).sqrt();
-                        distance <= DRAG_THRESHOLD
-                    } else {
+                    let (press, long_clicked) = {
+                        let mut state = gesture.borrow_mut();
+                        (state.end_press(), std::mem::take(&mut state.long_clicked))
+                    };
+                    let Some(press) = press else {
                         // No Down was tracked - fire click anyway
                         // This preserves the original behavior for cases where Down
                         // was handled by a different mechanism
-                        true
-                    };
-
-                    // Reset press position
-                    *press_position.borrow_mut() = None;
-
-                    if should_click {
-                        handler(Point {
-                            x: event.position.x,
-                            y: event.position.y,
-                        });
+                        (handlers.on_click)(local);
                         event.consume();
+                        return;
+                    };
+                    if !press.is_within_drag_threshold(&event) {
+                        // Dragged off the press point: no click, but the
+                        // pressed state still has to end
+                        emit(Interaction::Cancel { position: local });
+                        return;
+                    }
+                    emit(Interaction::Release { position: local });
+                    event.consume();
+                    if long_clicked {
+                        // The long click replaced the click
+                        return;
                     }
+                    Self::click(&handlers, &gesture, local);
                 }
                 PointerEventKind::Cancel => {
                     // Clear press state on cancel
-                    *press_position.borrow_mut() = None;
+                    if gesture.borrow_mut().end_press().is_some() {
+                        emit(Interaction::Cancel { position: local });
+                    }
                 }
+                PointerEventKind::Enter => emit(Interaction::Enter),
+                PointerEventKind::Exit => emit(Interaction::Exit),
             }
         })
     }
 
+    /// Reports a completed click. With a double click handler, the first
+    /// click waits for [`DOUBLE_CLICK_TIMEOUT_MILLIS`] in case a second one
+    /// follows; two clicks further apart are two single clicks.
+    fn click(handlers: &ClickHandlers, gesture: &Rc<RefCell<ClickGesture>>, position: Point) {
+        let Some(on_double_click) = &handlers.on_double_click else {
+            (handlers.on_click)(position);
+            return;
+        };
+        let first_click = gesture.borrow_mut().pending_click.take();
+        if first_click.is_some() {
+            on_double_click(position);
+            return;
+        }
+        let on_click = Rc::clone(&handlers.on_click);
+        let pending = Rc::clone(gesture);
+        let timeout = FrameTimeout::start(DOUBLE_CLICK_TIMEOUT_MILLIS, move || {
+            pending.borrow_mut().pending_click = None;
+            on_click(position);
+        });
+        match timeout {
+            Some(timeout) => gesture.borrow_mut().pending_click = Some(timeout),
+            // Nothing to time a second click with
+            None => (handlers.on_click)(position),
+        }
+    }
+
     pub fn handler(&self) -> Rc<dyn Fn(Point)> {
-        self.on_click.clone()
+        self.handlers.on_click.clone()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable the node. Disabling it mid-press cancels the press,
+    /// so the pressed state does not outlive the input that started it.
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if enabled {
+            return;
+        }
+        let press = {
+            let mut gesture = self.gesture.borrow_mut();
+            gesture.pending_click = None;
+            gesture.end_press()
+        };
+        if let (Some(press), Some(source)) = (press, &self.interaction_source) {
+            source.emit(Interaction::Cancel {
+                position: press.local,
+            });
+        }
     }
 }
 
@@ -1332,7 +1617,7 @@ impl PointerInputNode for ClickableNode {
     }
 
     fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
-        // Return the cached handler - this ensures the same closure (with its press_position state)
+        // Return the cached handler - this ensures the same closure (with its gesture state)
         // is used across multiple calls to pointer_input_handler()
         Some(self.cached_handler.clone())
     }
@@ -1341,18 +1626,53 @@ impl PointerInputNode for ClickableNode {
 /// Element that creates and updates clickable nodes.
 #[derive(Clone)]
 pub struct ClickableElement {
-    on_click: Rc<dyn Fn(Point)>,
+    handlers: ClickHandlers,
+    interaction_source: Option<InteractionSource>,
+    enabled: bool,
 }
 
 impl ClickableElement {
     pub fn new(on_click: impl Fn(Point) + 'static) -> Self {
+        Self::with_handler(Rc::new(on_click))
+    }
+
+    pub fn with_handler(on_click: Rc<dyn Fn(Point)>) -> Self {
         Self {
-            on_click: Rc::new(on_click),
+            handlers: ClickHandlers {
+                on_click,
+                on_long_click: None,
+                on_double_click: None,
+            },
+            interaction_source: None,
+            enabled: true,
         }
     }
 
-    pub fn with_handler(on_click: Rc<dyn Fn(Point)>) -> Self {
-        Self { on_click }
+    /// Disabled clickables ignore pointer input.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Report press and hover interactions to `interaction_source`.
+    pub fn with_interaction_source(mut self, interaction_source: InteractionSource) -> Self {
+        self.interaction_source = Some(interaction_source);
+        self
+    }
+
+    /// Call `on_long_click` instead of clicking when a press is held for
+    /// [`LONG_CLICK_TIMEOUT_MILLIS`].
+    pub fn with_long_click(mut self, on_long_click: Rc<dyn Fn(Point)>) -> Self {
+        self.handlers.on_long_click = Some(on_long_click);
+        self
+    }
+
+    /// Call `on_double_click` for two clicks within
+    /// [`DOUBLE_CLICK_TIMEOUT_MILLIS`]. Single clicks are then reported once
+    /// that timeout has passed.
+    pub fn with_double_click(mut self, on_double_click: Rc<dyn Fn(Point)>) -> Self {
+        self.handlers.on_double_click = Some(on_double_click);
+        self
     }
 }
 
@@ -1384,21 +1704,32 @@ impl ModifierNodeElement for ClickableElement {
     type Node = ClickableNode;
 
     fn create(&self) -> Self::Node {
-        ClickableNode::with_handler(self.on_click.clone())
+        let mut node = ClickableNode::with_interaction_source(
+            self.handlers.on_click.clone(),
+            self.interaction_source.clone(),
+        );
+        self.update(&mut node);
+        node
     }
 
     // Note: key() is deliberately NOT implemented (returns None by default)
     // This enables type-based node reuse: the same ClickableNode instance is
     // reused across recompositions, preserving the cached_handler and its
-    // captured press_position state for proper drag detection.
+    // captured gesture state for proper drag detection.
 
     fn update(&self, node: &mut Self::Node) {
-        // Update the handler - the cached_handler needs to be recreated
-        // with the new on_click while preserving press_position
-        node.on_click = self.on_click.clone();
-        // Recreate the cached handler with the same press_position but new click handler
-        node.cached_handler =
-            ClickableNode::create_handler(node.on_click.clone(), node.press_position.clone());
+        // Update the handlers - the cached_handler needs to be recreated
+        // with the new callbacks while preserving the gesture state
+        node.handlers = self.handlers.clone();
+        node.interaction_source = self.interaction_source.clone();
+        node.set_enabled(self.enabled);
+        // Recreate the cached handler with the same gesture state but new callbacks
+        node.cached_handler = ClickableNode::create_handler(
+            node.handlers.clone(),
+            node.gesture.clone(),
+            node.interaction_source.clone(),
+            node.enabled,
+        );
     }
 
     fn capabilities(&self) -> NodeCapabilities {
@@ -1411,6 +1742,169 @@ impl ModifierNodeElement for ClickableElement {
     }
 }
 
+// ============================================================================
+// Secondary Click Modifier Node
+// ============================================================================
+
+/// Node that reports clicks with the secondary (right) mouse button, e.g. to
+/// open a context menu.
+pub struct SecondaryClickNode {
+    on_secondary_click: Rc<dyn Fn(Point)>,
+    state: NodeState,
+    /// Shared secondary press position (per-node state, accessible by handler closure)
+    press_position: Rc<RefCell<Option<PressPosition>>>,
+    /// Cached handler closure - created once, returned on every pointer_input_handler() call
+    cached_handler: Rc<dyn Fn(PointerEvent)>,
+}
+
+impl std::fmt::Debug for SecondaryClickNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecondaryClickNode").finish()
+    }
+}
+
+impl SecondaryClickNode {
+    pub fn new(on_secondary_click: Rc<dyn Fn(Point)>) -> Self {
+        let press_position = Rc::new(RefCell::new(None));
+        let cached_handler =
+            Self::create_handler(on_secondary_click.clone(), press_position.clone());
+        Self {
+            on_secondary_click,
+            state: NodeState::new(),
+            press_position,
+            cached_handler,
+        }
+    }
+
+    fn create_handler(
+        on_secondary_click: Rc<dyn Fn(Point)>,
+        press_position: Rc<RefCell<Option<PressPosition>>>,
+    ) -> Rc<dyn Fn(PointerEvent)> {
+        Rc::new(move |event: PointerEvent| {
+            if event.is_consumed() {
+                press_position.borrow_mut().take();
+                return;
+            }
+            match event.kind {
+                PointerEventKind::Down | PointerEventKind::Up
+                    if event.changed_button() != PointerButton::Secondary => {}
+                PointerEventKind::Down => {
+                    *press_position.borrow_mut() = Some(PressPosition::from_event(&event));
+                }
+                PointerEventKind::Up => {
+                    let press = press_position.borrow_mut().take();
+                    if press.is_some_and(|press| press.is_within_drag_threshold(&event)) {
+                        on_secondary_click(event.position);
+                        event.consume();
+                    }
+                }
+                PointerEventKind::Cancel => {
+                    press_position.borrow_mut().take();
+                }
+                PointerEventKind::Move | PointerEventKind::Enter | PointerEventKind::Exit => {}
+            }
+        })
+    }
+}
+
+impl DelegatableNode for SecondaryClickNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for SecondaryClickNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::PointerInput);
+    }
+
+    fn as_pointer_input_node(&self) -> Option<&dyn PointerInputNode> {
+        Some(self)
+    }
+
+    fn as_pointer_input_node_mut(&mut self) -> Option<&mut dyn PointerInputNode> {
+        Some(self)
+    }
+}
+
+impl PointerInputNode for SecondaryClickNode {
+    fn on_pointer_event(
+        &mut self,
+        _context: &mut dyn ModifierNodeContext,
+        event: &PointerEvent,
+    ) -> bool {
+        (self.cached_handler)(event.clone());
+        event.is_consumed()
+    }
+
+    fn hit_test(&self, _x: f32, _y: f32) -> bool {
+        true
+    }
+
+    fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
+        Some(self.cached_handler.clone())
+    }
+}
+
+/// Element that creates and updates secondary click nodes.
+#[derive(Clone)]
+pub struct SecondaryClickElement {
+    on_secondary_click: Rc<dyn Fn(Point)>,
+}
+
+impl SecondaryClickElement {
+    pub fn new(on_secondary_click: impl Fn(Point) + 'static) -> Self {
+        Self {
+            on_secondary_click: Rc::new(on_secondary_click),
+        }
+    }
+}
+
+impl std::fmt::Debug for SecondaryClickElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecondaryClickElement").finish()
+    }
+}
+
+impl PartialEq for SecondaryClickElement {
+    fn eq(&self, _other: &Self) -> bool {
+        // Like ClickableElement: reuse the node and its press state
+        true
+    }
+}
+
+impl Eq for SecondaryClickElement {}
+
+impl Hash for SecondaryClickElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "secondaryClick".hash(state);
+    }
+}
+
+impl ModifierNodeElement for SecondaryClickElement {
+    type Node = SecondaryClickNode;
+
+    fn create(&self) -> Self::Node {
+        SecondaryClickNode::new(self.on_secondary_click.clone())
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.on_secondary_click = self.on_secondary_click.clone();
+        node.cached_handler = SecondaryClickNode::create_handler(
+            node.on_secondary_click.clone(),
+            node.press_position.clone(),
+        );
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::POINTER_INPUT
+    }
+
+    fn always_update(&self) -> bool {
+        true
+    }
+}
+
 // ============================================================================
 // Pointer Input Modifier Node
 // ============================================================================
@@ -1530,6 +2024,78 @@ impl ModifierNodeElement for PointerEventHandlerElement {
     }
 }
 
+// ============================================================================
+// Minimum Touch Target Modifier Node
+// ============================================================================
+
+/// Node that grows its layout node's pointer hit region to at least `size`
+/// without affecting layout or drawing.
+#[derive(Debug)]
+pub struct MinimumTouchTargetNode {
+    size: Size,
+    state: NodeState,
+}
+
+impl MinimumTouchTargetNode {
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl DelegatableNode for MinimumTouchTargetNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for MinimumTouchTargetNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::PointerInput);
+    }
+}
+
+/// Element that creates minimum touch target nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimumTouchTargetElement {
+    size: Size,
+}
+
+impl MinimumTouchTargetElement {
+    pub fn new(size: Size) -> Self {
+        Self { size }
+    }
+}
+
+impl Hash for MinimumTouchTargetElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.size.width);
+        hash_f32_value(state, self.size.height);
+    }
+}
+
+impl ModifierNodeElement for MinimumTouchTargetElement {
+    type Node = MinimumTouchTargetNode;
+
+    fn create(&self) -> Self::Node {
+        MinimumTouchTargetNode::new(self.size)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.size = self.size;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::POINTER_INPUT
+    }
+}
+
 // ============================================================================
 // Alpha Modifier Node
 // ============================================================================
@@ -1823,17 +2389,24 @@ impl ModifierNodeElement for DrawCommandElement {
 
 /// Node that offsets its content by a fixed (x, y) amount.
 ///
+/// The reported size is the child's size, so offsets never grow the parent;
+/// overflowing content is only clipped by a clipping ancestor.
+///
 /// Matches Kotlin: `OffsetNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Offset.kt
 #[derive(Debug)]
 pub struct OffsetNode {
-    x: f32,
-    y: f32,
+    x: LayoutLength,
+    y: LayoutLength,
     rtl_aware: bool,
     state: NodeState,
 }
 
 impl OffsetNode {
     pub fn new(x: f32, y: f32, rtl_aware: bool) -> Self {
+        Self::from_lengths(x.into(), y.into(), rtl_aware)
+    }
+
+    pub(crate) fn from_lengths(x: LayoutLength, y: LayoutLength, rtl_aware: bool) -> Self {
         Self {
             x,
             y,
@@ -1842,10 +2415,11 @@ impl OffsetNode {
         }
     }
 
+    /// Offset in layout pixels at the measure-pass density.
     pub fn offset(&self) -> Point {
         Point {
-            x: self.x,
-            y: self.y,
+            x: self.x.to_px(),
+            y: self.y.to_px(),
         }
     }
 
@@ -1883,6 +2457,7 @@ impl LayoutModifierNode for OffsetNode {
     ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
         // Offset doesn't affect measurement, just placement
         let placeable = measurable.measure(constraints);
+        let offset = self.offset();
 
         // Return child size unchanged, but specify the offset for placement
         cranpose_ui_layout::LayoutModifierMeasureResult::new(
@@ -1890,8 +2465,8 @@ impl LayoutModifierNode for OffsetNode {
                 width: placeable.width(),
                 height: placeable.height(),
             },
-            self.x, // Place child offset by x
-            self.y, // Place child offset by y
+            offset.x, // Place child offset by x
+            offset.y, // Place child offset by y
         )
     }
 
@@ -1926,8 +2501,8 @@ impl LayoutModifierNode for OffsetNode {
 /// directly implements measurement logic. Since offset doesn't affect measurement
 /// (only placement), this is a simple passthrough.
 struct OffsetMeasurementProxy {
-    x: f32,
-    y: f32,
+    x: LayoutLength,
+    y: LayoutLength,
     #[allow(dead_code)]
     rtl_aware: bool,
 }
@@ -1948,8 +2523,8 @@ impl MeasurementProxy for OffsetMeasurementProxy {
                 width: placeable.width(),
                 height: placeable.height(),
             },
-            self.x, // Place child offset by x
-            self.y, // Place child offset by y
+            self.x.to_px(), // Place child offset by x
+            self.y.to_px(), // Place child offset by y
         )
     }
 
@@ -1975,21 +2550,25 @@ impl MeasurementProxy for OffsetMeasurementProxy {
 /// Matches Kotlin: `OffsetElement` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Offset.kt
 #[derive(Debug, Clone, PartialEq)]
 pub struct OffsetElement {
-    x: f32,
-    y: f32,
+    x: LayoutLength,
+    y: LayoutLength,
     rtl_aware: bool,
 }
 
 impl OffsetElement {
     pub fn new(x: f32, y: f32, rtl_aware: bool) -> Self {
+        Self::from_lengths(x.into(), y.into(), rtl_aware)
+    }
+
+    pub(crate) fn from_lengths(x: LayoutLength, y: LayoutLength, rtl_aware: bool) -> Self {
         Self { x, y, rtl_aware }
     }
 }
 
 impl Hash for OffsetElement {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        hash_f32_value(state, self.x);
-        hash_f32_value(state, self.y);
+        self.x.hash(state);
+        self.y.hash(state);
         self.rtl_aware.hash(state);
     }
 }
@@ -1998,7 +2577,7 @@ impl ModifierNodeElement for OffsetElement {
     type Node = OffsetNode;
 
     fn create(&self) -> Self::Node {
-        OffsetNode::new(self.x, self.y, self.rtl_aware)
+        OffsetNode::from_lengths(self.x, self.y, self.rtl_aware)
     }
 
     fn update(&self, node: &mut Self::Node) {
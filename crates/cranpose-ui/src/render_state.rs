@@ -140,7 +140,9 @@ pub fn current_density() -> f32 {
 /// Updates the current density scale factor.
 ///
 /// This triggers a global layout invalidation when the value changes because
-/// density impacts layout, text measurement, and input thresholds.
+/// density impacts layout, text measurement, and input thresholds. Layout
+/// caches are also keyed on the density, so the next measure recomputes
+/// sizes even if the constraints stay the same.
 pub fn set_density(density: f32) {
     let normalized = if density.is_finite() && density > 0.0 {
         density
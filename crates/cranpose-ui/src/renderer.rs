@@ -124,7 +124,7 @@ fn evaluate_modifier(
                     behind.push(RenderOp::Primitive {
                         node_id,
                         layer: PaintLayer::Behind,
-                        primitive: translate_primitive(primitive, rect.x, rect.y),
+                        primitive: primitive.translate(rect.x, rect.y),
                     });
                 }
             }
@@ -133,7 +133,7 @@ fn evaluate_modifier(
                     overlay.push(RenderOp::Primitive {
                         node_id,
                         layer: PaintLayer::Overlay,
-                        primitive: translate_primitive(primitive, rect.x, rect.y),
+                        primitive: primitive.translate(rect.x, rect.y),
                     });
                 }
             }
@@ -143,20 +143,6 @@ fn evaluate_modifier(
     (behind, overlay)
 }
 
-fn translate_primitive(primitive: DrawPrimitive, dx: f32, dy: f32) -> DrawPrimitive {
-    match primitive {
-        DrawPrimitive::Rect { rect, brush } => DrawPrimitive::Rect {
-            rect: rect.translate(dx, dy),
-            brush,
-        },
-        DrawPrimitive::RoundRect { rect, brush, radii } => DrawPrimitive::RoundRect {
-            rect: rect.translate(dx, dy),
-            brush,
-            radii,
-        },
-    }
-}
-
 #[cfg(test)]
 #[path = "tests/renderer_tests.rs"]
 mod tests;
@@ -0,0 +1,155 @@
+//! State that outlives its composable.
+//!
+//! A [`SaveableStateRegistry`] keeps values by key while the content that
+//! owns them leaves the composition, e.g. a screen further down a navigation
+//! back stack. [`rememberSaveable`] registers its state with the registry
+//! provided through [`LocalSaveableStateRegistry`] and picks the saved value
+//! back up when the content returns. State objects that are not plain values,
+//! such as scroll states, go through a [`Saver`] with [`rememberSaveableWith`].
+
+#![allow(non_snake_case)]
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cranpose_core::{
+    remember, staticCompositionLocalOf, useState, DisposableEffect, MutableState,
+    StaticCompositionLocal,
+};
+
+type SaveProvider = Rc<dyn Fn() -> Box<dyn Any>>;
+
+#[derive(Default)]
+struct RegistryInner {
+    saved: RefCell<HashMap<String, Box<dyn Any>>>,
+    providers: RefCell<Vec<(String, SaveProvider)>>,
+}
+
+/// Saved values by key, plus the live providers that produce new ones.
+#[derive(Clone, Default)]
+pub struct SaveableStateRegistry {
+    inner: Rc<RegistryInner>,
+}
+
+impl SaveableStateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the value saved under `key`, if it has the requested type.
+    pub fn consume_restored<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let value = self.inner.saved.borrow_mut().remove(key)?;
+        value.downcast_ref::<T>().cloned()
+    }
+
+    /// Store the current value of every registered provider. Call it before
+    /// the content that registered them leaves the composition.
+    pub fn perform_save(&self) {
+        let providers = self.inner.providers.borrow().clone();
+        let mut saved = self.inner.saved.borrow_mut();
+        for (key, provider) in providers {
+            saved.insert(key, provider());
+        }
+    }
+
+    fn register(&self, key: String, provider: SaveProvider) {
+        self.inner.providers.borrow_mut().push((key, provider));
+    }
+
+    fn unregister(&self, provider: &SaveProvider) {
+        self.inner
+            .providers
+            .borrow_mut()
+            .retain(|(_, registered)| !Rc::ptr_eq(registered, provider));
+    }
+}
+
+impl PartialEq for SaveableStateRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+thread_local! {
+    static LOCAL_SAVEABLE_STATE_REGISTRY: StaticCompositionLocal<Option<SaveableStateRegistry>> =
+        staticCompositionLocalOf(|| None);
+}
+
+/// Registry that [`rememberSaveable`] saves into, or `None` outside of any
+/// saveable scope.
+pub fn LocalSaveableStateRegistry() -> StaticCompositionLocal<Option<SaveableStateRegistry>> {
+    LOCAL_SAVEABLE_STATE_REGISTRY.with(Clone::clone)
+}
+
+/// Converts a state object to a plain value a [`SaveableStateRegistry`] can
+/// keep, and back.
+pub trait Saver<T> {
+    type Saved: Clone + 'static;
+
+    fn save(&self, value: &T) -> Self::Saved;
+
+    /// Rebuilds the object from a saved value. Runs during composition.
+    fn restore(&self, saved: Self::Saved) -> T;
+}
+
+/// Like [`useState`], but the value survives the composable leaving the
+/// composition and coming back, as long as the surrounding
+/// [`LocalSaveableStateRegistry`] saved it in between.
+///
+/// `key` must be unique among the saveable values of the same registry.
+/// Without a registry this is plain [`useState`].
+///
+/// Mirrors Jetpack Compose's `rememberSaveable`.
+pub fn rememberSaveable<T: Clone + 'static>(
+    key: &str,
+    init: impl FnOnce() -> T,
+) -> MutableState<T> {
+    let registry = LocalSaveableStateRegistry().current();
+    let state = useState(|| {
+        registry
+            .as_ref()
+            .and_then(|registry| registry.consume_restored(key))
+            .unwrap_or_else(init)
+    });
+    if let Some(registry) = registry {
+        register_provider(registry, key, Rc::new(move || Box::new(state.value())));
+    }
+    state
+}
+
+/// Like [`rememberSaveable`], for a state object that `saver` converts to
+/// and from a plain value, e.g. a scroll state.
+///
+/// Mirrors Jetpack Compose's `rememberSaveable(saver = ...)`.
+pub fn rememberSaveableWith<T, S>(key: &str, saver: S, init: impl FnOnce() -> T) -> T
+where
+    T: Clone + 'static,
+    S: Saver<T> + 'static,
+{
+    let registry = LocalSaveableStateRegistry().current();
+    let value = remember(|| {
+        registry
+            .as_ref()
+            .and_then(|registry| registry.consume_restored(key))
+            .map(|saved| saver.restore(saved))
+            .unwrap_or_else(init)
+    })
+    .with(Clone::clone);
+    if let Some(registry) = registry {
+        let saved = value.clone();
+        register_provider(registry, key, Rc::new(move || Box::new(saver.save(&saved))));
+    }
+    value
+}
+
+/// Keeps `provider` registered under `key` while the caller stays in the
+/// composition.
+fn register_provider(registry: SaveableStateRegistry, key: &str, provider: SaveProvider) {
+    let key = key.to_owned();
+    DisposableEffect!((), move |scope| {
+        registry.register(key, Rc::clone(&provider));
+        scope.on_dispose(move || registry.unregister(&provider))
+    });
+}
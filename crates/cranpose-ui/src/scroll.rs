@@ -8,6 +8,7 @@
 //! The actual `Modifier.horizontal_scroll()` and `Modifier.vertical_scroll()`
 //! extension methods are defined in `modifier/scroll.rs`.
 
+use crate::saveable::Saver;
 use cranpose_core::{mutableStateOf, MutableState, NodeId};
 use cranpose_foundation::{
     Constraints, DelegatableNode, LayoutModifierNode, Measurable, ModifierNode,
@@ -121,8 +122,14 @@ impl ScrollState {
     }
 
     /// Sets the maximum scroll value (internal use by ScrollNode).
+    ///
+    /// A position beyond the new maximum, e.g. one restored for content that
+    /// has shrunk since, moves back to it.
     pub(crate) fn set_max_value(&self, max: f32) {
         *self.inner.max_value.borrow_mut() = max;
+        if self.value_non_reactive() > max {
+            self.inner.value.set(max);
+        }
     }
 
     /// Scrolls to the given position immediately.
@@ -166,6 +173,23 @@ impl ScrollState {
     }
 }
 
+/// Saves a [`ScrollState`] as its scroll position, for
+/// [`rememberSaveableWith`](crate::rememberSaveableWith).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScrollStateSaver;
+
+impl Saver<ScrollState> for ScrollStateSaver {
+    type Saved = f32;
+
+    fn save(&self, state: &ScrollState) -> f32 {
+        state.value_non_reactive()
+    }
+
+    fn restore(&self, position: f32) -> ScrollState {
+        ScrollState::new(position)
+    }
+}
+
 /// Element for creating a ScrollNode.
 #[derive(Clone)]
 pub struct ScrollElement {
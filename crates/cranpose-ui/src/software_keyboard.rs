@@ -0,0 +1,35 @@
+//! Software keyboard composition local.
+//!
+//! The host installs a [`SoftwareKeyboardController`] bridging to the
+//! platform's on-screen keyboard and provides it through
+//! [`LocalSoftwareKeyboardController`]. Text fields show it when they gain
+//! focus and hide it when they lose focus; other content can read the local
+//! to dismiss the keyboard, e.g. after submitting a form.
+
+#![allow(non_snake_case)]
+
+use std::rc::Rc;
+
+use cranpose_core::{staticCompositionLocalOf, StaticCompositionLocal};
+
+/// Shows and hides the platform's on-screen keyboard.
+pub trait SoftwareKeyboardController {
+    /// Ask the platform to show the keyboard.
+    fn show(&self);
+    /// Ask the platform to hide the keyboard.
+    fn hide(&self);
+}
+
+thread_local! {
+    static LOCAL_SOFTWARE_KEYBOARD_CONTROLLER: StaticCompositionLocal<
+        Option<Rc<dyn SoftwareKeyboardController>>,
+    > = staticCompositionLocalOf(|| None);
+}
+
+/// Controller of the on-screen keyboard, or `None` if the host has none.
+///
+/// Mirrors Jetpack Compose's `LocalSoftwareKeyboardController`.
+pub fn LocalSoftwareKeyboardController(
+) -> StaticCompositionLocal<Option<Rc<dyn SoftwareKeyboardController>>> {
+    LOCAL_SOFTWARE_KEYBOARD_CONTROLLER.with(Clone::clone)
+}
@@ -1,9 +1,10 @@
 use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use cranpose_core::{
-    Composer, NodeError, NodeId, Phase, SlotBackend, SlotId, SlotsHost, SubcomposeState,
+    ApplierHost, Composer, NodeError, NodeId, Phase, RuntimeHandle, SlotBackend, SlotId, SlotsHost,
+    SnapshotStateObserver, SubcomposeState,
 };
 use indexmap::IndexSet;
 
@@ -13,6 +14,7 @@ use crate::widgets::nodes::{
 };
 
 use cranpose_foundation::{InvalidationKind, ModifierInvalidation, NodeCapabilities};
+use cranpose_ui_graphics::Density;
 
 pub use cranpose_ui_layout::{Constraints, MeasureResult, Placement};
 
@@ -183,52 +185,14 @@ impl<'a> SubcomposeMeasureScopeImpl<'a> {
     where
         Content: FnOnce(),
     {
-        let mut inner = self.parent_handle.inner.borrow_mut();
-
-        // Reuse or create virtual node
-        let (virtual_node_id, is_reused) =
-            if let Some(node_id) = self.state.take_node_from_reusables(slot_id) {
-                (node_id, true)
-            } else {
-                let id = allocate_virtual_node_id();
-                let node = LayoutNode::new_virtual();
-                // CRITICAL FIX: Register virtual node in Applier so that insert_child commands
-                // can find it. Previously, virtual nodes were only stored in inner.virtual_nodes
-                // which caused applier.get_mut(virtual_node_id) to fail, breaking child attachment.
-                if let Err(e) = self
-                    .composer
-                    .register_virtual_node(id, Box::new(node.clone()))
-                {
-                    eprintln!(
-                        "[Subcompose] Failed to register virtual node {}: {:?}",
-                        id, e
-                    );
-                }
-                register_layout_node(id, &node);
-
-                inner.virtual_nodes.insert(id, Rc::new(node));
-                inner.children.insert(id);
-                (id, false)
-            };
-
-        // Manually link parent
-        if let Some(v_node) = inner.virtual_nodes.get(&virtual_node_id) {
-            v_node.set_parent(self.root_id);
-        }
-
-        drop(inner);
-
-        // CRITICAL FIX: Clear children of reused virtual nodes BEFORE subcomposing new content.
-        // Without this, old children remain attached when the node is reused for different items,
-        // causing items from different scroll positions to interleave (e.g., [1,16,31,2,17,32...]).
-        if is_reused {
-            self.composer.clear_node_children(virtual_node_id);
-        }
-
-        let slot_host = self.state.get_or_create_slots(slot_id);
-        let _ = self
-            .composer
-            .subcompose_slot(&slot_host, Some(virtual_node_id), |_| content());
+        let virtual_node_id = compose_slot(
+            &self.composer,
+            &self.parent_handle.inner,
+            self.state,
+            self.root_id,
+            slot_id,
+            content,
+        );
 
         self.state.register_active(slot_id, &[virtual_node_id], &[]);
 
@@ -239,6 +203,92 @@ impl<'a> SubcomposeMeasureScopeImpl<'a> {
     }
 }
 
+/// Composes `content` for `slot_id` into a virtual node owned by the
+/// subcompose layout `root_id` and returns that node.
+fn compose_slot<Content>(
+    composer: &Composer,
+    inner: &RefCell<SubcomposeLayoutNodeInner>,
+    state: &mut SubcomposeState,
+    root_id: NodeId,
+    slot_id: SlotId,
+    content: Content,
+) -> NodeId
+where
+    Content: FnOnce(),
+{
+    let mut inner = inner.borrow_mut();
+
+    // Reuse or create virtual node
+    let (virtual_node_id, is_reused) =
+        if let Some(node_id) = state.take_node_from_reusables(slot_id) {
+            (node_id, true)
+        } else {
+            let id = allocate_virtual_node_id();
+            let node = LayoutNode::new_virtual();
+            // CRITICAL FIX: Register virtual node in Applier so that insert_child commands
+            // can find it. Previously, virtual nodes were only stored in inner.virtual_nodes
+            // which caused applier.get_mut(virtual_node_id) to fail, breaking child attachment.
+            if let Err(e) = composer.register_virtual_node(id, Box::new(node.clone())) {
+                eprintln!(
+                    "[Subcompose] Failed to register virtual node {}: {:?}",
+                    id, e
+                );
+            }
+            register_layout_node(id, &node);
+
+            inner.virtual_nodes.insert(id, Rc::new(node));
+            inner.children.insert(id);
+            (id, false)
+        };
+
+    // Manually link parent
+    if let Some(v_node) = inner.virtual_nodes.get(&virtual_node_id) {
+        v_node.set_parent(root_id);
+    }
+
+    drop(inner);
+
+    // CRITICAL FIX: Clear children of reused virtual nodes BEFORE subcomposing new content.
+    // Without this, old children remain attached when the node is reused for different items,
+    // causing items from different scroll positions to interleave (e.g., [1,16,31,2,17,32...]).
+    if is_reused {
+        composer.clear_node_children(virtual_node_id);
+    }
+
+    let slot_host = state.get_or_create_slots(slot_id);
+    let _ = composer.subcompose_slot(&slot_host, Some(virtual_node_id), |_| content());
+    virtual_node_id
+}
+
+/// Composes `content` for `slot_id` and tracks it as precomposed, unless this
+/// pass already placed the slot. Returns the root nodes of the content.
+fn precompose_slot<Content>(
+    composer: &Composer,
+    inner: &RefCell<SubcomposeLayoutNodeInner>,
+    state: &mut SubcomposeState,
+    root_id: NodeId,
+    slot_id: SlotId,
+    content: Content,
+) -> Vec<NodeId>
+where
+    Content: FnOnce(),
+{
+    let virtual_node_id = compose_slot(composer, inner, state, root_id, slot_id, content);
+    if !state.is_activated(slot_id) {
+        state.register_precomposed(slot_id, virtual_node_id);
+    }
+    composer.get_node_children(virtual_node_id)
+}
+
+/// The applier and runtime a subcompose layout was composed with, kept so it
+/// can compose slots outside of measure.
+///
+/// The applier is held weakly: it owns the node that holds this.
+struct PrecomposeHost {
+    applier: Weak<dyn ApplierHost>,
+    runtime: RuntimeHandle,
+}
+
 impl<'a> SubcomposeLayoutScope for SubcomposeMeasureScopeImpl<'a> {
     fn constraints(&self) -> Constraints {
         self.constraints
@@ -297,6 +347,26 @@ impl<'a> SubcomposeMeasureScopeImpl<'a> {
             .collect()
     }
 
+    /// Composes `content` for `slot_id` without placing it, so that a later
+    /// pass subcomposing the slot reuses the composed nodes.
+    ///
+    /// Lazy layouts use this to prefetch items just outside the viewport.
+    /// See [`SubcomposeLayoutNodeHandle::precompose`] for how long the slot
+    /// is kept.
+    pub fn precompose<Content>(&mut self, slot_id: SlotId, content: Content) -> Vec<NodeId>
+    where
+        Content: FnOnce(),
+    {
+        precompose_slot(
+            &self.composer,
+            &self.parent_handle.inner,
+            self.state,
+            self.root_id,
+            slot_id,
+            content,
+        )
+    }
+
     /// Returns the number of active slots in the subcompose state.
     ///
     /// Used by lazy layouts to report statistics about slot usage.
@@ -359,6 +429,8 @@ pub struct SubcomposeLayoutNode {
     needs_redraw: Cell<bool>,
     needs_pointer_pass: Cell<bool>,
     needs_focus_sync: Cell<bool>,
+    /// Modifier, measure policy or density changed since the last `update()`
+    needs_update: Cell<bool>,
     virtual_children_count: Cell<usize>,
 }
 
@@ -375,6 +447,7 @@ impl SubcomposeLayoutNode {
             needs_redraw: Cell::new(true),
             needs_pointer_pass: Cell::new(false),
             needs_focus_sync: Cell::new(false),
+            needs_update: Cell::new(false),
             virtual_children_count: Cell::new(0),
         };
         // Set modifier and dispatch invalidations after borrow is released
@@ -405,6 +478,7 @@ impl SubcomposeLayoutNode {
             needs_redraw: Cell::new(true),
             needs_pointer_pass: Cell::new(false),
             needs_focus_sync: Cell::new(false),
+            needs_update: Cell::new(false),
             virtual_children_count: Cell::new(0),
         };
         // Set modifier and dispatch invalidations after borrow is released
@@ -421,7 +495,11 @@ impl SubcomposeLayoutNode {
     }
 
     pub fn set_measure_policy(&mut self, policy: Rc<MeasurePolicy>) {
-        self.inner.borrow_mut().set_measure_policy(policy);
+        let mut inner = self.inner.borrow_mut();
+        if !Rc::ptr_eq(&inner.measure_policy, &policy) {
+            inner.set_measure_policy(policy);
+            self.needs_update.set(true);
+        }
     }
 
     pub fn set_modifier(&mut self, modifier: Modifier) {
@@ -436,11 +514,38 @@ impl SubcomposeLayoutNode {
         // Pass both prev and curr caps so removed modifiers still trigger invalidation
         self.dispatch_modifier_invalidations(&invalidations, prev_caps);
         if modifier_changed {
+            self.needs_update.set(true);
             self.mark_needs_measure();
             self.request_semantics_update();
         }
     }
 
+    /// Remembers the applier and runtime of the composer emitting this node,
+    /// which [`SubcomposeLayoutNodeHandle::precompose`] composes slots with.
+    pub(crate) fn set_precompose_host(&mut self, composer: &Composer) {
+        self.inner.borrow_mut().precompose_host = Some(PrecomposeHost {
+            applier: Rc::downgrade(&composer.applier_host()),
+            runtime: composer.runtime_handle(),
+        });
+    }
+
+    /// Sets the [`LocalDensity`](crate::LocalDensity) this node was composed
+    /// under; its modifiers' [`Dp`](crate::Dp) lengths are measured with it.
+    pub fn set_density(&mut self, density: Density) {
+        let changed = {
+            let mut inner = self.inner.borrow_mut();
+            let changed = inner.modifier_chain.set_density(density);
+            if changed {
+                inner.resolved_modifiers = inner.modifier_chain.resolved_modifiers();
+            }
+            changed
+        };
+        if changed {
+            self.needs_update.set(true);
+            self.mark_needs_measure();
+        }
+    }
+
     pub fn set_debug_modifiers(&mut self, enabled: bool) {
         self.inner.borrow_mut().set_debug_modifiers(enabled);
     }
@@ -656,6 +761,14 @@ impl cranpose_core::Node for SubcomposeLayoutNode {
             .detach_nodes();
     }
 
+    fn update(&mut self) {
+        self.needs_update.set(false);
+    }
+
+    fn needs_update(&self) -> bool {
+        self.needs_update.get()
+    }
+
     fn insert_child(&mut self, child: NodeId) {
         if is_virtual_node(child) {
             let count = self.virtual_children_count.get();
@@ -707,6 +820,15 @@ impl cranpose_core::Node for SubcomposeLayoutNode {
         }
     }
 
+    fn has_child(&self, child: NodeId) -> bool {
+        let inner = self.inner.borrow();
+        if !inner.last_placements.is_empty() {
+            inner.last_placements.contains(&child)
+        } else {
+            inner.children.contains(&child)
+        }
+    }
+
     fn set_node_id(&mut self, id: NodeId) {
         self.id.set(Some(id));
         self.inner.borrow_mut().modifier_chain.set_node_id(Some(id));
@@ -810,6 +932,94 @@ impl SubcomposeLayoutNodeHandle {
         self.inner.borrow_mut().set_debug_modifiers(enabled);
     }
 
+    /// Composes `content` for `slot_id` ahead of the measure pass that places it.
+    ///
+    /// Unlike subcomposition from a measure policy this runs outside of
+    /// measure, e.g. from an idle callback while a lazy list is scrolling,
+    /// with the applier and runtime this node was composed with. The next
+    /// measure that subcomposes `slot_id` reuses the composed nodes. Until
+    /// then the slot is kept across passes, unless it is cancelled with
+    /// [`cancel_precompose`](Self::cancel_precompose) or more slots are
+    /// precomposed than the state keeps, which disposes the oldest ones.
+    /// Returns the root nodes of the composed content.
+    pub fn precompose<Content>(
+        &self,
+        node_id: NodeId,
+        slot_id: SlotId,
+        content: Content,
+    ) -> Result<Vec<NodeId>, NodeError>
+    where
+        Content: FnOnce(),
+    {
+        let composer = self.detached_composer(node_id)?;
+        let mut state = std::mem::take(&mut self.inner.borrow_mut().state);
+        let children = precompose_slot(
+            &composer,
+            &self.inner,
+            &mut state,
+            node_id,
+            slot_id,
+            content,
+        );
+        let disposed = state.trim_precomposed();
+        self.inner.borrow_mut().state = state;
+        self.dispose_virtual_nodes(&composer, disposed)?;
+        Ok(children)
+    }
+
+    /// Disposes the content precomposed for `slot_id` if no measure pass has
+    /// placed it yet.
+    pub fn cancel_precompose(&self, node_id: NodeId, slot_id: SlotId) -> Result<(), NodeError> {
+        let disposed = self.inner.borrow_mut().state.remove_precomposed(slot_id);
+        if disposed.is_empty() {
+            return Ok(());
+        }
+        let composer = self.detached_composer(node_id)?;
+        self.dispose_virtual_nodes(&composer, disposed)
+    }
+
+    /// A composer for work on `node_id` outside of composition and measure.
+    fn detached_composer(&self, node_id: NodeId) -> Result<Composer, NodeError> {
+        let inner = self.inner.borrow();
+        let host = inner
+            .precompose_host
+            .as_ref()
+            .ok_or(NodeError::MissingContext {
+                id: node_id,
+                reason: "subcompose layout was not emitted by a composer",
+            })?;
+        let applier = host.applier.upgrade().ok_or(NodeError::MissingContext {
+            id: node_id,
+            reason: "applier of the subcompose layout was dropped",
+        })?;
+        Ok(Composer::new(
+            Rc::new(SlotsHost::new(SlotBackend::default())),
+            applier,
+            host.runtime.clone(),
+            SnapshotStateObserver::new(|callback| callback()),
+            Some(node_id),
+        ))
+    }
+
+    /// Removes virtual nodes of disposed slots from this node and the applier.
+    fn dispose_virtual_nodes(
+        &self,
+        composer: &Composer,
+        disposed: Vec<NodeId>,
+    ) -> Result<(), NodeError> {
+        {
+            let mut inner = self.inner.borrow_mut();
+            for id in &disposed {
+                inner.virtual_nodes.remove(id);
+                inner.children.shift_remove(id);
+            }
+        }
+        for id in disposed {
+            composer.remove_virtual_node(id)?;
+        }
+        Ok(())
+    }
+
     pub fn measure<'a>(
         &self,
         composer: &Composer,
@@ -842,19 +1052,22 @@ impl SubcomposeLayoutNodeHandle {
         //
         // Reference: LazyLayoutMeasureScope.subcompose() in JC reuses existing slots by key,
         // and SubcomposeLayoutState holds `slotIdToNode` map across measurements.
-        let result = composer.subcompose_slot(&slots_host, Some(node_id), |inner_composer| {
-            let mut scope = SubcomposeMeasureScopeImpl::new(
-                inner_composer.clone(),
-                &mut state,
-                constraints_copy,
-                measurer,
-                Rc::clone(&error),
-                self.clone(), // Pass handle
-                node_id,      // Pass root_id
-            );
-            (policy)(&mut scope, constraints_copy)
+        let result = composer.runtime_handle().with_layout_phase(|| {
+            composer.subcompose_slot(&slots_host, Some(node_id), |inner_composer| {
+                let mut scope = SubcomposeMeasureScopeImpl::new(
+                    inner_composer.clone(),
+                    &mut state,
+                    constraints_copy,
+                    measurer,
+                    Rc::clone(&error),
+                    self.clone(), // Pass handle
+                    node_id,      // Pass root_id
+                );
+                (policy)(&mut scope, constraints_copy)
+            })
         })?;
 
+        let disposed = state.trim_precomposed();
         state.finish_pass();
 
         if previous != composer.phase() {
@@ -872,6 +1085,7 @@ impl SubcomposeLayoutNodeHandle {
             // tracked via insert_child/remove_child, while last_placements tracks rendered nodes.
             inner.last_placements = result.placements.iter().map(|p| p.node_id).collect();
         }
+        self.dispose_virtual_nodes(composer, disposed)?;
 
         Ok(result)
     }
@@ -903,6 +1117,7 @@ struct SubcomposeLayoutNodeInner {
     // Cached placement children from the last measure pass.
     // Used by children() for semantic/render traversal without clearing structural children.
     last_placements: Vec<NodeId>,
+    precompose_host: Option<PrecomposeHost>,
 }
 
 impl SubcomposeLayoutNodeInner {
@@ -919,6 +1134,7 @@ impl SubcomposeLayoutNodeInner {
             debug_modifiers: false,
             virtual_nodes: HashMap::new(),
             last_placements: Vec::new(),
+            precompose_host: None,
         }
     }
 
@@ -1,7 +1,7 @@
 use crate::{Brush, Color, Column, ColumnSpec, CornerRadii, Modifier, Row, RowSpec, Text};
 use cranpose_core::{
-    location_key, Composition, MemoryApplier, MutableState, Node, NodeError,
-    __launched_effect_async_impl as launched_effect_async_impl,
+    __launched_effect_async_impl as launched_effect_async_impl, location_key, Composition,
+    MemoryApplier, MutableState, Node, NodeError,
 };
 use cranpose_macros::composable;
 
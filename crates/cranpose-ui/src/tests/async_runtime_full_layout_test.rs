@@ -13,8 +13,8 @@ use crate::{
     Text,
 };
 use cranpose_core::{
-    location_key, Composition, MemoryApplier, MutableState, Node,
-    __launched_effect_async_impl as launched_effect_async_impl,
+    __launched_effect_async_impl as launched_effect_async_impl, location_key, Composition,
+    MemoryApplier, MutableState, Node,
 };
 use cranpose_macros::composable;
 
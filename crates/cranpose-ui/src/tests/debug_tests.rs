@@ -1,6 +1,7 @@
 use super::*;
 use crate::layout::{LayoutBox, LayoutNodeData, LayoutNodeKind};
 use crate::modifier::{Modifier, ModifierNodeSlices, Point, Rect, ResolvedModifiers};
+use std::rc::Rc;
 
 #[test]
 fn test_count_nodes() {
@@ -25,20 +26,20 @@ fn test_count_nodes() {
         content_offset: Point::default(),
         node_data: node_data(),
         children: vec![
-            LayoutBox {
+            Rc::new(LayoutBox {
                 node_id: 1,
                 rect: empty_rect,
                 content_offset: Point::default(),
                 node_data: node_data(),
                 children: vec![],
-            },
-            LayoutBox {
+            }),
+            Rc::new(LayoutBox {
                 node_id: 2,
                 rect: empty_rect,
                 content_offset: Point::default(),
                 node_data: node_data(),
                 children: vec![],
-            },
+            }),
         ],
     };
 
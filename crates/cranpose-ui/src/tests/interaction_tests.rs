@@ -0,0 +1,116 @@
+use super::*;
+use crate::modifier::{collect_slices_from_modifier, Modifier};
+use cranpose_core::{DefaultScheduler, Runtime};
+use cranpose_foundation::{PointerEvent, PointerEventKind};
+use std::sync::Arc;
+
+fn recorded(source: &InteractionSource) -> Rc<RefCell<Vec<Interaction>>> {
+    let interactions = Rc::new(RefCell::new(Vec::new()));
+    source.subscribe({
+        let interactions = interactions.clone();
+        move |interaction| interactions.borrow_mut().push(*interaction)
+    });
+    interactions
+}
+
+fn event(kind: PointerEventKind, x: f32, y: f32) -> PointerEvent {
+    PointerEvent::new(kind, Point { x, y }, Point { x, y })
+}
+
+#[test]
+fn press_and_release_emit_interactions() {
+    let _runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let source = InteractionSource::new();
+    let interactions = recorded(&source);
+    let clicks = Rc::new(Cell::new(0));
+
+    let modifier = Modifier::empty().clickable_with_interaction(source.clone(), {
+        let clicks = clicks.clone();
+        move |_| clicks.set(clicks.get() + 1)
+    });
+    let handler = collect_slices_from_modifier(&modifier).pointer_inputs()[0].clone();
+
+    handler(event(PointerEventKind::Down, 10.0, 10.0));
+    assert!(source.is_pressed());
+    handler(event(PointerEventKind::Up, 11.0, 10.0));
+
+    assert!(!source.is_pressed());
+    assert_eq!(clicks.get(), 1);
+    assert_eq!(
+        *interactions.borrow(),
+        vec![
+            Interaction::Press {
+                position: Point { x: 10.0, y: 10.0 }
+            },
+            Interaction::Release {
+                position: Point { x: 11.0, y: 10.0 }
+            },
+        ]
+    );
+}
+
+#[test]
+fn press_dragged_off_is_cancelled_without_click() {
+    let _runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let source = InteractionSource::new();
+    let interactions = recorded(&source);
+    let clicks = Rc::new(Cell::new(0));
+
+    let modifier = Modifier::empty().clickable_with_interaction(source.clone(), {
+        let clicks = clicks.clone();
+        move |_| clicks.set(clicks.get() + 1)
+    });
+    let handler = collect_slices_from_modifier(&modifier).pointer_inputs()[0].clone();
+
+    handler(event(PointerEventKind::Down, 10.0, 10.0));
+    handler(event(PointerEventKind::Up, 200.0, 10.0));
+
+    assert!(!source.is_pressed());
+    assert_eq!(clicks.get(), 0);
+    assert!(matches!(
+        interactions.borrow().last(),
+        Some(Interaction::Cancel { .. })
+    ));
+}
+
+#[test]
+fn hover_tracks_enter_and_exit() {
+    let _runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let source = InteractionSource::new();
+    let modifier = Modifier::empty().clickable_with_interaction(source.clone(), |_| {});
+    let handler = collect_slices_from_modifier(&modifier).pointer_inputs()[0].clone();
+
+    handler(event(PointerEventKind::Enter, 5.0, 5.0));
+    assert!(source.is_hovered());
+    handler(event(PointerEventKind::Exit, 50.0, 5.0));
+    assert!(!source.is_hovered());
+}
+
+#[test]
+fn disabling_mid_press_cancels_the_press() {
+    use crate::modifier_nodes::ClickableElement;
+    use cranpose_foundation::{ModifierNodeElement, PointerInputNode};
+
+    let _runtime = Runtime::new(Arc::new(DefaultScheduler));
+    let source = InteractionSource::new();
+    let interactions = recorded(&source);
+    let element = ClickableElement::new(|_| {}).with_interaction_source(source.clone());
+    let mut node = element.create();
+
+    let handler = node.pointer_input_handler().expect("clickable handler");
+    handler(event(PointerEventKind::Down, 10.0, 10.0));
+    assert!(source.is_pressed());
+
+    element.clone().with_enabled(false).update(&mut node);
+
+    assert!(!source.is_pressed());
+    assert_eq!(
+        interactions.borrow().last(),
+        Some(&Interaction::Cancel {
+            position: Point { x: 10.0, y: 10.0 }
+        })
+    );
+    let handler = node.pointer_input_handler().expect("clickable handler");
+    handler(event(PointerEventKind::Down, 10.0, 10.0));
+    assert!(!source.is_pressed());
+}
@@ -133,6 +133,42 @@ fn padding_node_respects_intrinsics() {
     assert_eq!(node.max_intrinsic_height(&measurable, 100.0), 50.0);
 }
 
+#[test]
+fn text_node_reports_word_and_line_intrinsic_widths() {
+    use crate::{measure_text, TextModifierNode};
+    let measurable = TestMeasurable {
+        intrinsic_width: 0.0,
+        intrinsic_height: 0.0,
+    };
+    let width_of = |text: &str| measure_text(text).width;
+
+    let node = TextModifierNode::new("fit the\nwidest word".to_string());
+    let proxy = node.create_measurement_proxy().expect("text proxy");
+    assert_eq!(
+        node.min_intrinsic_width(&measurable, 0.0),
+        width_of("widest")
+    );
+    assert_eq!(
+        node.max_intrinsic_width(&measurable, 0.0),
+        width_of("widest word")
+    );
+    assert_eq!(
+        proxy.min_intrinsic_width_proxy(&measurable, 0.0),
+        width_of("widest")
+    );
+    assert!(width_of("widest") < width_of("widest word"));
+
+    let unbroken = TextModifierNode::new("unbreakable".to_string());
+    assert_eq!(
+        unbroken.min_intrinsic_width(&measurable, 0.0),
+        unbroken.max_intrinsic_width(&measurable, 0.0)
+    );
+
+    let empty = TextModifierNode::new(String::new());
+    assert_eq!(empty.min_intrinsic_width(&measurable, 0.0), 0.0);
+    assert_eq!(empty.max_intrinsic_width(&measurable, 0.0), 0.0);
+}
+
 #[test]
 fn background_node_is_draw_only() {
     let mut chain = ModifierNodeChain::new();
@@ -192,7 +228,7 @@ fn modifier_chain_reuses_padding_nodes() {
     assert_eq!(initial_node, updated_node);
     {
         let node_ref = chain.node::<PaddingNode>(0).unwrap();
-        assert_eq!(node_ref.padding.left, 20.0);
+        assert_eq!(node_ref.padding().left, 20.0);
     }
 }
 
@@ -5,16 +5,19 @@ use crate::modifier::{Modifier, Size};
 use crate::subcompose_layout::{Constraints, SubcomposeLayoutNode};
 use crate::widgets::nodes::LayoutNode;
 use crate::widgets::{
-    BoxWithConstraints, Column, ColumnSpec, DynamicTextSource, Row, RowSpec, Spacer, Text,
+    BoxWithConstraints, ButtonOptions, ButtonWithOptions, Column, ColumnSpec, DynamicTextSource,
+    ErrorBoundary, Row, RowSpec, Spacer, Text, When,
 };
 use crate::{run_test_composition, LayoutEngine};
 use cranpose_core::{
-    self, location_key, Applier, Composer, Composition, ConcreteApplierHost, MemoryApplier, NodeId,
-    Phase, SlotBackend, SlotStorage, SlotsHost, SnapshotStateObserver, State,
+    self, location_key, Applier, Composer, Composition, ConcreteApplierHost, DefaultScheduler,
+    DisposableEffect, MemoryApplier, MutableState, NodeId, Phase, Runtime, SlotBackend,
+    SlotBackendKind, SlotStorage, SlotsHost, SnapshotStateObserver, State,
 };
 use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement, VerticalAlignment};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::sync::Arc;
 
 thread_local! {
     static COUNTER_ROW_INVOCATIONS: Cell<usize> = const { Cell::new(0) };
@@ -340,6 +343,204 @@ fn box_with_constraints_reacts_to_constraint_changes() {
     assert_eq!(invocations.get(), 2);
 }
 
+fn fail_composition() {
+    panic!("widget failed to compose");
+}
+
+#[composable]
+fn FailingWidget() {
+    Text("partial", Modifier::empty());
+    fail_composition();
+}
+
+fn column_children(composition: &mut Composition<MemoryApplier>) -> Vec<NodeId> {
+    let root = composition.root().expect("column root");
+    composition
+        .applier_mut()
+        .with_node::<LayoutNode, _>(root, |node| node.children.iter().copied().collect())
+        .expect("layout node available")
+}
+
+#[test]
+fn error_boundary_renders_fallback_and_keeps_siblings() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let content_runs = Rc::new(Cell::new(0));
+    let render = |composition: &mut Composition<MemoryApplier>| {
+        let ids = Rc::clone(&ids);
+        let content_runs = Rc::clone(&content_runs);
+        composition
+            .render(key, move || {
+                let ids = Rc::clone(&ids);
+                let content_runs = Rc::clone(&content_runs);
+                Column(Modifier::empty(), ColumnSpec::default(), move || {
+                    ids.borrow_mut().clear();
+                    ids.borrow_mut().push(Text("before", Modifier::empty()));
+                    ErrorBoundary(
+                        {
+                            let ids = Rc::clone(&ids);
+                            move || ids.borrow_mut().push(Text("fallback", Modifier::empty()))
+                        },
+                        {
+                            let content_runs = Rc::clone(&content_runs);
+                            move || {
+                                content_runs.set(content_runs.get() + 1);
+                                FailingWidget();
+                            }
+                        },
+                    );
+                    ids.borrow_mut().push(Text("after", Modifier::empty()));
+                });
+            })
+            .expect("render contains the panic");
+    };
+
+    render(&mut composition);
+    let first = ids.borrow().clone();
+    assert_eq!(first.len(), 3);
+    assert_eq!(column_children(&mut composition), first);
+    let node_count = composition.applier_mut().len();
+
+    render(&mut composition);
+    assert_eq!(content_runs.get(), 1, "failed content is not retried");
+    assert_eq!(column_children(&mut composition), first);
+    assert_eq!(*ids.borrow(), first);
+    assert_eq!(composition.applier_mut().len(), node_count);
+}
+
+#[test]
+fn failing_fallback_propagates_to_enclosing_boundary() {
+    let outer_fallback: Rc<Cell<Option<NodeId>>> = Rc::new(Cell::new(None));
+    let capture = Rc::clone(&outer_fallback);
+    let mut composition = run_test_composition(move || {
+        let capture = Rc::clone(&capture);
+        Column(Modifier::empty(), ColumnSpec::default(), move || {
+            let capture = Rc::clone(&capture);
+            ErrorBoundary(
+                move || capture.set(Some(Text("outer fallback", Modifier::empty()))),
+                || {
+                    ErrorBoundary(FailingWidget, FailingWidget);
+                },
+            );
+        });
+    });
+
+    let fallback = outer_fallback.get().expect("outer fallback composed");
+    assert_eq!(column_children(&mut composition), vec![fallback]);
+}
+
+#[test]
+fn disabled_button_ignores_clicks_and_is_skipped_by_focus() {
+    use crate::modifier::Point;
+    use cranpose_foundation::{PointerEvent, PointerEventKind};
+
+    let clicks = Rc::new(Cell::new(0));
+    let buttons: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let mut composition = run_test_composition({
+        let clicks = clicks.clone();
+        let buttons = buttons.clone();
+        move || {
+            let clicks = clicks.clone();
+            let buttons = buttons.clone();
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                for enabled in [true, false] {
+                    let clicks = clicks.clone();
+                    let id = ButtonWithOptions(
+                        Modifier::empty(),
+                        ButtonOptions { enabled },
+                        move || clicks.set(clicks.get() + 1),
+                        || {
+                            Text("Save", Modifier::empty());
+                        },
+                    );
+                    buttons.borrow_mut().push(id);
+                }
+            });
+        }
+    });
+    let (enabled, disabled) = (buttons.borrow()[0], buttons.borrow()[1]);
+
+    let root = composition.root().expect("root node");
+    let measurements = crate::layout::measure_layout(
+        &mut composition.applier_mut(),
+        root,
+        Size::new(200.0, 200.0),
+    )
+    .expect("measure layout");
+    let semantics = measurements.semantics_tree();
+    assert_eq!(semantics.focus_order(), vec![enabled]);
+    let disabled_semantics = semantics
+        .root()
+        .children
+        .iter()
+        .find(|node| node.node_id == disabled)
+        .expect("disabled button semantics");
+    assert!(!disabled_semantics.is_enabled);
+    assert!(disabled_semantics.actions.is_empty());
+
+    let slices = composition
+        .applier_mut()
+        .with_node::<LayoutNode, _>(disabled, |node| node.modifier_slices_snapshot())
+        .expect("disabled button node");
+    for handler in slices.pointer_inputs() {
+        let at = Point { x: 5.0, y: 5.0 };
+        handler(PointerEvent::new(PointerEventKind::Down, at, at));
+        handler(PointerEvent::new(PointerEventKind::Up, at, at));
+    }
+    assert_eq!(clicks.get(), 0);
+}
+
+#[test]
+fn when_disposes_content_once_per_hide() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let visible = MutableState::with_runtime(true, composition.runtime_handle());
+    let starts = Rc::new(Cell::new(0));
+    let cleanups = Rc::new(Cell::new(0));
+    let (starts_in, cleanups_in) = (Rc::clone(&starts), Rc::clone(&cleanups));
+    composition
+        .render(location_key(file!(), line!(), column!()), move || {
+            let (starts, cleanups) = (Rc::clone(&starts_in), Rc::clone(&cleanups_in));
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                Text("header", Modifier::empty());
+                let (starts, cleanups) = (Rc::clone(&starts), Rc::clone(&cleanups));
+                When(visible.value(), move || {
+                    let (starts, cleanups) = (Rc::clone(&starts), Rc::clone(&cleanups));
+                    DisposableEffect!((), move |scope| {
+                        starts.set(starts.get() + 1);
+                        scope.on_dispose(move || cleanups.set(cleanups.get() + 1))
+                    });
+                    Text("content", Modifier::empty());
+                });
+            });
+        })
+        .expect("initial render");
+    assert_eq!((starts.get(), cleanups.get()), (1, 0));
+    assert_eq!(column_children(&mut composition).len(), 2);
+    let node_count = composition.applier_mut().len();
+
+    for round in 1..=2 {
+        visible.set(false);
+        let _ = composition.process_invalid_scopes().expect("hide");
+        assert_eq!((starts.get(), cleanups.get()), (round, round));
+        assert_eq!(column_children(&mut composition).len(), 1);
+        assert_eq!(composition.applier_mut().len(), node_count - 1);
+
+        visible.set(true);
+        let _ = composition.process_invalid_scopes().expect("show");
+        assert_eq!((starts.get(), cleanups.get()), (round + 1, round));
+        assert_eq!(column_children(&mut composition).len(), 2);
+        assert_eq!(composition.applier_mut().len(), node_count);
+    }
+
+    // Toggling between passes is invisible to the content.
+    visible.set(false);
+    visible.set(true);
+    let _ = composition.process_invalid_scopes().expect("toggle");
+    assert_eq!((starts.get(), cleanups.get()), (3, 2));
+    assert_eq!(column_children(&mut composition).len(), 2);
+}
+
 #[test]
 fn test_fill_max_width_respects_parent_bounds() {
     let mut composition = Composition::new(MemoryApplier::new());
@@ -1304,3 +1505,728 @@ fn find_node_layout(tree: &LayoutBox, target: NodeId) -> Option<LayoutBox> {
     }
     None
 }
+
+#[test]
+fn text_picks_up_provided_text_style() {
+    use crate::widgets::{ProvideTextStyle, StyledText};
+    use cranpose_ui_graphics::{Color, TextStyle};
+
+    let red = Color(1.0, 0.0, 0.0, 1.0);
+    let blue = Color(0.0, 0.0, 1.0, 1.0);
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids = Rc::clone(&ids_render);
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let mut ids = ids.borrow_mut();
+                ids.clear();
+                let theme = TextStyle {
+                    font_size: Some(28.0),
+                    color: Some(red),
+                    ..Default::default()
+                };
+                ProvideTextStyle(theme, || {
+                    ids.push(Text("Themed", Modifier::empty()));
+                    let accent = TextStyle {
+                        color: Some(blue),
+                        ..Default::default()
+                    };
+                    ProvideTextStyle(accent, || ids.push(Text("Nested", Modifier::empty())));
+                    let small = TextStyle {
+                        font_size: Some(14.0),
+                        ..Default::default()
+                    };
+                    ids.push(StyledText("Small", Modifier::empty(), small));
+                });
+                ids.push(Text("Plain", Modifier::empty()));
+            });
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+    let styled = |index: usize| {
+        let id = ids.borrow()[index];
+        let layout = find_node_layout(layout_tree.root(), id).expect("text layout");
+        let style = layout
+            .node_data
+            .modifier_slices()
+            .text_style()
+            .cloned()
+            .expect("text style");
+        (style.font_size, style.color, layout.rect.width)
+    };
+
+    let base_width = crate::text::measure_text("Themed").width;
+    assert_eq!(styled(0), (Some(28.0), Some(red), base_width * 2.0));
+    assert_eq!(styled(1), (Some(28.0), Some(blue), base_width * 2.0));
+    assert_eq!((styled(2).0, styled(2).1), (Some(14.0), Some(red)));
+    assert_eq!(
+        styled(3),
+        (None, None, crate::text::measure_text("Plain").width)
+    );
+}
+
+#[test]
+fn row_aligns_texts_of_different_sizes_by_baseline() {
+    use crate::widgets::StyledText;
+    use cranpose_ui_graphics::TextStyle;
+    use cranpose_ui_layout::AlignmentLine;
+
+    let large = TextStyle {
+        font_size: Some(32.0),
+        ..Default::default()
+    };
+    let small = TextStyle {
+        font_size: Some(12.0),
+        ..Default::default()
+    };
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+    let (large_render, small_render) = (large.clone(), small.clone());
+
+    composition
+        .render(key, move || {
+            let ids = Rc::clone(&ids_render);
+            let (large, small) = (large_render.clone(), small_render.clone());
+            Row(
+                Modifier::empty(),
+                RowSpec::new()
+                    .vertical_alignment(VerticalAlignment::AlignBy(AlignmentLine::FirstBaseline)),
+                move || {
+                    let mut ids = ids.borrow_mut();
+                    ids.clear();
+                    ids.push(StyledText("Large", Modifier::empty(), large.clone()));
+                    ids.push(StyledText(
+                        "small",
+                        Modifier::empty().padding(4.0),
+                        small.clone(),
+                    ));
+                    // Has no baseline, so it stays at the top.
+                    ids.push(Spacer(Size {
+                        width: 10.0,
+                        height: 5.0,
+                    }));
+                },
+            );
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+    let top = |index: usize| {
+        let id = ids.borrow()[index];
+        find_node_layout(layout_tree.root(), id)
+            .expect("child layout")
+            .rect
+            .y
+    };
+    let first_baseline = |text: &str, style: &TextStyle| {
+        let metrics = crate::text::measure_styled_text(text, style);
+        crate::text::text_alignment_lines(&metrics)
+            .get(AlignmentLine::FirstBaseline)
+            .expect("text has a baseline")
+    };
+
+    let large_baseline = top(0) + first_baseline("Large", &large);
+    let small_baseline = top(1) + 4.0 + first_baseline("small", &small);
+    assert!(
+        (large_baseline - small_baseline).abs() < 0.01,
+        "baselines differ: {large_baseline} vs {small_baseline}"
+    );
+    assert!(
+        top(1) > top(0),
+        "the smaller text moves down to the baseline"
+    );
+    assert_eq!(top(2), layout_tree.root().rect.y);
+}
+
+#[test]
+fn dp_lengths_and_sp_text_resolve_with_local_density() {
+    use crate::widgets::{Box, BoxSpec};
+    use crate::{Density, Dp, LocalDensity};
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids = Rc::clone(&ids_render);
+            let density = Density::new(2.0).with_font_scale(1.5);
+            cranpose_core::CompositionLocalProvider([LocalDensity().provides(density)], || {
+                Column(Modifier::empty(), ColumnSpec::default(), move || {
+                    let inner_ids = Rc::clone(&ids);
+                    Box(
+                        Modifier::empty().padding(Dp(16.0)),
+                        BoxSpec::default(),
+                        move || {
+                            let inner = Box(
+                                Modifier::empty().size_points(Dp(10.0), Dp(5.0)),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                            inner_ids.borrow_mut().push(inner);
+                        },
+                    );
+                    ids.borrow_mut().push(Text("Scaled", Modifier::empty()));
+                });
+            });
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let inner = find_node_layout(layout_tree.root(), ids.borrow()[0]).expect("inner layout");
+    assert_eq!((inner.rect.x, inner.rect.y), (32.0, 32.0));
+    assert_eq!((inner.rect.width, inner.rect.height), (20.0, 10.0));
+
+    let text = find_node_layout(layout_tree.root(), ids.borrow()[1]).expect("text layout");
+    let style = text
+        .node_data
+        .modifier_slices()
+        .text_style()
+        .cloned()
+        .expect("text style");
+    assert_eq!(style.font_size, Some(42.0));
+}
+
+/// Leaf of a fixed preferred size that counts how often it is measured.
+#[derive(Clone, PartialEq)]
+struct CountingLeafPolicy {
+    size: Size,
+    measures: Rc<Cell<usize>>,
+}
+
+impl cranpose_ui_layout::MeasurePolicy for CountingLeafPolicy {
+    fn measure(
+        &self,
+        _measurables: &[Box<dyn cranpose_ui_layout::Measurable>],
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::MeasureResult {
+        self.measures.set(self.measures.get() + 1);
+        let (width, height) = constraints.constrain(self.size.width, self.size.height);
+        cranpose_ui_layout::MeasureResult::new(Size { width, height }, Vec::new())
+    }
+}
+
+/// Alternates children between two equal columns. Each child is probed
+/// first and measured again narrower if it does not fit its column.
+#[derive(Clone, PartialEq)]
+struct TwoColumnPolicy;
+
+impl cranpose_ui_layout::MeasurePolicy for TwoColumnPolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn cranpose_ui_layout::Measurable>],
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::MeasureResult {
+        let column_width = constraints.max_width / 2.0;
+        let probe = Constraints::loose(constraints.max_width, constraints.max_height);
+        let mut heights = [0.0_f32; 2];
+        let mut placements = Vec::new();
+        for (index, measurable) in measurables.iter().enumerate() {
+            let probed = measurable.measure(probe);
+            let placeable = if probed.width() > column_width {
+                measurable.measure(Constraints::loose(column_width, constraints.max_height))
+            } else {
+                measurable.measure(probe)
+            };
+            // Earlier placeables of the child report its latest measurement.
+            assert_eq!(probed.width(), placeable.width());
+            let column = index % 2;
+            let (x, y) = (column as f32 * column_width, heights[column]);
+            placeable.place(x, y);
+            placements.push(cranpose_ui_layout::Placement::new(
+                placeable.node_id(),
+                x,
+                y,
+                0,
+            ));
+            heights[column] += placeable.height();
+        }
+        let (width, height) =
+            constraints.constrain(constraints.max_width, heights[0].max(heights[1]));
+        cranpose_ui_layout::MeasureResult::new(Size { width, height }, placements)
+    }
+}
+
+#[test]
+fn custom_layout_measures_children_with_their_own_constraints() {
+    let sizes = [(30.0, 20.0), (70.0, 10.0), (20.0, 40.0)];
+    let counters: Vec<Rc<Cell<usize>>> = sizes.iter().map(|_| Rc::new(Cell::new(0))).collect();
+    let children = counters.clone();
+    let mut composition = run_test_composition(move || {
+        let children = children.clone();
+        crate::widgets::Layout(Modifier::empty(), TwoColumnPolicy, move || {
+            for (&(width, height), measures) in sizes.iter().zip(&children) {
+                crate::widgets::Layout(
+                    Modifier::empty(),
+                    CountingLeafPolicy {
+                        size: Size { width, height },
+                        measures: measures.clone(),
+                    },
+                    || {},
+                );
+            }
+        });
+    });
+    let root = composition.root().expect("layout root");
+
+    let layout = crate::layout::measure_layout(
+        &mut composition.applier_mut(),
+        root,
+        Size {
+            width: 100.0,
+            height: 100.0,
+        },
+    )
+    .expect("layout")
+    .into_layout_tree();
+
+    let rects: Vec<_> = layout
+        .root()
+        .children
+        .iter()
+        .map(|child| {
+            (
+                child.rect.x,
+                child.rect.y,
+                child.rect.width,
+                child.rect.height,
+            )
+        })
+        .collect();
+    assert_eq!(
+        rects,
+        vec![
+            (0.0, 0.0, 30.0, 20.0),
+            (50.0, 0.0, 50.0, 10.0),
+            (0.0, 20.0, 20.0, 40.0),
+        ]
+    );
+    assert_eq!(layout.root().rect.height, 60.0);
+    // A repeated probe is served from the cache; only the narrowed child
+    // is measured once more per pass.
+    let counts: Vec<usize> = counters.iter().map(|count| count.get()).collect();
+    assert_eq!(counts[0], counts[2]);
+    assert_eq!(counts[1], 2 * counts[0]);
+}
+
+const FIELD_TEXT: cranpose_ui_layout::AlignmentLine = cranpose_ui_layout::AlignmentLine::custom(
+    "FieldText",
+    cranpose_ui_layout::AlignmentLineMerge::Min,
+);
+
+/// Leaf of a fixed size that reports [`FIELD_TEXT`] at `line`.
+#[derive(Clone, PartialEq)]
+struct FieldLeafPolicy {
+    size: Size,
+    line: f32,
+}
+
+impl cranpose_ui_layout::MeasurePolicy for FieldLeafPolicy {
+    fn measure(
+        &self,
+        _measurables: &[Box<dyn cranpose_ui_layout::Measurable>],
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::MeasureResult {
+        let (width, height) = constraints.constrain(self.size.width, self.size.height);
+        cranpose_ui_layout::MeasureResult::new(Size { width, height }, Vec::new())
+            .with_alignment_lines(
+                cranpose_ui_layout::AlignmentLines::new().with(FIELD_TEXT, self.line),
+            )
+    }
+}
+
+#[test]
+fn row_aligns_children_by_a_custom_alignment_line() {
+    let field = |width: f32, height: f32, line: f32| {
+        crate::widgets::Layout(
+            Modifier::empty(),
+            FieldLeafPolicy {
+                size: Size { width, height },
+                line,
+            },
+            || {},
+        )
+    };
+    let mut composition = run_test_composition(move || {
+        Row(
+            Modifier::empty(),
+            RowSpec::new().vertical_alignment(VerticalAlignment::AlignBy(FIELD_TEXT)),
+            move || {
+                field(30.0, 40.0, 30.0);
+                // The column inherits the topmost line of its children.
+                Column(Modifier::empty(), ColumnSpec::default(), move || {
+                    field(20.0, 10.0, 4.0);
+                    field(20.0, 20.0, 5.0);
+                });
+            },
+        );
+    });
+    let root = composition.root().expect("row root");
+
+    let layout = crate::layout::measure_layout(
+        &mut composition.applier_mut(),
+        root,
+        Size {
+            width: 100.0,
+            height: 100.0,
+        },
+    )
+    .expect("layout")
+    .into_layout_tree();
+
+    let tops: Vec<f32> = layout
+        .root()
+        .children
+        .iter()
+        .map(|child| child.rect.y)
+        .collect();
+    assert_eq!(tops, vec![0.0, 26.0]);
+    // 30 above the line, plus the column's 26 below it.
+    assert_eq!(layout.root().rect.height, 56.0);
+}
+
+const SLOT_BACKENDS: [SlotBackendKind; 4] = [
+    SlotBackendKind::Baseline,
+    SlotBackendKind::Chunked,
+    SlotBackendKind::Hierarchical,
+    SlotBackendKind::Split,
+];
+
+fn composition_with_backend(kind: SlotBackendKind) -> Composition<MemoryApplier> {
+    Composition::with_backend(
+        MemoryApplier::new(),
+        Runtime::new(Arc::new(DefaultScheduler)),
+        kind,
+    )
+}
+
+#[test]
+fn state_read_recomposes_only_the_lambdas_that_read_it() {
+    for kind in SLOT_BACKENDS {
+        state_read_recomposes_only_the_lambdas_that_read_it_with(kind);
+    }
+}
+
+fn state_read_recomposes_only_the_lambdas_that_read_it_with(kind: SlotBackendKind) {
+    let mut composition = composition_with_backend(kind);
+    let count = MutableState::with_runtime(0, composition.runtime_handle());
+    let column_runs = Rc::new(Cell::new(0));
+    let row_runs: Rc<[Cell<usize>; 3]> = Rc::new(Default::default());
+    let (column_runs_in, row_runs_in) = (Rc::clone(&column_runs), Rc::clone(&row_runs));
+    composition
+        .render(location_key(file!(), line!(), column!()), move || {
+            let (column_runs, row_runs) = (Rc::clone(&column_runs_in), Rc::clone(&row_runs_in));
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                column_runs.set(column_runs.get() + 1);
+                for index in 0..3 {
+                    let row_runs = Rc::clone(&row_runs);
+                    Row(Modifier::empty(), RowSpec::default(), move || {
+                        let runs = &row_runs[index];
+                        runs.set(runs.get() + 1);
+                        // The first two siblings read the same state.
+                        let label = if index < 2 {
+                            format!("row {index}: {}", count.value())
+                        } else {
+                            format!("row {index}")
+                        };
+                        Text(label, Modifier::empty());
+                    });
+                }
+            });
+        })
+        .expect("initial render");
+    let children = column_children(&mut composition);
+    assert_eq!(children.len(), 3, "{kind:?}");
+
+    count.set(1);
+    let _ = composition.process_invalid_scopes().expect("recompose");
+
+    assert_eq!(
+        column_runs.get(),
+        1,
+        "{kind:?}: the column's content is not re-run"
+    );
+    let runs: Vec<usize> = row_runs.iter().map(Cell::get).collect();
+    assert_eq!(runs, vec![2, 2, 1], "{kind:?}");
+    assert_eq!(column_children(&mut composition), children, "{kind:?}");
+}
+
+#[composable]
+fn OptionalLabel(visible: State<bool>) {
+    if visible.value() {
+        Text("middle", Modifier::empty());
+    }
+}
+
+#[test]
+fn recomposed_lambdas_and_composables_keep_their_children_in_place() {
+    for kind in SLOT_BACKENDS {
+        recomposed_lambdas_and_composables_keep_their_children_in_place_with(kind);
+    }
+}
+
+fn recomposed_lambdas_and_composables_keep_their_children_in_place_with(kind: SlotBackendKind) {
+    let mut composition = composition_with_backend(kind);
+    let inline = MutableState::with_runtime(false, composition.runtime_handle());
+    let nested = MutableState::with_runtime(false, composition.runtime_handle());
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_in = Rc::clone(&ids);
+    composition
+        .render(location_key(file!(), line!(), column!()), move || {
+            let ids = Rc::clone(&ids_in);
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let mut ids = ids.borrow_mut();
+                ids.clear();
+                ids.push(Text("first", Modifier::empty()));
+                if inline.value() {
+                    ids.push(Text("inline", Modifier::empty()));
+                }
+                OptionalLabel(nested.as_state());
+                ids.push(Text("last", Modifier::empty()));
+            });
+        })
+        .expect("initial render");
+    assert_eq!(column_children(&mut composition), *ids.borrow(), "{kind:?}");
+    let node_count = composition.applier_mut().len();
+
+    inline.set(true);
+    let _ = composition.process_invalid_scopes().expect("show inline");
+    assert_eq!(ids.borrow().len(), 3, "{kind:?}");
+    assert_eq!(column_children(&mut composition), *ids.borrow(), "{kind:?}");
+
+    nested.set(true);
+    let _ = composition.process_invalid_scopes().expect("show nested");
+    let texts = ids.borrow().clone();
+    let children = column_children(&mut composition);
+    assert_eq!(children.len(), 4, "{kind:?}");
+    assert_eq!(children[..2], texts[..2], "{kind:?}");
+    assert_eq!(children[3], texts[2], "{kind:?}");
+    assert_eq!(composition.applier_mut().len(), node_count + 2, "{kind:?}");
+
+    inline.set(false);
+    nested.set(false);
+    let _ = composition.process_invalid_scopes().expect("hide both");
+    assert_eq!(ids.borrow().len(), 2, "{kind:?}");
+    assert_eq!(column_children(&mut composition), *ids.borrow(), "{kind:?}");
+    assert_eq!(composition.applier_mut().len(), node_count, "{kind:?}");
+}
+
+#[test]
+fn dp_modifier_resolves_with_the_density_of_the_node_measuring_it() {
+    use crate::widgets::{Box, BoxSpec};
+    use crate::{Density, Dp, LocalDensity};
+
+    // Built once, before any density is known.
+    let sized = Modifier::empty().size_points(Dp(10.0), Dp(5.0));
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids = Rc::clone(&ids_render);
+            let sized = sized.clone();
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                ids.borrow_mut()
+                    .push(Box(sized.clone(), BoxSpec::default(), || {}));
+                let ids = Rc::clone(&ids);
+                let sized = sized.clone();
+                cranpose_core::CompositionLocalProvider(
+                    [LocalDensity().provides(Density::new(3.0))],
+                    move || {
+                        ids.borrow_mut()
+                            .push(Box(sized.clone(), BoxSpec::default(), || {}));
+                    },
+                );
+            });
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let plain = find_node_layout(layout_tree.root(), ids.borrow()[0]).expect("plain layout");
+    assert_eq!((plain.rect.width, plain.rect.height), (10.0, 5.0));
+    let dense = find_node_layout(layout_tree.root(), ids.borrow()[1]).expect("dense layout");
+    assert_eq!((dense.rect.width, dense.rect.height), (30.0, 15.0));
+    assert_eq!(dense.rect.y, 5.0);
+}
+
+#[test]
+fn state_written_by_a_layout_measure_policy_is_committed_after_the_frame() {
+    use crate::widgets::Layout;
+    use cranpose_core::RuntimeHandle;
+    use cranpose_ui_layout::{Measurable, MeasurePolicy, MeasureResult};
+
+    #[derive(Clone)]
+    struct WritesWhileMeasuring {
+        written: MutableState<i32>,
+        runtime: RuntimeHandle,
+        observed: Rc<RefCell<Vec<(i32, bool)>>>,
+    }
+
+    impl PartialEq for WritesWhileMeasuring {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.observed, &other.observed)
+        }
+    }
+
+    impl MeasurePolicy for WritesWhileMeasuring {
+        fn measure(
+            &self,
+            _measurables: &[Box<dyn Measurable>],
+            _constraints: cranpose_ui_layout::Constraints,
+        ) -> MeasureResult {
+            self.written.set(7);
+            self.written.update(|value| *value += 1);
+            self.observed.borrow_mut().push((
+                self.written.get_non_reactive(),
+                self.runtime.has_invalid_scopes(),
+            ));
+            MeasureResult::new(Size::default(), Vec::new())
+        }
+    }
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    composition
+        .applier_mut()
+        .set_runtime_handle(runtime.clone());
+    let written = MutableState::with_runtime(0, runtime.clone());
+    let reads = Rc::new(Cell::new(0));
+    let observed = Rc::new(RefCell::new(Vec::new()));
+    let policy = WritesWhileMeasuring {
+        written,
+        runtime: runtime.clone(),
+        observed: Rc::clone(&observed),
+    };
+    let reads_in = Rc::clone(&reads);
+    composition
+        .render(location_key(file!(), line!(), column!()), move || {
+            let reads = Rc::clone(&reads_in);
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                reads.set(reads.get() + 1);
+                let _ = written.value();
+            });
+            Layout(Modifier::empty(), policy.clone(), || {});
+        })
+        .expect("render");
+    assert!(!runtime.has_invalid_scopes());
+
+    let root = composition.root().expect("root node");
+    composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 100.0,
+                height: 100.0,
+            },
+        )
+        .expect("compute layout");
+
+    // The pass keeps reading the old value and nothing is invalidated; the
+    // writes land together once the frame is over.
+    assert_eq!(observed.borrow().as_slice(), [(0, false)]);
+    assert!(!runtime.in_layout_phase());
+    assert_eq!(written.get_non_reactive(), 0);
+    assert!(!runtime.has_invalid_scopes());
+    assert!(composition.should_render());
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(written.get_non_reactive(), 8);
+    assert_eq!(reads.get(), 2);
+}
+
+#[test]
+fn recomposing_an_unchanged_tree_queues_no_node_updates() {
+    use crate::widgets::{Box, BoxSpec};
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let tick = MutableState::with_runtime(0, runtime.clone());
+    let wide = MutableState::with_runtime(false, runtime.clone());
+    let mut render = move || {
+        Column(Modifier::empty(), ColumnSpec::default(), move || {
+            let _ = tick.value();
+            let width = if wide.value() { 40.0 } else { 20.0 };
+            Row(Modifier::empty(), RowSpec::default(), || {
+                Text("static", Modifier::empty());
+                Spacer(Size {
+                    width: 4.0,
+                    height: 4.0,
+                });
+            });
+            Box(
+                Modifier::empty().size_points(width, 10.0),
+                BoxSpec::default(),
+                || {},
+            );
+        });
+    };
+    composition
+        .render(location_key(file!(), line!(), column!()), &mut render)
+        .expect("initial render");
+    let baseline = runtime.metrics().nodes_updated;
+
+    tick.set(1);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(runtime.metrics().nodes_updated, baseline);
+
+    wide.set(true);
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(
+        runtime.metrics().nodes_updated,
+        baseline + 1,
+        "only the box whose modifier changed is updated"
+    );
+}
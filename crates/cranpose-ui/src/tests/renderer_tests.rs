@@ -1,5 +1,5 @@
 use super::*;
-use crate::modifier::{Brush, Color, Modifier};
+use crate::modifier::{Brush, Color, CornerRadii, Modifier, RoundedCornerShape};
 use crate::primitives::{Column, ColumnSpec, SubcomposeLayout, Text};
 use crate::{
     layout::LayoutEngine, Composition, Placement, SubcomposeLayoutScope, SubcomposeMeasureScope,
@@ -90,6 +90,62 @@ fn renderer_honors_resolved_background_shape() {
     );
 }
 
+#[test]
+fn renderer_keeps_per_corner_background_radii() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            Text(
+                "Sheet".to_string(),
+                Modifier::empty()
+                    .size_points(100.0, 40.0)
+                    .background(Color(0.5, 0.2, 0.2, 1.0))
+                    .rounded_corner_shape(RoundedCornerShape::new(16.0, 8.0, 0.0, 0.0)),
+            );
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("text root");
+    let layout = compute_layout(&mut composition, root);
+    let scene = HeadlessRenderer::new().render(&layout);
+
+    match &scene.operations()[0] {
+        RenderOp::Primitive {
+            primitive: DrawPrimitive::RoundRect { radii, .. },
+            ..
+        } => assert_eq!(
+            *radii,
+            CornerRadii {
+                top_left: 16.0,
+                top_right: 8.0,
+                bottom_right: 0.0,
+                bottom_left: 0.0,
+            }
+        ),
+        other => panic!("unexpected op: {other:?}"),
+    }
+}
+
+#[test]
+fn oversized_corner_radii_scale_down_proportionally() {
+    let shape = RoundedCornerShape::new(60.0, 20.0, 0.0, 0.0);
+    // Top edge needs 80 but only 40 is available, so every radius is halved.
+    assert_eq!(
+        shape.resolve(40.0, 100.0),
+        CornerRadii {
+            top_left: 30.0,
+            top_right: 10.0,
+            bottom_right: 0.0,
+            bottom_left: 0.0,
+        }
+    );
+    assert_eq!(
+        RoundedCornerShape::uniform(30.0).resolve(100.0, 40.0),
+        CornerRadii::uniform(20.0)
+    );
+}
+
 #[test]
 fn renderer_translates_draw_commands() {
     let mut composition = Composition::new(MemoryApplier::new());
@@ -135,7 +191,7 @@ fn renderer_translates_draw_commands() {
                     saw_translated = true;
                 }
             }
-            DrawPrimitive::RoundRect { rect, .. } => {
+            DrawPrimitive::RoundRect { rect, .. } | DrawPrimitive::Stroke { rect, .. } => {
                 if rect.x >= 10.0 && rect.y >= 10.0 {
                     saw_translated = true;
                 }
@@ -163,7 +219,9 @@ fn renderer_translates_draw_commands() {
     assert_eq!(overlay_ops.len(), 1);
     if let RenderOp::Primitive { primitive, .. } = overlay_ops[0] {
         match primitive {
-            DrawPrimitive::Rect { rect, .. } | DrawPrimitive::RoundRect { rect, .. } => {
+            DrawPrimitive::Rect { rect, .. }
+            | DrawPrimitive::RoundRect { rect, .. }
+            | DrawPrimitive::Stroke { rect, .. } => {
                 assert!(rect.x >= 10.0);
                 assert!(rect.y >= 10.0);
             }
@@ -209,3 +267,182 @@ fn renderer_renders_subcompose_background() {
         .iter()
         .any(|op| matches!(op, RenderOp::Text { value, .. } if value == "Subcomposed")));
 }
+
+#[test]
+fn focus_border_follows_active_focus_target() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            Column(Modifier::empty(), ColumnSpec::default(), || {
+                Text(
+                    "First".to_string(),
+                    Modifier::empty().focus_border(2.0, Color(0.0, 0.5, 1.0, 1.0)),
+                );
+                Text(
+                    "Second".to_string(),
+                    Modifier::empty().focus_border(2.0, Color(0.0, 0.5, 1.0, 1.0)),
+                );
+            });
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("column root");
+    let layout = compute_layout(&mut composition, root);
+    let first = &layout.root().children[0];
+    let second = &layout.root().children[1];
+    let renderer = HeadlessRenderer::new();
+    let rings = |layout: &LayoutTree| -> Vec<Rect> {
+        renderer
+            .render(layout)
+            .operations()
+            .iter()
+            .filter_map(|op| match op {
+                RenderOp::Primitive {
+                    primitive: DrawPrimitive::Stroke { rect, width, .. },
+                    ..
+                } => {
+                    assert_eq!(*width, 2.0);
+                    Some(*rect)
+                }
+                _ => None,
+            })
+            .collect()
+    };
+
+    crate::set_active_focus_target(None);
+    assert!(rings(&layout).is_empty());
+
+    crate::set_active_focus_target(Some(first.node_id));
+    assert_eq!(rings(&layout), vec![first.rect]);
+
+    crate::set_active_focus_target(Some(second.node_id));
+    assert_eq!(rings(&layout), vec![second.rect]);
+
+    crate::set_active_focus_target(None);
+    assert!(rings(&layout).is_empty());
+}
+
+#[test]
+fn compute_layout_counts_layout_passes() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            Text("Hello".to_string(), Modifier::empty());
+        })
+        .expect("initial render");
+    let root = composition.root().expect("text root");
+    let runtime = composition.runtime_handle();
+    runtime.reset_metrics();
+
+    compute_layout(&mut composition, root);
+    compute_layout(&mut composition, root);
+
+    assert_eq!(runtime.metrics().layout_passes, 2);
+}
+
+fn background_rect(modifier: Modifier) -> Rect {
+    use crate::widgets::{Box, BoxSpec};
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || {
+            Box(modifier.clone(), BoxSpec::default(), || {});
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("box root");
+    let layout = compute_layout(&mut composition, root);
+    let scene = HeadlessRenderer::new().render(&layout);
+    let rect = match scene.primitives_for(PaintLayer::Behind).next() {
+        Some(DrawPrimitive::Rect { rect, .. }) => *rect,
+        other => panic!("expected a background rect, got {other:?}"),
+    };
+    rect
+}
+
+#[test]
+fn background_respects_its_position_relative_to_padding() {
+    let color = Color(0.2, 0.4, 0.6, 1.0);
+    let size = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+
+    let inside = background_rect(Modifier::empty().size(size).padding(10.0).background(color));
+    assert_eq!(
+        inside,
+        Rect {
+            x: 10.0,
+            y: 10.0,
+            width: 80.0,
+            height: 80.0,
+        }
+    );
+
+    let outside = background_rect(Modifier::empty().size(size).background(color).padding(10.0));
+    assert_eq!(outside, Rect::from_size(size));
+
+    let between = background_rect(
+        Modifier::empty()
+            .size(size)
+            .padding(5.0)
+            .padding_horizontal(3.0)
+            .background(color)
+            .padding(10.0),
+    );
+    assert_eq!(
+        between,
+        Rect {
+            x: 8.0,
+            y: 5.0,
+            width: 84.0,
+            height: 90.0,
+        }
+    );
+}
+
+#[test]
+fn focus_border_draws_inside_padding_declared_before_it() {
+    use crate::widgets::{Box, BoxSpec};
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            Box(
+                Modifier::empty()
+                    .size(Size {
+                        width: 100.0,
+                        height: 100.0,
+                    })
+                    .padding(10.0)
+                    .focus_border(2.0, Color(1.0, 0.0, 0.0, 1.0)),
+                BoxSpec::default(),
+                || {},
+            );
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("box root");
+    crate::set_active_focus_target(Some(root));
+    let layout = compute_layout(&mut composition, root);
+    let scene = HeadlessRenderer::new().render(&layout);
+    crate::set_active_focus_target(None);
+
+    let rect = match scene.primitives_for(PaintLayer::Overlay).next() {
+        Some(DrawPrimitive::Stroke { rect, .. }) => *rect,
+        other => panic!("expected a focus border stroke, got {other:?}"),
+    };
+    assert_eq!(
+        rect,
+        Rect {
+            x: 10.0,
+            y: 10.0,
+            width: 80.0,
+            height: 80.0,
+        }
+    );
+}
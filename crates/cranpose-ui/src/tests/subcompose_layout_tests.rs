@@ -256,3 +256,246 @@ fn inactive_slots_move_to_reusable_pool() {
         assert!(!typed.state().reusable().is_empty());
     }
 }
+
+#[test]
+fn state_written_during_measure_is_committed_after_the_frame() {
+    let (handle, mut composition) = runtime_handle();
+    let written = MutableState::with_runtime(0, handle.clone());
+    composition
+        .render(
+            cranpose_core::location_key(file!(), line!(), column!()),
+            move || {
+                let _ = written.value();
+            },
+        )
+        .expect("render");
+    assert!(!handle.has_invalid_scopes());
+
+    let mut slots = SlotBackend::default();
+    let mut applier = cranpose_core::MemoryApplier::new();
+    let observed = Rc::new(RefCell::new(Vec::new()));
+    let policy: Rc<MeasurePolicy> = Rc::new({
+        let observed = Rc::clone(&observed);
+        let handle = handle.clone();
+        move |scope, _constraints| {
+            written.set(7);
+            written.update(|value| *value += 1);
+            observed
+                .borrow_mut()
+                .push((written.get_non_reactive(), handle.has_invalid_scopes()));
+            scope.layout(0.0, 0.0, Vec::new())
+        }
+    });
+    let node_id = applier.create(Box::new(SubcomposeLayoutNode::new(
+        crate::modifier::Modifier::empty(),
+        Rc::clone(&policy),
+    )));
+    measure_once(
+        &mut slots,
+        &mut applier,
+        &handle,
+        node_id,
+        Constraints::tight(0.0, 0.0),
+    );
+
+    // The pass keeps reading the old value and nothing is invalidated; the
+    // writes land together once the frame is over.
+    assert_eq!(observed.borrow().as_slice(), [(0, false)]);
+    assert_eq!(written.get_non_reactive(), 0);
+    assert!(!handle.has_invalid_scopes());
+    assert!(composition.should_render());
+    assert!(composition.process_invalid_scopes().expect("recompose"));
+    assert_eq!(written.get_non_reactive(), 8);
+    assert!(!handle.has_updates());
+}
+
+fn precompose_once(
+    slots: &mut SlotBackend,
+    applier: &mut cranpose_core::MemoryApplier,
+    handle: &cranpose_core::RuntimeHandle,
+    node_id: cranpose_core::NodeId,
+    slot_id: SlotId,
+) -> Vec<cranpose_core::NodeId> {
+    let (composer, slots_host, applier_host) =
+        setup_composer(slots, applier, handle.clone(), Some(node_id));
+    let node_handle = {
+        let mut applier_ref = applier_host.borrow_typed();
+        let node = applier_ref.get_mut(node_id).expect("node available");
+        let typed = node
+            .as_any_mut()
+            .downcast_mut::<SubcomposeLayoutNode>()
+            .expect("subcompose layout node");
+        typed.set_precompose_host(&composer);
+        typed.handle()
+    };
+    drop(composer);
+    let children = node_handle
+        .precompose(node_id, slot_id, emit_child)
+        .expect("precompose succeeds");
+    teardown_composer(slots, applier, slots_host, applier_host);
+    children
+}
+
+fn cancel_precompose_once(
+    slots: &mut SlotBackend,
+    applier: &mut cranpose_core::MemoryApplier,
+    handle: &cranpose_core::RuntimeHandle,
+    node_id: cranpose_core::NodeId,
+    slot_id: SlotId,
+) {
+    let (composer, slots_host, applier_host) =
+        setup_composer(slots, applier, handle.clone(), Some(node_id));
+    let node_handle = {
+        let mut applier_ref = applier_host.borrow_typed();
+        let node = applier_ref.get_mut(node_id).expect("node available");
+        let typed = node
+            .as_any_mut()
+            .downcast_mut::<SubcomposeLayoutNode>()
+            .expect("subcompose layout node");
+        typed.set_precompose_host(&composer);
+        typed.handle()
+    };
+    drop(composer);
+    node_handle
+        .cancel_precompose(node_id, slot_id)
+        .expect("cancel succeeds");
+    teardown_composer(slots, applier, slots_host, applier_host);
+}
+
+/// Leaf that tracks its parent, so disposing its slot removes it too.
+#[derive(Default)]
+struct ChildNode {
+    parent: Option<cranpose_core::NodeId>,
+}
+
+impl cranpose_core::Node for ChildNode {
+    fn on_attached_to_parent(&mut self, parent: cranpose_core::NodeId) {
+        self.parent = Some(parent);
+    }
+
+    fn on_removed_from_parent(&mut self) {
+        self.parent = None;
+    }
+
+    fn parent(&self) -> Option<cranpose_core::NodeId> {
+        self.parent
+    }
+}
+
+fn emit_child() {
+    cranpose_core::with_current_composer(|composer| {
+        composer.emit_node(ChildNode::default);
+    });
+}
+
+fn subcompose_slot_policy(
+    slot_id: SlotId,
+    recorded: Rc<RefCell<Vec<cranpose_core::NodeId>>>,
+) -> Rc<MeasurePolicy> {
+    Rc::new(move |scope, _constraints| {
+        let measurables = scope.subcompose(slot_id, emit_child);
+        recorded
+            .borrow_mut()
+            .extend(measurables.iter().map(|child| child.node_id()));
+        scope.layout(0.0, 0.0, Vec::new())
+    })
+}
+
+#[test]
+fn precomposed_slot_is_reused_by_the_measure_that_places_it() {
+    let (handle, _composition) = runtime_handle();
+    let mut slots = SlotBackend::default();
+    let mut applier = cranpose_core::MemoryApplier::new();
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+    let node_id = applier.create(Box::new(SubcomposeLayoutNode::new(
+        crate::modifier::Modifier::empty(),
+        subcompose_slot_policy(SlotId::new(1), Rc::clone(&recorded)),
+    )));
+
+    let precomposed = precompose_once(&mut slots, &mut applier, &handle, node_id, SlotId::new(1));
+    assert_eq!(precomposed.len(), 1);
+    // The content exists before any measure pass has placed it.
+    assert!(applier
+        .get_mut(precomposed[0])
+        .expect("precomposed node exists")
+        .as_any_mut()
+        .is::<ChildNode>());
+
+    measure_once(
+        &mut slots,
+        &mut applier,
+        &handle,
+        node_id,
+        Constraints::tight(0.0, 0.0),
+    );
+
+    assert_eq!(recorded.borrow().as_slice(), precomposed.as_slice());
+    let node = applier.get_mut(node_id).expect("node available");
+    let typed = node
+        .as_any_mut()
+        .downcast_mut::<SubcomposeLayoutNode>()
+        .expect("subcompose layout node");
+    assert!(typed.state().precomposed().is_empty());
+    assert_eq!(typed.state().active_slots_count(), 1);
+}
+
+#[test]
+fn precomposed_slot_is_kept_across_passes_until_cancelled() {
+    let (handle, _composition) = runtime_handle();
+    let mut slots = SlotBackend::default();
+    let mut applier = cranpose_core::MemoryApplier::new();
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+    let node_id = applier.create(Box::new(SubcomposeLayoutNode::new(
+        crate::modifier::Modifier::empty(),
+        subcompose_slot_policy(SlotId::new(1), Rc::clone(&recorded)),
+    )));
+
+    let precomposed = precompose_once(&mut slots, &mut applier, &handle, node_id, SlotId::new(2));
+    for _ in 0..2 {
+        measure_once(
+            &mut slots,
+            &mut applier,
+            &handle,
+            node_id,
+            Constraints::tight(0.0, 0.0),
+        );
+    }
+
+    // Passes that do not place the slot leave it composed.
+    assert!(!recorded.borrow().contains(&precomposed[0]));
+    assert!(applier.get_mut(precomposed[0]).is_ok());
+    {
+        let node = applier.get_mut(node_id).expect("node available");
+        let typed = node
+            .as_any_mut()
+            .downcast_mut::<SubcomposeLayoutNode>()
+            .expect("subcompose layout node");
+        assert!(typed.state().precomposed().contains_key(&SlotId::new(2)));
+        assert_eq!(typed.state().active_slots_count(), 1);
+    }
+
+    cancel_precompose_once(&mut slots, &mut applier, &handle, node_id, SlotId::new(2));
+
+    assert!(applier.get_mut(precomposed[0]).is_err());
+    let node = applier.get_mut(node_id).expect("node available");
+    let typed = node
+        .as_any_mut()
+        .downcast_mut::<SubcomposeLayoutNode>()
+        .expect("subcompose layout node");
+    assert!(typed.state().precomposed().is_empty());
+}
+
+#[test]
+fn precompose_without_a_composer_host_reports_missing_context() {
+    let node = SubcomposeLayoutNode::new(
+        crate::modifier::Modifier::empty(),
+        subcompose_slot_policy(SlotId::new(1), Rc::default()),
+    );
+
+    let result = node.handle().precompose(7, SlotId::new(1), emit_child);
+
+    assert!(matches!(
+        result,
+        Err(NodeError::MissingContext { id: 7, .. })
+    ));
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn narrower_width_wraps_into_taller_metrics() {
+    let measurer = MonospacedTextMeasurer;
+    let text = "hello wide world";
+
+    let wide = measurer.measure(text, 400.0);
+    let narrow = measurer.measure(text, 80.0);
+
+    assert_eq!(wide.line_count, 1);
+    assert_eq!(wide.height, MonospacedTextMeasurer::LINE_HEIGHT);
+    assert_eq!(narrow.line_count, 2);
+    assert_eq!(narrow.height, 2.0 * MonospacedTextMeasurer::LINE_HEIGHT);
+    assert!(narrow.width <= 80.0);
+    assert_ne!(text_width_bucket(400.0), text_width_bucket(80.0));
+}
+
+#[test]
+fn unbounded_width_has_its_own_bucket() {
+    assert_eq!(text_width_bucket(f32::MAX), u32::MAX);
+    assert_eq!(text_width_bucket(f32::INFINITY), u32::MAX);
+    assert_ne!(text_width_bucket(1.0e12), u32::MAX);
+    assert_eq!(text_width_bucket(79.2), 80);
+
+    let measurer = MonospacedTextMeasurer;
+    let text = "a b c d e f";
+    assert_eq!(measurer.measure(text, f32::MAX).line_count, 1);
+    assert_eq!(
+        measurer.measure(text, f32::MAX),
+        measurer.measure(text, f32::INFINITY)
+    );
+}
+
+#[test]
+fn wrapping_keeps_explicit_newlines_and_oversized_words() {
+    let measurer = MonospacedTextMeasurer;
+
+    let metrics = measurer.measure("ab cd\nextraordinary", 40.0);
+    assert_eq!(metrics.line_count, 2);
+    assert_eq!(metrics.width, 13.0 * MonospacedTextMeasurer::CHAR_WIDTH);
+
+    let empty = measurer.measure("", 10.0);
+    assert_eq!(empty.line_count, 1);
+    assert_eq!(empty.width, 0.0);
+}
@@ -1,6 +1,9 @@
+use std::ops::Range;
 use std::sync::{OnceLock, RwLock};
 
 use crate::text_layout_result::TextLayoutResult;
+use cranpose_ui_graphics::TextStyle;
+use cranpose_ui_layout::{AlignmentLine, AlignmentLines};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct TextMetrics {
@@ -13,7 +16,11 @@ pub struct TextMetrics {
 }
 
 pub trait TextMeasurer: Send + Sync + 'static {
-    fn measure(&self, text: &str) -> TextMetrics;
+    /// Measures `text` wrapped to `max_width`.
+    ///
+    /// Pass `f32::INFINITY` (or `f32::MAX`) for unbounded, single-line-per-paragraph
+    /// metrics. Implementations that cache must key on [`text_width_bucket`].
+    fn measure(&self, text: &str, max_width: f32) -> TextMetrics;
 
     /// Returns byte offset in text for given x position.
     /// Used for cursor positioning on click.
@@ -28,6 +35,14 @@ pub trait TextMeasurer: Send + Sync + 'static {
     /// Computes full text layout with cached glyph positions.
     /// Returns TextLayoutResult for O(1) position lookups.
     fn layout(&self, text: &str) -> TextLayoutResult;
+
+    /// Distance from the top of a line `line_height` tall to its baseline.
+    ///
+    /// Rows aligning texts by baseline use it. Defaults to 80% of the line
+    /// height, close to where most fonts sit.
+    fn baseline(&self, line_height: f32) -> f32 {
+        line_height * 0.8
+    }
 }
 
 #[derive(Default)]
@@ -39,23 +54,10 @@ impl MonospacedTextMeasurer {
 }
 
 impl TextMeasurer for MonospacedTextMeasurer {
-    fn measure(&self, text: &str) -> TextMetrics {
-        // Split by newlines to handle multiline
-        let lines: Vec<&str> = text.split('\n').collect();
-        let line_count = lines.len().max(1);
-
-        // Width is the max width of any line
-        let width = lines
-            .iter()
-            .map(|line| line.chars().count() as f32 * Self::CHAR_WIDTH)
-            .fold(0.0_f32, f32::max);
-
-        TextMetrics {
-            width,
-            height: line_count as f32 * Self::LINE_HEIGHT,
-            line_height: Self::LINE_HEIGHT,
-            line_count,
-        }
+    fn measure(&self, text: &str, max_width: f32) -> TextMetrics {
+        measure_wrapped(text, max_width, Self::LINE_HEIGHT, |line| {
+            text[line].chars().count() as f32 * Self::CHAR_WIDTH
+        })
     }
 
     fn get_offset_for_position(&self, text: &str, x: f32, y: f32) -> usize {
@@ -102,6 +104,78 @@ impl TextMeasurer for MonospacedTextMeasurer {
     }
 }
 
+/// Returns `true` when `max_width` places no limit on line length.
+pub fn is_unbounded_width(max_width: f32) -> bool {
+    !max_width.is_finite() || max_width >= f32::MAX
+}
+
+/// Cache bucket for a wrapping width: whole logical pixels, with every unbounded
+/// width sharing one bucket distinct from any finite width.
+pub fn text_width_bucket(max_width: f32) -> u32 {
+    if is_unbounded_width(max_width) {
+        u32::MAX
+    } else {
+        (max_width.max(0.0).ceil() as u32).min(u32::MAX - 1)
+    }
+}
+
+/// Measures `text` with greedy word wrapping at `max_width`.
+///
+/// Explicit newlines always break. A word wider than `max_width` keeps a line to
+/// itself rather than being split. `line_width` measures the single unwrapped
+/// line `text[range]`; a range never crosses a newline.
+pub fn measure_wrapped(
+    text: &str,
+    max_width: f32,
+    line_height: f32,
+    mut line_width: impl FnMut(Range<usize>) -> f32,
+) -> TextMetrics {
+    let unbounded = is_unbounded_width(max_width);
+    let mut width = 0.0_f32;
+    let mut line_count = 0;
+    let mut paragraph_start = 0;
+    for paragraph in text.split('\n') {
+        let offset = paragraph_start;
+        paragraph_start += paragraph.len() + 1;
+        let paragraph_width = line_width(offset..offset + paragraph.len());
+        if unbounded || paragraph_width <= max_width {
+            width = width.max(paragraph_width);
+            line_count += 1;
+            continue;
+        }
+        let mut line_start = 0;
+        let mut line_end = 0;
+        for (word_start, word) in word_spans(paragraph) {
+            let word_end = word_start + word.len();
+            if line_end > line_start
+                && line_width(offset + line_start..offset + word_end) > max_width
+            {
+                width = width.max(line_width(offset + line_start..offset + line_end));
+                line_count += 1;
+                line_start = word_start;
+            }
+            line_end = word_end;
+        }
+        width = width.max(line_width(offset + line_start..offset + line_end));
+        line_count += 1;
+    }
+    let line_count = line_count.max(1);
+    TextMetrics {
+        width,
+        height: line_count as f32 * line_height,
+        line_height,
+        line_count,
+    }
+}
+
+/// Non-whitespace runs of `paragraph` with their byte offsets.
+fn word_spans(paragraph: &str) -> impl Iterator<Item = (usize, &str)> {
+    paragraph
+        .split(char::is_whitespace)
+        .filter(|word| !word.is_empty())
+        .map(move |word| (word.as_ptr() as usize - paragraph.as_ptr() as usize, word))
+}
+
 fn global_text_measurer() -> &'static RwLock<Box<dyn TextMeasurer>> {
     static TEXT_MEASURER: OnceLock<RwLock<Box<dyn TextMeasurer>>> = OnceLock::new();
     TEXT_MEASURER.get_or_init(|| RwLock::new(Box::new(MonospacedTextMeasurer)))
@@ -114,11 +188,56 @@ pub fn set_text_measurer<M: TextMeasurer>(measurer: M) {
     *guard = Box::new(measurer);
 }
 
+/// Measures `text` without a width limit.
 pub fn measure_text(text: &str) -> TextMetrics {
+    measure_text_with_max_width(text, f32::INFINITY)
+}
+
+/// Measures `text` rendered with `style`; metrics scale with its font size.
+pub fn measure_styled_text(text: &str, style: &TextStyle) -> TextMetrics {
+    let metrics = measure_text(text);
+    let scale = style.font_scale();
+    TextMetrics {
+        width: metrics.width * scale,
+        height: metrics.height * scale,
+        line_height: metrics.line_height * scale,
+        line_count: metrics.line_count,
+    }
+}
+
+/// First and last baselines of text laid out with `metrics`, from its top.
+pub fn text_alignment_lines(metrics: &TextMetrics) -> AlignmentLines {
+    let first_baseline = global_text_measurer()
+        .read()
+        .expect("text measurer lock poisoned")
+        .baseline(metrics.line_height);
+    let last_line = metrics.line_count.saturating_sub(1) as f32;
+    AlignmentLines::new()
+        .with(AlignmentLine::FirstBaseline, first_baseline)
+        .with(
+            AlignmentLine::LastBaseline,
+            first_baseline + last_line * metrics.line_height,
+        )
+}
+
+/// Measures `text` wrapped at word boundaries to fit `max_width`.
+pub fn measure_text_with_max_width(text: &str, max_width: f32) -> TextMetrics {
     global_text_measurer()
         .read()
         .expect("text measurer lock poisoned")
-        .measure(text)
+        .measure(text, max_width)
+}
+
+/// Width of the widest word in `text`, the narrowest it can wrap to without
+/// splitting a word. Text without any word measures zero.
+pub fn measure_longest_word_width(text: &str) -> f32 {
+    let measurer = global_text_measurer()
+        .read()
+        .expect("text measurer lock poisoned");
+    text.split(char::is_whitespace)
+        .filter(|word| !word.is_empty())
+        .map(|word| measurer.measure(word, f32::INFINITY).width)
+        .fold(0.0, f32::max)
 }
 
 /// Returns byte offset in text for given x position.
@@ -147,3 +266,7 @@ pub fn layout_text(text: &str) -> TextLayoutResult {
         .expect("text measurer lock poisoned")
         .layout(text)
 }
+
+#[cfg(test)]
+#[path = "tests/text_tests.rs"]
+mod tests;
@@ -14,6 +14,7 @@ use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 
 use crate::key_event::KeyEvent;
+use crate::software_keyboard::SoftwareKeyboardController;
 
 /// Handler trait for focused text field operations.
 /// Stored in focus module for O(1) key/clipboard dispatch.
@@ -40,6 +41,8 @@ thread_local! {
     static FOCUSED_FIELD: RefCell<Option<Weak<RefCell<bool>>>> = const { RefCell::new(None) };
     // O(1) handler for key dispatch - avoids tree scan
     static FOCUSED_HANDLER: RefCell<Option<Rc<dyn FocusedTextFieldHandler>>> = const { RefCell::new(None) };
+    // Keyboard shown for the focused field, hidden again when focus is lost
+    static FOCUSED_KEYBOARD: RefCell<Option<Rc<dyn SoftwareKeyboardController>>> = const { RefCell::new(None) };
 }
 
 /// Requests focus for a text field.
@@ -68,6 +71,11 @@ pub fn request_focus(is_focused: Rc<RefCell<bool>>, handler: Rc<dyn FocusedTextF
         *h.borrow_mut() = Some(handler);
     });
 
+    // The newly focused field shows its own keyboard, if any
+    FOCUSED_KEYBOARD.with(|k| {
+        k.borrow_mut().take();
+    });
+
     // Start cursor blink animation (timer-based, not continuous redraw)
     crate::cursor_animation::start_cursor_blink();
 
@@ -96,12 +104,29 @@ pub fn clear_focus() {
         *h.borrow_mut() = None;
     });
 
+    hide_focused_keyboard();
+
     // Stop cursor blink animation
     crate::cursor_animation::stop_cursor_blink();
 
     crate::request_render_invalidation();
 }
 
+/// Shows `keyboard` for the field that just requested focus. It is hidden
+/// again when that field loses focus.
+pub fn show_keyboard_for_focused_field(keyboard: Rc<dyn SoftwareKeyboardController>) {
+    keyboard.show();
+    FOCUSED_KEYBOARD.with(|k| {
+        *k.borrow_mut() = Some(keyboard);
+    });
+}
+
+fn hide_focused_keyboard() {
+    if let Some(keyboard) = FOCUSED_KEYBOARD.with(|k| k.borrow_mut().take()) {
+        keyboard.hide();
+    }
+}
+
 /// Returns true if any text field currently has focus.
 /// Checks weak ref liveness and clears stale focus state.
 pub fn has_focused_field() -> bool {
@@ -117,6 +142,7 @@ pub fn has_focused_field() -> bool {
             FOCUSED_HANDLER.with(|h| {
                 *h.borrow_mut() = None;
             });
+            hide_focused_keyboard();
             // Also stop cursor blink since focus is lost
             crate::cursor_animation::stop_cursor_blink();
         }
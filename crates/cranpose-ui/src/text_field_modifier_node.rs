@@ -28,6 +28,8 @@ use std::cell::{Cell, RefCell};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+use crate::software_keyboard::SoftwareKeyboardController;
+
 /// Default cursor color (white - visible on dark backgrounds)
 const DEFAULT_CURSOR_COLOR: Color = Color(1.0, 1.0, 1.0, 1.0);
 
@@ -64,6 +66,8 @@ pub(crate) struct TextFieldRefs {
     pub click_count: Rc<Cell<u8>>,
     /// Node ID for scoped layout invalidation
     pub node_id: Rc<Cell<Option<cranpose_core::NodeId>>>,
+    /// On-screen keyboard shown when the field gains focus
+    pub keyboard: Rc<RefCell<Option<Rc<dyn SoftwareKeyboardController>>>>,
 }
 
 impl TextFieldRefs {
@@ -77,6 +81,7 @@ impl TextFieldRefs {
             last_click_time: Rc::new(Cell::new(None::<web_time::Instant>)),
             click_count: Rc::new(Cell::new(0_u8)),
             node_id: Rc::new(Cell::new(None::<cranpose_core::NodeId>)),
+            keyboard: Rc::new(RefCell::new(None)),
         }
     }
 }
@@ -153,6 +158,15 @@ impl TextFieldModifierNode {
         self
     }
 
+    /// Sets the on-screen keyboard shown when the field gains focus.
+    pub fn with_keyboard_controller(
+        self,
+        keyboard: Option<Rc<dyn SoftwareKeyboardController>>,
+    ) -> Self {
+        *self.refs.keyboard.borrow_mut() = keyboard;
+        self
+    }
+
     /// Returns the current line limits configuration.
     pub fn line_limits(&self) -> TextFieldLineLimits {
         self.line_limits
@@ -178,6 +192,13 @@ impl TextFieldModifierNode {
                     let handler =
                         TextFieldHandler::new(state.clone(), refs.node_id.get(), line_limits);
                     crate::text_field_focus::request_focus(refs.is_focused.clone(), handler);
+                    if let Some(keyboard) = refs.keyboard.borrow().clone() {
+                        crate::text_field_focus::show_keyboard_for_focused_field(keyboard);
+                    }
+                    // Reveal the field if it is partly scrolled out of sight
+                    if let Some(node_id) = refs.node_id.get() {
+                        crate::modifier::bring_into_view(node_id);
+                    }
 
                     let now = web_time::Instant::now();
                     let text = state.text();
@@ -722,6 +743,8 @@ pub struct TextFieldElement {
     cursor_color: Color,
     /// Line limits configuration
     line_limits: TextFieldLineLimits,
+    /// On-screen keyboard shown on focus
+    keyboard: Option<Rc<dyn SoftwareKeyboardController>>,
 }
 
 impl TextFieldElement {
@@ -731,6 +754,7 @@ impl TextFieldElement {
             state,
             cursor_color: DEFAULT_CURSOR_COLOR,
             line_limits: TextFieldLineLimits::default(),
+            keyboard: None,
         }
     }
 
@@ -745,6 +769,15 @@ impl TextFieldElement {
         self.line_limits = line_limits;
         self
     }
+
+    /// Creates an element that shows `keyboard` when the field gains focus.
+    pub fn with_keyboard_controller(
+        mut self,
+        keyboard: Option<Rc<dyn SoftwareKeyboardController>>,
+    ) -> Self {
+        self.keyboard = keyboard;
+        self
+    }
 }
 
 impl std::fmt::Debug for TextFieldElement {
@@ -789,6 +822,7 @@ impl ModifierNodeElement for TextFieldElement {
         TextFieldModifierNode::new(self.state.clone())
             .with_cursor_color(self.cursor_color)
             .with_line_limits(self.line_limits)
+            .with_keyboard_controller(self.keyboard.clone())
     }
 
     fn update(&self, node: &mut Self::Node) {
@@ -796,6 +830,7 @@ impl ModifierNodeElement for TextFieldElement {
         node.state = self.state.clone();
         node.cursor_brush = Brush::solid(self.cursor_color);
         node.line_limits = self.line_limits;
+        *node.refs.keyboard.borrow_mut() = self.keyboard.clone();
 
         // Recreate the cached handler with the new state but same refs
         node.cached_handler = TextFieldModifierNode::create_handler(
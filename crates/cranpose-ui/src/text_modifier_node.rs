@@ -24,6 +24,8 @@ use cranpose_foundation::{
     LayoutModifierNode, Measurable, MeasurementProxy, ModifierNode, ModifierNodeContext,
     ModifierNodeElement, NodeCapabilities, NodeState, SemanticsConfiguration, SemanticsNode, Size,
 };
+use cranpose_ui_graphics::TextStyle;
+use cranpose_ui_layout::AlignmentLines;
 use std::hash::{Hash, Hasher};
 
 /// Node that stores text content and handles measurement, drawing, and semantics.
@@ -38,13 +40,19 @@ use std::hash::{Hash, Hasher};
 #[derive(Debug)]
 pub struct TextModifierNode {
     text: String,
+    style: TextStyle,
     state: NodeState,
 }
 
 impl TextModifierNode {
     pub fn new(text: String) -> Self {
+        Self::with_style(text, TextStyle::default())
+    }
+
+    pub fn with_style(text: String, style: TextStyle) -> Self {
         Self {
             text,
+            style,
             state: NodeState::new(),
         }
     }
@@ -53,14 +61,24 @@ impl TextModifierNode {
         &self.text
     }
 
+    pub fn style(&self) -> &TextStyle {
+        &self.style
+    }
+
     /// Helper to measure text content size.
     fn measure_text_content(&self) -> Size {
-        let metrics = crate::text::measure_text(&self.text);
+        let metrics = crate::text::measure_styled_text(&self.text, &self.style);
         Size {
             width: metrics.width,
             height: metrics.height,
         }
     }
+
+    /// Baselines of the text, so rows can line it up with other texts.
+    fn alignment_lines(&self) -> AlignmentLines {
+        let metrics = crate::text::measure_styled_text(&self.text, &self.style);
+        crate::text::text_alignment_lines(&metrics)
+    }
 }
 
 impl DelegatableNode for TextModifierNode {
@@ -124,10 +142,12 @@ impl LayoutModifierNode for TextModifierNode {
         // We don't call measurable.measure() because there's no wrapped content
         // (Text uses EmptyMeasurePolicy which has no children)
         cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size { width, height })
+            .with_alignment_lines(self.alignment_lines())
     }
 
     fn min_intrinsic_width(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
-        self.measure_text_content().width
+        // Narrowest wrap: every word on its own line.
+        crate::text::measure_longest_word_width(&self.text) * self.style.font_scale()
     }
 
     fn max_intrinsic_width(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
@@ -145,6 +165,7 @@ impl LayoutModifierNode for TextModifierNode {
     fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
         Some(Box::new(TextMeasurementProxy {
             text: self.text.clone(),
+            style: self.style.clone(),
         }))
     }
 }
@@ -155,18 +176,25 @@ impl LayoutModifierNode for TextModifierNode {
 /// directly implements measurement logic using the snapshotted text content.
 struct TextMeasurementProxy {
     text: String,
+    style: TextStyle,
 }
 
 impl TextMeasurementProxy {
     /// Measure the text content dimensions.
     /// Matches TextModifierNode::measure_text_content() logic.
     fn measure_text_content(&self) -> Size {
-        let metrics = crate::text::measure_text(&self.text);
+        let metrics = crate::text::measure_styled_text(&self.text, &self.style);
         Size {
             width: metrics.width,
             height: metrics.height,
         }
     }
+
+    /// Baselines of the text, so rows can line it up with other texts.
+    fn alignment_lines(&self) -> AlignmentLines {
+        let metrics = crate::text::measure_styled_text(&self.text, &self.style);
+        crate::text::text_alignment_lines(&metrics)
+    }
 }
 
 impl MeasurementProxy for TextMeasurementProxy {
@@ -189,10 +217,11 @@ impl MeasurementProxy for TextMeasurementProxy {
 
         // Text is a leaf node - return the text size directly with no offset
         cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size { width, height })
+            .with_alignment_lines(self.alignment_lines())
     }
 
     fn min_intrinsic_width_proxy(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
-        self.measure_text_content().width
+        crate::text::measure_longest_word_width(&self.text) * self.style.font_scale()
     }
 
     fn max_intrinsic_width_proxy(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
@@ -236,20 +265,30 @@ impl SemanticsNode for TextModifierNode {
 /// - Declaring capabilities (LAYOUT | DRAW | SEMANTICS)
 ///
 /// Matches Jetpack Compose: `TextStringSimpleElement` in BasicText.kt
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TextModifierElement {
     text: String,
+    style: TextStyle,
 }
 
 impl TextModifierElement {
     pub fn new(text: String) -> Self {
-        Self { text }
+        Self::with_style(text, TextStyle::default())
+    }
+
+    pub fn with_style(text: String, style: TextStyle) -> Self {
+        Self { text, style }
     }
 }
 
 impl Hash for TextModifierElement {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.text.hash(state);
+        self.style.font_size.map(f32::to_bits).hash(state);
+        self.style
+            .color
+            .map(|color| [color.0, color.1, color.2, color.3].map(f32::to_bits))
+            .hash(state);
     }
 }
 
@@ -257,10 +296,13 @@ impl ModifierNodeElement for TextModifierElement {
     type Node = TextModifierNode;
 
     fn create(&self) -> Self::Node {
-        TextModifierNode::new(self.text.clone())
+        TextModifierNode::with_style(self.text.clone(), self.style.clone())
     }
 
     fn update(&self, node: &mut Self::Node) {
+        if node.style != self.style {
+            node.style = self.style.clone();
+        }
         if node.text != self.text {
             node.text = self.text.clone();
             // Text changed - need to invalidate layout, draw, and semantics
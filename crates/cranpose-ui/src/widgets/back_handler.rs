@@ -0,0 +1,116 @@
+//! Platform back navigation.
+//!
+//! The host provides a [`BackDispatcher`] through [`LocalBackDispatcher`] and
+//! routes the platform back button or gesture to it. [`BackHandler`] registers
+//! a callback for as long as it stays in the composition; the most recently
+//! registered enabled handler receives the event.
+
+#![allow(non_snake_case)]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::composable;
+use cranpose_core::{remember, staticCompositionLocalOf, DisposableEffect, StaticCompositionLocal};
+
+struct BackEntry {
+    enabled: Cell<bool>,
+    on_back: RefCell<Box<dyn FnMut()>>,
+}
+
+/// Stack of back handlers, most recent last.
+#[derive(Clone, Default)]
+pub struct BackDispatcher {
+    entries: Rc<RefCell<Vec<Rc<BackEntry>>>>,
+}
+
+impl BackDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call the most recently registered enabled handler. Returns false when
+    /// there is none, so the platform can apply its default, e.g. closing
+    /// the app.
+    pub fn dispatch(&self) -> bool {
+        let entry = self
+            .entries
+            .borrow()
+            .iter()
+            .rev()
+            .find(|entry| entry.enabled.get())
+            .cloned();
+        match entry {
+            Some(entry) => {
+                (entry.on_back.borrow_mut())();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether any registered handler is enabled.
+    pub fn has_enabled_handlers(&self) -> bool {
+        self.entries
+            .borrow()
+            .iter()
+            .any(|entry| entry.enabled.get())
+    }
+
+    fn register(&self, entry: Rc<BackEntry>) {
+        self.entries.borrow_mut().push(entry);
+    }
+
+    fn unregister(&self, entry: &Rc<BackEntry>) {
+        self.entries
+            .borrow_mut()
+            .retain(|registered| !Rc::ptr_eq(registered, entry));
+    }
+}
+
+impl PartialEq for BackDispatcher {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.entries, &other.entries)
+    }
+}
+
+thread_local! {
+    static LOCAL_BACK_DISPATCHER: StaticCompositionLocal<Option<BackDispatcher>> =
+        staticCompositionLocalOf(|| None);
+}
+
+/// Dispatcher that [`BackHandler`] registers with, or `None` if the host
+/// does not route back events.
+pub fn LocalBackDispatcher() -> StaticCompositionLocal<Option<BackDispatcher>> {
+    LOCAL_BACK_DISPATCHER.with(Clone::clone)
+}
+
+/// Calls `on_back` when the platform back button or gesture is used while
+/// `enabled` is true.
+///
+/// Handlers nest: the one registered last, usually the innermost or most
+/// recently shown, receives the event. Leaving the composition unregisters it.
+///
+/// Mirrors Jetpack Compose's `BackHandler`.
+#[composable]
+pub fn BackHandler<F>(enabled: bool, on_back: F)
+where
+    F: FnMut() + 'static,
+{
+    let Some(dispatcher) = LocalBackDispatcher().current() else {
+        return;
+    };
+    let entry = remember(|| {
+        Rc::new(BackEntry {
+            enabled: Cell::new(enabled),
+            on_back: RefCell::new(Box::new(|| {})),
+        })
+    })
+    .with(Rc::clone);
+    entry.enabled.set(enabled);
+    *entry.on_back.borrow_mut() = Box::new(on_back);
+    DisposableEffect!((), move |scope| {
+        dispatcher.register(Rc::clone(&entry));
+        scope.on_dispose(move || dispatcher.unregister(&entry))
+    });
+}
@@ -8,6 +8,7 @@
 use crate::composable;
 use crate::layout::policies::EmptyMeasurePolicy;
 use crate::modifier::Modifier;
+use crate::software_keyboard::LocalSoftwareKeyboardController;
 use crate::text_field_modifier_node::TextFieldElement;
 use crate::widgets::Layout;
 use cranpose_core::NodeId;
@@ -89,7 +90,8 @@ pub fn BasicTextFieldWithOptions(
     // Build the text field element with line limits
     let text_field_element = TextFieldElement::new(state)
         .with_cursor_color(options.cursor_color)
-        .with_line_limits(options.line_limits);
+        .with_line_limits(options.line_limits)
+        .with_keyboard_controller(LocalSoftwareKeyboardController().current());
 
     // Wrap it in a modifier
     let text_field_modifier = modifier_element(text_field_element);
@@ -4,7 +4,7 @@
 
 use crate::composable;
 use crate::layout::policies::FlexMeasurePolicy;
-use crate::modifier::Modifier;
+use crate::modifier::{ClickableOptions, Modifier};
 use crate::widgets::Layout;
 use cranpose_core::NodeId;
 use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
@@ -17,6 +17,37 @@ use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
 /// of the modern modifier chain system.
 #[composable]
 pub fn Button<F, G>(modifier: Modifier, on_click: F, content: G) -> NodeId
+where
+    F: FnMut() + 'static,
+    G: FnMut() + 'static,
+{
+    ButtonWithOptions(modifier, ButtonOptions::default(), on_click, content)
+}
+
+/// Options for customizing Button behavior.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ButtonOptions {
+    /// Disabled buttons ignore clicks, are skipped by focus traversal and are
+    /// announced as disabled.
+    pub enabled: bool,
+}
+
+impl Default for ButtonOptions {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Creates a button with custom options.
+///
+/// This is the full version of `Button` with all configuration options.
+#[composable]
+pub fn ButtonWithOptions<F, G>(
+    modifier: Modifier,
+    options: ButtonOptions,
+    on_click: F,
+    content: G,
+) -> NodeId
 where
     F: FnMut() + 'static,
     G: FnMut() + 'static,
@@ -28,9 +59,15 @@ where
     let on_click_rc: Rc<RefCell<dyn FnMut()>> = Rc::new(RefCell::new(on_click));
 
     // Add clickable modifier to handle click events
-    let clickable_modifier = modifier.clickable(move |_point| {
-        (on_click_rc.borrow_mut())();
-    });
+    let clickable_options = ClickableOptions {
+        enabled: options.enabled,
+        ..ClickableOptions::default()
+    };
+    let clickable_modifier = modifier
+        .clickable_with_options(clickable_options, move |_point| {
+            (on_click_rc.borrow_mut())();
+        })
+        .focusable(options.enabled);
 
     // Use Layout with FlexMeasurePolicy (column) to arrange button content
     // This matches how Button is implemented in Jetpack Compose
@@ -0,0 +1,72 @@
+//! Dialog widget implementation
+
+#![allow(non_snake_case)]
+
+use super::back_handler::BackHandler;
+use super::box_widget::{Box, BoxSpec};
+use super::nodes::{PopupLayer, PopupPosition};
+use super::popup::popup_layer_host;
+use crate::composable;
+use crate::modifier::{Color, Modifier};
+use cranpose_core::NodeId;
+use cranpose_ui_layout::Alignment;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Color of the scrim that dims the UI behind a dialog.
+pub const DIALOG_SCRIM_COLOR: Color = Color(0.0, 0.0, 0.0, 0.32);
+
+/// Shows `content` centered in a modal layer above the rest of the UI.
+///
+/// The layer covers the whole viewport with a dimming scrim that swallows
+/// every pointer event, so nothing underneath receives input while the dialog
+/// is shown. A tap on the scrim or the platform back button calls
+/// `on_dismiss`; taps inside `content` never do. Hiding the dialog is left to
+/// the caller. A dialog opened from inside another dialog stacks above it and
+/// captures input and back presses first.
+#[composable]
+pub fn Dialog<D, C>(on_dismiss: D, content: C) -> NodeId
+where
+    D: FnMut() + 'static,
+    C: FnMut() + 'static,
+{
+    let on_dismiss: Rc<RefCell<dyn FnMut()>> = Rc::new(RefCell::new(on_dismiss));
+    let on_back = Rc::clone(&on_dismiss);
+    BackHandler(true, move || (on_back.borrow_mut())());
+    let layer = PopupLayer {
+        position: PopupPosition::Viewport,
+        on_dismiss_request: Rc::clone(&on_dismiss),
+    };
+    let scrim = Modifier::empty()
+        .fill_max_size()
+        .background(DIALOG_SCRIM_COLOR)
+        .clickable(move |_| (on_dismiss.borrow_mut())())
+        .then(block_pointer_input());
+    let content = Rc::new(RefCell::new(content));
+    popup_layer_host(layer, move || {
+        let content = Rc::clone(&content);
+        Box(
+            scrim.clone(),
+            BoxSpec::default().content_alignment(Alignment::CENTER),
+            move || {
+                let content = Rc::clone(&content);
+                Box(block_pointer_input(), BoxSpec::default(), move || {
+                    (content.borrow_mut())()
+                });
+            },
+        );
+    })
+}
+
+/// Consumes every pointer event reaching the node, hiding it from the nodes below.
+fn block_pointer_input() -> Modifier {
+    Modifier::empty().pointer_input((), |scope| async move {
+        scope
+            .await_pointer_event_scope(|await_scope| async move {
+                loop {
+                    await_scope.await_pointer_event().await.consume();
+                }
+            })
+            .await;
+    })
+}
@@ -0,0 +1,43 @@
+//! ErrorBoundary widget implementation
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+use cranpose_core::location_key;
+use std::cell::Cell;
+
+/// Composes `content`, switching to `fallback` if composing it panics.
+///
+/// A panic inside `content` is caught and its partially composed subtree is
+/// rolled back, so siblings of the boundary keep composing normally. The
+/// failure is sticky: later recompositions show `fallback` without retrying
+/// `content`. A panic raised by `fallback` itself is not contained and
+/// propagates to the nearest enclosing boundary.
+///
+/// The boundary only works when panics unwind. The workspace `release`
+/// profile uses `panic = "abort"`; build with the `release-unwind` profile to
+/// keep boundaries working in optimized builds. On targets that always abort,
+/// such as `wasm32-unknown-unknown`, a panic in `content` ends the app as it
+/// would without a boundary.
+#[composable]
+pub fn ErrorBoundary<F, C>(mut fallback: F, mut content: C)
+where
+    F: FnMut() + 'static,
+    C: FnMut() + 'static,
+{
+    let content_key = location_key(file!(), line!(), column!());
+    let failed = cranpose_core::remember(|| Cell::new(false));
+    if failed.with(|failed| failed.get()) {
+        // Revisit the emptied content group so the fallback keeps its slots.
+        cranpose_core::with_current_composer(|composer| composer.with_group(content_key, |_| {}));
+    } else {
+        let composed = cranpose_core::with_current_composer(|composer| {
+            composer.with_group_catching(content_key, |_| content())
+        });
+        if composed.is_ok() {
+            return;
+        }
+        failed.with(|failed| failed.set(true));
+    }
+    cranpose_core::with_key(&"ErrorBoundary::fallback", fallback);
+}
@@ -5,6 +5,7 @@
 use super::nodes::LayoutNode;
 use super::scopes::{BoxWithConstraintsScope, BoxWithConstraintsScopeImpl};
 use crate::composable;
+use crate::density::current_local_density;
 use crate::modifier::Modifier;
 use crate::subcompose_layout::{
     Constraints, MeasurePolicy as SubcomposeMeasurePolicy, MeasureResult, SubcomposeLayoutNode,
@@ -15,17 +16,69 @@ use cranpose_ui_layout::{MeasurePolicy, Placement};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Emits a node whose children are measured and placed by `measure_policy`.
+///
+/// This is the building block for custom layouts. The policy's
+/// [`MeasurePolicy::measure`] receives one [`Measurable`](cranpose_ui_layout::Measurable)
+/// per child emitted by `content` together with the incoming constraints. It
+/// may measure each child with its own constraints, and must place every
+/// child it wants shown and return its own size:
+///
+/// ```rust,ignore
+/// #[derive(Clone, PartialEq)]
+/// struct TwoColumns;
+///
+/// impl MeasurePolicy for TwoColumns {
+///     fn measure(&self, measurables: &[Box<dyn Measurable>], constraints: Constraints) -> MeasureResult {
+///         let column_width = constraints.max_width / 2.0;
+///         let child_constraints = Constraints::loose(column_width, constraints.max_height);
+///         let mut heights = [0.0, 0.0];
+///         let mut placements = Vec::new();
+///         for (index, measurable) in measurables.iter().enumerate() {
+///             let placeable = measurable.measure(child_constraints);
+///             let column = index % 2;
+///             let (x, y) = (column as f32 * column_width, heights[column]);
+///             placeable.place(x, y);
+///             placements.push(Placement::new(placeable.node_id(), x, y, 0));
+///             heights[column] += placeable.height();
+///         }
+///         let (width, height) = constraints.constrain(constraints.max_width, heights[0].max(heights[1]));
+///         MeasureResult::new(Size { width, height }, placements)
+///     }
+/// }
+///
+/// Layout(Modifier::empty(), TwoColumns, || { /* children */ });
+/// ```
+///
+/// Measuring a child more than once in one pass is allowed, e.g. to probe
+/// its size before choosing final constraints. Repeated constraints are
+/// served from the measurement cache, and every placeable of that child
+/// reports the latest measurement. The intrinsic size methods default to
+/// treating children as stacked; override them if the layout is queried
+/// for intrinsics and places children side by side.
 #[composable]
 pub fn Layout<F, P>(modifier: Modifier, measure_policy: P, content: F) -> NodeId
 where
     F: FnMut() + 'static,
     P: MeasurePolicy + Clone + PartialEq + 'static,
 {
-    let policy: Rc<dyn MeasurePolicy> = Rc::new(measure_policy);
+    // Keep handing the node the same `Rc` while the policy compares equal, so
+    // an unchanged policy does not mark the node for update and remeasure.
+    let policy =
+        cranpose_core::remember(|| None::<(P, Rc<dyn MeasurePolicy>)>).update(|last| match last {
+            Some((previous, policy)) if *previous == measure_policy => Rc::clone(policy),
+            _ => {
+                let policy: Rc<dyn MeasurePolicy> = Rc::new(measure_policy.clone());
+                *last = Some((measure_policy, Rc::clone(&policy)));
+                policy
+            }
+        });
+    let density = current_local_density();
     let id = cranpose_core::with_current_composer(|composer| {
         composer.emit_node(|| LayoutNode::new(modifier.clone(), Rc::clone(&policy)))
     });
     if let Err(err) = cranpose_core::with_node_mut(id, |node: &mut LayoutNode| {
+        node.set_density(density);
         node.set_modifier(modifier.clone());
         node.set_measure_policy(Rc::clone(&policy));
     }) {
@@ -44,16 +97,20 @@ pub fn SubcomposeLayout(
         + 'static,
 ) -> NodeId {
     let policy: Rc<SubcomposeMeasurePolicy> = Rc::new(measure_policy);
-    let id = cranpose_core::with_current_composer(|composer| {
-        composer.emit_node(|| SubcomposeLayoutNode::new(modifier.clone(), Rc::clone(&policy)))
-    });
-    if let Err(err) = cranpose_core::with_node_mut(id, |node: &mut SubcomposeLayoutNode| {
-        node.set_modifier(modifier.clone());
-        node.set_measure_policy(Rc::clone(&policy));
-    }) {
-        debug_assert!(false, "failed to update SubcomposeLayout node: {err}");
-    }
-    id
+    let density = current_local_density();
+    cranpose_core::with_current_composer(|composer| {
+        let id =
+            composer.emit_node(|| SubcomposeLayoutNode::new(modifier.clone(), Rc::clone(&policy)));
+        if let Err(err) = composer.with_node_mut(id, |node: &mut SubcomposeLayoutNode| {
+            node.set_precompose_host(composer);
+            node.set_density(density);
+            node.set_modifier(modifier.clone());
+            node.set_measure_policy(Rc::clone(&policy));
+        }) {
+            debug_assert!(false, "failed to update SubcomposeLayout node: {err}");
+        }
+        id
+    })
 }
 
 #[composable(no_skip)]
@@ -8,7 +8,9 @@
 
 use std::rc::Rc;
 
+use crate::fling_animation::SnapFlingBehavior;
 use crate::modifier::Modifier;
+use crate::saveable::Saver;
 use crate::subcompose_layout::{
     Placement, SubcomposeLayoutNode, SubcomposeLayoutScope, SubcomposeMeasureScope,
     SubcomposeMeasureScopeImpl,
@@ -17,7 +19,7 @@ use crate::widgets::nodes::cranpose_node;
 use cranpose_core::{NodeId, SlotId};
 use cranpose_foundation::lazy::{
     measure_lazy_list, LazyListIntervalContent, LazyListMeasureConfig, LazyListMeasuredItem,
-    LazyListState, SmallNodeVec, SmallOffsetVec, DEFAULT_ITEM_SIZE_ESTIMATE,
+    LazyListState, SmallNodeVec, SmallOffsetVec,
 };
 use cranpose_ui_layout::{Constraints, LinearArrangement, MeasureResult, Placeable};
 use smallvec::SmallVec;
@@ -25,6 +27,29 @@ use smallvec::SmallVec;
 // Re-export from foundation - single source of truth
 pub use cranpose_foundation::lazy::{LazyListItemInfo, LazyListLayoutInfo};
 
+/// Saves a [`LazyListState`] as its first visible item and that item's
+/// scroll offset, for [`rememberSaveableWith`](crate::rememberSaveableWith).
+///
+/// A restored position past the end of a list that has shrunk in the meantime
+/// is clamped by the next measure pass.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LazyListStateSaver;
+
+impl Saver<LazyListState> for LazyListStateSaver {
+    type Saved = (usize, f32);
+
+    fn save(&self, state: &LazyListState) -> (usize, f32) {
+        (
+            state.first_visible_item_index(),
+            state.first_visible_item_scroll_offset(),
+        )
+    }
+
+    fn restore(&self, (index, scroll_offset): (usize, f32)) -> LazyListState {
+        LazyListState::new(index, scroll_offset)
+    }
+}
+
 /// Specification for LazyColumn layout behavior.
 #[derive(Clone, Debug)]
 pub struct LazyColumnSpec {
@@ -39,6 +64,8 @@ pub struct LazyColumnSpec {
     pub beyond_bounds_item_count: usize,
     /// Whether to reverse the layout direction (bottom-to-top).
     pub reverse_layout: bool,
+    /// Settles the list on an item after a drag or fling; `None` flings freely.
+    pub fling_behavior: Option<SnapFlingBehavior>,
 }
 
 impl Default for LazyColumnSpec {
@@ -49,6 +76,7 @@ impl Default for LazyColumnSpec {
             content_padding_bottom: 0.0,
             beyond_bounds_item_count: 2,
             reverse_layout: false,
+            fling_behavior: None,
         }
     }
 }
@@ -80,6 +108,11 @@ impl LazyColumnSpec {
         self.reverse_layout = reverse;
         self
     }
+
+    pub fn fling_behavior(mut self, behavior: SnapFlingBehavior) -> Self {
+        self.fling_behavior = Some(behavior);
+        self
+    }
 }
 
 /// Specification for LazyRow layout behavior.
@@ -95,6 +128,8 @@ pub struct LazyRowSpec {
     pub beyond_bounds_item_count: usize,
     /// Whether to reverse the layout direction (end-to-start).
     pub reverse_layout: bool,
+    /// Settles the list on an item after a drag or fling; `None` flings freely.
+    pub fling_behavior: Option<SnapFlingBehavior>,
 }
 
 impl Default for LazyRowSpec {
@@ -105,6 +140,7 @@ impl Default for LazyRowSpec {
             content_padding_end: 0.0,
             beyond_bounds_item_count: 2,
             reverse_layout: false,
+            fling_behavior: None,
         }
     }
 }
@@ -136,6 +172,11 @@ impl LazyRowSpec {
         self.reverse_layout = reverse;
         self
     }
+
+    pub fn fling_behavior(mut self, behavior: SnapFlingBehavior) -> Self {
+        self.fling_behavior = Some(behavior);
+        self
+    }
 }
 
 /// Internal helper to create a lazy list measure policy.
@@ -313,50 +354,18 @@ fn measure_lazy_list_internal(
         state.update_prefetch_queue(first_visible, last_visible, items_count);
 
         // 3. Pre-compose prefetched items (compose but don't place)
-        // Uses cached item sizes when available for better size estimation
-        let prefetch_indices = state.take_prefetch_indices();
-        let average_size = state.average_item_size();
-        for idx in prefetch_indices {
+        // SubcomposeState keeps them as precomposed until a later pass places
+        // them, so scrolling onto a prefetched item reuses its nodes.
+        for idx in state.take_prefetch_indices() {
             if idx < items_count {
-                // Subcompose without placing - just to have it ready
-                // SubcomposeState automatically tracks these as precomposed
-                let key = content.get_key(idx);
-                let key_slot_id = key.to_slot_id();
-                let content_type_prefetch = content.get_content_type(idx);
-                let slot_id = SlotId(key_slot_id);
+                let slot_id = SlotId(content.get_key(idx).to_slot_id());
 
                 // Update content type for prefetched items too
-                scope.update_content_type(slot_id, content_type_prefetch);
-
-                // Use cached size if available, otherwise use average
-                let estimated_size = state
-                    .get_cached_size(idx)
-                    .unwrap_or(average_size.max(DEFAULT_ITEM_SIZE_ESTIMATE));
-
-                let prefetch_idx = idx;
-                let is_vertical = config.is_vertical;
-                let _ = scope.subcompose_with_size(
-                    slot_id,
-                    || {
-                        content.invoke_content(prefetch_idx);
-                    },
-                    move |_| {
-                        // Use correct axis based on orientation:
-                        // Vertical list: width = cross_axis, height = main_axis (estimated)
-                        // Horizontal list: width = main_axis (estimated), height = cross_axis
-                        if is_vertical {
-                            crate::modifier::Size {
-                                width: cross_axis_size,
-                                height: estimated_size + config.spacing,
-                            }
-                        } else {
-                            crate::modifier::Size {
-                                width: estimated_size + config.spacing,
-                                height: cross_axis_size,
-                            }
-                        }
-                    },
-                );
+                scope.update_content_type(slot_id, content.get_content_type(idx));
+
+                scope.precompose(slot_id, || {
+                    content.invoke_content(idx);
+                });
             }
         }
     }
@@ -563,9 +572,12 @@ fn LazyColumnImpl(
     .with(|p| p.clone());
 
     // Apply clipping and scroll gesture handling to modifier
-    let scroll_modifier = modifier
-        .clip_to_bounds()
-        .lazy_vertical_scroll(state, spec.reverse_layout);
+    let scroll_modifier = modifier.clip_to_bounds().lazy_scroll(
+        state,
+        true,
+        spec.reverse_layout,
+        spec.fling_behavior,
+    );
 
     // Create and register the subcompose layout node with the composer
     let node_id = cranpose_node(move || {
@@ -631,9 +643,12 @@ fn LazyRowImpl(
     .with(|p| p.clone());
 
     // Apply clipping and scroll gesture handling to modifier
-    let scroll_modifier = modifier
-        .clip_to_bounds()
-        .lazy_horizontal_scroll(state, spec.reverse_layout);
+    let scroll_modifier = modifier.clip_to_bounds().lazy_scroll(
+        state,
+        false,
+        spec.reverse_layout,
+        spec.fling_behavior,
+    );
 
     // Create and register the subcompose layout node with the composer
     let node_id = cranpose_node(move || {
@@ -1,27 +1,41 @@
 //! UI Widget components
 
+pub mod back_handler;
 pub mod basic_text_field;
 pub mod box_widget;
 pub mod button;
 pub mod column;
+pub mod dialog;
+pub mod error_boundary;
 pub mod foreach;
 pub mod layout;
 pub mod lazy_list;
+pub mod nav_host;
 pub mod nodes;
+pub mod popup;
 pub mod row;
 pub mod scopes;
 pub mod spacer;
 pub mod text;
+pub mod tooltip;
+pub mod when;
 
+pub use back_handler::*;
 pub use basic_text_field::*;
 pub use box_widget::*;
 pub use button::*;
 pub use column::*;
+pub use dialog::*;
+pub use error_boundary::*;
 pub use foreach::*;
 pub use layout::*;
 pub use lazy_list::*;
+pub use nav_host::*;
 pub use nodes::*;
+pub use popup::*;
 pub use row::*;
 pub use scopes::*;
 pub use spacer::*;
 pub use text::*;
+pub use tooltip::*;
+pub use when::*;
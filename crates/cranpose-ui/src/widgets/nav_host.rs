@@ -0,0 +1,193 @@
+//! Screen navigation with a back stack.
+//!
+//! A [`NavController`] owns the stack of visited routes and [`NavHost`]
+//! composes the route on top. Each back stack entry has its own
+//! [`SaveableStateRegistry`], so [`rememberSaveable`](crate::rememberSaveable)
+//! values of a screen survive navigating away and are restored on the way
+//! back.
+
+#![allow(non_snake_case)]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use super::back_handler::BackHandler;
+use super::box_widget::{Box, BoxSpec};
+use crate::composable;
+use crate::modifier::Modifier;
+use crate::saveable::{LocalSaveableStateRegistry, SaveableStateRegistry};
+use cranpose_core::{mutableStateOf, remember, with_key, CompositionLocalProvider, MutableState};
+
+#[derive(Clone)]
+struct BackStackEntry {
+    id: u64,
+    route: String,
+    registry: SaveableStateRegistry,
+}
+
+struct NavControllerInner {
+    back_stack: RefCell<Vec<BackStackEntry>>,
+    next_id: Cell<u64>,
+    /// Bumped on every stack change so the host recomposes.
+    version: MutableState<u64>,
+}
+
+/// Back stack of routes shown by a [`NavHost`].
+#[derive(Clone)]
+pub struct NavController {
+    inner: Rc<NavControllerInner>,
+}
+
+impl NavController {
+    /// An empty controller; the host pushes its start route on first
+    /// composition. Needs an active runtime.
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(NavControllerInner {
+                back_stack: RefCell::new(Vec::new()),
+                next_id: Cell::new(0),
+                version: mutableStateOf(0),
+            }),
+        }
+    }
+
+    /// Show `route` on top of the current one. Navigating to the route that
+    /// is already on top does nothing.
+    pub fn navigate(&self, route: impl Into<String>) {
+        let route = route.into();
+        let mut back_stack = self.inner.back_stack.borrow_mut();
+        if let Some(top) = back_stack.last() {
+            if top.route == route {
+                return;
+            }
+            top.registry.perform_save();
+        }
+        back_stack.push(self.new_entry(route));
+        drop(back_stack);
+        self.bump_version();
+    }
+
+    /// Return to the previous route, discarding the state of the current one.
+    /// Returns false, leaving the stack as is, when there is nothing to go
+    /// back to.
+    pub fn pop_back_stack(&self) -> bool {
+        let mut back_stack = self.inner.back_stack.borrow_mut();
+        if back_stack.len() < 2 {
+            return false;
+        }
+        back_stack.pop();
+        drop(back_stack);
+        self.bump_version();
+        true
+    }
+
+    /// Route on top of the back stack.
+    pub fn current_route(&self) -> Option<String> {
+        self.inner
+            .back_stack
+            .borrow()
+            .last()
+            .map(|entry| entry.route.clone())
+    }
+
+    /// Routes on the back stack, oldest first.
+    pub fn back_stack(&self) -> Vec<String> {
+        self.inner
+            .back_stack
+            .borrow()
+            .iter()
+            .map(|entry| entry.route.clone())
+            .collect()
+    }
+
+    fn new_entry(&self, route: String) -> BackStackEntry {
+        let id = self.inner.next_id.get();
+        self.inner.next_id.set(id + 1);
+        BackStackEntry {
+            id,
+            route,
+            registry: SaveableStateRegistry::new(),
+        }
+    }
+
+    fn bump_version(&self) {
+        self.inner.version.update(|version| *version += 1);
+    }
+}
+
+impl Default for NavController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for NavController {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+/// A [`NavController`] that stays the same across recompositions.
+pub fn rememberNavController() -> NavController {
+    remember(NavController::new).with(Clone::clone)
+}
+
+/// Composes the route on top of `controller`'s back stack with `routes` in a
+/// [`Box`], starting at `start_route`.
+///
+/// Leaving a route disposes its content; going back composes it again with
+/// its saved [`rememberSaveable`](crate::rememberSaveable) values. While
+/// there is a route to go back to, the platform back button pops the stack.
+///
+/// Mirrors Jetpack Compose's `NavHost`.
+#[composable(no_skip)]
+pub fn NavHost<F>(controller: NavController, start_route: &'static str, routes: F)
+where
+    F: FnMut(&str) + 'static,
+{
+    if controller.inner.back_stack.borrow().is_empty() {
+        let entry = controller.new_entry(start_route.to_owned());
+        controller.inner.back_stack.borrow_mut().push(entry);
+    }
+    NavHostContent(controller, NavRoutes(Rc::new(RefCell::new(routes))));
+}
+
+type RouteContent = dyn FnMut(&str);
+
+/// Route content shared with the recomposable part of [`NavHost`].
+#[derive(Clone)]
+struct NavRoutes(Rc<RefCell<RouteContent>>);
+
+impl PartialEq for NavRoutes {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[composable]
+fn NavHostContent(controller: NavController, routes: NavRoutes) {
+    // Subscribe to stack changes.
+    controller.inner.version.with(|_| ());
+    let (entry, can_go_back) = {
+        let back_stack = controller.inner.back_stack.borrow();
+        let entry = back_stack.last().cloned().expect("back stack is not empty");
+        (entry, back_stack.len() > 1)
+    };
+    // Registered before the route content, so handlers inside the route
+    // receive back events first.
+    BackHandler(can_go_back, move || {
+        controller.pop_back_stack();
+    });
+    // Routes share a container node, so the nodes of the route that was left
+    // are dropped from it.
+    Box(Modifier::empty(), BoxSpec::default(), move || {
+        let entry = entry.clone();
+        let routes = routes.clone();
+        with_key(&entry.id, || {
+            CompositionLocalProvider(
+                [LocalSaveableStateRegistry().provides(Some(entry.registry))],
+                || (routes.0.borrow_mut())(&entry.route),
+            );
+        });
+    });
+}
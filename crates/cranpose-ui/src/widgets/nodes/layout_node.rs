@@ -1,8 +1,10 @@
 use crate::{
-    layout::MeasuredNode,
+    density::with_measure_density,
+    layout::{LayoutBox, MeasuredNode},
     modifier::{
-        collect_modifier_slices, Modifier, ModifierChainHandle, ModifierLocalSource,
-        ModifierLocalToken, ModifierLocalsHandle, ModifierNodeSlices, ResolvedModifierLocal,
+        collect_modifier_slices, FocusState, FocusTargetNode, Modifier, ModifierChainHandle,
+        ModifierLocalKey, ModifierLocalSource, ModifierLocalToken, ModifierLocalsHandle,
+        ModifierNodeKind, ModifierNodeSlices, Point, Rect, ResolvedModifierLocal,
         ResolvedModifiers,
     },
 };
@@ -10,6 +12,7 @@ use cranpose_core::{Node, NodeId};
 use cranpose_foundation::{
     InvalidationKind, ModifierInvalidation, NodeCapabilities, SemanticsConfiguration,
 };
+use cranpose_ui_graphics::Density;
 use cranpose_ui_layout::{Constraints, MeasurePolicy};
 use indexmap::IndexSet;
 use std::cell::{Cell, RefCell};
@@ -66,11 +69,23 @@ impl Hash for IntrinsicKind {
     }
 }
 
+/// The layout box last built for a node, valid while the node is placed at
+/// `origin` with a measurement shaped like `measured`.
+struct PlacementCacheEntry {
+    origin: Point,
+    measured: Rc<MeasuredNode>,
+    layout: Rc<LayoutBox>,
+}
+
 #[derive(Default)]
 struct NodeCacheState {
     epoch: u64,
+    /// Density the cached sizes were measured at; tracked on measure roots.
+    density_bits: Option<u32>,
     measurements: Vec<MeasurementCacheEntry>,
     intrinsics: Vec<(IntrinsicKind, f32)>,
+    // Not tied to the epoch: a re-measure that reproduces the same geometry keeps it valid.
+    placement: Option<PlacementCacheEntry>,
 }
 
 #[derive(Clone, Default)]
@@ -83,7 +98,20 @@ impl LayoutNodeCacheHandles {
         let mut state = self.state.borrow_mut();
         state.measurements.clear();
         state.intrinsics.clear();
+        state.placement = None;
         state.epoch = 0;
+        state.density_bits = None;
+    }
+
+    /// Records that the tree is measured at `density` and reports whether
+    /// the cached sizes were measured at a different one.
+    pub(crate) fn update_density(&self, density: f32) -> bool {
+        let bits = density.to_bits();
+        self.state
+            .borrow_mut()
+            .density_bits
+            .replace(bits)
+            .is_some_and(|previous| previous != bits)
     }
 
     pub(crate) fn activate(&self, epoch: u64) {
@@ -124,6 +152,42 @@ impl LayoutNodeCacheHandles {
         }
     }
 
+    /// Returns the cached layout box when the node sits at `origin` again and
+    /// `is_current` accepts it and the measurement it was built from.
+    pub(crate) fn get_placement(
+        &self,
+        origin: Point,
+        is_current: impl FnOnce(&MeasuredNode, &LayoutBox) -> bool,
+    ) -> Option<Rc<LayoutBox>> {
+        let state = self.state.borrow();
+        let entry = state.placement.as_ref()?;
+        (entry.origin == origin && is_current(&entry.measured, &entry.layout))
+            .then(|| Rc::clone(&entry.layout))
+    }
+
+    pub(crate) fn clear_placement(&self) {
+        self.state.borrow_mut().placement = None;
+    }
+
+    /// Bounds of the last placement, in root coordinates.
+    pub(crate) fn placed_rect(&self) -> Option<Rect> {
+        let state = self.state.borrow();
+        state.placement.as_ref().map(|entry| entry.layout.rect)
+    }
+
+    pub(crate) fn store_placement(
+        &self,
+        origin: Point,
+        measured: Rc<MeasuredNode>,
+        layout: Rc<LayoutBox>,
+    ) {
+        self.state.borrow_mut().placement = Some(PlacementCacheEntry {
+            origin,
+            measured,
+            layout,
+        });
+    }
+
     pub(crate) fn get_intrinsic(&self, kind: &IntrinsicKind) -> Option<f32> {
         let state = self.state.borrow();
         state
@@ -147,6 +211,28 @@ impl LayoutNodeCacheHandles {
     }
 }
 
+/// Where a popup layer is placed on screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PopupPosition {
+    /// At the given offset from the top-left corner of the parent layout,
+    /// sized to its content within the viewport.
+    Anchored(Point),
+    /// Over the whole viewport, which the layer's root is forced to fill.
+    Viewport,
+}
+
+/// Marks a node as the root of a popup layer.
+///
+/// A popup layer is left out of its parent's measurement: it is measured
+/// against the viewport, placed according to `position` and drawn (and
+/// hit-tested) above the main tree.
+#[derive(Clone)]
+pub struct PopupLayer {
+    pub position: PopupPosition,
+    /// Invoked when a press lands outside the popup.
+    pub on_dismiss_request: Rc<RefCell<dyn FnMut()>>,
+}
+
 pub struct LayoutNode {
     pub modifier: Modifier,
     modifier_chain: ModifierChainHandle,
@@ -164,6 +250,8 @@ pub struct LayoutNode {
     needs_redraw: Cell<bool>,
     needs_pointer_pass: Cell<bool>,
     needs_focus_sync: Cell<bool>,
+    /// Modifier, measure policy or density changed since the last `update()`
+    needs_update: Cell<bool>,
     /// Parent for dirty flag bubbling (skips virtual nodes)
     parent: Cell<Option<NodeId>>,
     /// Direct parent in the tree (may be virtual)
@@ -176,6 +264,7 @@ pub struct LayoutNode {
     is_virtual: bool,
     /// Count of virtual children (for lazy unfolded children computation)
     virtual_children_count: Cell<usize>,
+    popup_layer: Option<PopupLayer>,
 }
 
 impl LayoutNode {
@@ -213,12 +302,14 @@ impl LayoutNode {
             needs_redraw: Cell::new(true),  // First render should draw the node
             needs_pointer_pass: Cell::new(false),
             needs_focus_sync: Cell::new(false),
+            needs_update: Cell::new(false),
             parent: Cell::new(None),        // Non-virtual parent for bubbling
             folded_parent: Cell::new(None), // Direct parent (may be virtual)
             id: Cell::new(None),            // ID set by applier after creation
             debug_modifiers: Cell::new(false),
             is_virtual,
             virtual_children_count: Cell::new(0),
+            popup_layer: None,
         };
         if !is_virtual {
             node.set_modifier(modifier);
@@ -234,8 +325,12 @@ impl LayoutNode {
         // called on matched nodes.
         let modifier_changed = self.modifier != modifier;
         self.modifier = modifier;
+        // Equal elements may still swap handlers or draw state, so any update
+        // invalidates the placed layout box that captured them.
+        self.cache.clear_placement();
         self.sync_modifier_chain();
         if modifier_changed {
+            self.needs_update.set(true);
             self.cache.clear();
             self.mark_needs_measure();
             self.request_semantics_update();
@@ -309,11 +404,45 @@ impl LayoutNode {
         // Only mark dirty if policy actually changed (pointer comparison)
         if !Rc::ptr_eq(&self.measure_policy, &policy) {
             self.measure_policy = policy;
+            self.needs_update.set(true);
+            self.cache.clear();
+            self.mark_needs_measure();
+        }
+    }
+
+    /// Sets the [`LocalDensity`](crate::LocalDensity) this node was composed
+    /// under; its modifiers' [`Dp`](crate::Dp) lengths are measured with it.
+    pub fn set_density(&mut self, density: Density) {
+        if self.modifier_chain.set_density(density) {
+            self.resolved_modifiers = self.modifier_chain.resolved_modifiers();
+            self.needs_update.set(true);
             self.cache.clear();
             self.mark_needs_measure();
         }
     }
 
+    pub fn density(&self) -> Density {
+        self.modifier_chain.density()
+    }
+
+    /// Turns this node into the root of a popup layer, or back into a regular
+    /// child of its parent when `layer` is `None`.
+    pub fn set_popup_layer(&mut self, layer: Option<PopupLayer>) {
+        let moved = match (&self.popup_layer, &layer) {
+            (Some(current), Some(next)) => current.position != next.position,
+            (None, None) => false,
+            _ => true,
+        };
+        self.popup_layer = layer;
+        if moved {
+            self.mark_needs_measure();
+        }
+    }
+
+    pub fn popup_layer(&self) -> Option<&PopupLayer> {
+        self.popup_layer.as_ref()
+    }
+
     /// Mark this node as needing measure. Also marks it as needing layout.
     pub fn mark_needs_measure(&self) {
         self.needs_measure.set(true);
@@ -328,6 +457,7 @@ impl LayoutNode {
     /// Mark this node as needing redraw without forcing measure/layout.
     pub fn mark_needs_redraw(&self) {
         self.needs_redraw.set(true);
+        self.cache.clear_placement();
         if let Some(id) = self.id.get() {
             crate::schedule_draw_repass(id);
         }
@@ -473,6 +603,11 @@ impl LayoutNode {
         self.cache.clone()
     }
 
+    /// Returns the resolved modifier nodes in chain order (see [`ModifierNodeKind`]).
+    pub fn modifier_summary(&self) -> Vec<ModifierNodeKind> {
+        self.modifier_chain.modifier_summary()
+    }
+
     pub fn resolved_modifiers(&self) -> ResolvedModifiers {
         self.resolved_modifiers
     }
@@ -537,7 +672,29 @@ impl LayoutNode {
     }
 
     pub fn modifier_slices_snapshot(&self) -> ModifierNodeSlices {
-        collect_modifier_slices(self.modifier_chain.chain())
+        with_measure_density(self.density(), || {
+            collect_modifier_slices(self.modifier_chain.chain())
+        })
+    }
+
+    /// Moves this node's focus targets to `state`, notifying their focus
+    /// callbacks. Returns false if no target took it; disabled targets refuse
+    /// to become active.
+    pub fn set_focus_state(&self, state: FocusState) -> bool {
+        let mut applied = false;
+        self.modifier_chain.chain().for_each_node_with_capability(
+            NodeCapabilities::FOCUS,
+            |_, node| {
+                let Some(target) = node.as_any().downcast_ref::<FocusTargetNode>() else {
+                    return;
+                };
+                if state != FocusState::Active || target.request_focus() {
+                    target.set_focus_state(state);
+                    applied = true;
+                }
+            },
+        );
+        applied
     }
 
     pub fn semantics_configuration(&self) -> Option<SemanticsConfiguration> {
@@ -577,13 +734,16 @@ impl Clone for LayoutNode {
             needs_redraw: Cell::new(self.needs_redraw.get()),
             needs_pointer_pass: Cell::new(self.needs_pointer_pass.get()),
             needs_focus_sync: Cell::new(self.needs_focus_sync.get()),
+            needs_update: Cell::new(self.needs_update.get()),
             parent: Cell::new(self.parent.get()),
             folded_parent: Cell::new(self.folded_parent.get()),
             id: Cell::new(None),
             debug_modifiers: Cell::new(self.debug_modifiers.get()),
             is_virtual: self.is_virtual,
             virtual_children_count: Cell::new(self.virtual_children_count.get()),
+            popup_layer: self.popup_layer.clone(),
         };
+        node.modifier_chain.set_density(self.density());
         node.sync_modifier_chain();
         node
     }
@@ -596,6 +756,14 @@ impl Node for LayoutNode {
         chain.attach_nodes(&mut *context);
     }
 
+    fn update(&mut self) {
+        self.needs_update.set(false);
+    }
+
+    fn needs_update(&self) -> bool {
+        self.needs_update.get()
+    }
+
     fn unmount(&mut self) {
         self.modifier_chain.chain_mut().detach_nodes();
     }
@@ -658,6 +826,10 @@ impl Node for LayoutNode {
         self.children.iter().copied().collect()
     }
 
+    fn has_child(&self, child: NodeId) -> bool {
+        self.children.contains(&child)
+    }
+
     fn on_attached_to_parent(&mut self, parent: NodeId) {
         self.set_parent(parent);
     }
@@ -725,6 +897,7 @@ struct LayoutNodeRegistryEntry {
     parent: Option<NodeId>,
     modifier_child_capabilities: NodeCapabilities,
     modifier_locals: ModifierLocalsHandle,
+    cache: LayoutNodeCacheHandles,
     is_virtual: bool,
 }
 
@@ -736,6 +909,7 @@ pub(crate) fn register_layout_node(id: NodeId, node: &LayoutNode) {
                 parent: node.parent(),
                 modifier_child_capabilities: node.modifier_child_capabilities(),
                 modifier_locals: node.modifier_locals_handle(),
+                cache: node.cache_handles(),
                 is_virtual: node.is_virtual(),
             },
         );
@@ -758,6 +932,12 @@ pub(crate) fn is_virtual_node(id: NodeId) -> bool {
     })
 }
 
+/// Bounds `node` was last placed at, in root coordinates, or `None` if it
+/// has not been laid out.
+pub(crate) fn layout_node_rect(node: NodeId) -> Option<Rect> {
+    LAYOUT_NODE_REGISTRY.with(|registry| registry.borrow().get(&node)?.cache.placed_rect())
+}
+
 pub(crate) fn allocate_virtual_node_id() -> NodeId {
     use std::sync::atomic::Ordering;
     // Allocate IDs from a high range to avoid conflict with SlotTable IDs.
@@ -799,6 +979,48 @@ fn resolve_modifier_local_from_parent_chain(
     None
 }
 
+/// Values provided for `key` by the modifier chains of the ancestors of
+/// `node`, nearest first. Unlike a modifier local consumer this is read at
+/// the time of the call, so it also sees ancestors attached after `node`.
+pub(crate) fn ancestor_modifier_local_values<T: Clone + 'static>(
+    node: NodeId,
+    key: &ModifierLocalKey<T>,
+) -> Vec<T> {
+    ancestors_providing_modifier_local(node, key)
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect()
+}
+
+/// Like [`ancestor_modifier_local_values`], paired with the ancestor that
+/// provides each value.
+pub(crate) fn ancestors_providing_modifier_local<T: Clone + 'static>(
+    node: NodeId,
+    key: &ModifierLocalKey<T>,
+) -> Vec<(NodeId, T)> {
+    let mut values = Vec::new();
+    let mut current = LAYOUT_NODE_REGISTRY
+        .with(|registry| registry.borrow().get(&node).and_then(|entry| entry.parent));
+    while let Some(parent_id) = current {
+        current = LAYOUT_NODE_REGISTRY.with(|registry| {
+            let registry = registry.borrow();
+            let entry = registry.get(&parent_id)?;
+            if entry
+                .modifier_child_capabilities
+                .contains(NodeCapabilities::MODIFIER_LOCALS)
+            {
+                if let Some(resolved) = entry.modifier_locals.borrow().resolve(key.token()) {
+                    if let Some(value) = resolved.value().downcast_ref::<T>() {
+                        values.push((parent_id, value.clone()));
+                    }
+                }
+            }
+            entry.parent
+        });
+    }
+    values
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -849,6 +1071,31 @@ mod tests {
         ModifierInvalidation::new(kind, NodeCapabilities::for_invalidation(kind))
     }
 
+    #[test]
+    fn modifier_summary_lists_nodes_in_chain_order() {
+        use crate::modifier::Color;
+
+        let node = LayoutNode::new(
+            Modifier::empty()
+                .padding(4.0)
+                .background(Color(1.0, 0.0, 0.0, 1.0))
+                .then(Modifier::empty().clickable(|_| {}).padding(2.0)),
+            Rc::new(TestMeasurePolicy),
+        );
+
+        assert_eq!(
+            node.modifier_summary(),
+            vec![
+                ModifierNodeKind::Padding,
+                ModifierNodeKind::Background,
+                ModifierNodeKind::Clickable,
+                // `clickable` also installs button semantics.
+                ModifierNodeKind::Semantics,
+                ModifierNodeKind::Padding,
+            ]
+        );
+    }
+
     #[test]
     fn layout_invalidation_requires_layout_capability() {
         let mut node = fresh_node();
@@ -3,9 +3,12 @@ use cranpose_core::{Node, NodeId};
 mod layout_node;
 
 pub use layout_node::IntrinsicKind;
-pub use layout_node::LayoutNode;
 pub(crate) use layout_node::LayoutNodeCacheHandles;
-pub(crate) use layout_node::{allocate_virtual_node_id, is_virtual_node, register_layout_node};
+pub(crate) use layout_node::{
+    allocate_virtual_node_id, ancestor_modifier_local_values, ancestors_providing_modifier_local,
+    is_virtual_node, layout_node_rect, register_layout_node,
+};
+pub use layout_node::{LayoutNode, PopupLayer, PopupPosition};
 
 pub fn cranpose_node<N: Node + 'static>(init: impl FnOnce() -> N) -> NodeId {
     cranpose_core::with_current_composer(|composer| composer.emit_node(init))
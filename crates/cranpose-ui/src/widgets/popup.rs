@@ -0,0 +1,52 @@
+//! Popup widget implementation
+
+#![allow(non_snake_case)]
+
+use super::layout::Layout;
+use super::nodes::{LayoutNode, PopupLayer, PopupPosition};
+use crate::composable;
+use crate::layout::policies::BoxMeasurePolicy;
+use crate::modifier::{Modifier, Point};
+use cranpose_core::NodeId;
+use cranpose_ui_layout::Alignment;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Composes `content` into a popup layer drawn above the rest of the UI.
+///
+/// The popup is positioned at `offset` from the top-left corner of the layout
+/// it is composed in, but it takes no space there and is not clipped by it:
+/// its content is measured against the whole viewport, drawn after the main
+/// tree and hit-tested before it. `on_dismiss_request` runs when a press lands
+/// outside the popup; hiding the popup is left to the caller.
+#[composable]
+pub fn Popup<D, C>(offset: Point, on_dismiss_request: D, content: C) -> NodeId
+where
+    D: FnMut() + 'static,
+    C: FnMut() + 'static,
+{
+    let layer = PopupLayer {
+        position: PopupPosition::Anchored(offset),
+        on_dismiss_request: Rc::new(RefCell::new(on_dismiss_request)),
+    };
+    popup_layer_host(layer, content)
+}
+
+/// Composes `content` inside a node that roots the popup `layer`.
+pub(crate) fn popup_layer_host<C>(layer: PopupLayer, content: C) -> NodeId
+where
+    C: FnMut() + 'static,
+{
+    let modifier = match layer.position {
+        PopupPosition::Anchored(_) => Modifier::empty(),
+        PopupPosition::Viewport => Modifier::empty().fill_max_size(),
+    };
+    let policy = BoxMeasurePolicy::new(Alignment::TOP_START, false);
+    let id = Layout(modifier, policy, content);
+    if let Err(err) = cranpose_core::with_node_mut(id, |node: &mut LayoutNode| {
+        node.set_popup_layer(Some(layer));
+    }) {
+        debug_assert!(false, "failed to update popup layer node: {err}");
+    }
+    id
+}
@@ -8,14 +8,38 @@
 #![allow(non_snake_case)]
 
 use crate::composable;
+use crate::density::current_local_density;
 use crate::layout::policies::EmptyMeasurePolicy;
 use crate::modifier::Modifier;
 use crate::text_modifier_node::TextModifierElement;
 use crate::widgets::Layout;
-use cranpose_core::{MutableState, NodeId, State};
+use cranpose_core::{compositionLocalOf, CompositionLocal, MutableState, NodeId, State};
 use cranpose_foundation::modifier_element;
+use cranpose_ui_graphics::{Density, Sp, TextStyle, DEFAULT_FONT_SIZE};
 use std::rc::Rc;
 
+thread_local! {
+    static LOCAL_TEXT_STYLE: CompositionLocal<TextStyle> = compositionLocalOf(TextStyle::default);
+}
+
+/// Text style that [`Text`] uses for every attribute its call does not set.
+///
+/// Mirrors Jetpack Compose's `LocalTextStyle`. Provide it with
+/// [`ProvideTextStyle`] to theme a subtree.
+#[allow(non_snake_case)]
+pub fn LocalTextStyle() -> CompositionLocal<TextStyle> {
+    LOCAL_TEXT_STYLE.with(Clone::clone)
+}
+
+/// Merges `style` over the current [`LocalTextStyle`] for `content`, so nested
+/// providers only override the attributes they set.
+#[allow(non_snake_case)]
+pub fn ProvideTextStyle(style: TextStyle, content: impl FnOnce()) {
+    let local = LocalTextStyle();
+    let merged = local.current().merge(&style);
+    cranpose_core::CompositionLocalProvider([local.provides(merged)], content);
+}
+
 #[derive(Clone)]
 pub struct DynamicTextSource(Rc<dyn Fn() -> String>);
 
@@ -121,16 +145,43 @@ impl IntoTextSource for DynamicTextSource {
 ///
 /// Text content lives in the modifier node (TextModifierNode), not in the measure policy,
 /// which properly separates layout policy (child arrangement) from content rendering (text).
+///
+/// The text is styled with the current [`LocalTextStyle`].
 #[composable]
 pub fn Text<S>(value: S, modifier: Modifier) -> NodeId
 where
     S: IntoTextSource + Clone + PartialEq + 'static,
 {
-    let current = value.into_text_source().resolve();
+    let style = LocalTextStyle().current();
+    text_layout(value.into_text_source().resolve(), modifier, style)
+}
+
+/// Like [`Text`], with `style` overriding the attributes it sets on top of the
+/// current [`LocalTextStyle`].
+#[composable]
+pub fn StyledText<S>(value: S, modifier: Modifier, style: TextStyle) -> NodeId
+where
+    S: IntoTextSource + Clone + PartialEq + 'static,
+{
+    let style = LocalTextStyle().current().merge(&style);
+    text_layout(value.into_text_source().resolve(), modifier, style)
+}
 
+fn text_layout(current: String, modifier: Modifier, style: TextStyle) -> NodeId {
+    // Font sizes are sp, so they follow both the density and the font scale.
+    let density = current_local_density();
+    let style = if density == Density::default() {
+        style
+    } else {
+        let size = Sp(style.font_size.unwrap_or(DEFAULT_FONT_SIZE));
+        TextStyle {
+            font_size: Some(density.sp_to_px(size)),
+            ..style
+        }
+    };
     // Create a text modifier element that will add TextModifierNode to the chain
     // TextModifierNode handles measurement, drawing, and semantics
-    let text_element = modifier_element(TextModifierElement::new(current.clone()));
+    let text_element = modifier_element(TextModifierElement::with_style(current, style));
     let final_modifier = Modifier::from_parts(vec![text_element]);
     let combined_modifier = modifier.then(final_modifier);
 
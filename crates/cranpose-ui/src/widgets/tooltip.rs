@@ -0,0 +1,122 @@
+//! Tooltip widget implementation
+
+#![allow(non_snake_case)]
+
+use super::box_widget::{Box, BoxSpec};
+use super::popup::Popup;
+use super::text::Text;
+use crate::composable;
+use crate::modifier::{Color, Modifier, Point, PointerEventKind};
+use cranpose_core::{FrameCallbackRegistration, FrameClock, MutableState, NodeId};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// How long the pointer has to rest over the content before the tooltip shows.
+pub const TOOLTIP_DWELL_MILLIS: u64 = 500;
+
+/// Vertical distance between the pointer and the top of the tooltip.
+const TOOLTIP_POINTER_GAP: f32 = 16.0;
+
+const TOOLTIP_BACKGROUND: Color = Color(0.2, 0.2, 0.2, 0.9);
+
+/// Shows `text` in a popup next to the pointer once it has hovered over
+/// `content` for [`TOOLTIP_DWELL_MILLIS`].
+///
+/// The dwell is timed with frame callbacks, so it advances with the frame
+/// clock. Leaving `content` before the delay elapses cancels the pending
+/// tooltip; leaving it while the tooltip is shown hides it.
+#[composable]
+pub fn Tooltip<S, C>(text: S, mut content: C) -> NodeId
+where
+    S: Into<String> + Clone + PartialEq + 'static,
+    C: FnMut() + 'static,
+{
+    let text: String = text.into();
+    let shown: MutableState<Option<Point>> = cranpose_core::useState(|| None);
+    let pending: Rc<RefCell<Option<FrameCallbackRegistration>>> =
+        cranpose_core::remember(|| Rc::new(RefCell::new(None))).with(|pending| pending.clone());
+    let clock =
+        cranpose_core::with_current_composer(|composer| composer.runtime_handle().frame_clock());
+
+    let hover = Modifier::empty().pointer_input((), move |scope| {
+        let pending = Rc::clone(&pending);
+        let clock = clock.clone();
+        async move {
+            scope
+                .await_pointer_event_scope(|await_scope| async move {
+                    let anchor = Rc::new(Cell::new(Point::default()));
+                    loop {
+                        let event = await_scope.await_pointer_event().await;
+                        match event.kind {
+                            PointerEventKind::Enter | PointerEventKind::Move => {
+                                anchor.set(event.position);
+                                let idle = pending.borrow().is_none();
+                                if idle && shown.with(Option::is_none) {
+                                    schedule_dwell(
+                                        clock.clone(),
+                                        Rc::clone(&pending),
+                                        None,
+                                        shown,
+                                        Rc::clone(&anchor),
+                                    );
+                                }
+                            }
+                            PointerEventKind::Exit => {
+                                pending.borrow_mut().take();
+                                if shown.with(Option::is_some) {
+                                    shown.set(None);
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                })
+                .await;
+        }
+    });
+
+    Box(hover, BoxSpec::default(), move || {
+        content();
+        if let Some(anchor) = shown.get() {
+            let text = text.clone();
+            Popup(
+                Point {
+                    x: anchor.x,
+                    y: anchor.y + TOOLTIP_POINTER_GAP,
+                },
+                || {},
+                move || {
+                    Text(
+                        text.clone(),
+                        Modifier::empty()
+                            .background(TOOLTIP_BACKGROUND)
+                            .padding(6.0),
+                    );
+                },
+            );
+        }
+    })
+}
+
+/// Waits frame by frame until the dwell delay has passed since the first
+/// frame, then shows the tooltip at the latest pointer position.
+fn schedule_dwell(
+    clock: FrameClock,
+    pending: Rc<RefCell<Option<FrameCallbackRegistration>>>,
+    start_nanos: Option<u64>,
+    shown: MutableState<Option<Point>>,
+    anchor: Rc<Cell<Point>>,
+) {
+    let next_clock = clock.clone();
+    let next_pending = Rc::clone(&pending);
+    let registration = clock.with_frame_nanos(move |frame_time_nanos| {
+        let start = start_nanos.unwrap_or(frame_time_nanos);
+        if frame_time_nanos.saturating_sub(start) >= TOOLTIP_DWELL_MILLIS * 1_000_000 {
+            next_pending.borrow_mut().take();
+            shown.set(Some(anchor.get()));
+        } else {
+            schedule_dwell(next_clock, next_pending, Some(start), shown, anchor);
+        }
+    });
+    *pending.borrow_mut() = Some(registration);
+}
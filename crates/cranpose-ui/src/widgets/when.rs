@@ -0,0 +1,23 @@
+//! When conditional content helper
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+
+/// Composes `content` only while `condition` is true.
+///
+/// Unlike a bare `if`, the helper always occupies its own group, so hiding the
+/// content empties that group: its nodes are removed, its remembered values are
+/// dropped and every `DisposableEffect` inside runs its cleanup. Showing it again
+/// composes `content` from scratch under the same key. Only the condition seen
+/// by a composition pass matters; toggling it back and forth between two passes
+/// disposes nothing.
+#[composable]
+pub fn When<C>(condition: bool, mut content: C)
+where
+    C: FnMut() + 'static,
+{
+    if condition {
+        cranpose_core::with_key(&"When::content", content);
+    }
+}
@@ -0,0 +1,66 @@
+//! Window insets composition local.
+//!
+//! The platform reports the parts of the window covered by system UI: the
+//! status bar, the navigation bar and the on-screen keyboard. The app shell
+//! provides them through [`LocalWindowInsets`], and content keeps clear of
+//! them with [`Modifier::safe_content_padding`](crate::Modifier::safe_content_padding)
+//! or [`Modifier::window_insets_padding`](crate::Modifier::window_insets_padding).
+
+#![allow(non_snake_case)]
+
+use cranpose_core::{compositionLocalOf, CompositionLocal};
+use cranpose_ui_graphics::EdgeInsets;
+
+/// Insets of the window covered by system UI, in layout pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WindowInsets {
+    pub status_bars: EdgeInsets,
+    pub navigation_bars: EdgeInsets,
+    /// Space taken by the on-screen keyboard (IME); zero while it is hidden.
+    pub ime: EdgeInsets,
+}
+
+impl WindowInsets {
+    /// Status and navigation bars combined.
+    pub fn system_bars(&self) -> EdgeInsets {
+        union(self.status_bars, self.navigation_bars)
+    }
+
+    /// Everything content must stay clear of: system bars and the keyboard.
+    pub fn safe_content(&self) -> EdgeInsets {
+        union(self.system_bars(), self.ime)
+    }
+}
+
+/// Per-side maximum of `a` and `b`.
+pub(crate) fn union(a: EdgeInsets, b: EdgeInsets) -> EdgeInsets {
+    EdgeInsets::from_components(
+        a.left.max(b.left),
+        a.top.max(b.top),
+        a.right.max(b.right),
+        a.bottom.max(b.bottom),
+    )
+}
+
+/// What is left of `insets` once `consumed` has already been applied.
+pub(crate) fn exclude(insets: EdgeInsets, consumed: EdgeInsets) -> EdgeInsets {
+    EdgeInsets::from_components(
+        (insets.left - consumed.left).max(0.0),
+        (insets.top - consumed.top).max(0.0),
+        (insets.right - consumed.right).max(0.0),
+        (insets.bottom - consumed.bottom).max(0.0),
+    )
+}
+
+thread_local! {
+    static LOCAL_WINDOW_INSETS: CompositionLocal<WindowInsets> =
+        compositionLocalOf(WindowInsets::default);
+}
+
+/// Window insets reported by the platform.
+///
+/// Zero everywhere unless the host provides them. Readers recompose when the
+/// insets change, e.g. when the keyboard opens.
+pub fn LocalWindowInsets() -> CompositionLocal<WindowInsets> {
+    LOCAL_WINDOW_INSETS.with(Clone::clone)
+}
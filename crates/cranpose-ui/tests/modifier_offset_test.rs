@@ -311,3 +311,85 @@ fn test_nested_padding_accumulates() {
         "Inner box should be at outer padding (10) + middle padding (5)"
     );
 }
+
+#[test]
+fn test_offset_outside_parent_keeps_parent_size() {
+    // A badge-like child pushed partly out of its wrap-content parent on both
+    // sides; the parent must size itself as if there were no offset.
+    let mut composition = run_test_composition(|| {
+        Column(Modifier::empty(), ColumnSpec::default(), || {
+            Box(Modifier::empty(), BoxSpec::default(), || {
+                Box(
+                    Modifier::empty()
+                        .size(Size {
+                            width: 40.0,
+                            height: 40.0,
+                        })
+                        .offset(-10.0, -15.0),
+                    BoxSpec::default(),
+                    || {},
+                );
+            });
+            Box(
+                Modifier::empty()
+                    .size(Size {
+                        width: 100.0,
+                        height: 100.0,
+                    })
+                    .clip_to_bounds(),
+                BoxSpec::default(),
+                || {
+                    Box(
+                        Modifier::empty()
+                            .size(Size {
+                                width: 40.0,
+                                height: 40.0,
+                            })
+                            .offset(80.0, 90.0),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                },
+            );
+        });
+    });
+
+    let root = composition.root().expect("has root");
+    let mut applier = composition.applier_mut();
+    let layout = applier
+        .compute_layout(
+            root,
+            Size {
+                width: 800.0,
+                height: 600.0,
+            },
+        )
+        .expect("layout computation");
+
+    let column = layout.root();
+    assert_eq!(column.rect.width, 100.0, "Column width ignores offsets");
+    assert_eq!(column.rect.height, 140.0, "Column height ignores offsets");
+
+    let wrapping = &column.children[0];
+    assert_eq!(
+        wrapping.rect.width, 40.0,
+        "Parent width should be unchanged"
+    );
+    assert_eq!(
+        wrapping.rect.height, 40.0,
+        "Parent height should be unchanged"
+    );
+    let badge = &wrapping.children[0].rect;
+    assert_eq!(badge.x, -10.0, "Negative x offset should not be clamped");
+    assert_eq!(badge.y, -15.0, "Negative y offset should not be clamped");
+
+    // Clipping is a drawing concern: the overflowing child keeps its full rect.
+    let clipping = &column.children[1];
+    assert_eq!(clipping.rect.width, 100.0);
+    assert_eq!(clipping.rect.height, 100.0);
+    let overflowing = &clipping.children[0].rect;
+    assert_eq!(overflowing.x, 80.0);
+    assert_eq!(overflowing.y, 130.0, "Column position (40) + offset (90)");
+    assert_eq!(overflowing.width, 40.0);
+    assert_eq!(overflowing.height, 40.0);
+}